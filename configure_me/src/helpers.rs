@@ -0,0 +1,2178 @@
+//! Helpers called by the generated code to keep it short
+//!
+//! This is not a public API, it may change with any release of `configure_me_codegen`.
+
+use parse_arg::{ParseArg, ParseArgError};
+use serde::de::{self, Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+use toml::Value;
+
+/// Count of a switch recovered from the value it's mapped to by `map_to`, the position of the value
+pub fn mapped_count<T: PartialEq>(value: &T, values: &[T]) -> u32 {
+    values.iter().position(|candidate| candidate == value).map_or(0, |count| count as u32)
+}
+
+/// Replaces the value with the new one, if any
+pub fn merge<T>(target: &mut Option<T>, value: Option<T>) {
+    if value.is_some() {
+        *target = value;
+    }
+}
+
+/// Merges the new value, if any, into the old one using `merge_fn`
+pub fn merge_with<T, F: FnOnce(&mut T, T)>(target: &mut Option<T>, value: Option<T>, merge_fn: F) {
+    if let Some(value) = value {
+        match target {
+            Some(old) => merge_fn(old, value),
+            None => *target = Some(value),
+        }
+    }
+}
+
+/// Parses the environment variable `name`, if present
+pub fn parse_env<T: ParseArg>(name: &str) -> Result<Option<T>, T::Error> {
+    parse_env_value(std::env::var_os(name))
+}
+
+/// Parses the environment variable `name`, if present and not empty
+pub fn parse_env_ignore_empty<T: ParseArg>(name: &str) -> Result<Option<T>, T::Error> {
+    parse_env_value_ignore_empty(std::env::var_os(name))
+}
+
+/// Parses the environment variable `name`, if present, failing with `None` if it's empty
+pub fn parse_env_reject_empty<T: ParseArg>(name: &str) -> Result<Option<T>, Option<T::Error>> {
+    parse_env_value_reject_empty(std::env::var_os(name))
+}
+
+/// Parses the value of an environment variable, if present
+pub fn parse_env_value<T: ParseArg>(value: Option<OsString>) -> Result<Option<T>, T::Error> {
+    match value {
+        Some(value) => T::parse_owned_arg(value).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Parses the value of an environment variable, if present and not empty
+pub fn parse_env_value_ignore_empty<T: ParseArg>(value: Option<OsString>) -> Result<Option<T>, T::Error> {
+    match value {
+        Some(ref value) if value.is_empty() => Ok(None),
+        Some(value) => T::parse_owned_arg(value).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Parses the value of an environment variable, if present, failing with `None` if it's empty
+pub fn parse_env_value_reject_empty<T: ParseArg>(value: Option<OsString>) -> Result<Option<T>, Option<T::Error>> {
+    match value {
+        Some(ref value) if value.is_empty() => Err(None),
+        Some(value) => T::parse_owned_arg(value).map(Some).map_err(Some),
+        None => Ok(None),
+    }
+}
+
+/// Variables set in a `.env` file, used if they aren't set in the environment
+#[derive(Default)]
+pub struct EnvFile {
+    vars: Vec<(String, OsString)>,
+}
+
+impl EnvFile {
+    /// Loads the variables from the file at `path`, a missing file sets none
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(EnvFile::default()),
+            Err(error) => return Err(error),
+        };
+        let mut vars = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let line = line.strip_prefix("export ").map_or(line, str::trim_start);
+            let invalid = |problem: &str| io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", i + 1, problem));
+            let pos = line.find('=').ok_or_else(|| invalid("expected NAME=value"))?;
+            let name = line[..pos].trim_end();
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(invalid(&format!("invalid variable name '{}'", name)));
+            }
+            let value = env_file_value(line[(pos + 1)..].trim_start()).ok_or_else(|| invalid("unterminated quoted value"))?;
+            vars.push((name.to_owned(), value.into()));
+        }
+        Ok(EnvFile { vars })
+    }
+
+    /// Looks up the variable `name` in the environment, falling back to the file
+    pub fn var(&self, name: &str) -> Option<OsString> {
+        std::env::var_os(name).or_else(|| self.file_var(name))
+    }
+
+    /// All variables in the environment followed by the ones only set in the file
+    pub fn vars(&self) -> Vec<(OsString, OsString)> {
+        let mut vars = std::env::vars_os().collect::<Vec<_>>();
+        for (name, _) in &self.vars {
+            if !vars.iter().any(|(var, _)| var == OsStr::new(name)) {
+                vars.push((name.into(), self.file_var(name).expect("the variable is in the file")));
+            }
+        }
+        vars
+    }
+
+    /// The last value of `name` in the file
+    fn file_var(&self, name: &str) -> Option<OsString> {
+        self.vars.iter().rev().find(|(var, _)| var == name).map(|(_, value)| value.clone())
+    }
+}
+
+/// Value of a variable in a `.env` file, `None` if a quote is missing
+///
+/// Values in single quotes are taken literally, double quotes allow escaping with a backslash and
+/// unquoted values end at a comment.
+fn env_file_value(value: &str) -> Option<String> {
+    if let Some(value) = value.strip_prefix('\'') {
+        return value.find('\'').map(|end| value[..end].to_owned());
+    }
+    if let Some(value) = value.strip_prefix('"') {
+        let mut result = String::new();
+        let mut chars = value.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return Some(result),
+                '\\' => match chars.next()? {
+                    'n' => result.push('\n'),
+                    't' => result.push('\t'),
+                    c => result.push(c),
+                },
+                c => result.push(c),
+            }
+        }
+        return None;
+    }
+    Some(value.split(" #").next().unwrap_or_default().trim_end().to_owned())
+}
+
+/// Chooses the environment variable to read, falling back to the old names of a renamed one
+///
+/// An old name is only used if `name` is unset, with a warning asking to rename it.
+pub fn renamed_env_var<F: Fn(&str) -> Option<OsString>>(name: &'static str, old_names: &[&'static str], var: F) -> &'static str {
+    if var(name).is_some() {
+        return name;
+    }
+    match old_names.iter().find(|old| var(old).is_some()) {
+        Some(&old) => {
+            eprintln!("Warning: the environment variable {} is deprecated, use {} instead", old, name);
+            old
+        },
+        None => name,
+    }
+}
+
+/// Returns the value of the environment variable `name` or, if it's unset, the contents of the
+/// file named by the variable `name` followed by `_FILE`
+///
+/// This is the convention of Docker and Kubernetes secrets. A single trailing line ending is
+/// removed from the contents, the error contains the path of the file.
+pub fn secret_env_var<F: Fn(&str) -> Option<OsString>>(name: &str, var: F) -> Result<Option<OsString>, (PathBuf, io::Error)> {
+    if let Some(value) = var(name) {
+        return Ok(Some(value));
+    }
+    let path = match var(&format!("{}_FILE", name)) {
+        Some(path) => PathBuf::from(path),
+        None => return Ok(None),
+    };
+    let mut content = match fs::read(&path) {
+        Ok(content) => content,
+        Err(error) => return Err((path, error)),
+    };
+    if content.ends_with(b"\n") {
+        content.pop();
+        if content.ends_with(b"\r") {
+            content.pop();
+        }
+    }
+    match bytes_to_os_string(content) {
+        Ok(value) => Ok(Some(value)),
+        Err(error) => Err((path, error)),
+    }
+}
+
+/// Moves the value of the old key of a renamed field in the config file `file` to the field
+///
+/// The value of the new key wins if both are present.
+pub fn rename_key<T>(target: &mut Option<T>, old_value: Option<T>, old: &str, new: &str, file: &Path) {
+    if old_value.is_some() {
+        eprintln!("Warning: `{}` in the configuration file {} is deprecated, use `{}` instead", old, file.display(), new);
+        if target.is_none() {
+            *target = old_value;
+        }
+    }
+}
+
+/// Primitive integer that can be parsed in any radix
+pub trait RadixInt: ParseArg<Error=ParseArgError<ParseIntError>> {
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_radix_int {
+    ($($type:ty),*) => {
+        $(
+            impl RadixInt for $type {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$type>::from_str_radix(src, radix)
+                }
+            }
+        )*
+    }
+}
+
+impl_radix_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Integer parsed from decimal or hexadecimal (`0x`), octal (`0o`) or binary (`0b`) notation
+pub struct Int<T>(pub T);
+
+/// Error of parsing an `Int`
+#[derive(Debug)]
+pub enum IntError {
+    Digits(ParseIntError),
+    SignAfterPrefix,
+}
+
+impl fmt::Display for IntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntError::Digits(error) => write!(f, "{}", error),
+            IntError::SignAfterPrefix => write!(f, "the sign must go before the prefix of the notation"),
+        }
+    }
+}
+
+impl<T: RadixInt> ParseArg for Int<T> {
+    type Error = ParseArgError<IntError>;
+
+    fn parse_arg(arg: &OsStr) -> Result<Self, Self::Error> {
+        let arg = arg.to_str().ok_or(ParseArgError::InvalidUtf8)?;
+        let (sign, unsigned) = match arg.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", arg.strip_prefix('+').unwrap_or(arg)),
+        };
+        let prefixes = [("0x", 16), ("0X", 16), ("0o", 8), ("0O", 8), ("0b", 2), ("0B", 2)];
+        let prefixed = prefixes
+            .iter()
+            .find_map(|&(prefix, radix)| unsigned.strip_prefix(prefix).map(|digits| (digits, radix)));
+        let value = match prefixed {
+            Some((digits, _)) if digits.starts_with(['+', '-']) => return Err(ParseArgError::FromStr(IntError::SignAfterPrefix)),
+            Some((digits, radix)) => T::from_str_radix(&format!("{}{}", sign, digits), radix),
+            None => T::from_str_radix(arg, 10),
+        };
+        value
+            .map(Int)
+            .map_err(|error| ParseArgError::FromStr(IntError::Digits(error)))
+    }
+
+    fn describe_type<W: fmt::Write>(writer: W) -> fmt::Result {
+        T::describe_type(writer)
+    }
+}
+
+/// `Int` whose description also lists the notations, used in the hints of parse errors if
+/// `--help` mentions them
+pub struct NotatedInt<T>(pub T);
+
+impl<T: RadixInt> ParseArg for NotatedInt<T> {
+    type Error = <Int<T> as ParseArg>::Error;
+
+    fn parse_arg(arg: &OsStr) -> Result<Self, Self::Error> {
+        Int::parse_arg(arg).map(|Int(value)| NotatedInt(value))
+    }
+
+    fn describe_type<W: fmt::Write>(mut writer: W) -> fmt::Result {
+        T::describe_type(&mut writer)?;
+        write!(writer, " in decimal, hexadecimal (0x), octal (0o) or binary (0b) notation")
+    }
+}
+
+/// Duration written like `30s`, `5m` or `1h30m`, the type of params with `type = "duration"`
+#[cfg(feature = "humantime")]
+pub struct HumanDuration(pub ::std::time::Duration);
+
+#[cfg(feature = "humantime")]
+impl ParseArg for HumanDuration {
+    type Error = ParseArgError<::humantime::DurationError>;
+
+    fn parse_arg(arg: &OsStr) -> Result<Self, Self::Error> {
+        let arg = arg.to_str().ok_or(ParseArgError::InvalidUtf8)?;
+        ::humantime::parse_duration(arg)
+            .map(HumanDuration)
+            .map_err(ParseArgError::FromStr)
+    }
+
+    fn describe_type<W: fmt::Write>(mut writer: W) -> fmt::Result {
+        write!(writer, "a duration such as 30s, 5m or 1h30m")
+    }
+}
+
+#[cfg(feature = "humantime")]
+impl<'de> Deserialize<'de> for HumanDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        ::humantime::parse_duration(&value)
+            .map(HumanDuration)
+            .map_err(|error| de::Error::custom(format!("invalid duration '{}': {}", value, error)))
+    }
+}
+
+/// Deserializes a duration written like `30s` from a string in a config file
+#[cfg(feature = "humantime")]
+pub fn deserialize_duration<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<::std::time::Duration>, D::Error> {
+    HumanDuration::deserialize(deserializer).map(|value| Some(value.0))
+}
+
+/// Number of bytes written like `64KiB`, `2GB` or `4096`, the type of params with `type = "size"`
+pub struct ByteSize(pub u64);
+
+/// Error of parsing a size
+#[derive(Debug)]
+pub enum SizeError {
+    InvalidNumber,
+    UnknownUnit(String),
+    Overflow,
+}
+
+impl fmt::Display for SizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SizeError::InvalidNumber => write!(f, "the size doesn't start with a number"),
+            SizeError::UnknownUnit(unit) => write!(f, "unknown unit '{}', expected B, kB, MB, GB, TB, PB, EB, KiB, MiB, GiB, TiB, PiB or EiB", unit),
+            SizeError::Overflow => write!(f, "the size doesn't fit into 64 bits"),
+        }
+    }
+}
+
+impl ByteSize {
+    /// Parses a decimal number optionally followed by a decimal or binary unit, case-insensitive
+    fn parse(size: &str) -> Result<Self, SizeError> {
+        const UNITS: &[(&str, u64)] = &[
+            ("", 1), ("b", 1),
+            ("k", 1_000), ("kb", 1_000), ("ki", 1 << 10), ("kib", 1 << 10),
+            ("m", 1_000_000), ("mb", 1_000_000), ("mi", 1 << 20), ("mib", 1 << 20),
+            ("g", 1_000_000_000), ("gb", 1_000_000_000), ("gi", 1 << 30), ("gib", 1 << 30),
+            ("t", 1_000_000_000_000), ("tb", 1_000_000_000_000), ("ti", 1 << 40), ("tib", 1 << 40),
+            ("p", 1_000_000_000_000_000), ("pb", 1_000_000_000_000_000), ("pi", 1 << 50), ("pib", 1 << 50),
+            ("e", 1_000_000_000_000_000_000), ("eb", 1_000_000_000_000_000_000), ("ei", 1 << 60), ("eib", 1 << 60),
+        ];
+
+        let size = size.trim();
+        let digits = size.find(|c: char| !c.is_ascii_digit()).unwrap_or(size.len());
+        if digits == 0 {
+            return Err(SizeError::InvalidNumber);
+        }
+        let number = size[..digits].parse::<u64>().map_err(|_| SizeError::Overflow)?;
+        let unit = size[digits..].trim_start();
+        let multiplier = UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|&(_, multiplier)| multiplier)
+            .ok_or_else(|| SizeError::UnknownUnit(unit.to_owned()))?;
+        number.checked_mul(multiplier).map(ByteSize).ok_or(SizeError::Overflow)
+    }
+}
+
+impl ParseArg for ByteSize {
+    type Error = ParseArgError<SizeError>;
+
+    fn parse_arg(arg: &OsStr) -> Result<Self, Self::Error> {
+        let arg = arg.to_str().ok_or(ParseArgError::InvalidUtf8)?;
+        ByteSize::parse(arg).map_err(ParseArgError::FromStr)
+    }
+
+    fn describe_type<W: fmt::Write>(mut writer: W) -> fmt::Result {
+        write!(writer, "a size in bytes such as 4096, 64KiB or 2GB")
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SizeVisitor;
+
+        impl<'de> de::Visitor<'de> for SizeVisitor {
+            type Value = ByteSize;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a number of bytes or a string such as \"64KiB\"")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(ByteSize(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                ::std::convert::TryFrom::try_from(value)
+                    .map(ByteSize)
+                    .map_err(|_| E::custom(format!("invalid size {}: the size can't be negative", value)))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                ByteSize::parse(value).map_err(|error| E::custom(format!("invalid size '{}': {}", value, error)))
+            }
+        }
+
+        deserializer.deserialize_any(SizeVisitor)
+    }
+}
+
+/// Deserializes a size from a TOML integer or a string like `64KiB` in a config file
+pub fn deserialize_size<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    ByteSize::deserialize(deserializer).map(|value| Some(value.0))
+}
+
+/// Values of a repeatable param given in a single environment variable, separated by `D`
+///
+/// An empty string is an empty list. Strings that aren't valid UTF-8 can't be split, so they're
+/// parsed as a single value.
+pub struct Delimited<T, const D: char>(pub Vec<T>);
+
+impl<T: ParseArg, const D: char> ParseArg for Delimited<T, D> {
+    type Error = T::Error;
+
+    fn parse_arg(arg: &OsStr) -> Result<Self, Self::Error> {
+        match arg.to_str() {
+            Some("") => Ok(Delimited(Vec::new())),
+            Some(arg) => arg.split(D).map(|value| T::parse_arg(value.as_ref())).collect::<Result<_, _>>().map(Delimited),
+            None => T::parse_arg(arg).map(|value| Delimited(vec![value])),
+        }
+    }
+
+    fn describe_type<W: fmt::Write>(mut writer: W) -> fmt::Result {
+        T::describe_type(&mut writer)?;
+        write!(writer, " separated by '{}'", D)
+    }
+}
+
+/// Appends the values of a repeatable param from a source with a higher priority
+pub fn append_values<T>(values: &mut Vec<T>, other: Vec<T>) {
+    values.extend(other);
+}
+
+/// Joins the values of a repeatable param into the value of an environment variable
+pub fn join_values<'a, T: 'a + ::ToArg, I: IntoIterator<Item=&'a T>>(values: I, delimiter: char) -> OsString {
+    let mut joined = OsString::new();
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            joined.push(delimiter.encode_utf8(&mut [0; 4]));
+        }
+        joined.push(value.to_arg());
+    }
+    joined
+}
+
+/// Entry of a map param given as `key=value`, split at the first `=`
+pub struct KeyValue<T>(pub String, pub T);
+
+/// Error of parsing `key=value`
+#[derive(Debug)]
+pub enum KeyValueError<E> {
+    InvalidUtf8,
+    MissingSeparator,
+    Value(E),
+}
+
+impl<E: fmt::Display> fmt::Display for KeyValueError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyValueError::InvalidUtf8 => write!(f, "invalid UTF-8"),
+            KeyValueError::MissingSeparator => write!(f, "the key isn't followed by '='"),
+            KeyValueError::Value(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<T: ParseArg> ParseArg for KeyValue<T> {
+    type Error = KeyValueError<T::Error>;
+
+    fn parse_arg(arg: &OsStr) -> Result<Self, Self::Error> {
+        let arg = arg.to_str().ok_or(KeyValueError::InvalidUtf8)?;
+        let (key, value) = arg.split_once('=').ok_or(KeyValueError::MissingSeparator)?;
+        T::parse_arg(value.as_ref())
+            .map(|value| KeyValue(key.to_owned(), value))
+            .map_err(KeyValueError::Value)
+    }
+
+    fn describe_type<W: fmt::Write>(mut writer: W) -> fmt::Result {
+        write!(writer, "KEY=VALUE where VALUE is ")?;
+        T::describe_type(writer)
+    }
+}
+
+/// Entries of a map param
+pub type Map<T> = HashMap<String, T>;
+
+/// Merges the entries of a map param from a source with a higher priority, which override the
+/// entries with the same keys
+pub fn merge_maps<T>(map: &mut Map<T>, other: Map<T>) {
+    map.extend(other);
+}
+
+/// Parses the environment variables starting with `prefix` into the entries of a map param, the
+/// keys are the rest of the names in lowercase
+///
+/// Returns `None` if there are no such variables, the name of the variable is returned with the
+/// error.
+pub fn parse_env_map<T: ParseArg, I: IntoIterator<Item=(OsString, OsString)>>(prefix: &str, vars: I) -> Result<Option<Map<T>>, (String, T::Error)> {
+    let mut map = None;
+    for (name, value) in vars {
+        let name = match name.to_str() {
+            Some(name) => name,
+            None => continue,
+        };
+        match name.strip_prefix(prefix) {
+            Some(key) if !key.is_empty() => {
+                let value = T::parse_owned_arg(value).map_err(|error| (name.to_owned(), error))?;
+                map.get_or_insert_with(HashMap::new).insert(key.to_ascii_lowercase(), value);
+            },
+            _ => (),
+        }
+    }
+    Ok(map)
+}
+
+/// Returns the entries of a map param sorted by their keys
+fn sorted_entries<T>(map: &Map<T>) -> Vec<(&String, &T)> {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(key, _)| *key);
+    entries
+}
+
+/// Converts the entries of a map param into environment variables, the names are `prefix`
+/// followed by the keys in uppercase
+pub fn map_env_vars<T: ::ToArg>(prefix: &str, map: &Map<T>) -> Vec<(OsString, OsString)> {
+    sorted_entries(map)
+        .into_iter()
+        .map(|(key, value)| (format!("{}{}", prefix, key.to_ascii_uppercase()).into(), value.to_arg()))
+        .collect()
+}
+
+/// Converts the entries of a map param into arguments `name=key=value`
+pub fn map_args<T: ::ToArg>(name: &str, map: &Map<T>) -> Vec<OsString> {
+    sorted_entries(map)
+        .into_iter()
+        .map(|(key, value)| arg_with_value(name, &arg_with_value(key, value)))
+        .collect()
+}
+
+/// Formats the entries of a map param for humans, sorted by their keys and separated by spaces
+pub fn format_map<T: ::ToArg>(map: &Map<T>) -> String {
+    sorted_entries(map)
+        .into_iter()
+        .map(|(key, value)| arg_with_value(key, value).to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Generates between `min` and `max` arbitrary values of a param
+#[cfg(feature = "test-helpers")]
+pub fn arbitrary_values<'a, T: ::arbitrary::Arbitrary<'a>>(u: &mut ::arbitrary::Unstructured<'a>, min: usize, max: usize) -> ::arbitrary::Result<Vec<T>> {
+    let len = u.int_in_range(min..=max)?;
+    (0..len).map(|_| u.arbitrary()).collect()
+}
+
+/// Pattern of `validate_regex`, compiled when it's first used
+#[cfg(feature = "regex")]
+pub struct Pattern {
+    pattern: &'static str,
+    regex: ::std::sync::OnceLock<::regex::Regex>,
+}
+
+#[cfg(feature = "regex")]
+impl Pattern {
+    pub const fn new(pattern: &'static str) -> Self {
+        Pattern {
+            pattern,
+            regex: ::std::sync::OnceLock::new(),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.pattern
+    }
+
+    /// The pattern was checked by `configure_me_codegen`, so it always compiles
+    pub fn is_match(&self, value: &str) -> bool {
+        self.regex
+            .get_or_init(|| ::regex::Regex::new(self.pattern).expect("invalid pattern"))
+            .is_match(value)
+    }
+}
+
+/// State of a loaded config file, compared to detect changes
+#[derive(Clone)]
+struct FileState {
+    modified: Option<::std::time::SystemTime>,
+    len: u64,
+    hash: u64,
+}
+
+/// Length and hash of the content of a loaded config file, kept instead of the content itself
+#[derive(Clone)]
+pub struct FileDigest {
+    len: u64,
+    hash: u64,
+}
+
+impl FileDigest {
+    /// Computes the digest of `content`
+    pub fn of(content: &[u8]) -> Self {
+        FileDigest {
+            len: content.len() as u64,
+            hash: hash_content(content),
+        }
+    }
+}
+
+fn hash_content(content: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Sources of a loaded configuration, used to detect changes of config files by polling them
+#[derive(Clone)]
+pub struct PollState {
+    args: Vec<OsString>,
+    optional_files: Vec<PathBuf>,
+    /// Missing files have no state
+    files: Vec<(PathBuf, Option<FileState>)>,
+}
+
+impl PollState {
+    /// Records the state of the files the configuration was loaded from, remote files aren't
+    /// polled
+    pub fn new(loaded_files: &[(PathBuf, FileDigest)]) -> Self {
+        let files = loaded_files
+            .iter()
+            .filter(|(path, _)| !is_remote(path))
+            .map(|(path, digest)| {
+                let metadata = fs::metadata(path).ok();
+                let state = FileState {
+                    modified: metadata.as_ref().and_then(|metadata| metadata.modified().ok()),
+                    len: metadata.map_or(digest.len, |metadata| metadata.len()),
+                    hash: digest.hash,
+                };
+                (path.clone(), Some(state))
+            })
+            .collect();
+
+        PollState {
+            args: Vec::new(),
+            optional_files: Vec::new(),
+            files,
+        }
+    }
+
+    /// Records the arguments and optional config files the configuration was loaded from
+    pub fn set_sources(&mut self, args: Vec<OsString>, optional_files: Vec<PathBuf>) {
+        for path in optional_files.iter().filter(|path| !is_remote(path)) {
+            if !self.files.iter().any(|(file, _)| file == path) {
+                self.files.push((path.clone(), None));
+            }
+        }
+        self.args = args;
+        self.optional_files = optional_files;
+    }
+
+    pub fn args(&self) -> &[OsString] {
+        &self.args
+    }
+
+    pub fn optional_files(&self) -> &[PathBuf] {
+        &self.optional_files
+    }
+
+    /// The polled files, including the missing optional ones
+    pub fn files(&self) -> impl Iterator<Item=&Path> {
+        self.files.iter().map(|(path, _)| &**path)
+    }
+
+    /// Whether any config file appeared, disappeared or changed
+    ///
+    /// The contents are only read if the modification time or size differ.
+    pub fn changed(&self) -> bool {
+        self.files.iter().any(|(path, state)| match (state, fs::metadata(path)) {
+            (None, metadata) => metadata.is_ok(),
+            (Some(_), Err(_)) => true,
+            (Some(state), Ok(metadata)) => {
+                if metadata.modified().ok() == state.modified && metadata.len() == state.len {
+                    return false;
+                }
+                fs::read(path).map_or(true, |content| hash_content(&content) != state.hash)
+            },
+        })
+    }
+}
+
+/// Calls `on_event` from another thread whenever something changes in the directories of `files`
+///
+/// The directories are watched instead of the files so that files replaced by renaming another
+/// file over them, like editors do, or created later are noticed too. The caller has to check
+/// whether the files actually changed. Missing directories aren't watched.
+#[cfg(feature = "watch")]
+pub fn watch_files<'a, I, F>(files: I, mut on_event: F) -> io::Result<::ConfigWatcher> where I: IntoIterator<Item=&'a Path>, F: FnMut() + Send + 'static {
+    use notify::{EventKind, RecursiveMode, Watcher};
+
+    let mut dirs = files
+        .into_iter()
+        .map(|file| match file.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_owned(),
+            _ => PathBuf::from("."),
+        })
+        .filter(|dir| dir.is_dir())
+        .collect::<Vec<_>>();
+    dirs.sort();
+    dirs.dedup();
+
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+        Ok(event) if matches!(event.kind, EventKind::Access(_)) => (),
+        // Errors may mean missed events, so the files are checked too
+        _ => on_event(),
+    }).map_err(io::Error::other)?;
+    for dir in &dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive).map_err(io::Error::other)?;
+    }
+    Ok(::ConfigWatcher { _watcher: watcher })
+}
+
+/// Config files of the program `name`, the one of the user first so that it overrides the one of
+/// the system
+pub fn user_config_files(name: &str) -> Vec<PathBuf> {
+    config_dirs()
+        .into_iter()
+        .map(|dir| dir.join(name).join("config.toml"))
+        .collect()
+}
+
+/// The directories of the config files of the user and the system
+///
+/// The directory of the user is `$XDG_CONFIG_HOME`, or `~/.config` if it isn't set to an absolute
+/// path, as required by the XDG Base Directory Specification.
+#[cfg(not(windows))]
+fn config_dirs() -> Vec<PathBuf> {
+    let user_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| std::env::var_os("HOME").filter(|home| !home.is_empty()).map(|home| Path::new(&home).join(".config")));
+    user_dir
+        .into_iter()
+        .chain(Some(PathBuf::from("/etc")))
+        .collect()
+}
+
+/// The directories of the config files of the user and the system, `%APPDATA%` and `%PROGRAMDATA%`
+#[cfg(windows)]
+fn config_dirs() -> Vec<PathBuf> {
+    ["APPDATA", "PROGRAMDATA"]
+        .iter()
+        .filter_map(std::env::var_os)
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .collect()
+}
+
+/// The `*.toml` files in the directory named like `config_file` with `.d` appended, in lexical
+/// order
+///
+/// A missing directory has no files, the error contains the path of the directory.
+pub fn drop_in_files(config_file: &Path) -> Result<Vec<PathBuf>, (PathBuf, io::Error)> {
+    let mut dir = config_file.as_os_str().to_owned();
+    dir.push(".d");
+    let dir = PathBuf::from(dir);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err((dir, error)),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|error| (dir.clone(), error))?.path();
+        if path.extension() == Some(OsStr::new("toml")) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// The maximum number of config files including each other, the outermost one included
+pub const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// The config files currently including other files, used to detect cycles and limit the depth
+#[derive(Debug, Default)]
+pub struct Includes {
+    stack: Vec<PathBuf>,
+}
+
+impl Includes {
+    /// Starts loading the files included by `config_file`, returns their paths resolved relative
+    /// to the directory of `config_file`
+    ///
+    /// Fails if `config_file` is already including files or too many files include each other.
+    /// The error contains the path of `config_file`. `leave()` must be called after the included
+    /// files are loaded.
+    pub fn enter(&mut self, config_file: &Path, includes: Vec<PathBuf>) -> Result<Vec<PathBuf>, (PathBuf, io::Error)> {
+        let canonical = self.check_cycle(config_file)?;
+        if self.stack.len() >= MAX_INCLUDE_DEPTH {
+            return Err((config_file.to_owned(), io::Error::new(io::ErrorKind::InvalidData, format!("more than {} config files include each other", MAX_INCLUDE_DEPTH))));
+        }
+        self.stack.push(canonical);
+        let dir = config_file.parent().unwrap_or_else(|| Path::new(""));
+        Ok(includes.into_iter().map(|include| dir.join(include)).collect())
+    }
+
+    /// Fails if `config_file` is already including files, returns its canonical path otherwise
+    ///
+    /// Called before loading an included file, which is loaded only once per parse.
+    pub fn check_cycle(&self, config_file: &Path) -> Result<PathBuf, (PathBuf, io::Error)> {
+        let canonical = fs::canonicalize(config_file).unwrap_or_else(|_| config_file.to_owned());
+        if self.stack.contains(&canonical) {
+            return Err((config_file.to_owned(), io::Error::new(io::ErrorKind::InvalidData, "the file includes itself")));
+        }
+        Ok(canonical)
+    }
+
+    /// Finishes loading the files included by the innermost file
+    pub fn leave(&mut self) {
+        self.stack.pop();
+    }
+}
+
+/// Converts the error of reading an included file, which is mandatory unlike the config files
+pub fn include_error(error: io::Error) -> io::Error {
+    if error.kind() == io::ErrorKind::NotFound {
+        io::Error::new(io::ErrorKind::InvalidInput, "the included file doesn't exist")
+    } else {
+        error
+    }
+}
+
+/// The profile selected by `option` given before `--` in `args`, or by the value of the
+/// environment variable, the first argument is the program path
+///
+/// Both `--profile NAME` and `--profile=NAME` are accepted, the last one wins. The profile has to
+/// be known before loading config files, which happens before the arguments are parsed.
+/// Abbreviated options have to be normalized beforehand, see `normalize_long_options`. The values
+/// of the options listed in `valued` are skipped, see `skip_values`.
+pub fn selected_profile(args: &[OsString], option: &str, valued: &[(&str, usize, usize)], env_var: Option<OsString>) -> Option<String> {
+    let mut profile = None;
+    let mut args = args.iter().skip(1).take_while(|arg| *arg != "--").peekable();
+    while let Some(arg) = args.next() {
+        if arg == option {
+            profile = args.next().cloned();
+        } else if let Some(value) = arg.to_str().and_then(|arg| arg.strip_prefix(option)).and_then(|arg| arg.strip_prefix('=')) {
+            profile = Some(value.into());
+        } else {
+            skip_values(arg, &mut args, valued);
+        }
+    }
+    profile.or(env_var).and_then(|profile| profile.into_string().ok())
+}
+
+/// Skips the values following the option `arg` if it takes any, so that they aren't mistaken for
+/// options
+///
+/// `valued` lists the options taking values with the minimum and maximum count of them, e.g.
+/// `("--port", 1, 1)` or `("-p", 1, 1)`. Short options may be combined with switches and have
+/// their first value attached, e.g. `-vp80`.
+fn skip_values<'a, I: Iterator<Item=&'a OsString>>(arg: &OsStr, args: &mut ::std::iter::Peekable<I>, valued: &[(&str, usize, usize)]) {
+    let (min, max) = match value_counts(arg, valued) {
+        Some(counts) => counts,
+        None => return,
+    };
+    for _ in 0..min {
+        args.next();
+    }
+    // Like the parser, optional values end at the first argument starting with `-`
+    for _ in min..max {
+        match args.peek() {
+            Some(arg) if !arg.to_str().is_some_and(|arg| arg.starts_with('-')) => args.next(),
+            _ => break,
+        };
+    }
+}
+
+/// Minimum and maximum count of the values following `arg` as separate arguments
+fn value_counts(arg: &OsStr, valued: &[(&str, usize, usize)]) -> Option<(usize, usize)> {
+    let arg = arg.to_str()?;
+    let counts = |option: &str| valued.iter().find(|&&(name, _, _)| name == option).map(|&(_, min, max)| (min, max));
+    if arg.starts_with("--") {
+        return counts(arg);
+    }
+    let shorts = arg.strip_prefix('-')?;
+    for (i, short) in shorts.char_indices() {
+        if let Some((min, max)) = counts(&format!("-{}", short)) {
+            let attached = i + short.len_utf8() < shorts.len();
+            return Some(if attached { (min - 1, max - 1) } else { (min, max) });
+        }
+    }
+    None
+}
+
+/// Layers the table `profile.<profile>` of a config file over the other values and removes the
+/// `profile` table
+///
+/// Tables are merged recursively, other values of the profile replace the values of the file.
+pub fn select_profile<D: ConfigDocument>(config_content: D, profile: Option<&str>) -> Result<::toml::value::Table, ::toml::de::Error> {
+    let mut table = config_content.into_table()?;
+    let mut profiles = match table.remove("profile") {
+        Some(Value::Table(profiles)) => profiles,
+        Some(_) => return Err(de::Error::custom("`profile` is not a table")),
+        None => return Ok(table),
+    };
+    match profile.and_then(|profile| profiles.remove(profile).map(|values| (profile, values))) {
+        Some((_, Value::Table(values))) => merge_tables(&mut table, values),
+        Some((profile, _)) => return Err(de::Error::custom(format_args!("`profile.{}` is not a table", profile))),
+        None => (),
+    }
+    Ok(table)
+}
+
+/// Merges `values` into `table`, replacing all values except tables
+fn merge_tables(table: &mut ::toml::value::Table, values: ::toml::value::Table) {
+    for (key, value) in values {
+        match value {
+            Value::Table(values) => match table.get_mut(&key) {
+                Some(Value::Table(table)) => merge_tables(table, values),
+                _ => {
+                    table.insert(key, Value::Table(values));
+                },
+            },
+            value => {
+                table.insert(key, value);
+            },
+        }
+    }
+}
+
+/// Content of a config file, either still to be parsed or already parsed into a table
+///
+/// Parsed tables, e.g. converted from JSON or cached for a file specified again, are deserialized
+/// directly instead of being serialized to TOML and parsed again.
+pub trait ConfigDocument {
+    /// Parses the content unless it's already a table
+    fn into_table(self) -> Result<::toml::value::Table, ::toml::de::Error>;
+}
+
+impl ConfigDocument for &[u8] {
+    fn into_table(self) -> Result<::toml::value::Table, ::toml::de::Error> {
+        ::toml::from_slice(self)
+    }
+}
+
+impl ConfigDocument for ::toml::value::Table {
+    fn into_table(self) -> Result<::toml::value::Table, ::toml::de::Error> {
+        Ok(self)
+    }
+}
+
+/// Returns the table at `path` in a config file, a missing table is empty
+fn table_at<D: ConfigDocument>(config_content: D, path: &[&str]) -> Result<::toml::value::Table, ::toml::de::Error> {
+    let mut value = Value::Table(config_content.into_table()?);
+    for (i, key) in path.iter().enumerate() {
+        value = match value {
+            Value::Table(mut table) => table.remove(*key).unwrap_or_else(|| Value::Table(Default::default())),
+            _ => return Err(de::Error::custom(format_args!("`{}` is not a table", path[..i].join(".")))),
+        };
+    }
+    match value {
+        Value::Table(table) => Ok(table),
+        _ => Err(de::Error::custom(format_args!("`{}` is not a table", path.join(".")))),
+    }
+}
+
+/// Parses the content of a config file in `format` into a TOML table, so that all formats are
+/// processed the same way
+#[cfg(feature = "json")]
+pub fn to_toml(config_content: &[u8], format: ::ConfigFormat) -> Result<::toml::value::Table, ::toml::de::Error> {
+    match format {
+        ::ConfigFormat::Toml => config_content.into_table(),
+        ::ConfigFormat::Json => match ::serde_json::from_slice::<Value>(config_content).map_err(de::Error::custom)? {
+            Value::Table(table) => Ok(table),
+            _ => Err(de::Error::custom("the JSON config file must contain an object")),
+        },
+    }
+}
+
+/// Deserializes the table at `path` in a config file, a missing table is empty
+pub fn parse_table<T: de::DeserializeOwned, D: ConfigDocument>(config_content: D, path: &[&str]) -> Result<T, ::toml::de::Error> {
+    Value::Table(table_at(config_content, path)?).try_into()
+}
+
+/// Deserializes the table at `path` in a config file, moving the keys of the tables of
+/// `sections` up, prefixed by the name of the section
+///
+/// The prefixed names of the keys listed for the sections are rejected outside the sections.
+pub fn parse_sections<T: de::DeserializeOwned, D: ConfigDocument>(config_content: D, path: &[&str], sections: &[(&str, &[&str])]) -> Result<T, ::toml::de::Error> {
+    let mut table = table_at(config_content, path)?;
+    for (section, keys) in sections {
+        for key in *keys {
+            let prefixed = format!("{}_{}", section, key);
+            if table.contains_key(&prefixed) {
+                let section_path = path.iter().chain(Some(section)).cloned().collect::<Vec<_>>().join(".");
+                return Err(de::Error::custom(format_args!("`{}` must be written as `{}` in `[{}]`", prefixed, key, section_path)));
+            }
+        }
+        match table.remove(*section) {
+            Some(Value::Table(section_table)) => {
+                for (key, value) in section_table {
+                    table.insert(format!("{}_{}", section, key), value);
+                }
+            },
+            Some(_) => return Err(de::Error::custom(format_args!("`{}` is not a table", path.iter().chain(Some(section)).cloned().collect::<Vec<_>>().join(".")))),
+            None => (),
+        }
+    }
+    Value::Table(table).try_into()
+}
+
+/// Deserializes an `OsString` from a string in a config file
+pub fn deserialize_os_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<OsString>, D::Error> {
+    String::deserialize(deserializer).map(|value| Some(value.into()))
+}
+
+/// Value of a param with `values` that isn't one of them
+#[derive(Debug)]
+pub struct InvalidValue {
+    pub value: String,
+    pub allowed: &'static [&'static str],
+}
+
+impl InvalidValue {
+    pub fn new(value: &str, allowed: &'static [&'static str]) -> Self {
+        InvalidValue {
+            value: value.to_owned(),
+            allowed,
+        }
+    }
+}
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid value '{}', possible values: {}", self.value, self.allowed.join(", "))
+    }
+}
+
+/// Describes the type of a param with `values` for `ParseArg::describe_type()`
+pub fn describe_values<W: fmt::Write>(mut writer: W, allowed: &[&str]) -> fmt::Result {
+    write!(writer, "one of: {}", allowed.join(", "))
+}
+
+/// Deserializes a value of a param with `values` from a string in a config file
+pub fn deserialize_from_str<'de, T: ::std::str::FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> where T::Err: fmt::Display {
+    String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+}
+
+/// Displays mandatory params that weren't specified together with the arguments and environment
+/// variables specifying them
+pub fn display_missing_fields<I: IntoIterator<Item=(&'static str, &'static [&'static str])>>(f: &mut fmt::Formatter, fields: I) -> fmt::Result {
+    let fields = fields.into_iter().collect::<Vec<_>>();
+    if fields.len() == 1 {
+        write!(f, "Configuration parameter ")?;
+    } else {
+        write!(f, "Configuration parameters ")?;
+    }
+    for (i, (name, sources)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "'{}'", name)?;
+        if !sources.is_empty() {
+            write!(f, " ({})", sources.join(", "))?;
+        }
+    }
+    write!(f, " not specified.")
+}
+
+/// Displays the name of a param or switch together with the sources of its value
+fn display_field(f: &mut fmt::Formatter, name: &str, sources: &[Source]) -> fmt::Result {
+    write!(f, "'{}'", name)?;
+    for (i, source) in sources.iter().enumerate() {
+        write!(f, "{}{}", if i == 0 { " (from " } else { ", " }, source)?;
+    }
+    if !sources.is_empty() {
+        write!(f, ")")?;
+    }
+    Ok(())
+}
+
+/// Displays a param or switch that is set without another one it requires
+pub fn display_missing_requirement(f: &mut fmt::Formatter, name: &str, sources: &[Source], required: &str) -> fmt::Result {
+    write!(f, "Configuration parameter ")?;
+    display_field(f, name, sources)?;
+    write!(f, " requires '{}', which isn't set.", required)
+}
+
+/// Displays two params or switches that are set but conflict with each other
+pub fn display_conflict(f: &mut fmt::Formatter, name: &str, sources: &[Source], other: &str, other_sources: &[Source]) -> fmt::Result {
+    write!(f, "Configuration parameters ")?;
+    display_field(f, name, sources)?;
+    write!(f, " and ")?;
+    display_field(f, other, other_sources)?;
+    write!(f, " can't be used together.")
+}
+
+/// Displays a required group without any member set
+pub fn display_group_missing(f: &mut fmt::Formatter, group: &str, members: &[&str]) -> fmt::Result {
+    write!(f, "One of configuration parameters ")?;
+    for (i, member) in members.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "'{}'", member)?;
+    }
+    write!(f, " (group '{}') must be set.", group)
+}
+
+/// Displays the members of a group set together although at most one of them can be
+pub fn display_group_conflict(f: &mut fmt::Formatter, group: &str, members: &[(&str, Vec<Source>)]) -> fmt::Result {
+    write!(f, "At most one of configuration parameters in group '{}' can be set, but ", group)?;
+    for (i, (name, sources)) in members.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", if i + 1 == members.len() { " and " } else { ", " })?;
+        }
+        display_field(f, name, sources)?;
+    }
+    write!(f, " are.")
+}
+
+fn display_error<T: ParseArg>(f: &mut fmt::Formatter, source: fmt::Arguments, error: Option<&T::Error>) -> fmt::Result {
+    match error {
+        Some(error) => write!(f, "Failed to parse {}: {}.\n\nHint: the value must be ", source, error)?,
+        None => write!(f, "Failed to parse {}.\n\nHint: the value must be ", source)?,
+    }
+    T::describe_type(&mut *f)?;
+    write!(f, ".")
+}
+
+/// Displays the error of parsing the value of argument `arg`
+pub fn display_arg_error<T: ParseArg>(f: &mut fmt::Formatter, arg: &str, error: &T::Error) -> fmt::Result {
+    display_error::<T>(f, format_args!("argument '{}'", arg), Some(error))
+}
+
+/// Displays the error of parsing the value of argument `arg` of a secret param
+///
+/// The error isn't shown, because it could contain the value.
+pub fn display_secret_arg_error<T: ParseArg>(f: &mut fmt::Formatter, arg: &str) -> fmt::Result {
+    display_error::<T>(f, format_args!("argument '{}'", arg), None)
+}
+
+/// Displays the error of parsing the environment variable `name`
+pub fn display_env_error<T: ParseArg>(f: &mut fmt::Formatter, name: &str, error: &T::Error) -> fmt::Result {
+    display_error::<T>(f, format_args!("environment variable '{}'", name), Some(error))
+}
+
+/// Displays the error of parsing the environment variable `name` of a secret param
+///
+/// The error isn't shown, because it could contain the value.
+pub fn display_secret_env_error<T: ParseArg>(f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+    display_error::<T>(f, format_args!("environment variable '{}'", name), None)
+}
+
+/// Option, positional argument or subcommand listed by `display_help`
+pub struct HelpEntry {
+    /// E.g. `-p, --port PORT`
+    pub names: &'static str,
+    pub doc: &'static str,
+    pub default: Option<&'static str>,
+    pub env_var: Option<&'static str>,
+}
+
+/// Entries listed by `display_help` under a common heading
+pub struct HelpSection {
+    pub heading: &'static str,
+    pub entries: &'static [HelpEntry],
+}
+
+/// Indentation of the entries of the help
+const HELP_INDENT: usize = 2;
+/// Space between the names of the entries and their docs
+const HELP_GAP: usize = 4;
+/// The docs are moved below the names if less than this is left for them
+const HELP_MIN_DOC_WIDTH: usize = 30;
+
+/// Width of the terminal taken from `COLUMNS`, 80 by default
+fn help_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse().ok())
+        .filter(|width| *width >= 40)
+        .unwrap_or(80)
+}
+
+/// Whether the headings are highlighted, only when the help is printed to a terminal and the user
+/// didn't opt out by setting `NO_COLOR`
+fn help_color() -> bool {
+    use std::io::IsTerminal;
+
+    std::env::var_os("NO_COLOR").map_or(true, |value| value.is_empty()) && io::stdout().is_terminal()
+}
+
+fn write_help_heading(f: &mut fmt::Formatter, heading: &str, color: bool) -> fmt::Result {
+    if color {
+        write!(f, "\x1b[1;4m{}:\x1b[0m", heading)
+    } else {
+        write!(f, "{}:", heading)
+    }
+}
+
+/// Writes the words starting at `column`, continuation lines start at `indent`
+fn write_help_wrapped<'a, I: IntoIterator<Item=&'a str>>(f: &mut fmt::Formatter, words: I, mut column: usize, indent: usize, width: usize) -> fmt::Result {
+    // Words longer than the line are never split, they overflow it
+    for (i, word) in words.into_iter().enumerate() {
+        let len = word.chars().count();
+        if i > 0 && column + 1 + len > width {
+            write!(f, "\n{:indent$}", "", indent = indent)?;
+            column = indent;
+        } else if i > 0 {
+            write!(f, " ")?;
+            column += 1;
+        }
+        write!(f, "{}", word)?;
+        column += len;
+    }
+    Ok(())
+}
+
+/// Displays the help wrapped to the width of the terminal, with the docs of the entries aligned
+/// and followed by their default values and environment variables
+///
+/// The help starts with `about`, the summary and the description of the program, if present.
+pub fn display_help(f: &mut fmt::Formatter, program_name: &str, about: Option<&str>, usage: &str, sections: &[HelpSection], note: Option<&str>) -> fmt::Result {
+    let width = help_width();
+    let color = help_color();
+
+    if let Some(about) = about {
+        for line in about.lines() {
+            write_help_wrapped(f, line.split_whitespace(), 0, 0, width)?;
+            writeln!(f)?;
+        }
+        writeln!(f)?;
+    }
+    write_help_heading(f, "Usage", color)?;
+    write!(f, " ")?;
+    write_help_wrapped(f, program_name.split_whitespace().chain(usage.split_whitespace()), 7, 8, width)?;
+    for section in sections.iter().filter(|section| !section.entries.is_empty()) {
+        write!(f, "\n\n")?;
+        write_help_heading(f, section.heading, color)?;
+        let names_width = section.entries.iter().map(|entry| entry.names.chars().count()).max().unwrap_or(0);
+        let doc_column = if HELP_INDENT + names_width + HELP_GAP + HELP_MIN_DOC_WIDTH <= width {
+            HELP_INDENT + names_width + HELP_GAP
+        } else {
+            HELP_INDENT * 4
+        };
+        for entry in section.entries {
+            // The annotations are kept on a single line
+            let default = entry.default.map(|default| format!("[default: {}]", default));
+            let env_var = entry.env_var.map(|env_var| format!("[env: {}]", env_var));
+            let words = entry.doc.split_whitespace().chain(default.as_deref()).chain(env_var.as_deref()).collect::<Vec<_>>();
+            write!(f, "\n{:indent$}{}", "", entry.names, indent = HELP_INDENT)?;
+            if words.is_empty() {
+                continue;
+            }
+            let column = HELP_INDENT + entry.names.chars().count();
+            if column + HELP_GAP > doc_column {
+                write!(f, "\n{:indent$}", "", indent = doc_column)?;
+            } else {
+                write!(f, "{:gap$}", "", gap = doc_column - column)?;
+            }
+            write_help_wrapped(f, words, doc_column, doc_column, width)?;
+        }
+    }
+    if let Some(note) = note {
+        write!(f, "\n\n")?;
+        write_help_wrapped(f, note.split_whitespace(), 0, 0, width)?;
+    }
+    Ok(())
+}
+
+/// Values of other types that represent the same thing as `value`
+fn coercions(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Integer(value) => vec![Value::String(value.to_string())],
+        Value::Float(value) if value.fract() == 0.0 && (i64::MIN as f64..i64::MAX as f64).contains(value) => vec![Value::Integer(*value as i64)],
+        Value::String(value) => value.parse().map(Value::Integer).into_iter().chain(value.parse().map(Value::Float)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Deserializes the value of `name` from a config file, converting it if it has a wrong type
+///
+/// Integers are accepted as strings and vice versa and floats are accepted as integers if
+/// they have no fractional part. A warning is printed whenever a value is converted.
+pub fn deserialize_lenient<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D, name: &str) -> Result<Option<T>, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    let error = match T::deserialize(value.clone()) {
+        Ok(value) => return Ok(Some(value)),
+        Err(error) => error,
+    };
+    for coerced in coercions(&value) {
+        if let Ok(result) = T::deserialize(coerced.clone()) {
+            eprintln!("Warning: converted the value of '{}' from {} to {}", name, value, coerced);
+            return Ok(Some(result));
+        }
+    }
+    Err(de::Error::custom(error))
+}
+
+/// Like `deserialize_lenient()`, but for secret params, so the warning and the error only
+/// mention the types of the value
+pub fn deserialize_lenient_secret<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D, name: &str) -> Result<Option<T>, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    if let Ok(value) = T::deserialize(value.clone()) {
+        return Ok(Some(value));
+    }
+    for coerced in coercions(&value) {
+        if let Ok(result) = T::deserialize(coerced.clone()) {
+            eprintln!("Warning: converted the value of '{}' from {} to {}", name, value.type_str(), coerced.type_str());
+            return Ok(Some(result));
+        }
+    }
+    Err(de::Error::custom(SECRET_VALUE_ERROR))
+}
+
+/// Replaces the error of deserializing a secret param, which could contain the value
+const SECRET_VALUE_ERROR: &str = "invalid value of a secret parameter";
+
+/// Deserializes the value of a secret param from a config file, without the value in the error
+pub fn deserialize_secret<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer)
+        .map(Some)
+        .map_err(|_| de::Error::custom(SECRET_VALUE_ERROR))
+}
+
+/// Formats the value of a secret param for `Debug`
+pub struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// Returns the value of `--switch=value` if `arg` is `switch` followed by `=`
+pub fn switch_value(switch: &str, arg: &OsStr) -> Option<OsString> {
+    let arg = arg.to_str()?;
+    if arg.len() > switch.len() && arg.starts_with(switch) && arg.as_bytes()[switch.len()] == b'=' {
+        Some(arg[(switch.len() + 1)..].into())
+    } else {
+        None
+    }
+}
+
+/// Replaces the values of secret params in reports
+pub const REDACTED: &str = "<redacted>";
+
+/// Serializes the value as a string formatted like an argument, for types such as `Duration`
+/// whose own representation isn't accepted in config files
+pub struct SerializeAsArg<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ::ToArg + ?Sized> ::serde::Serialize for SerializeAsArg<'a, T> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_value(self.0))
+    }
+}
+
+/// Formats a value for humans
+pub fn format_value<T: ::ToArg + ?Sized>(value: &T) -> String {
+    value.to_arg().to_string_lossy().into_owned()
+}
+
+/// Formats multiple values of a param for humans, separated by spaces
+pub fn format_values<'a, T: 'a + ::ToArg, I: IntoIterator<Item=&'a T>>(values: I) -> String {
+    values.into_iter().map(format_value).collect::<Vec<_>>().join(" ")
+}
+
+/// Joins the formatted fields into a single line
+pub fn join_fields(fields: &[(&str, String)]) -> String {
+    fields.iter().map(|(name, value)| format!("{} = {}", name, value)).collect::<Vec<_>>().join(", ")
+}
+
+/// Formats the values of a config file, nested in the tables at `path`
+pub fn format_toml(mut table: ::toml::value::Table, path: &[&str]) -> Result<String, ::toml::ser::Error> {
+    for name in path.iter().rev() {
+        let mut parent = ::toml::value::Table::new();
+        parent.insert((*name).to_owned(), Value::Table(table));
+        table = parent;
+    }
+    ::toml::to_string(&Value::Table(table))
+}
+
+/// Whether `switch` is given in `args` before `--`, the first argument is the program path
+///
+/// The values of the options listed in `valued` are skipped, see `skip_values`.
+pub fn has_switch(args: &[OsString], switch: &str, valued: &[(&str, usize, usize)]) -> bool {
+    let mut args = args.iter().skip(1).take_while(|arg| *arg != "--").peekable();
+    while let Some(arg) = args.next() {
+        if arg == switch {
+            return true;
+        }
+        skip_values(arg, &mut args, valued);
+    }
+    false
+}
+
+/// `args` with the long options given before `--` normalized by `normalize`, the first argument
+/// is the program path
+///
+/// Arguments failing to normalize are kept as they are, parsing them reports the error.
+pub fn normalize_long_options<F, E>(args: &[OsString], mut normalize: F) -> Vec<OsString> where F: FnMut(OsString) -> Result<OsString, E> {
+    let mut separated = false;
+    args.iter()
+        .enumerate()
+        .map(|(i, arg)| {
+            separated |= i > 0 && arg == "--";
+            if separated || i == 0 {
+                arg.clone()
+            } else {
+                normalize(arg.clone()).unwrap_or_else(|_| arg.clone())
+            }
+        })
+        .collect()
+}
+
+/// Removes the arguments given before `--` from `args` which are `switch` once normalized by
+/// `normalize`
+pub fn remove_normalized_switch<F, E>(args: Vec<OsString>, switch: &str, normalize: F) -> Vec<OsString> where F: FnMut(OsString) -> Result<OsString, E> {
+    let normalized = normalize_long_options(&args, normalize);
+    let mut separated = false;
+    args.into_iter()
+        .zip(normalized)
+        .enumerate()
+        .filter(|(i, (_, normalized))| {
+            separated |= *i > 0 && normalized == "--";
+            separated || *i == 0 || normalized != switch
+        })
+        .map(|(_, (arg, _))| arg)
+        .collect()
+}
+
+/// Removes `switch` given before `--` from `args`
+pub fn remove_switch(args: Vec<OsString>, switch: &str) -> Vec<OsString> {
+    let mut separated = false;
+    args.into_iter()
+        .enumerate()
+        .filter(|(i, arg)| {
+            separated |= *i > 0 && arg == "--";
+            separated || *i == 0 || arg != switch
+        })
+        .map(|(_, arg)| arg)
+        .collect()
+}
+
+/// Name of the binary executed as `program_path`, shown in the usage
+pub fn program_name(program_path: &Path) -> String {
+    program_path.file_name().unwrap_or(program_path.as_os_str()).to_string_lossy().into_owned()
+}
+
+/// Formats `--name=value`
+pub fn arg_with_value<T: ::ToArg + ?Sized>(name: &str, value: &T) -> OsString {
+    let mut arg = OsString::from(name);
+    arg.push("=");
+    arg.push(value.to_arg());
+    arg
+}
+
+/// Parses the value of a switch, returning it back if it's not one of the allowed spellings
+pub fn parse_switch(value: OsString, true_values: &[&str], false_values: &[&str]) -> Result<bool, OsString> {
+    match value.to_str() {
+        Some(value) if true_values.contains(&value) => Ok(true),
+        Some(value) if false_values.contains(&value) => Ok(false),
+        _ => Err(value),
+    }
+}
+
+struct AllowedValues<'a> {
+    true_values: &'a [&'a str],
+    false_values: &'a [&'a str],
+}
+
+impl<'a> fmt::Display for AllowedValues<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, value) in self.false_values.iter().chain(self.true_values).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays the error of parsing the value of switch `name`
+pub fn display_switch_error(f: &mut fmt::Formatter, name: &str, value: &OsStr, true_values: &[&str], false_values: &[&str]) -> fmt::Result {
+    write!(f, "Invalid value '{}' for '{}'.\n\nHint: the allowed values are {}.", value.to_string_lossy(), name, AllowedValues { true_values, false_values })
+}
+
+/// Deserializes a switch from a config file accepting both booleans and the allowed spellings
+pub fn deserialize_switch<'de, D: Deserializer<'de>>(deserializer: D, true_values: &[&str], false_values: &[&str]) -> Result<Option<bool>, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::Boolean(value) => Ok(Some(value)),
+        Value::String(value) => parse_switch(value.into(), true_values, false_values)
+            .map(Some)
+            .map_err(|value| de::Error::custom(format_args!("invalid value {:?}, the allowed values are true, false, {}", value, AllowedValues { true_values, false_values }))),
+        Value::Integer(value) => parse_switch(value.to_string().into(), true_values, false_values)
+            .map(Some)
+            .map_err(|value| de::Error::custom(format_args!("invalid value {}, the allowed values are true, false, {}", value.to_string_lossy(), AllowedValues { true_values, false_values }))),
+        value => Err(de::Error::custom(format_args!("invalid type: {}, expected a boolean", value.type_str()))),
+    }
+}
+
+/// Where the value of a param or switch came from
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    EnvVar(&'static str),
+    Argument(&'static str),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "config file {}", path.display()),
+            Source::EnvVar(name) => write!(f, "environment variable {}", name),
+            Source::Argument(arg) => write!(f, "argument {}", arg),
+        }
+    }
+}
+
+/// Value that was set by one source and then overridden by another one with a higher priority
+#[derive(Debug, Clone)]
+pub struct Override {
+    pub field: &'static str,
+    pub old: Source,
+    pub new: Source,
+}
+
+impl fmt::Display for Override {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the value of '{}' from {} was overridden by {}", self.field, self.old, self.new)
+    }
+}
+
+/// Sources of the values of a config, recorded to explain which values were overridden
+#[derive(Debug, Default)]
+pub struct Sources {
+    sources: Vec<(&'static str, Source)>,
+    /// Sources of values combined by `merge_fn` or counted by count switches
+    combined: Vec<(&'static str, Source)>,
+    overrides: Vec<Override>,
+    /// Whether the overrides are reported
+    pub explain: bool,
+}
+
+impl Sources {
+    /// Records that `field` was set from `source`, possibly overriding a previous value
+    pub fn set(&mut self, field: &'static str, source: Source) {
+        match self.sources.iter_mut().find(|(name, _)| *name == field) {
+            Some((_, old)) => {
+                let old = std::mem::replace(old, source.clone());
+                self.overrides.push(Override { field, old, new: source });
+            },
+            None => self.sources.push((field, source)),
+        }
+    }
+
+    /// Records the source of `value`, if any, and returns the value
+    pub fn track<T>(&mut self, field: &'static str, value: Option<T>, source: Source) -> Option<T> {
+        if value.is_some() {
+            self.set(field, source);
+        }
+        value
+    }
+
+    /// Records that `field` combines a value from `source` with the previous ones
+    pub fn add(&mut self, field: &'static str, source: Source) {
+        if !self.combined.iter().any(|(name, old)| *name == field && *old == source) {
+            self.combined.push((field, source));
+        }
+    }
+
+    /// Records `source` as contributing to the combined `value`, if any, and returns the value
+    pub fn track_combined<T>(&mut self, field: &'static str, value: Option<T>, source: Source) -> Option<T> {
+        if value.is_some() {
+            self.add(field, source);
+        }
+        value
+    }
+
+    /// Sources the value of `field` comes from, empty if it wasn't set
+    pub fn of(&self, field: &str) -> Vec<&Source> {
+        match self.sources.iter().find(|(name, _)| *name == field) {
+            Some((_, source)) => vec![source],
+            None => self.combined.iter().filter(|(name, _)| *name == field).map(|(_, source)| source).collect(),
+        }
+    }
+
+    /// Records the sources of a config merged in, its values override the current ones
+    pub fn merge_in(&mut self, other: Sources) {
+        self.overrides.extend(other.overrides);
+        for (field, source) in other.combined {
+            self.add(field, source);
+        }
+        for (field, source) in other.sources {
+            self.set(field, source);
+        }
+        self.explain |= other.explain;
+    }
+
+    /// Overridden values if explaining was requested, none otherwise
+    pub fn overrides(&self) -> Vec<Override> {
+        if self.explain {
+            self.overrides.clone()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Param or switch reported to the audit callback
+#[derive(Debug)]
+pub struct AuditEntry<'a> {
+    pub name: &'static str,
+    /// Sources the value comes from, empty if it's the default
+    pub sources: Vec<&'a Source>,
+    /// `None` if the optional param is unset, redacted for secret params
+    pub value: Option<String>,
+}
+
+/// Callback registered by `Config::set_audit_callback()`
+pub type AuditCallback = ::std::sync::Arc<dyn Fn(&AuditEntry) + Send + Sync>;
+
+/// How to treat a config file other users can modify
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InsecureFile {
+    Warn,
+    Error,
+}
+
+/// Checks performed when reading a config file
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ConfigFileChecks {
+    /// Handling of files other users can modify, allowed if `None`
+    pub insecure: Option<InsecureFile>,
+    /// Maximum size of the file in bytes
+    pub max_size: Option<u64>,
+    /// Maximum length of a string in bytes, including escape sequences
+    pub max_string_length: Option<usize>,
+    /// Maximum nesting depth of tables and arrays
+    pub max_depth: Option<usize>,
+    /// Files larger than this many bytes are memory-mapped instead of read, see `mmap_threshold()`
+    pub mmap_threshold: Option<u64>,
+}
+
+/// Handling of `ConfigFileChecks::insecure`, requires the `insecure-files` feature
+#[cfg(feature = "insecure-files")]
+pub const fn insecure_files(handling: InsecureFile) -> Option<InsecureFile> {
+    Some(handling)
+}
+
+/// Threshold of `ConfigFileChecks::mmap_threshold`, requires the `mmap` feature
+///
+/// A mapped file must not be truncated while it's being parsed, the program would be killed by
+/// `SIGBUS` on Unix.
+#[cfg(feature = "mmap")]
+pub const fn mmap_threshold(bytes: u64) -> Option<u64> {
+    Some(bytes)
+}
+
+/// Content of a config file, either read into memory or memory-mapped
+#[derive(Debug)]
+pub enum ConfigContent {
+    /// The file was read into a buffer
+    Read(Vec<u8>),
+    /// The file is larger than `ConfigFileChecks::mmap_threshold`
+    #[cfg(feature = "mmap")]
+    Mapped(::memmap2::Mmap),
+}
+
+impl ::std::ops::Deref for ConfigContent {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            ConfigContent::Read(content) => content,
+            #[cfg(feature = "mmap")]
+            ConfigContent::Mapped(map) => map,
+        }
+    }
+}
+
+/// Reads a config file, performing the `checks`
+///
+/// Insecure files are those writable by all users or owned by another user than the current one
+/// or root, the same applies to the directories containing them, except that directories with the
+/// sticky bit, such as `/tmp`, may be writable by all users.
+pub fn read_config_file(path: &Path, checks: ConfigFileChecks) -> io::Result<ConfigContent> {
+    let file = File::open(path)?;
+    let problem = match checks.insecure {
+        Some(_) => insecurity(path, &file.metadata()?)?,
+        None => None,
+    };
+    if let (Some(insecure), Some(problem)) = (checks.insecure, problem) {
+        match insecure {
+            InsecureFile::Warn => eprintln!("Warning: the configuration file {} {}", path.display(), problem),
+            InsecureFile::Error => return Err(io::Error::new(io::ErrorKind::PermissionDenied, format!("the file {}", problem))),
+        }
+    }
+    #[cfg(feature = "mmap")]
+    {
+        if let Some(threshold) = checks.mmap_threshold {
+            let len = file.metadata()?.len();
+            if len > threshold {
+                return map_content(&file, len, checks);
+            }
+        }
+    }
+    read_content(file, checks.max_size, checks).map(ConfigContent::Read)
+}
+
+/// Maps the content of a config file of length `len`, checking it like `read_content()`
+#[cfg(feature = "mmap")]
+fn map_content(file: &File, len: u64, checks: ConfigFileChecks) -> io::Result<ConfigContent> {
+    if let Some(max_size) = checks.max_size.filter(|max_size| len > *max_size) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, format!("the file is larger than {} bytes", max_size)));
+    }
+    // Safety: the mapping is only read while parsing, a file truncated meanwhile is documented to
+    // kill the program
+    let map = unsafe { ::memmap2::Mmap::map(file)? };
+    if checks.max_string_length.is_some() || checks.max_depth.is_some() {
+        check_limits(&map, checks.max_string_length, checks.max_depth).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    }
+    Ok(ConfigContent::Mapped(map))
+}
+
+/// Reads a config file from `reader`, e.g. the standard input, performing the `checks` except the
+/// one of insecure files
+pub fn read_config_reader<R: Read>(reader: R, checks: ConfigFileChecks) -> io::Result<Vec<u8>> {
+    read_content(reader, checks.max_size, checks)
+}
+
+/// Reads the content of a config file, which can't be larger than `max_size`, checking the limits
+/// of its strings and nesting
+fn read_content<R: Read>(reader: R, max_size: Option<u64>, checks: ConfigFileChecks) -> io::Result<Vec<u8>> {
+    let mut content = Vec::new();
+    match max_size {
+        // Reading one more byte detects files that are too large without reading them whole
+        Some(max_size) => {
+            reader.take(max_size.saturating_add(1)).read_to_end(&mut content)?;
+            if content.len() as u64 > max_size {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, format!("the file is larger than {} bytes", max_size)));
+            }
+        },
+        None => {
+            let mut reader = reader;
+            reader.read_to_end(&mut content)?;
+        },
+    }
+    if checks.max_string_length.is_some() || checks.max_depth.is_some() {
+        check_limits(&content, checks.max_string_length, checks.max_depth).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    }
+    Ok(content)
+}
+
+/// The maximum size of a remote config file in bytes unless `max_config_file_size` is set
+pub const DEFAULT_REMOTE_MAX_SIZE: u64 = 1 << 20;
+
+/// Whether `path` is an `http://` or `https://` URL of a remote config file
+pub fn is_remote(path: &Path) -> bool {
+    match path.to_str() {
+        Some(path) => path.starts_with("https://") || path.starts_with("http://"),
+        None => false,
+    }
+}
+
+/// Reads a config file like `read_config_file()` or fetches it if `path` is an `http://` or
+/// `https://` URL
+///
+/// Plain `http://` URLs are refused unless `allow_http` is true. The remote file must be received
+/// within `timeout` and can't be larger than `checks.max_size`, or `DEFAULT_REMOTE_MAX_SIZE` if it
+/// isn't set. Unlike a missing local file, a remote file the server doesn't have is an error,
+/// since the server is expected to provide it.
+#[cfg(feature = "remote")]
+pub fn read_config_source(path: &Path, checks: ConfigFileChecks, timeout: ::std::time::Duration, allow_http: bool) -> io::Result<ConfigContent> {
+    let url = match path.to_str() {
+        Some(url) if is_remote(path) => url,
+        _ => return read_config_file(path, checks),
+    };
+    if !allow_http && !url.starts_with("https://") {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, "plain HTTP isn't allowed, use an https:// URL"));
+    }
+    let agent = ::ureq::AgentBuilder::new().timeout(timeout).build();
+    let response = match agent.get(url).call() {
+        Ok(response) => response,
+        Err(::ureq::Error::Status(status, response)) => return Err(io::Error::other(format!("the server responded {} {}", status, response.status_text()))),
+        Err(::ureq::Error::Transport(error)) => return Err(io::Error::other(error.to_string())),
+    };
+    read_content(response.into_reader(), Some(checks.max_size.unwrap_or(DEFAULT_REMOTE_MAX_SIZE)), checks).map(ConfigContent::Read)
+}
+
+/// Checks string lengths and nesting depth of a TOML document without parsing it, so that the
+/// parser doesn't run out of memory or stack on malicious input
+///
+/// The depth is the number of tables and arrays containing a value, not counting the root table,
+/// and only approximate for dotted keys inside inline tables.
+fn check_limits(content: &[u8], max_string_length: Option<usize>, max_depth: Option<usize>) -> Result<(), String> {
+    let max_string_length = max_string_length.unwrap_or(usize::MAX);
+    let max_depth = max_depth.unwrap_or(usize::MAX);
+    let too_deep = |line| Err(format!("line {}: tables or arrays are nested deeper than {} levels", line, max_depth));
+
+    let mut line = 1;
+    let mut pos = 0;
+    // Depth of the current table
+    let mut table_depth = 0;
+    // Depth of the current key-value pair or `None` when a key or table header is expected
+    let mut value_depth = None;
+    let mut key_segments = 1;
+    let mut brackets = 0;
+    while pos < content.len() {
+        let byte = content[pos];
+        match byte {
+            b'"' | b'\'' => {
+                let multiline = content[pos..].starts_with(if byte == b'"' { b"\"\"\"" } else { b"'''" });
+                let delimiter_len = if multiline { 3 } else { 1 };
+                let start = pos + delimiter_len;
+                let mut end = start;
+                loop {
+                    match content.get(end) {
+                        // Syntax errors are reported by the parser
+                        None => return Ok(()),
+                        Some(b'\\') if byte == b'"' => end += 2,
+                        Some(b'\n') => {
+                            line += 1;
+                            end += 1;
+                        },
+                        Some(&other) if other == byte && (!multiline || content[end..].starts_with(&content[pos..start])) => break,
+                        Some(_) => end += 1,
+                    }
+                }
+                // Up to two quotes may precede the closing delimiter of a multiline string
+                if multiline {
+                    for _ in 0..2 {
+                        if content.get(end + 3) != Some(&byte) {
+                            break;
+                        }
+                        end += 1;
+                    }
+                }
+                if end - start > max_string_length {
+                    return Err(format!("line {}: string is longer than {} bytes", line, max_string_length));
+                }
+                pos = end + delimiter_len;
+                continue;
+            },
+            b'#' => {
+                while pos < content.len() && content[pos] != b'\n' {
+                    pos += 1;
+                }
+                continue;
+            },
+            b'\n' => {
+                line += 1;
+                if brackets == 0 {
+                    value_depth = None;
+                    key_segments = 1;
+                }
+            },
+            b'[' if value_depth.is_none() => {
+                // Table header, an array of tables adds a level
+                let array = content.get(pos + 1) == Some(&b'[');
+                let mut segments = 1;
+                while pos < content.len() && content[pos] != b'\n' && content[pos] != b']' {
+                    if content[pos] == b'.' {
+                        segments += 1;
+                    }
+                    // Quoted keys are rare in headers, dots in them only overestimate the depth
+                    pos += 1;
+                }
+                table_depth = segments + array as usize;
+                if table_depth > max_depth {
+                    return too_deep(line);
+                }
+                while pos < content.len() && content[pos] != b'\n' {
+                    pos += 1;
+                }
+                continue;
+            },
+            b'.' if value_depth.is_none() => key_segments += 1,
+            b'=' if value_depth.is_none() => {
+                let depth = table_depth + key_segments - 1;
+                if depth > max_depth {
+                    return too_deep(line);
+                }
+                value_depth = Some(depth);
+            },
+            b'[' | b'{' => {
+                brackets += 1;
+                if value_depth.unwrap_or(0) + brackets > max_depth {
+                    return too_deep(line);
+                }
+            },
+            b']' | b'}' => brackets = brackets.saturating_sub(1),
+            _ => (),
+        }
+        pos += 1;
+    }
+    Ok(())
+}
+
+#[cfg(all(unix, feature = "insecure-files"))]
+fn insecurity(path: &Path, metadata: &fs::Metadata) -> io::Result<Option<String>> {
+    use std::os::unix::fs::MetadataExt;
+
+    // geteuid has no preconditions and never fails
+    let uid = unsafe { ::libc::geteuid() };
+    let problem = |metadata: &fs::Metadata, is_dir: bool| {
+        // Only owners can remove or rename entries of sticky directories
+        if metadata.mode() & 0o002 != 0 && !(is_dir && metadata.mode() & 0o1000 != 0) {
+            Some("writable by all users")
+        } else if metadata.uid() != 0 && metadata.uid() != uid {
+            Some("owned by another user")
+        } else {
+            None
+        }
+    };
+
+    if let Some(problem) = problem(metadata, false) {
+        return Ok(Some(format!("is {}", problem)));
+    }
+    // Whoever can write to a directory can replace the file in it
+    for dir in fs::canonicalize(path)?.ancestors().skip(1) {
+        if let Some(problem) = problem(&fs::metadata(dir)?, true) {
+            return Ok(Some(format!("is in the directory {}, which is {}", dir.display(), problem)));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(not(all(unix, feature = "insecure-files")))]
+fn insecurity(_path: &Path, _metadata: &fs::Metadata) -> io::Result<Option<String>> {
+    Ok(None)
+}
+
+#[cfg(unix)]
+fn bytes_to_os_string(bytes: Vec<u8>) -> io::Result<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+
+    Ok(OsString::from_vec(bytes))
+}
+
+#[cfg(not(unix))]
+fn bytes_to_os_string(bytes: Vec<u8>) -> io::Result<OsString> {
+    String::from_utf8(bytes)
+        .map(Into::into)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "the file isn't valid UTF-8"))
+}
+
+/// Formats `value` as a TOML basic string
+fn toml_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04X}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "::serde")]
+struct TomlValue<T> {
+    value: T,
+}
+
+/// Checks that `T` deserializes from the TOML `value` the same way it would from a config file
+fn check_toml_value<T: de::DeserializeOwned>(value: &str) -> Result<(), String> {
+    ::toml::from_str::<TomlValue<T>>(&format!("value = {}", value))
+        .map(|toml| drop(toml.value))
+        .map_err(|error| error.to_string())
+}
+
+/// Returns the TOML representation of `input` deserializing into `T`, trying it verbatim first
+/// so that numbers and booleans aren't quoted
+fn toml_value<T: de::DeserializeOwned>(input: &str) -> Result<String, String> {
+    check_toml_value::<T>(input)
+        .map(|_| input.to_owned())
+        .or_else(|_| {
+            let quoted = toml_string(input);
+            check_toml_value::<T>(&quoted).map(|_| quoted)
+        })
+}
+
+/// Asks the user for the values of params and switches, producing a config file
+pub struct Wizard<R: io::BufRead, W: io::Write> {
+    input: R,
+    output: W,
+    file: String,
+}
+
+impl<R: io::BufRead, W: io::Write> Wizard<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Wizard {
+            input,
+            output,
+            file: String::new(),
+        }
+    }
+
+    /// Asks until `parse` accepts the answer, returns `None` if the answer is empty and the value
+    /// isn't mandatory
+    fn ask<F: Fn(&str) -> Result<String, String>>(&mut self, name: &str, doc: Option<&str>, hint: &str, mandatory: bool, parse: F) -> io::Result<Option<String>> {
+        writeln!(self.output)?;
+        if let Some(doc) = doc {
+            writeln!(self.output, "{}", doc)?;
+        }
+        loop {
+            write!(self.output, "{} [{}]: ", name, hint)?;
+            self.output.flush()?;
+
+            let mut answer = String::new();
+            if self.input.read_line(&mut answer)? == 0 {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "the input ended before the setup was finished"));
+            }
+            let answer = answer.trim();
+            if answer.is_empty() {
+                if mandatory {
+                    writeln!(self.output, "The value is required.")?;
+                    continue;
+                }
+                return Ok(None);
+            }
+            match parse(answer) {
+                Ok(value) => return Ok(Some(value)),
+                Err(error) => writeln!(self.output, "Invalid value: {}", error)?,
+            }
+        }
+    }
+
+    /// Puts the following values into the table `name`
+    pub fn table(&mut self, name: &str) {
+        self.file.push('[');
+        self.file.push_str(name);
+        self.file.push_str("]\n");
+    }
+
+    fn store(&mut self, name: &str, doc: Option<&str>, value: Option<String>) {
+        if let Some(value) = value {
+            if let Some(doc) = doc {
+                for line in doc.lines() {
+                    self.file.push_str("# ");
+                    self.file.push_str(line);
+                    self.file.push('\n');
+                }
+            }
+            self.file.push_str(name);
+            self.file.push_str(" = ");
+            self.file.push_str(&value);
+            self.file.push('\n');
+        }
+    }
+
+    /// Asks for a param taking a single value of type `T`
+    pub fn param<T: de::DeserializeOwned>(&mut self, name: &str, doc: Option<&str>, default: Option<&str>, mandatory: bool) -> io::Result<()> {
+        let hint = match default {
+            Some(default) => format!("default: {}", default),
+            None if mandatory => "required".to_owned(),
+            None => "optional".to_owned(),
+        };
+        let value = self.ask(name, doc, &hint, mandatory, toml_value::<T>)?;
+        self.store(name, doc, value);
+        Ok(())
+    }
+
+    /// Asks for a param taking multiple values of type `T` separated by spaces, stored in `C`
+    pub fn param_values<T: de::DeserializeOwned, C: de::DeserializeOwned>(&mut self, name: &str, doc: Option<&str>, default: Option<&str>, mandatory: bool) -> io::Result<()> {
+        let hint = match default {
+            Some(default) => format!("space-separated, default: {}", default),
+            None if mandatory => "space-separated, required".to_owned(),
+            None => "space-separated, optional".to_owned(),
+        };
+        let value = self.ask(name, doc, &hint, mandatory, |input| {
+            let values = input.split_whitespace().map(toml_value::<T>).collect::<Result<Vec<_>, _>>()?;
+            let array = format!("[{}]", values.join(", "));
+            check_toml_value::<C>(&array).map(|_| array)
+        })?;
+        self.store(name, doc, value);
+        Ok(())
+    }
+
+    /// Asks for the entries of a map param with values of type `T` given as space-separated
+    /// `key=value`, stored in `C`
+    pub fn param_map<T: de::DeserializeOwned, C: de::DeserializeOwned>(&mut self, name: &str, doc: Option<&str>, default: Option<&str>, mandatory: bool) -> io::Result<()> {
+        let hint = match default {
+            Some(default) => format!("space-separated key=value, default: {}", default),
+            None if mandatory => "space-separated key=value, required".to_owned(),
+            None => "space-separated key=value, optional".to_owned(),
+        };
+        let value = self.ask(name, doc, &hint, mandatory, |input| {
+            let entries = input
+                .split_whitespace()
+                .map(|entry| {
+                    let (key, value) = entry.split_once('=').ok_or_else(|| format!("'{}' isn't key=value", entry))?;
+                    toml_value::<T>(value).map(|value| format!("{} = {}", toml_string(key), value))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let table = format!("{{ {} }}", entries.join(", "));
+            check_toml_value::<C>(&table).map(|_| table)
+        })?;
+        self.store(name, doc, value);
+        Ok(())
+    }
+
+    /// Asks for a switch turned on or off
+    pub fn switch(&mut self, name: &str, doc: Option<&str>, default: bool, true_values: &[&str], false_values: &[&str]) -> io::Result<()> {
+        let hint = format!("default: {}", default);
+        let value = self.ask(name, doc, &hint, false, |input| {
+            parse_switch(input.into(), true_values, false_values)
+                .map(|value| value.to_string())
+                .map_err(|_| format!("the allowed values are {}", AllowedValues { true_values, false_values }))
+        })?;
+        self.store(name, doc, value);
+        Ok(())
+    }
+
+    /// Asks for the number of occurrences of a count switch
+    pub fn count_switch(&mut self, name: &str, doc: Option<&str>) -> io::Result<()> {
+        let value = self.ask(name, doc, "default: 0", false, |input| {
+            input.parse::<u32>()
+                .map(|value| value.to_string())
+                .map_err(|error| error.to_string())
+        })?;
+        self.store(name, doc, value);
+        Ok(())
+    }
+
+    /// Writes the answers into a new config file, failing if it already exists
+    pub fn write<P: AsRef<Path>>(mut self, path: P) -> io::Result<()> {
+        use std::io::Write;
+
+        let path = path.as_ref();
+        let mut file = fs::OpenOptions::new().write(true).create_new(true).open(path)?;
+        file.write_all(self.file.as_bytes())?;
+        file.sync_all()?;
+        writeln!(self.output)?;
+        writeln!(self.output, "The configuration was written to {}", path.display())
+    }
+}
@@ -96,6 +96,24 @@
 pub extern crate serde;
 pub extern crate toml;
 pub extern crate parse_arg;
+#[cfg(feature = "shared")]
+pub extern crate arc_swap;
+#[cfg(feature = "test-helpers")]
+pub extern crate arbitrary;
+#[cfg(feature = "regex")]
+pub extern crate regex;
+#[cfg(feature = "humantime")]
+pub extern crate humantime;
+#[cfg(feature = "json")]
+extern crate serde_json;
+#[cfg(feature = "remote")]
+extern crate ureq;
+#[cfg(feature = "watch")]
+extern crate notify;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(all(unix, feature = "insecure-files"))]
+extern crate libc;
 
 #[allow(unused_imports)]
 #[macro_use]
@@ -103,6 +121,63 @@ extern crate serde_derive;
 #[doc(hidden)]
 pub use serde_derive::*;
 
+#[doc(hidden)]
+pub mod helpers;
+mod to_arg;
+
+pub use to_arg::{ToArg, ToArgDisplay};
+
+/// Format of a config file
+///
+/// Only available with the `json` feature, which is required by specifications setting
+/// `json_config = true`.
+#[cfg(feature = "json")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+#[cfg(feature = "json")]
+impl ConfigFormat {
+    /// Format of the file at `path`, `.json` files are JSON, all other files are TOML
+    pub fn of(path: &::std::path::Path) -> Self {
+        match path.extension().and_then(::std::ffi::OsStr::to_str) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Toml,
+        }
+    }
+}
+
+/// Watches config files until it's dropped, returned by `Config::watch()`
+///
+/// Only available with the `watch` feature, which is required by specifications setting
+/// `watch_config = true`.
+#[cfg(feature = "watch")]
+pub struct ConfigWatcher {
+    _watcher: ::notify::RecommendedWatcher,
+}
+
+/// Expands to the items only if the `test-helpers` feature is enabled
+///
+/// The feature is checked in this crate, so the generated code doesn't depend on the features of
+/// the crate including it.
+#[cfg(feature = "test-helpers")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! if_test_helpers {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "test-helpers"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! if_test_helpers {
+    ($($item:item)*) => {};
+}
+
 #[macro_export]
 macro_rules! include_config {
     () => {
@@ -123,4 +198,18 @@ macro_rules! include_config {
 
         use config::prelude::*;
     };
+    (pub) => {
+        pub mod config {
+            #![allow(unused)]
+
+            include!(concat!(env!("OUT_DIR"), "/configure_me_config.rs"));
+        }
+    };
+    (pub $binary:literal) => {
+        pub mod config {
+            #![allow(unused)]
+
+            include!(concat!(env!("OUT_DIR"), "/", $binary, "_configure_me_config.rs"));
+        }
+    };
 }
@@ -0,0 +1,68 @@
+//! Conversion of values back to arguments, the inverse of `ParseArg`
+
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Converts a value to a string that parses back to the same value
+///
+/// This is used by the code exporting the configuration, e.g. `Config::to_env_vars()`, so the
+/// types of exported params have to implement it. Types implementing `Display` compatibly with
+/// `ParseArg` can simply implement `ToArgDisplay` instead.
+pub trait ToArg {
+    fn to_arg(&self) -> OsString;
+}
+
+/// Implements `ToArg` using `Display`
+pub trait ToArgDisplay: fmt::Display {}
+
+impl<T: ToArgDisplay + ?Sized> ToArg for T {
+    fn to_arg(&self) -> OsString {
+        self.to_string().into()
+    }
+}
+
+macro_rules! impl_to_arg_display {
+    ($($type:ty),*) => {
+        $(
+            impl ToArgDisplay for $type {}
+        )*
+    }
+}
+
+impl_to_arg_display!(bool, char, String, str, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_to_arg_display!(::std::num::NonZeroU8, ::std::num::NonZeroU16, ::std::num::NonZeroU32, ::std::num::NonZeroU64, ::std::num::NonZeroU128, ::std::num::NonZeroUsize);
+impl_to_arg_display!(::std::net::IpAddr, ::std::net::Ipv4Addr, ::std::net::Ipv6Addr, ::std::net::SocketAddr, ::std::net::SocketAddrV4, ::std::net::SocketAddrV6);
+
+impl ToArg for OsStr {
+    fn to_arg(&self) -> OsString {
+        self.to_owned()
+    }
+}
+
+impl ToArg for OsString {
+    fn to_arg(&self) -> OsString {
+        self.clone()
+    }
+}
+
+/// Formats the duration like `1h 30m`, which `type = "duration"` parses back
+#[cfg(feature = "humantime")]
+impl ToArg for ::std::time::Duration {
+    fn to_arg(&self) -> OsString {
+        ::humantime::format_duration(*self).to_string().into()
+    }
+}
+
+impl ToArg for Path {
+    fn to_arg(&self) -> OsString {
+        self.as_os_str().to_owned()
+    }
+}
+
+impl ToArg for PathBuf {
+    fn to_arg(&self) -> OsString {
+        self.as_os_str().to_owned()
+    }
+}
+
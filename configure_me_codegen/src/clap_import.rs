@@ -0,0 +1,289 @@
+//! Conversion of `clap` command definitions into specifications
+//!
+//! `clap` can't serialize its `Command`, so the command is described by `ClapCommand`, which
+//! mirrors the getters of `clap::Command` and `clap::Arg` and can be deserialized from JSON. The
+//! parts that have no equivalent in `configure_me` are left out with a note in the specification.
+
+use std::fmt::{self, Write};
+use std::io::Read;
+use {Error, ErrorData};
+
+/// Description of a `clap::Command`
+#[derive(Deserialize)]
+pub struct ClapCommand {
+    /// `Command::get_name()`, `None` if the name of the package is used
+    #[serde(default)]
+    pub name: Option<String>,
+    /// `Command::get_about()`
+    #[serde(default)]
+    pub about: Option<String>,
+    /// `Command::get_long_about()`
+    #[serde(default)]
+    pub long_about: Option<String>,
+    /// `Command::get_arguments()`
+    #[serde(default)]
+    pub args: Vec<ClapArg>,
+}
+
+/// Description of a `clap::Arg`
+#[derive(Deserialize)]
+pub struct ClapArg {
+    /// `Arg::get_id()`
+    pub id: String,
+    /// `Arg::get_long()`
+    #[serde(default)]
+    pub long: Option<String>,
+    /// `Arg::get_short()`
+    #[serde(default)]
+    pub short: Option<char>,
+    /// `Arg::get_help()`
+    #[serde(default)]
+    pub help: Option<String>,
+    /// `Arg::is_required_set()`
+    #[serde(default)]
+    pub required: bool,
+    /// `Arg::get_action()`
+    #[serde(default)]
+    pub action: ClapAction,
+    /// `Arg::get_num_args()`, `None` if it's not set
+    #[serde(default)]
+    pub num_args: Option<ClapNumArgs>,
+    /// `Arg::get_default_values()`
+    #[serde(default)]
+    pub default_values: Vec<String>,
+    /// `Arg::get_env()`
+    #[serde(default)]
+    pub env: Option<String>,
+    /// Rust type of the value, `clap` can't tell it, so it's `String` unless specified
+    #[serde(default, rename = "type")]
+    pub ty: Option<String>,
+}
+
+/// `clap::ArgAction`
+#[derive(Deserialize, Copy, Clone, Eq, PartialEq, Default)]
+pub enum ClapAction {
+    #[default]
+    Set,
+    Append,
+    SetTrue,
+    SetFalse,
+    Count,
+    Help,
+    HelpShort,
+    HelpLong,
+    Version,
+}
+
+/// `clap::builder::ValueRange`
+#[derive(Deserialize, Copy, Clone)]
+pub struct ClapNumArgs {
+    /// `ValueRange::min_values()`
+    pub min: usize,
+    /// `ValueRange::max_values()`
+    pub max: usize,
+}
+
+/// Types whose defaults are written as they are
+const LITERAL_TYPES: &[&str] = &["bool", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32", "f64"];
+
+fn quote(string: &str) -> String {
+    ::toml::Value::String(string.to_owned()).to_string()
+}
+
+/// Rust expression creating the default value of the type from the `clap` default
+fn default_expr(ty: &str, value: &str) -> String {
+    if LITERAL_TYPES.contains(&ty) {
+        value.to_owned()
+    } else if ty == "String" {
+        format!("{:?}.to_owned()", value)
+    } else if ty.ends_with("PathBuf") || ty.ends_with("OsString") {
+        format!("{:?}.into()", value)
+    } else {
+        format!("{:?}.parse().expect(\"invalid default value\")", value)
+    }
+}
+
+/// Prefix of environment variables, `None` if the variable is the upper-case name itself
+fn env_prefix(env: &str, name: &str) -> Option<Option<String>> {
+    let name = name.to_ascii_uppercase();
+    if env == name {
+        Some(None)
+    } else if env.len() > name.len() + 1 && env.ends_with(&name) && env[..(env.len() - name.len())].ends_with('_') {
+        Some(Some(env[..(env.len() - name.len() - 1)].to_owned()))
+    } else {
+        None
+    }
+}
+
+/// Name of the param or switch, derived from the long option so that the option doesn't change
+///
+/// Inverted switches are named without the `no-` prefix, which configure_me adds.
+fn arg_name(arg: &ClapArg) -> String {
+    let long = arg.long.as_ref().unwrap_or(&arg.id);
+    let long = match arg.action {
+        ClapAction::SetFalse if long.starts_with("no-") => &long[3..],
+        _ => long,
+    };
+    long.replace('-', "_")
+}
+
+fn write_arg<W: Write>(mut spec: W, arg: &ClapArg, prefix: &Option<Option<String>>) -> fmt::Result {
+    let name = arg_name(arg);
+    let mut notes = Vec::new();
+    let long = match arg.action {
+        ClapAction::SetFalse => format!("--no-{}", name.replace('_', "-")),
+        _ => format!("--{}", name.replace('_', "-")),
+    };
+    if arg.long.as_ref().map(|clap_long| format!("--{}", clap_long)) != Some(long.clone()) {
+        notes.push(format!("`{}` is given as `{}`", arg.id, long));
+    }
+    let env_var = match &arg.env {
+        Some(env) if env_prefix(env, &name).as_ref() == prefix.as_ref() => true,
+        Some(env) => {
+            notes.push(format!("the environment variable {} doesn't match the others, it's not read", env));
+            false
+        },
+        None => false,
+    };
+    let switch = matches!(arg.action, ClapAction::SetTrue | ClapAction::SetFalse | ClapAction::Count);
+    let abbr = match (arg.action, arg.short) {
+        (ClapAction::SetFalse, Some(short)) => {
+            notes.push(format!("inverted switches can't have short options, `-{}` is dropped", short));
+            None
+        },
+        (_, short) => short,
+    };
+    if arg.action == ClapAction::Append {
+        notes.push("clap appends the values of repeated arguments, only the last one is kept unless `merge_fn` is set".to_owned());
+    }
+    let nargs = match arg.num_args {
+        Some(num_args) if !switch && num_args.max > 1 => {
+            if num_args.min == 0 {
+                notes.push("the values can't be omitted, at least one is required".to_owned());
+            }
+            // clap uses `usize::MAX` for unlimited values, which doesn't fit into TOML
+            Some((num_args.min.max(1), num_args.max.min(u32::MAX as usize)))
+        },
+        Some(ClapNumArgs { min: 0, .. }) if !switch => {
+            notes.push("the value can't be omitted".to_owned());
+            None
+        },
+        _ => None,
+    };
+    let ty = arg.ty.as_ref().map_or("String", AsRef::as_ref);
+    let default = match (arg.default_values.as_slice(), nargs) {
+        ([], _) => None,
+        ([value], None) => Some(default_expr(ty, value)),
+        (values, Some((min, max))) if min == max && values.len() == min => Some(format!("[{}]", values.iter().map(|value| default_expr(ty, value)).collect::<Vec<_>>().join(", "))),
+        (_, Some((min, max))) if min == max => {
+            notes.push(format!("the default values are dropped, exactly {} are required", min));
+            None
+        },
+        (values, Some(_)) => Some(format!("vec![{}]", values.iter().map(|value| default_expr(ty, value)).collect::<Vec<_>>().join(", "))),
+        (_, None) => {
+            notes.push("multiple default values of a single value are dropped".to_owned());
+            None
+        },
+    };
+
+    for note in notes {
+        writeln!(spec, "# Note: {}", note)?;
+    }
+    if switch {
+        writeln!(spec, "[[switch]]")?;
+        writeln!(spec, "name = {}", quote(&name))?;
+        if arg.action == ClapAction::Count {
+            writeln!(spec, "count = true")?;
+        }
+        if arg.action == ClapAction::SetFalse {
+            writeln!(spec, "default = true")?;
+        }
+    } else {
+        writeln!(spec, "[[param]]")?;
+        writeln!(spec, "name = {}", quote(&name))?;
+        writeln!(spec, "type = {}", quote(ty))?;
+        match nargs {
+            Some((min, max)) if min == max => writeln!(spec, "nargs = {}", min)?,
+            Some((min, max)) => writeln!(spec, "nargs = {{ min = {}, max = {} }}", min, max)?,
+            None => (),
+        }
+        match &default {
+            Some(default) => writeln!(spec, "default = {}", quote(default))?,
+            None => writeln!(spec, "optional = {}", !arg.required)?,
+        }
+    }
+    if let Some(abbr) = abbr {
+        writeln!(spec, "abbr = {}", quote(&abbr.to_string()))?;
+    }
+    if let Some(help) = &arg.help {
+        writeln!(spec, "doc = {}", quote(help))?;
+    }
+    if env_var {
+        writeln!(spec, "env_var = true")?;
+    }
+    writeln!(spec)
+}
+
+/// Converts the command into an equivalent specification.
+///
+/// The specification is validated before it's returned. Arguments that can't be converted are
+/// skipped and the differences are explained by `# Note:` comments.
+pub fn spec_from_command(command: &ClapCommand) -> Result<String, Error> {
+    let mut spec = String::new();
+    // Writing to String never fails
+    write_spec(&mut spec, command).unwrap();
+    ::validate_spec(spec.as_bytes())?;
+    Ok(spec)
+}
+
+fn write_spec<W: Write>(mut spec: W, command: &ClapCommand) -> fmt::Result {
+    match &command.name {
+        Some(name) => writeln!(spec, "# Converted from the clap command `{}`, review the notes", name)?,
+        None => writeln!(spec, "# Converted from a clap command, review the notes")?,
+    }
+    writeln!(spec)?;
+
+    // configure_me derives the names of environment variables from a common prefix
+    let prefix = command.args
+        .iter()
+        .find_map(|arg| arg.env.as_ref().and_then(|env| env_prefix(env, &arg_name(arg))));
+    writeln!(spec, "[general]")?;
+    if let Some(name) = &command.name {
+        writeln!(spec, "name = {}", quote(name))?;
+    }
+    if let Some(about) = &command.about {
+        writeln!(spec, "summary = {}", quote(about))?;
+    }
+    if let Some(long_about) = &command.long_about {
+        writeln!(spec, "doc = {}", quote(long_about))?;
+    }
+    if let Some(Some(prefix)) = &prefix {
+        writeln!(spec, "env_prefix = {}", quote(prefix))?;
+        writeln!(spec)?;
+        writeln!(spec, "[defaults]")?;
+        writeln!(spec, "env_vars = false")?;
+    }
+    writeln!(spec)?;
+
+    for arg in &command.args {
+        match arg.action {
+            ClapAction::Help | ClapAction::HelpShort | ClapAction::HelpLong => continue,
+            ClapAction::Version => {
+                writeln!(spec, "# Note: `{}` prints the version in clap, configure_me has no equivalent", arg.id)?;
+                writeln!(spec)?;
+            },
+            _ if arg.long.is_none() && arg.short.is_none() => {
+                writeln!(spec, "# Note: the positional argument `{}` is returned among the remaining arguments", arg.id)?;
+                writeln!(spec)?;
+            },
+            _ => write_arg(&mut spec, arg, &prefix)?,
+        }
+    }
+    Ok(())
+}
+
+/// Converts the command described in JSON into an equivalent specification, see `spec_from_command()`
+pub fn spec_from_json<R: Read>(json: R) -> Result<String, Error> {
+    let command = ::serde_json::from_reader(json).map_err(ErrorData::ClapJson)?;
+    spec_from_command(&command)
+}
@@ -1,5 +1,6 @@
+use std::borrow::Cow;
 use std::fmt::{self, Write};
-use ::config::{Config, Optionality};
+use ::config::{Config, DuplicateArg, EmptyEnvVar, Ident, InsecureFile, ListMerge, Nargs, Optionality};
 use ::unicode_segmentation::UnicodeSegmentation;
 
 mod visitor {
@@ -9,7 +10,7 @@ mod visitor {
         fn visit_write<W: fmt::Write>(&self, output: W) -> fmt::Result;
     }
 
-    impl<'a, T, U> VisitWrite<T> for &'a U where U: VisitWrite<T> {
+    impl<T, U> VisitWrite<T> for &U where U: VisitWrite<T> {
         fn visit_write<W: fmt::Write>(&self, output: W) -> fmt::Result {
             (*self).visit_write(output)
         }
@@ -49,17 +50,21 @@ empty!(::config::General, RawConfigDecl);
 
 impl VisitWrite<visitor::RawConfigDecl> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        writeln!(output, "        {}: Option<{}>,", self.name.as_snake_case(), self.ty)
+        writeln!(output, "        {}: Option<{}>,", self.name.as_snake_case(), self.field_type())
+    }
+}
+
+fn raw_switch_type(switch: &::config::Switch) -> &'static str {
+    if switch.is_count() {
+        "u32"
+    } else {
+        "bool"
     }
 }
 
 impl VisitWrite<visitor::RawConfigDecl> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.is_count() {
-            writeln!(output, "        {}: Option<u32>,", self.name.as_snake_case())
-        } else {
-            writeln!(output, "        {}: Option<bool>,", self.name.as_snake_case())
-        }
+        writeln!(output, "        {}: Option<{}>,", self.name.as_snake_case(), raw_switch_type(self))
     }
 }
 
@@ -68,7 +73,7 @@ empty!(::config::General, ArgParseErrorDecl);
 impl VisitWrite<visitor::ArgParseErrorDecl> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         if self.argument {
-            writeln!(output, "    Field{}(<{} as ::configure_me::parse_arg::ParseArg>::Error),", self.name.as_pascal_case(), self.ty)
+            writeln!(output, "    Field{}(<{} as ::configure_me::parse_arg::ParseArg>::Error),", self.name.as_pascal_case(), arg_type(self, &self.parse_type()))
         } else {
             Ok(())
         }
@@ -77,12 +82,21 @@ impl VisitWrite<visitor::ArgParseErrorDecl> for ::config::Param {
 
 empty!(::config::Switch, ArgParseErrorDecl);
 
+impl VisitWrite<visitor::ArgParseErrorDecl> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        writeln!(output, "    Field{}(<{} as ::configure_me::parse_arg::ParseArg>::Error),", self.name.as_pascal_case(), self.parse_type())
+    }
+}
+
 empty!(::config::General, EnvParseErrorDecl);
 
 impl VisitWrite<visitor::EnvParseErrorDecl> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.env_var {
-            writeln!(output, "    Field{}(<{} as ::configure_me::parse_arg::ParseArg>::Error),", self.name.as_pascal_case(), self.ty)
+        // The entries of maps are read from multiple variables
+        if self.env_var && self.map {
+            writeln!(output, "    Field{}(String, <{} as ::configure_me::parse_arg::ParseArg>::Error),", self.name.as_pascal_case(), self.parse_type())
+        } else if self.env_var {
+            writeln!(output, "    Field{}(<{} as ::configure_me::parse_arg::ParseArg>::Error),", self.name.as_pascal_case(), self.parse_type())
         } else {
             Ok(())
         }
@@ -106,15 +120,17 @@ impl VisitWrite<visitor::EnvParseErrorDecl> for ::config::Switch {
 impl VisitWrite<visitor::ConfigFinal> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         match self.optionality {
-            Optionality::Optional => writeln!(output, "    pub {}: Option<{}>,", self.name.as_snake_case(), self.convert_into),
-            _ => writeln!(output, "    pub {}: {},", self.name.as_snake_case(), self.convert_into),
+            Optionality::Optional => writeln!(output, "    pub {}: Option<{}>,", self.key(), self.convert_into),
+            _ => writeln!(output, "    pub {}: {},", self.key(), self.convert_into),
         }
     }
 }
 
 impl VisitWrite<visitor::ConfigFinal> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.is_count() {
+        if let Some(map_to) = &self.map_to {
+            writeln!(output, "    pub {}: {},", self.name.as_snake_case(), map_to)
+        } else if self.is_count() {
             writeln!(output, "    pub {}: u32,", self.name.as_snake_case())
         } else {
             writeln!(output, "    pub {}: bool,", self.name.as_snake_case())
@@ -122,11 +138,21 @@ impl VisitWrite<visitor::ConfigFinal> for ::config::Switch {
     }
 }
 
+impl VisitWrite<visitor::ConfigFinal> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        if self.optional && !self.variadic {
+            writeln!(output, "    pub {}: Option<{}>,", self.name.as_snake_case(), self.field_type())
+        } else {
+            writeln!(output, "    pub {}: {},", self.name.as_snake_case(), self.field_type())
+        }
+    }
+}
+
 impl VisitWrite<visitor::Validate> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         match self.optionality {
             Optionality::Optional => writeln!(output, "            let {} = self.{};", self.name.as_snake_case(), self.name.as_snake_case()),
-            Optionality::Mandatory => writeln!(output, "            let {} = self.{}.ok_or(ValidationError::MissingField(\"{}\"))?;", self.name.as_snake_case(), self.name.as_snake_case(), self.name.as_snake_case()),
+            Optionality::Mandatory => writeln!(output, "            let {} = self.{};", self.name.as_snake_case(), self.name.as_snake_case()),
             Optionality::DefaultValue(ref val) => writeln!(output, "            let {} = self.{}.unwrap_or_else(|| {{ {} }});", self.name.as_snake_case(), self.name.as_snake_case(), val),
         }
     }
@@ -134,18 +160,27 @@ impl VisitWrite<visitor::Validate> for ::config::Param {
 
 empty!(::config::Switch, Validate);
 
+impl VisitWrite<visitor::Validate> for ::config::Positional {
+    fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        writeln!(output, "            let {} = self.{};", self.name.as_snake_case(), self.name.as_snake_case())
+    }
+}
+
 impl VisitWrite<visitor::ConstructConfig> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if let Optionality::Optional = self.optionality {
-            writeln!(output, "                {}: {}.map(Into::into),", self.name.as_snake_case(), self.name.as_snake_case())
-        } else {
-            writeln!(output, "                {}: {}.into(),", self.name.as_snake_case(), self.name.as_snake_case())
+        match self.optionality {
+            Optionality::Optional => writeln!(output, "                {}: {}.map(Into::into),", self.key(), self.name.as_snake_case()),
+            Optionality::Mandatory | Optionality::DefaultValue(_) => writeln!(output, "                {}: {}.into(),", self.key(), self.name.as_snake_case()),
         }
     }
 }
 
 impl VisitWrite<visitor::ConstructConfig> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
+        if self.map_to.is_some() {
+            let count = format!("self.{}.unwrap_or(0)", self.name.as_snake_case());
+            return writeln!(output, "                {}: {},", self.name.as_snake_case(), map_count(self, &count));
+        }
         let default_value = if self.is_inverted() {
             "true"
         } else if self.is_count() {
@@ -157,24 +192,54 @@ impl VisitWrite<visitor::ConstructConfig> for ::config::Switch {
     }
 }
 
-impl VisitWrite<visitor::MergeIn> for ::config::Param {
+impl VisitWrite<visitor::ConstructConfig> for ::config::Positional {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if let Some(merge_fn) = &self.merge_fn {
-            writeln!(output, "            if let Some({}) = other.{} {{", self.name.as_snake_case(), self.name.as_snake_case())?;
-            writeln!(output, "                if let Some({}_old) = &mut self.{} {{", self.name.as_snake_case(), self.name.as_snake_case())?;
-            writeln!(output, "                    {}({}_old, {});", merge_fn, self.name.as_snake_case(), self.name.as_snake_case())?;
-            writeln!(output, "                }} else {{")?;
-            writeln!(output, "                    self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case())?;
-            writeln!(output, "                }}")?;
-            writeln!(output, "            }}")
-        } else {
-            writeln!(output, "            if other.{}.is_some() {{", self.name.as_snake_case())?;
-            writeln!(output, "                self.{} = other.{};", self.name.as_snake_case(), self.name.as_snake_case())?;
-            writeln!(output, "            }}")
+        match (self.optional, self.variadic) {
+            (true, true) => writeln!(output, "                {}: {}.unwrap_or_default(),", self.name.as_snake_case(), self.name.as_snake_case()),
+            (true, false) | (false, _) => writeln!(output, "                {},", self.name.as_snake_case()),
         }
     }
 }
 
+/// Expression of the value a count switch with `map_to` maps the `count` to
+fn map_count(switch: &::config::Switch, count: &str) -> String {
+    let (last, values) = switch.map_values.split_last().expect("map_to requires values");
+    if values.is_empty() {
+        return last.clone();
+    }
+    let arms = values
+        .iter()
+        .enumerate()
+        .map(|(count, value)| format!("{} => {}, ", count, value))
+        .collect::<String>();
+    format!("match {} {{ {}_ => {} }}", count, arms, last)
+}
+
+/// Expression of the value of a switch of the generated `Config`, which is the count again for
+/// count switches with `map_to`
+fn switch_value(switch: &::config::Switch, config: &str) -> String {
+    let field = format!("{}.{}", config, switch.name.as_snake_case());
+    if switch.map_to.is_some() {
+        format!("::configure_me::helpers::mapped_count(&{}, &[{}])", field, switch.map_values.join(", "))
+    } else {
+        field
+    }
+}
+
+/// Writes a call of the runtime helper merging `value` into the field of the param
+fn write_merge<W: fmt::Write>(param: &::config::Param, mut output: W, indent: &str, value: &str) -> fmt::Result {
+    match &param.merge_fn {
+        Some(merge_fn) => writeln!(output, "{}::configure_me::helpers::merge_with(&mut self.{}, {}, {});", indent, param.name.as_snake_case(), value, merge_fn),
+        None => writeln!(output, "{}::configure_me::helpers::merge(&mut self.{}, {});", indent, param.name.as_snake_case(), value),
+    }
+}
+
+impl VisitWrite<visitor::MergeIn> for ::config::Param {
+    fn visit_write<W: fmt::Write>(&self, output: W) -> fmt::Result {
+        write_merge(self, output, "            ", &format!("other.{}", self.name.as_snake_case()))
+    }
+}
+
 impl VisitWrite<visitor::MergeIn> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
         writeln!(output, "            if other.{}.is_some() {{", self.name.as_snake_case())?;
@@ -183,41 +248,171 @@ impl VisitWrite<visitor::MergeIn> for ::config::Switch {
     }
 }
 
+/// Writes `let <name> = <values>;` where the first value is `first` and the remaining values of a
+/// param taking multiple values are taken from `iter`.
+fn write_param_values<W: fmt::Write>(param: &::config::Param, mut output: W, indent: &str, option: &str, first: &str) -> fmt::Result {
+    use ::config::Nargs;
+
+    let name = param.name.as_snake_case();
+    let next = if param.is_wrapped() {
+        format!("<{} as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument(\"{}\"))?).map_err(ArgParseError::Field{})?.0", param.parse_type(), option, param.name.as_pascal_case())
+    } else {
+        format!("::configure_me::parse_arg::ParseArg::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument(\"{}\"))?).map_err(ArgParseError::Field{})?", option, param.name.as_pascal_case())
+    };
+    match param.nargs {
+        None if param.map => {
+            let value = if param.is_wrapped() { "value.0" } else { "value" };
+            writeln!(output, "{}let ::configure_me::helpers::KeyValue(key, value) = {};", indent, first)?;
+            writeln!(output, "{}let {} = ::std::iter::once((key, {})).collect::<{}>();", indent, name, value, param.field_type())
+        },
+        None if param.multiple.is_some() => writeln!(output, "{}let {} = vec![{}];", indent, name, first),
+        None => writeln!(output, "{}let {} = {};", indent, name, first),
+        Some(Nargs::Fixed(count)) => {
+            writeln!(output, "{}let {} = [", indent, name)?;
+            writeln!(output, "{}    {},", indent, first)?;
+            for _ in 1..count {
+                writeln!(output, "{}    {},", indent, next)?;
+            }
+            writeln!(output, "{}];", indent)
+        },
+        Some(Nargs::Bounded { min, max }) => {
+            writeln!(output, "{}let mut {} = vec![{}];", indent, name, first)?;
+            writeln!(output, "{}while {}.len() < {} {{", indent, name, max)?;
+            // The first value is always present
+            let enough = if min > 1 { format!("{}.len() >= {} && ", name, min) } else { String::new() };
+            writeln!(output, "{}    if {}iter.peek().map_or(true, |next| next.to_str().map_or(false, |next| next.starts_with('-'))) {{", indent, enough)?;
+            writeln!(output, "{}        break;", indent)?;
+            writeln!(output, "{}    }}", indent)?;
+            writeln!(output, "{}    {}.push({});", indent, name, next)?;
+            writeln!(output, "{}}}", indent)
+        },
+    }
+}
+
+/// Type parsed from a single argument, `helpers::KeyValue` for maps
+fn arg_type(param: &::config::Param, ty: &str) -> String {
+    if param.map {
+        format!("::configure_me::helpers::KeyValue<{}>", ty)
+    } else {
+        ty.to_owned()
+    }
+}
+
+/// Turbofish and field access used to parse integers as `helpers::Int` so that they're accepted
+/// in hexadecimal, octal or binary notation too, durations as `helpers::HumanDuration` and sizes as `helpers::ByteSize`, the entries of maps are always parsed as
+/// `helpers::KeyValue` and their values are accessed by `write_param_values()`
+fn int_parsing(param: &::config::Param, rest_params: &str) -> (String, &'static str) {
+    if param.map {
+        (format!("::<{}{}>", arg_type(param, &param.parse_type()), rest_params), "")
+    } else if param.is_wrapped() {
+        (format!("::<{}{}>", param.parse_type(), rest_params), ".0")
+    } else {
+        (String::new(), "")
+    }
+}
+
+/// Writes `assignment` of a value given on the command line as `option`, honoring the
+/// `duplicate_arg` policy tracked by the `seen_<name>` flag
+fn write_arg_assignment<W: fmt::Write>(mut output: W, indent: &str, name: &str, option: &str, duplicate_arg: DuplicateArg, explain_overrides: bool, assignment: &str) -> fmt::Result {
+    let inner_indent = match duplicate_arg {
+        DuplicateArg::Last => indent.to_owned(),
+        DuplicateArg::First => {
+            writeln!(output, "{}if !seen_{} {{", indent, name)?;
+            writeln!(output, "{}    seen_{} = true;", indent, name)?;
+            format!("{}    ", indent)
+        },
+        DuplicateArg::Error => {
+            writeln!(output, "{}if seen_{} {{", indent, name)?;
+            writeln!(output, "{}    return Err(ArgParseError::DuplicateArgument(\"{}\").into());", indent, option)?;
+            writeln!(output, "{}}}", indent)?;
+            writeln!(output, "{}seen_{} = true;", indent, name)?;
+            indent.to_owned()
+        },
+    };
+    if explain_overrides {
+        writeln!(output, "{}self._sources.set(\"{}\", ::configure_me::helpers::Source::Argument(\"{}\"));", inner_indent, name, option)?;
+    }
+    writeln!(output, "{}{}", inner_indent, assignment)?;
+    if duplicate_arg == DuplicateArg::First {
+        writeln!(output, "{}}}", indent)?;
+    }
+    Ok(())
+}
+
+/// Writes the recording of `option` as a source of the combined value if it's audited
+fn write_audit_combined<W: fmt::Write>(mut output: W, indent: &str, name: &str, option: &str, audit_combined: bool) -> fmt::Result {
+    if audit_combined {
+        writeln!(output, "{}self._sources.add(\"{}\", ::configure_me::helpers::Source::Argument(\"{}\"));", indent, name, option)?;
+    }
+    Ok(())
+}
+
+/// Writes the assignment of the values of a param with `list_merge = "replace"` given on the
+/// command line as `option`, the first occurrence replaces the values from other sources and the
+/// others are appended
+fn write_replace_values<W: fmt::Write>(param: &::config::Param, mut output: W, indent: &str, option: &str) -> fmt::Result {
+    let name = param.name.as_snake_case();
+    if param.explain_overrides {
+        writeln!(output, "{}self._sources.set(\"{}\", ::configure_me::helpers::Source::Argument(\"{}\"));", indent, name, option)?;
+    }
+    writeln!(output, "{}if seen_{} {{", indent, name)?;
+    writeln!(output, "{}    self.{}.get_or_insert_with(Vec::new).extend({});", indent, name, name)?;
+    writeln!(output, "{}}} else {{", indent)?;
+    writeln!(output, "{}    seen_{} = true;", indent, name)?;
+    writeln!(output, "{}    self.{} = Some({});", indent, name, name)?;
+    writeln!(output, "{}}}", indent)
+}
+
 impl VisitWrite<visitor::MergeArgs> for ::config::Param {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.argument {
-            writeln!(output, "                }} else if let Some(value) = ::configure_me::parse_arg::match_arg(\"--{}\", &arg, &mut iter) {{", self.name.as_hypenated())?;
-            writeln!(output, "                    let {} = value.map_err(|err| err.map_or(ArgParseError::MissingArgument(\"--{}\"), ArgParseError::Field{}))?;", self.name.as_snake_case(), self.name.as_hypenated(), self.name.as_pascal_case())?;
+        if !self.argument {
+            return Ok(());
+        }
+        let (turbofish, field) = int_parsing(self, ", _, _");
+        // Aliases are handled the same way, errors name the option that was given
+        for option in ::std::iter::once(param_long(self)).chain(param_aliases(self)) {
+            let first = format!("value.map_err(|err| err.map_or(ArgParseError::MissingArgument(\"{}\"), ArgParseError::Field{}))?{}", option, self.name.as_pascal_case(), field);
+            writeln!(output, "                }} else if let Some(value) = ::configure_me::parse_arg::match_arg{}(\"{}\", &arg, &mut iter) {{", turbofish, option)?;
+            write_param_values(self, &mut output, "                    ", &option, &first)?;
             writeln!(output)?;
-            if let Some(merge_fn) = &self.merge_fn {
-                writeln!(output, "                    if let Some({}_old) = &mut self.{} {{", self.name.as_snake_case(), self.name.as_snake_case())?;
-                writeln!(output, "                        {}({}_old, {});", merge_fn, self.name.as_snake_case(), self.name.as_snake_case())?;
-                writeln!(output, "                    }} else {{")?;
-                writeln!(output, "                        self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case())?;
-                writeln!(output, "                    }}")
+            if self.merge_fn.is_some() {
+                write_audit_combined(&mut output, "                    ", self.name.as_snake_case(), &option, self.audit_combined)?;
+                write_merge(self, &mut output, "                    ", &format!("Some({})", self.name.as_snake_case()))?;
+            } else if self.multiple == Some(ListMerge::Replace) {
+                write_replace_values(self, &mut output, "                    ", &option)?;
             } else {
-                writeln!(output, "                    self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case())
+                let assignment = format!("self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case());
+                write_arg_assignment(&mut output, "                    ", self.name.as_snake_case(), &option, self.duplicate_arg, self.explain_overrides, &assignment)?;
             }
-        } else {
-            Ok(())
         }
+        Ok(())
     }
 }
 
 impl VisitWrite<visitor::MergeArgs> for ::config::Switch {
     fn visit_write<W: fmt::Write>(&self, mut output: W) -> fmt::Result {
-        if self.is_inverted() {
-            writeln!(output, "                }} else if arg == *\"--no-{}\" {{", self.name.as_hypenated())?;
-            writeln!(output, "                    self.{} = Some(false);", self.name.as_snake_case())
-        } else {
-            writeln!(output, "                }} else if arg == *\"--{}\" {{", self.name.as_hypenated())?;
-
+        let name = self.name.as_snake_case();
+        for long in ::std::iter::once(switch_long(self)).chain(switch_aliases(self)) {
+            writeln!(output, "                }} else if arg == *\"{}\" {{", long)?;
             if self.is_count() {
-                writeln!(output, "                    *(self.{}.get_or_insert(0)) += 1;", self.name.as_snake_case())
-            } else {
-                writeln!(output, "                    self.{} = Some(true);", self.name.as_snake_case())
+                write_audit_combined(&mut output, "                    ", name, &long, self.audit_combined)?;
+                writeln!(output, "                    *(self.{}.get_or_insert(0)) += 1;", name)?;
+                continue;
             }
+            let assignment = format!("self.{} = Some({});", name, !self.is_inverted());
+            write_arg_assignment(&mut output, "                    ", name, &long, self.duplicate_arg, self.explain_overrides, &assignment)?;
+
+            writeln!(output, "                }} else if let Some(value) = ::configure_me::helpers::switch_value(\"{}\", &arg) {{", long)?;
+            writeln!(output, "                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue(\"{}\", value))?;", long)?;
+            let assignment = format!("self.{} = Some({}value);", name, if self.is_inverted() { "!" } else { "" });
+            write_arg_assignment(&mut output, "                    ", name, &long, self.duplicate_arg, self.explain_overrides, &assignment)?;
         }
+        for long in switch_negations(self) {
+            writeln!(output, "                }} else if arg == *\"{}\" {{", long)?;
+            let assignment = format!("self.{} = Some(false);", name);
+            write_arg_assignment(&mut output, "                    ", name, &long, self.duplicate_arg, self.explain_overrides, &assignment)?;
+        }
+        Ok(())
     }
 }
 
@@ -226,7 +421,20 @@ impl VisitWrite<visitor::MergeArgs> for ::config::General {
         if let Some(conf_file) = &self.conf_file_param {
             writeln!(output, "                }} else if let Some(value) = ::configure_me::parse_arg::match_arg(\"--{}\", &arg, &mut iter) {{", conf_file.as_hypenated())?;
             writeln!(output, "                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument(\"--{}\"), |never| match never {{}}))?;", conf_file.as_hypenated())?;
-            writeln!(output, "                    let mut config = Config::load(file_path)?;")?;
+            writeln!(output, "                    let loaded = if file_path.as_os_str() == \"-\" {{")?;
+            writeln!(output, "                        self.load_stdin(&file_path)")?;
+            writeln!(output, "                    }} else {{")?;
+            writeln!(output, "                        self.load_once(&file_path)")?;
+            writeln!(output, "                    }};")?;
+            let steps = load_steps(self);
+            if let Some((last, steps)) = steps.split_last() {
+                for step in steps {
+                    writeln!(output, "                    let loaded = self.{}(&file_path, loaded);", step)?;
+                }
+                writeln!(output, "                    let mut config = self.{}(&file_path, loaded)?;", last)?;
+            } else {
+                writeln!(output, "                    let mut config = loaded?;")?;
+            }
             writeln!(output, "                    self.merge_in(config);")?;
         }
 
@@ -239,16 +447,50 @@ impl VisitWrite<visitor::MergeArgs> for ::config::General {
             writeln!(output, "                        Err(err) => return Err(ArgParseError::OpenConfDir(err, dir_path).into()),")?;
             writeln!(output, "                    }};")?;
             writeln!(output)?;
+            writeln!(output, "                    let mut files = Vec::new();")?;
             writeln!(output, "                    for file in dir {{")?;
             writeln!(output, "                        let file = match file {{")?;
             writeln!(output, "                            Ok(file) => file,")?;
             writeln!(output, "                            Err(err) => return Err(ArgParseError::ReadConfDir(err, dir_path).into()),")?;
             writeln!(output, "                        }};")?;
+            writeln!(output, "                        if !file.file_type().map_or(false, |file_type| file_type.is_dir()) {{")?;
+            writeln!(output, "                            files.push(file.path());")?;
+            writeln!(output, "                        }}")?;
+            writeln!(output, "                    }}")?;
             writeln!(output)?;
-            writeln!(output, "                        let mut config = Config::load(file.path())?;")?;
+            writeln!(output, "                    // Later files override earlier ones")?;
+            writeln!(output, "                    files.sort();")?;
+            writeln!(output, "                    for file_path in files {{")?;
+            if self.config_includes {
+                writeln!(output, "                        let loaded = self.load_once(&file_path);")?;
+                writeln!(output, "                        let mut config = self.with_includes(&file_path, loaded)?;")?;
+            } else {
+                writeln!(output, "                        let mut config = self.load_once(file_path)?;")?;
+            }
             writeln!(output, "                        self.merge_in(config);")?;
             writeln!(output, "                    }}")?;
         }
+
+        if let Some(profile) = &self.profile_param {
+            // The profile was already selected before loading config files
+            writeln!(output, "                }} else if let Some(value) = ::configure_me::parse_arg::match_arg(\"--{}\", &arg, &mut iter) {{", profile.as_hypenated())?;
+            writeln!(output, "                    let _: ::std::ffi::OsString = value.map_err(|err| err.map_or(ArgParseError::MissingArgument(\"--{}\"), |never| match never {{}}))?;", profile.as_hypenated())?;
+        }
+
+        if let Some(explain) = &self.explain_config_switch {
+            writeln!(output, "                }} else if arg == *\"--{}\" {{", explain.as_hypenated())?;
+            writeln!(output, "                    self._sources.explain = true;")?;
+        }
+
+        if let Some(check) = &self.check_config_switch {
+            // Handled before loading the configuration
+            writeln!(output, "                }} else if arg == *\"--{}\" {{", check.as_hypenated())?;
+        }
+
+        if let Some(dump) = &self.dump_config_switch {
+            // Handled before loading the configuration
+            writeln!(output, "                }} else if arg == *\"--{}\" {{", dump.as_hypenated())?;
+        }
         Ok(())
     }
 }
@@ -258,7 +500,25 @@ impl VisitWrite<visitor::MergeShort> for ::config::Param {
         // TODO remove invalid case (false, Some(_))
         if let (true, Some(short) )= (self.argument, self.abbr) {
             writeln!(output, "                        }} else if short == '{}' {{", short)?;
-            writeln!(output, "                            self.{} = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument(\"-{}\"), ArgParseError::Field{}))?);", &self.name.as_snake_case(), short, self.name.as_pascal_case())?;
+            write_audit_combined(&mut output, "                            ", self.name.as_snake_case(), &format!("-{}", short), self.audit_combined)?;
+            let (turbofish, field) = int_parsing(self, ", _");
+            if self.has_multiple_values() {
+                let option = format!("-{}", short);
+                let first = format!("shorts.parse_remaining{}(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument(\"{}\"), ArgParseError::Field{}))?{}", turbofish, option, self.name.as_pascal_case(), field);
+                write_param_values(self, &mut output, "                            ", &option, &first)?;
+                match self.multiple {
+                    Some(ListMerge::Append) => write_merge(self, &mut output, "                            ", &format!("Some({})", self.name.as_snake_case()))?,
+                    None if self.map => write_merge(self, &mut output, "                            ", &format!("Some({})", self.name.as_snake_case()))?,
+                    Some(ListMerge::Replace) => write_replace_values(self, &mut output, "                            ", &option)?,
+                    None => {
+                        let assignment = format!("self.{} = Some({});", self.name.as_snake_case(), self.name.as_snake_case());
+                        write_arg_assignment(&mut output, "                            ", self.name.as_snake_case(), &option, self.duplicate_arg, self.explain_overrides, &assignment)?;
+                    },
+                }
+            } else {
+                let assignment = format!("self.{} = Some(shorts.parse_remaining{}(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument(\"-{}\"), ArgParseError::Field{}))?{});", &self.name.as_snake_case(), turbofish, short, self.name.as_pascal_case(), field);
+                write_arg_assignment(&mut output, "                            ", self.name.as_snake_case(), &format!("-{}", short), self.duplicate_arg, self.explain_overrides, &assignment)?;
+            }
             writeln!(output, "                            break;")
         } else {
             Ok(())
@@ -274,9 +534,11 @@ impl VisitWrite<visitor::MergeShort> for ::config::Switch {
             writeln!(output, "                        }} else if short == '{}' {{", abbr)?;
 
             if *count {
+                write_audit_combined(&mut output, "                            ", self.name.as_snake_case(), &format!("-{}", abbr), self.audit_combined)?;
                 writeln!(output, "                            *(self.{}.get_or_insert(0)) += 1;", self.name.as_snake_case())
             } else {
-                writeln!(output, "                            self.{} = Some(true);", self.name.as_snake_case())
+                let assignment = format!("self.{} = Some(true);", self.name.as_snake_case());
+                write_arg_assignment(&mut output, "                            ", self.name.as_snake_case(), &format!("-{}", abbr), self.duplicate_arg, self.explain_overrides, &assignment)
             }
         } else {
             Ok(())
@@ -290,29 +552,159 @@ pub(crate) fn param_long_raw(param: &str) -> String {
     let mut res = String::with_capacity(param.len() + 2);
     res.push_str("--");
                                             // Writing to String never fails
-    underscore_to_hypen(&mut res, &param).unwrap();
+    underscore_to_hypen(&mut res, param).unwrap();
 
     res
 }
 
+/// Placeholders of the values of a param, e.g. `POINT POINT` or `FILE...`
+pub(crate) fn param_value_names(param: &::config::Param) -> String {
+    let name = param.name.as_upper_case().to_string();
+    match param.nargs {
+        None if param.map => "KEY=VALUE".to_owned(),
+        None => name,
+        Some(::config::Nargs::Fixed(count)) => vec![name; count].join(" "),
+        Some(::config::Nargs::Bounded { min, .. }) => vec![name; min].join(" ") + "...",
+    }
+}
+
 pub(crate) fn param_long(param: &::config::Param) -> String {
-    param_long_raw(&param.name.as_snake_case())
+    param_long_raw(param.name.as_snake_case())
+}
+
+/// Long options of the aliases of a param
+pub(crate) fn param_aliases(param: &::config::Param) -> Vec<String> {
+    param.aliases.iter().map(|alias| param_long_raw(alias.as_snake_case())).collect()
+}
+
+/// Long options of the aliases of a switch, prefixed by `--no-` if it's inverted
+pub(crate) fn switch_aliases(switch: &::config::Switch) -> Vec<String> {
+    let prefix = if switch.is_inverted() { "--no-" } else { "--" };
+    switch.aliases.iter().map(|alias| format!("{}{}", prefix, alias.as_hypenated())).collect()
+}
+
+/// Long options turning off a negatable switch, `--no-<name>` and the same for each alias
+pub(crate) fn switch_negations(switch: &::config::Switch) -> Vec<String> {
+    if !switch.negatable {
+        return Vec::new();
+    }
+    ::std::iter::once(&switch.name)
+        .chain(&switch.aliases)
+        .map(|name| format!("--no-{}", name.as_hypenated()))
+        .collect()
+}
+
+/// Appends the option turning off a negatable switch to `doc`
+pub(crate) fn doc_with_negation<'a>(doc: Option<Cow<'a, str>>, switch: &::config::Switch) -> Option<Cow<'a, str>> {
+    if !switch.negatable {
+        return doc;
+    }
+    let negation = format!("Turned off by --no-{}.", switch.name.as_hypenated());
+    match doc {
+        None => Some(negation.into()),
+        Some(doc) => Some(append_sentence(&doc, &negation).into()),
+    }
+}
+
+/// Appends the list of `aliases` to `doc`
+pub(crate) fn doc_with_aliases<'a>(doc: Option<Cow<'a, str>>, aliases: &[String]) -> Option<Cow<'a, str>> {
+    if aliases.is_empty() {
+        return doc;
+    }
+    let aliases = format!("Aliases: {}.", aliases.join(", "));
+    match doc {
+        None => Some(aliases.into()),
+        Some(doc) => Some(append_sentence(&doc, &aliases).into()),
+    }
+}
+
+/// Appends `sentence` to `doc`, ending the doc with a full stop first if needed
+fn append_sentence(doc: &str, sentence: &str) -> String {
+    let doc = doc.trim_end();
+    let separator = if doc.ends_with(['.', '!', '?']) { " " } else { ". " };
+    format!("{}{}{}", doc, separator, sentence)
+}
+
+/// Doc of a param shown in the help, listing the aliases if they're shown
+fn param_help_doc(param: &::config::Param) -> Option<Cow<'_, str>> {
+    if param.show_aliases {
+        doc_with_aliases(param_doc(param), &param_aliases(param))
+    } else {
+        param_doc(param)
+    }
+}
+
+/// Doc of a switch shown in the help, listing the aliases if they're shown
+fn switch_help_doc(switch: &::config::Switch) -> Option<Cow<'_, str>> {
+    let doc = doc_with_negation(switch.doc.as_deref().map(Cow::Borrowed), switch);
+    if switch.show_aliases {
+        doc_with_aliases(doc, &switch_aliases(switch))
+    } else {
+        doc
+    }
 }
 
 pub(crate) fn switch_long(switch: &::config::Switch) -> String {
     if switch.is_inverted() {
         let mut res = String::with_capacity(switch.name.as_snake_case().len() + 5);
                                             // Writing to String never fails
-        write!(res, "--no-{}", switch.name.as_snake_case()).unwrap();
+        write!(res, "--no-{}", switch.name.as_hypenated()).unwrap();
         res
     } else {
         let mut res = String::with_capacity(switch.name.as_snake_case().len() + 2);
                                             // Writing to String never fails
-        write!(res, "--{}", switch.name.as_snake_case()).unwrap();
+        write!(res, "--{}", switch.name.as_hypenated()).unwrap();
         res
     }
 }
 
+/// Name of the positional argument in the usage, e.g. `[FILES...]` for an optional variadic one
+pub(crate) fn positional_usage(positional: &::config::Positional) -> String {
+    let name = if positional.trailing { format!("-- {}", positional.value_name()) } else { positional.value_name() };
+    match (positional.optional, positional.variadic) {
+        (false, false) => name,
+        (false, true) => format!("{}...", name),
+        (true, false) => format!("[{}]", name),
+        (true, true) => format!("[{}...]", name),
+    }
+}
+
+pub(crate) fn param_doc(param: &::config::Param) -> Option<Cow<'_, str>> {
+    let syntax = match &param.values {
+        Some(values) => Some(format!("Possible values: {}.", values.join(", "))),
+        None if param.is_duration() => Some(DURATION_SYNTAX.to_owned()),
+        None if param.size => Some(SIZE_SYNTAX.to_owned()),
+        None => None,
+    };
+    match (&param.doc, syntax) {
+        (doc, None) => doc.as_ref().map(|doc| Cow::Borrowed(doc.as_str())),
+        (None, Some(syntax)) => Some(syntax.into()),
+        (Some(doc), Some(syntax)) => Some(append_sentence(doc, &syntax).into()),
+    }
+}
+
+/// Documentation of the positional including the syntax of durations and sizes
+pub(crate) fn positional_doc(positional: &::config::Positional) -> Option<Cow<'_, str>> {
+    let syntax = if positional.is_duration() {
+        Some(DURATION_SYNTAX)
+    } else if positional.size {
+        Some(SIZE_SYNTAX)
+    } else {
+        None
+    };
+    match (&positional.doc, syntax) {
+        (Some(doc), Some(syntax)) => Some(append_sentence(doc, syntax).into()),
+        (Some(doc), None) => Some(Cow::Borrowed(doc.as_str())),
+        (None, syntax) => syntax.map(Cow::Borrowed),
+    }
+}
+
+/// Describes the values of `duration` params in the help and the man page
+const DURATION_SYNTAX: &str = "Duration such as 30s, 5m or 1h30m.";
+
+/// Describes the values of `size` params in the help and the man page
+const SIZE_SYNTAX: &str = "Size in bytes such as 4096, 64KiB or 2GB.";
+
 pub(crate) fn param_short(param: &::config::Param) -> Option<String> {
     let abbr = param.abbr?;
     let mut res = String::with_capacity(2);
@@ -350,97 +742,2104 @@ fn write_config<T, W: Write>(config: &Config, mut output: W) -> fmt::Result wher
     write_params_and_switches::<T, _>(config, &mut output)
 }
 
-fn gen_raw_config<W: Write>(config: &Config, output: W) -> fmt::Result {
-    write_params_and_switches::<visitor::RawConfigDecl, _>(config, output)
+/// Returns the function deserializing the value of the param from config files if the param needs
+/// one
+fn deserialize_fn(config: &Config, param: &::config::Param) -> Option<String> {
+    if config.general.lenient_config_values {
+        Some(format!("lenient_{}", param.name.as_snake_case()))
+    } else if param.secret {
+        Some(format!("secret_{}", param.name.as_snake_case()))
+    } else if param.is_os_string() {
+        Some("::configure_me::helpers::deserialize_os_string".to_owned())
+    } else if param.is_duration() {
+        Some("::configure_me::helpers::deserialize_duration".to_owned())
+    } else if param.size {
+        Some("::configure_me::helpers::deserialize_size".to_owned())
+    } else {
+        None
+    }
+}
+
+/// Type deserialized from config files instead of the type of the param and the conversion of the
+/// deserialized `Option`, if the type can't be deserialized from a string in config files
+fn config_file_conversion(param: &::config::Param) -> Option<(&'static str, &'static str)> {
+    if param.is_os_string() {
+        Some(("String", ".map(|value| value.map(Into::into))"))
+    } else if param.is_duration() {
+        Some(("::configure_me::helpers::HumanDuration", ".map(|value| value.map(|value| value.0))"))
+    } else if param.size {
+        Some((::config::SIZE_PARSE_TYPE, ".map(|value| value.map(|value| value.0))"))
+    } else {
+        None
+    }
+}
+
+fn gen_raw_config<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for param in &config.params {
+        if let Some(function) = deserialize_fn(config, param) {
+            writeln!(output, "        #[serde(default, deserialize_with = \"{}\")]", function)?;
+        }
+        for alias in &param.aliases {
+            writeln!(output, "        #[serde(alias = \"{}\")]", alias.as_snake_case())?;
+        }
+        VisitWrite::<visitor::RawConfigDecl>::visit_write(param, &mut output)?;
+        for old in &param.renamed_from {
+            match deserialize_fn(config, param) {
+                Some(function) => writeln!(output, "        #[serde(default, rename = \"{}\", deserialize_with = \"{}\")]", old.as_snake_case(), function)?,
+                None => writeln!(output, "        #[serde(rename = \"{}\")]", old.as_snake_case())?,
+            }
+            writeln!(output, "        _renamed_{}: Option<{}>,", old.as_snake_case(), param.field_type())?;
+        }
+    }
+    for switch in &config.switches {
+        if !switch.is_count() {
+            writeln!(output, "        #[serde(default, deserialize_with = \"deserialize_switch\")]")?;
+        } else if config.general.lenient_config_values {
+            writeln!(output, "        #[serde(default, deserialize_with = \"lenient_{}\")]", switch.name.as_snake_case())?;
+        }
+        for alias in &switch.aliases {
+            writeln!(output, "        #[serde(alias = \"{}\")]", alias.as_snake_case())?;
+        }
+        VisitWrite::<visitor::RawConfigDecl>::visit_write(switch, &mut output)?;
+    }
+    // Positional arguments are never read from config files
+    for positional in &config.positionals {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        {}: Option<{}>,", positional.name.as_snake_case(), positional.field_type())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        _subcommand: Option<super::Subcommand>,")?;
+    }
+    if config.general.tracks_sources() {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        _sources: ::configure_me::helpers::Sources,")?;
+    }
+    if config.general.config_includes {
+        writeln!(output, "        #[serde(default, rename = \"include\")]")?;
+        writeln!(output, "        _include: Vec<PathBuf>,")?;
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        _including: ::configure_me::helpers::Includes,")?;
+    }
+    if config.general.profile_param.is_some() {
+        writeln!(output, "        #[serde(skip)]")?;
+        writeln!(output, "        pub(super) _profile: Option<String>,")?;
+    }
+    Ok(())
+}
+
+/// Whether some switch is boolean and thus parsed using `general.true_values` and `general.false_values`
+fn has_bool_switches(config: &Config) -> bool {
+    config.switches.iter().any(|switch| !switch.is_count())
+}
+
+/// Params and switches given on the command line whose repeated occurrences have to be tracked,
+/// including params whose first occurrence replaces the values from other sources
+fn tracked_args(config: &Config) -> impl Iterator<Item=(&str, DuplicateArg)> {
+    let params = config.params
+        .iter()
+        .filter(|param| param.argument && (param.duplicate_arg != DuplicateArg::Last || param.multiple == Some(ListMerge::Replace)))
+        .map(|param| (param.name.as_snake_case(), param.duplicate_arg));
+    let switches = config.switches
+        .iter()
+        .filter(|switch| switch.duplicate_arg != DuplicateArg::Last)
+        .map(|switch| (switch.name.as_snake_case(), switch.duplicate_arg));
+    params.chain(switches)
+}
+
+fn rejects_duplicate_args(config: &Config) -> bool {
+    tracked_args(config).any(|(_, duplicate_arg)| duplicate_arg == DuplicateArg::Error)
+}
+
+/// Writes a list of string literals
+fn write_str_slice<W: Write>(mut output: W, values: &[&str]) -> fmt::Result {
+    write!(output, "&[")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(output, ", ")?;
+        }
+        write!(output, "{:?}", value)?;
+    }
+    write!(output, "]")
 }
 
 fn gen_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if config.general.prints_version() {
+        writeln!(output, "    VersionRequested(String),")?;
+    }
     write_params_and_switches::<visitor::ArgParseErrorDecl, _>(config, &mut output)?;
+    visitor::iter::<visitor::ArgParseErrorDecl, _, _>(&config.positionals, &mut output)?;
+    if !config.subcommands.is_empty() {
+        writeln!(output, "    UnknownSubcommand(String),")?;
+    }
+    for subcommand in &config.subcommands {
+        writeln!(output, "    Subcommand{}(Box<{}::Error>),", subcommand.name.as_pascal_case(), subcommand.name.as_snake_case())?;
+    }
     if config.general.conf_dir_param.is_some() {
         writeln!(output, "    OpenConfDir(std::io::Error, std::path::PathBuf),")?;
         writeln!(output, "    ReadConfDir(std::io::Error, std::path::PathBuf),")?;
     }
+    if config.general.long_option_prefixes {
+        writeln!(output, "    AmbiguousArgument(String, Vec<&'static str>),")?;
+    }
+    if has_bool_switches(config) {
+        writeln!(output, "    InvalidSwitchValue(&'static str, ::std::ffi::OsString),")?;
+    }
+    if rejects_duplicate_args(config) {
+        writeln!(output, "    DuplicateArgument(&'static str),")?;
+    }
     Ok(())
 }
 
-fn gen_env_parse_error<W: Write>(config: &Config, output: W) -> fmt::Result {
-    write_params_and_switches::<visitor::EnvParseErrorDecl, _>(config, output)
+/// Whether long options need to be canonicalized before matching them
+fn normalizes_long_options(config: &Config) -> bool {
+    config.general.long_option_prefixes || config.general.case_insensitive_long_options || config.general.slash_options
 }
 
-fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
-    use ::config::SwitchKind;
+/// All long options accepted by the program
+fn long_options(config: &Config) -> Vec<String> {
+    let mut options = vec!["--help".to_owned()];
+    options.extend(config.general.conf_file_param.iter().chain(&config.general.conf_dir_param).chain(&config.general.profile_param).chain(&config.general.explain_config_switch).chain(&config.general.check_config_switch).chain(&config.general.dump_config_switch).map(|param| param_long_raw(param.as_snake_case())));
+    options.extend(config.params.iter().filter(|param| param.argument).flat_map(|param| ::std::iter::once(param_long(param)).chain(param_aliases(param))));
+    options.extend(config.switches.iter().flat_map(|switch| ::std::iter::once(switch_long(switch)).chain(switch_aliases(switch)).chain(switch_negations(switch))));
+    options
+}
 
-    let sum_arg_len = config
-        .params
-        .iter()
-        .filter(|param| param.argument)
-        .map(|param| param.name.as_snake_case().len() * 2 + 6)
-        .sum::<usize>()
-        + config
-        .switches
-        .iter()
-        .map(|switch| switch.name.as_snake_case().len() + if switch.is_inverted() { 8 } else { 5 })
-        .sum::<usize>()
-        + config
-        .general
-        .conf_file_param
-        .as_ref()
-        .map(|param| param.as_snake_case().len() + 6 + 9)
-        .unwrap_or(0)
-        + config
-        .general
-        .conf_dir_param
-        .as_ref()
-        .map(|param| param.as_snake_case().len() + 6 + 8)
-        .unwrap_or(0);
+fn gen_merge_args_init<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for (name, _) in tracked_args(config) {
+        writeln!(output, "            let mut seen_{} = false;", name)?;
+    }
+    Ok(())
+}
 
-    write!(output, "        ArgParseError::HelpRequested(program_name) => write!(f, \"Usage: {{}}")?;
-    // Standard width of the terminal - "Usage: ".len()
-    if sum_arg_len < (80 - 7) {
-        if let Some(conf_file_param) = &config.general.conf_file_param {
-            write!(output, " [--{} CONF_FILE]", conf_file_param.as_hypenated())?;
-        }
-        if let Some(conf_dir_param) = &config.general.conf_dir_param {
-            write!(output, " [--{} CONF_DIR]", conf_dir_param.as_hypenated())?;
-        }
-        for param in config.params.iter().filter(|param| param.argument) {
-            if let Some(abbr) = &param.abbr {
-                write!(output, " [-{} {}|--", abbr, param.name.as_upper_case())?;
+fn gen_normalize_arg<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if normalizes_long_options(config) {
+        writeln!(output, "                let arg = normalize_long_option(arg)?;")?;
+    }
+    Ok(())
+}
+
+/// Writes the function deserializing field `name` of the raw config with `lenient_config_values`
+fn write_lenient_fn<W: Write>(mut output: W, name: &str, ty: &str, helper: &str) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    fn lenient_{}<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<{}>, D::Error> {{", name, ty)?;
+    writeln!(output, "        ::configure_me::helpers::{}(deserializer, \"{}\")", helper, name)?;
+    writeln!(output, "    }}")
+}
+
+/// Name of the runtime helper deserializing the value of the param leniently
+fn lenient_helper(param: &::config::Param) -> &'static str {
+    if param.secret {
+        "deserialize_lenient_secret"
+    } else {
+        "deserialize_lenient"
+    }
+}
+
+/// Generates helper functions of the `raw` module
+fn gen_raw_fns<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if config.general.lenient_config_values {
+        for param in &config.params {
+            if let Some((ty, conversion)) = config_file_conversion(param) {
+                writeln!(output)?;
+                writeln!(output, "    fn lenient_{}<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<{}>, D::Error> {{", param.name.as_snake_case(), param.ty)?;
+                writeln!(output, "        ::configure_me::helpers::{}::<{}, _>(deserializer, \"{}\"){}", lenient_helper(param), ty, param.name.as_snake_case(), conversion)?;
+                writeln!(output, "    }}")?;
             } else {
-                write!(output, " [--")?;
+                write_lenient_fn(&mut output, param.name.as_snake_case(), &param.field_type(), lenient_helper(param))?;
             }
-            write!(output, "{} {}]", param.name.as_hypenated(), param.name.as_upper_case())?;
         }
-        for switch in config.switches.iter() {
-            if let SwitchKind::Normal { abbr: Some(abbr), .. } = &switch.kind {
-                write!(output, " [-{}|--", abbr)?;
+        for switch in config.switches.iter().filter(|switch| switch.is_count()) {
+            write_lenient_fn(&mut output, switch.name.as_snake_case(), raw_switch_type(switch), "deserialize_lenient")?;
+        }
+    } else {
+        // Errors of deserializing secret params could contain their values
+        for param in config.params.iter().filter(|param| param.secret) {
+            writeln!(output)?;
+            if let Some((ty, conversion)) = config_file_conversion(param) {
+                writeln!(output, "    fn secret_{}<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<{}>, D::Error> {{", param.name.as_snake_case(), param.ty)?;
+                writeln!(output, "        ::configure_me::helpers::deserialize_secret::<{}, _>(deserializer){}", ty, conversion)?;
             } else {
-                write!(output, " [--")?;
-            }
-            if switch.is_inverted() {
-                write!(output, "no-")?;
+                writeln!(output, "    fn secret_{}<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<{}>, D::Error> {{", param.name.as_snake_case(), param.field_type())?;
+                writeln!(output, "        ::configure_me::helpers::deserialize_secret(deserializer)")?;
             }
-            write!(output, "{}", switch.name.as_hypenated())?;
-            if switch.is_count() {
-                write!(output, " ...")?;
+            writeln!(output, "    }}")?;
+        }
+    }
+
+    if has_bool_switches(config) {
+        writeln!(output)?;
+        write!(output, "    pub const TRUE_VALUES: &[&str] = ")?;
+        write_str_slice(&mut output, &config.general.true_values())?;
+        writeln!(output, ";")?;
+        write!(output, "    pub const FALSE_VALUES: &[&str] = ")?;
+        write_str_slice(&mut output, &config.general.false_values())?;
+        writeln!(output, ";")?;
+        writeln!(output)?;
+        writeln!(output, "    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {{")?;
+        writeln!(output, "        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)")?;
+        writeln!(output, "    }}")?;
+    }
+
+    if scans_args(config) {
+        writeln!(output)?;
+        write!(output, "    pub const VALUED_OPTIONS: &[(&str, usize, usize)] = &[")?;
+        for (i, (option, min, max)) in valued_options(config).iter().enumerate() {
+            if i > 0 {
+                write!(output, ", ")?;
             }
-            write!(output, "]")?;
+            write!(output, "({:?}, {}, {})", option, min, max)?;
         }
-    } else {
-        write!(output, " [ARGUMENTS...]")?;
+        writeln!(output, "];")?;
     }
-    let conf_files = config
+
+    if normalizes_long_options(config) {
+        gen_normalize_long_option(config, &mut output)?;
+    }
+
+    if checks_config_files(config) {
+        gen_config_file_checks(config, &mut output)?;
+    }
+
+    if renames_keys(config) {
+        gen_rename_keys(config, &mut output)?;
+    }
+
+    if config.general.tracks_sources() {
+        gen_track_file(config, &mut output)?;
+    }
+
+    if config.general.conf_file_param.is_some() {
+        gen_load_stdin(config, &mut output)?;
+    }
+
+    if config.general.config_includes {
+        gen_with_includes(&mut output)?;
+    }
+
+    if config.general.drop_ins {
+        gen_with_drop_ins(config, &mut output)?;
+    }
+
+    if config.general.merge_report || config.general.env_file.is_some() {
+        gen_merge_env_with(config, &mut output)?;
+    }
+
+    if config.general.merge_report {
+        gen_raw_merge_report_fns(config, &mut output)?;
+    }
+
+    if !config.positionals.is_empty() {
+        gen_merge_positionals(config, &mut output)?;
+    }
+
+    if !config.subcommands.is_empty() {
+        gen_merge_subcommand(config, &mut output)?;
+    }
+    Ok(())
+}
+
+/// Generates the method parsing the subcommand selected by the first argument remaining after the
+/// options, which reads the config files loaded so far too
+fn gen_merge_subcommand<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    impl Config {{")?;
+    writeln!(output, "        pub fn merge_subcommand<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<::std::vec::IntoIter<::std::ffi::OsString>, super::Error> {{")?;
+    writeln!(output, "            let arg = match args.next() {{")?;
+    writeln!(output, "                Some(arg) => arg,")?;
+    writeln!(output, "                None => return Ok(Vec::new().into_iter()),")?;
+    writeln!(output, "            }};")?;
+    writeln!(output, "            let program_name: String = {};", program_name_expr(config))?;
+    for (i, subcommand) in config.subcommands.iter().enumerate() {
+        let name = subcommand.name.as_snake_case();
+        if i == 0 {
+            writeln!(output, "            if arg == *\"{}\" {{", name)?;
+        } else {
+            writeln!(output, "            }} else if arg == *\"{}\" {{", name)?;
+        }
+        writeln!(output, "                let args = ::std::iter::once(format!(\"{{}} {}\", program_name).into()).chain(args);", name)?;
+        writeln!(output, "                let config_files = self._loaded_files.iter().map(|(file, _)| file.clone()).collect::<Vec<_>>();")?;
+        writeln!(output, "                let (config, remaining_args) = super::{}::Config::custom_args_and_optional_files(args, config_files).map_err(|err| ArgParseError::Subcommand{}(Box::new(err)))?;", name, subcommand.name.as_pascal_case())?;
+        writeln!(output, "                self._subcommand = Some(super::Subcommand::{}(config));", subcommand.name.as_pascal_case())?;
+        writeln!(output, "                Ok(remaining_args.collect::<Vec<_>>().into_iter())")?;
+    }
+    writeln!(output, "            }} else {{")?;
+    writeln!(output, "                Err(ArgParseError::UnknownSubcommand(arg.to_string_lossy().into_owned()).into())")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")
+}
+
+/// Generates the method filling the positional arguments in order from the arguments remaining
+/// after the options
+fn gen_merge_positionals<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    impl Config {{")?;
+    if has_trailing_positional(config) {
+        writeln!(output, "        pub fn merge_positionals<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<impl Iterator<Item=::std::ffi::OsString>, super::Error> {{")?;
+        // The other positionals are taken from the arguments before the separator, which is
+        // always forwarded by `merge_args` then
+        writeln!(output, "            let mut leading = Vec::new();")?;
+        writeln!(output, "            for arg in &mut args {{")?;
+        writeln!(output, "                if arg == *\"--\" {{")?;
+        writeln!(output, "                    break;")?;
+        writeln!(output, "                }}")?;
+        writeln!(output, "                leading.push(arg);")?;
+        writeln!(output, "            }}")?;
+        let leading_mut = if config.positionals.len() > 1 { "mut " } else { "" };
+        writeln!(output, "            let {}leading = leading.into_iter();", leading_mut)?;
+    } else {
+        writeln!(output, "        pub fn merge_positionals<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<I, super::Error> {{")?;
+    }
+    for positional in &config.positionals {
+        let source = if positional.trailing || !has_trailing_positional(config) { "args" } else { "leading" };
+        let name = positional.name.as_snake_case();
+        let value = if positional.is_wrapped() {
+            format!("<{} as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::Field{})?.0", positional.parse_type(), positional.name.as_pascal_case())
+        } else {
+            format!("::configure_me::parse_arg::ParseArg::parse_owned_arg(arg).map_err(ArgParseError::Field{})?", positional.name.as_pascal_case())
+        };
+        if positional.variadic {
+            writeln!(output, "            for arg in &mut {} {{", source)?;
+            writeln!(output, "                self.{}.get_or_insert_with(Vec::new).push({});", name, value)?;
+        } else {
+            writeln!(output, "            if let Some(arg) = {}.next() {{", source)?;
+            writeln!(output, "                self.{} = Some({});", name, value)?;
+        }
+        writeln!(output, "            }}")?;
+    }
+    if has_trailing_positional(config) {
+        writeln!(output, "            Ok(leading.chain(args))")?;
+    } else {
+        writeln!(output, "            Ok(args)")?;
+    }
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")
+}
+
+/// Whether a positional takes the arguments after `--`
+fn has_trailing_positional(config: &Config) -> bool {
+    config.positionals.iter().any(|positional| positional.trailing)
+}
+
+/// Whether some param was renamed and its old keys have to be moved to the new ones
+fn renames_keys(config: &Config) -> bool {
+    config.params.iter().any(|param| !param.renamed_from.is_empty())
+}
+
+/// Generates the function moving the values of old keys of renamed params to the new ones
+fn gen_rename_keys<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    fn rename_keys(mut config: Config, file: &::std::path::Path) -> Config {{")?;
+    for param in &config.params {
+        for old in &param.renamed_from {
+            writeln!(output, "        ::configure_me::helpers::rename_key(&mut config.{}, config._renamed_{}.take(), \"{}\", \"{}\", file);", param.name.as_snake_case(), old.as_snake_case(), param.section_path(old), param.field_path())?;
+        }
+    }
+    writeln!(output, "        config")?;
+    writeln!(output, "    }}")
+}
+
+/// Methods applied in order to the result of loading a config file passed to them
+fn load_steps(general: &::config::General) -> Vec<&'static str> {
+    let mut steps = Vec::new();
+    if general.config_includes {
+        steps.push("with_includes");
+    }
+    if general.drop_ins {
+        steps.push("with_drop_ins");
+    }
+    steps
+}
+
+/// Generates the method loading the config file piped to the standard input
+fn gen_load_stdin<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let checks = if checks_config_files(config) { "CONFIG_FILE_CHECKS" } else { "Default::default()" };
+
+    writeln!(output)?;
+    writeln!(output, "    impl Config {{")?;
+    writeln!(output, "        /// Loads the config file from the standard input, given as `-` in the arguments")?;
+    writeln!(output, "        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {{")?;
+    writeln!(output, "            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), {}).map_err(|error| super::Error::Reading {{ file: config_file_name.into(), error }})?;", checks)?;
+    writeln!(output, "            self.parse(config_file_name, &config_content)")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")
+}
+
+/// Generates the method loading the files included by a config file
+fn gen_with_includes<W: Write>(mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    impl Config {{")?;
+    writeln!(output, "        /// Merges `config`, which is the result of loading the file, into the files it includes")?;
+    writeln!(output, "        ///")?;
+    writeln!(output, "        /// The files listed in the `include` key are relative to the directory of the file, later")?;
+    writeln!(output, "        /// ones override earlier ones and the file overrides all of them.")?;
+    writeln!(output, "        pub fn with_includes(&mut self, config_file_name: &::std::path::Path, config: Result<Self, super::Error>) -> Result<Self, super::Error> {{")?;
+    writeln!(output, "            let mut config = config?;")?;
+    writeln!(output, "            if config._include.is_empty() {{")?;
+    writeln!(output, "                return Ok(config);")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "            let includes = ::std::mem::replace(&mut config._include, Vec::new());")?;
+    writeln!(output, "            let includes = self._including.enter(config_file_name, includes).map_err(|(file, error)| super::Error::Reading {{ file, error }})?;")?;
+    writeln!(output, "            let included = self.load_includes(includes);")?;
+    writeln!(output, "            self._including.leave();")?;
+    writeln!(output, "            let mut included = included?;")?;
+    writeln!(output, "            included.merge_in(config);")?;
+    writeln!(output, "            Ok(included)")?;
+    writeln!(output, "        }}")?;
+    writeln!(output)?;
+    writeln!(output, "        fn load_includes(&mut self, includes: Vec<PathBuf>) -> Result<Self, super::Error> {{")?;
+    writeln!(output, "            let mut config = Self::default();")?;
+    writeln!(output, "            for file in includes {{")?;
+    writeln!(output, "                self._including.check_cycle(&file).map_err(|(file, error)| super::Error::Reading {{ file, error }})?;")?;
+    writeln!(output, "                let loaded = self.load_once(&file).map_err(|err| match err {{")?;
+    writeln!(output, "                    super::Error::Reading {{ file, error }} => super::Error::Reading {{ file, error: ::configure_me::helpers::include_error(error) }},")?;
+    writeln!(output, "                    err => err,")?;
+    writeln!(output, "                }});")?;
+    writeln!(output, "                let included = self.with_includes(&file, loaded)?;")?;
+    writeln!(output, "                config.merge_in(included);")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "            Ok(config)")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")
+}
+
+/// Generates the method merging the drop-ins of a config file
+fn gen_with_drop_ins<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    impl Config {{")?;
+    writeln!(output, "        /// Merges the drop-ins of the config file into `config`, which is the result of loading the file")?;
+    writeln!(output, "        ///")?;
+    writeln!(output, "        /// The drop-ins are the `*.toml` files in the directory named like the file with `.d` appended,")?;
+    writeln!(output, "        /// they override the file in lexical order and are used even if the file doesn't exist.")?;
+    writeln!(output, "        pub fn with_drop_ins(&mut self, config_file_name: &::std::path::Path, config: Result<Self, super::Error>) -> Result<Self, super::Error> {{")?;
+    writeln!(output, "            let drop_ins = ::configure_me::helpers::drop_in_files(config_file_name).map_err(|(file, error)| super::Error::Reading {{ file, error }})?;")?;
+    writeln!(output, "            let mut config = match config {{")?;
+    writeln!(output, "                Err(super::Error::Reading {{ ref error, .. }}) if error.kind() == ::std::io::ErrorKind::NotFound && !drop_ins.is_empty() => Self::default(),")?;
+    writeln!(output, "                config => config?,")?;
+    writeln!(output, "            }};")?;
+    writeln!(output, "            for file in drop_ins {{")?;
+    if config.general.config_includes {
+        writeln!(output, "                let loaded = self.load_once(&file);")?;
+        writeln!(output, "                let drop_in = self.with_includes(&file, loaded)?;")?;
+    } else {
+        writeln!(output, "                let drop_in = self.load_once(file)?;")?;
+    }
+    writeln!(output, "                config.merge_in(drop_in);")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "            Ok(config)")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")
+}
+
+fn gen_track_file<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let params = config.params.iter().map(|param| (param.name.as_snake_case(), param.explain_overrides, param.audit_combined));
+    let switches = config.switches.iter().map(|switch| (switch.name.as_snake_case(), switch.explain_overrides, switch.audit_combined));
+
+    writeln!(output)?;
+    writeln!(output, "    fn track_file(mut config: Config, file: &::std::path::Path) -> Config {{")?;
+    for (name, explain_overrides, audit_combined) in params.chain(switches) {
+        let method = match (explain_overrides, audit_combined) {
+            (true, _) => "set",
+            (false, true) => "add",
+            (false, false) => continue,
+        };
+        writeln!(output, "        if config.{}.is_some() {{", name)?;
+        writeln!(output, "            config._sources.{}(\"{}\", ::configure_me::helpers::Source::File(file.to_owned()));", method, name)?;
+        writeln!(output, "        }}")?;
+    }
+    writeln!(output, "        config")?;
+    writeln!(output, "    }}")
+}
+
+fn gen_global_config<W: Write>(mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "static GLOBAL_CONFIG: ::std::sync::OnceLock<Config> = ::std::sync::OnceLock::new();")?;
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Stores the configuration so that it's accessible using `Config::global()`")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Panics if the configuration was already stored.")?;
+    writeln!(output, "    pub fn init_global(self) -> &'static Self {{")?;
+    writeln!(output, "        if GLOBAL_CONFIG.set(self).is_err() {{")?;
+    writeln!(output, "            panic!(\"the global configuration was already initialized\");")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "        Self::global()")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Returns the configuration stored by `Config::init_global()`")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Panics if it wasn't stored yet.")?;
+    writeln!(output, "    pub fn global() -> &'static Self {{")?;
+    writeln!(output, "        GLOBAL_CONFIG.get().expect(\"the global configuration wasn't initialized, call Config::init_global() first\")")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Returns the configuration stored by `Config::init_global()`, if any")?;
+    writeln!(output, "    pub fn try_global() -> Option<&'static Self> {{")?;
+    writeln!(output, "        GLOBAL_CONFIG.get()")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_shared_config<W: Write>(mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "/// Configuration that can be replaced atomically while it's being used")?;
+    writeln!(output, "///")?;
+    writeln!(output, "/// Requires the `shared` feature of `configure_me`.")?;
+    writeln!(output, "pub struct SharedConfig(::configure_me::arc_swap::ArcSwap<Config>);")?;
+    writeln!(output)?;
+    writeln!(output, "impl SharedConfig {{")?;
+    writeln!(output, "    pub fn new(config: Config) -> Self {{")?;
+    writeln!(output, "        SharedConfig(::configure_me::arc_swap::ArcSwap::from_pointee(config))")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Returns the current configuration")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// The returned value isn't affected by later calls to `replace()`.")?;
+    writeln!(output, "    pub fn load(&self) -> ::std::sync::Arc<Config> {{")?;
+    writeln!(output, "        self.0.load_full()")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Atomically replaces the configuration, returning the previous one")?;
+    writeln!(output, "    pub fn replace(&self, config: Config) -> ::std::sync::Arc<Config> {{")?;
+    writeln!(output, "        self.0.swap(::std::sync::Arc::new(config))")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl From<Config> for SharedConfig {{")?;
+    writeln!(output, "    fn from(config: Config) -> Self {{")?;
+    writeln!(output, "        SharedConfig::new(config)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_export_env_vars<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Returns environment variables reproducing the configuration in a child process")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Params and switches without an environment variable and unset optional params are skipped.")?;
+    writeln!(output, "    pub fn to_env_vars(&self) -> Vec<(::std::ffi::OsString, ::std::ffi::OsString)> {{")?;
+    writeln!(output, "        let mut vars = Vec::new();")?;
+    for param in config.params.iter().filter(|param| param.env_var) {
+        let name = env_var_name(config, param.name.as_snake_case());
+        // Every entry of a map has its own variable
+        if param.map {
+            match param.optionality {
+                Optionality::Optional => {
+                    writeln!(output, "        if let Some(value) = &self.{} {{", param.field_path())?;
+                    writeln!(output, "            vars.extend(::configure_me::helpers::map_env_vars(\"{}_\", value));", name)?;
+                    writeln!(output, "        }}")?;
+                },
+                _ => writeln!(output, "        vars.extend(::configure_me::helpers::map_env_vars(\"{}_\", &self.{}));", name, param.field_path())?,
+            }
+            continue;
+        }
+        let to_value = |value: &str| match param.multiple {
+            Some(_) => format!("::configure_me::helpers::join_values({}, {:?})", value, param.env_delimiter),
+            None => format!("::configure_me::ToArg::to_arg({})", value),
+        };
+        match param.optionality {
+            Optionality::Optional => {
+                writeln!(output, "        if let Some(value) = &self.{} {{", param.field_path())?;
+                writeln!(output, "            vars.push((\"{}\".into(), {}));", name, to_value("value"))?;
+                writeln!(output, "        }}")?;
+            },
+            _ => writeln!(output, "        vars.push((\"{}\".into(), {}));", name, to_value(&format!("&self.{}", param.field_path())))?,
+        }
+    }
+    for switch in config.switches.iter().filter(|switch| switch.env_var) {
+        let name = env_var_name(config, switch.name.as_snake_case());
+        if switch.is_count() {
+            writeln!(output, "        vars.push((\"{}\".into(), ::configure_me::ToArg::to_arg(&{})));", name, switch_value(switch, "self"))?;
+            continue;
+        }
+        // A switch can't be exported if the list of spellings for its value is empty
+        match (config.general.true_values().first(), config.general.false_values().first()) {
+            (Some(true_value), Some(false_value)) => writeln!(output, "        vars.push((\"{}\".into(), if self.{} {{ \"{}\" }} else {{ \"{}\" }}.into()));", name, switch.name.as_snake_case(), true_value.escape_default(), false_value.escape_default())?,
+            (Some(true_value), None) => writeln!(output, "        if self.{} {{ vars.push((\"{}\".into(), \"{}\".into())); }}", switch.name.as_snake_case(), name, true_value.escape_default())?,
+            (None, Some(false_value)) => writeln!(output, "        if !self.{} {{ vars.push((\"{}\".into(), \"{}\".into())); }}", switch.name.as_snake_case(), name, false_value.escape_default())?,
+            (None, None) => (),
+        }
+    }
+    writeln!(output, "        vars")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn write_export_param_args<W: Write>(param: &::config::Param, mut output: W, indent: &str, value: &str) -> fmt::Result {
+    let option = format!("--{}", param.name.as_hypenated());
+    if param.map {
+        writeln!(output, "{}args.extend(::configure_me::helpers::map_args(\"{}\", {}));", indent, option, value)
+    } else if param.multiple.is_some() {
+        writeln!(output, "{}args.extend({}.iter().map(|value| ::configure_me::helpers::arg_with_value(\"{}\", value)));", indent, value, option)
+    } else if param.nargs.is_some() {
+        writeln!(output, "{}args.push(\"{}\".into());", indent, option)?;
+        writeln!(output, "{}args.extend({}.iter().map(::configure_me::ToArg::to_arg));", indent, value)
+    } else {
+        // The value may start with '-', so it's kept in the same argument
+        writeln!(output, "{}args.push(::configure_me::helpers::arg_with_value(\"{}\", {}));", indent, option, value)
+    }
+}
+
+fn gen_export_args<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Returns arguments reproducing the configuration, excluding the program name")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Params without an argument and unset optional params are skipped.")?;
+    writeln!(output, "    pub fn to_args(&self) -> Vec<::std::ffi::OsString> {{")?;
+    writeln!(output, "        let mut args = Vec::new();")?;
+    for param in config.params.iter().filter(|param| param.argument) {
+        match param.optionality {
+            Optionality::Optional => {
+                writeln!(output, "        if let Some(value) = &self.{} {{", param.field_path())?;
+                write_export_param_args(param, &mut output, "            ", "value")?;
+                writeln!(output, "        }}")?;
+            },
+            _ => write_export_param_args(param, &mut output, "        ", &format!("&self.{}", param.field_path()))?,
+        }
+    }
+    let false_value = config.general.false_values().first().map(|value| value.escape_default().to_string());
+    for switch in &config.switches {
+        let long = switch_long(switch);
+        let name = switch.name.as_snake_case();
+        if switch.is_count() {
+            writeln!(output, "        args.extend((0..{}).map(|_| \"{}\".into()));", switch_value(switch, "self"), long)?;
+            continue;
+        }
+        let given = if switch.is_inverted() { format!("!self.{}", name) } else { format!("self.{}", name) };
+        // The switch is given explicitly even if it's not, so that it overrides config files
+        match &false_value {
+            Some(false_value) => writeln!(output, "        args.push(if {} {{ \"{}\" }} else {{ \"{}={}\" }}.into());", given, long, long, false_value)?,
+            None if switch.negatable => writeln!(output, "        args.push(if {} {{ \"{}\" }} else {{ \"--no-{}\" }}.into());", given, long, switch.name.as_hypenated())?,
+            None => writeln!(output, "        if {} {{ args.push(\"{}\".into()); }}", given, long)?,
+        }
+    }
+    writeln!(output, "        args")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+/// Returns the expression formatting the value of the param for `NonDefaultField`, `value` is
+/// a reference to it
+fn format_param_value(param: &::config::Param, value: &str) -> String {
+    if param.secret {
+        "::configure_me::helpers::REDACTED.to_owned()".to_owned()
+    } else if param.map {
+        format!("::configure_me::helpers::format_map({})", value)
+    } else if param.has_multiple_values() {
+        format!("::configure_me::helpers::format_values({})", value)
+    } else {
+        format!("::configure_me::helpers::format_value({})", value)
+    }
+}
+
+fn gen_non_default_fields<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "/// Param or switch whose value differs from the default")?;
+    writeln!(output, "pub struct NonDefaultField {{")?;
+    writeln!(output, "    pub name: &'static str,")?;
+    writeln!(output, "    /// `None` if the param is mandatory or unset by default")?;
+    writeln!(output, "    pub default: Option<String>,")?;
+    writeln!(output, "    /// `None` if the optional param is unset")?;
+    writeln!(output, "    pub value: Option<String>,")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Returns the params and switches whose values differ from the defaults")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Mandatory params are always returned, the values of secret params are redacted.")?;
+    writeln!(output, "    pub fn non_default_fields(&self) -> Vec<NonDefaultField> {{")?;
+    writeln!(output, "        let mut fields = Vec::new();")?;
+    for param in &config.params {
+        let name = param.name.as_snake_case();
+        let field = param.field_path();
+        match &param.optionality {
+            Optionality::Optional if param.secret => {
+                writeln!(output, "        if self.{}.is_some() {{", field)?;
+                writeln!(output, "            fields.push(NonDefaultField {{ name: \"{}\", default: None, value: Some({}) }});", name, format_param_value(param, "value"))?;
+                writeln!(output, "        }}")?;
+            },
+            Optionality::Optional => {
+                writeln!(output, "        if let Some(value) = &self.{} {{", field)?;
+                writeln!(output, "            fields.push(NonDefaultField {{ name: \"{}\", default: None, value: Some({}) }});", name, format_param_value(param, "value"))?;
+                writeln!(output, "        }}")?;
+            },
+            Optionality::Mandatory => {
+                writeln!(output, "        fields.push(NonDefaultField {{ name: \"{}\", default: None, value: Some({}) }});", name, format_param_value(param, &format!("&self.{}", field)))?;
+            },
+            Optionality::DefaultValue(default) => {
+                // The default is converted the same way as when validating
+                writeln!(output, "        let default: {} = {{ let default: {} = {{ {} }}; default.into() }};", param.convert_into, param.field_type(), default)?;
+                writeln!(output, "        if self.{} != default {{", field)?;
+                writeln!(output, "            fields.push(NonDefaultField {{ name: \"{}\", default: Some({}), value: Some({}) }});", name, format_param_value(param, "&default"), format_param_value(param, &format!("&self.{}", field)))?;
+                writeln!(output, "        }}")?;
+            },
+        }
+    }
+    for switch in &config.switches {
+        let name = switch.name.as_snake_case();
+        let value = switch_value(switch, "self");
+        let (default, differs) = if switch.is_count() {
+            ("0", format!("{} != 0", value))
+        } else if switch.is_inverted() {
+            ("true", format!("!self.{}", name))
+        } else {
+            ("false", format!("self.{}", name))
+        };
+        writeln!(output, "        if {} {{", differs)?;
+        writeln!(output, "            fields.push(NonDefaultField {{ name: \"{}\", default: Some(\"{}\".to_owned()), value: Some({}.to_string()) }});", name, default, value)?;
+        writeln!(output, "        }}")?;
+    }
+    writeln!(output, "        fields")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_audit<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "static AUDIT_CALLBACK: ::std::sync::Mutex<Option<::configure_me::helpers::AuditCallback>> = ::std::sync::Mutex::new(None);")?;
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Registers the callback invoked for every param and switch when the configuration is loaded")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// The callback gets the sources of the value and the value itself, redacted for secret params.")?;
+    writeln!(output, "    /// It may be invoked from multiple threads at once and may load the configuration itself.")?;
+    writeln!(output, "    pub fn set_audit_callback<F: Fn(&::configure_me::helpers::AuditEntry) + Send + Sync + 'static>(callback: F) {{")?;
+    writeln!(output, "        *AUDIT_CALLBACK.lock().unwrap_or_else(|err| err.into_inner()) = Some(::std::sync::Arc::new(callback));")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    fn audit(&self, sources: &::configure_me::helpers::Sources) {{")?;
+    // The lock isn't held while calling the callback, which could register another one
+    writeln!(output, "        let callback = AUDIT_CALLBACK.lock().unwrap_or_else(|err| err.into_inner()).clone();")?;
+    writeln!(output, "        let callback = match callback {{")?;
+    writeln!(output, "            Some(callback) => callback,")?;
+    writeln!(output, "            None => return,")?;
+    writeln!(output, "        }};")?;
+    for param in &config.params {
+        let name = param.name.as_snake_case();
+        let field = param.field_path();
+        let value = match &param.optionality {
+            Optionality::Optional if param.secret => format!("self.{}.as_ref().map(|_| {})", field, format_param_value(param, "value")),
+            Optionality::Optional => format!("self.{}.as_ref().map(|value| {})", field, format_param_value(param, "value")),
+            _ => format!("Some({})", format_param_value(param, &format!("&self.{}", field))),
+        };
+        writeln!(output, "        callback(&::configure_me::helpers::AuditEntry {{ name: \"{}\", sources: sources.of(\"{}\"), value: {} }});", name, name, value)?;
+    }
+    for switch in &config.switches {
+        let name = switch.name.as_snake_case();
+        writeln!(output, "        callback(&::configure_me::helpers::AuditEntry {{ name: \"{}\", sources: sources.of(\"{}\"), value: Some({}.to_string()) }});", name, name, switch_value(switch, "self"))?;
+    }
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+/// Returns the expression converting the param to a TOML value, `value` is a reference to it
+fn toml_param_value(param: &::config::Param, value: &str) -> String {
+    if param.secret {
+        "::configure_me::toml::Value::String(::configure_me::helpers::REDACTED.to_owned())".to_owned()
+    } else if param.is_os_string() && param.convert_into == param.ty {
+        // OsString would be serialized in a platform-specific representation
+        format!("::configure_me::toml::Value::String(::configure_me::helpers::format_value({}))", value)
+    } else {
+        format!("::configure_me::toml::Value::try_from({})?", value)
+    }
+}
+
+/// Inserts the values of `params` into the TOML table in the variable `table`
+fn write_toml_params<'a, W: Write, I: IntoIterator<Item=&'a ::config::Param>>(mut output: W, params: I, table: &str) -> fmt::Result {
+    for param in params {
+        let key = param.key();
+        let field = param.field_path();
+        match &param.optionality {
+            Optionality::Optional if param.secret => {
+                writeln!(output, "        if self.{}.is_some() {{", field)?;
+                writeln!(output, "            {}.insert(\"{}\".to_owned(), {});", table, key, toml_param_value(param, "value"))?;
+                writeln!(output, "        }}")?;
+            },
+            Optionality::Optional => {
+                writeln!(output, "        if let Some(value) = &self.{} {{", field)?;
+                writeln!(output, "            {}.insert(\"{}\".to_owned(), {});", table, key, toml_param_value(param, "value"))?;
+                writeln!(output, "        }}")?;
+            },
+            _ => writeln!(output, "        {}.insert(\"{}\".to_owned(), {});", table, key, toml_param_value(param, &format!("&self.{}", field)))?,
+        }
+    }
+    Ok(())
+}
+
+fn gen_redacted_rendering<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Formats the values of params and switches into a single line, e.g. for logs")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Unset optional params are skipped, the values of secret params are redacted.")?;
+    writeln!(output, "    pub fn to_redacted_string(&self) -> String {{")?;
+    writeln!(output, "        let mut fields = Vec::new();")?;
+    for param in &config.params {
+        let name = param.name.as_snake_case();
+        let field = param.field_path();
+        match &param.optionality {
+            Optionality::Optional if param.secret => {
+                writeln!(output, "        if self.{}.is_some() {{", field)?;
+                writeln!(output, "            fields.push((\"{}\", {}));", name, format_param_value(param, "value"))?;
+                writeln!(output, "        }}")?;
+            },
+            Optionality::Optional => {
+                writeln!(output, "        if let Some(value) = &self.{} {{", field)?;
+                writeln!(output, "            fields.push((\"{}\", {}));", name, format_param_value(param, "value"))?;
+                writeln!(output, "        }}")?;
+            },
+            _ => writeln!(output, "        fields.push((\"{}\", {}));", name, format_param_value(param, &format!("&self.{}", field)))?,
+        }
+    }
+    for switch in &config.switches {
+        let name = switch.name.as_snake_case();
+        writeln!(output, "        fields.push((\"{}\", {}.to_string()));", name, switch_value(switch, "self"))?;
+    }
+    writeln!(output, "        ::configure_me::helpers::join_fields(&fields)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Formats the configuration as a config file")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Unset optional params are skipped, the values of secret params are redacted.")?;
+    writeln!(output, "    pub fn to_redacted_toml(&self) -> Result<String, ::configure_me::toml::ser::Error> {{")?;
+    writeln!(output, "        let mut table = ::configure_me::toml::value::Table::new();")?;
+    write_toml_params(&mut output, config.section_params(None), "table")?;
+    for switch in &config.switches {
+        let name = switch.name.as_snake_case();
+        writeln!(output, "        table.insert(\"{}\".to_owned(), ::configure_me::toml::Value::try_from({})?);", name, switch_value(switch, "self"))?;
+    }
+    for section in &config.sections {
+        let name = section.name.as_snake_case();
+        let section_table = format!("{}_table", name);
+        writeln!(output, "        let mut {} = ::configure_me::toml::value::Table::new();", section_table)?;
+        write_toml_params(&mut output, config.section_params(Some(&section.name)), &section_table)?;
+        writeln!(output, "        if !{}.is_empty() {{", section_table)?;
+        writeln!(output, "            table.insert(\"{}\".to_owned(), ::configure_me::toml::Value::Table({}));", name, section_table)?;
+        writeln!(output, "        }}")?;
+    }
+    let path = match &config.general.config_table {
+        Some(table) => format!("{:?}", table.split('.').collect::<Vec<_>>()),
+        None => "[]".to_owned(),
+    };
+    writeln!(output, "        ::configure_me::helpers::format_toml(table, &{})", path)?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+/// Writes the fields of `params` passed to `DebugStruct`, the values of secret params are redacted
+fn write_debug_params<'a, W: Write, I: IntoIterator<Item=&'a ::config::Param>>(mut output: W, params: I) -> fmt::Result {
+    for param in params {
+        let key = param.key();
+        match &param.optionality {
+            Optionality::Optional if param.secret => writeln!(output, "            .field(\"{}\", &self.{}.as_ref().map(|_| ::configure_me::helpers::Redacted))", key, key)?,
+            _ if param.secret => writeln!(output, "            .field(\"{}\", &::configure_me::helpers::Redacted)", key)?,
+            _ => writeln!(output, "            .field(\"{}\", &self.{})", key, key)?,
+        }
+    }
+    Ok(())
+}
+
+fn gen_debug<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl ::std::fmt::Debug for Config {{")?;
+    writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        f.debug_struct(\"Config\")")?;
+    write_debug_params(&mut output, config.section_params(None))?;
+    for name in config.switches.iter().map(|switch| &switch.name).chain(config.positionals.iter().map(|positional| &positional.name)) {
+        writeln!(output, "            .field(\"{}\", &self.{})", name.as_snake_case(), name.as_snake_case())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "            .field(\"subcommand\", &self.subcommand)")?;
+    }
+    for section in &config.sections {
+        writeln!(output, "            .field(\"{}\", &self.{})", section.name.as_snake_case(), section.name.as_snake_case())?;
+    }
+    writeln!(output, "            .finish()")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    for section in &config.sections {
+        writeln!(output)?;
+        writeln!(output, "impl ::std::fmt::Debug for {} {{", section.name.as_pascal_case())?;
+        writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+        writeln!(output, "        f.debug_struct(\"{}\")", section.name.as_pascal_case())?;
+        write_debug_params(&mut output, config.section_params(Some(&section.name)))?;
+        writeln!(output, "            .finish()")?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+    }
+    // The configurations of subcommands inherit `general.debug`
+    if !config.subcommands.is_empty() {
+        writeln!(output)?;
+        writeln!(output, "impl ::std::fmt::Debug for Subcommand {{")?;
+        writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+        writeln!(output, "        match self {{")?;
+        for subcommand in &config.subcommands {
+            let variant = subcommand.name.as_pascal_case();
+            writeln!(output, "            Subcommand::{}(config) => f.debug_tuple(\"{}\").field(config).finish(),", variant, variant)?;
+        }
+        writeln!(output, "        }}")?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+    }
+    Ok(())
+}
+
+/// Expression serializing the value of the param behind the reference `value` like config files
+/// expect it if the type of the field isn't serialized that way
+fn serialize_as_arg(param: &::config::Param, value: &str) -> Option<String> {
+    if param.convert_into != param.ty || !(param.is_os_string() || param.is_duration()) {
+        None
+    } else if param.map {
+        Some(format!("::std::iter::IntoIterator::into_iter({}).map(|(key, value)| (key, ::configure_me::helpers::SerializeAsArg(value))).collect::<::std::collections::BTreeMap<_, _>>()", value))
+    } else if param.has_multiple_values() {
+        Some(format!("::std::iter::IntoIterator::into_iter({}).map(::configure_me::helpers::SerializeAsArg).collect::<Vec<_>>()", value))
+    } else {
+        Some(format!("::configure_me::helpers::SerializeAsArg({})", value))
+    }
+}
+
+/// Writes the fields of `params` passed to `SerializeStruct`, the values of secret params are
+/// redacted
+fn write_serialize_params<'a, W: Write, I: IntoIterator<Item=&'a ::config::Param>>(mut output: W, params: I) -> fmt::Result {
+    for param in params {
+        let key = param.key();
+        let field = format!("&self.{}", key);
+        let value = match (param.secret, &param.optionality) {
+            (true, Optionality::Optional) => format!("&self.{}.as_ref().map(|_| ::configure_me::helpers::REDACTED)", key),
+            (true, _) => "&::configure_me::helpers::REDACTED".to_owned(),
+            (false, Optionality::Optional) => match serialize_as_arg(param, "value") {
+                Some(converted) => format!("&self.{}.as_ref().map(|value| {})", key, converted),
+                None => field,
+            },
+            (false, _) => serialize_as_arg(param, &field).map_or(field, |converted| format!("&{}", converted)),
+        };
+        writeln!(output, "        state.serialize_field(\"{}\", {})?;", key, value)?;
+    }
+    Ok(())
+}
+
+fn gen_serialize<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let top_level = config.section_params(None).count() + config.switches.len() + config.sections.len();
+    writeln!(output)?;
+    writeln!(output, "impl ::configure_me::serde::Serialize for Config {{")?;
+    writeln!(output, "    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+    writeln!(output, "        use ::configure_me::serde::ser::SerializeStruct;")?;
+    writeln!(output)?;
+    writeln!(output, "        let mut state = serializer.serialize_struct(\"Config\", {})?;", top_level)?;
+    write_serialize_params(&mut output, config.section_params(None).filter(|param| !param.map))?;
+    for switch in &config.switches {
+        writeln!(output, "        state.serialize_field(\"{}\", &{})?;", switch.name.as_snake_case(), switch_value(switch, "self"))?;
+    }
+    // Maps are tables, which can't be followed by other values in config files
+    write_serialize_params(&mut output, config.section_params(None).filter(|param| param.map))?;
+    for section in &config.sections {
+        writeln!(output, "        state.serialize_field(\"{}\", &self.{})?;", section.name.as_snake_case(), section.name.as_snake_case())?;
+    }
+    writeln!(output, "        state.end()")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    for section in &config.sections {
+        let ty = section.name.as_pascal_case();
+        writeln!(output)?;
+        writeln!(output, "impl ::configure_me::serde::Serialize for {} {{", ty)?;
+        writeln!(output, "    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(output, "        use ::configure_me::serde::ser::SerializeStruct;")?;
+        writeln!(output)?;
+        writeln!(output, "        let mut state = serializer.serialize_struct(\"{}\", {})?;", ty, config.section_params(Some(&section.name)).count())?;
+        write_serialize_params(&mut output, config.section_params(Some(&section.name)).filter(|param| !param.map))?;
+        write_serialize_params(&mut output, config.section_params(Some(&section.name)).filter(|param| param.map))?;
+        writeln!(output, "        state.end()")?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+    }
+    Ok(())
+}
+
+fn write_wizard_params<'a, W: Write, I: IntoIterator<Item=&'a ::config::Param>>(mut output: W, params: I) -> fmt::Result {
+    for param in params {
+        let name = param.key();
+        let doc = format!("{:?}", param.doc);
+        let default = match &param.optionality {
+            Optionality::DefaultValue(default) if param.map => format!("Some(&::configure_me::helpers::format_map(&{{ let default: {} = {{ {} }}; default }}))", param.field_type(), default),
+            Optionality::DefaultValue(default) if param.has_multiple_values() => format!("Some(&::configure_me::helpers::format_values(&{{ let default: {} = {{ {} }}; default }}))", param.field_type(), default),
+            Optionality::DefaultValue(default) => format!("Some(&::configure_me::helpers::format_value(&{{ let default: {} = {{ {} }}; default }}))", param.ty, default),
+            _ => "None".to_owned(),
+        };
+        let mandatory = matches!(param.optionality, Optionality::Mandatory);
+        if param.map {
+            writeln!(output, "        wizard.param_map::<{}, {}>(\"{}\", {}, {}, {})?;", param.ty, param.field_type(), name, doc, default, mandatory)?;
+        } else if param.has_multiple_values() {
+            writeln!(output, "        wizard.param_values::<{}, {}>(\"{}\", {}, {}, {})?;", param.ty, param.field_type(), name, doc, default, mandatory)?;
+        } else {
+            // Config files store OsString and durations as strings
+            let ty = config_file_conversion(param).map_or(param.ty.as_str(), |(ty, _)| ty);
+            writeln!(output, "        wizard.param::<{}>(\"{}\", {}, {}, {})?;", ty, name, doc, default, mandatory)?;
+        }
+    }
+    Ok(())
+}
+
+fn gen_interactive_setup<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Asks the user for the values of params and switches and writes them into a new config file")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Fails if the file already exists.")?;
+    writeln!(output, "    pub fn interactive_setup<P: AsRef<::std::path::Path>>(path: P) -> ::std::io::Result<()> {{")?;
+    writeln!(output, "        let stdin = ::std::io::stdin();")?;
+    writeln!(output, "        let stdout = ::std::io::stdout();")?;
+    writeln!(output, "        Self::interactive_setup_with(path, stdin.lock(), stdout.lock())")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Like `interactive_setup()`, reading the answers from `input` and writing the questions to `output`")?;
+    writeln!(output, "    pub fn interactive_setup_with<P: AsRef<::std::path::Path>, R: ::std::io::BufRead, W: ::std::io::Write>(path: P, input: R, output: W) -> ::std::io::Result<()> {{")?;
+    writeln!(output, "        let mut wizard = ::configure_me::helpers::Wizard::new(input, output);")?;
+    if let Some(table) = &config.general.config_table {
+        writeln!(output, "        wizard.table(\"{}\");", table)?;
+    }
+    write_wizard_params(&mut output, config.section_params(None))?;
+    for switch in &config.switches {
+        let doc = format!("{:?}", switch.doc);
+        if switch.is_count() {
+            writeln!(output, "        wizard.count_switch(\"{}\", {})?;", switch.name.as_snake_case(), doc)?;
+        } else {
+            writeln!(output, "        wizard.switch(\"{}\", {}, {}, raw::TRUE_VALUES, raw::FALSE_VALUES)?;", switch.name.as_snake_case(), doc, switch.is_inverted())?;
+        }
+    }
+    // The keys following a table header belong to the table, so sections go last
+    for section in &config.sections {
+        match &config.general.config_table {
+            Some(table) => writeln!(output, "        wizard.table(\"{}.{}\");", table, section.name.as_snake_case())?,
+            None => writeln!(output, "        wizard.table(\"{}\");", section.name.as_snake_case())?,
+        }
+        write_wizard_params(&mut output, config.section_params(Some(&section.name)))?;
+    }
+    writeln!(output, "        wizard.write(path)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+/// Whether the arguments are collected before loading config files to look for switches exiting
+/// the program
+fn has_exiting_switches(config: &Config) -> bool {
+    config.general.check_config_switch.is_some() || config.general.dump_config_switch.is_some()
+}
+
+/// Whether the arguments are searched for options before loading config files
+fn scans_args(config: &Config) -> bool {
+    has_exiting_switches(config) || config.general.profile_param.is_some()
+}
+
+/// Options taking values with the minimum and maximum count of them, the values are skipped when
+/// the arguments are searched before parsing them
+fn valued_options(config: &Config) -> Vec<(String, usize, usize)> {
+    let mut options = config.general.conf_file_param.iter()
+        .chain(&config.general.conf_dir_param)
+        .chain(&config.general.profile_param)
+        .map(|param| (param_long_raw(param.as_snake_case()), 1, 1))
+        .collect::<Vec<_>>();
+    for param in config.params.iter().filter(|param| param.argument) {
+        let (min, max) = match param.nargs {
+            Some(::config::Nargs::Fixed(count)) => (count, count),
+            Some(::config::Nargs::Bounded { min, max }) => (min, max),
+            None => (1, 1),
+        };
+        let names = ::std::iter::once(param_long(param)).chain(param_aliases(param)).chain(param_short(param));
+        options.extend(names.map(|name| (name, min, max)));
+    }
+    options
+}
+
+/// The arguments searched for the options needed before loading config files, long options are
+/// normalized the same way as when parsing the arguments
+fn scanned_args(config: &Config) -> &'static str {
+    if normalizes_long_options(config) {
+        "&::configure_me::helpers::normalize_long_options(&args, raw::normalize_long_option)"
+    } else {
+        "&args"
+    }
+}
+
+fn gen_check_config_call<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if !has_exiting_switches(config) {
+        return Ok(());
+    }
+    // The switches have to be found before loading config files, which could fail
+    writeln!(output, "        let args = args.into_iter().map(Into::into).collect::<Vec<::std::ffi::OsString>>();")?;
+    if let Some(check) = &config.general.check_config_switch {
+        writeln!(output, "        if ::configure_me::helpers::has_switch({}, \"--{}\", raw::VALUED_OPTIONS) {{", scanned_args(config), check.as_hypenated())?;
+        writeln!(output, "            Self::check_config(args, config_files);")?;
+        writeln!(output, "        }}")?;
+    }
+    if let Some(dump) = &config.general.dump_config_switch {
+        writeln!(output, "        if ::configure_me::helpers::has_switch({}, \"--{}\", raw::VALUED_OPTIONS) {{", scanned_args(config), dump.as_hypenated())?;
+        writeln!(output, "            Self::dump_config(args, config_files);")?;
+        writeln!(output, "        }}")?;
+    }
+    writeln!(output)
+}
+
+/// Name of the environment variable selecting the profile of config files
+fn profile_env_var(config: &Config, profile: &Ident) -> Result<String, fmt::Error> {
+    let mut name = String::new();
+    config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut name, prefix)?; write!(name, "_") }).unwrap_or(Ok(()))?;
+    write!(name, "{}", profile.as_upper_case())?;
+    Ok(name)
+}
+
+/// Selects the profile before loading config files, the arguments are parsed only after that
+fn gen_profile_init<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if let Some(profile) = &config.general.profile_param {
+        if !has_exiting_switches(config) && !config.general.reload_if_changed {
+            writeln!(output, "        let args = args.into_iter().map(Into::into).collect::<Vec<::std::ffi::OsString>>();")?;
+        }
+        writeln!(output, "        config._profile = ::configure_me::helpers::selected_profile({}, \"--{}\", raw::VALUED_OPTIONS, ::std::env::var_os(\"{}\"));", scanned_args(config), profile.as_hypenated(), profile_env_var(config, profile)?)?;
+    }
+    Ok(())
+}
+
+/// Keeps the arguments and config files so that the configuration can be reloaded
+fn gen_poll_init<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if !has_exiting_switches(config) {
+        writeln!(output, "        let args = args.into_iter().map(Into::into).collect::<Vec<::std::ffi::OsString>>();")?;
+    }
+    writeln!(output, "        let watched_args = args.clone();")?;
+    writeln!(output, "        let mut watched_files = Vec::new();")?;
+    writeln!(output, "        let config_files = config_files.into_iter().inspect(|path| watched_files.push(path.as_ref().to_owned()));")?;
+    writeln!(output)
+}
+
+fn write_doc_comment<W: Write>(mut output: W, indent: &str, doc: &Option<String>) -> fmt::Result {
+    for line in doc.iter().flat_map(|doc| doc.lines()) {
+        if line.is_empty() {
+            writeln!(output, "{}///", indent)?;
+        } else {
+            writeln!(output, "{}/// {}", indent, line)?;
+        }
+    }
+    Ok(())
+}
+
+fn gen_sections<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for section in &config.sections {
+        writeln!(output)?;
+        write_doc_comment(&mut output, "", &section.doc)?;
+        writeln!(output, "pub struct {} {{", section.name.as_pascal_case())?;
+        visitor::iter::<visitor::ConfigFinal, _, _>(config.section_params(Some(&section.name)), &mut output)?;
+        writeln!(output, "}}")?;
+    }
+    Ok(())
+}
+
+/// Errors of subcommands are reported by their own `unwrap_or_exit()`, so that asking them for
+/// help succeeds
+fn gen_subcommand_errors<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for subcommand in &config.subcommands {
+        writeln!(output, "            Err(Error::Arguments(ArgParseError::Subcommand{}(err))) => {}::ResultExt::unwrap_or_exit(Err(*err)),", subcommand.name.as_pascal_case(), subcommand.name.as_snake_case())?;
+    }
+    Ok(())
+}
+
+/// Generates the enum of subcommands and a module with the configuration of each of them
+fn gen_subcommands<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if config.subcommands.is_empty() {
+        return Ok(());
+    }
+    writeln!(output)?;
+    writeln!(output, "/// Subcommand selected by the first argument after the options")?;
+    writeln!(output, "pub enum Subcommand {{")?;
+    for subcommand in &config.subcommands {
+        write_doc_comment(&mut output, "    ", &subcommand.doc)?;
+        writeln!(output, "    {}({}::Config),", subcommand.name.as_pascal_case(), subcommand.name.as_snake_case())?;
+    }
+    writeln!(output, "}}")?;
+    for subcommand in &config.subcommands {
+        writeln!(output)?;
+        write_doc_comment(&mut output, "", &subcommand.doc)?;
+        writeln!(output, "pub mod {} {{", subcommand.name.as_snake_case())?;
+        // Types used by the specification are resolved like in the parent
+        writeln!(output, "    #[allow(unused_imports)]")?;
+        writeln!(output, "    use super::*;")?;
+        writeln!(output)?;
+        let mut code = String::new();
+        generate_code(&subcommand.config, &mut code)?;
+        for line in code.lines() {
+            if line.is_empty() {
+                writeln!(output)?;
+            } else {
+                writeln!(output, "    {}", line)?;
+            }
+        }
+        writeln!(output, "}}")?;
+    }
+    Ok(())
+}
+
+fn gen_enums<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for param in config.params.iter().filter(|param| param.values.is_some()) {
+        writeln!(output, "/// Possible values of `{}`", param.name.as_snake_case())?;
+        write_enum(config, &param.ty, &param.variants(), &mut output)?;
+    }
+    for switch in config.switches.iter().filter(|switch| switch.values.is_some()) {
+        let ty = switch.map_to.as_ref().expect("values of a switch require map_to");
+        writeln!(output, "/// Values of `{}` for the counts of its occurrences", switch.name.as_snake_case())?;
+        write_enum(config, ty, &switch.variants(), &mut output)?;
+    }
+    Ok(())
+}
+
+/// Writes the enum `ty` with the variants and the values they represent
+fn write_enum<W: Write>(config: &Config, ty: &str, variants: &[(String, &str)], mut output: W) -> fmt::Result {
+    writeln!(output, "#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]")?;
+    writeln!(output, "pub enum {} {{", ty)?;
+    for (variant, _) in variants {
+        writeln!(output, "    {},", variant)?;
+    }
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl {} {{", ty)?;
+    writeln!(output, "    /// Values accepted in arguments, environment variables and config files")?;
+    write!(output, "    pub const VALUES: &'static [&'static str] = ")?;
+    write_str_slice(&mut output, &variants.iter().map(|(_, value)| *value).collect::<Vec<_>>())?;
+    writeln!(output, ";")?;
+    writeln!(output)?;
+    writeln!(output, "    pub fn as_str(&self) -> &'static str {{")?;
+    writeln!(output, "        match self {{")?;
+    for (variant, value) in variants {
+        writeln!(output, "            {}::{} => \"{}\",", ty, variant, value)?;
+    }
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl ::std::str::FromStr for {} {{", ty)?;
+    writeln!(output, "    type Err = ::configure_me::helpers::InvalidValue;")?;
+    writeln!(output)?;
+    writeln!(output, "    fn from_str(value: &str) -> Result<Self, Self::Err> {{")?;
+    writeln!(output, "        match value {{")?;
+    for (variant, value) in variants {
+        writeln!(output, "            \"{}\" => Ok({}::{}),", value, ty, variant)?;
+    }
+    writeln!(output, "            _ => Err(::configure_me::helpers::InvalidValue::new(value, Self::VALUES)),")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl ::std::fmt::Display for {} {{", ty)?;
+    writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        f.write_str(self.as_str())")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl ::configure_me::parse_arg::ParseArgFromStr for {} {{", ty)?;
+    writeln!(output, "    fn describe_type<W: ::std::fmt::Write>(writer: W) -> ::std::fmt::Result {{")?;
+    writeln!(output, "        ::configure_me::helpers::describe_values(writer, Self::VALUES)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl ::configure_me::ToArgDisplay for {} {{}}", ty)?;
+    writeln!(output)?;
+    writeln!(output, "impl<'de> ::configure_me::serde::Deserialize<'de> for {} {{", ty)?;
+    writeln!(output, "    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+    writeln!(output, "        ::configure_me::helpers::deserialize_from_str(deserializer)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl ::configure_me::serde::Serialize for {} {{", ty)?;
+    writeln!(output, "    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+    writeln!(output, "        serializer.serialize_str(self.as_str())")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    if config.general.arbitrary {
+        writeln!(output, "::configure_me::if_test_helpers! {{")?;
+        writeln!(output, "    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for {} {{", ty)?;
+        writeln!(output, "        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {{")?;
+        let choices = variants.iter().map(|(variant, _)| format!("{}::{}", ty, variant)).collect::<Vec<_>>();
+        writeln!(output, "            u.choose(&[{}]).copied()", choices.join(", "))?;
+        writeln!(output, "        }}")?;
+        writeln!(output, "    }}")?;
+        writeln!(output, "}}")?;
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+/// The generated enums are defined outside of the `raw` module
+fn gen_raw_imports<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    for param in config.params.iter().filter(|param| param.values.is_some()) {
+        writeln!(output, "    use super::{};", param.ty)?;
+    }
+    for switch in config.switches.iter().filter(|switch| switch.values.is_some()) {
+        writeln!(output, "    use super::{};", switch.map_to.as_ref().expect("values of a switch require map_to"))?;
+    }
+    Ok(())
+}
+
+fn gen_arbitrary<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "::configure_me::if_test_helpers! {{")?;
+    writeln!(output, "    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for Config {{")?;
+    writeln!(output, "        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {{")?;
+    writeln!(output, "            Ok(Config {{")?;
+    write_arbitrary_params(&mut output, config.section_params(None))?;
+    for switch in &config.switches {
+        // Counts above the maximum or the last mapped value would be invalid or redundant
+        let max = switch.map_values.len().checked_sub(1).map(|max| max as u32).into_iter().chain(switch.max_count).min();
+        match max {
+            Some(max) if switch.map_to.is_some() => writeln!(output, "                {}: {{ let count: u32 = u.int_in_range(0..={})?; {} }},", switch.name.as_snake_case(), max, map_count(switch, "count"))?,
+            Some(max) => writeln!(output, "                {}: u.int_in_range(0..={})?,", switch.name.as_snake_case(), max)?,
+            None => writeln!(output, "                {}: u.arbitrary()?,", switch.name.as_snake_case())?,
+        }
+    }
+    for positional in &config.positionals {
+        writeln!(output, "                {}: u.arbitrary()?,", positional.name.as_snake_case())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "                subcommand: None,")?;
+    }
+    for section in &config.sections {
+        writeln!(output, "                {}: u.arbitrary()?,", section.name.as_snake_case())?;
+    }
+    if config.general.reload_if_changed {
+        writeln!(output, "                _poll_state: ::configure_me::helpers::PollState::new(&[]),")?;
+    }
+    if config.general.loaded_config_files {
+        writeln!(output, "                _loaded_config_files: Vec::new(),")?;
+    }
+    if config.general.explain_config_switch.is_some() {
+        writeln!(output, "                _overrides: Vec::new(),")?;
+    }
+    writeln!(output, "            }})")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    for section in &config.sections {
+        writeln!(output)?;
+        writeln!(output, "    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for {} {{", section.name.as_pascal_case())?;
+        writeln!(output, "        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {{")?;
+        writeln!(output, "            Ok({} {{", section.name.as_pascal_case())?;
+        write_arbitrary_params(&mut output, config.section_params(Some(&section.name)))?;
+        writeln!(output, "            }})")?;
+        writeln!(output, "        }}")?;
+        writeln!(output, "    }}")?;
+    }
+    writeln!(output, "}}")
+}
+
+fn write_arbitrary_params<'a, W: Write, I: IntoIterator<Item=&'a ::config::Param>>(mut output: W, params: I) -> fmt::Result {
+    for param in params {
+        let name = param.key();
+        match param.nargs {
+            // Respect the declared number of values unless the field is converted
+            Some(Nargs::Bounded { min, max }) if param.convert_into == param.field_type() => {
+                let values = format!("::configure_me::helpers::arbitrary_values(u, {}, {})?", min, max);
+                match param.optionality {
+                    Optionality::Optional => writeln!(output, "                {}: if u.arbitrary()? {{ Some({}) }} else {{ None }},", name, values)?,
+                    _ => writeln!(output, "                {}: {},", name, values)?,
+                }
+            },
+            _ => writeln!(output, "                {}: u.arbitrary()?,", name)?,
+        }
+    }
+    Ok(())
+}
+
+fn gen_reload_if_changed<W: Write>(mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Loads the configuration again if any config file changed since it was loaded")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// The same arguments and config files are used, environment variables are read again.")?;
+    writeln!(output, "    pub fn reload_if_changed(&self) -> Result<Option<Config>, Error> {{")?;
+    writeln!(output, "        if !self._poll_state.changed() {{")?;
+    writeln!(output, "            return Ok(None);")?;
+    writeln!(output, "        }}")?;
+    writeln!(output)?;
+    writeln!(output, "        Self::custom_args_and_optional_files(self._poll_state.args().to_vec(), self._poll_state.optional_files())")?;
+    writeln!(output, "            .map(|(config, _)| Some(config))")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_watch<W: Write>(mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Calls `on_change` with the configuration loaded again whenever a config file changes, until")?;
+    writeln!(output, "    /// the returned watcher is dropped")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// `on_change` is called from another thread. Only the config files of this configuration are")?;
+    writeln!(output, "    /// watched, the changes are detected like in `reload_if_changed()`.")?;
+    writeln!(output, "    pub fn watch<F>(&self, mut on_change: F) -> Result<::configure_me::ConfigWatcher, ::std::io::Error> where F: FnMut(Result<Config, Error>) + Send + 'static {{")?;
+    writeln!(output, "        let mut poll_state = self._poll_state.clone();")?;
+    writeln!(output, "        ::configure_me::helpers::watch_files(self._poll_state.files(), move || {{")?;
+    writeln!(output, "            if !poll_state.changed() {{")?;
+    writeln!(output, "                return;")?;
+    writeln!(output, "            }}")?;
+    writeln!(output)?;
+    writeln!(output, "            let config = Self::custom_args_and_optional_files(poll_state.args().to_vec(), poll_state.optional_files()).map(|(config, _)| config);")?;
+    writeln!(output, "            if let Ok(config) = &config {{")?;
+    writeln!(output, "                poll_state = config._poll_state.clone();")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "            on_change(config);")?;
+    writeln!(output, "        }})")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_user_config<W: Write>(name: &str, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Loads the config files of the user and the system")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// `$XDG_CONFIG_HOME/{0}/config.toml`, or `~/.config/{0}/config.toml` if the variable isn't set,", name)?;
+    writeln!(output, "    /// overrides `/etc/{}/config.toml`.", name)?;
+    writeln!(output, "    /// On Windows, `%APPDATA%\\{0}\\config.toml` overrides `%PROGRAMDATA%\\{0}\\config.toml`.", name)?;
+    writeln!(output, "    pub fn including_user_config() -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> {{")?;
+    writeln!(output, "        Self::including_optional_config_files(::configure_me::helpers::user_config_files({:?}))", name)?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_loaded_config_files<W: Write>(mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Paths of the config files that were loaded, in the order they were read")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Missing optional files aren't included, the files given in arguments come last.")?;
+    writeln!(output, "    pub fn loaded_config_files(&self) -> &[::std::path::PathBuf] {{")?;
+    writeln!(output, "        &self._loaded_config_files")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_overrides<W: Write>(explain: &Ident, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Values overridden by a source with a higher priority, empty unless `--{}` was passed", explain.as_hypenated())?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// Params with `merge_fn` and count switches combine their values, so they aren't included.")?;
+    writeln!(output, "    pub fn overrides(&self) -> &[::configure_me::helpers::Override] {{")?;
+    writeln!(output, "        &self._overrides")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_partial_config<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "/// Configuration that isn't validated yet")?;
+    writeln!(output, "///")?;
+    writeln!(output, "/// Layers loaded from different sources are merged in the order of their priority and then")?;
+    writeln!(output, "/// finalized, which applies the defaults and validates the result.")?;
+    writeln!(output, "#[derive(Default)]")?;
+    writeln!(output, "pub struct PartialConfig(raw::Config);")?;
+    writeln!(output)?;
+    writeln!(output, "impl PartialConfig {{")?;
+    writeln!(output, "    /// Loads the config file, which has to exist")?;
+    writeln!(output, "    pub fn from_file<P: AsRef<::std::path::Path>>(path: P) -> Result<Self, Error> {{")?;
+    writeln!(output, "        let path = path.as_ref();")?;
+    writeln!(output, "        let mut config = raw::Config::default();")?;
+    if config.general.json_config {
+        writeln!(output, "        let loaded = config.load_once_as(path, ::configure_me::ConfigFormat::of(path));")?;
+    } else {
+        writeln!(output, "        let loaded = config.load_once(path);")?;
+    }
+    for step in load_steps(&config.general) {
+        writeln!(output, "        let loaded = config.{}(path, loaded);", step)?;
+    }
+    writeln!(output, "        loaded.map(PartialConfig)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Parses `content` in the format of config files, `name` identifies the source in errors")?;
+    writeln!(output, "    pub fn from_toml_str(name: &str, content: &str) -> Result<Self, Error> {{")?;
+    writeln!(output, "        raw::Config::default().parse(name.as_ref(), content.as_bytes()).map(PartialConfig)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Loads the values of environment variables")?;
+    writeln!(output, "    pub fn from_env() -> Result<Self, Error> {{")?;
+    writeln!(output, "        let mut config = raw::Config::default();")?;
+    writeln!(output, "        config.merge_env()?;")?;
+    writeln!(output, "        Ok(PartialConfig(config))")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Parses the arguments, starting with the program name, and returns the ones not consumed")?;
+    writeln!(output, "    pub fn from_args<A>(args: A) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where A: IntoIterator, A::Item: Into<::std::ffi::OsString> {{")?;
+    writeln!(output, "        let mut config = raw::Config::default();")?;
+    writeln!(output, "        let remaining_args = config.merge_args(args.into_iter().map(Into::into))?;")?;
+    if !config.positionals.is_empty() {
+        writeln!(output, "        let remaining_args = config.merge_positionals(remaining_args)?;")?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "        let remaining_args = config.merge_subcommand(remaining_args)?;")?;
+    }
+    writeln!(output, "        Ok((PartialConfig(config), remaining_args))")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Merges the layers, the values of `other` take precedence")?;
+    writeln!(output, "    pub fn merge(mut self, other: Self) -> Self {{")?;
+    writeln!(output, "        self.0.merge_in(other.0);")?;
+    writeln!(output, "        self")?;
+    writeln!(output, "    }}")?;
+    writeln!(output)?;
+    writeln!(output, "    /// Applies the defaults and validates the configuration")?;
+    writeln!(output, "    pub fn try_finalize(self) -> Result<Config, Error> {{")?;
+    writeln!(output, "        self.0.validate().map_err(Into::into)")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_env_file<W: Write>(env_file: &str, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "static ENV_FILE: ::std::sync::Mutex<Option<::std::path::PathBuf>> = ::std::sync::Mutex::new(None);")?;
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Sets the path of the `.env` file loaded with the environment variables, `{}` by default", env_file)?;
+    writeln!(output, "    pub fn set_env_file<P: Into<::std::path::PathBuf>>(path: P) {{")?;
+    writeln!(output, "        *ENV_FILE.lock().unwrap_or_else(|err| err.into_inner()) = Some(path.into());")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+/// Removes the switch exiting the program from the arguments before loading the configuration
+fn write_remove_switch<W: Write>(config: &Config, switch: &str, mut output: W) -> fmt::Result {
+    if normalizes_long_options(config) {
+        writeln!(output, "        let args = ::configure_me::helpers::remove_normalized_switch(args, \"{}\", raw::normalize_long_option);", switch)
+    } else {
+        writeln!(output, "        let args = ::configure_me::helpers::remove_switch(args, \"{}\");", switch)
+    }
+}
+
+fn gen_check_config<W: Write>(config: &Config, check: &str, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Loads and validates the configuration and exits with a report instead of returning")?;
+    writeln!(output, "    fn check_config<I>(args: Vec<::std::ffi::OsString>, config_files: I) -> ! where I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
+    writeln!(output, "        use std::io::Write;")?;
+    writeln!(output)?;
+    write_remove_switch(config, check, &mut output)?;
+    writeln!(output, "        match Self::custom_args_and_optional_files(args, config_files) {{")?;
+    writeln!(output, "            Ok(_) => {{")?;
+    writeln!(output, "                println!(\"The configuration is valid.\");")?;
+    writeln!(output, "                std::io::stdout().flush().expect(\"failed to flush stdout\");")?;
+    writeln!(output, "                ::std::process::exit(0)")?;
+    writeln!(output, "            }},")?;
+    writeln!(output, "            Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {{")?;
+    writeln!(output, "                println!(\"{{}}\", err);")?;
+    writeln!(output, "                std::io::stdout().flush().expect(\"failed to flush stdout\");")?;
+    writeln!(output, "                ::std::process::exit(0)")?;
+    writeln!(output, "            }},")?;
+    writeln!(output, "            Err(err) => {{")?;
+    writeln!(output, "                eprintln!(\"Error: {{}}\", err);")?;
+    writeln!(output, "                eprintln!(\"The configuration is invalid.\");")?;
+    writeln!(output, "                std::io::stderr().flush().expect(\"failed to flush stderr\");")?;
+    writeln!(output, "                ::std::process::exit(1)")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+fn gen_dump_config<W: Write>(config: &Config, dump: &str, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Loads and validates the configuration and exits printing it as a config file instead of")?;
+    writeln!(output, "    /// returning, the values of secret params are redacted")?;
+    writeln!(output, "    fn dump_config<I>(args: Vec<::std::ffi::OsString>, config_files: I) -> ! where I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
+    writeln!(output, "        use std::io::Write;")?;
+    writeln!(output)?;
+    write_remove_switch(config, dump, &mut output)?;
+    writeln!(output, "        let config = Self::custom_args_and_optional_files(args, config_files).map(|(config, _)| config);")?;
+    writeln!(output, "        match config.map(|config| config.to_redacted_toml()) {{")?;
+    writeln!(output, "            Ok(Ok(toml)) => {{")?;
+    writeln!(output, "                print!(\"{{}}\", toml);")?;
+    writeln!(output, "                std::io::stdout().flush().expect(\"failed to flush stdout\");")?;
+    writeln!(output, "                ::std::process::exit(0)")?;
+    writeln!(output, "            }},")?;
+    writeln!(output, "            Ok(Err(err)) => {{")?;
+    writeln!(output, "                eprintln!(\"Error: failed to format the configuration: {{}}\", err);")?;
+    writeln!(output, "                std::io::stderr().flush().expect(\"failed to flush stderr\");")?;
+    writeln!(output, "                ::std::process::exit(1)")?;
+    writeln!(output, "            }},")?;
+    writeln!(output, "            Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {{")?;
+    writeln!(output, "                println!(\"{{}}\", err);")?;
+    writeln!(output, "                std::io::stdout().flush().expect(\"failed to flush stdout\");")?;
+    writeln!(output, "                ::std::process::exit(0)")?;
+    writeln!(output, "            }},")?;
+    writeln!(output, "            Err(err) => {{")?;
+    writeln!(output, "                eprintln!(\"Error: {{}}\", err);")?;
+    writeln!(output, "                std::io::stderr().flush().expect(\"failed to flush stderr\");")?;
+    writeln!(output, "                ::std::process::exit(1)")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+/// Generates `merge_env_with()` merging environment variables looked up using a closure
+fn gen_merge_env_with<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    impl Config {{")?;
+    if has_env_maps(config) {
+        writeln!(output, "        /// Merges environment variables looked up using `var`, the entries of maps are taken from `vars`")?;
+        writeln!(output, "        pub fn merge_env_with<F: Fn(&str) -> Option<::std::ffi::OsString>, V: IntoIterator<Item=(::std::ffi::OsString, ::std::ffi::OsString)>>(&mut self, var: F, vars: V) -> Result<(), super::Error> {{")?;
+        // Every map scans all of them
+        writeln!(output, "            let vars = vars.into_iter().collect::<Vec<_>>();")?;
+    } else {
+        writeln!(output, "        /// Merges environment variables looked up using `var`")?;
+        writeln!(output, "        pub fn merge_env_with<F: Fn(&str) -> Option<::std::ffi::OsString>>(&mut self, var: F) -> Result<(), super::Error> {{")?;
+    }
+    gen_merge_env(config, &mut output, Some("var"))?;
+    writeln!(output, "            Ok(())")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "    }}")
+}
+
+fn gen_raw_merge_report_fns<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "    /// Adds the values set in `config` to the reports of the fields, which are in the order of the specification")?;
+    writeln!(output, "    pub fn report_values(config: &Config, source: &super::ReportSource, fields: &mut [super::FieldReport]) {{")?;
+    for (i, param) in config.params.iter().enumerate() {
+        let name = param.name.as_snake_case();
+        if param.secret {
+            writeln!(output, "        if config.{}.is_some() {{", name)?;
+        } else {
+            writeln!(output, "        if let Some(value) = &config.{} {{", name)?;
+        }
+        writeln!(output, "            fields[{}].contributions.push(super::Contribution {{ source: source.clone(), value: {} }});", i, format_param_value(param, "value"))?;
+        writeln!(output, "        }}")?;
+    }
+    for (i, switch) in config.switches.iter().enumerate() {
+        writeln!(output, "        if let Some(value) = config.{} {{", switch.name.as_snake_case())?;
+        writeln!(output, "            fields[{}].contributions.push(super::Contribution {{ source: source.clone(), value: value.to_string() }});", config.params.len() + i)?;
+        writeln!(output, "        }}")?;
+    }
+    writeln!(output, "    }}")
+}
+
+fn gen_merge_report<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "/// Source contributing a value")?;
+    writeln!(output, "#[derive(Debug, Clone, Eq, PartialEq)]")?;
+    writeln!(output, "pub enum ReportSource {{")?;
+    writeln!(output, "    File(::std::path::PathBuf),")?;
+    writeln!(output, "    Environment,")?;
+    writeln!(output, "    /// Includes the config files loaded by arguments")?;
+    writeln!(output, "    Arguments,")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "/// Value of a param or switch contributed by a source")?;
+    writeln!(output, "pub struct Contribution {{")?;
+    writeln!(output, "    pub source: ReportSource,")?;
+    writeln!(output, "    pub value: String,")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "/// Values of a param or switch contributed by the sources")?;
+    writeln!(output, "pub struct FieldReport {{")?;
+    writeln!(output, "    pub name: &'static str,")?;
+    writeln!(output, "    /// Whether the contributions are combined instead of overriding each other")?;
+    writeln!(output, "    pub combined: bool,")?;
+    writeln!(output, "    /// Sorted from the lowest priority to the highest one")?;
+    writeln!(output, "    pub contributions: Vec<Contribution>,")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl FieldReport {{")?;
+    writeln!(output, "    /// The contribution used in the configuration unless they are combined")?;
+    writeln!(output, "    pub fn winner(&self) -> Option<&Contribution> {{")?;
+    writeln!(output, "        self.contributions.last()")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "/// Values contributed by each source, see `Config::explain()`")?;
+    writeln!(output, "pub struct MergeReport {{")?;
+    writeln!(output, "    /// Params and switches in the order of the specification")?;
+    writeln!(output, "    pub fields: Vec<FieldReport>,")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl Config {{")?;
+    writeln!(output, "    /// Reports the values contributed by each source without validating the configuration")?;
+    writeln!(output, "    ///")?;
+    writeln!(output, "    /// `env` is used instead of the environment variables of the process.")?;
+    writeln!(output, "    pub fn explain<A, E, K, V, I>(args: A, env: E, config_files: I) -> Result<MergeReport, Error> where")?;
+    writeln!(output, "        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,")?;
+    writeln!(output, "        E: IntoIterator<Item=(K, V)>, K: Into<::std::ffi::OsString>, V: Into<::std::ffi::OsString>,")?;
+    writeln!(output, "        I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
+    writeln!(output)?;
+    writeln!(output, "        let mut fields = vec![")?;
+    for param in &config.params {
+        writeln!(output, "            FieldReport {{ name: \"{}\", combined: {}, contributions: Vec::new() }},", param.name.as_snake_case(), param.merge_fn.is_some())?;
+    }
+    for switch in &config.switches {
+        writeln!(output, "            FieldReport {{ name: \"{}\", combined: {}, contributions: Vec::new() }},", switch.name.as_snake_case(), switch.is_count())?;
+    }
+    writeln!(output, "        ];")?;
+    writeln!(output)?;
+    writeln!(output, "        // Earlier files override later ones")?;
+    writeln!(output, "        let config_files = config_files.into_iter().map(|path| path.as_ref().to_owned()).collect::<Vec<_>>();")?;
+    if config.general.drop_ins {
+        writeln!(output, "        let mut files = Vec::new();")?;
+        writeln!(output, "        for path in config_files {{")?;
+        writeln!(output, "            let drop_ins = ::configure_me::helpers::drop_in_files(&path).map_err(|(file, error)| Error::Reading {{ file, error }})?;")?;
+        writeln!(output, "            files.extend(drop_ins.into_iter().rev());")?;
+        writeln!(output, "            files.push(path);")?;
+        writeln!(output, "        }}")?;
+        writeln!(output, "        let config_files = files;")?;
+    }
+    let collect_env = "        let env = env.into_iter().map(|(name, value)| (name.into(), value.into())).collect::<::std::collections::HashMap<::std::ffi::OsString, ::std::ffi::OsString>>();";
+    if config.general.profile_param.is_some() {
+        // The profile is needed to load the config files
+        writeln!(output, "        let args = args.into_iter().map(Into::into).collect::<Vec<::std::ffi::OsString>>();")?;
+        writeln!(output, "{}", collect_env)?;
+    }
+    writeln!(output, "        let mut loader = raw::Config::default();")?;
+    if let Some(profile) = &config.general.profile_param {
+        writeln!(output, "        loader._profile = ::configure_me::helpers::selected_profile({}, \"--{}\", raw::VALUED_OPTIONS, env.get(::std::ffi::OsStr::new(\"{}\")).cloned());", scanned_args(config), profile.as_hypenated(), profile_env_var(config, profile)?)?;
+    }
+    writeln!(output, "        for path in config_files.into_iter().rev() {{")?;
+    if config.general.config_includes {
+        writeln!(output, "            let loaded = loader.load_once(&path);")?;
+        writeln!(output, "            match loader.with_includes(&path, loaded) {{")?;
+    } else {
+        writeln!(output, "            match loader.load_once(&path) {{")?;
+    }
+    writeln!(output, "                Ok(config) => raw::report_values(&config, &ReportSource::File(path), &mut fields),")?;
+    writeln!(output, "                Err(Error::Reading {{ ref error, .. }}) if error.kind() == ::std::io::ErrorKind::NotFound => (),")?;
+    writeln!(output, "                Err(err) => return Err(err),")?;
+    writeln!(output, "            }}")?;
+    writeln!(output, "        }}")?;
+    writeln!(output)?;
+    if config.general.profile_param.is_none() {
+        writeln!(output, "{}", collect_env)?;
+    }
+    writeln!(output, "        let mut config = raw::Config::default();")?;
+    if has_env_maps(config) {
+        writeln!(output, "        config.merge_env_with(|name| env.get(::std::ffi::OsStr::new(name)).cloned(), env.clone())?;")?;
+    } else {
+        writeln!(output, "        config.merge_env_with(|name| env.get(::std::ffi::OsStr::new(name)).cloned())?;")?;
+    }
+    writeln!(output, "        raw::report_values(&config, &ReportSource::Environment, &mut fields);")?;
+    writeln!(output)?;
+    writeln!(output, "        let mut config = raw::Config::default();")?;
+    writeln!(output, "        config.merge_args(args.into_iter().map(Into::into))?;")?;
+    writeln!(output, "        raw::report_values(&config, &ReportSource::Arguments, &mut fields);")?;
+    writeln!(output)?;
+    writeln!(output, "        Ok(MergeReport {{ fields }})")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
+
+/// Whether config files are checked for permissions or limits when reading them
+fn checks_config_files(config: &Config) -> bool {
+    let general = &config.general;
+    general.insecure_config_files() != InsecureFile::Allow || general.max_config_file_size.is_some() || general.max_config_string_length.is_some() || general.max_config_depth.is_some() || general.mmap_config_threshold.is_some() || general.remote_config
+}
+
+fn gen_read_config_file<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let read = if config.general.remote_config {
+        let timeout = config.general.remote_config_timeout.unwrap_or(30);
+        format!("::configure_me::helpers::read_config_source(&config_file_name, CONFIG_FILE_CHECKS, ::std::time::Duration::from_secs({}), {})", timeout, config.general.remote_config_http)
+    } else if checks_config_files(config) {
+        "::configure_me::helpers::read_config_file(&config_file_name, CONFIG_FILE_CHECKS)".to_owned()
+    } else {
+        "::std::fs::read(&config_file_name)".to_owned()
+    };
+    writeln!(output, "            let config_content = {}.map_err(|error| super::Error::Reading {{ file: config_file_name.clone(), error }})?;", read)
+}
+
+/// Generates `load_once_as()` loading a config file in the given format regardless of its
+/// extension
+fn gen_load_once_as<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    writeln!(output, "        /// Loads the file in `format`, reading and parsing it only once even if it's specified multiple times")?;
+    writeln!(output, "        pub fn load_once_as<P: Into<PathBuf>>(&mut self, config_file_name: P, format: ::configure_me::ConfigFormat) -> Result<Self, super::Error> {{")?;
+    writeln!(output, "            let config_file_name = config_file_name.into();")?;
+    writeln!(output, "            if let Some((_, table)) = self._parsed_files.iter().find(|(file, _)| *file == config_file_name) {{")?;
+    writeln!(output, "                return self.parse_document(&config_file_name, table.clone());")?;
+    writeln!(output, "            }}")?;
+    writeln!(output)?;
+    gen_read_config_file(config, &mut output)?;
+    writeln!(output, "            let table = ::configure_me::helpers::to_toml(&config_content, format)")?;
+    writeln!(output, "                .map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.clone(), error }})?;")?;
+    writeln!(output, "            let config = self.parse_document(&config_file_name, table.clone())?;")?;
+    writeln!(output, "            self._loaded_files.push((config_file_name.clone(), ::configure_me::helpers::FileDigest::of(&config_content)));")?;
+    writeln!(output, "            self._parsed_files.push((config_file_name, table));")?;
+    writeln!(output, "            Ok(config)")?;
+    writeln!(output, "        }}")
+}
+
+/// Whether TOML config files are deserialized directly from their content, which keeps the
+/// location of errors
+///
+/// Other formats, profiles, sections and nested tables require parsing the content into a table
+/// first.
+fn parses_content_directly(config: &Config) -> bool {
+    !config.general.json_config && config.general.profile_param.is_none() && config.sections.is_empty() && config.general.config_table.is_none()
+}
+
+/// Writes `Some(value)` or `None`
+fn write_option<W: Write, T: fmt::Display>(mut output: W, value: Option<T>) -> fmt::Result {
+    match value {
+        Some(value) => write!(output, "Some({})", value),
+        None => write!(output, "None"),
+    }
+}
+
+fn gen_config_file_checks<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let general = &config.general;
+    let insecure = match general.insecure_config_files() {
+        InsecureFile::Allow => None,
+        InsecureFile::Warn => Some("::configure_me::helpers::InsecureFile::Warn"),
+        InsecureFile::Error => Some("::configure_me::helpers::InsecureFile::Error"),
+    };
+
+    writeln!(output)?;
+    writeln!(output, "    const CONFIG_FILE_CHECKS: ::configure_me::helpers::ConfigFileChecks = ::configure_me::helpers::ConfigFileChecks {{")?;
+    // Like `mmap_threshold()`, the function exists only with the `insecure-files` feature
+    match insecure {
+        Some(insecure) => write!(output, "        insecure: ::configure_me::helpers::insecure_files({})", insecure)?,
+        None => write!(output, "        insecure: None")?,
+    }
+    write!(output, ",\n        max_size: ")?;
+    write_option(&mut output, general.max_config_file_size)?;
+    write!(output, ",\n        max_string_length: ")?;
+    write_option(&mut output, general.max_config_string_length)?;
+    write!(output, ",\n        max_depth: ")?;
+    write_option(&mut output, general.max_config_depth)?;
+    // The function exists only with the `mmap` feature, so that it's not silently ignored
+    match general.mmap_config_threshold {
+        Some(threshold) => write!(output, ",\n        mmap_threshold: ::configure_me::helpers::mmap_threshold({})", threshold)?,
+        None => write!(output, ",\n        mmap_threshold: None")?,
+    }
+    writeln!(output, ",")?;
+    writeln!(output, "    }};")
+}
+
+fn gen_rename_keys_call<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if renames_keys(config) {
+        writeln!(output, "                .map(|config| rename_keys(config, config_file_name))")?;
+    }
+    Ok(())
+}
+
+fn gen_track_file_call<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if config.general.tracks_sources() {
+        writeln!(output, "                .map(|config| track_file(config, config_file_name))")?;
+    }
+    Ok(())
+}
+
+/// Expression recording the source of `value` when the overrides of the value are explained or
+/// the sources of the combined value are audited
+fn track_source(param: &::config::Param, name: &str, value: String, source: &str) -> String {
+    if param.explain_overrides {
+        format!("self._sources.track(\"{}\", {}, ::configure_me::helpers::Source::{})", name, value, source)
+    } else if param.audit_combined {
+        format!("self._sources.track_combined(\"{}\", {}, ::configure_me::helpers::Source::{})", name, value, source)
+    } else {
+        value
+    }
+}
+
+fn gen_normalize_long_option<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    writeln!(output)?;
+    write!(output, "    const LONG_OPTIONS: &[&str] = ")?;
+    write_str_slice(&mut output, &long_options(config).iter().map(AsRef::as_ref).collect::<Vec<_>>())?;
+    writeln!(output, ";")?;
+    writeln!(output)?;
+    writeln!(output, "    pub fn normalize_long_option(arg: ::std::ffi::OsString) -> Result<::std::ffi::OsString, ArgParseError> {{")?;
+    writeln!(output, "        let (name, value) = match arg.to_str() {{")?;
+    writeln!(output, "            Some(arg) if arg.starts_with(\"--\") && arg.len() > 2 => match arg.find('=') {{")?;
+    writeln!(output, "                Some(pos) => (arg[..pos].to_owned(), arg[pos..].to_owned()),")?;
+    writeln!(output, "                None => (arg.to_owned(), String::new()),")?;
+    writeln!(output, "            }},")?;
+    if config.general.slash_options {
+        writeln!(output, "            Some(\"/?\") => return Ok(\"--help\".into()),")?;
+        writeln!(output, "            Some(arg) if arg.starts_with('/') && arg.len() > 1 => match arg.find(&[':', '='][..]) {{")?;
+        writeln!(output, "                Some(pos) => (format!(\"--{{}}\", &arg[1..pos]), format!(\"={{}}\", &arg[(pos + 1)..])),")?;
+        writeln!(output, "                None => (format!(\"--{{}}\", &arg[1..]), String::new()),")?;
+        writeln!(output, "            }},")?;
+    }
+    writeln!(output, "            _ => return Ok(arg),")?;
+    writeln!(output, "        }};")?;
+    // All generated long options are lower case
+    if config.general.case_insensitive_long_options {
+        writeln!(output, "        let name = name.to_ascii_lowercase();")?;
+    }
+    writeln!(output)?;
+    writeln!(output, "        if LONG_OPTIONS.contains(&&*name) {{")?;
+    writeln!(output, "            return Ok(format!(\"{{}}{{}}\", name, value).into());")?;
+    writeln!(output, "        }}")?;
+    if config.general.long_option_prefixes {
+        writeln!(output)?;
+        if config.general.slash_options {
+            // Paths like /tmp must not be mistaken for prefixes of options
+            writeln!(output, "        if arg.to_str().map_or(false, |arg| arg.starts_with('/')) {{")?;
+            writeln!(output, "            return Ok(arg);")?;
+            writeln!(output, "        }}")?;
+        }
+        writeln!(output, "        let candidates = LONG_OPTIONS.iter().cloned().filter(|option| option.starts_with(&*name)).collect::<Vec<_>>();")?;
+        writeln!(output, "        match candidates.len() {{")?;
+        writeln!(output, "            0 => (),")?;
+        writeln!(output, "            1 => return Ok(format!(\"{{}}{{}}\", candidates[0], value).into()),")?;
+        writeln!(output, "            _ => return Err(ArgParseError::AmbiguousArgument(name, candidates)),")?;
+        writeln!(output, "        }}")?;
+    }
+    writeln!(output, "        Ok(arg)")?;
+    writeln!(output, "    }}")
+}
+
+fn gen_env_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    write_params_and_switches::<visitor::EnvParseErrorDecl, _>(config, &mut output)?;
+    if rejects_empty_env_vars(config) {
+        writeln!(output, "    Empty(&'static str),")?;
+    }
+    if reads_secret_files(config) {
+        writeln!(output, "    SecretFile {{ name: &'static str, file: ::std::path::PathBuf, error: ::std::io::Error }},")?;
+    }
+    Ok(())
+}
+
+/// Whether the value of some secret param can be read from the file named by an environment
+/// variable
+fn reads_secret_files(config: &Config) -> bool {
+    config.params.iter().any(|param| param.env_var && param.secret && !param.map)
+}
+
+/// Whether some environment variable fails to parse if it's empty, see `EmptyEnvVar::Error`
+/// Whether the entries of some map are read from environment variables
+fn has_env_maps(config: &Config) -> bool {
+    config.params.iter().any(|param| param.env_var && param.map)
+}
+
+fn rejects_empty_env_vars(config: &Config) -> bool {
+    config.params.iter().any(|param| param.env_var && param.empty_env_var == EmptyEnvVar::Error)
+        || (config.general.empty_env_var() == EmptyEnvVar::Error && config.switches.iter().any(|switch| switch.env_var))
+}
+
+/// Writes the options and positional arguments shown after the program name in the usage line
+fn write_usage<W: Write>(config: &Config, sum_arg_len: usize, mut output: W) -> fmt::Result {
+    use ::config::SwitchKind;
+
+    // Standard width of the terminal - "Usage: ".len()
+    if sum_arg_len < (80 - 7) {
+        if let Some(conf_file_param) = &config.general.conf_file_param {
+            write!(output, " [--{} CONF_FILE]", conf_file_param.as_hypenated())?;
+        }
+        if let Some(conf_dir_param) = &config.general.conf_dir_param {
+            write!(output, " [--{} CONF_DIR]", conf_dir_param.as_hypenated())?;
+        }
+        if let Some(profile_param) = &config.general.profile_param {
+            write!(output, " [--{} PROFILE]", profile_param.as_hypenated())?;
+        }
+        if let Some(explain) = &config.general.explain_config_switch {
+            write!(output, " [--{}]", explain.as_hypenated())?;
+        }
+        for param in config.params.iter().filter(|param| param.argument) {
+            if let Some(abbr) = &param.abbr {
+                write!(output, " [-{} {}|--", abbr, param_value_names(param))?;
+            } else {
+                write!(output, " [--")?;
+            }
+            write!(output, "{} {}", param.name.as_hypenated(), param_value_names(param))?;
+            if param.multiple.is_some() || param.map {
+                write!(output, " ...")?;
+            }
+            write!(output, "]")?;
+        }
+        for switch in config.switches.iter() {
+            if let SwitchKind::Normal { abbr: Some(abbr), .. } = &switch.kind {
+                write!(output, " [-{}|--", abbr)?;
+            } else {
+                write!(output, " [--")?;
+            }
+            if switch.is_inverted() {
+                write!(output, "no-")?;
+            }
+            write!(output, "{}", switch.name.as_hypenated())?;
+            if switch.is_count() {
+                write!(output, " ...")?;
+            }
+            write!(output, "]")?;
+        }
+    } else {
+        write!(output, " [ARGUMENTS...]")?;
+    }
+    for positional in &config.positionals {
+        write!(output, " {}", positional_usage(positional))?;
+    }
+    if !config.subcommands.is_empty() {
+        write!(output, " [SUBCOMMAND ...]")?;
+    }
+    Ok(())
+}
+
+/// Summary and description of the program shown at the beginning of the help, separated by an
+/// empty line
+///
+/// Like in the man page, the description of the package is used as the summary if the
+/// specification doesn't have one and as the description otherwise.
+fn help_about(config: &Config) -> Option<String> {
+    let package_description = config.package_description.as_ref();
+    let summary = config.general.summary.as_ref().or(package_description);
+    let doc = config.general.doc.as_ref().or(package_description.filter(|_| config.general.summary.is_some()));
+    let about = summary.into_iter().chain(doc).map(|text| text.trim()).collect::<Vec<_>>().join("\n\n");
+    if about.is_empty() {
+        None
+    } else {
+        Some(about)
+    }
+}
+
+/// Writes `text` wrapped to the standard width of the terminal, escaped for a format string
+fn write_wrapped_text<W: Write>(mut output: W, text: &str) -> fmt::Result {
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            write!(output, "\\n")?;
+        }
+        let mut pos = 0;
+        for word in line.split_whitespace() {
+            let word_len = word.graphemes(true).count();
+            if pos > 0 && pos + 1 + word_len > 80 {
+                write!(output, "\\n")?;
+                pos = 0;
+            } else if pos > 0 {
+                write!(output, " ")?;
+                pos += 1;
+            }
+            let escaped = format!("{:?}", word);
+            write!(output, "{}", escaped[1..(escaped.len() - 1)].replace('{', "{{").replace('}', "}}"))?;
+            pos += word_len;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the help displayed for `ArgParseError::HelpRequested`, formatted when generating the code
+fn gen_display_help<W: Write>(config: &Config, sum_arg_len: usize, mut output: W) -> fmt::Result {
+    use ::config::SwitchKind;
+
+    write!(output, "        ArgParseError::HelpRequested(program_name) => write!(f, \"")?;
+    if let Some(about) = help_about(config) {
+        write_wrapped_text(&mut output, &about)?;
+        write!(output, "\\n\\n")?;
+    }
+    write!(output, "Usage: {{}}")?;
+    write_usage(config, sum_arg_len, &mut output)?;
+    let conf_files = config
         .general.conf_file_param
         .as_ref()
         .into_iter()
         .chain(config.general.conf_dir_param.as_ref())
+        .chain(config.general.profile_param.as_ref())
+        .chain(config.general.explain_config_switch.as_ref())
         .map(|arg| arg.as_snake_case().len());
 
     let max_param_len = config
         .params
         .iter()
         .filter(|param| param.argument)
-        .filter(|param| sum_arg_len > (80 - 7) || param.doc.is_some())
+        .filter(|param| sum_arg_len > (80 - 7) || param_help_doc(param).is_some())
         .map(|param| param.name.as_snake_case().len() + if param.abbr.is_some() { 4 } else { 0 })
         .chain(conf_files)
         .max()
@@ -448,7 +2847,7 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
     let max_switch_len = config
         .switches
         .iter()
-        .filter(|switch| sum_arg_len > (80 - 7) || switch.doc.is_some())
+        .filter(|switch| sum_arg_len > (80 - 7) || switch_help_doc(switch).is_some())
         .map(|switch| switch.name.as_snake_case().len() + match switch.kind {
             SwitchKind::Normal { abbr: Some(_), .. } => 4,
             SwitchKind::Inverted => 3,
@@ -457,33 +2856,42 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
         .max()
         .unwrap_or(0);
     let max_arg_len = ::std::cmp::max(max_param_len, max_switch_len);
-    let doc_start = 8 + 2 + max_arg_len + 4;
     if max_arg_len > 0 {
         write!(output, "\\n\\nArguments:")?;
         let conf_file = config
             .general.conf_file_param
             .as_ref()
-            .map(|arg| (arg, Some("Load configuration from this file."), SwitchKind::Normal { abbr: None, count: false }))
+            .map(|arg| (arg, Some(Cow::Borrowed("Load configuration from this file.")), SwitchKind::Normal { abbr: None, count: false }))
             .into_iter();
         let conf_dir = config
             .general.conf_dir_param
             .as_ref()
-            .map(|arg| (arg, Some("Load configuration from files in this directory."), SwitchKind::Normal { abbr: None, count: false }))
+            .map(|arg| (arg, Some(Cow::Borrowed("Load configuration from files in this directory.")), SwitchKind::Normal { abbr: None, count: false }))
+            .into_iter();
+        let profile = config
+            .general.profile_param
+            .as_ref()
+            .map(|arg| (arg, Some(Cow::Borrowed("Use this profile of config files.")), SwitchKind::Normal { abbr: None, count: false }))
+            .into_iter();
+        let explain = config
+            .general.explain_config_switch
+            .as_ref()
+            .map(|arg| (arg, Some(Cow::Borrowed("Explain which source overrode each value.")), SwitchKind::Normal { abbr: None, count: false }))
             .into_iter();
 
         let params = config
             .params
             .iter()
             .filter(|param| param.argument)
-            .map(|param| (&param.name, param.doc.as_ref().map(AsRef::as_ref), SwitchKind::Normal { abbr: param.abbr, count: false }));
+            .map(|param| (&param.name, param_help_doc(param), SwitchKind::Normal { abbr: param.abbr, count: false }));
         let switches = config
             .switches
             .iter()
-            .map(|switch| (&switch.name, switch.doc.as_ref().map(AsRef::as_ref), switch.kind));
+            .map(|switch| (&switch.name, switch_help_doc(switch), switch.kind));
 
-        for (name, doc, switch_kind) in conf_file.chain(conf_dir).chain(params).chain(switches) {
+        for (name, doc, switch_kind) in conf_file.chain(conf_dir).chain(profile).chain(explain).chain(params).chain(switches) {
             if let Some(doc) = doc {
-                if doc.len() > 0 || sum_arg_len > (80 - 7) {
+                if !doc.is_empty() || sum_arg_len > (80 - 7) {
                     let name_len = match switch_kind {
                         SwitchKind::Normal { abbr: Some(abbr), .. } => {
                             write!(output, "\\n        -{}, --{}", abbr, name.as_hypenated())?;
@@ -502,22 +2910,7 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
                     for _ in 0..(max_arg_len + 4 - name_len) {
                         output.write_char(' ')?;
                     }
-                    let mut pos = doc_start;
-                    for word in doc.split_word_bounds() {
-                        let word_len = word.graphemes(true).count();
-                        if word_len + pos > 80 {
-                            write!(output, "\\n          ")?;
-                            for _ in 0..(max_arg_len + 4) {
-                                write!(output, " ")?;
-                            }
-                            pos = doc_start;
-                        }
-
-                        if !(word.trim().len() == 0 && pos ==  doc_start) {
-                            write!(output, "{}", word)?;
-                            pos += word_len;
-                        }
-                    }
+                    write_wrapped_doc(&mut output, &doc, max_arg_len)?;
                 }
             } else if sum_arg_len > (80 - 7) {
                     match switch_kind {
@@ -526,74 +2919,582 @@ fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt:
                         SwitchKind::Inverted => write!(output, "no-")?,
                     }
 
-                    write!(output, "{}\\n", name.as_hypenated())?;
-            }
+                    write!(output, "{}\\n", name.as_hypenated())?;
+            }
+        }
+    }
+    let max_positional_len = config
+        .positionals
+        .iter()
+        .filter(|positional| positional.doc.is_some() || positional.is_duration() || positional.size)
+        .map(|positional| positional.value_name().len())
+        .max();
+    if let Some(max_positional_len) = max_positional_len {
+        write!(output, "\\n\\nPositional arguments:")?;
+        for positional in &config.positionals {
+            if let Some(doc) = positional_doc(positional) {
+                let name = positional.value_name();
+                write!(output, "\\n        {}", name)?;
+                // Aligned like the docs of options, which are preceded by `--`
+                for _ in 0..(max_positional_len + 6 - name.len()) {
+                    output.write_char(' ')?;
+                }
+                write_wrapped_doc(&mut output, &doc, max_positional_len)?;
+            }
+        }
+    }
+    if let Some(max_subcommand_len) = config.subcommands.iter().map(|subcommand| subcommand.name.as_snake_case().len()).max() {
+        write!(output, "\\n\\nSubcommands:")?;
+        for subcommand in &config.subcommands {
+            let name = subcommand.name.as_snake_case();
+            write!(output, "\\n        {}", name)?;
+            if let Some(doc) = &subcommand.doc {
+                for _ in 0..(max_subcommand_len + 6 - name.len()) {
+                    output.write_char(' ')?;
+                }
+                write_wrapped_doc(&mut output, doc, max_subcommand_len)?;
+            }
+        }
+    }
+    if mentions_integer_notation(config) {
+        write!(output, "\\n\\nIntegers may also be written in hexadecimal (0x1F), octal (0o755) or binary (0b1010) notation.")?;
+    }
+    writeln!(output, "\", program_name),")?;
+    Ok(())
+}
+
+/// Whether `--help` mentions the notations of integers, requested by `help_integer_notation`
+fn mentions_integer_notation(config: &Config) -> bool {
+    config.general.help_integer_notation && (config.params.iter().any(|param| param.argument && param.is_integer()) || config.positionals.iter().any(::config::Positional::is_integer))
+}
+
+/// Writes a `helpers::HelpEntry` listed by the rich help
+fn write_help_entry<W: Write>(mut output: W, names: &str, doc: Option<&str>, default: Option<&str>, env_var: Option<String>) -> fmt::Result {
+    write!(output, "                ::configure_me::helpers::HelpEntry {{ names: {:?}, doc: {:?}, default: ", names, doc.unwrap_or(""))?;
+    match default {
+        Some(default) => write!(output, "Some({:?})", default)?,
+        None => write!(output, "None")?,
+    }
+    write!(output, ", env_var: ")?;
+    match env_var {
+        Some(env_var) => write!(output, "Some({:?})", env_var)?,
+        None => write!(output, "None")?,
+    }
+    writeln!(output, " }},")
+}
+
+/// Writes the help displayed for `ArgParseError::HelpRequested` with `general.rich_help`, which
+/// is formatted by `helpers::display_help` when it's displayed
+fn gen_display_rich_help<W: Write>(config: &Config, sum_arg_len: usize, mut output: W) -> fmt::Result {
+    let mut usage = String::new();
+    write_usage(config, sum_arg_len, &mut usage)?;
+    writeln!(output, "        ArgParseError::HelpRequested(program_name) => ::configure_me::helpers::display_help(f, program_name, {:?}, {:?}, &[", help_about(config), usage)?;
+
+    writeln!(output, "            ::configure_me::helpers::HelpSection {{ heading: \"Arguments\", entries: &[")?;
+    let conf_params = [
+        (&config.general.conf_file_param, "CONF_FILE", "Load configuration from this file."),
+        (&config.general.conf_dir_param, "CONF_DIR", "Load configuration from files in this directory."),
+        (&config.general.profile_param, "PROFILE", "Use this profile of config files."),
+    ];
+    for (param, value_name, doc) in conf_params.iter() {
+        if let Some(param) = param {
+            write_help_entry(&mut output, &format!("--{} {}", param.as_hypenated(), value_name), Some(doc), None, None)?;
         }
     }
-    writeln!(output, "\", program_name),")?;
+    if let Some(explain) = &config.general.explain_config_switch {
+        write_help_entry(&mut output, &format!("--{}", explain.as_hypenated()), Some("Explain which source overrode each value."), None, None)?;
+    }
+    for param in config.params.iter().filter(|param| param.argument) {
+        let mut names = match &param.abbr {
+            Some(abbr) => format!("-{}, {} {}", abbr, param_long(param), param_value_names(param)),
+            None => format!("{} {}", param_long(param), param_value_names(param)),
+        };
+        if param.multiple.is_some() || param.map {
+            names.push_str(" ...");
+        }
+        let default = match &param.optionality {
+            Optionality::DefaultValue(default) if !param.secret => Some(::gen_example::toml_default(default).unwrap_or(default)),
+            _ => None,
+        };
+        let env_var = match (param.env_var, param.map) {
+            (true, true) => Some(format!("{}_<KEY>", env_var_name(config, param.name.as_snake_case()))),
+            (true, false) => Some(env_var_name(config, param.name.as_snake_case())),
+            (false, _) => None,
+        };
+        write_help_entry(&mut output, &names, param_help_doc(param).as_deref(), default, env_var)?;
+    }
+    for switch in &config.switches {
+        let names = match switch_short(switch) {
+            Some(short) => format!("{}, {}", short, switch_long(switch)),
+            None => switch_long(switch),
+        };
+        let env_var = if switch.env_var { Some(env_var_name(config, switch.name.as_snake_case())) } else { None };
+        write_help_entry(&mut output, &names, switch_help_doc(switch).as_deref(), None, env_var)?;
+    }
+    write_help_entry(&mut output, "-h, --help", Some("Print this help and exit."), None, None)?;
+    if config.general.prints_version() {
+        write_help_entry(&mut output, "-V, --version", Some("Print the version and exit."), None, None)?;
+    }
+    writeln!(output, "            ] }},")?;
+
+    if !config.positionals.is_empty() {
+        writeln!(output, "            ::configure_me::helpers::HelpSection {{ heading: \"Positional arguments\", entries: &[")?;
+        for positional in &config.positionals {
+            write_help_entry(&mut output, &positional.value_name(), positional_doc(positional).as_deref(), None, None)?;
+        }
+        writeln!(output, "            ] }},")?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "            ::configure_me::helpers::HelpSection {{ heading: \"Subcommands\", entries: &[")?;
+        for subcommand in &config.subcommands {
+            write_help_entry(&mut output, subcommand.name.as_snake_case(), subcommand.doc.as_deref(), None, None)?;
+        }
+        writeln!(output, "            ] }},")?;
+    }
+    let note = if mentions_integer_notation(config) {
+        "Some(\"Integers may also be written in hexadecimal (0x1F), octal (0o755) or binary (0b1010) notation.\")"
+    } else {
+        "None"
+    };
+    writeln!(output, "        ], {}),", note)
+}
+
+fn gen_display_arg_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let notation = mentions_integer_notation(config);
+    if config.general.prints_version() {
+        writeln!(output, "            ArgParseError::VersionRequested(version) => write!(f, \"{{}}\", version),")?;
+    }
+    let sum_arg_len = config
+        .params
+        .iter()
+        .filter(|param| param.argument)
+        .map(|param| param.name.as_snake_case().len() * 2 + 6)
+        .sum::<usize>()
+        + config
+        .switches
+        .iter()
+        .map(|switch| switch.name.as_snake_case().len() + if switch.is_inverted() { 8 } else { 5 })
+        .sum::<usize>()
+        + config
+        .general
+        .conf_file_param
+        .as_ref()
+        .map(|param| param.as_snake_case().len() + 6 + 9)
+        .unwrap_or(0)
+        + config
+        .general
+        .conf_dir_param
+        .as_ref()
+        .map(|param| param.as_snake_case().len() + 6 + 8)
+        .unwrap_or(0)
+        + config
+        .general
+        .profile_param
+        .as_ref()
+        .map(|param| param.as_snake_case().len() + 6 + 8)
+        .unwrap_or(0);
+
+    if config.general.rich_help {
+        gen_display_rich_help(config, sum_arg_len, &mut output)?;
+    } else {
+        gen_display_help(config, sum_arg_len, &mut output)?;
+    }
     for param in &config.params {
         if !param.argument {
             continue;
         }
 
-        writeln!(output, "        ArgParseError::Field{}(err) => {{", param.name.as_pascal_case())?;
-        writeln!(output, "            write!(f, \"Failed to parse argument '--{}': {{}}.\\n\\nHint: the value must be \", err)?;", param.name.as_hypenated())?;
-        writeln!(output, "            <{} as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;", param.ty)?;
-        writeln!(output, "            write!(f, \".\")")?;
-        writeln!(output, "        }},")?;
+        if param.secret {
+            writeln!(output, "        ArgParseError::Field{}(_) => ::configure_me::helpers::display_secret_arg_error::<{}>(f, \"--{}\"),", param.name.as_pascal_case(), arg_type(param, &param.hint_type(notation)), param.name.as_hypenated())?;
+        } else {
+            writeln!(output, "        ArgParseError::Field{}(err) => ::configure_me::helpers::display_arg_error::<{}>(f, \"--{}\", err),", param.name.as_pascal_case(), arg_type(param, &param.hint_type(notation)), param.name.as_hypenated())?;
+        }
+    }
+    for positional in &config.positionals {
+        writeln!(output, "        ArgParseError::Field{}(err) => ::configure_me::helpers::display_arg_error::<{}>(f, \"{}\", err),", positional.name.as_pascal_case(), positional.hint_type(notation), positional.value_name())?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "        ArgParseError::UnknownSubcommand(arg) => write!(f, \"An unknown subcommand '{{}}' was specified.\", arg),")?;
+    }
+    for subcommand in &config.subcommands {
+        writeln!(output, "        ArgParseError::Subcommand{}(err) => write!(f, \"{{}}\", err),", subcommand.name.as_pascal_case())?;
     }
     if config.general.conf_dir_param.is_some() {
         writeln!(output, "        ArgParseError::OpenConfDir(err, dir) => write!(f, \"Failed to open configuration directory {{}}: {{}}\", dir.display(), err),")?;
         writeln!(output, "        ArgParseError::ReadConfDir(err, dir) => write!(f, \"Failed to read configuration directory {{}}: {{}}\", dir.display(), err),")?;
     }
+    if config.general.long_option_prefixes {
+        writeln!(output, "        ArgParseError::AmbiguousArgument(arg, candidates) => write!(f, \"The argument '{{}}' is ambiguous, it may mean: {{}}.\", arg, candidates.join(\", \")),")?;
+    }
+    if has_bool_switches(config) {
+        writeln!(output, "        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),")?;
+    }
+    if rejects_duplicate_args(config) {
+        writeln!(output, "        ArgParseError::DuplicateArgument(arg) => write!(f, \"The argument '{{}}' was specified more than once.\", arg),")?;
+    }
+    Ok(())
+}
+
+/// Writes `doc` wrapped at 80 columns, continuation lines are aligned with the docs of arguments
+/// up to `max_arg_len` long
+fn write_wrapped_doc<W: Write>(mut output: W, doc: &str, max_arg_len: usize) -> fmt::Result {
+    let doc_start = 8 + 2 + max_arg_len + 4;
+    let mut pos = doc_start;
+    for word in doc.split_word_bounds() {
+        let word_len = word.graphemes(true).count();
+        if word_len + pos > 80 {
+            write!(output, "\\n          ")?;
+            for _ in 0..(max_arg_len + 4) {
+                write!(output, " ")?;
+            }
+            pos = doc_start;
+        }
+
+        if !(word.trim().is_empty() && pos ==  doc_start) {
+            write!(output, "{}", word)?;
+            pos += word_len;
+        }
+    }
     Ok(())
 }
 
 fn gen_display_env_parse_error<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let notation = mentions_integer_notation(config);
     for param in &config.params {
         if !param.env_var {
             continue;
         }
+        if param.map && param.secret {
+            writeln!(output, "        EnvParseError::Field{}(ref name, _) => ::configure_me::helpers::display_secret_env_error::<{}>(f, name),", param.name.as_pascal_case(), param.hint_type(notation))?;
+            continue;
+        }
+        if param.map {
+            writeln!(output, "        EnvParseError::Field{}(ref name, ref err) => ::configure_me::helpers::display_env_error::<{}>(f, name, err),", param.name.as_pascal_case(), param.hint_type(notation))?;
+            continue;
+        }
 
-        writeln!(output, "        EnvParseError::Field{}(ref err) => {{", param.name.as_pascal_case())?;
-        write!(output, "            write!(f, \"Failed to parse environment variable '")?;
-        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-        writeln!(output, "{}': {{}}.\\n\\nHint: the value must be \", err)?;", param.name.as_upper_case())?;
-        writeln!(output, "            <{} as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;", param.ty)?;
-        writeln!(output, "            write!(f, \".\")")?;
-        writeln!(output, "        }},")?;
+        if param.secret {
+            write!(output, "        EnvParseError::Field{}(_) => ::configure_me::helpers::display_secret_env_error::<{}>(f, \"", param.name.as_pascal_case(), param.hint_type(notation))?;
+        } else {
+            write!(output, "        EnvParseError::Field{}(ref err) => ::configure_me::helpers::display_env_error::<{}>(f, \"", param.name.as_pascal_case(), param.hint_type(notation))?;
+        }
+        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
+        if param.secret {
+            writeln!(output, "{}\"),", param.name.as_upper_case())?;
+        } else {
+            writeln!(output, "{}\", err),", param.name.as_upper_case())?;
+        }
     }
     for switch in &config.switches {
         if !switch.env_var {
             continue;
         }
 
-        writeln!(output, "        EnvParseError::Field{}(ref err) => {{", switch.name.as_pascal_case())?;
         if switch.is_count() {
+            writeln!(output, "        EnvParseError::Field{}(ref err) => {{", switch.name.as_pascal_case())?;
             write!(output, "            write!(f, \"Invalid value for '")?;
-            config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
+            config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
             writeln!(output, "{}': {{}}.\\n\\nHint: the value must be \", err)?;", switch.name.as_upper_case())?;
             writeln!(output, "            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;")?;
             writeln!(output, "            write!(f, \".\")")?;
+            writeln!(output, "        }},")?;
         } else {
-            write!(output, "            write!(f, \"Invalid value '{{:?}}' for '")?;
-            config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-            writeln!(output, "{}'.\\n\\nHint: the allowed values are 0, false, 1, true.\", err)", switch.name.as_upper_case())?;
+            write!(output, "        EnvParseError::Field{}(ref err) => ::configure_me::helpers::display_switch_error(f, \"", switch.name.as_pascal_case())?;
+            config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
+            writeln!(output, "{}\", err, raw::TRUE_VALUES, raw::FALSE_VALUES),", switch.name.as_upper_case())?;
         }
-        writeln!(output, "        }},")?;
+    }
+    if rejects_empty_env_vars(config) {
+        writeln!(output, "        EnvParseError::Empty(name) => write!(f, \"The environment variable '{{}}' is set but empty.\", name),")?;
+    }
+    if reads_secret_files(config) {
+        writeln!(output, "        EnvParseError::SecretFile {{ name, ref file, ref error }} => write!(f, \"Failed to read the file {{}} named by the environment variable '{{}}_FILE': {{}}\", file.display(), name, error),")?;
     }
     Ok(())
 }
 
+/// Name of the environment variable of a param or switch
+pub(crate) fn env_var_name(config: &Config, name: &str) -> String {
+    match &config.general.env_prefix {
+        Some(prefix) => format!("{}_{}", prefix, name).to_ascii_uppercase(),
+        None => name.to_ascii_uppercase(),
+    }
+}
+
 fn gen_validation_fn<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    gen_relation_checks(config, &mut output)?;
     write_params_and_switches::<visitor::Validate, _>(config, &mut output)?;
+    visitor::iter::<visitor::Validate, _, _>(&config.positionals, &mut output)?;
     writeln!(output)?;
-    writeln!(output, "            Ok(super::Config {{")?;
-    write_params_and_switches::<visitor::ConstructConfig, _>(config, &mut output)?;
-    writeln!(output, "            }})")?;
+    let mandatory = config.params.iter().filter(|param| matches!(param.optionality, Optionality::Mandatory)).collect::<Vec<_>>();
+    let mandatory_positionals = config.positionals.iter().filter(|positional| !positional.optional).collect::<Vec<_>>();
+    if !mandatory.is_empty() || !mandatory_positionals.is_empty() {
+        // All missing fields are reported at once
+        writeln!(output, "            let mut missing_fields = Vec::new();")?;
+        for param in &mandatory {
+            let mut sources = Vec::new();
+            if param.argument {
+                sources.push(param_long(param));
+            }
+            if param.env_var {
+                sources.push(env_var_name(config, param.name.as_snake_case()));
+            }
+            writeln!(output, "            if {}.is_none() {{", param.name.as_snake_case())?;
+            write!(output, "                missing_fields.push(super::MissingField {{ name: \"{}\", sources: ", param.name.as_snake_case())?;
+            write_str_slice(&mut output, &sources.iter().map(AsRef::as_ref).collect::<Vec<_>>())?;
+            writeln!(output, " }});")?;
+            writeln!(output, "            }}")?;
+        }
+        for positional in &mandatory_positionals {
+            writeln!(output, "            if {}.is_none() {{", positional.name.as_snake_case())?;
+            write!(output, "                missing_fields.push(super::MissingField {{ name: \"{}\", sources: ", positional.name.as_snake_case())?;
+            write_str_slice(&mut output, &[&positional_usage(positional)])?;
+            writeln!(output, " }});")?;
+            writeln!(output, "            }}")?;
+        }
+        // Unwrapping the values at once makes the missing ones an error rather than a panic
+        let names = mandatory.iter().map(|param| param.name.as_snake_case())
+            .chain(mandatory_positionals.iter().map(|positional| positional.name.as_snake_case()))
+            .collect::<Vec<_>>();
+        let some_patterns = names.iter().map(|name| format!("Some({})", name)).collect::<Vec<_>>();
+        writeln!(output, "            let {} = match {} {{", tuple(&names), tuple(&names))?;
+        writeln!(output, "                {} => {},", tuple(&some_patterns), tuple(&names))?;
+        writeln!(output, "                _ => return Err(ValidationError::MissingFields(missing_fields)),")?;
+        writeln!(output, "            }};")?;
+        writeln!(output)?;
+    }
+    for param in config.params.iter().filter(|param| param.min.is_some() || param.max.is_some()) {
+        gen_range_check(param, &mut output)?;
+    }
+    for switch in &config.switches {
+        if let Some(max_count) = switch.max_count {
+            let name = switch.name.as_snake_case();
+            writeln!(output, "            if let Some(value) = self.{}.filter(|&value| value > {}) {{", name, max_count)?;
+            writeln!(output, "                return Err(ValidationError::OutOfRange {{ name: \"{}\", value: value.to_string(), range: \"at most {}\" }});", name, max_count)?;
+            writeln!(output, "            }}")?;
+            writeln!(output)?;
+        }
+    }
+    for param in &config.params {
+        if let Some(pattern) = &param.validate_regex {
+            gen_regex_check(param, pattern, &mut output)?;
+        }
+        if let Some(validate) = &param.validate {
+            gen_validate_call(param, validate, &mut output)?;
+        }
+    }
+    let is_checked = config.general.audit || config.general.validate.is_some();
+    if is_checked {
+        writeln!(output, "            let config = super::Config {{")?;
+    } else {
+        writeln!(output, "            Ok(super::Config {{")?;
+    }
+    visitor::iter::<visitor::ConstructConfig, _, _>(config.section_params(None), &mut output)?;
+    visitor::iter::<visitor::ConstructConfig, _, _>(&config.switches, &mut output)?;
+    visitor::iter::<visitor::ConstructConfig, _, _>(&config.positionals, &mut output)?;
+    if !config.subcommands.is_empty() {
+        writeln!(output, "                subcommand: self._subcommand,")?;
+    }
+    for section in &config.sections {
+        writeln!(output, "                {}: super::{} {{", section.name.as_snake_case(), section.name.as_pascal_case())?;
+        let mut fields = String::new();
+        visitor::iter::<visitor::ConstructConfig, _, _>(config.section_params(Some(&section.name)), &mut fields)?;
+        for line in fields.lines() {
+            writeln!(output, "    {}", line)?;
+        }
+        writeln!(output, "                }},")?;
+    }
+    if config.general.reload_if_changed {
+        writeln!(output, "                _poll_state: ::configure_me::helpers::PollState::new(&self._loaded_files),")?;
+    }
+    if config.general.loaded_config_files {
+        writeln!(output, "                _loaded_config_files: self._loaded_files.iter().map(|(file, _)| file.clone()).collect(),")?;
+    }
+    if config.general.explain_config_switch.is_some() {
+        // All sources are merged at this point
+        writeln!(output, "                _overrides: self._sources.overrides(),")?;
+    }
+    if is_checked {
+        writeln!(output, "            }};")?;
+        if let Some(validate) = &config.general.validate {
+            writeln!(output, "            if let Err(error) = {}(&config) {{", validate)?;
+            writeln!(output, "                return Err(ValidationError::Custom(error.to_string()));")?;
+            writeln!(output, "            }}")?;
+        }
+        if config.general.audit {
+            writeln!(output, "            config.audit(&self._sources);")?;
+        }
+        writeln!(output, "            Ok(config)")?;
+    } else {
+        writeln!(output, "            }})")?;
+    }
+    Ok(())
+}
+
+/// Joins the items into a tuple expression or pattern, a single item is left as is
+fn tuple<T: AsRef<str>>(items: &[T]) -> String {
+    match items {
+        [item] => item.as_ref().to_owned(),
+        _ => format!("({})", items.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+/// Describes the range allowed by `min` and `max` of a param, e.g. `from 1 to 65535`
+pub(crate) fn param_range(param: &::config::Param) -> Option<String> {
+    match (param.min, param.max) {
+        (Some(min), Some(max)) => Some(format!("from {} to {}", min, max)),
+        (Some(min), None) => Some(format!("at least {}", min)),
+        (None, Some(max)) => Some(format!("at most {}", max)),
+        (None, None) => None,
+    }
+}
+
+/// Generates the check of the range of a param, which applies to the values from all sources and
+/// to the default
+fn gen_range_check<W: Write>(param: &::config::Param, mut output: W) -> fmt::Result {
+    let is_float = param.ty.trim().starts_with('f');
+    let literal = |bound: ::config::Bound| if is_float { format!("{:?}", bound.as_f64()) } else { bound.to_string() };
+    // Bounds equal to the limits of the type would make absurd comparisons
+    let (type_min, type_max) = if is_float { (i128::MIN, i128::MAX) } else { ::config::integer_range(param.ty.trim()) };
+    let is_limit = |bound: ::config::Bound, limit: i128| match bound {
+        ::config::Bound::Integer(bound) => i128::from(bound) == limit,
+        ::config::Bound::Float(_) => false,
+    };
+    let condition = param.min.filter(|&min| !is_limit(min, type_min)).map(|min| format!("value < {}", literal(min)))
+        .into_iter()
+        .chain(param.max.filter(|&max| !is_limit(max, type_max)).map(|max| format!("value > {}", literal(max))))
+        .collect::<Vec<_>>()
+        .join(" || ");
+    if condition.is_empty() {
+        return Ok(());
+    }
+    let name = param.name.as_snake_case();
+    let range = param_range(param).expect("the param has min or max");
+    let value = match param.optionality {
+        Optionality::DefaultValue(_) | Optionality::Mandatory => format!("Some({})", name),
+        Optionality::Optional => name.to_owned(),
+    };
+    // The values of secret params are never shown
+    if param.secret {
+        writeln!(output, "            if {}.filter(|&value| {}).is_some() {{", value, condition)?;
+        writeln!(output, "                return Err(ValidationError::OutOfRange {{ name: \"{}\", value: ::configure_me::helpers::REDACTED.to_owned(), range: \"{}\" }});", name, range)?;
+    } else {
+        writeln!(output, "            if let Some(value) = {}.filter(|&value| {}) {{", value, condition)?;
+        writeln!(output, "                return Err(ValidationError::OutOfRange {{ name: \"{}\", value: value.to_string(), range: \"{}\" }});", name, range)?;
+    }
+    writeln!(output, "            }}")?;
+    writeln!(output)
+}
+
+/// Expression telling whether a param is set in any source or a switch is on (or the opposite if
+/// `set` is false), the raw values are checked before defaults are applied
+fn is_set_expr(config: &Config, name: &str, set: bool) -> String {
+    match config.switches.iter().find(|switch| switch.name.as_snake_case() == name) {
+        Some(switch) if switch.is_count() => format!("self.{}.unwrap_or(0) {} 0", name, if set { ">" } else { "==" }),
+        Some(switch) => format!("{}self.{}.unwrap_or({})", if set { "" } else { "!" }, name, switch.is_inverted()),
+        None => format!("self.{}.{}()", name, if set { "is_some" } else { "is_none" }),
+    }
+}
+
+/// Generates the checks of `requires`, `conflicts_with` and groups, the errors include the sources
+/// of the values
+fn gen_relation_checks<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    let params = config.params.iter().map(|param| (param.name.as_snake_case(), &param.requires, &param.conflicts_with));
+    let switches = config.switches.iter().map(|switch| (switch.name.as_snake_case(), &switch.requires, &switch.conflicts_with));
+    let sources = |name: &str| format!("self._sources.of(\"{}\").into_iter().cloned().collect()", name);
+    let mut any = false;
+    for (name, requires, conflicts_with) in params.chain(switches) {
+        for required in requires {
+            let required = required.as_snake_case();
+            writeln!(output, "            if {} && {} {{", is_set_expr(config, name, true), is_set_expr(config, required, false))?;
+            writeln!(output, "                return Err(ValidationError::MissingRequirement {{ name: \"{}\", sources: {}, required: \"{}\" }});", name, sources(name), required)?;
+            writeln!(output, "            }}")?;
+            any = true;
+        }
+        for other in conflicts_with {
+            let other = other.as_snake_case();
+            writeln!(output, "            if {} && {} {{", is_set_expr(config, name, true), is_set_expr(config, other, true))?;
+            writeln!(output, "                return Err(ValidationError::Conflict {{ name: \"{}\", sources: {}, other: \"{}\", other_sources: {} }});", name, sources(name), other, sources(other))?;
+            writeln!(output, "            }}")?;
+            any = true;
+        }
+    }
+    for group in &config.groups {
+        let members = group.members.iter().map(|member| member.as_snake_case()).collect::<Vec<_>>();
+        writeln!(output, "            {{")?;
+        write!(output, "                let members = [")?;
+        for (i, member) in members.iter().enumerate() {
+            if i > 0 {
+                write!(output, ", ")?;
+            }
+            write!(output, "(\"{}\", {})", member, is_set_expr(config, member, true))?;
+        }
+        writeln!(output, "];")?;
+        writeln!(output, "                let members = members.iter().filter(|(_, is_set)| *is_set).map(|(name, _)| (*name, self._sources.of(name).into_iter().cloned().collect())).collect::<Vec<(_, Vec<_>)>>();")?;
+        if group.required {
+            writeln!(output, "                if members.is_empty() {{")?;
+            write!(output, "                    return Err(ValidationError::GroupMissing {{ group: \"{}\", members: ", group.name.as_snake_case())?;
+            write_str_slice(&mut output, &members)?;
+            writeln!(output, " }});")?;
+            writeln!(output, "                }}")?;
+        }
+        if !group.multiple {
+            writeln!(output, "                if members.len() > 1 {{")?;
+            writeln!(output, "                    return Err(ValidationError::GroupConflict {{ group: \"{}\", members }});", group.name.as_snake_case())?;
+            writeln!(output, "                }}")?;
+        }
+        writeln!(output, "            }}")?;
+        any = true;
+    }
+    if any {
+        writeln!(output)?;
+    }
     Ok(())
 }
 
+/// Expression iterating over references to all values of a param in the validation function
+fn param_values_expr(param: &::config::Param) -> String {
+    let name = param.name.as_snake_case();
+    let is_single = param.nargs.is_none() && param.multiple.is_none() && !param.map;
+    match (&param.optionality, is_single) {
+        (Optionality::DefaultValue(_) | Optionality::Mandatory, true) => format!("::std::iter::once(&{})", name),
+        (Optionality::DefaultValue(_) | Optionality::Mandatory, false) if param.map => format!("{}.values()", name),
+        (Optionality::DefaultValue(_) | Optionality::Mandatory, false) => format!("{}.iter()", name),
+        (Optionality::Optional, true) => format!("{}.iter()", name),
+        (Optionality::Optional, false) if param.map => format!("{}.iter().flat_map(|map| map.values())", name),
+        (Optionality::Optional, false) => format!("{}.iter().flatten()", name),
+    }
+}
+
+/// Generates the call of the custom `validate` function for every value of a param
+fn gen_validate_call<W: Write>(param: &::config::Param, validate: &str, mut output: W) -> fmt::Result {
+    let name = param.name.as_snake_case();
+    writeln!(output, "            for value in {} {{", param_values_expr(param))?;
+    writeln!(output, "                if let Err(message) = {}(value) {{", validate)?;
+    writeln!(output, "                    return Err(ValidationError::Invalid {{ name: \"{}\", message }});", name)?;
+    writeln!(output, "                }}")?;
+    writeln!(output, "            }}")?;
+    writeln!(output)
+}
+
+/// Generates the check of `validate_regex`, the pattern is compiled on the first use and then kept
+/// in a static
+fn gen_regex_check<W: Write>(param: &::config::Param, pattern: &str, mut output: W) -> fmt::Result {
+    let name = param.name.as_snake_case();
+    let pattern_static = format!("{}_PATTERN", name.to_uppercase());
+    let values = param_values_expr(param);
+    writeln!(output, "            static {}: ::configure_me::helpers::Pattern = ::configure_me::helpers::Pattern::new({:?});", pattern_static, pattern)?;
+    if param.secret {
+        writeln!(output, "            if {}.any(|value| !{}.is_match(value)) {{", values, pattern_static)?;
+        writeln!(output, "                return Err(ValidationError::PatternMismatch {{ name: \"{}\", value: ::configure_me::helpers::REDACTED.to_owned(), pattern: {}.as_str() }});", name, pattern_static)?;
+    } else {
+        writeln!(output, "            if let Some(value) = {}.find(|value| !{}.is_match(value)) {{", values, pattern_static)?;
+        writeln!(output, "                return Err(ValidationError::PatternMismatch {{ name: \"{}\", value: value.clone(), pattern: {}.as_str() }});", name, pattern_static)?;
+    }
+    writeln!(output, "            }}")?;
+    writeln!(output)
+}
+
 fn underscore_to_hypen<W: Write>(mut output: W, ident: &str) -> fmt::Result {
     for c in ident.chars() {
         if c == '_' {
@@ -605,44 +3506,103 @@ fn underscore_to_hypen<W: Write>(mut output: W, ident: &str) -> fmt::Result {
     Ok(())
 }
 
-fn gen_merge_env<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+/// Writes the merging of the entries of a map param from the environment variables starting
+/// with `name` followed by `_`, which are taken from the iterator `vars`
+fn write_merge_env_map<W: Write>(param: &::config::Param, output: W, name: &str, vars: &str) -> fmt::Result {
+    let field = if param.is_wrapped() { ".map(|map| map.into_iter().map(|(key, value)| (key, value.0)).collect())" } else { "" };
+    let value = format!("::configure_me::helpers::parse_env_map::<{}, _>(\"{}_\", {}).map_err(|(name, err)| super::EnvParseError::Field{}(name, err))?{}", param.parse_type(), name, vars, param.name.as_pascal_case(), field);
+    let value = track_source(param, param.name.as_snake_case(), value, &format!("EnvVar(\"{}_*\")", name));
+    write_merge(param, output, "        ", &value)
+}
+
+/// Writes the merging of environment variables, which are looked up using the function `var` if
+/// it's given instead of reading the environment of the process, the entries of maps are then
+/// taken from the iterator `vars`
+fn gen_merge_env<W: Write>(config: &Config, mut output: W, var: Option<&str>) -> fmt::Result {
     for param in &config.params {
         if !param.env_var {
             continue;
         }
-        write!(output, "        if let Some(val) = ::std::env::var_os(\"")?;
-        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-        writeln!(output, "{}\") {{", param.name.as_upper_case())?;
-        writeln!(output, "            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::Field{})?;", param.name.as_pascal_case())?;
-        if let Some(merge_fn) = &param.merge_fn {
-            writeln!(output, "            if let Some({}_old) = &mut self.{} {{", param.name.as_snake_case(), param.name.as_snake_case())?;
-            writeln!(output, "                {}({}_old, val);", merge_fn, param.name.as_snake_case())?;
-            writeln!(output, "            }} else {{")?;
-            writeln!(output, "                self.{} = Some(val);", param.name.as_snake_case())?;
-            writeln!(output, "            }}")?;
-        } else {
-            writeln!(output, "            self.{} = Some(val);", param.name.as_snake_case())?;
+        let mut name = String::new();
+        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut name, prefix)?; write!(name, "_") }).unwrap_or(Ok(()))?;
+        write!(name, "{}", param.name.as_upper_case())?;
+        if param.map {
+            write_merge_env_map(param, &mut output, &name, var.map_or("::std::env::vars_os()", |_| "vars.iter().cloned()"))?;
+            continue;
         }
-        writeln!(output, "        }}")?;
+        // The variable is chosen at runtime if the param was renamed
+        let name = if param.renamed_from.is_empty() {
+            format!("\"{}\"", name)
+        } else {
+            let mut old_names = Vec::new();
+            for old in &param.renamed_from {
+                let mut old_name = String::new();
+                config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut old_name, prefix)?; write!(old_name, "_") }).unwrap_or(Ok(()))?;
+                write!(old_name, "{}", old.as_upper_case())?;
+                old_names.push(old_name);
+            }
+            let lookup = var.map_or("|name| ::std::env::var_os(name)".to_owned(), |var| format!("&{}", var));
+            write!(output, "        let {}_env_var = ::configure_me::helpers::renamed_env_var(\"{}\", ", param.name.as_snake_case(), name)?;
+            write_str_slice(&mut output, &old_names.iter().map(AsRef::as_ref).collect::<Vec<_>>())?;
+            writeln!(output, ", {});", lookup)?;
+            format!("{}_env_var", param.name.as_snake_case())
+        };
+        let (helper, arg) = match var {
+            // Secret params can be read from the file named by the variable with `_FILE` appended
+            _ if param.secret => {
+                let lookup = var.map_or("|name| ::std::env::var_os(name)".to_owned(), |var| format!("&{}", var));
+                ("parse_env_value", format!("::configure_me::helpers::secret_env_var({}, {}).map_err(|(file, error)| super::EnvParseError::SecretFile {{ name: {}, file, error }})?", name, lookup, name))
+            },
+            Some(var) => ("parse_env_value", format!("{}({})", var, name)),
+            None => ("parse_env", name.clone()),
+        };
+        let (turbofish, field) = match param.multiple {
+            Some(_) => {
+                let values = if param.is_wrapped() { ".into_iter().map(|value| value.0).collect()" } else { "" };
+                (format!("::<::configure_me::helpers::Delimited<{}, {:?}>>", param.parse_type(), param.env_delimiter), format!(".map(|values| values.0{})", values))
+            },
+            None => {
+                let (turbofish, field) = int_parsing(param, "");
+                (turbofish, if field.is_empty() { String::new() } else { format!(".map(|value| value{})", field) })
+            },
+        };
+        let value = match param.empty_env_var {
+            EmptyEnvVar::Value => format!("::configure_me::helpers::{}{}({}).map_err(super::EnvParseError::Field{})?{}", helper, turbofish, arg, param.name.as_pascal_case(), field),
+            EmptyEnvVar::Unset => format!("::configure_me::helpers::{}_ignore_empty{}({}).map_err(super::EnvParseError::Field{})?{}", helper, turbofish, arg, param.name.as_pascal_case(), field),
+            EmptyEnvVar::Error => format!("::configure_me::helpers::{}_reject_empty{}({}).map_err(|err| err.map_or(super::EnvParseError::Empty({}), super::EnvParseError::Field{}))?{}", helper, turbofish, arg, name, param.name.as_pascal_case(), field),
+        };
+        let value = track_source(param, param.name.as_snake_case(), value, &format!("EnvVar({})", name));
+        write_merge(param, &mut output, "        ", &value)?;
     }
     for switch in &config.switches {
         if !switch.env_var {
             continue;
         }
-        write!(output, "        if let Some(val) = ::std::env::var_os(\"")?;
-        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut output, &prefix)?; write!(output, "_") }).unwrap_or(Ok(()))?;
-        writeln!(output, "{}\") {{", switch.name.as_upper_case())?;
+        let mut name = String::new();
+        config.general.env_prefix.as_ref().map(|prefix| { upper_case(&mut name, prefix)?; write!(name, "_") }).unwrap_or(Ok(()))?;
+        write!(name, "{}", switch.name.as_upper_case())?;
+        let var = var.unwrap_or("::std::env::var_os");
+        match config.general.empty_env_var() {
+            EmptyEnvVar::Value => writeln!(output, "        if let Some(val) = {}(\"{}\") {{", var, name)?,
+            EmptyEnvVar::Unset => writeln!(output, "        if let Some(val) = {}(\"{}\").filter(|val| !val.is_empty()) {{", var, name)?,
+            EmptyEnvVar::Error => {
+                writeln!(output, "        if let Some(val) = {}(\"{}\") {{", var, name)?;
+                writeln!(output, "            if val.is_empty() {{")?;
+                writeln!(output, "                return Err(super::EnvParseError::Empty(\"{}\").into());", name)?;
+                writeln!(output, "            }}")?;
+            },
+        }
         if switch.is_count() {
             write!(output, "            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::Field{})?;", switch.name.as_pascal_case())?;
             writeln!(output, "            self.{} = Some(val);", switch.name.as_snake_case())?;
+            if switch.audit_combined {
+                writeln!(output, "            self._sources.add(\"{}\", ::configure_me::helpers::Source::EnvVar(\"{}\"));", switch.name.as_snake_case(), name)?;
+            }
         } else {
-            writeln!(output, "            if val == *\"1\" || val == *\"true\" {{")?;
-            writeln!(output, "                self.{} = Some(true);", switch.name.as_snake_case())?;
-            writeln!(output, "            }} else if val == *\"0\" || val == *\"false\" {{")?;
-            writeln!(output, "                self.{} = Some(false);", switch.name.as_snake_case())?;
-            writeln!(output, "            }} else {{")?;
-            writeln!(output, "                return Err(super::EnvParseError::Field{}(val).into());", switch.name.as_pascal_case())?;
-            writeln!(output, "            }}")?;
+            writeln!(output, "            self.{} = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::Field{})?);", switch.name.as_snake_case(), switch.name.as_pascal_case())?;
+            if switch.explain_overrides {
+                writeln!(output, "            self._sources.set(\"{}\", ::configure_me::helpers::Source::EnvVar(\"{}\"));", switch.name.as_snake_case(), name)?;
+            }
         }
         writeln!(output, "        }}")?;
     }
@@ -654,11 +3614,43 @@ fn gen_merge_args<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     write_config::<visitor::MergeArgs, _>(config, &mut output)
 }
 
+/// Expression of the version printed by `--version`, if it's generated
+fn version_expr(config: &Config) -> Option<String> {
+    match &config.general.version {
+        Some(::config::Version::Package(true)) => Some("env!(\"CARGO_PKG_VERSION\")".to_owned()),
+        Some(::config::Version::Fixed(version)) => Some(format!("{:?}", version)),
+        _ => None,
+    }
+}
+
+/// Expression evaluating to the program name shown in the usage
+///
+/// It's `general.name` if set, otherwise the path the program was executed with, or the name of
+/// the executed binary if the code is shared by multiple binaries.
+fn program_name_expr(config: &Config) -> String {
+    name_or_path_expr(config, config.general.name.as_deref())
+}
+
+/// Expression evaluating to the program name printed by `--version`, which is also the name of
+/// the cargo target if it's known
+fn version_name_expr(config: &Config) -> String {
+    name_or_path_expr(config, config.program_name())
+}
+
+fn name_or_path_expr(config: &Config, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{:?}.to_owned()", name),
+        None if config.library => "::configure_me::helpers::program_name(self._program_path.as_ref().unwrap())".to_owned(),
+        None => "self._program_path.as_ref().unwrap().to_string_lossy().into()".to_owned(),
+    }
+}
+
 pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "pub mod prelude {{")?;
     writeln!(output, "    pub use super::{{Config, ResultExt}};")?;
     writeln!(output, "}}")?;
     writeln!(output)?;
+    gen_enums(config, &mut output)?;
     writeln!(output, "pub enum ArgParseError {{")?;
     writeln!(output, "    MissingArgument(&'static str),")?;
     writeln!(output, "    UnknownArgument(String),")?;
@@ -701,14 +3693,45 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "    }}")?;
     writeln!(output, "}}")?;
     writeln!(output)?;
+    writeln!(output, "/// Mandatory param missing in all sources")?;
+    writeln!(output, "pub struct MissingField {{")?;
+    writeln!(output, "    pub name: &'static str,")?;
+    writeln!(output, "    /// Arguments and environment variables that can specify it")?;
+    writeln!(output, "    pub sources: &'static [&'static str],")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
     writeln!(output, "pub enum ValidationError {{")?;
-    writeln!(output, "    MissingField(&'static str),")?;
+    writeln!(output, "    MissingFields(Vec<MissingField>),")?;
+    writeln!(output, "    /// Value of a param outside of the range allowed by its `min` and `max`")?;
+    writeln!(output, "    OutOfRange {{ name: &'static str, value: String, range: &'static str }},")?;
+    writeln!(output, "    /// Value of a param not matching its `validate_regex`")?;
+    writeln!(output, "    PatternMismatch {{ name: &'static str, value: String, pattern: &'static str }},")?;
+    writeln!(output, "    /// Value of a param rejected by its `validate` function")?;
+    writeln!(output, "    Invalid {{ name: &'static str, message: String }},")?;
+    writeln!(output, "    /// Error returned by the function in `general.validate`")?;
+    writeln!(output, "    Custom(String),")?;
+    writeln!(output, "    /// Param or switch set without another one listed in its `requires`")?;
+    writeln!(output, "    MissingRequirement {{ name: &'static str, sources: Vec<::configure_me::helpers::Source>, required: &'static str }},")?;
+    writeln!(output, "    /// Param or switch set together with another one listed in its `conflicts_with`")?;
+    writeln!(output, "    Conflict {{ name: &'static str, sources: Vec<::configure_me::helpers::Source>, other: &'static str, other_sources: Vec<::configure_me::helpers::Source> }},")?;
+    writeln!(output, "    /// No member of a required group set")?;
+    writeln!(output, "    GroupMissing {{ group: &'static str, members: &'static [&'static str] }},")?;
+    writeln!(output, "    /// More than one member of a group set, with the sources of their values")?;
+    writeln!(output, "    GroupConflict {{ group: &'static str, members: Vec<(&'static str, Vec<::configure_me::helpers::Source>)> }},")?;
     writeln!(output, "}}")?;
     writeln!(output)?;
     writeln!(output, "impl ::std::fmt::Display for ValidationError {{")?;
     writeln!(output, "    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{")?;
     writeln!(output, "        match self {{")?;
-    writeln!(output, "            ValidationError::MissingField(field) => write!(f, \"Configuration parameter '{{}}' not specified.\", field),")?;
+    writeln!(output, "            ValidationError::MissingFields(fields) => ::configure_me::helpers::display_missing_fields(f, fields.iter().map(|field| (field.name, field.sources))),")?;
+    writeln!(output, "            ValidationError::OutOfRange {{ name, value, range }} => write!(f, \"Configuration parameter '{{}}' is {{}}, but it must be {{}}.\", name, value, range),")?;
+    writeln!(output, "            ValidationError::PatternMismatch {{ name, value, pattern }} => write!(f, \"Configuration parameter '{{}}' is '{{}}', but it must match the pattern '{{}}'.\", name, value, pattern),")?;
+    writeln!(output, "            ValidationError::Invalid {{ name, message }} => write!(f, \"Configuration parameter '{{}}' is invalid: {{}}\", name, message),")?;
+    writeln!(output, "            ValidationError::Custom(error) => write!(f, \"{{}}\", error),")?;
+    writeln!(output, "            ValidationError::MissingRequirement {{ name, sources, required }} => ::configure_me::helpers::display_missing_requirement(f, name, sources, required),")?;
+    writeln!(output, "            ValidationError::Conflict {{ name, sources, other, other_sources }} => ::configure_me::helpers::display_conflict(f, name, sources, other, other_sources),")?;
+    writeln!(output, "            ValidationError::GroupMissing {{ group, members }} => ::configure_me::helpers::display_group_missing(f, group, members),")?;
+    writeln!(output, "            ValidationError::GroupConflict {{ group, members }} => ::configure_me::helpers::display_group_conflict(f, group, members),")?;
     writeln!(output, "        }}")?;
     writeln!(output, "    }}")?;
     writeln!(output, "}}")?;
@@ -766,23 +3789,95 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "mod raw {{")?;
     writeln!(output, "    use ::std::path::PathBuf;")?;
     writeln!(output, "    use super::{{ArgParseError, ValidationError}};")?;
+    gen_raw_imports(config, &mut output)?;
     writeln!(output)?;
     writeln!(output, "    #[derive(Deserialize, Default)]")?;
     writeln!(output, "    #[serde(crate = \"crate::configure_me::serde\")]")?;
     writeln!(output, "    pub struct Config {{")?;
     writeln!(output, "        _program_path: Option<PathBuf>,")?;
+    writeln!(output, "        #[serde(skip)]")?;
+    writeln!(output, "        pub(super) _loaded_files: Vec<(PathBuf, ::configure_me::helpers::FileDigest)>,")?;
+    writeln!(output, "        #[serde(skip)]")?;
+    writeln!(output, "        pub(super) _parsed_files: Vec<(PathBuf, ::configure_me::toml::value::Table)>,")?;
     gen_raw_config(config, &mut output)?;
     writeln!(output, "    }}")?;
     writeln!(output)?;
     writeln!(output, "    impl Config {{")?;
-    writeln!(output, "        pub fn load<P: AsRef<::std::path::Path>>(config_file_name: P) -> Result<Self, super::Error> {{")?;
-    writeln!(output, "            use std::io::Read;")?;
+    writeln!(output, "        pub fn parse(&self, config_file_name: &::std::path::Path, config_content: &[u8]) -> Result<Self, super::Error> {{")?;
+    if config.general.json_config {
+        writeln!(output, "            self.parse_as(config_file_name, config_content, ::configure_me::ConfigFormat::of(config_file_name))")?;
+        writeln!(output, "        }}")?;
+        writeln!(output)?;
+        writeln!(output, "        fn parse_as(&self, config_file_name: &::std::path::Path, config_content: &[u8], format: ::configure_me::ConfigFormat) -> Result<Self, super::Error> {{")?;
+        writeln!(output, "            let config_content = ::configure_me::helpers::to_toml(config_content, format).map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.into(), error }})?;")?;
+        writeln!(output, "            self.parse_document(config_file_name, config_content)")?;
+    } else if parses_content_directly(config) {
+        writeln!(output, "            ::configure_me::toml::from_slice(config_content)")?;
+        gen_rename_keys_call(config, &mut output)?;
+        gen_track_file_call(config, &mut output)?;
+        writeln!(output, "                .map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.into(), error }})")?;
+    } else {
+        writeln!(output, "            self.parse_document(config_file_name, config_content)")?;
+    }
+    writeln!(output, "        }}")?;
+    writeln!(output)?;
+    writeln!(output, "        fn parse_document<D: ::configure_me::helpers::ConfigDocument>(&self, config_file_name: &::std::path::Path, config_content: D) -> Result<Self, super::Error> {{")?;
+    if config.general.profile_param.is_some() {
+        writeln!(output, "            let config_content = ::configure_me::helpers::select_profile(config_content, self._profile.as_deref()).map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.into(), error }})?;")?;
+    }
+    let table = config.general.config_table.as_ref().map_or_else(Vec::new, |table| table.split('.').collect::<Vec<_>>());
+    if !config.sections.is_empty() {
+        write!(output, "            ::configure_me::helpers::parse_sections(config_content, &{:?}, &[", table)?;
+        for (i, section) in config.sections.iter().enumerate() {
+            if i > 0 {
+                write!(output, ", ")?;
+            }
+            let params = config.section_params(Some(&section.name)).collect::<Vec<_>>();
+            // Old names of renamed params and aliases are prefixed by the section too
+            let old_keys = params
+                .iter()
+                .flat_map(|param| param.renamed_from.iter().chain(&param.aliases).map(move |old| param.section_key(old)));
+            let keys = params.iter().map(|param| param.key()).chain(old_keys).collect::<Vec<_>>();
+            write!(output, "({:?}, ", section.name.as_snake_case())?;
+            write_str_slice(&mut output, &keys)?;
+            write!(output, ")")?;
+        }
+        writeln!(output, "])")?;
+    } else {
+        writeln!(output, "            ::configure_me::helpers::parse_table(config_content, &{:?})", table)?;
+    }
+    gen_rename_keys_call(config, &mut output)?;
+    gen_track_file_call(config, &mut output)?;
+    writeln!(output, "                .map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.into(), error }})")?;
+    writeln!(output, "        }}")?;
     writeln!(output)?;
-    writeln!(output, "            let mut config_file = ::std::fs::File::open(&config_file_name).map_err(|error| super::Error::Reading {{ file: config_file_name.as_ref().into(), error }})?;")?;
-    writeln!(output, "            let mut config_content = Vec::new();")?;
-    writeln!(output, "            config_file.read_to_end(&mut config_content).map_err(|error| super::Error::Reading {{ file: config_file_name.as_ref().into(), error }})?;")?;
-    writeln!(output, "            ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.as_ref().into(), error }})")?;
+    writeln!(output, "        /// Loads the file, reading and parsing it only once even if it's specified multiple times")?;
+    writeln!(output, "        pub fn load_once<P: Into<PathBuf>>(&mut self, config_file_name: P) -> Result<Self, super::Error> {{")?;
+    writeln!(output, "            let config_file_name = config_file_name.into();")?;
+    writeln!(output, "            if let Some((_, table)) = self._parsed_files.iter().find(|(file, _)| *file == config_file_name) {{")?;
+    writeln!(output, "                return self.parse_document(&config_file_name, table.clone());")?;
+    writeln!(output, "            }}")?;
+    writeln!(output)?;
+    gen_read_config_file(config, &mut output)?;
+    if config.general.json_config {
+        writeln!(output, "            let table = ::configure_me::helpers::to_toml(&config_content, ::configure_me::ConfigFormat::of(&config_file_name))")?;
+    } else {
+        writeln!(output, "            let table = ::configure_me::helpers::ConfigDocument::into_table(&*config_content)")?;
+    }
+    writeln!(output, "                .map_err(|error| super::Error::ConfigParsing {{ file: config_file_name.clone(), error }})?;")?;
+    if parses_content_directly(config) {
+        writeln!(output, "            // Errors deserializing the table lack the location, parsing the content reports it")?;
+        writeln!(output, "            let config = self.parse_document(&config_file_name, table.clone()).or_else(|_| self.parse(&config_file_name, &config_content))?;")?;
+    } else {
+        writeln!(output, "            let config = self.parse_document(&config_file_name, table.clone())?;")?;
+    }
+    writeln!(output, "            self._loaded_files.push((config_file_name.clone(), ::configure_me::helpers::FileDigest::of(&config_content)));")?;
+    writeln!(output, "            self._parsed_files.push((config_file_name, table));")?;
+    writeln!(output, "            Ok(config)")?;
     writeln!(output, "        }}")?;
+    if config.general.json_config {
+        gen_load_once_as(config, &mut output)?;
+    }
     writeln!(output)?;
     writeln!(output, "        pub fn validate(self) -> Result<super::Config, ValidationError> {{")?;
     gen_validation_fn(config, &mut output)?;
@@ -790,22 +3885,51 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output)?;
     writeln!(output, "        pub fn merge_in(&mut self, other: Self) {{")?;
     write_params_and_switches::<visitor::MergeIn, _>(config, &mut output)?;
+    if config.general.tracks_sources() {
+        writeln!(output, "            self._sources.merge_in(other._sources);")?;
+    }
+    if config.general.profile_param.is_some() {
+        writeln!(output, "            self._profile = other._profile.or(self._profile.take());")?;
+    }
+    // Only layers of `PartialConfig` can contain the values of arguments that aren't options
+    if config.general.partial_config {
+        for positional in &config.positionals {
+            writeln!(output, "            ::configure_me::helpers::merge(&mut self.{}, other.{});", positional.name.as_snake_case(), positional.name.as_snake_case())?;
+        }
+        if !config.subcommands.is_empty() {
+            writeln!(output, "            ::configure_me::helpers::merge(&mut self._subcommand, other._subcommand);")?;
+        }
+    }
     writeln!(output, "        }}")?;
     writeln!(output)?;
     writeln!(output, "        pub fn merge_args<I: IntoIterator<Item=::std::ffi::OsString>>(&mut self, args: I) -> Result<impl Iterator<Item=::std::ffi::OsString>, super::Error> {{")?;
-    writeln!(output, "            let mut iter = args.into_iter().fuse();")?;
+    // Peekable so that params taking a bounded number of values can look at the next argument
+    writeln!(output, "            let mut iter = args.into_iter().fuse().peekable();")?;
     writeln!(output, "            self._program_path = iter.next().map(Into::into);")?;
+    gen_merge_args_init(config, &mut output)?;
     writeln!(output)?;
     writeln!(output, "            while let Some(arg) = iter.next() {{")?;
+    gen_normalize_arg(config, &mut output)?;
     writeln!(output, "                if arg == *\"--\" {{")?;
-    writeln!(output, "                    return Ok(None.into_iter().chain(iter));")?;
+    let separator = if config.general.forward_separator || has_trailing_positional(config) { "Some(arg)" } else { "None" };
+    writeln!(output, "                    return Ok({}.into_iter().chain(iter));", separator)?;
     writeln!(output, "                }} else if (arg == *\"--help\") || (arg == *\"-h\") {{")?;
-    writeln!(output, "                    return Err(ArgParseError::HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into()).into());")?;
+    writeln!(output, "                    return Err(ArgParseError::HelpRequested({}).into());", program_name_expr(config))?;
+    if let Some(version) = version_expr(config) {
+        writeln!(output, "                }} else if arg == *\"--version\" {{")?;
+        writeln!(output, "                    let program_name: String = {};", version_name_expr(config))?;
+        writeln!(output, "                    return Err(ArgParseError::VersionRequested(format!(\"{{}} {{}}\", program_name, {})).into());", version)?;
+    }
     write_config::<visitor::MergeArgs, _>(config, &mut output)?;
     writeln!(output, "                }} else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {{")?;
     writeln!(output, "                    for short in &mut shorts {{")?;
     writeln!(output, "                        if short == 'h' {{")?;
-    writeln!(output, "                            return Err(ArgParseError::HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into()).into())")?;
+    writeln!(output, "                            return Err(ArgParseError::HelpRequested({}).into())", program_name_expr(config))?;
+    if let Some(version) = version_expr(config) {
+        writeln!(output, "                        }} else if short == 'V' {{")?;
+        writeln!(output, "                            let program_name: String = {};", version_name_expr(config))?;
+        writeln!(output, "                            return Err(ArgParseError::VersionRequested(format!(\"{{}} {{}}\", program_name, {})).into())", version)?;
+    }
     write_config::<visitor::MergeShort, _>(config, &mut output)?;
     writeln!(output, "                        }} else {{")?;
     writeln!(output, "                            let mut arg = String::with_capacity(2);")?;
@@ -825,16 +3949,54 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "        }}")?;
     writeln!(output)?;
     writeln!(output, "        pub fn merge_env(&mut self) -> Result<(), super::Error> {{")?;
-    gen_merge_env(config, &mut output)?;
+    if let Some(env_file) = &config.general.env_file {
+        // Variables set in the environment take precedence over the file
+        writeln!(output, "            let env_file_path = super::ENV_FILE.lock().unwrap_or_else(|err| err.into_inner()).clone().unwrap_or_else(|| {:?}.into());", env_file)?;
+        writeln!(output, "            let env_file = ::configure_me::helpers::EnvFile::load(&env_file_path).map_err(|error| super::Error::Reading {{ file: env_file_path, error }})?;")?;
+        if has_env_maps(config) {
+            writeln!(output, "            self.merge_env_with(|name| env_file.var(name), env_file.vars())?;")?;
+        } else {
+            writeln!(output, "            self.merge_env_with(|name| env_file.var(name))?;")?;
+        }
+    } else if config.general.merge_report {
+        if has_env_maps(config) {
+            writeln!(output, "            self.merge_env_with(|name| ::std::env::var_os(name), ::std::env::vars_os())?;")?;
+        } else {
+            writeln!(output, "            self.merge_env_with(|name| ::std::env::var_os(name))?;")?;
+        }
+    } else {
+        gen_merge_env(config, &mut output, None)?;
+    }
     writeln!(output, "            Ok(())")?;
     writeln!(output, "        }}")?;
     writeln!(output, "    }}")?;
+    gen_raw_fns(config, &mut output)?;
     writeln!(output, "}}")?;
     writeln!(output)?;
     writeln!(output, "/// Configuration of the application")?;
     writeln!(output, "pub struct Config {{")?;
-    write_params_and_switches::<visitor::ConfigFinal, _>(config, &mut output)?;
+    visitor::iter::<visitor::ConfigFinal, _, _>(config.section_params(None), &mut output)?;
+    visitor::iter::<visitor::ConfigFinal, _, _>(&config.switches, &mut output)?;
+    visitor::iter::<visitor::ConfigFinal, _, _>(&config.positionals, &mut output)?;
+    if !config.subcommands.is_empty() {
+        writeln!(output, "    pub subcommand: Option<Subcommand>,")?;
+    }
+    for section in &config.sections {
+        write_doc_comment(&mut output, "    ", &section.doc)?;
+        writeln!(output, "    pub {}: {},", section.name.as_snake_case(), section.name.as_pascal_case())?;
+    }
+    if config.general.reload_if_changed {
+        writeln!(output, "    _poll_state: ::configure_me::helpers::PollState,")?;
+    }
+    if config.general.loaded_config_files {
+        writeln!(output, "    _loaded_config_files: Vec<::std::path::PathBuf>,")?;
+    }
+    if config.general.explain_config_switch.is_some() {
+        writeln!(output, "    _overrides: Vec<::configure_me::helpers::Override>,")?;
+    }
     writeln!(output, "}}")?;
+    gen_sections(config, &mut output)?;
+    gen_subcommands(config, &mut output)?;
     writeln!(output)?;
     writeln!(output, "impl Config {{")?;
     writeln!(output, "    pub fn including_optional_config_files<I>(config_files: I) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
@@ -845,10 +4007,54 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,")?;
     writeln!(output, "        I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
     writeln!(output)?;
+    if config.general.json_config {
+        writeln!(output, "        Self::custom_args_and_files_with_format(args, config_files, ::configure_me::ConfigFormat::of)")?;
+        writeln!(output, "    }}")?;
+        writeln!(output)?;
+        writeln!(output, "    /// Loads the config files in `format` regardless of their extensions")?;
+        writeln!(output, "    pub fn including_optional_config_files_as<I>(config_files: I, format: ::configure_me::ConfigFormat) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
+        writeln!(output, "        Self::custom_args_and_optional_files_as(::std::env::args_os(), config_files, format)")?;
+        writeln!(output, "    }}")?;
+        writeln!(output)?;
+        writeln!(output, "    /// Loads the config files in `format` regardless of their extensions")?;
+        writeln!(output, "    pub fn custom_args_and_optional_files_as<A, I>(args: A, config_files: I, format: ::configure_me::ConfigFormat) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where")?;
+        writeln!(output, "        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,")?;
+        writeln!(output, "        I: IntoIterator, I::Item: AsRef<::std::path::Path> {{")?;
+        writeln!(output)?;
+        writeln!(output, "        Self::custom_args_and_files_with_format(args, config_files, move |_| format)")?;
+        writeln!(output, "    }}")?;
+        writeln!(output)?;
+        writeln!(output, "    fn custom_args_and_files_with_format<A, I, F>(args: A, config_files: I, format: F) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where")?;
+        writeln!(output, "        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,")?;
+        writeln!(output, "        I: IntoIterator, I::Item: AsRef<::std::path::Path>,")?;
+        writeln!(output, "        F: Fn(&::std::path::Path) -> ::configure_me::ConfigFormat {{")?;
+        writeln!(output)?;
+    }
+    gen_check_config_call(config, &mut output)?;
+    if config.general.reload_if_changed {
+        gen_poll_init(config, &mut output)?;
+    }
     writeln!(output, "        let mut config = raw::Config::default();")?;
+    gen_profile_init(config, &mut output)?;
     writeln!(output, "        for path in config_files {{")?;
-    writeln!(output, "            match raw::Config::load(path) {{")?;
+    let load = if config.general.json_config {
+        "config.load_once_as(path.as_ref(), format(path.as_ref()))"
+    } else {
+        "config.load_once(path.as_ref())"
+    };
+    let steps = load_steps(&config.general);
+    if let Some((last, steps)) = steps.split_last() {
+        writeln!(output, "            let loaded = {};", load)?;
+        for step in steps {
+            writeln!(output, "            let loaded = config.{}(path.as_ref(), loaded);", step)?;
+        }
+        writeln!(output, "            match config.{}(path.as_ref(), loaded) {{", last)?;
+    } else {
+        writeln!(output, "            match {} {{", load)?;
+    }
     writeln!(output, "                Ok(mut new_config) => {{")?;
+    writeln!(output, "                    new_config._loaded_files = ::std::mem::replace(&mut config._loaded_files, Vec::new());")?;
+    writeln!(output, "                    new_config._parsed_files = ::std::mem::replace(&mut config._parsed_files, Vec::new());")?;
     writeln!(output, "                    std::mem::swap(&mut config, &mut new_config);")?;
     writeln!(output, "                    config.merge_in(new_config)")?;
     writeln!(output, "                }},")?;
@@ -859,13 +4065,89 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output)?;
     writeln!(output, "        config.merge_env()?;")?;
     writeln!(output, "        let remaining_args = config.merge_args(args.into_iter().map(Into::into))?;")?;
+    if !config.positionals.is_empty() {
+        writeln!(output, "        let remaining_args = config.merge_positionals(remaining_args)?;")?;
+    }
+    if !config.subcommands.is_empty() {
+        writeln!(output, "        let remaining_args = config.merge_subcommand(remaining_args)?;")?;
+    }
     writeln!(output)?;
     writeln!(output, "        config")?;
     writeln!(output, "            .validate()")?;
+    if config.general.reload_if_changed {
+        writeln!(output, "            .map(|mut cfg| {{")?;
+        writeln!(output, "                cfg._poll_state.set_sources(watched_args, watched_files);")?;
+        writeln!(output, "                cfg")?;
+        writeln!(output, "            }})")?;
+    }
     writeln!(output, "            .map(|cfg| (cfg, remaining_args))")?;
     writeln!(output, "            .map_err(Into::into)")?;
     writeln!(output, "    }}")?;
     writeln!(output, "}}")?;
+    if config.general.global_config {
+        gen_global_config(&mut output)?;
+    }
+    if config.general.shared_config {
+        gen_shared_config(&mut output)?;
+    }
+    if config.general.export_env_vars {
+        gen_export_env_vars(config, &mut output)?;
+    }
+    if config.general.export_args {
+        gen_export_args(config, &mut output)?;
+    }
+    if config.general.non_default_fields {
+        gen_non_default_fields(config, &mut output)?;
+    }
+    if config.general.interactive_setup {
+        gen_interactive_setup(config, &mut output)?;
+    }
+    if let Some(check) = &config.general.check_config_switch {
+        gen_check_config(config, &param_long_raw(check.as_snake_case()), &mut output)?;
+    }
+    if let Some(dump) = &config.general.dump_config_switch {
+        gen_dump_config(config, &param_long_raw(dump.as_snake_case()), &mut output)?;
+    }
+    if config.general.merge_report {
+        gen_merge_report(config, &mut output)?;
+    }
+    if config.general.reload_if_changed {
+        gen_reload_if_changed(&mut output)?;
+    }
+    if config.general.watch_config {
+        gen_watch(&mut output)?;
+    }
+    if config.general.arbitrary {
+        gen_arbitrary(config, &mut output)?;
+    }
+    // The dumped configuration is formatted by `to_redacted_toml()`
+    if config.general.redacted_rendering || config.general.dump_config_switch.is_some() {
+        gen_redacted_rendering(config, &mut output)?;
+    }
+    if config.general.audit {
+        gen_audit(config, &mut output)?;
+    }
+    if config.general.debug {
+        gen_debug(config, &mut output)?;
+    }
+    if config.general.serialize {
+        gen_serialize(config, &mut output)?;
+    }
+    if let Some(env_file) = &config.general.env_file {
+        gen_env_file(env_file, &mut output)?;
+    }
+    if let (true, Some(name)) = (config.general.user_config, &config.general.name) {
+        gen_user_config(name, &mut output)?;
+    }
+    if config.general.loaded_config_files {
+        gen_loaded_config_files(&mut output)?;
+    }
+    if let Some(explain) = &config.general.explain_config_switch {
+        gen_overrides(explain, &mut output)?;
+    }
+    if config.general.partial_config {
+        gen_partial_config(config, &mut output)?;
+    }
     writeln!(output)?;
     writeln!(output, "pub trait ResultExt {{")?;
     writeln!(output, "    type Item;")?;
@@ -886,6 +4168,14 @@ pub fn generate_code<W: Write>(config: &Config, mut output: W) -> fmt::Result {
     writeln!(output, "                std::io::stdout().flush().expect(\"failed to flush stdout\");")?;
     writeln!(output, "                ::std::process::exit(0)")?;
     writeln!(output, "            }},")?;
+    if config.general.prints_version() {
+        writeln!(output, "            Err(err @ Error::Arguments(ArgParseError::VersionRequested(_))) => {{")?;
+        writeln!(output, "                println!(\"{{}}\", err);")?;
+        writeln!(output, "                std::io::stdout().flush().expect(\"failed to flush stdout\");")?;
+        writeln!(output, "                ::std::process::exit(0)")?;
+        writeln!(output, "            }},")?;
+    }
+    gen_subcommand_errors(config, &mut output)?;
     writeln!(output, "            Err(err) => {{")?;
     writeln!(output, "                eprintln!(\"Error: {{}}\", err);")?;
     writeln!(output, "                std::io::stderr().flush().expect(\"failed to flush stderr\");")?;
@@ -3,29 +3,167 @@ use std::fmt;
 #[derive(Debug)]
 pub enum ValidationErrorKind {
     MandatoryWithDefault,
+    DefaultWithDefaultFn,
+    InvalidDefaultFn(Box<str>),
     InvertedWithAbbr,
     InvertedWithCount,
+    NegatableNotNormal,
+    CountOptionsWithoutCount,
+    InvalidMaxCount,
+    InvalidCountMapping,
+    CountValuesType,
+    InvalidMapTo(Box<str>),
     InvalidAbbr,
+    AlreadyDefined,
+    UnknownOverride,
+    InvalidSwitchOverride,
+    InvalidType(Box<str>),
+    Duplicate,
+    InvalidPrefix,
+    InvalidConvertInto(Box<str>),
+    InvalidValidateFn(Box<str>),
+    InvalidNargs,
+    NargsWithEnvVar,
+    RepeatableDuplicateArg,
+    RenamedFromDefined,
+    AliasDefined,
+    RangeType,
+    InvalidRange,
+    RegexType,
+    DurationMultiple,
+    SizeMultiple,
+    UnknownRelation,
+    GroupDefined,
+    InvalidGroupMembers,
+    UnconstrainedGroup,
+    InvalidRegex(Box<str>),
+    MultipleConflict,
+    NotMultiple,
+    MapConflict,
+    MapEnvVarConflict(Box<str>),
+    InvalidValues,
+    ValuesType,
+    DuplicateValues,
+    ReservedValuesType,
+    SectionDefined,
+    SectionParamDefined,
+    PositionalDefined,
+    VariadicNotLast,
+    TrailingNotVariadic,
+    MandatoryAfterOptional,
+    SubcommandDefined,
+    SubcommandWithPositionals,
+    SubcommandField,
+    ReservedSubcommand,
+    SubcommandFileWithItems,
+    ReservedInclude,
+    ReservedProfile,
+    ReservedVersion,
 }
 
+/// Byte range in the specification source
+pub type Span = (usize, usize);
+
 #[derive(Debug)]
-pub struct ValidationError {
-    name: String,
-    kind: ValidationErrorKind,
+pub enum ValidationError {
+    Field { section: &'static str, name: String, kind: ValidationErrorKind, span: Span },
+    MissingDoc { names: Vec<String> },
+    ImplicitOptionality { names: Vec<String> },
+    RenamedConflict(::legacy::Renamed),
+    AmbiguousSwitchValue(String),
+    InvalidConfigTable(String),
+    InvalidValidateFn(String),
+    UserConfigWithoutName,
+    WatchConfigWithoutReload,
+}
+
+impl ValidationError {
+    /// Location of the offending item in the specification, if known
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            ValidationError::Field { span, .. } => Some(*span),
+            ValidationError::MissingDoc { .. } | ValidationError::ImplicitOptionality { .. } | ValidationError::RenamedConflict(_) | ValidationError::AmbiguousSwitchValue(_) | ValidationError::InvalidConfigTable(_) | ValidationError::InvalidValidateFn(_) | ValidationError::UserConfigWithoutName | ValidationError::WatchConfigWithoutReload => None,
+        }
+    }
 }
 
 impl fmt::Display for ValidationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::ValidationErrorKind::*;
 
-        let msg = match self.kind {
-            MandatoryWithDefault => "parameter with default value must be optional",
-            InvertedWithAbbr => "inverted switch can't have short option",
-            InvertedWithCount => "inverted switch can't be count",
-            InvalidAbbr => "invalid short switch: must be [a-zA-Z]",
-        };
+        match self {
+            ValidationError::Field { section, name, kind, .. } => {
+                let msg = match kind {
+                    MandatoryWithDefault => "default conflicts with optional = false",
+                    DefaultWithDefaultFn => "default and default_fn can't be combined",
+                    InvertedWithAbbr => "inverted switch can't have short option",
+                    InvertedWithCount => "inverted switch can't be count",
+                    NegatableNotNormal => "negatable requires a switch that is neither inverted nor count",
+                    CountOptionsWithoutCount => "max_count, map_to, values and map_values require count = true",
+                    InvalidMaxCount => "max_count must be at least 1",
+                    InvalidCountMapping => "map_to requires either values naming the variants of a generated enum or map_values, the values for the counts",
+                    CountValuesType => "map_to of a switch with values must be the name of the generated enum",
+                    InvalidAbbr => "invalid short switch: must be [a-zA-Z]",
+                    AlreadyDefined => "already defined in the extended specification, use [[override]] to change it",
+                    UnknownOverride => "no param or switch with this name in the extended specification",
+                    InvalidSwitchOverride => "only doc can be overridden for switches",
+                    Duplicate => "defined more than once, include the fragment with a different prefix",
+                    InvalidPrefix => "prefix must consist of [a-z0-9_] and not start with a digit",
+                    InvalidNargs => "nargs must be at least 1, min can't exceed max and a fixed count can't exceed 32",
+                    NargsWithEnvVar => "params taking multiple values can't be read from environment variables",
+                    RepeatableDuplicateArg => "duplicate_arg can't be set on a param with merge_fn or a count switch, all their occurrences are used",
+                    RenamedFromDefined => "renamed_from can't contain the name of a param or switch",
+                    AliasDefined => "aliases can't contain the name of a param, switch, an old name or another alias",
+                    RangeType => "min and max require a param of a primitive numeric type taking a single value",
+                    InvalidRange => "min and max of an integer param must be integers fitting into its type and min can't exceed max",
+                    RegexType => "validate_regex requires a param of type String taking a single value or multiple = true",
+                    DurationMultiple => "params of type duration take a single value, they can't use nargs, multiple or map",
+                    SizeMultiple => "params of type size take a single value, they can't use nargs, multiple or map",
+                    UnknownRelation => "requires and conflicts_with must contain names of other params or switches",
+                    GroupDefined => "another group has the same name",
+                    InvalidGroupMembers => "members must be distinct names of params or switches",
+                    UnconstrainedGroup => "a group allowing multiple members must be required, otherwise it doesn't check anything",
+                    MultipleConflict => "multiple = true can't be combined with nargs, merge_fn or duplicate_arg, use list_merge to combine the values",
+                    NotMultiple => "list_merge and env_delimiter require multiple = true",
+                    MapConflict => "map = true can't be combined with nargs, multiple, merge_fn or duplicate_arg, the entries are merged by their keys",
+                    InvalidValues => "values must be distinct, start with a letter and consist of [a-zA-Z0-9_-]",
+                    ValuesType => "type of a param with values must be the name of the generated enum",
+                    DuplicateValues => "values of this type are already defined by another param, omit them to reuse the enum",
+                    ReservedValuesType => "the name of the generated enum is already used by a generated item or a standard type",
+                    SectionDefined => "the name is already used by a param, switch or another section",
+                    SectionParamDefined => "the name prefixed by the name of the section is already used by a param or switch",
+                    PositionalDefined => "the name is already used by a param, switch, section or another positional",
+                    VariadicNotLast => "only the last positional can be variadic",
+                    TrailingNotVariadic => "trailing requires variadic = true",
+                    MandatoryAfterOptional => "a mandatory positional can't follow an optional one",
+                    SubcommandDefined => "another subcommand has the same name",
+                    SubcommandWithPositionals => "subcommands can't be combined with positionals, the first argument after the options selects the subcommand",
+                    SubcommandField => "the field `subcommand` holding the selected subcommand is already used by a param or switch",
+                    ReservedSubcommand => "raw and prelude are reserved for the generated modules",
+                    SubcommandFileWithItems => "file can't be combined with params, switches or positionals of the subcommand, declare them in the file",
+                    ReservedInclude => "include is reserved for the files included by config files when general.config_includes is set",
+                    ReservedProfile => "profile is reserved for the profiles of config files when general.profile_param is set",
+                    ReservedVersion => "version and the short option V are reserved for --version when general.version is set",
+                    InvalidType(error) => return write!(f, "{} \"{}\": `type` is not a valid Rust type: {}", section, name, error),
+                    InvalidConvertInto(error) => return write!(f, "{} \"{}\": `convert_into` is not a valid Rust type: {}", section, name, error),
+                    InvalidValidateFn(error) => return write!(f, "{} \"{}\": `validate` is not a valid path of a function: {}", section, name, error),
+                    InvalidDefaultFn(error) => return write!(f, "{} \"{}\": `default_fn` is not a valid path of a function: {}", section, name, error),
+                    InvalidMapTo(error) => return write!(f, "{} \"{}\": `map_to` is not a valid Rust type: {}", section, name, error),
+                    InvalidRegex(error) => return write!(f, "{} \"{}\": `validate_regex` is not a valid regular expression: {}", section, name, error),
+                    MapEnvVarConflict(other) => return write!(f, "{} \"{}\": the environment variables of the map would include the one of \"{}\", rename one of them or set env_var = false", section, name, other),
+                };
 
-        write!(f, "invalid configuration for field {}: {}", self.name, msg)
+                write!(f, "{} \"{}\": {}", section, name, msg)
+            },
+            ValidationError::MissingDoc { names } => write!(f, "documentation is required (general.require_doc = true), but these fields are missing doc: {}", names.join(", ")),
+            ValidationError::ImplicitOptionality { names } => write!(f, "explicit optionality is required (general.require_explicit_optional = true), but these params have neither optional nor default: {}", names.join(", ")),
+            ValidationError::AmbiguousSwitchValue(value) => write!(f, "\"{}\" is in both general.true_values and general.false_values", value),
+            ValidationError::InvalidConfigTable(table) => write!(f, "general.config_table \"{}\" must be keys consisting of [a-zA-Z0-9_-] separated by dots", table),
+            ValidationError::InvalidValidateFn(path) => write!(f, "general.validate \"{}\" is not a valid path of a function", path),
+            ValidationError::UserConfigWithoutName => write!(f, "general.user_config requires general.name, which names the directories of the config files"),
+            ValidationError::WatchConfigWithoutReload => write!(f, "general.watch_config requires general.reload_if_changed, which keeps the sources of the configuration"),
+            ValidationError::RenamedConflict(renamed) => write!(f, "both `{}.{}` and `{}.{}` are specified, remove the deprecated one", renamed.section, renamed.old, renamed.section, renamed.new),
+        }
     }
 }
 
@@ -33,6 +171,7 @@ mod ident {
     use std::convert::TryFrom;
     use std::fmt::{self, Write};
 
+    #[derive(Debug)]
     pub struct Error {
         string: String,
         position: usize,
@@ -44,7 +183,7 @@ mod ident {
         }
     }
 
-    #[derive(Debug, Deserialize)]
+    #[derive(Debug, Clone, Deserialize)]
     #[serde(try_from = "String")]
     pub struct Ident(String);
 
@@ -55,7 +194,7 @@ mod ident {
             let bad_char = string
                 .chars()
                 .enumerate()
-                .find(|&(i, c)| c != '_' && ! ((c >= 'a' && c <= 'z') || (c >= '0' && c <= '9' && i > 0)));
+                .find(|&(i, c)| c != '_' && ! (c.is_ascii_lowercase() || (c.is_ascii_digit() && i > 0)));
 
             match bad_char {
                 Some((i, _)) => {
@@ -133,91 +272,838 @@ mod ident {
     }
 }
 
-use self::ident::Ident;
+pub use self::ident::Ident;
 
 pub mod raw {
-    use super::{ValidationError, ValidationErrorKind, Optionality, SwitchKind};
+    use super::{ValidationError, ValidationErrorKind, Optionality, SwitchKind, Span};
     use super::ident::Ident;
+    use ::serde::{Deserialize, Deserializer};
+    use std::convert::TryFrom;
+
+    /// Like `toml::Spanned`, but can be constructed, so that included items can be renamed
+    #[derive(Debug, Clone)]
+    pub struct Spanned<T> {
+        span: Span,
+        value: T,
+    }
+
+    impl<T> Spanned<T> {
+        pub fn span(&self) -> Span {
+            self.span
+        }
+
+        pub fn get_ref(&self) -> &T {
+            &self.value
+        }
+
+        pub fn into_inner(self) -> T {
+            self.value
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Spanned<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let spanned = ::toml::Spanned::<T>::deserialize(deserializer)?;
+            Ok(Spanned {
+                span: spanned.span(),
+                value: spanned.into_inner(),
+            })
+        }
+    }
 
     trait ResultExt {
         type Item;
 
-        fn field_name(self, name: &Ident) -> Result<Self::Item, ValidationError>;
+        fn field_error(self, section: &'static str, name: &Ident, span: Span) -> Result<Self::Item, ValidationError>;
     }
 
     impl<T> ResultExt for Result<T, ValidationErrorKind> {
         type Item = T;
 
-        fn field_name(self, name: &Ident) -> Result<Self::Item, ValidationError> {
-            self.map_err(|kind| ValidationError { name: name.as_snake_case().to_owned(), kind })
+        fn field_error(self, section: &'static str, name: &Ident, span: Span) -> Result<Self::Item, ValidationError> {
+            self.map_err(|kind| ValidationError::Field { section, name: name.as_snake_case().to_owned(), kind, span })
         }
     }
 
-    #[derive(Debug)]
+    /// Fragment of specification whose params and switches are added to this one
+    #[derive(Debug, Clone)]
+    #[derive(Deserialize)]
+    pub struct Include {
+        /// Path to the fragment, relative to the including specification
+        pub file: Spanned<String>,
+        /// Prepended to names of all params and switches of the fragment
+        #[serde(default)]
+        pub prefix: String,
+    }
+
+    #[derive(Debug, Clone, Default)]
     #[derive(Deserialize)]
-    #[serde(deny_unknown_fields)]
     pub struct Config {
+        /// Path to the specification this one extends, relative to this one
+        extends: Option<String>,
+        #[serde(rename = "include")]
+        #[serde(default)]
+        includes: Vec<Include>,
+        #[serde(rename = "override")]
+        #[serde(default)]
+        overrides: Vec<Override>,
         #[serde(rename = "param")]
         #[serde(default)]
         pub params: Vec<Param>,
         #[serde(rename = "switch")]
         #[serde(default)]
         pub switches: Vec<Switch>,
+        #[serde(rename = "section")]
+        #[serde(default)]
+        sections: Vec<Section>,
+        #[serde(rename = "positional")]
+        #[serde(default)]
+        positionals: Vec<Positional>,
+        #[serde(rename = "subcommand")]
+        #[serde(default)]
+        subcommands: Vec<Subcommand>,
+        #[serde(rename = "group")]
+        #[serde(default)]
+        groups: Vec<Group>,
         #[serde(default)]
         general: super::General,
         #[serde(default)]
-        defaults: super::Defaults,
+        defaults: Defaults,
         #[cfg(feature = "debconf")]
         debconf: Option<::debconf::DebConfig>,
     }
 
+    /// Group of params stored in a table of config files and in a nested struct
+    #[derive(Debug, Clone)]
+    #[derive(Deserialize)]
+    struct Section {
+        name: Spanned<Ident>,
+        doc: Option<String>,
+        #[serde(rename = "param")]
+        #[serde(default)]
+        params: Vec<Param>,
+    }
+
+    /// Params and switches of which at most one can be set
+    #[derive(Debug, Clone)]
+    #[derive(Deserialize)]
+    struct Group {
+        name: Spanned<Ident>,
+        doc: Option<String>,
+        members: Vec<Ident>,
+        #[serde(default)]
+        required: bool,
+        #[serde(default)]
+        multiple: bool,
+    }
+
+    /// Argument identified by its position instead of an option
+    #[derive(Debug, Clone)]
+    #[derive(Deserialize)]
+    struct Positional {
+        name: Spanned<Ident>,
+        #[serde(rename = "type")]
+        ty: Spanned<String>,
+        #[serde(default)]
+        optional: bool,
+        #[serde(default)]
+        variadic: bool,
+        #[serde(default)]
+        trailing: bool,
+        doc: Option<String>,
+    }
+
+    /// Program selected by the first argument after the options, declared inline or in its own
+    /// specification
+    #[derive(Debug, Clone)]
+    #[derive(Deserialize)]
+    pub struct Subcommand {
+        name: Spanned<Ident>,
+        doc: Option<String>,
+        /// Path to the specification of the subcommand, relative to this one
+        file: Option<Spanned<String>>,
+        #[serde(rename = "param")]
+        #[serde(default)]
+        params: Vec<Param>,
+        #[serde(rename = "switch")]
+        #[serde(default)]
+        switches: Vec<Switch>,
+        #[serde(rename = "positional")]
+        #[serde(default)]
+        positionals: Vec<Positional>,
+        /// Loaded from `file`
+        #[serde(skip)]
+        spec: Option<Box<Config>>,
+    }
+
+    impl Subcommand {
+        fn has_items(&self) -> bool {
+            !self.params.is_empty() || !self.switches.is_empty() || !self.positionals.is_empty()
+        }
+
+        /// The specification of the subcommand inherits the environment variable prefix and the
+        /// name of the program, its values are stored in a table named after it.
+        fn validate(self, general: &super::General) -> Result<super::Subcommand, ValidationError> {
+            let name_span = self.name.span();
+            if self.file.is_some() && self.has_items() {
+                return Err(ValidationErrorKind::SubcommandFileWithItems).field_error("subcommand", self.name.get_ref(), name_span);
+            }
+            let name = self.name.into_inner();
+            let mut spec = match self.spec {
+                Some(spec) => *spec,
+                None => Config {
+                    params: self.params,
+                    switches: self.switches,
+                    positionals: self.positionals,
+                    ..Default::default()
+                },
+            };
+            let key = name.as_snake_case();
+            if spec.general.env_prefix.is_none() {
+                spec.general.env_prefix = general.env_prefix.as_ref().map(|prefix| format!("{}_{}", prefix, key));
+            }
+            if spec.general.config_table.is_none() {
+                spec.general.config_table = Some(match &general.config_table {
+                    Some(table) => format!("{}.{}", table, key),
+                    None => key.to_owned(),
+                });
+            }
+            if spec.general.name.is_none() {
+                spec.general.name = general.name.as_ref().map(|program| format!("{} {}", program, key));
+            }
+            // `Debug` of the parent requires the configurations of subcommands to implement it
+            spec.general.debug |= general.debug;
+            spec.general.rich_help |= general.rich_help;
+            spec.general.help_integer_notation |= general.help_integer_notation;
+
+            Ok(super::Subcommand {
+                config: spec.validate()?,
+                name,
+                doc: self.doc,
+            })
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    #[derive(Deserialize)]
+    struct Defaults {
+        args: Option<bool>,
+        env_vars: Option<bool>,
+        optional: Option<bool>,
+        negatable: Option<bool>,
+    }
+
+    impl Defaults {
+        fn or(self, base: Defaults) -> Defaults {
+            Defaults {
+                args: self.args.or(base.args),
+                env_vars: self.env_vars.or(base.env_vars),
+                optional: self.optional.or(base.optional),
+                negatable: self.negatable.or(base.negatable),
+            }
+        }
+    }
+
+    /// Changes an inherited param or switch
+    #[derive(Debug, Clone)]
+    #[derive(Deserialize)]
+    struct Override {
+        name: Spanned<Ident>,
+        doc: Option<String>,
+        optional: Option<bool>,
+        default: Option<Spanned<String>>,
+    }
+
+    impl Override {
+        /// Setting either `optional` or `default` replaces both inherited values.
+        fn apply(self, params: &mut [Param], switches: &mut [Switch]) -> Result<(), ValidationError> {
+            let span = self.name.span();
+            let name = self.name.into_inner();
+
+            if let Some(param) = params.iter_mut().find(|param| param.name.get_ref().as_snake_case() == name.as_snake_case()) {
+                if self.doc.is_some() {
+                    param.doc = self.doc;
+                }
+                if self.optional.is_some() || self.default.is_some() {
+                    param.optional = self.optional;
+                    param.default = self.default;
+                }
+                Ok(())
+            } else if let Some(switch) = switches.iter_mut().find(|switch| switch.name.get_ref().as_snake_case() == name.as_snake_case()) {
+                if self.optional.is_some() || self.default.is_some() {
+                    return Err(ValidationErrorKind::InvalidSwitchOverride).field_error("override", &name, span);
+                }
+                if self.doc.is_some() {
+                    switch.doc = self.doc;
+                }
+                Ok(())
+            } else {
+                Err(ValidationErrorKind::UnknownOverride).field_error("override", &name, span)
+            }
+        }
+    }
+
     impl Config {
-        pub fn validate(self) -> Result<super::Config, ValidationError> {
-            let default_optional = self.defaults.optional;
-            let default_argument = self.defaults.args;
+        /// Takes the path of the extended specification out of this one
+        pub fn take_extends(&mut self) -> Option<String> {
+            self.extends.take()
+        }
+
+        /// Derives the prefix of environment variables from the name of the program unless it's
+        /// set, `general.name` overrides `inferred_name`
+        pub fn derive_env_prefix(&mut self, inferred_name: Option<&str>) {
+            if self.general.env_prefix.is_none() {
+                let name = self.general.name.as_deref().or(inferred_name);
+                self.general.env_prefix = name.map(|name| name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect());
+            }
+        }
+
+        /// Moves the params of sections among the other params, with names prefixed by the name of
+        /// the section.
+        ///
+        /// The params remember their section, only the name and doc of the section are kept.
+        pub fn flatten_sections(&mut self) -> Result<(), ValidationError> {
+            for section in &mut self.sections {
+                let prefix = section.name.get_ref().as_snake_case();
+                for mut param in std::mem::take(&mut section.params) {
+                    let prefixed = |name: &Ident| Ident::try_from(format!("{}_{}", prefix, name.as_snake_case())).expect("prefixing an identifier keeps it valid");
+                    param.name = Spanned { span: param.name.span(), value: prefixed(param.name.get_ref()) };
+                    param.renamed_from = param.renamed_from.iter().map(prefixed).collect();
+                    param.aliases = param.aliases.iter().map(prefixed).collect();
+                    param.section = Some(section.name.get_ref().clone());
+                    if self.params.iter().any(|other| other.name.get_ref().as_snake_case() == param.name.get_ref().as_snake_case()) ||
+                        self.switches.iter().any(|switch| switch.name.get_ref().as_snake_case() == param.name.get_ref().as_snake_case()) {
+                        return Err(ValidationErrorKind::SectionParamDefined).field_error("param", param.name.get_ref(), param.name.span());
+                    }
+                    self.params.push(param);
+                }
+            }
+            Ok(())
+        }
+
+        /// Loads the specifications of subcommands declared in their own files using `load`
+        pub fn load_subcommands<E, F: FnMut(&Spanned<String>) -> Result<Config, E>>(&mut self, mut load: F) -> Result<(), E> {
+            // Files of subcommands that also declare items aren't loaded, validation rejects them
+            for subcommand in self.subcommands.iter_mut().filter(|subcommand| !subcommand.has_items()) {
+                if let Some(file) = &subcommand.file {
+                    subcommand.spec = Some(Box::new(load(file)?));
+                }
+            }
+            Ok(())
+        }
+
+        /// Takes the fragments included by this specification out of it
+        pub fn take_includes(&mut self) -> Vec<Include> {
+            std::mem::take(&mut self.includes)
+        }
+
+        /// Adds params and switches of `fragment` with names prefixed by `prefix`.
+        ///
+        /// Only params, switches and groups are taken from the fragment, positionals and subcommands
+        /// can only be declared by the program itself. Short options are dropped when
+        /// prefixing, as they would clash if the fragment is included more than once. The items
+        /// point to the include statement so that errors about them are reported there.
+        pub fn include(&mut self, fragment: Config, span: Span, prefix: &str) -> Result<(), ValidationError> {
+            let rename = |name: Spanned<Ident>| -> Result<Spanned<Ident>, ValidationError> {
+                let name = if prefix.is_empty() {
+                    name.into_inner()
+                } else {
+                    Ident::try_from(format!("{}{}", prefix, name.get_ref().as_snake_case()))
+                        .map_err(|_| ValidationError::Field { section: "include", name: prefix.to_owned(), kind: ValidationErrorKind::InvalidPrefix, span })?
+                };
+                Ok(Spanned { span, value: name })
+            };
+            let rename_all = |names: Vec<Ident>| -> Result<Vec<Ident>, ValidationError> {
+                names
+                    .into_iter()
+                    .map(|name| rename(Spanned { span, value: name }).map(Spanned::into_inner))
+                    .collect()
+            };
+
+            for mut param in fragment.params {
+                param.name = rename(param.name)?;
+                // Sections of the fragment aren't included, their params become top-level ones
+                param.section = None;
+                param.renamed_from = rename_all(param.renamed_from)?;
+                param.aliases = rename_all(param.aliases)?;
+                param.requires = rename_all(param.requires)?;
+                param.conflicts_with = rename_all(param.conflicts_with)?;
+                if !prefix.is_empty() {
+                    param.abbr = None;
+                }
+                if self.is_defined(param.name.get_ref()) {
+                    return Err(ValidationErrorKind::Duplicate).field_error("param", param.name.get_ref(), span);
+                }
+                self.params.push(param);
+            }
+            for mut switch in fragment.switches {
+                switch.name = rename(switch.name)?;
+                switch.aliases = rename_all(switch.aliases)?;
+                switch.requires = rename_all(switch.requires)?;
+                switch.conflicts_with = rename_all(switch.conflicts_with)?;
+                if !prefix.is_empty() {
+                    switch.abbr = None;
+                }
+                if self.is_defined(switch.name.get_ref()) {
+                    return Err(ValidationErrorKind::Duplicate).field_error("switch", switch.name.get_ref(), span);
+                }
+                self.switches.push(switch);
+            }
+            for mut group in fragment.groups {
+                group.name = rename(group.name)?;
+                group.members = rename_all(group.members)?;
+                if self.groups.iter().any(|other| other.name.get_ref().as_snake_case() == group.name.get_ref().as_snake_case()) {
+                    return Err(ValidationErrorKind::Duplicate).field_error("group", group.name.get_ref(), span);
+                }
+                self.groups.push(group);
+            }
+            Ok(())
+        }
+
+        fn is_defined(&self, name: &Ident) -> bool {
+            self.params.iter().any(|param| param.name.get_ref().as_snake_case() == name.as_snake_case()) ||
+                self.switches.iter().any(|switch| switch.name.get_ref().as_snake_case() == name.as_snake_case()) ||
+                self.positionals.iter().any(|positional| positional.name.get_ref().as_snake_case() == name.as_snake_case())
+        }
+
+        /// Merges this specification into the one it extends.
+        ///
+        /// The `base` must already have its own `extends` resolved.
+        pub fn extend(self, mut base: Config) -> Result<Config, ValidationError> {
+            for param in &self.params {
+                if base.is_defined(param.name.get_ref()) {
+                    return Err(ValidationErrorKind::AlreadyDefined).field_error("param", param.name.get_ref(), param.name.span());
+                }
+            }
+            for switch in &self.switches {
+                if base.is_defined(switch.name.get_ref()) {
+                    return Err(ValidationErrorKind::AlreadyDefined).field_error("switch", switch.name.get_ref(), switch.name.span());
+                }
+            }
+            for positional in &self.positionals {
+                if base.is_defined(positional.name.get_ref()) {
+                    return Err(ValidationErrorKind::AlreadyDefined).field_error("positional", positional.name.get_ref(), positional.name.span());
+                }
+            }
+            for subcommand in &self.subcommands {
+                if base.subcommands.iter().any(|other| other.name.get_ref().as_snake_case() == subcommand.name.get_ref().as_snake_case()) {
+                    return Err(ValidationErrorKind::AlreadyDefined).field_error("subcommand", subcommand.name.get_ref(), subcommand.name.span());
+                }
+            }
+            for section in &self.sections {
+                if base.is_defined(section.name.get_ref()) || base.sections.iter().any(|other| other.name.get_ref().as_snake_case() == section.name.get_ref().as_snake_case()) {
+                    return Err(ValidationErrorKind::AlreadyDefined).field_error("section", section.name.get_ref(), section.name.span());
+                }
+            }
+            for override_ in self.overrides {
+                override_.apply(&mut base.params, &mut base.switches)?;
+            }
+
+            base.params.extend(self.params);
+            base.switches.extend(self.switches);
+            base.sections.extend(self.sections);
+            base.positionals.extend(self.positionals);
+            base.subcommands.extend(self.subcommands);
+            base.groups.extend(self.groups);
+
+            Ok(Config {
+                extends: None,
+                includes: Vec::new(),
+                overrides: Vec::new(),
+                params: base.params,
+                switches: base.switches,
+                sections: base.sections,
+                positionals: base.positionals,
+                subcommands: base.subcommands,
+                groups: base.groups,
+                general: self.general.or(base.general),
+                defaults: self.defaults.or(base.defaults),
+                #[cfg(feature = "debconf")]
+                debconf: self.debconf.or(base.debconf),
+            })
+        }
+
+        /// Features the params and switches depend on
+        pub fn required_features(&self) -> impl Iterator<Item=&str> {
+            self.params
+                .iter()
+                .filter_map(|param| param.enabled_if_feature.as_ref())
+                .chain(self.switches.iter().filter_map(|switch| switch.enabled_if_feature.as_ref()))
+                .map(AsRef::as_ref)
+        }
+
+        fn check_docs(&self) -> Result<(), ValidationError> {
+            let names = self.params
+                .iter()
+                .filter(|param| param.doc.is_none())
+                .map(|param| param.name.get_ref().as_snake_case().to_owned())
+                .chain(self.switches
+                    .iter()
+                    .filter(|switch| switch.doc.is_none())
+                    .map(|switch| switch.name.get_ref().as_snake_case().to_owned()))
+                .collect::<Vec<_>>();
+
+            if names.is_empty() {
+                Ok(())
+            } else {
+                Err(ValidationError::MissingDoc { names })
+            }
+        }
+
+        fn check_explicit_optionality(&self) -> Result<(), ValidationError> {
+            let names = self.params
+                .iter()
+                .filter(|param| param.optional.is_none() && param.default.is_none())
+                .map(|param| param.name.get_ref().as_snake_case().to_owned())
+                .collect::<Vec<_>>();
+
+            if names.is_empty() {
+                Ok(())
+            } else {
+                Err(ValidationError::ImplicitOptionality { names })
+            }
+        }
+
+        pub fn validate(mut self) -> Result<super::Config, ValidationError> {
+            // Overrides are consumed when resolving `extends`, so there's nothing to override
+            if let Some(override_) = self.overrides.first() {
+                return Err(ValidationErrorKind::UnknownOverride).field_error("override", override_.name.get_ref(), override_.name.span());
+            }
+
+            self.params.retain(|param| is_enabled(&param.enabled_if_feature));
+            self.switches.retain(|switch| is_enabled(&switch.enabled_if_feature));
+
+            if self.general.require_doc {
+                self.check_docs()?;
+            }
+            if self.general.require_explicit_optional {
+                self.check_explicit_optionality()?;
+            }
+            let false_values = self.general.false_values();
+            if let Some(value) = self.general.true_values().into_iter().find(|value| false_values.contains(value)) {
+                return Err(ValidationError::AmbiguousSwitchValue(value.to_owned()));
+            }
+            if let Some(table) = &self.general.config_table {
+                let is_key = |key: &str| !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+                if !table.split('.').all(is_key) {
+                    return Err(ValidationError::InvalidConfigTable(table.clone()));
+                }
+            }
+            if self.general.user_config && self.general.name.is_none() {
+                return Err(ValidationError::UserConfigWithoutName);
+            }
+            if self.general.watch_config && !self.general.reload_if_changed {
+                return Err(ValidationError::WatchConfigWithoutReload);
+            }
+            if let Some(path) = &self.general.validate {
+                if ::syn::parse_str::<::syn::Path>(path).is_err() {
+                    return Err(ValidationError::InvalidValidateFn(path.clone()));
+                }
+            }
+
+            let default_optional = self.defaults.optional.unwrap_or(true);
+            let default_negatable = self.defaults.negatable.unwrap_or(false);
+            let default_argument = self.defaults.args.unwrap_or(true);
             let default_env_var = self.defaults.env_vars.unwrap_or(self.general.env_prefix.is_some());
+            let default_empty_env_var = self.general.empty_env_var();
+            let default_duplicate_arg = self.general.duplicate_arg();
+            for param in &self.params {
+                if param.renamed_from.iter().any(|old| self.is_defined(old)) {
+                    return Err(ValidationErrorKind::RenamedFromDefined).field_error("param", param.name.get_ref(), param.name.span());
+                }
+            }
+            let param_aliases = self.params.iter().map(|param| ("param", &param.name, &param.aliases));
+            let switch_aliases = self.switches.iter().map(|switch| ("switch", &switch.name, &switch.aliases));
+            let all_aliases = self.params.iter().flat_map(|param| &param.aliases).chain(self.switches.iter().flat_map(|switch| &switch.aliases)).collect::<Vec<_>>();
+            for (section, name, aliases) in param_aliases.chain(switch_aliases) {
+                for alias in aliases {
+                    if self.is_defined(alias) ||
+                        self.params.iter().flat_map(|param| &param.renamed_from).any(|old| old.as_snake_case() == alias.as_snake_case()) ||
+                        all_aliases.iter().filter(|other| other.as_snake_case() == alias.as_snake_case()).count() > 1 {
+                        return Err(ValidationErrorKind::AliasDefined).field_error(section, name.get_ref(), name.span());
+                    }
+                }
+            }
+            let param_relations = self.params.iter().map(|param| ("param", &param.name, param.requires.iter().chain(&param.conflicts_with)));
+            let switch_relations = self.switches.iter().map(|switch| ("switch", &switch.name, switch.requires.iter().chain(&switch.conflicts_with)));
+            for (section, name, others) in param_relations.chain(switch_relations) {
+                for other in others {
+                    let is_param_or_switch = self.params.iter().map(|param| &param.name).chain(self.switches.iter().map(|switch| &switch.name))
+                        .any(|item| item.get_ref().as_snake_case() == other.as_snake_case());
+                    if !is_param_or_switch || other.as_snake_case() == name.get_ref().as_snake_case() {
+                        return Err(ValidationErrorKind::UnknownRelation).field_error(section, name.get_ref(), name.span());
+                    }
+                }
+            }
+            for (i, group) in self.groups.iter().enumerate() {
+                let name = group.name.get_ref();
+                let span = group.name.span();
+                if self.groups[..i].iter().any(|other| other.name.get_ref().as_snake_case() == name.as_snake_case()) {
+                    return Err(ValidationErrorKind::GroupDefined).field_error("group", name, span);
+                }
+                let is_param_or_switch = |member: &Ident| self.params.iter().map(|param| &param.name).chain(self.switches.iter().map(|switch| &switch.name))
+                    .any(|item| item.get_ref().as_snake_case() == member.as_snake_case());
+                let is_distinct = group.members.iter().enumerate().all(|(i, member)| !group.members[..i].iter().any(|other| other.as_snake_case() == member.as_snake_case()));
+                if group.members.is_empty() || !group.members.iter().all(is_param_or_switch) || !is_distinct {
+                    return Err(ValidationErrorKind::InvalidGroupMembers).field_error("group", name, span);
+                }
+                if group.multiple && !group.required {
+                    return Err(ValidationErrorKind::UnconstrainedGroup).field_error("group", name, span);
+                }
+            }
+            if self.general.config_includes {
+                let param_names = self.params.iter().map(|param| ("param", &param.name, &param.aliases));
+                let switch_names = self.switches.iter().map(|switch| ("switch", &switch.name, &switch.aliases));
+                for (section, name, aliases) in param_names.chain(switch_names) {
+                    if name.get_ref().as_snake_case() == "include" || aliases.iter().any(|alias| alias.as_snake_case() == "include") {
+                        return Err(ValidationErrorKind::ReservedInclude).field_error(section, name.get_ref(), name.span());
+                    }
+                }
+                if let Some(param) = self.params.iter().find(|param| param.renamed_from.iter().any(|old| old.as_snake_case() == "include")) {
+                    return Err(ValidationErrorKind::ReservedInclude).field_error("param", param.name.get_ref(), param.name.span());
+                }
+            }
+            if self.general.profile_param.is_some() {
+                let param_names = self.params.iter().map(|param| ("param", &param.name, &param.aliases));
+                let switch_names = self.switches.iter().map(|switch| ("switch", &switch.name, &switch.aliases));
+                for (section, name, aliases) in param_names.chain(switch_names) {
+                    if name.get_ref().as_snake_case() == "profile" || aliases.iter().any(|alias| alias.as_snake_case() == "profile") {
+                        return Err(ValidationErrorKind::ReservedProfile).field_error(section, name.get_ref(), name.span());
+                    }
+                }
+            }
+            if self.general.prints_version() {
+                let param_names = self.params.iter().map(|param| ("param", &param.name, &param.aliases, param.abbr));
+                let switch_names = self.switches.iter().map(|switch| ("switch", &switch.name, &switch.aliases, switch.abbr.as_ref().map(|abbr| *abbr.get_ref())));
+                for (section, name, aliases, abbr) in param_names.chain(switch_names) {
+                    if name.get_ref().as_snake_case() == "version" || aliases.iter().any(|alias| alias.as_snake_case() == "version") || abbr == Some('V') {
+                        return Err(ValidationErrorKind::ReservedVersion).field_error(section, name.get_ref(), name.span());
+                    }
+                }
+            }
+            // The errors about the relations and groups report where the values came from
+            self.general.relations = !self.groups.is_empty() ||
+                self.params.iter().any(|param| !param.requires.is_empty() || !param.conflicts_with.is_empty()) ||
+                self.switches.iter().any(|switch| !switch.requires.is_empty() || !switch.conflicts_with.is_empty());
+            for (i, section) in self.sections.iter().enumerate() {
+                let name = section.name.get_ref();
+                if self.is_defined(name) || self.sections[..i].iter().any(|other| other.name.get_ref().as_snake_case() == name.as_snake_case()) {
+                    return Err(ValidationErrorKind::SectionDefined).field_error("section", name, section.name.span());
+                }
+            }
+            for (i, positional) in self.positionals.iter().enumerate() {
+                let name = positional.name.get_ref();
+                let others = self.positionals[..i].iter().chain(&self.positionals[(i + 1)..]);
+                if self.params.iter().any(|param| param.name.get_ref().as_snake_case() == name.as_snake_case()) ||
+                    self.switches.iter().any(|switch| switch.name.get_ref().as_snake_case() == name.as_snake_case()) ||
+                    self.sections.iter().any(|section| section.name.get_ref().as_snake_case() == name.as_snake_case()) ||
+                    others.clone().any(|other| other.name.get_ref().as_snake_case() == name.as_snake_case()) {
+                    return Err(ValidationErrorKind::PositionalDefined).field_error("positional", name, positional.name.span());
+                }
+                if positional.variadic && i + 1 < self.positionals.len() {
+                    return Err(ValidationErrorKind::VariadicNotLast).field_error("positional", name, positional.name.span());
+                }
+                if !positional.optional && self.positionals[..i].iter().any(|other| other.optional) {
+                    return Err(ValidationErrorKind::MandatoryAfterOptional).field_error("positional", name, positional.name.span());
+                }
+            }
+            for (i, subcommand) in self.subcommands.iter().enumerate() {
+                let name = subcommand.name.get_ref();
+                let span = subcommand.name.span();
+                if !self.positionals.is_empty() {
+                    return Err(ValidationErrorKind::SubcommandWithPositionals).field_error("subcommand", name, span);
+                }
+                if self.is_defined(&Ident::try_from("subcommand".to_owned()).expect("valid identifier")) {
+                    return Err(ValidationErrorKind::SubcommandField).field_error("subcommand", name, span);
+                }
+                if name.as_snake_case() == "raw" || name.as_snake_case() == "prelude" {
+                    return Err(ValidationErrorKind::ReservedSubcommand).field_error("subcommand", name, span);
+                }
+                if self.subcommands[..i].iter().any(|other| other.name.get_ref().as_snake_case() == name.as_snake_case()) {
+                    return Err(ValidationErrorKind::SubcommandDefined).field_error("subcommand", name, span);
+                }
+            }
+            let params = self.params
+                .iter()
+                .filter(|param| param.values.is_some())
+                .map(|param| ("param", param.name.get_ref(), &param.ty));
+            let switches = self.switches
+                .iter()
+                .filter(|switch| switch.values.is_some())
+                .filter_map(|switch| switch.map_to.as_ref().map(|map_to| ("switch", switch.name.get_ref(), map_to)));
+            let mut enums = Vec::new();
+            for (item, name, ty) in params.chain(switches) {
+                if enums.contains(&ty.get_ref()) {
+                    return Err(ValidationErrorKind::DuplicateValues).field_error(item, name, ty.span());
+                }
+                let is_section = self.sections.iter().any(|section| section.name.get_ref().as_pascal_case().to_string() == *ty.get_ref());
+                if super::RESERVED_TYPE_NAMES.contains(&ty.get_ref().as_str()) || is_section {
+                    return Err(ValidationErrorKind::ReservedValuesType).field_error(item, name, ty.span());
+                }
+                enums.push(ty.get_ref());
+            }
+            // The entries of a map are read from all variables prefixed by its name
+            let env_var_names = self.params.iter().filter(|param| param.env_var.unwrap_or(default_env_var)).map(|param| &param.name)
+                .chain(self.switches.iter().filter(|switch| switch.env_var.unwrap_or(default_env_var)).map(|switch| &switch.name))
+                .collect::<Vec<_>>();
+            for map in self.params.iter().filter(|param| param.map && param.env_var.unwrap_or(default_env_var)) {
+                let prefix = format!("{}_", map.name.get_ref().as_snake_case());
+                if let Some(other) = env_var_names.iter().find(|name| name.get_ref().as_snake_case().starts_with(&prefix)) {
+                    return Err(ValidationErrorKind::MapEnvVarConflict(other.get_ref().as_snake_case().into())).field_error("param", map.name.get_ref(), map.name.span());
+                }
+            }
+            let general = &self.general;
             let params = self.params
                 .into_iter()
-                .map(|param| param.validate(default_optional, default_argument, default_env_var))
+                .map(|param| param.validate(default_optional, default_argument, default_env_var, default_empty_env_var, default_duplicate_arg, general))
                 .collect::<Result<Vec<_>, _>>()?;
 
             let switches = self.switches
                 .into_iter()
-                .map(|switch| switch.validate(default_env_var))
+                .map(|switch| switch.validate(default_env_var, default_negatable, default_duplicate_arg, general))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let sections = self.sections
+                .into_iter()
+                .map(|section| super::Section {
+                    name: section.name.into_inner(),
+                    doc: section.doc,
+                })
+                .collect();
+
+            let positionals = self.positionals
+                .into_iter()
+                .map(Positional::validate)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let subcommands = self.subcommands
+                .into_iter()
+                .map(|subcommand| subcommand.validate(general))
                 .collect::<Result<Vec<_>, _>>()?;
 
+            let groups = self.groups
+                .into_iter()
+                .map(|group| super::Group {
+                    name: group.name.into_inner(),
+                    doc: group.doc,
+                    members: group.members,
+                    required: group.required,
+                    multiple: group.multiple,
+                })
+                .collect();
+
             Ok(super::Config {
                 general: self.general,
-                defaults: self.defaults,
+                deprecations: Vec::new(),
+                source_hash: Default::default(),
+                extended_files: Vec::new(),
+                inferred_name: None,
+                package_description: None,
+                library: false,
+                ignored_fields: Vec::new(),
                 params,
                 switches,
+                sections,
+                positionals,
+                subcommands,
+                groups,
                 #[cfg(feature = "debconf")]
                 debconf: self.debconf,
             })
         }
     }
 
-    #[derive(Debug)]
+    /// Checks whether the cargo feature required by a param or switch is enabled.
+    ///
+    /// Cargo passes enabled features to build scripts as `CARGO_FEATURE_<NAME>` env vars.
+    pub fn is_feature_enabled(feature: &str) -> bool {
+        let var = format!("CARGO_FEATURE_{}", feature.to_ascii_uppercase().replace('-', "_"));
+        ::std::env::var_os(var).is_some()
+    }
+
+    fn is_enabled(feature: &Option<String>) -> bool {
+        match feature {
+            Some(feature) => is_feature_enabled(feature),
+            None => true,
+        }
+    }
+
+    #[derive(Debug, Clone)]
     #[derive(Deserialize)]
-    #[serde(deny_unknown_fields)]
     pub struct Param {
-        name: Ident,
+        name: Spanned<Ident>,
         abbr: Option<char>,
         #[serde(rename = "type")]
-        ty: String,
+        ty: Spanned<String>,
         optional: Option<bool>,
-        default: Option<String>,
+        default: Option<Spanned<String>>,
+        default_fn: Option<Spanned<String>>,
         doc: Option<String>,
         argument: Option<bool>,
         env_var: Option<bool>,
-        convert_into: Option<String>,
+        convert_into: Option<Spanned<String>>,
         merge_fn: Option<String>,
+        nargs: Option<Spanned<Nargs>>,
+        empty_env_var: Option<super::EmptyEnvVar>,
+        duplicate_arg: Option<super::DuplicateArg>,
+        secret: Option<bool>,
+        #[serde(default)]
+        renamed_from: Vec<Ident>,
+        #[serde(default)]
+        aliases: Vec<Ident>,
+        #[serde(default)]
+        show_aliases: bool,
+        #[serde(default)]
+        requires: Vec<Ident>,
+        #[serde(default)]
+        conflicts_with: Vec<Ident>,
+        min: Option<Spanned<super::Bound>>,
+        max: Option<Spanned<super::Bound>>,
+        validate_regex: Option<Spanned<String>>,
+        validate: Option<Spanned<String>>,
+        #[serde(skip)]
+        section: Option<Ident>,
+        values: Option<Vec<String>>,
+        #[serde(default)]
+        multiple: bool,
+        list_merge: Option<super::ListMerge>,
+        env_delimiter: Option<char>,
+        #[serde(default)]
+        map: bool,
+        enabled_if_feature: Option<String>,
         #[cfg(feature = "debconf")]
         debconf_priority: Option<::debconf::Priority>,
         #[cfg(feature = "debconf")]
         debconf_default: Option<String>,
     }
 
+    /// Either `nargs = 2` or `nargs = { min = 1, max = 3 }`
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(untagged)]
+    enum Nargs {
+        Fixed(usize),
+        Bounded {
+            #[serde(default = "Nargs::default_min")]
+            min: usize,
+            max: usize,
+        },
+    }
+
+    impl Nargs {
+        fn default_min() -> usize {
+            1
+        }
+
+        /// Fixed counts are stored in arrays, which serde only supports up to 32 items.
+        fn validate(self) -> Result<super::Nargs, ValidationErrorKind> {
+            match self {
+                Nargs::Fixed(count) if (1..=32).contains(&count) => Ok(super::Nargs::Fixed(count)),
+                Nargs::Bounded { min, max } if min >= 1 && min <= max => Ok(super::Nargs::Bounded { min, max }),
+                _ => Err(ValidationErrorKind::InvalidNargs),
+            }
+        }
+    }
+
     impl Param {
         fn validate_optionality(optional: Option<bool>, default_optional: bool, default: Option<String>) -> Result<Optionality, ValidationErrorKind> {
             match (optional, default_optional, default) {
@@ -230,52 +1116,255 @@ pub mod raw {
             }
         }
 
-        fn validate(self, default_optional: bool, default_argument: bool, default_env_var: bool) -> Result<super::Param, ValidationError> {
-            let optionality = Param::validate_optionality(self.optional, default_optional, self.default)
-                .field_name(&self.name)?;
-
-            let ty = self.ty;
-            let argument = self.argument.unwrap_or(default_argument);
-            let env_var = self.env_var.unwrap_or(default_env_var);
-            let convert_into = self.convert_into.unwrap_or_else(|| ty.clone());
-
-            Ok(super::Param {
-                name: self.name,
-                ty,
-                optionality,
-                abbr: self.abbr,
-                doc: self.doc,
-                argument,
-                env_var,
-                convert_into,
-                merge_fn: self.merge_fn,
-                #[cfg(feature = "debconf")]
-                debconf_priority: self.debconf_priority,
-                #[cfg(feature = "debconf")]
-                debconf_default: self.debconf_default,
-            })
+        /// Turns `default_fn` into a call, which is evaluated lazily like any other default
+        fn validate_default(default: Option<Spanned<String>>, default_fn: Option<Spanned<String>>) -> Result<Option<String>, ValidationErrorKind> {
+            match (default, default_fn) {
+                (Some(_), Some(_)) => Err(ValidationErrorKind::DefaultWithDefaultFn),
+                (default, None) => Ok(default.map(Spanned::into_inner)),
+                (None, Some(path)) => {
+                    let path = path.into_inner();
+                    match ::syn::parse_str::<::syn::Path>(&path) {
+                        Ok(_) => Ok(Some(format!("{}()", path))),
+                        Err(error) => Err(ValidationErrorKind::InvalidDefaultFn(error.to_string().into())),
+                    }
+                },
+            }
         }
-    }
 
-    #[derive(Debug)]
+        /// Checks that the type is valid Rust syntax so that mistakes don't end up as errors in
+        /// the generated code.
+        fn validate_type(ty: Spanned<String>, kind: fn(Box<str>) -> ValidationErrorKind) -> Result<String, ValidationErrorKind> {
+            let ty = ty.into_inner();
+            match ::syn::parse_str::<::syn::Type>(&ty) {
+                Ok(_) => Ok(ty),
+                Err(error) => Err(kind(error.to_string().into())),
+            }
+        }
+
+        /// Ranges are only checked for primitive numbers, the bounds of integers must fit into the
+        /// type so that the generated comparisons compile.
+        fn validate_range(ty: &str, min: Option<super::Bound>, max: Option<super::Bound>, single_value: bool) -> Result<(), ValidationErrorKind> {
+            let is_float = ty == "f32" || ty == "f64";
+            if !single_value || !(is_float || super::is_integer(ty)) {
+                return Err(ValidationErrorKind::RangeType);
+            }
+            if !is_float {
+                let (type_min, type_max) = super::integer_range(ty);
+                for bound in min.iter().chain(&max) {
+                    match bound {
+                        super::Bound::Integer(value) if (type_min..=type_max).contains(&i128::from(*value)) => (),
+                        _ => return Err(ValidationErrorKind::InvalidRange),
+                    }
+                }
+            }
+            match (min, max) {
+                (Some(min), Some(max)) if min.as_f64() > max.as_f64() => Err(ValidationErrorKind::InvalidRange),
+                _ => Ok(()),
+            }
+        }
+
+        /// The pattern is compiled here so that the generated code can't panic on it.
+        fn validate_regex(ty: &str, pattern: &str, single_or_multiple: bool) -> Result<(), ValidationErrorKind> {
+            if ty != "String" || !single_or_multiple {
+                return Err(ValidationErrorKind::RegexType);
+            }
+            match ::regex::Regex::new(pattern) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(ValidationErrorKind::InvalidRegex(error.to_string().into())),
+            }
+        }
+
+        fn validate(self, default_optional: bool, default_argument: bool, default_env_var: bool, default_empty_env_var: super::EmptyEnvVar, default_duplicate_arg: super::DuplicateArg, general: &super::General) -> Result<super::Param, ValidationError> {
+            let name_span = self.name.span();
+            let name = self.name.into_inner();
+            let default_span = self.default.as_ref().or(self.default_fn.as_ref()).map_or(name_span, Spanned::span);
+            let default = Param::validate_default(self.default, self.default_fn)
+                .field_error("param", &name, default_span)?;
+            let optionality = Param::validate_optionality(self.optional, default_optional, default)
+                .field_error("param", &name, default_span)?;
+
+            let ty_span = self.ty.span();
+            let size = self.ty.get_ref().trim() == "size";
+            let ty = Param::validate_type(self.ty, ValidationErrorKind::InvalidType)
+                .map(super::builtin_type)
+                .field_error("param", &name, ty_span)?;
+            if let Some(values) = &self.values {
+                if ::syn::parse_str::<::syn::Ident>(&ty).is_err() {
+                    return Err(ValidationErrorKind::ValuesType).field_error("param", &name, ty_span);
+                }
+                if !super::are_valid_values(values) {
+                    return Err(ValidationErrorKind::InvalidValues).field_error("param", &name, name_span);
+                }
+            }
+            let convert_into_span = self.convert_into.as_ref().map_or(name_span, Spanned::span);
+            let convert_into = self.convert_into
+                .map(|convert_into| Param::validate_type(convert_into, ValidationErrorKind::InvalidConvertInto))
+                .transpose()
+                .field_error("param", &name, convert_into_span)?;
+            let nargs_span = self.nargs.as_ref().map_or(name_span, Spanned::span);
+            let nargs = self.nargs
+                .map(|nargs| nargs.into_inner().validate())
+                .transpose()
+                .field_error("param", &name, nargs_span)?;
+            let multiple = match (self.multiple, self.list_merge, self.env_delimiter) {
+                (true, _, _) if nargs.is_some() || self.merge_fn.is_some() || self.duplicate_arg.is_some() => return Err(ValidationErrorKind::MultipleConflict).field_error("param", &name, name_span),
+                (true, list_merge, _) => Some(list_merge.unwrap_or_default()),
+                (false, None, None) => None,
+                (false, _, _) => return Err(ValidationErrorKind::NotMultiple).field_error("param", &name, name_span),
+            };
+            let map = self.map;
+            if map && (nargs.is_some() || multiple.is_some() || self.merge_fn.is_some() || self.duplicate_arg.is_some()) {
+                return Err(ValidationErrorKind::MapConflict).field_error("param", &name, name_span);
+            }
+            // Config files can only hold a single duration, see `helpers::deserialize_duration()`
+            if ty == super::DURATION_TYPE && (nargs.is_some() || multiple.is_some() || map) {
+                return Err(ValidationErrorKind::DurationMultiple).field_error("param", &name, ty_span);
+            }
+            // Like durations, sizes written as strings are only accepted in config files one by one
+            if size && (nargs.is_some() || multiple.is_some() || map) {
+                return Err(ValidationErrorKind::SizeMultiple).field_error("param", &name, ty_span);
+            }
+            let range_span = self.min.as_ref().or(self.max.as_ref()).map_or(name_span, Spanned::span);
+            let min = self.min.map(Spanned::into_inner);
+            let max = self.max.map(Spanned::into_inner);
+            if min.is_some() || max.is_some() {
+                Param::validate_range(ty.trim(), min, max, nargs.is_none() && multiple.is_none() && !map)
+                    .field_error("param", &name, range_span)?;
+            }
+            let regex_span = self.validate_regex.as_ref().map_or(name_span, Spanned::span);
+            let validate_regex = self.validate_regex.map(Spanned::into_inner);
+            if let Some(pattern) = &validate_regex {
+                Param::validate_regex(ty.trim(), pattern, nargs.is_none() && !map)
+                    .field_error("param", &name, regex_span)?;
+            }
+            let validate_span = self.validate.as_ref().map_or(name_span, Spanned::span);
+            let validate = self.validate
+                .map(Spanned::into_inner)
+                .map(|path| match ::syn::parse_str::<::syn::Path>(&path) {
+                    Ok(_) => Ok(path),
+                    Err(error) => Err(ValidationErrorKind::InvalidValidateFn(error.to_string().into())),
+                })
+                .transpose()
+                .field_error("param", &name, validate_span)?;
+            let convert_into = convert_into.unwrap_or_else(|| super::field_type(&ty, nargs, multiple.is_some(), map));
+            let argument = self.argument.unwrap_or(default_argument);
+            // Multiple values can't be parsed from a single env var
+            let env_var = match (nargs, self.env_var) {
+                (Some(_), Some(true)) => return Err(ValidationErrorKind::NargsWithEnvVar).field_error("param", &name, nargs_span),
+                (Some(_), _) => false,
+                (None, env_var) => env_var.unwrap_or(default_env_var),
+            };
+            // Appended values and entries of maps are merged like by a custom merge_fn
+            let merge_fn = match multiple {
+                Some(super::ListMerge::Append) => Some("::configure_me::helpers::append_values".to_owned()),
+                _ if map => Some("::configure_me::helpers::merge_maps".to_owned()),
+                _ => self.merge_fn,
+            };
+            // Every occurrence of a param with merge_fn or multiple values is used
+            let duplicate_arg = match (&merge_fn, self.duplicate_arg) {
+                (Some(_), Some(_)) => return Err(ValidationErrorKind::RepeatableDuplicateArg).field_error("param", &name, name_span),
+                (Some(_), None) => super::DuplicateArg::Last,
+                (None, _) if multiple.is_some() => super::DuplicateArg::Last,
+                (None, duplicate_arg) => duplicate_arg.unwrap_or(default_duplicate_arg),
+            };
+
+            Ok(super::Param {
+                name,
+                ty,
+                size,
+                nargs,
+                optionality,
+                abbr: self.abbr,
+                doc: self.doc,
+                argument,
+                env_var,
+                convert_into,
+                empty_env_var: self.empty_env_var.unwrap_or(default_empty_env_var),
+                explain_overrides: general.tracks_sources() && merge_fn.is_none(),
+                audit_combined: general.audit && merge_fn.is_some(),
+                merge_fn,
+                values: self.values,
+                multiple,
+                env_delimiter: self.env_delimiter.unwrap_or(','),
+                map,
+                duplicate_arg,
+                secret: self.secret.unwrap_or(false),
+                renamed_from: self.renamed_from,
+                aliases: self.aliases,
+                show_aliases: self.show_aliases,
+                requires: self.requires,
+                conflicts_with: self.conflicts_with,
+                min,
+                max,
+                validate_regex,
+                validate,
+                section: self.section,
+                #[cfg(feature = "debconf")]
+                debconf_priority: self.debconf_priority,
+                #[cfg(feature = "debconf")]
+                debconf_default: self.debconf_default,
+            })
+        }
+    }
+
+    impl Positional {
+        fn validate(self) -> Result<super::Positional, ValidationError> {
+            let name_span = self.name.span();
+            let name = self.name.into_inner();
+            if self.trailing && !self.variadic {
+                return Err(ValidationErrorKind::TrailingNotVariadic).field_error("positional", &name, name_span);
+            }
+            let ty_span = self.ty.span();
+            let size = self.ty.get_ref().trim() == "size";
+            let ty = Param::validate_type(self.ty, ValidationErrorKind::InvalidType)
+                .map(super::builtin_type)
+                .field_error("positional", &name, ty_span)?;
+
+            Ok(super::Positional {
+                name,
+                ty,
+                size,
+                optional: self.optional,
+                variadic: self.variadic,
+                trailing: self.trailing,
+                doc: self.doc,
+            })
+        }
+    }
+
+    #[derive(Debug, Clone)]
     #[derive(Deserialize)]
-    #[serde(deny_unknown_fields)]
     pub struct Switch {
-        name: Ident,
-        abbr: Option<char>,
+        name: Spanned<Ident>,
+        abbr: Option<Spanned<char>>,
         #[serde(default)]
         default: bool,
         doc: Option<String>,
         env_var: Option<bool>,
         #[serde(default)]
         count: bool,
+        max_count: Option<Spanned<u32>>,
+        map_to: Option<Spanned<String>>,
+        values: Option<Vec<String>>,
+        map_values: Option<Vec<String>>,
+        duplicate_arg: Option<super::DuplicateArg>,
+        negatable: Option<Spanned<bool>>,
+        #[serde(default)]
+        aliases: Vec<Ident>,
+        #[serde(default)]
+        show_aliases: bool,
+        #[serde(default)]
+        requires: Vec<Ident>,
+        #[serde(default)]
+        conflicts_with: Vec<Ident>,
+        enabled_if_feature: Option<String>,
         #[cfg(feature = "debconf")]
         debconf_priority: Option<::debconf::Priority>,
     }
 
     impl Switch {
         fn validate_abbr(abbr: char) -> Result<char, ValidationErrorKind> {
-            if (abbr >= 'a' && abbr <= 'z') || (abbr >= 'A' && abbr <= 'Z') {
+            if abbr.is_ascii_lowercase() || abbr.is_ascii_uppercase() {
                 Ok(abbr)
             } else {
                 Err(ValidationErrorKind::InvalidAbbr)
@@ -291,20 +1380,80 @@ pub mod raw {
             }
         }
 
-        fn validate(self, default_env_var: bool) -> Result<super::Switch, ValidationError> {
+        fn validate(self, default_env_var: bool, default_negatable: bool, default_duplicate_arg: super::DuplicateArg, general: &super::General) -> Result<super::Switch, ValidationError> {
+            let name_span = self.name.span();
+            let name = self.name.into_inner();
+            let abbr_span = self.abbr.as_ref().map_or(name_span, Spanned::span);
             let abbr = self.abbr
+                .map(Spanned::into_inner)
                 .map(Switch::validate_abbr)
                 .transpose()
-                .field_name(&self.name)?;
+                .field_error("switch", &name, abbr_span)?;
 
             let kind = Switch::validate_kind(abbr, self.default, self.count)
-                .field_name(&self.name)?;
+                .field_error("switch", &name, abbr_span)?;
+            // The default only applies to the switches that can be negated
+            let negatable = match (&kind, self.negatable) {
+                (SwitchKind::Normal { count: false, .. }, negatable) => negatable.map_or(default_negatable, Spanned::into_inner),
+                (_, Some(negatable)) if *negatable.get_ref() => {
+                    let span = negatable.span();
+                    return Err(ValidationErrorKind::NegatableNotNormal).field_error("switch", &name, span);
+                },
+                (_, _) => false,
+            };
+            // Every occurrence of a count switch is counted
+            let duplicate_arg = match (self.count, self.duplicate_arg) {
+                (true, Some(_)) => return Err(ValidationErrorKind::RepeatableDuplicateArg).field_error("switch", &name, name_span),
+                (true, None) => super::DuplicateArg::Last,
+                (false, duplicate_arg) => duplicate_arg.unwrap_or(default_duplicate_arg),
+            };
+            if !self.count && (self.max_count.is_some() || self.map_to.is_some() || self.values.is_some() || self.map_values.is_some()) {
+                return Err(ValidationErrorKind::CountOptionsWithoutCount).field_error("switch", &name, name_span);
+            }
+            let max_count_span = self.max_count.as_ref().map_or(name_span, Spanned::span);
+            let max_count = self.max_count.map(Spanned::into_inner);
+            if max_count == Some(0) {
+                return Err(ValidationErrorKind::InvalidMaxCount).field_error("switch", &name, max_count_span);
+            }
+            let map_to_span = self.map_to.as_ref().map_or(name_span, Spanned::span);
+            let map_to = self.map_to
+                .map(|map_to| Param::validate_type(map_to, ValidationErrorKind::InvalidMapTo))
+                .transpose()
+                .field_error("switch", &name, map_to_span)?;
+            // The values of the counts are stored as expressions, the variants of the generated enum
+            // are paths to them
+            let map_values = match (&map_to, &self.values, self.map_values) {
+                (None, None, None) => Vec::new(),
+                (Some(ty), Some(values), None) => {
+                    if ::syn::parse_str::<::syn::Ident>(ty).is_err() {
+                        return Err(ValidationErrorKind::CountValuesType).field_error("switch", &name, map_to_span);
+                    }
+                    if !super::are_valid_values(values) {
+                        return Err(ValidationErrorKind::InvalidValues).field_error("switch", &name, name_span);
+                    }
+                    values.iter().map(|value| format!("{}::{}", ty, super::variant_name(value))).collect()
+                },
+                (Some(_), None, Some(map_values)) if !map_values.is_empty() => map_values,
+                _ => return Err(ValidationErrorKind::InvalidCountMapping).field_error("switch", &name, map_to_span),
+            };
 
             Ok(super::Switch {
-                name: self.name,
+                name,
                 kind,
                 doc: self.doc,
                 env_var: self.env_var.unwrap_or(default_env_var),
+                negatable,
+                duplicate_arg,
+                explain_overrides: general.tracks_sources() && !self.count,
+                audit_combined: general.audit && self.count,
+                max_count,
+                map_to,
+                map_values,
+                values: self.values,
+                aliases: self.aliases,
+                show_aliases: self.show_aliases,
+                requires: self.requires,
+                conflicts_with: self.conflicts_with,
                 #[cfg(feature = "debconf")]
                 debconf_priority: self.debconf_priority,
             })
@@ -312,22 +1461,90 @@ pub mod raw {
     }
 }
 
-fn make_true() -> bool {
-    true
-}
-
 pub struct Config {
     pub general: General,
+    pub deprecations: Vec<::legacy::Renamed>,
+    /// Hash of all sources the specification was loaded from
+    pub source_hash: ::fnv::FnvHasher,
+    /// Specifications this one extends or includes (transitively)
+    pub extended_files: Vec<::std::path::PathBuf>,
+    /// Name of the cargo target the specification belongs to, if known
+    pub inferred_name: Option<String>,
+    /// Description of the package, used in the help if the specification doesn't have one
+    pub package_description: Option<String>,
+    /// The code is included in a library shared by multiple binaries
+    pub library: bool,
+    /// Unknown fields ignored because of `UnknownFields::Warn`
+    pub ignored_fields: Vec<String>,
     #[cfg(feature = "debconf")]
     pub debconf: Option<::debconf::DebConfig>,
-    pub defaults: Defaults,
     pub params: Vec<Param>,
     pub switches: Vec<Switch>,
+    pub sections: Vec<Section>,
+    /// Arguments filled in order from the ones remaining after the options
+    pub positionals: Vec<Positional>,
+    /// Programs selected by the first argument remaining after the options
+    pub subcommands: Vec<Subcommand>,
+    /// Params and switches of which at most one can be set
+    pub groups: Vec<Group>,
 }
 
-#[derive(Debug)]
+/// Params and switches of which at most one can be set, or exactly one if the group is required
+pub struct Group {
+    pub name: Ident,
+    pub doc: Option<String>,
+    pub members: Vec<Ident>,
+    /// Whether at least one member must be set
+    pub required: bool,
+    /// Whether more than one member can be set, only in required groups
+    pub multiple: bool,
+}
+
+/// Subcommand with its own configuration, generated in a module named after it
+pub struct Subcommand {
+    pub name: Ident,
+    pub doc: Option<String>,
+    pub config: Config,
+}
+
+/// Argument identified by its position, only the last one can be variadic and the optional ones
+/// follow the mandatory ones
+pub struct Positional {
+    pub name: Ident,
+    pub ty: String,
+    /// Whether it has the built-in type `size`, `ty` is then `u64`
+    pub size: bool,
+    pub optional: bool,
+    /// Whether it takes all the remaining arguments, collected into a `Vec`
+    pub variadic: bool,
+    /// Whether the variadic positional only takes the arguments after `--`
+    pub trailing: bool,
+    pub doc: Option<String>,
+}
+
+/// Group of params, their values are in a nested struct of the generated `Config`
+pub struct Section {
+    pub name: Ident,
+    pub doc: Option<String>,
+}
+
+impl Config {
+    /// Params of the section, `None` selects the top-level ones
+    pub fn section_params<'a>(&'a self, section: Option<&'a Ident>) -> impl Iterator<Item=&'a Param> {
+        let section = section.map(Ident::as_snake_case);
+        self.params
+            .iter()
+            .filter(move |param| param.section.as_ref().map(Ident::as_snake_case) == section)
+    }
+
+    /// Name of the program, `general.name` overrides the name of the cargo target
+    pub fn program_name(&self) -> Option<&str> {
+        self.general.name.as_deref().or(self.inferred_name.as_deref())
+    }
+}
+
+#[derive(Debug, Clone)]
 #[derive(Deserialize, Default)]
-#[serde(deny_unknown_fields)]
 pub struct General {
     /// Name of the program
     pub name: Option<String>,
@@ -338,6 +1555,9 @@ pub struct General {
     /// Long description of the program
     pub doc: Option<String>,
 
+    /// Version printed by `--version`
+    pub version: Option<Version>,
+
     /// Prefix for all env vars - enables
     /// all env vars by default if present
     pub env_prefix: Option<String>,
@@ -355,30 +1575,465 @@ pub struct General {
     /// directory, parse them, and override all
     /// configuration provided so far with them.
     pub conf_dir_param: Option<Ident>,
+
+    /// The name of the parameter selecting
+    /// the table of `[profile]` in config
+    /// files layered over their other values.
+    pub profile_param: Option<Ident>,
+
+    /// Dotted path to the table in config
+    /// files containing the configuration,
+    /// so that one file can configure
+    /// multiple programs.
+    pub config_table: Option<String>,
+
+    /// The name of the switch which, if
+    /// specified, prints a note about every
+    /// value overridden by a source with a
+    /// higher priority.
+    pub explain_config_switch: Option<Ident>,
+
+    /// The name of the switch which, if
+    /// specified, only checks that the
+    /// configuration is valid and exits.
+    pub check_config_switch: Option<Ident>,
+
+    /// The name of the switch which, if
+    /// specified, prints the configuration
+    /// as a config file and exits.
+    pub dump_config_switch: Option<Ident>,
+
+    /// Fails code generation if any param
+    /// or switch is missing `doc`.
+    #[serde(default)]
+    pub require_doc: bool,
+
+    /// Writes `example-config.toml` with every
+    /// param and switch next to the code.
+    #[serde(default)]
+    pub example_config: bool,
+
+    /// Writes `completion.ps1` registering a
+    /// PowerShell argument completer next to
+    /// the code.
+    #[serde(default)]
+    pub powershell_completion: bool,
+
+    /// Renders `--help` at runtime, wrapped to
+    /// the width of the terminal, with default
+    /// values, environment variables and
+    /// highlighted headings.
+    #[serde(default)]
+    pub rich_help: bool,
+
+    /// Mentions the hexadecimal, octal and
+    /// binary notation of integers in `--help`.
+    #[serde(default)]
+    pub help_integer_notation: bool,
+
+    /// Fails code generation if any param
+    /// relies on `defaults.optional` instead
+    /// of specifying `optional` or `default`.
+    #[serde(default)]
+    pub require_explicit_optional: bool,
+
+    /// Accepts unambiguous prefixes of
+    /// long options, e.g. `--por` for
+    /// `--port`.
+    #[serde(default)]
+    pub long_option_prefixes: bool,
+
+    /// Matches long options ignoring
+    /// case, e.g. `--Port` for `--port`.
+    #[serde(default)]
+    pub case_insensitive_long_options: bool,
+
+    /// Accepts Windows-style `/port 8080`
+    /// and `/port:8080` in addition to
+    /// `--port`.
+    #[serde(default)]
+    pub slash_options: bool,
+
+    /// Includes `--` in the remaining
+    /// arguments instead of dropping it.
+    #[serde(default)]
+    pub forward_separator: bool,
+
+    /// Converts values of a wrong type
+    /// in config files with a warning,
+    /// e.g. `port = "8080"`.
+    #[serde(default)]
+    pub lenient_config_values: bool,
+
+    /// Spellings turning a switch on
+    /// in environment variables,
+    /// `--switch=value` and config files.
+    pub true_values: Option<Vec<String>>,
+
+    /// Spellings turning a switch off.
+    pub false_values: Option<Vec<String>>,
+
+    /// Handling of environment variables
+    /// that are set but empty.
+    pub empty_env_var: Option<EmptyEnvVar>,
+
+    /// Handling of a param or switch
+    /// given more than once on the
+    /// command line.
+    pub duplicate_arg: Option<DuplicateArg>,
+
+    /// Handling of config files writable
+    /// by all users or owned by another
+    /// user than the current one or root.
+    pub insecure_config_files: Option<InsecureFile>,
+
+    /// Maximum size of a config file
+    /// in bytes.
+    pub max_config_file_size: Option<u64>,
+
+    /// Maximum length of a string in
+    /// a config file in bytes.
+    pub max_config_string_length: Option<usize>,
+
+    /// Maximum nesting depth of tables
+    /// and arrays in a config file.
+    pub max_config_depth: Option<usize>,
+
+    /// Config files larger than this many
+    /// bytes are memory-mapped instead of
+    /// read, requires the `mmap` feature
+    /// of `configure_me`.
+    pub mmap_config_threshold: Option<u64>,
+
+    /// Generates `Config::init_global()`
+    /// and `Config::global()` storing the
+    /// configuration in a static.
+    #[serde(default)]
+    pub global_config: bool,
+
+    /// Generates `SharedConfig`, which allows
+    /// replacing the configuration atomically.
+    #[serde(default)]
+    pub shared_config: bool,
+
+    /// Generates `Config::to_env_vars()`.
+    #[serde(default)]
+    pub export_env_vars: bool,
+
+    /// Generates `Config::to_args()`.
+    #[serde(default)]
+    pub export_args: bool,
+
+    /// Generates `Config::non_default_fields()`.
+    #[serde(default)]
+    pub non_default_fields: bool,
+
+    /// Generates `Config::interactive_setup()`.
+    #[serde(default)]
+    pub interactive_setup: bool,
+
+    /// Generates `Config::explain()`.
+    #[serde(default)]
+    pub merge_report: bool,
+
+    /// Generates `Config::reload_if_changed()`.
+    #[serde(default)]
+    pub reload_if_changed: bool,
+
+    /// Generates `Config::watch()`, requires
+    /// the `watch` feature of `configure_me`.
+    #[serde(default)]
+    pub watch_config: bool,
+
+    /// Loads config files with the `.json`
+    /// extension as JSON, requires the
+    /// `json` feature of `configure_me`.
+    #[serde(default)]
+    pub json_config: bool,
+
+    /// Generates an implementation of `arbitrary::Arbitrary` for `Config` if the `test-helpers`
+    /// feature of `configure_me` is enabled.
+    #[serde(default)]
+    pub arbitrary: bool,
+
+    /// Generates `Config::to_redacted_string()` and `Config::to_redacted_toml()`.
+    #[serde(default)]
+    pub redacted_rendering: bool,
+
+    /// Generates `Config::set_audit_callback()` registering a callback invoked for every param
+    /// and switch with the sources of its value when the configuration is loaded.
+    #[serde(default)]
+    pub audit: bool,
+
+    /// Path to a function checking the
+    /// whole configuration after all
+    /// sources are merged.
+    pub validate: Option<String>,
+
+    /// Generates an implementation of `Debug` for `Config` with the values of secret params
+    /// redacted.
+    #[serde(default)]
+    pub debug: bool,
+
+    /// Generates an implementation of `Serialize` for `Config` with the values of secret params
+    /// redacted.
+    #[serde(default)]
+    pub serialize: bool,
+
+    /// Generates `PartialConfig` so that applications can
+    /// merge layers of their own with the loaded ones.
+    #[serde(default)]
+    pub partial_config: bool,
+
+    /// Loads the `*.toml` files in the
+    /// directory named like a config file
+    /// with `.d` appended after the file.
+    #[serde(default)]
+    pub drop_ins: bool,
+
+    /// Generates `Config::including_user_config()`
+    /// loading the config files of the user and
+    /// the system, found using `name`.
+    #[serde(default)]
+    pub user_config: bool,
+
+    /// Path of a `.env` file setting
+    /// environment variables that aren't
+    /// set already.
+    pub env_file: Option<String>,
+
+    /// Resolves the files listed in the
+    /// `include` key of config files.
+    #[serde(default)]
+    pub config_includes: bool,
+
+    /// Generates `Config::loaded_config_files()`
+    /// returning the paths of the config
+    /// files that were loaded.
+    #[serde(default)]
+    pub loaded_config_files: bool,
+
+    /// Fetches config files given as
+    /// `https://` URLs, requires the
+    /// `remote` feature of `configure_me`.
+    #[serde(default)]
+    pub remote_config: bool,
+
+    /// Also fetches config files given as
+    /// plain `http://` URLs.
+    #[serde(default)]
+    pub remote_config_http: bool,
+
+    /// Seconds to wait for a remote config
+    /// file, 30 by default.
+    pub remote_config_timeout: Option<u64>,
+
+    /// Whether any param or switch has
+    /// `requires` or `conflicts_with` or
+    /// there's a group, set when validating
+    /// the specification.
+    #[serde(skip)]
+    pub relations: bool,
 }
 
-#[derive(Debug)]
-#[derive(Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct Defaults {
-    #[serde(default = "make_true")]
-    pub args: bool,
-    #[serde(default)]
-    pub env_vars: Option<bool>,
-    #[serde(default = "make_true")]
-    pub optional: bool,
+impl General {
+    /// Fills in the values missing in `self` from the extended specification
+    fn or(self, base: General) -> General {
+        General {
+            name: self.name.or(base.name),
+            summary: self.summary.or(base.summary),
+            doc: self.doc.or(base.doc),
+            version: self.version.or(base.version),
+            env_prefix: self.env_prefix.or(base.env_prefix),
+            conf_file_param: self.conf_file_param.or(base.conf_file_param),
+            conf_dir_param: self.conf_dir_param.or(base.conf_dir_param),
+            profile_param: self.profile_param.or(base.profile_param),
+            config_table: self.config_table.or(base.config_table),
+            explain_config_switch: self.explain_config_switch.or(base.explain_config_switch),
+            check_config_switch: self.check_config_switch.or(base.check_config_switch),
+            dump_config_switch: self.dump_config_switch.or(base.dump_config_switch),
+            require_doc: self.require_doc || base.require_doc,
+            example_config: self.example_config || base.example_config,
+            powershell_completion: self.powershell_completion || base.powershell_completion,
+            rich_help: self.rich_help || base.rich_help,
+            help_integer_notation: self.help_integer_notation || base.help_integer_notation,
+            require_explicit_optional: self.require_explicit_optional || base.require_explicit_optional,
+            long_option_prefixes: self.long_option_prefixes || base.long_option_prefixes,
+            case_insensitive_long_options: self.case_insensitive_long_options || base.case_insensitive_long_options,
+            slash_options: self.slash_options || base.slash_options,
+            forward_separator: self.forward_separator || base.forward_separator,
+            lenient_config_values: self.lenient_config_values || base.lenient_config_values,
+            true_values: self.true_values.or(base.true_values),
+            false_values: self.false_values.or(base.false_values),
+            empty_env_var: self.empty_env_var.or(base.empty_env_var),
+            duplicate_arg: self.duplicate_arg.or(base.duplicate_arg),
+            insecure_config_files: self.insecure_config_files.or(base.insecure_config_files),
+            max_config_file_size: self.max_config_file_size.or(base.max_config_file_size),
+            max_config_string_length: self.max_config_string_length.or(base.max_config_string_length),
+            max_config_depth: self.max_config_depth.or(base.max_config_depth),
+            mmap_config_threshold: self.mmap_config_threshold.or(base.mmap_config_threshold),
+            global_config: self.global_config || base.global_config,
+            shared_config: self.shared_config || base.shared_config,
+            export_env_vars: self.export_env_vars || base.export_env_vars,
+            export_args: self.export_args || base.export_args,
+            non_default_fields: self.non_default_fields || base.non_default_fields,
+            interactive_setup: self.interactive_setup || base.interactive_setup,
+            merge_report: self.merge_report || base.merge_report,
+            reload_if_changed: self.reload_if_changed || base.reload_if_changed,
+            watch_config: self.watch_config || base.watch_config,
+            json_config: self.json_config || base.json_config,
+            arbitrary: self.arbitrary || base.arbitrary,
+            redacted_rendering: self.redacted_rendering || base.redacted_rendering,
+            audit: self.audit || base.audit,
+            validate: self.validate.or(base.validate),
+            debug: self.debug || base.debug,
+            serialize: self.serialize || base.serialize,
+            partial_config: self.partial_config || base.partial_config,
+            env_file: self.env_file.or(base.env_file),
+            user_config: self.user_config || base.user_config,
+            drop_ins: self.drop_ins || base.drop_ins,
+            config_includes: self.config_includes || base.config_includes,
+            loaded_config_files: self.loaded_config_files || base.loaded_config_files,
+            remote_config: self.remote_config || base.remote_config,
+            remote_config_http: self.remote_config_http || base.remote_config_http,
+            remote_config_timeout: self.remote_config_timeout.or(base.remote_config_timeout),
+            relations: self.relations || base.relations,
+        }
+    }
+
+    /// Whether the sources of values are tracked, for explaining overrides, auditing or reporting
+    /// relations
+    pub fn tracks_sources(&self) -> bool {
+        self.explain_config_switch.is_some() || self.audit || self.relations
+    }
+
+    /// Whether `--version` is generated
+    pub fn prints_version(&self) -> bool {
+        match &self.version {
+            Some(Version::Package(enabled)) => *enabled,
+            Some(Version::Fixed(_)) => true,
+            None => false,
+        }
+    }
+
+    /// Spellings turning a switch on, `1` and `true` unless specified
+    pub fn true_values(&self) -> Vec<&str> {
+        match &self.true_values {
+            Some(values) => values.iter().map(AsRef::as_ref).collect(),
+            None => vec!["1", "true"],
+        }
+    }
+
+    /// Handling of empty environment variables unless a param overrides it
+    pub fn empty_env_var(&self) -> EmptyEnvVar {
+        self.empty_env_var.unwrap_or_default()
+    }
+
+    /// Handling of config files other users can modify, allowed unless specified
+    pub fn insecure_config_files(&self) -> InsecureFile {
+        self.insecure_config_files.unwrap_or_default()
+    }
+
+    /// Handling of repeated arguments unless a param or switch overrides it
+    pub fn duplicate_arg(&self) -> DuplicateArg {
+        self.duplicate_arg.unwrap_or_default()
+    }
+
+    /// Spellings turning a switch off, `0` and `false` unless specified
+    pub fn false_values(&self) -> Vec<&str> {
+        match &self.false_values {
+            Some(values) => values.iter().map(AsRef::as_ref).collect(),
+            None => vec!["0", "false"],
+        }
+    }
+}
+
+/// Version printed by `--version`, `version = true` or `version = "1.0.0"`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Version {
+    /// The version of the package if `true`, no `--version` if `false`
+    Package(bool),
+    Fixed(String),
 }
 
-impl Default for Defaults {
-    fn default() -> Self {
-        Defaults {
-            args: true,
-            env_vars: None,
-            optional: true,
+/// Bound of the values of a numeric param, `min = 1` or `max = 0.5`
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Bound {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Bound {
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Bound::Integer(value) => value as f64,
+            Bound::Float(value) => value,
         }
     }
 }
 
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Bound::Integer(value) => write!(f, "{}", value),
+            Bound::Float(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+/// How to treat an environment variable that is set but empty
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmptyEnvVar {
+    /// Parse the empty string like any other value (the default)
+    #[default]
+    Value,
+    /// Behave as if the variable wasn't set
+    Unset,
+    /// Fail with an error
+    Error,
+}
+
+/// How to treat a param or switch given more than once on the command line
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicateArg {
+    /// The last occurrence wins (the default)
+    #[default]
+    Last,
+    /// The first occurrence wins, the values of the others are still parsed
+    First,
+    /// Fail with an error
+    Error,
+}
+
+/// How the values of a param with `multiple = true` from different sources are combined
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListMerge {
+    /// The values from all sources are collected (the default)
+    #[default]
+    Append,
+    /// The values from a source with a higher priority replace the others, the occurrences on
+    /// the command line are still collected
+    Replace,
+}
+
+/// How to treat a config file other users can modify
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InsecureFile {
+    /// Load it silently (the default)
+    #[default]
+    Allow,
+    /// Load it with a warning
+    Warn,
+    /// Refuse to load it
+    Error,
+}
+
 pub enum Optionality {
     Mandatory,
     Optional,
@@ -391,16 +2046,128 @@ pub enum SwitchKind {
     Inverted,
 }
 
+/// Number of values taken by a single occurrence of a param
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Nargs {
+    /// Exactly this many values, stored in an array
+    Fixed(usize),
+    /// At least `min` and at most `max` values, stored in a `Vec`
+    Bounded { min: usize, max: usize },
+}
+
+/// Name of the enum variant of a value of a param with `values`, e.g. `PlainText` for
+/// `plain-text`
+/// Checks that the values of a generated enum are distinct and valid names of variants
+fn are_valid_values(values: &[String]) -> bool {
+    let variants = values.iter().map(|value| variant_name(value)).collect::<Vec<_>>();
+    let is_valid = |value: &String| value.starts_with(|c: char| c.is_ascii_alphabetic()) && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    let is_distinct = variants.iter().enumerate().all(|(i, variant)| !variants[..i].contains(variant));
+    !values.is_empty() && values.iter().all(is_valid) && is_distinct
+}
+
+fn variant_name(value: &str) -> String {
+    let mut name = String::with_capacity(value.len());
+    let mut capitalize = true;
+    for c in value.chars() {
+        if c == '-' || c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            name.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// Type of the raw field holding the values of a param of type `ty`
+fn field_type(ty: &str, nargs: Option<Nargs>, multiple: bool, map: bool) -> String {
+    match nargs {
+        None if map => format!("::std::collections::HashMap<String, {}>", ty),
+        None if multiple => format!("Vec<{}>", ty),
+        None => ty.to_owned(),
+        Some(Nargs::Fixed(count)) => format!("[{}; {}]", ty, count),
+        Some(Nargs::Bounded { .. }) => format!("Vec<{}>", ty),
+    }
+}
+
+impl Nargs {
+    /// Minimum number of values
+    pub fn min(self) -> usize {
+        match self {
+            Nargs::Fixed(count) => count,
+            Nargs::Bounded { min, .. } => min,
+        }
+    }
+
+    /// Maximum number of values
+    pub fn max(self) -> usize {
+        match self {
+            Nargs::Fixed(count) => count,
+            Nargs::Bounded { max, .. } => max,
+        }
+    }
+}
+
 pub struct Param {
     pub name: Ident,
     pub abbr: Option<char>,
+    /// Type of a single value, see `field_type()`
     pub ty: String,
+    /// Whether it has the built-in type `size`, `ty` is then `u64`
+    pub size: bool,
+    pub nargs: Option<Nargs>,
     pub optionality: Optionality,
     pub doc: Option<String>,
     pub argument: bool,
     pub env_var: bool,
     pub convert_into: String,
+    /// `helpers::append_values` for params with `multiple = true` that append the values and
+    /// `helpers::merge_maps` for maps
     pub merge_fn: Option<String>,
+    /// Allowed values, `ty` is then the name of the generated enum
+    pub values: Option<Vec<String>>,
+    /// `Some` if the param can be given multiple times, collecting the values into a `Vec`
+    pub multiple: Option<ListMerge>,
+    /// Separator of the values of a param with `multiple = true` in the environment variable
+    pub env_delimiter: char,
+    /// Whether the values are entries of a `HashMap` with `String` keys, given as `key=value` on
+    /// the command line and in environment variables suffixed by the key
+    pub map: bool,
+    pub empty_env_var: EmptyEnvVar,
+    /// Always `Last` for params with `merge_fn`
+    pub duplicate_arg: DuplicateArg,
+    /// Whether the sources of the value are tracked for `general.explain_config_switch` or
+    /// `general.audit`, never for params with `merge_fn`, which combine the values instead of
+    /// overriding them
+    pub explain_overrides: bool,
+    /// Whether all sources combined by `merge_fn` are tracked for `general.audit`
+    pub audit_combined: bool,
+    /// Whether the value is redacted in reports,
+    /// `Debug` and errors and can be read from
+    /// the file named by `<VAR>_FILE`
+    pub secret: bool,
+    /// Previous names still accepted in config files and environment variables with a warning
+    pub renamed_from: Vec<Ident>,
+    /// Other names accepted on the command line and in config files
+    pub aliases: Vec<Ident>,
+    /// Whether the aliases are listed in the help
+    pub show_aliases: bool,
+    /// Params and switches that must be set if this one is
+    pub requires: Vec<Ident>,
+    /// Params and switches that can't be set together with this one
+    pub conflicts_with: Vec<Ident>,
+    /// Smallest allowed value, only on primitive numbers
+    pub min: Option<Bound>,
+    /// Largest allowed value, only on primitive numbers
+    pub max: Option<Bound>,
+    /// Pattern all values must match, only on strings
+    pub validate_regex: Option<String>,
+    /// Path to a `fn(&T) -> Result<(), String>` checking each value
+    pub validate: Option<String>,
+    /// Section containing the param, `name` is then prefixed by the name of the section
+    pub section: Option<Ident>,
     #[cfg(feature = "debconf")]
     pub debconf_priority: Option<::debconf::Priority>,
     #[cfg(feature = "debconf")]
@@ -412,21 +2179,256 @@ pub struct Switch {
     pub kind: SwitchKind,
     pub doc: Option<String>,
     pub env_var: bool,
+    /// Whether `--no-<name>` is accepted to turn off a normal switch
+    pub negatable: bool,
+    /// Always `Last` for count switches
+    pub duplicate_arg: DuplicateArg,
+    /// Whether the sources of the value are tracked for `general.explain_config_switch` or
+    /// `general.audit`, never for count switches
+    pub explain_overrides: bool,
+    /// Whether all sources counted by a count switch are tracked for `general.audit`
+    pub audit_combined: bool,
+    /// Other names accepted on the command line and in config files
+    pub aliases: Vec<Ident>,
+    /// Whether the aliases are listed in the help
+    pub show_aliases: bool,
+    /// Params and switches that must be set if this one is on
+    pub requires: Vec<Ident>,
+    /// Params and switches that can't be set while this one is on
+    pub conflicts_with: Vec<Ident>,
+    /// Maximum number of occurrences of a count switch
+    pub max_count: Option<u32>,
+    /// Type of the field of a count switch instead of `u32`
+    pub map_to: Option<String>,
+    /// Expressions of the values of `map_to` for the counts from 0, the last one is used for
+    /// higher counts
+    pub map_values: Vec<String>,
+    /// Values of the enum `map_to` generated for the switch
+    pub values: Option<Vec<String>>,
     #[cfg(feature = "debconf")]
     pub debconf_priority: Option<::debconf::Priority>,
 }
 
+impl Param {
+    /// Type of the field in the raw config, an array or `Vec` of `ty` if the param takes multiple
+    /// values or a `HashMap` if it's a map
+    pub fn field_type(&self) -> String {
+        field_type(&self.ty, self.nargs, self.multiple.is_some(), self.map)
+    }
+
+    /// Name of the field in the generated struct and the key in config files, which is the name
+    /// without the section prefix
+    pub fn key(&self) -> &str {
+        self.section_key(&self.name)
+    }
+
+    /// Key of the current or an old name of the param within its section
+    pub fn section_key<'a>(&self, name: &'a Ident) -> &'a str {
+        match &self.section {
+            Some(section) => &name.as_snake_case()[(section.as_snake_case().len() + 1)..],
+            None => name.as_snake_case(),
+        }
+    }
+
+    /// Path of the current or an old name of the param, e.g. `database.url`
+    pub fn section_path(&self, name: &Ident) -> String {
+        match &self.section {
+            Some(section) => format!("{}.{}", section.as_snake_case(), self.section_key(name)),
+            None => name.as_snake_case().to_owned(),
+        }
+    }
+
+    /// Path of the field in the generated `Config`, e.g. `database.url`
+    pub fn field_path(&self) -> String {
+        self.section_path(&self.name)
+    }
+
+    /// Names of the enum variants and the values they represent if the param has `values`
+    pub fn variants(&self) -> Vec<(String, &str)> {
+        self.values
+            .iter()
+            .flatten()
+            .map(|value| (variant_name(value), value.as_str()))
+            .collect()
+    }
+
+    /// Whether the field holds multiple values, either taken by a single occurrence or collected
+    /// from repeated ones, including the entries of maps
+    pub fn has_multiple_values(&self) -> bool {
+        self.nargs.is_some() || self.multiple.is_some() || self.map
+    }
+
+    /// Whether the field is an `OsString`, which serde only deserializes from a platform-specific
+    /// representation, not from a string
+    pub fn is_os_string(&self) -> bool {
+        if self.has_multiple_values() {
+            return false;
+        }
+        match ::syn::parse_str::<::syn::Type>(&self.ty) {
+            Ok(::syn::Type::Path(ty)) if ty.qself.is_none() => {
+                let segments = ty.path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+                segments == ["OsString"] || segments == ["std", "ffi", "OsString"]
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether the param is a primitive integer, which is also accepted in hexadecimal, octal or
+    /// binary notation from arguments and environment variables
+    pub fn is_integer(&self) -> bool {
+        !self.size && is_integer(&self.ty)
+    }
+
+    /// Type the values are parsed as from arguments and environment variables
+    pub fn parse_type(&self) -> String {
+        if self.size {
+            SIZE_PARSE_TYPE.to_owned()
+        } else {
+            parse_type(&self.ty)
+        }
+    }
+
+    /// Type describing the values in the hints of parse errors, which lists the notations of
+    /// integers if `notation` is set
+    pub fn hint_type(&self, notation: bool) -> String {
+        if notation && self.is_integer() {
+            format!("::configure_me::helpers::NotatedInt<{}>", self.ty.trim())
+        } else {
+            self.parse_type()
+        }
+    }
+
+    /// Whether the values are parsed as a wrapper from `helpers` holding the value in `.0`
+    pub fn is_wrapped(&self) -> bool {
+        self.size || is_wrapped(&self.ty)
+    }
+
+    /// Whether the param has the built-in type `duration`
+    pub fn is_duration(&self) -> bool {
+        self.ty == DURATION_TYPE
+    }
+}
+
+impl Positional {
+    /// Type of the field in the raw config, a `Vec` of `ty` if it's variadic
+    pub fn field_type(&self) -> String {
+        field_type(&self.ty, None, self.variadic, false)
+    }
+
+    /// Name shown in the usage, e.g. `INPUT`
+    pub fn value_name(&self) -> String {
+        self.name.as_upper_case().to_string()
+    }
+
+    /// Whether it's a primitive integer, accepted in the same notations as integer params
+    pub fn is_integer(&self) -> bool {
+        !self.size && is_integer(&self.ty)
+    }
+
+    /// Type the values are parsed as
+    pub fn parse_type(&self) -> String {
+        if self.size {
+            SIZE_PARSE_TYPE.to_owned()
+        } else {
+            parse_type(&self.ty)
+        }
+    }
+
+    /// Type describing the values in the hints of parse errors, which lists the notations of
+    /// integers if `notation` is set
+    pub fn hint_type(&self, notation: bool) -> String {
+        if notation && self.is_integer() {
+            format!("::configure_me::helpers::NotatedInt<{}>", self.ty.trim())
+        } else {
+            self.parse_type()
+        }
+    }
+
+    /// Whether the values are parsed as a wrapper from `helpers` holding the value in `.0`
+    pub fn is_wrapped(&self) -> bool {
+        self.size || is_wrapped(&self.ty)
+    }
+
+    /// Whether it has the built-in type `duration`
+    pub fn is_duration(&self) -> bool {
+        self.ty == DURATION_TYPE
+    }
+}
+
+/// Names of the items generated next to the enums of params with `values` and of the standard
+/// types the generated code refers to unqualified
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "ArgParseError", "Config", "Contribution", "EnvParseError", "Error", "FieldReport", "MergeReport", "MissingField",
+    "NonDefaultField", "PartialConfig", "ReportSource", "ResultExt", "SharedConfig", "Subcommand", "ValidationError",
+    "Box", "Option", "PathBuf", "Result", "String", "Vec",
+];
+
+/// Type of fields of the built-in type `duration`, parsed by `humantime`
+pub const DURATION_TYPE: &str = "::std::time::Duration";
+
+/// Type parsing the values of the built-in type `size`, which are stored as `u64`
+pub const SIZE_PARSE_TYPE: &str = "::configure_me::helpers::ByteSize";
+
+/// Replaces the name of a built-in type by the Rust type
+fn builtin_type(ty: String) -> String {
+    match ty.trim() {
+        "duration" => DURATION_TYPE.to_owned(),
+        "size" => "u64".to_owned(),
+        _ => ty,
+    }
+}
+
+fn is_wrapped(ty: &str) -> bool {
+    is_integer(ty) || ty == DURATION_TYPE
+}
+
+fn is_integer(ty: &str) -> bool {
+    const INTEGERS: &[&str] = &["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+    INTEGERS.contains(&ty.trim())
+}
+
+/// Values of a primitive integer type, the bounds of 64-bit and larger types are only limited by
+/// the `i64` bounds of TOML
+pub(crate) fn integer_range(ty: &str) -> (i128, i128) {
+    match ty {
+        "u8" => (0, u8::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "u32" => (0, u32::MAX.into()),
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        _ if ty.starts_with('u') => (0, i128::MAX),
+        _ => (i128::MIN, i128::MAX),
+    }
+}
+
+fn parse_type(ty: &str) -> String {
+    if is_integer(ty) {
+        format!("::configure_me::helpers::Int<{}>", ty.trim())
+    } else if ty == DURATION_TYPE {
+        "::configure_me::helpers::HumanDuration".to_owned()
+    } else {
+        ty.to_owned()
+    }
+}
+
 impl Switch {
     pub fn is_inverted(&self) -> bool {
         self.kind == SwitchKind::Inverted
     }
 
     pub fn is_count(&self) -> bool {
-        if let SwitchKind::Normal { count: true, .. } = self.kind {
-            true
-        } else {
-            false
-        }
+        matches!(self.kind, SwitchKind::Normal { count: true, .. })
+    }
+
+    /// Names of the enum variants and the values they represent if the switch has `values`
+    pub fn variants(&self) -> Vec<(String, &str)> {
+        self.values
+            .iter()
+            .flatten()
+            .map(|value| (variant_name(value), value.as_str()))
+            .collect()
     }
 
 }
@@ -38,7 +38,7 @@ impl<'de> Deserialize<'de> for Priority {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct DebConfig {
     pub package_name: String,
     #[serde(default)]
@@ -139,7 +139,7 @@ impl TryFrom<String> for Umask {
 }
 
 /// How config file should be generated
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ConfigMode {
     /// Generates full script with incldes and `db_go`
     WithBoilerplate,
@@ -165,7 +165,7 @@ impl<'de> Deserialize<'de> for ConfigMode {
 }
 
 /// How postinst file should be generated
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum PostinstMode {
     /// Generates full script which stores the configuration into the specified conf file
@@ -261,16 +261,29 @@ impl VisitWrite<visitor::Postinst> for ::config::Config {
             if let Some(group) = conf_group {
                 writeln!(output, "chgrp {} \"$CONF_FILE\"", group)?;
             }
-            visitor::iter::<visitor::Postinst, _, _>(&self.params, deb_config, &mut output)?;
-            visitor::iter::<visitor::Postinst, _, _>(&self.switches, deb_config, &mut output)?;
+            write_postinst_values(self, deb_config, &mut output)?;
             writeln!(output, "umask \"$OLD_UMASK\"")?;
             writeln!(output)?;
             writeln!(output, "#DEBHELPER#")
         } else {
-            visitor::iter::<visitor::Postinst, _, _>(&self.params, deb_config, &mut output)?;
-            visitor::iter::<visitor::Postinst, _, _>(&self.switches, deb_config, &mut output)
+            write_postinst_values(self, deb_config, &mut output)
+        }
+    }
+}
+
+/// Writes the values of params and switches into the config file, the keys following a table
+/// header belong to the table, so sections go last
+fn write_postinst_values<W: fmt::Write>(config: &::config::Config, deb_config: &DebConfig, mut output: W) -> fmt::Result {
+    visitor::iter::<visitor::Postinst, _, _>(config.section_params(None), deb_config, &mut output)?;
+    visitor::iter::<visitor::Postinst, _, _>(&config.switches, deb_config, &mut output)?;
+    for section in &config.sections {
+        let params = config.section_params(Some(&section.name)).collect::<Vec<_>>();
+        if params.iter().any(|param| param.debconf_priority.is_some()) {
+            writeln!(output, "echo '[{}]' >> \"$CONF_FILE\"", section.name.as_snake_case())?;
+            visitor::iter::<visitor::Postinst, _, _>(params, deb_config, &mut output)?;
         }
     }
+    Ok(())
 }
 
 impl VisitWrite<visitor::Templates> for ::config::Param {
@@ -279,6 +292,9 @@ impl VisitWrite<visitor::Templates> for ::config::Param {
             writeln!(output, "Template: {}/{}", config.package_name, self.name.as_snake_case())?;
             if self.ty == "bool" {
                 writeln!(output, "Type: bool")?;
+            } else if let Some(values) = &self.values {
+                writeln!(output, "Type: select")?;
+                writeln!(output, "Choices: {}", values.join(", "))?;
             } else {
                 writeln!(output, "Type: string")?;
             }
@@ -342,8 +358,8 @@ impl VisitWrite<visitor::Postinst> for ::config::Param {
             writeln!(output, "db_get {}/{}", config.package_name, self.name.as_snake_case())?;
             match self.ty.as_str() {
                 "bool" | "u8" | "u16" | "u32" | "u64" | "u128" |
-                    "i8" | "i16" | "i32" | "i64" | "i128" | "f32" | "f64" => writeln!(output, "echo {}=\"$RET\" >> \"$CONF_FILE\"", self.name.as_snake_case()),
-                _ => writeln!(output, "echo \"$RET\" | sed -e 's/\"/\\\"/g' -e 's/^/{}=\"/' -e 's/$/\"/' >> \"$CONF_FILE\"", self.name.as_snake_case()),
+                    "i8" | "i16" | "i32" | "i64" | "i128" | "f32" | "f64" => writeln!(output, "echo {}=\"$RET\" >> \"$CONF_FILE\"", self.key()),
+                _ => writeln!(output, "echo \"$RET\" | sed -e 's/\"/\\\"/g' -e 's/^/{}=\"/' -e 's/$/\"/' >> \"$CONF_FILE\"", self.key()),
             }
         } else {
             Ok(())
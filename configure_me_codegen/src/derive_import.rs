@@ -0,0 +1,320 @@
+//! Migration of `clap` derive and `structopt` structs to specifications
+//!
+//! The struct deriving `Parser` or `StructOpt` is converted into `clap_import::ClapCommand`,
+//! attributes that have no equivalent are reported instead.
+
+use clap_import::{self, ClapAction, ClapArg, ClapCommand, ClapNumArgs};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, Fields, GenericArgument, Ident, Item, ItemStruct, Lit, PathArguments, Type};
+use {Error, ErrorData};
+
+/// Specification converted from a derived parser
+#[derive(Debug)]
+pub struct Migration {
+    /// The specification, see `clap_import::spec_from_command()`
+    pub spec: String,
+    /// Attributes that were ignored because `configure_me` has no equivalent
+    pub unsupported: Vec<String>,
+}
+
+/// Item of `#[arg(...)]`, `#[command(...)]`, `#[clap(...)]` or `#[structopt(...)]`
+struct AttrItem {
+    name: Ident,
+    value: AttrValue,
+}
+
+enum AttrValue {
+    /// `short`
+    None,
+    /// `short = 'p'`
+    Expr(Box<Expr>),
+    /// `parse(from_occurrences)`
+    Group(String),
+}
+
+impl Parse for AttrItem {
+    fn parse(input: ParseStream) -> ::syn::Result<Self> {
+        let name = input.call(::syn::ext::IdentExt::parse_any)?;
+        let value = if input.peek(::syn::Token![=]) {
+            input.parse::<::syn::Token![=]>()?;
+            AttrValue::Expr(input.parse()?)
+        } else if input.peek(::syn::token::Paren) {
+            let content;
+            ::syn::parenthesized!(content in input);
+            let tokens = content.cursor().token_stream().to_string();
+            content.step(|cursor| {
+                let mut rest = *cursor;
+                while let Some((_, next)) = rest.token_tree() {
+                    rest = next;
+                }
+                Ok(((), rest))
+            })?;
+            AttrValue::Group(tokens)
+        } else {
+            AttrValue::None
+        };
+        Ok(AttrItem { name, value })
+    }
+}
+
+const PARSER_DERIVES: &[&str] = &["Parser", "StructOpt", "Clap"];
+const PARSER_ATTRS: &[&str] = &["arg", "command", "clap", "structopt"];
+
+fn is_parser(item: &ItemStruct) -> bool {
+    item.attrs.iter().filter(|attr| attr.path.is_ident("derive")).any(|attr| {
+        attr.parse_args_with(Punctuated::<::syn::Path, ::syn::Token![,]>::parse_terminated)
+            .map(|paths| paths.iter().any(|path| path.segments.last().is_some_and(|segment| PARSER_DERIVES.contains(&&*segment.ident.to_string()))))
+            .unwrap_or(false)
+    })
+}
+
+/// The first paragraph of the doc comments, which clap uses as the help
+fn doc(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs.iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(::syn::Meta::NameValue(::syn::MetaNameValue { lit: Lit::Str(doc), .. })) => Some(doc.value().trim().to_owned()),
+            _ => None,
+        })
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+fn parser_attrs(attrs: &[Attribute], unsupported: &mut Vec<String>, context: &str) -> Vec<AttrItem> {
+    let mut items = Vec::new();
+    for attr in attrs {
+        if !PARSER_ATTRS.iter().any(|name| attr.path.is_ident(name)) {
+            continue;
+        }
+        match attr.parse_args_with(Punctuated::<AttrItem, ::syn::Token![,]>::parse_terminated) {
+            Ok(parsed) => items.extend(parsed),
+            Err(error) => unsupported.push(format!("{}: failed to parse an attribute: {}", context, error)),
+        }
+    }
+    items
+}
+
+/// The value of a literal as clap would see it
+fn literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(expr) => match &expr.lit {
+            Lit::Str(lit) => Some(lit.value()),
+            Lit::Char(lit) => Some(lit.value().to_string()),
+            Lit::Int(lit) => Some(lit.base10_digits().to_owned()),
+            Lit::Float(lit) => Some(lit.base10_digits().to_owned()),
+            Lit::Bool(lit) => Some(lit.value.to_string()),
+            _ => None,
+        },
+        Expr::Unary(::syn::ExprUnary { op: ::syn::UnOp::Neg(_), expr, .. }) => literal(expr).map(|value| format!("-{}", value)),
+        _ => None,
+    }
+}
+
+fn usize_literal(expr: &Expr) -> Option<usize> {
+    literal(expr)?.parse().ok()
+}
+
+/// `num_args = 2`, `num_args = 1..=3` or `num_args = 1..`
+fn num_args(expr: &Expr) -> Option<ClapNumArgs> {
+    match expr {
+        Expr::Range(range) => {
+            let min = match &range.from {
+                Some(from) => usize_literal(from)?,
+                None => 0,
+            };
+            let max = match (&range.to, &range.limits) {
+                (Some(to), ::syn::RangeLimits::Closed(_)) => usize_literal(to)?,
+                (Some(to), ::syn::RangeLimits::HalfOpen(_)) => usize_literal(to)?.checked_sub(1)?,
+                (None, _) => usize::MAX,
+            };
+            Some(ClapNumArgs { min, max })
+        },
+        expr => usize_literal(expr).map(|count| ClapNumArgs { min: count, max: count }),
+    }
+}
+
+/// Last segment of a path, e.g. `Count` of `ArgAction::Count`
+fn path_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(path) => path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// Formats simple types, `None` for references, tuples and other types clap can't parse anyway
+fn type_string(ty: &Type) -> Option<String> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let mut string = String::new();
+    if path.leading_colon.is_some() {
+        string.push_str("::");
+    }
+    for (i, segment) in path.segments.iter().enumerate() {
+        if i > 0 {
+            string.push_str("::");
+        }
+        string.push_str(&segment.ident.to_string());
+        match &segment.arguments {
+            PathArguments::None => (),
+            PathArguments::AngleBracketed(arguments) => {
+                let arguments = arguments.args
+                    .iter()
+                    .map(|argument| match argument {
+                        GenericArgument::Type(ty) => type_string(ty),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                string.push('<');
+                string.push_str(&arguments.join(", "));
+                string.push('>');
+            },
+            PathArguments::Parenthesized(_) => return None,
+        }
+    }
+    Some(string)
+}
+
+/// The type inside `Option<T>` or `Vec<T>` named `wrapper`
+fn unwrap_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => match &arguments.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn convert_field(field: &::syn::Field, unsupported: &mut Vec<String>) -> Option<ClapArg> {
+    let id = field.ident.as_ref()?.to_string();
+    let id = id.trim_start_matches("r#").to_owned();
+    let context = format!("field `{}`", id);
+    let items = parser_attrs(&field.attrs, unsupported, &context);
+    if items.iter().any(|item| item.name == "flatten" || item.name == "subcommand") {
+        unsupported.push(format!("{}: flattened structs and subcommands aren't supported, the field is skipped", context));
+        return None;
+    }
+
+    let (optional, ty) = match unwrap_type(&field.ty, "Option") {
+        Some(inner) => (true, inner),
+        None => (false, &field.ty),
+    };
+    let (multiple, ty) = match unwrap_type(ty, "Vec") {
+        Some(inner) => (true, inner),
+        None => (false, ty),
+    };
+    let ty = match type_string(ty) {
+        Some(ty) => ty,
+        None => {
+            unsupported.push(format!("{}: the type isn't supported, the field is skipped", context));
+            return None;
+        },
+    };
+    let mut arg = ClapArg {
+        id: id.clone(),
+        long: None,
+        short: None,
+        help: doc(&field.attrs),
+        required: false,
+        action: if ty == "bool" && !optional && !multiple { ClapAction::SetTrue } else if multiple { ClapAction::Append } else { ClapAction::Set },
+        num_args: None,
+        default_values: Vec::new(),
+        env: None,
+        ty: if ty == "bool" { None } else { Some(ty) },
+    };
+    let mut has_default = false;
+    for item in items {
+        let name = item.name.to_string();
+        match (&*name, &item.value) {
+            ("long", AttrValue::None) => arg.long = Some(id.replace('_', "-")),
+            ("short", AttrValue::None) => arg.short = id.chars().next(),
+            ("env", AttrValue::None) => arg.env = Some(id.to_ascii_uppercase()),
+            ("long", AttrValue::Expr(expr)) => arg.long = literal(expr),
+            ("short", AttrValue::Expr(expr)) => arg.short = literal(expr).and_then(|short| short.chars().next()),
+            ("env", AttrValue::Expr(expr)) => arg.env = literal(expr),
+            ("help", AttrValue::Expr(expr)) => arg.help = literal(expr),
+            ("required", AttrValue::None) => arg.required = true,
+            ("required", AttrValue::Expr(expr)) => arg.required = literal(expr).is_some_and(|required| required == "true"),
+            ("default_value", AttrValue::Expr(expr)) | ("default_value_t", AttrValue::Expr(expr)) if literal(expr).is_some() => {
+                arg.default_values = literal(expr).into_iter().collect();
+                has_default = true;
+            },
+            ("action", AttrValue::Expr(expr)) => match path_name(expr).as_ref().map(AsRef::as_ref) {
+                Some("Set") => arg.action = ClapAction::Set,
+                Some("Append") => arg.action = ClapAction::Append,
+                Some("SetTrue") => arg.action = ClapAction::SetTrue,
+                Some("SetFalse") => arg.action = ClapAction::SetFalse,
+                Some("Count") => arg.action = ClapAction::Count,
+                _ => unsupported.push(format!("{}: the action isn't supported", context)),
+            },
+            ("num_args", AttrValue::Expr(expr)) => match num_args(expr) {
+                Some(range) => arg.num_args = Some(range),
+                None => unsupported.push(format!("{}: `num_args` isn't a literal range", context)),
+            },
+            ("parse", AttrValue::Group(tokens)) if tokens == "from_occurrences" => arg.action = ClapAction::Count,
+            // The help is taken from the doc comments already
+            ("long_help", _) | ("verbatim_doc_comment", _) => (),
+            _ => unsupported.push(format!("{}: `{}` isn't supported", context, name)),
+        }
+    }
+    // Plain fields without a default are required
+    arg.required |= !optional && !multiple && !has_default && arg.action == ClapAction::Set;
+    Some(arg)
+}
+
+/// Converts the first struct deriving `Parser` or `StructOpt` found in the Rust source.
+pub fn spec_from_derive(source: &str) -> Result<Migration, Error> {
+    let file = ::syn::parse_file(source).map_err(ErrorData::RustSource)?;
+    let item = file.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(item) if is_parser(item) => Some(item),
+            _ => None,
+        })
+        .next()
+        .ok_or(ErrorData::MissingParserStruct)?;
+
+    let mut unsupported = Vec::new();
+    let context = format!("struct `{}`", item.ident);
+    let mut command = ClapCommand {
+        name: None,
+        about: doc(&item.attrs),
+        long_about: None,
+        args: Vec::new(),
+    };
+    for attr in parser_attrs(&item.attrs, &mut unsupported, &context) {
+        let name = attr.name.to_string();
+        match (&*name, &attr.value) {
+            ("name", AttrValue::Expr(expr)) => command.name = literal(expr),
+            ("about", AttrValue::Expr(expr)) => command.about = literal(expr),
+            ("long_about", AttrValue::Expr(expr)) => command.long_about = literal(expr),
+            // Taken from the package by default, so are the names of binaries
+            ("about", AttrValue::None) | ("long_about", AttrValue::None) => (),
+            ("rename_all", AttrValue::Expr(expr)) if literal(expr).as_ref().map(AsRef::as_ref) == Some("kebab-case") => (),
+            _ => unsupported.push(format!("{}: `{}` isn't supported", context, name)),
+        }
+    }
+    match &item.fields {
+        Fields::Named(fields) => command.args.extend(fields.named.iter().filter_map(|field| convert_field(field, &mut unsupported))),
+        _ => unsupported.push(format!("{}: only structs with named fields are supported", context)),
+    }
+
+    let spec = clap_import::spec_from_command(&command)?;
+    Ok(Migration { spec, unsupported })
+}
@@ -0,0 +1,143 @@
+//! Annotated sample config file (`example-config.toml`) suitable for shipping in packages
+
+use ::config::{Config, Optionality, Param, Switch};
+use std::fmt::{self, Write};
+
+fn write_doc<W: Write>(mut output: W, doc: &Option<String>) -> fmt::Result {
+    if let Some(doc) = doc {
+        for line in doc.trim().lines() {
+            match line.trim_end() {
+                "" => writeln!(output, "#")?,
+                line => writeln!(output, "# {}", line)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_env_var<W: Write>(mut output: W, config: &Config, name: &str) -> fmt::Result {
+    match &config.general.env_prefix {
+        Some(prefix) => writeln!(output, "# Environment variable: {}", format!("{}_{}", prefix, name).to_ascii_uppercase()),
+        None => writeln!(output, "# Environment variable: {}", name.to_ascii_uppercase()),
+    }
+}
+
+/// The default value if the expression is also valid TOML, possibly after dropping a conversion
+/// such as `.to_owned()`
+pub(crate) fn toml_default(default: &str) -> Option<&str> {
+    let default = default.trim();
+    [default, default.trim_end_matches(".to_owned()"), default.trim_end_matches(".to_string()"), default.trim_end_matches(".into()")]
+        .iter()
+        .find(|value| ::toml::from_str::<::toml::value::Table>(&format!("value = {}", value)).is_ok())
+        .copied()
+}
+
+/// A valid value of a single element of the param, used when the default isn't TOML
+fn sample_value(param: &Param) -> String {
+    let ty = param.ty.trim();
+    if let Some(value) = param.values.iter().flatten().next() {
+        format!("{:?}", value)
+    } else if param.size {
+        "\"64KiB\"".to_owned()
+    } else if param.is_duration() {
+        "\"30s\"".to_owned()
+    } else if param.is_integer() {
+        "0".to_owned()
+    } else if ty == "f32" || ty == "f64" {
+        "0.0".to_owned()
+    } else if ty == "bool" {
+        "false".to_owned()
+    } else {
+        "\"\"".to_owned()
+    }
+}
+
+fn param_value(param: &Param) -> String {
+    if let Optionality::DefaultValue(default) = &param.optionality {
+        if let Some(default) = toml_default(default) {
+            return default.to_owned();
+        }
+    }
+    let value = sample_value(param);
+    if param.map {
+        format!("{{ key = {} }}", value)
+    } else if let Some(::config::Nargs::Fixed(count)) = param.nargs {
+        format!("[{}]", vec![value; count].join(", "))
+    } else if param.has_multiple_values() {
+        format!("[{}]", value)
+    } else {
+        value
+    }
+}
+
+fn write_param<W: Write>(mut output: W, config: &Config, param: &Param) -> fmt::Result {
+    write_doc(&mut output, &param.doc)?;
+    writeln!(output, "# Type: {}", param.field_type())?;
+    match &param.optionality {
+        Optionality::Mandatory => writeln!(output, "# Mandatory")?,
+        Optionality::Optional => writeln!(output, "# Optional")?,
+        Optionality::DefaultValue(default) => writeln!(output, "# Default: {}", default)?,
+    }
+    if param.env_var && param.map {
+        write_env_var(&mut output, config, &format!("{}_<key>", param.name.as_snake_case()))?;
+    } else if param.env_var {
+        write_env_var(&mut output, config, param.name.as_snake_case())?;
+    }
+    match param.optionality {
+        Optionality::Mandatory => writeln!(output, "{} = {}", param.key(), param_value(param))?,
+        _ => writeln!(output, "#{} = {}", param.key(), param_value(param))?,
+    }
+    writeln!(output)
+}
+
+fn write_switch<W: Write>(mut output: W, config: &Config, switch: &Switch) -> fmt::Result {
+    write_doc(&mut output, &switch.doc)?;
+    let (ty, default) = if switch.is_count() {
+        ("number of occurrences", "0")
+    } else if switch.is_inverted() {
+        ("bool", "true")
+    } else {
+        ("bool", "false")
+    };
+    writeln!(output, "# Type: {}", ty)?;
+    writeln!(output, "# Default: {}", default)?;
+    if switch.env_var {
+        write_env_var(&mut output, config, switch.name.as_snake_case())?;
+    }
+    writeln!(output, "#{} = {}", switch.name.as_snake_case(), default)?;
+    writeln!(output)
+}
+
+/// Generates a config file containing every param and switch with its doc, type, default value
+/// and environment variable.
+///
+/// Only mandatory params are set, all other values are commented out. Params without a default
+/// value that is valid TOML get a sample value of their type.
+pub fn generate_example<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    if let Some(name) = config.program_name() {
+        writeln!(output, "# Example configuration of {}", name)?;
+        writeln!(output)?;
+    }
+    if let Some(table) = &config.general.config_table {
+        writeln!(output, "[{}]", table)?;
+        writeln!(output)?;
+    }
+    for param in config.section_params(None) {
+        write_param(&mut output, config, param)?;
+    }
+    for switch in &config.switches {
+        write_switch(&mut output, config, switch)?;
+    }
+    for section in &config.sections {
+        write_doc(&mut output, &section.doc)?;
+        match &config.general.config_table {
+            Some(table) => writeln!(output, "[{}.{}]", table, section.name.as_snake_case())?,
+            None => writeln!(output, "[{}]", section.name.as_snake_case())?,
+        }
+        writeln!(output)?;
+        for param in config.section_params(Some(&section.name)) {
+            write_param(&mut output, config, param)?;
+        }
+    }
+    Ok(())
+}
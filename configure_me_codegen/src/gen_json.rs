@@ -0,0 +1,190 @@
+//! Machine-readable description of all options (`params.json`)
+
+use ::config::{Config, Optionality};
+
+#[derive(Serialize)]
+struct Description<'a> {
+    name: Option<&'a str>,
+    summary: Option<&'a str>,
+    conf_file_param: Option<String>,
+    conf_dir_param: Option<String>,
+    profile_param: Option<String>,
+    params: Vec<Param<'a>>,
+    switches: Vec<Switch<'a>>,
+    positionals: Vec<Positional<'a>>,
+    subcommands: Vec<Subcommand<'a>>,
+    groups: Vec<Group<'a>>,
+}
+
+#[derive(Serialize)]
+struct Param<'a> {
+    name: &'a str,
+    section: Option<&'a str>,
+    key: &'a str,
+    #[serde(rename = "type")]
+    ty: &'a str,
+    convert_into: &'a str,
+    min_values: usize,
+    max_values: usize,
+    multiple: bool,
+    map: bool,
+    values: Option<&'a [String]>,
+    min: Option<::config::Bound>,
+    max: Option<::config::Bound>,
+    validate_regex: Option<&'a str>,
+    optional: bool,
+    default: Option<&'a str>,
+    doc: Option<&'a str>,
+    long: Option<String>,
+    short: Option<String>,
+    aliases: Vec<String>,
+    requires: Vec<&'a str>,
+    conflicts_with: Vec<&'a str>,
+    env_var: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Switch<'a> {
+    name: &'a str,
+    doc: Option<&'a str>,
+    long: String,
+    short: Option<String>,
+    count: bool,
+    max_count: Option<u32>,
+    inverted: bool,
+    aliases: Vec<String>,
+    requires: Vec<&'a str>,
+    conflicts_with: Vec<&'a str>,
+    env_var: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Positional<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    ty: &'a str,
+    optional: bool,
+    variadic: bool,
+    #[serde(skip_serializing_if = "::std::ops::Not::not")]
+    trailing: bool,
+    doc: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Group<'a> {
+    name: &'a str,
+    doc: Option<&'a str>,
+    members: Vec<&'a str>,
+    required: bool,
+    multiple: bool,
+}
+
+#[derive(Serialize)]
+struct Subcommand<'a> {
+    name: &'a str,
+    doc: Option<&'a str>,
+    options: Description<'a>,
+}
+
+fn describe(config: &Config) -> Description<'_> {
+    let params = config.params
+        .iter()
+        .map(|param| Param {
+            name: param.name.as_snake_case(),
+            section: param.section.as_ref().map(|section| section.as_snake_case()),
+            key: param.key(),
+            ty: &param.ty,
+            convert_into: &param.convert_into,
+            min_values: param.nargs.map_or(1, ::config::Nargs::min),
+            max_values: param.nargs.map_or(1, ::config::Nargs::max),
+            multiple: param.multiple.is_some(),
+            map: param.map,
+            values: param.values.as_deref(),
+            min: param.min,
+            max: param.max,
+            validate_regex: param.validate_regex.as_deref(),
+            optional: match param.optionality {
+                Optionality::Mandatory => false,
+                Optionality::Optional | Optionality::DefaultValue(_) => true,
+            },
+            default: match &param.optionality {
+                Optionality::DefaultValue(default) => Some(default),
+                Optionality::Mandatory | Optionality::Optional => None,
+            },
+            doc: param.doc.as_ref().map(AsRef::as_ref),
+            long: if param.argument { Some(::codegen::param_long(param)) } else { None },
+            short: if param.argument { ::codegen::param_short(param) } else { None },
+            aliases: if param.argument { ::codegen::param_aliases(param) } else { Vec::new() },
+            requires: param.requires.iter().map(|name| name.as_snake_case()).collect(),
+            conflicts_with: param.conflicts_with.iter().map(|name| name.as_snake_case()).collect(),
+            env_var: if param.env_var { Some(::codegen::env_var_name(config, param.name.as_snake_case())) } else { None },
+        })
+        .collect();
+
+    let switches = config.switches
+        .iter()
+        .map(|switch| Switch {
+            name: switch.name.as_snake_case(),
+            doc: switch.doc.as_ref().map(AsRef::as_ref),
+            long: ::codegen::switch_long(switch),
+            short: ::codegen::switch_short(switch),
+            count: switch.is_count(),
+            max_count: switch.max_count,
+            inverted: switch.is_inverted(),
+            aliases: ::codegen::switch_aliases(switch),
+            requires: switch.requires.iter().map(|name| name.as_snake_case()).collect(),
+            conflicts_with: switch.conflicts_with.iter().map(|name| name.as_snake_case()).collect(),
+            env_var: if switch.env_var { Some(::codegen::env_var_name(config, switch.name.as_snake_case())) } else { None },
+        })
+        .collect();
+
+    let positionals = config.positionals
+        .iter()
+        .map(|positional| Positional {
+            name: positional.name.as_snake_case(),
+            ty: &positional.ty,
+            optional: positional.optional,
+            variadic: positional.variadic,
+            trailing: positional.trailing,
+            doc: positional.doc.as_ref().map(AsRef::as_ref),
+        })
+        .collect();
+
+    let subcommands = config.subcommands
+        .iter()
+        .map(|subcommand| Subcommand {
+            name: subcommand.name.as_snake_case(),
+            doc: subcommand.doc.as_ref().map(AsRef::as_ref),
+            options: describe(&subcommand.config),
+        })
+        .collect();
+
+    let groups = config.groups
+        .iter()
+        .map(|group| Group {
+            name: group.name.as_snake_case(),
+            doc: group.doc.as_ref().map(AsRef::as_ref),
+            members: group.members.iter().map(|member| member.as_snake_case()).collect(),
+            required: group.required,
+            multiple: group.multiple,
+        })
+        .collect();
+
+    Description {
+        name: config.program_name(),
+        summary: config.general.summary.as_ref().map(AsRef::as_ref),
+        conf_file_param: config.general.conf_file_param.as_ref().map(|param| ::codegen::param_long_raw(param.as_snake_case())),
+        conf_dir_param: config.general.conf_dir_param.as_ref().map(|param| ::codegen::param_long_raw(param.as_snake_case())),
+        profile_param: config.general.profile_param.as_ref().map(|param| ::codegen::param_long_raw(param.as_snake_case())),
+        params,
+        switches,
+        positionals,
+        subcommands,
+        groups,
+    }
+}
+
+/// Generates JSON describing names, types, docs, defaults and env vars of all options.
+pub fn generate_json(config: &Config) -> String {
+    ::serde_json::to_string_pretty(&describe(config)).expect("serializing strings and bools never fails")
+}
@@ -4,7 +4,7 @@ use ::man::prelude::*;
 
 fn generate_meta(config: &Config, manifest: &Manifest) -> Result<Manual, manifest::Error> {
     let package = manifest.package.as_ref().ok_or(manifest::Error::MissingPackage)?;
-    let man = if let Some(name) = &config.general.name {
+    let man = if let Some(name) = config.program_name() {
         Manual::new(name)
     } else {
         Manual::new(&package.name)
@@ -58,28 +58,47 @@ fn generate_conf_dir_param(man: Manual, config: &Config) -> Manual {
     }
 }
 
+fn generate_profile_param(man: Manual, config: &Config) -> Manual {
+    if let Some(profile_param) = &config.general.profile_param {
+        let opt = Opt::new("PROFILE").long(&::codegen::param_long_raw(profile_param.as_snake_case()));
+        let opt = opt.help("Layers the profile.PROFILE tables of config files over their other values.");
+        man.option(opt)
+    } else {
+        man
+    }
+}
+
+fn generate_explain_switch(man: Manual, config: &Config) -> Manual {
+    if let Some(explain) = &config.general.explain_config_switch {
+        let flag = Flag::new().long(&::codegen::param_long_raw(explain.as_snake_case()));
+        man.flag(flag.help("Explains which source overrode each value."))
+    } else {
+        man
+    }
+}
+
 fn generate_params(man: Manual, config: &Config) -> Manual {
     config
         .params
         .iter()
         .filter(|param| param.argument).map(|param| {
-            let opt = Opt::new(&param.name.as_upper_case().to_string()).long(&::codegen::param_long(param));
+            let opt = Opt::new(&::codegen::param_value_names(param)).long(&::codegen::param_long(param));
             let opt = if let Some(short) = ::codegen::param_short(param) {
                 opt.short(&short)
             } else {
                 opt
             };
-            let opt = if let Some(doc) = &param.doc {
+            // Aliases are always documented under the canonical name, even if hidden in the help
+            let opt = if let Some(doc) = ::codegen::doc_with_aliases(::codegen::param_doc(param), &::codegen::param_aliases(param)) {
                 opt.help(&doc)
             } else {
                 opt
             };
-            let opt = if let ::config::Optionality::DefaultValue(default) = &param.optionality {
-                opt.default_value(&default)
+            if let ::config::Optionality::DefaultValue(default) = &param.optionality {
+                opt.default_value(default)
             } else {
                 opt
-            };
-            opt
+            }
         })
         .fold(man, |man, opt| man.option(opt))
 }
@@ -96,73 +115,168 @@ fn generate_switches(man: Manual, config: &Config) -> Manual {
             } else {
                 flag
             };
-            let flag = if let Some(doc) = &switch.doc {
+            let doc = ::codegen::doc_with_negation(switch.doc.as_deref().map(::std::borrow::Cow::Borrowed), switch);
+            if let Some(doc) = ::codegen::doc_with_aliases(doc, &::codegen::switch_aliases(switch)) {
                 flag.help(&doc)
             } else {
                 flag
-            };
-            flag
+            }
         })
         .fold(man, |man, flag| man.flag(flag))
 }
 
+fn generate_positionals(man: Manual, config: &Config) -> Manual {
+    config
+        .positionals
+        .iter()
+        .map(|positional| Arg::new(&::codegen::positional_usage(positional)))
+        .fold(man, |man, arg| man.arg(arg))
+}
+
+fn generate_subcommand_arg(man: Manual, config: &Config) -> Manual {
+    if config.subcommands.is_empty() {
+        man
+    } else {
+        man.arg(Arg::new("[SUBCOMMAND]"))
+    }
+}
+
+fn escape(text: &str) -> String {
+    let text = text.trim().replace('\\', "\\\\").replace('-', "\\-");
+    text.lines().map(|line| if line.starts_with(['.', '\'']) { format!("\\&{}", line) } else { line.to_owned() }).collect::<Vec<_>>().join("\n")
+}
+
+fn write_item(output: &mut String, item: &str, doc: Option<&str>) {
+    output.push_str(".TP\n");
+    output.push_str(item);
+    output.push('\n');
+    if let Some(doc) = doc {
+        output.push_str(&escape(doc));
+        output.push('\n');
+    }
+}
+
+/// Renders a section for each subcommand (and its own subcommands) listing its options.
+///
+/// The man crate doesn't support custom sections, so they are written as roff directly.
+fn write_subcommand_sections(output: &mut String, config: &Config, prefix: &str) {
+    for subcommand in &config.subcommands {
+        let name = format!("{}{}", prefix, subcommand.name.as_upper_case());
+        output.push_str(&format!(".SH SUBCOMMAND {}\n", name));
+        if let Some(doc) = &subcommand.doc {
+            output.push_str(&escape(doc));
+            output.push('\n');
+        }
+        let sub = &subcommand.config;
+        for param in sub.params.iter().filter(|param| param.argument) {
+            let default = match &param.optionality {
+                ::config::Optionality::DefaultValue(default) => format!(" [default: \\fI{}\\fR]", escape(default)),
+                _ => String::new(),
+            };
+            let item = format!("\\fB{}\\fR=\\fI{}\\fR{}", escape(&::codegen::param_long(param)), ::codegen::param_value_names(param), default);
+            write_item(output, &item, ::codegen::param_doc(param).as_deref());
+        }
+        for switch in &sub.switches {
+            let item = format!("\\fB{}\\fR", escape(&::codegen::switch_long(switch)));
+            write_item(output, &item, switch.doc.as_deref());
+        }
+        for positional in &sub.positionals {
+            let item = format!("\\fI{}\\fR", ::codegen::positional_usage(positional));
+            write_item(output, &item, ::codegen::positional_doc(positional).as_deref());
+        }
+        write_subcommand_sections(output, sub, &format!("{} ", name));
+    }
+}
+
+fn generate_subcommand_sections(mut page: String, config: &Config) -> String {
+    let mut sections = String::new();
+    write_subcommand_sections(&mut sections, config, "");
+    let pos = page.find(".SH EXIT STATUS").unwrap_or(page.len());
+    page.insert_str(pos, &sections);
+    page
+}
+
+/// Adds the name and version of the program to the header, which is the first line of the page
+fn generate_version(mut page: String, config: &Config, manifest: &Manifest) -> String {
+    let package = manifest.package.as_ref();
+    let version = match &config.general.version {
+        Some(::config::Version::Package(true)) => package.map(|package| package.version.as_str()),
+        Some(::config::Version::Fixed(version)) => Some(version.as_str()),
+        _ => None,
+    };
+    let name = config.program_name().or(package.map(|package| package.name.as_str()));
+    if let (Some(name), Some(version)) = (name, version) {
+        let pos = page.find('\n').unwrap_or(page.len());
+        page.insert_str(pos, &format!(" \"\" \"{} {}\"", name, version));
+    }
+    page
+}
+
 fn generate_param_env_vars(man: Manual, config: &Config) -> Manual {
-    let prefix = config.general.env_prefix.as_ref().map_or_else(String::new, |prefix| [&prefix, "_"].join(""));
+    let prefix = config.general.env_prefix.as_ref().map_or_else(String::new, |prefix| [prefix, "_"].join(""));
     config
         .params
         .iter()
         .filter(|param| param.env_var).map(|param| {
-            let env = Env::new(&[&prefix as &str, &param.name.as_upper_case().to_string()].join(""));
-            let env = if let Some(doc) = &param.doc {
+            let suffix = if param.map { "_<KEY>" } else { "" };
+            let env = Env::new(&[&prefix as &str, &param.name.as_upper_case().to_string(), suffix].join(""));
+            let env = if let Some(doc) = ::codegen::param_doc(param) {
                 env.help(&doc)
             } else {
                 env
             };
-            let env = if let ::config::Optionality::DefaultValue(default) = &param.optionality {
-                env.default_value(&default)
+            if let ::config::Optionality::DefaultValue(default) = &param.optionality {
+                env.default_value(default)
             } else {
                 env
-            };
-            env
+            }
         })
         .fold(man, |man, env| man.env(env))
 }
 
 fn generate_switch_env_vars(man: Manual, config: &Config) -> Manual {
-    let prefix = config.general.env_prefix.as_ref().map_or_else(String::new, |prefix| [&prefix, "_"].join(""));
+    let prefix = config.general.env_prefix.as_ref().map_or_else(String::new, |prefix| [prefix, "_"].join(""));
     config
         .switches
         .iter()
         .filter(|switch| switch.env_var).map(|switch| {
             let env = Env::new(&[&prefix as &str, &switch.name.as_upper_case().to_string()].join(""));
             let env = if let Some(doc) = &switch.doc {
-                env.help(&doc)
+                env.help(doc)
             } else {
                 env
             };
-            let env = if switch.is_inverted() {
+            if switch.is_inverted() {
                 env.default_value("true")
             } else {
                 env.default_value("false")
-            };
-            env
+            }
         })
         .fold(man, |man, env| man.env(env))
 }
 
 pub fn generate_man_page(config: &Config, manifest: &Manifest) -> Result<String, manifest::Error> {
     let man = generate_meta(config, manifest)?;
-    let man = if let Some(doc) = &config.general.doc {
+    // The package description is already used as the summary if the spec doesn't have one
+    let package_description = manifest.package.as_ref()
+        .and_then(|package| package.description.as_ref())
+        .filter(|_| config.general.summary.is_some());
+    let man = if let Some(doc) = config.general.doc.as_ref().or(package_description) {
         man.description(doc.to_owned())
     } else {
         man
     };
     let man = generate_conf_file_param(man, config);
     let man = generate_conf_dir_param(man, config);
+    let man = generate_profile_param(man, config);
+    let man = generate_explain_switch(man, config);
     let man = generate_params(man, config);
     let man = generate_switches(man, config);
+    let man = generate_positionals(man, config);
+    let man = generate_subcommand_arg(man, config);
     let man = generate_param_env_vars(man, config);
     let man = generate_switch_env_vars(man, config);
 
-    Ok(man.render())
+    let page = generate_version(man.render(), config, manifest);
+    Ok(generate_subcommand_sections(page, config))
 }
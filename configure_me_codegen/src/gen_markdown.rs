@@ -0,0 +1,185 @@
+//! Reference of all options in markdown (`options.md`) suitable for including in rustdoc
+
+use ::config::{Config, Ident, Optionality};
+use std::fmt::{self, Write};
+
+fn write_env_var<W: Write>(mut output: W, config: &Config, name: &str) -> fmt::Result {
+    match &config.general.env_prefix {
+        Some(prefix) => writeln!(output, "* Environment variable: `{}`", format!("{}_{}", prefix, name).to_ascii_uppercase()),
+        None => writeln!(output, "* Environment variable: `{}`", name.to_ascii_uppercase()),
+    }
+}
+
+fn write_doc<W: Write>(mut output: W, doc: &Option<String>) -> fmt::Result {
+    if let Some(doc) = doc {
+        writeln!(output, "{}", doc.trim())?;
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+fn write_aliases<W: Write>(mut output: W, aliases: &[String]) -> fmt::Result {
+    if !aliases.is_empty() {
+        writeln!(output, "* Aliases: {}", aliases.iter().map(|alias| format!("`{}`", alias)).collect::<Vec<_>>().join(", "))?;
+    }
+    Ok(())
+}
+
+fn write_relations<W: Write>(mut output: W, requires: &[Ident], conflicts_with: &[Ident]) -> fmt::Result {
+    let names = |names: &[Ident]| names.iter().map(|name| format!("`{}`", name.as_snake_case())).collect::<Vec<_>>().join(", ");
+    if !requires.is_empty() {
+        writeln!(output, "* Requires: {}", names(requires))?;
+    }
+    if !conflicts_with.is_empty() {
+        writeln!(output, "* Conflicts with: {}", names(conflicts_with))?;
+    }
+    Ok(())
+}
+
+fn write_conf_param<W: Write>(mut output: W, heading: &str, param: Option<&str>, value: &str, doc: &str) -> fmt::Result {
+    if let Some(param) = param {
+        writeln!(output, "{} `{} {}`", heading, ::codegen::param_long_raw(param), value)?;
+        writeln!(output)?;
+        writeln!(output, "{}", doc)?;
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+/// Generates markdown describing all options, for use with `#[doc = include_str!(...)]`.
+///
+/// The options are level 3 headings so that the file can be included under a custom level 2
+/// heading. Options of subcommands are one level below the heading of their subcommand.
+pub fn generate_markdown<W: Write>(config: &Config, mut output: W) -> fmt::Result {
+    write_options(config, &mut output, "###")
+}
+
+fn write_options(config: &Config, mut output: &mut dyn Write, heading: &str) -> fmt::Result {
+    write_conf_param(&mut output, heading, config.general.conf_file_param.as_ref().map(|param| param.as_snake_case()), "CONFIG_FILE", "Loads configuration from the specified CONFIG_FILE.")?;
+    write_conf_param(&mut output, heading, config.general.conf_dir_param.as_ref().map(|param| param.as_snake_case()), "CONFIG_DIR", "Loads configuration from all files in the directory CONFIG_DIR.")?;
+    write_conf_param(&mut output, heading, config.general.profile_param.as_ref().map(|param| param.as_snake_case()), "PROFILE", "Layers the profile.PROFILE tables of config files over their other values.")?;
+
+    for param in &config.params {
+        let value = ::codegen::param_value_names(param);
+        match (param.argument, ::codegen::param_short(param)) {
+            (true, Some(short)) => writeln!(output, "{} `{} {}`, `{} {}`", heading, ::codegen::param_long(param), value, short, value)?,
+            (true, None) => writeln!(output, "{} `{} {}`", heading, ::codegen::param_long(param), value)?,
+            (false, _) => writeln!(output, "{} `{}`", heading, param.field_path())?,
+        }
+        writeln!(output)?;
+        write_doc(&mut output, &param.doc)?;
+        writeln!(output, "* Type: `{}`", param.field_type())?;
+        match &param.optionality {
+            Optionality::Mandatory => writeln!(output, "* Mandatory")?,
+            Optionality::Optional => writeln!(output, "* Optional")?,
+            Optionality::DefaultValue(default) => writeln!(output, "* Default: `{}`", default)?,
+        }
+        if param.argument {
+            write_aliases(&mut output, &::codegen::param_aliases(param))?;
+        }
+        if let Some(range) = ::codegen::param_range(param) {
+            writeln!(output, "* Range: {}", range)?;
+        }
+        if let Some(pattern) = &param.validate_regex {
+            writeln!(output, "* Must match: `{}`", pattern)?;
+        }
+        if let Some(values) = &param.values {
+            writeln!(output, "* Possible values: {}", values.iter().map(|value| format!("`{}`", value)).collect::<Vec<_>>().join(", "))?;
+        }
+        if param.is_duration() {
+            writeln!(output, "* Format: a duration such as `30s`, `5m` or `1h30m`")?;
+        }
+        if param.size {
+            writeln!(output, "* Format: a size in bytes such as `4096`, `64KiB` or `2GB`")?;
+        }
+        write_relations(&mut output, &param.requires, &param.conflicts_with)?;
+        if param.multiple.is_some() {
+            writeln!(output, "* Can be repeated")?;
+        }
+        if param.map {
+            writeln!(output, "* Can be repeated, the entries are merged by their keys")?;
+        }
+        if let Some(section) = &param.section {
+            writeln!(output, "* Config file: `{}` in `[{}]`", param.key(), section.as_snake_case())?;
+        }
+        if param.env_var && param.map {
+            write_env_var(&mut output, config, &format!("{}_<key>", param.name.as_snake_case()))?;
+        } else if param.env_var {
+            write_env_var(&mut output, config, param.name.as_snake_case())?;
+        }
+        writeln!(output)?;
+    }
+
+    for switch in &config.switches {
+        match ::codegen::switch_short(switch) {
+            Some(short) => writeln!(output, "{} `{}`, `{}`", heading, ::codegen::switch_long(switch), short)?,
+            None => writeln!(output, "{} `{}`", heading, ::codegen::switch_long(switch))?,
+        }
+        writeln!(output)?;
+        write_doc(&mut output, &switch.doc)?;
+        write_aliases(&mut output, &::codegen::switch_aliases(switch))?;
+        if switch.negatable {
+            writeln!(output, "* Turned off by `--no-{}`", switch.name.as_hypenated())?;
+        }
+        write_relations(&mut output, &switch.requires, &switch.conflicts_with)?;
+        if switch.is_count() {
+            writeln!(output, "* Can be repeated")?;
+        }
+        if let Some(max_count) = switch.max_count {
+            writeln!(output, "* At most {} times", max_count)?;
+        }
+        if let Some(values) = &switch.values {
+            writeln!(output, "* Values by count: {}", values.iter().map(|value| format!("`{}`", value)).collect::<Vec<_>>().join(", "))?;
+        }
+        if switch.env_var {
+            write_env_var(&mut output, config, switch.name.as_snake_case())?;
+        }
+        writeln!(output)?;
+    }
+
+    for positional in &config.positionals {
+        writeln!(output, "{} `{}`", heading, ::codegen::positional_usage(positional))?;
+        writeln!(output)?;
+        write_doc(&mut output, &positional.doc)?;
+        writeln!(output, "* Type: `{}`", positional.field_type())?;
+        if positional.optional {
+            writeln!(output, "* Optional positional argument")?;
+        } else {
+            writeln!(output, "* Mandatory positional argument")?;
+        }
+        if positional.trailing {
+            writeln!(output, "* Takes all arguments after `--`")?;
+        } else if positional.variadic {
+            writeln!(output, "* Takes all remaining arguments")?;
+        }
+        if positional.is_duration() {
+            writeln!(output, "* Format: a duration such as `30s`, `5m` or `1h30m`")?;
+        }
+        if positional.size {
+            writeln!(output, "* Format: a size in bytes such as `4096`, `64KiB` or `2GB`")?;
+        }
+        writeln!(output)?;
+    }
+
+    for group in &config.groups {
+        writeln!(output, "{} Group `{}`", heading, group.name.as_snake_case())?;
+        writeln!(output)?;
+        write_doc(&mut output, &group.doc)?;
+        writeln!(output, "* Members: {}", group.members.iter().map(|member| format!("`{}`", member.as_snake_case())).collect::<Vec<_>>().join(", "))?;
+        match (group.required, group.multiple) {
+            (true, true) => writeln!(output, "* At least one must be set")?,
+            (true, false) => writeln!(output, "* Exactly one must be set")?,
+            (false, _) => writeln!(output, "* At most one can be set")?,
+        }
+        writeln!(output)?;
+    }
+
+    for subcommand in &config.subcommands {
+        writeln!(output, "{} Subcommand `{}`", heading, subcommand.name.as_snake_case())?;
+        writeln!(output)?;
+        write_doc(&mut output, &subcommand.doc)?;
+        write_options(&subcommand.config, output, &format!("{}#", heading))?;
+    }
+
+    Ok(())
+}
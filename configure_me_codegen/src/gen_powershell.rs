@@ -0,0 +1,143 @@
+//! PowerShell argument completer (`completion.ps1`) registered for the program
+
+use ::config::Config;
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+/// Option or subcommand offered by the completer
+struct Completion<'a> {
+    text: String,
+    kind: &'static str,
+    tooltip: Option<Cow<'a, str>>,
+}
+
+impl<'a> Completion<'a> {
+    fn option(text: String, tooltip: Option<Cow<'a, str>>) -> Self {
+        Completion { text, kind: "ParameterName", tooltip }
+    }
+}
+
+/// Single-quoted PowerShell string, the tooltip is a single line
+fn quote(string: &str) -> String {
+    format!("'{}'", string.split_whitespace().collect::<Vec<_>>().join(" ").replace('\'', "''"))
+}
+
+fn completions(config: &Config) -> Vec<Completion<'_>> {
+    let mut completions = Vec::new();
+    let general = &config.general;
+    let conf_params = [
+        (&general.conf_file_param, "Loads configuration from the specified file."),
+        (&general.conf_dir_param, "Loads configuration from all files in the directory."),
+        (&general.profile_param, "Layers the profile of config files over their other values."),
+        (&general.explain_config_switch, "Explains which source overrode each value."),
+        (&general.check_config_switch, "Checks that the configuration is valid and exits."),
+        (&general.dump_config_switch, "Prints the configuration as a config file and exits."),
+    ];
+    for (param, doc) in conf_params.iter() {
+        if let Some(param) = param {
+            completions.push(Completion::option(::codegen::param_long_raw(param.as_snake_case()), Some((*doc).into())));
+        }
+    }
+    for param in config.params.iter().filter(|param| param.argument) {
+        let options = ::std::iter::once(::codegen::param_long(param))
+            .chain(::codegen::param_short(param))
+            .chain(::codegen::param_aliases(param));
+        completions.extend(options.map(|option| Completion::option(option, ::codegen::param_doc(param))));
+    }
+    for switch in &config.switches {
+        let doc = switch.doc.as_deref().map(Cow::Borrowed);
+        let options = ::std::iter::once(::codegen::switch_long(switch))
+            .chain(::codegen::switch_short(switch))
+            .chain(::codegen::switch_aliases(switch));
+        completions.extend(options.map(|option| Completion::option(option, doc.clone())));
+        for negation in ::codegen::switch_negations(switch) {
+            completions.push(Completion::option(negation, ::codegen::doc_with_negation(None, switch)));
+        }
+    }
+    completions.push(Completion::option("--help".to_owned(), Some("Prints the help and exits.".into())));
+    completions.push(Completion::option("-h".to_owned(), Some("Prints the help and exits.".into())));
+    if general.prints_version() {
+        completions.push(Completion::option("--version".to_owned(), Some("Prints the version and exits.".into())));
+        completions.push(Completion::option("-V".to_owned(), Some("Prints the version and exits.".into())));
+    }
+    for subcommand in &config.subcommands {
+        completions.push(Completion {
+            text: subcommand.name.as_snake_case().to_owned(),
+            kind: "ParameterValue",
+            tooltip: subcommand.doc.as_deref().map(Cow::Borrowed),
+        });
+    }
+    completions
+}
+
+/// Sets `$options` to the completions of `config` and `$values` to the possible values of the
+/// param given as the previous word
+fn write_completions<W: Write>(mut output: W, config: &Config, indent: &str) -> fmt::Result {
+    // There are always at least two options (`--help` and `-h`), so that the array isn't
+    // flattened
+    writeln!(output, "{}$options = @(", indent)?;
+    let completions = completions(config);
+    for (i, completion) in completions.iter().enumerate() {
+        let tooltip = completion.tooltip.as_deref().unwrap_or(&completion.text);
+        let separator = if i + 1 < completions.len() { "," } else { "" };
+        writeln!(output, "{}    @({}, '{}', {}){}", indent, quote(&completion.text), completion.kind, quote(tooltip), separator)?;
+    }
+    writeln!(output, "{})", indent)?;
+    for param in config.params.iter().filter(|param| param.argument) {
+        if let Some(values) = &param.values {
+            let options = ::std::iter::once(::codegen::param_long(param))
+                .chain(::codegen::param_short(param))
+                .chain(::codegen::param_aliases(param))
+                .map(|option| format!("$previous -ceq {}", quote(&option)))
+                .collect::<Vec<_>>();
+            let values = values.iter().map(|value| quote(value)).collect::<Vec<_>>();
+            writeln!(output, "{}if ({}) {{", indent, options.join(" -or "))?;
+            writeln!(output, "{}    $values = @({})", indent, values.join(", "))?;
+            writeln!(output, "{}}}", indent)?;
+        }
+    }
+    Ok(())
+}
+
+/// Generates a script registering a native argument completer for the program `name`.
+///
+/// It completes the options, switches and subcommands as well as the possible values of params
+/// with `values`. The options of a subcommand are completed once it's given.
+pub fn generate_powershell<W: Write>(config: &Config, name: &str, mut output: W) -> fmt::Result {
+    writeln!(output, "# PowerShell completion of {}", name)?;
+    writeln!(output, "# Load it in your profile: . path\\to\\completion.ps1")?;
+    writeln!(output)?;
+    writeln!(output, "Register-ArgumentCompleter -Native -CommandName {} -ScriptBlock {{", quote(name))?;
+    writeln!(output, "    param($wordToComplete, $commandAst, $cursorPosition)")?;
+    writeln!(output)?;
+    writeln!(output, "    $words = @($commandAst.CommandElements | Where-Object {{ $_.Extent.EndOffset -lt $cursorPosition }} | ForEach-Object {{ \"$_\" }})")?;
+    writeln!(output, "    $previous = $words[-1]")?;
+    writeln!(output, "    $values = @()")?;
+    if config.subcommands.is_empty() {
+        write_completions(&mut output, config, "    ")?;
+    } else {
+        let names = config.subcommands.iter().map(|subcommand| quote(subcommand.name.as_snake_case())).collect::<Vec<_>>();
+        writeln!(output, "    $subcommand = $words | Select-Object -Skip 1 | Where-Object {{ $_ -cin @({}) }} | Select-Object -First 1", names.join(", "))?;
+        writeln!(output, "    switch -CaseSensitive (\"$subcommand\") {{")?;
+        for subcommand in &config.subcommands {
+            writeln!(output, "        {} {{", quote(subcommand.name.as_snake_case()))?;
+            write_completions(&mut output, &subcommand.config, "            ")?;
+            writeln!(output, "        }}")?;
+        }
+        writeln!(output, "        default {{")?;
+        write_completions(&mut output, config, "            ")?;
+        writeln!(output, "        }}")?;
+        writeln!(output, "    }}")?;
+    }
+    writeln!(output)?;
+    writeln!(output, "    if ($values) {{")?;
+    writeln!(output, "        $values | Where-Object {{ $_ -clike \"$wordToComplete*\" }} | ForEach-Object {{")?;
+    writeln!(output, "            [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)")?;
+    writeln!(output, "        }}")?;
+    writeln!(output, "        return")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "    $options | Where-Object {{ $_[0] -clike \"$wordToComplete*\" }} | ForEach-Object {{")?;
+    writeln!(output, "        [System.Management.Automation.CompletionResult]::new($_[0], $_[0], $_[1], $_[2])")?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")
+}
@@ -0,0 +1,114 @@
+//! Scaffolding of `configure_me` in an existing crate
+//!
+//! This creates a starter `config_spec.toml`, a `build.rs` and the metadata block in `Cargo.toml`
+//! so that the crate is ready to use `include_config!()`.
+
+use std::fmt::{self, Write as FmtWrite};
+use std::io::Write;
+use std::path::Path;
+use {Error, ErrorData};
+
+/// Name of the specification file created by `init()`
+pub const SPEC_FILE_NAME: &str = "config_spec.toml";
+
+const BUILD_SCRIPT: &str = "extern crate configure_me_codegen;
+
+fn main() -> Result<(), configure_me_codegen::Error> {
+    configure_me_codegen::build_script_auto()
+}
+";
+
+/// Parameter added to the starter specification
+pub struct StarterParam {
+    /// Name of the parameter
+    pub name: String,
+    /// Rust type of the parameter
+    pub ty: String,
+    /// Whether the parameter is optional
+    pub optional: bool,
+    /// Documentation of the parameter
+    pub doc: Option<String>,
+}
+
+fn quote(string: &str) -> String {
+    toml::Value::String(string.to_owned()).to_string()
+}
+
+/// Generates the starter specification containing given params.
+///
+/// The specification is validated before it's returned, so invalid names or types fail early.
+pub fn starter_spec(params: &[StarterParam]) -> Result<String, Error> {
+    let mut spec = String::new();
+    // Writing to String never fails
+    write_starter_spec(&mut spec, params).unwrap();
+    ::validate_spec(spec.as_bytes())?;
+    Ok(spec)
+}
+
+fn write_starter_spec<W: FmtWrite>(mut spec: W, params: &[StarterParam]) -> fmt::Result {
+    for param in params {
+        writeln!(spec, "[[param]]")?;
+        writeln!(spec, "name = {}", quote(&param.name))?;
+        writeln!(spec, "type = {}", quote(&param.ty))?;
+        writeln!(spec, "optional = {}", param.optional)?;
+        if let Some(doc) = &param.doc {
+            writeln!(spec, "doc = {}", quote(doc))?;
+        }
+        writeln!(spec)?;
+    }
+    Ok(())
+}
+
+fn create_new(path: &Path, contents: &str) -> Result<(), Error> {
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|error| if error.kind() == std::io::ErrorKind::AlreadyExists {
+            ErrorData::AlreadyExists(path.to_owned())
+        } else {
+            ErrorData::Open { file: path.to_owned(), error }
+        })?;
+    Ok(())
+}
+
+/// Sets up `configure_me` in the crate located in `crate_dir`.
+///
+/// Creates `config_spec.toml` with given params, `build.rs` calling `build_script_auto()` and adds
+/// `[package.metadata.configure_me]` to `Cargo.toml`. Fails without modifying anything if the
+/// specification or the build script already exist or the metadata is already present. The
+/// dependencies still need to be added manually.
+pub fn init<P: AsRef<Path>>(crate_dir: P, params: &[StarterParam]) -> Result<(), Error> {
+    let crate_dir = crate_dir.as_ref();
+    let manifest_file = crate_dir.join("Cargo.toml");
+    let spec_file = crate_dir.join(SPEC_FILE_NAME);
+    let build_file = crate_dir.join("build.rs");
+
+    let manifest = std::fs::read_to_string(&manifest_file).map_err(|error| ErrorData::Open { file: manifest_file.clone(), error })?;
+    if manifest.contains("metadata.configure_me") {
+        return Err(ErrorData::AlreadyExists(manifest_file).into());
+    }
+    for file in &[&spec_file, &build_file] {
+        if file.exists() {
+            return Err(ErrorData::AlreadyExists(file.to_path_buf()).into());
+        }
+    }
+
+    let spec = starter_spec(params)?;
+    create_new(&spec_file, &spec)?;
+    create_new(&build_file, BUILD_SCRIPT)?;
+
+    let mut metadata = String::new();
+    if !manifest.ends_with('\n') {
+        metadata.push('\n');
+    }
+    metadata.push_str(&format!("\n[package.metadata.configure_me]\nspec = {}\n", quote(SPEC_FILE_NAME)));
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(&manifest_file)
+        .and_then(|mut file| file.write_all(metadata.as_bytes()))
+        .map_err(|error| ErrorData::Open { file: manifest_file, error })?;
+
+    Ok(())
+}
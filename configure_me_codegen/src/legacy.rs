@@ -0,0 +1,66 @@
+//! Support for spec fields that were renamed.
+//!
+//! Unknown fields of the spec are rejected by default, so renaming a field would break every
+//! existing spec at once. Instead, the old name is accepted for one release and its use is
+//! recorded, which the build script reports as a cargo warning.
+
+use std::fmt;
+use config::ValidationError;
+
+/// Field which was renamed
+#[derive(Debug, Copy, Clone)]
+pub struct Renamed {
+    pub section: &'static str,
+    pub old: &'static str,
+    pub new: &'static str,
+}
+
+/// Fields renamed since the previous release, their old names are removed in the next one.
+pub const RENAMED: &[Renamed] = &[];
+
+/// Rewrites the old names of `renamed` fields in `spec` to the new ones, recording their use.
+///
+/// Returns `None` if no old name is used, so that the locations in errors keep pointing into the
+/// original text. `section` may be a table or an array of tables, such as `param`.
+pub fn migrate(spec: &str, renamed: &[Renamed], deprecations: &mut Vec<Renamed>) -> Result<Option<String>, ValidationError> {
+    if renamed.is_empty() {
+        return Ok(None);
+    }
+    // Syntax errors are reported by the parser of the spec
+    let mut value = match spec.parse::<::toml::Value>() {
+        Ok(value) => value,
+        Err(_) => return Ok(None),
+    };
+
+    let mut migrated = false;
+    for field in renamed {
+        let tables = match value.get_mut(field.section) {
+            Some(::toml::Value::Table(table)) => vec![table],
+            Some(::toml::Value::Array(items)) => items.iter_mut().filter_map(::toml::Value::as_table_mut).collect(),
+            _ => continue,
+        };
+        let mut used = false;
+        for table in tables {
+            if let Some(old) = table.remove(field.old) {
+                if table.contains_key(field.new) {
+                    return Err(ValidationError::RenamedConflict(*field));
+                }
+                table.insert(field.new.to_owned(), old);
+                used = true;
+            }
+        }
+        if used {
+            deprecations.push(*field);
+            migrated = true;
+        }
+    }
+
+    // Failing to serialize leaves the old names in place, which are then reported as unknown
+    Ok(if migrated { ::toml::to_string(&value).ok() } else { None })
+}
+
+impl fmt::Display for Renamed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}.{}` is deprecated and will be removed in the next release, use `{}.{}` instead", self.section, self.old, self.section, self.new)
+    }
+}
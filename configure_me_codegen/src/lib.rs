@@ -5,6 +5,8 @@ extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate toml;
+extern crate serde_json;
+extern crate serde_ignored;
 /*
 #[cfg(test)]
 #[macro_use]
@@ -13,33 +15,82 @@ extern crate pretty_assertions;
 extern crate unicode_segmentation;
 extern crate fmt2io;
 extern crate cargo_toml;
+extern crate syn;
+extern crate regex;
+extern crate fnv;
 #[cfg(feature = "man")]
 extern crate man;
 
 pub(crate) mod config;
 pub(crate) mod codegen;
+pub(crate) mod legacy;
+pub(crate) mod gen_json;
+pub(crate) mod gen_markdown;
+pub(crate) mod gen_example;
+pub(crate) mod gen_powershell;
 #[cfg(feature = "man")]
 pub (crate) mod gen_man;
 #[cfg(feature = "debconf")]
 pub (crate) mod debconf;
 
 pub mod manifest;
+pub mod init;
+pub mod clap_import;
+pub mod derive_import;
 
-use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::borrow::{Borrow, Cow};
 use std::fmt;
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use manifest::LoadManifest;
 
+/// Position in the specification
+#[derive(Debug)]
+struct Location {
+    file: Option<PathBuf>,
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    fn from_offset(source: &[u8], offset: usize) -> Self {
+        let before = &source[..offset];
+        let line_start = before.iter().rposition(|&byte| byte == b'\n').map_or(0, |pos| pos + 1);
+
+        Location {
+            file: None,
+            line: before.iter().filter(|&&byte| byte == b'\n').count() + 1,
+            column: String::from_utf8_lossy(&before[line_start..]).chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
 #[derive(Debug)]
 enum ErrorData {
-    Toml(toml::de::Error),
-    Config(config::ValidationError),
+    Toml { error: toml::de::Error, location: Option<Location> },
+    Config { error: config::ValidationError, location: Option<Location> },
     Io(io::Error),
     Open { file: PathBuf, error: io::Error },
     Manifest(manifest::Error),
     MissingManifestDirEnvVar,
     MissingOutDir,
+    AlreadyExists(PathBuf),
+    ExtendsTooDeep(PathBuf),
+    UnknownField(String),
+    UnknownBinary(String),
+    ClapJson(serde_json::Error),
+    RustSource(syn::Error),
+    MissingParserStruct,
     #[cfg(feature = "debconf")]
     Debconf(debconf::Error),
 }
@@ -52,13 +103,25 @@ pub struct Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.data {
-            ErrorData::Toml(err) => write!(f, "failed to parse config specification: {}", err),
+            ErrorData::Toml { location: Some(location), .. } | ErrorData::Config { location: Some(location), .. } => write!(f, "{}: ", location)?,
+            _ => (),
+        }
+
+        match &self.data {
+            ErrorData::Toml { error, .. } => write!(f, "failed to parse config specification: {}", error),
             ErrorData::Manifest(error) => write!(f, "failed to process manifest: {}", error),
-            ErrorData::Config(err) => fmt::Display::fmt(err, f),
+            ErrorData::Config { error, .. } => fmt::Display::fmt(error, f),
             ErrorData::Io(err) => write!(f, "I/O error: {}", err),
             ErrorData::Open { file, error } => write!(f, "failed to open file {}: {}", file.display(), error),
             ErrorData::MissingManifestDirEnvVar => write!(f, "missing environment variable: CARGO_MANIFEST_DIR"),
             ErrorData::MissingOutDir => write!(f, "missing environment variable: OUT_DIR"),
+            ErrorData::UnknownField(field) => write!(f, "unknown field `{}` in the config specification", field),
+            ErrorData::UnknownBinary(binary) => write!(f, "binary `{}` has no specification in `package.metadata.configure_me.bin`", binary),
+            ErrorData::ExtendsTooDeep(file) => write!(f, "too many nested `extends` or `include` when loading {}, is there a cycle?", file.display()),
+            ErrorData::AlreadyExists(file) => write!(f, "refusing to overwrite {}, configure_me seems to be already set up", file.display()),
+            ErrorData::ClapJson(error) => write!(f, "failed to parse the description of the clap command: {}", error),
+            ErrorData::RustSource(error) => write!(f, "failed to parse the Rust source: {}", error),
+            ErrorData::MissingParserStruct => write!(f, "no struct deriving Parser or StructOpt found"),
             #[cfg(feature = "debconf")]
             ErrorData::Debconf(err) => write!(f, "failed to generate debconf: {}", err),
         }
@@ -72,6 +135,19 @@ impl fmt::Debug for Error {
     }
 }
 
+impl Error {
+    /// Attaches the name of the specification file to the location of the error.
+    ///
+    /// Errors from extended specifications keep their own file name.
+    fn in_file(mut self, file: &Path) -> Self {
+        match &mut self.data {
+            ErrorData::Toml { location: Some(location), .. } | ErrorData::Config { location: Some(location), .. } if location.file.is_none() => location.file = Some(file.to_owned()),
+            _ => (),
+        }
+        self
+    }
+}
+
 impl From<ErrorData> for Error {
     fn from(data: ErrorData) -> Self {
         Error {
@@ -83,7 +159,7 @@ impl From<ErrorData> for Error {
 impl From<config::ValidationError> for Error {
     fn from(err: config::ValidationError) -> Self {
         Error {
-            data: ErrorData::Config(err),
+            data: ErrorData::Config { error: err, location: None },
         }
     }
 }
@@ -99,7 +175,7 @@ impl From<io::Error> for Error {
 impl From<toml::de::Error> for Error {
     fn from(err: toml::de::Error) -> Self {
         Error {
-            data: ErrorData::Toml(err),
+            data: ErrorData::Toml { error: err, location: None },
         }
     }
 }
@@ -136,19 +212,190 @@ impl From<debconf::Error> for Error {
     }
 }
 
+/// Maximum length of a chain of `extends` and `include`, protects against cycles
+const MAX_EXTENDS_DEPTH: usize = 16;
+
+/// How to treat fields of the specification unknown to this version of `configure_me_codegen`
+///
+/// Warning about them instead of failing allows sharing a specification between crates using
+/// different versions of the codegen during migrations.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnknownFields {
+    /// Fail with an error (the default)
+    #[default]
+    Deny,
+    /// Ignore them, reporting each as a warning
+    Warn,
+}
+
+/// Adds `bytes` to the hash of the sources of a specification
+///
+/// They're prefixed by their length, so that consecutive parts can't be mistaken for others. FNV
+/// and the explicit encoding keep the hash stable across Rust versions and platforms.
+fn hash_part(hasher: &mut ::fnv::FnvHasher, bytes: &[u8]) {
+    use std::hash::Hasher;
+
+    hasher.write(&(bytes.len() as u64).to_le_bytes());
+    hasher.write(bytes);
+}
+
+/// State shared when loading a specification together with all specifications it extends
+struct Sources {
+    unknown_fields: UnknownFields,
+    hasher: ::fnv::FnvHasher,
+    extended_files: Vec<PathBuf>,
+    ignored_fields: Vec<String>,
+    deprecations: Vec<legacy::Renamed>,
+}
+
+fn format_ignored_path(path: &serde_ignored::Path, out: &mut String) {
+    use serde_ignored::Path;
+
+    match path {
+        Path::Root => (),
+        Path::Seq { parent, index } => {
+            format_ignored_path(parent, out);
+            out.push_str(&format!("[{}]", index));
+        },
+        Path::Map { parent, key } => {
+            format_ignored_path(parent, out);
+            if !out.is_empty() {
+                out.push('.');
+            }
+            out.push_str(key);
+        },
+        Path::Some { parent } | Path::NewtypeStruct { parent } | Path::NewtypeVariant { parent } => format_ignored_path(parent, out),
+    }
+}
+
+fn parse(data: &[u8], sources: &mut Sources) -> Result<config::raw::Config, Error> {
+    let toml_error = |error: toml::de::Error| ErrorData::Toml { location: error.line_col().map(|(line, column)| Location { file: None, line: line + 1, column: column + 1 }), error };
+
+    let data = std::str::from_utf8(data).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    let mut ignored = Vec::new();
+    let cfg = serde_ignored::deserialize(&mut toml::de::Deserializer::new(data), |path| {
+        let mut field = String::new();
+        format_ignored_path(&path, &mut field);
+        ignored.push(field);
+    }).map_err(toml_error)?;
+
+    match sources.unknown_fields {
+        UnknownFields::Deny => match ignored.into_iter().next() {
+            Some(field) => Err(ErrorData::UnknownField(field).into()),
+            None => Ok(cfg),
+        },
+        UnknownFields::Warn => {
+            sources.ignored_fields.extend(ignored);
+            Ok(cfg)
+        },
+    }
+}
+
+/// Rewrites the old names of renamed fields in `data`, see `legacy`
+fn migrate<'a>(data: &'a [u8], sources: &mut Sources) -> Result<Cow<'a, [u8]>, Error> {
+    let spec = std::str::from_utf8(data).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    match legacy::migrate(spec, legacy::RENAMED, &mut sources.deprecations).map_err(|error| config_error(error, data))? {
+        Some(migrated) => Ok(Cow::Owned(migrated.into_bytes())),
+        None => Ok(Cow::Borrowed(data)),
+    }
+}
+
+fn config_error(error: config::ValidationError, data: &[u8]) -> Error {
+    ErrorData::Config { location: error.span().map(|(start, _)| Location::from_offset(data, start)), error }.into()
+}
+
+/// Loads specification `file` used by another one and resolves what it includes or extends.
+///
+/// It's also checked on its own, so that its errors point into the right file. The check reuses
+/// the resolved specification, each file is read and resolved only once.
+fn load_used(file: &Path, depth: usize, sources: &mut Sources) -> Result<config::raw::Config, Error> {
+    if depth >= MAX_EXTENDS_DEPTH {
+        return Err(ErrorData::ExtendsTooDeep(file.to_owned()).into());
+    }
+
+    let data = std::fs::read(file).map_err(|error| ErrorData::Open { file: file.to_owned(), error })?;
+    hash_part(&mut sources.hasher, &data);
+    let data = migrate(&data, sources).map_err(|error| error.in_file(file))?;
+    let cfg = parse(&data, sources).map_err(|error| error.in_file(file))?;
+    let dir = file.parent().unwrap_or_else(|| Path::new(""));
+    let cfg = resolve(cfg, &data, dir, depth, sources).map_err(|error| error.in_file(file))?;
+
+    let mut standalone = cfg.clone();
+    standalone.derive_env_prefix(None);
+    standalone.validate().map_err(|error| config_error(error, &data).in_file(file))?;
+    sources.extended_files.push(file.to_owned());
+    Ok(cfg)
+}
+
+/// Adds included fragments and the specifications of subcommands to `cfg` (parsed from `data`) and
+/// merges it into the specification it extends, recursively.
+///
+/// Relative paths are resolved against `dir`.
+fn resolve(mut cfg: config::raw::Config, data: &[u8], dir: &Path, depth: usize, sources: &mut Sources) -> Result<config::raw::Config, Error> {
+    cfg.flatten_sections().map_err(|error| config_error(error, data))?;
+    cfg.load_subcommands(|file| load_used(&dir.join(file.get_ref()), depth + 1, sources))?;
+    for include in cfg.take_includes() {
+        let fragment = load_used(&dir.join(include.file.get_ref()), depth + 1, sources)?;
+        cfg.include(fragment, include.file.span(), &include.prefix).map_err(|error| config_error(error, data))?;
+    }
+
+    match cfg.take_extends() {
+        Some(base_file) => {
+            let base = load_used(&dir.join(base_file), depth + 1, sources)?;
+            cfg.extend(base).map_err(|error| config_error(error, data))
+        },
+        None => Ok(cfg),
+    }
+}
+
+/// Loads the specification, `inferred_name` is the name of the program if it's known
+fn load_data(data: &[u8], file: Option<&Path>, depth: usize, unknown_fields: UnknownFields, inferred_name: Option<&str>) -> Result<config::Config, Error> {
+    let mut sources = Sources {
+        unknown_fields,
+        hasher: Default::default(),
+        extended_files: Vec::new(),
+        ignored_fields: Vec::new(),
+        deprecations: Vec::new(),
+    };
+    hash_part(&mut sources.hasher, data);
+    let data = &*migrate(data, &mut sources)?;
+    let dir = file.and_then(Path::parent).unwrap_or_else(|| Path::new(""));
+
+    let cfg = parse(data, &mut sources)?;
+    let mut cfg = resolve(cfg, data, dir, depth, &mut sources)?;
+    cfg.derive_env_prefix(inferred_name);
+    hash_part(&mut sources.hasher, inferred_name.map_or(&[0][..], |name| name.as_bytes()));
+    // Enabled features affect which params are generated
+    for feature in cfg.required_features() {
+        hash_part(&mut sources.hasher, feature.as_bytes());
+        hash_part(&mut sources.hasher, &[config::raw::is_feature_enabled(feature) as u8]);
+    }
+    let mut cfg = cfg
+        .validate()
+        .map_err(|error| config_error(error, data))?;
+    cfg.source_hash = sources.hasher;
+    cfg.extended_files = sources.extended_files;
+    cfg.ignored_fields = sources.ignored_fields;
+    cfg.deprecations = sources.deprecations;
+
+    Ok(cfg)
+}
+
 fn load<S: Read>(mut source: S) -> Result<config::Config, Error> {
     let mut data = Vec::new();
     source.read_to_end(&mut data)?;
-    let cfg = toml::from_slice::<config::raw::Config>(&data)?;
-    let cfg = cfg.validate()?;
+    load_data(&data, None, 0, UnknownFields::Deny, None)
+}
 
-    Ok(cfg)
+fn load_file(source: &Path, depth: usize, unknown_fields: UnknownFields, inferred_name: Option<&str>) -> Result<::config::Config, Error> {
+     let data = std::fs::read(source).map_err(|error| ErrorData::Open { file: source.into(), error })?;
+
+     load_data(&data, Some(source), depth, unknown_fields, inferred_name).map_err(|error| error.in_file(source))
 }
 
 fn load_from_file<P: AsRef<Path>>(source: P) -> Result<::config::Config, Error> {
-     let config_spec = std::fs::File::open(&source).map_err(|error| ErrorData::Open { file: source.as_ref().into(), error })?;
-
-     load(config_spec)
+    load_file(source.as_ref(), 0, UnknownFields::Deny, None)
 }
 
 fn path_in_out_dir<P: AsRef<Path>>(file_name: P) -> Result<PathBuf, Error> {
@@ -158,18 +405,40 @@ fn path_in_out_dir<P: AsRef<Path>>(file_name: P) -> Result<PathBuf, Error> {
     Ok(out)
 }
 
-fn default_out_file(binary: Option<&str>) -> Result<PathBuf, Error> {
-    const GENERATED_FILE_NAME: &str = "configure_me_config.rs";
+/// Prefixes the name of a generated file with the name of the binary, if any.
+fn generated_file_name(binary: Option<&str>, file_name: &'static str) -> std::borrow::Cow<'static, str> {
+    match binary {
+        Some(binary) => format!("{}_{}", binary, file_name).into(),
+        None => file_name.into(),
+    }
+}
 
-    let file_name_owned;
-    let file_name = match binary {
-        Some(binary) => {
-            file_name_owned = format!("{}_{}", binary, GENERATED_FILE_NAME);
-            &file_name_owned
-        },
-        None => GENERATED_FILE_NAME,
-    };
-    path_in_out_dir(file_name)
+fn out_file_name(binary: Option<&str>) -> std::borrow::Cow<'static, str> {
+    generated_file_name(binary, "configure_me_config.rs")
+}
+
+/// Name of the file describing all options, see `gen_json`
+fn params_file_name(binary: Option<&str>) -> std::borrow::Cow<'static, str> {
+    generated_file_name(binary, "params.json")
+}
+
+/// Name of the markdown reference of all options, see `gen_markdown`
+fn options_file_name(binary: Option<&str>) -> std::borrow::Cow<'static, str> {
+    generated_file_name(binary, "options.md")
+}
+
+/// Name of the sample config file, see `gen_example`
+fn example_file_name(binary: Option<&str>) -> std::borrow::Cow<'static, str> {
+    generated_file_name(binary, "example-config.toml")
+}
+
+/// Name of the PowerShell completion script, see `gen_powershell`
+fn powershell_file_name(binary: Option<&str>) -> std::borrow::Cow<'static, str> {
+    generated_file_name(binary, "completion.ps1")
+}
+
+fn default_out_file(binary: Option<&str>) -> Result<PathBuf, Error> {
+    path_in_out_dir(&*out_file_name(binary))
 }
 
 // Wrapper for error conversions
@@ -184,12 +453,93 @@ fn generate_to_file<P: AsRef<Path> + Into<PathBuf>>(config_spec: &::config::Conf
      ::fmt2io::write(config_code, |config_code| codegen::generate_code(config_spec, config_code)).map_err(Into::into)
 }
 
-fn load_and_generate_default<P: AsRef<Path>>(source: P, binary: Option<&str>) -> Result<::config::Config, Error> {
-    let config_spec = load_from_file(&source)?;
-    generate_to_file(&config_spec, default_out_file(binary)?)?;
+/// Generates the code only if the specification changed since the last time.
+///
+/// The hash of the specification is stored in a stamp file next to the generated file.
+fn generate_to_file_if_changed<P: AsRef<Path> + Into<PathBuf>>(config_spec: &::config::Config, file: P) -> Result<(), Error> {
+    use std::hash::Hasher;
+
+    let mut stamp_file = file.as_ref().as_os_str().to_owned();
+    stamp_file.push(".stamp");
+    let stamp_file = PathBuf::from(stamp_file);
+
+    let mut hasher = ::fnv::FnvHasher::with_key(config_spec.source_hash.finish());
+    // Different versions of codegen may generate different code from the same spec
+    hash_part(&mut hasher, env!("CARGO_PKG_VERSION").as_bytes());
+    let stamp = format!("{:016x}", hasher.finish());
+
+    if file.as_ref().exists() && std::fs::read_to_string(&stamp_file).ok().as_ref() == Some(&stamp) {
+        return Ok(());
+    }
+
+    generate_to_file(config_spec, file)?;
+    create_file(stamp_file)?.write_all(stamp.as_bytes())?;
+    Ok(())
+}
+
+/// Writes `content` to `file` unless the file already contains it.
+///
+/// The other generated files are small, so comparing them is cheaper than keeping a stamp for each
+/// of them. Their modification times only change along with their contents.
+fn write_if_changed<P: AsRef<Path> + Into<PathBuf>>(file: P, content: &str) -> Result<(), Error> {
+    if std::fs::read(file.as_ref()).ok().as_deref() == Some(content.as_bytes()) {
+        return Ok(());
+    }
+    create_file(file)?.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+/// Loads the specification of `binary` (the package itself if `None`) and generates the code.
+///
+/// If the code is included in a `library`, the name of the package isn't used as the program name.
+fn load_and_generate<P: AsRef<Path>>(source: P, out_file: PathBuf, binary: Option<&str>, unknown_fields: UnknownFields, library: bool) -> Result<::config::Config, Error> {
+    let inferred_name = binary.map(ToOwned::to_owned).or_else(|| if library { None } else { std::env::var("CARGO_PKG_NAME").ok() });
+    let mut config_spec = load_file(source.as_ref(), 0, unknown_fields, inferred_name.as_deref())?;
+    config_spec.inferred_name = inferred_name;
+    config_spec.library = library;
+    hash_part(&mut config_spec.source_hash, &[config_spec.library as u8]);
+    // A library may be shared by programs doing different things
+    if !library {
+        config_spec.package_description = std::env::var("CARGO_PKG_DESCRIPTION").ok().filter(|description| !description.is_empty());
+    }
+    hash_part(&mut config_spec.source_hash, config_spec.package_description.as_ref().map_or(&[0][..], |description| description.as_bytes()));
+    for deprecation in &config_spec.deprecations {
+        println!("cargo:warning={}: {}", source.as_ref().display(), deprecation);
+    }
+    for field in &config_spec.ignored_fields {
+        println!("cargo:warning={}: ignoring unknown field `{}`", source.as_ref().display(), field);
+    }
+    let params_file = out_file.with_file_name(&*params_file_name(binary));
+    let options_file = out_file.with_file_name(&*options_file_name(binary));
+    let example_file = out_file.with_file_name(&*example_file_name(binary));
+    let powershell_file = out_file.with_file_name(&*powershell_file_name(binary));
+    generate_to_file_if_changed(&config_spec, out_file)?;
+    // Writing to String never fails
+    write_if_changed(params_file, &gen_json::generate_json(&config_spec))?;
+    let mut options_md = String::new();
+    gen_markdown::generate_markdown(&config_spec, &mut options_md).unwrap();
+    write_if_changed(options_file, &options_md)?;
+    if config_spec.general.example_config {
+        let mut example = String::new();
+        gen_example::generate_example(&config_spec, &mut example).unwrap();
+        write_if_changed(example_file, &example)?;
+    }
+    if config_spec.general.powershell_completion {
+        match config_spec.program_name() {
+            Some(name) => {
+                let mut completion = String::new();
+                gen_powershell::generate_powershell(&config_spec, name, &mut completion).unwrap();
+                write_if_changed(powershell_file, &completion)?;
+            },
+            None => println!("cargo:warning={}: powershell_completion requires general.name in a library", source.as_ref().display()),
+        }
+    }
     #[cfg(feature = "debconf")]
     debconf::generate_if_requested(&config_spec)?;
     println!("cargo:rerun-if-changed={}", source.as_ref().display());
+    for file in &config_spec.extended_files {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
     Ok(config_spec)
 }
 
@@ -200,13 +550,65 @@ pub fn generate_source<S: Read, O: Write>(source: S, output: O) -> Result<(), Er
      ::fmt2io::write(output, |output| codegen::generate_code(&cfg, output)).map_err(Into::into)
 }
 
+/// JSON Schema describing the format of the specification
+///
+/// It can be used by editors to offer completion and validation of `config_spec.toml`.
+/// The same schema is shipped as `spec.schema.json` in the root of this crate.
+pub const SPEC_SCHEMA: &str = include_str!("../spec.schema.json");
+
+/// Checks the specification without generating any code.
+///
+/// This performs all the checks code generation would, so it's suitable for pre-validating
+/// specifications in CI.
+pub fn validate_spec<S: Read>(source: S) -> Result<(), Error> {
+    load(source).map(::std::mem::drop)
+}
+
+/// Generates the source code from `toml` configuration held in memory.
+///
+/// This is useful for tools and tests that don't want to touch the filesystem at all.
+pub fn generate_to_string(source: &str) -> Result<String, Error> {
+    let cfg = load(source.as_bytes())?;
+    let mut output = String::new();
+    // Writing to String never fails
+    codegen::generate_code(&cfg, &mut output).unwrap();
+
+    Ok(output)
+}
+
+/// Generates the source code from provided `toml` configuration file into the specified output.
+///
+/// Unlike `build_script()`, this doesn't rely on cargo environment, so it's suitable for other
+/// build systems that manage generated sources themselves. If `output` is an existing directory,
+/// the code is written into `configure_me_config.rs` inside it, otherwise `output` is the file
+/// to write.
+pub fn generate_to<P: AsRef<Path>, O: AsRef<Path>>(source: P, output: O) -> Result<(), Error> {
+    let config_spec = load_from_file(&source)?;
+    let output = output.as_ref();
+
+    if output.is_dir() {
+        generate_to_file(&config_spec, output.join(&*out_file_name(None)))
+    } else {
+        generate_to_file(&config_spec, output)
+    }
+}
+
 /// Generates the source code for you from provided `toml` configuration file.
 ///
 /// This function should be used from build script as it relies on cargo environment. It handles
 /// generating the name of the file (it's called `config.rs` inside `OUT_DIR`) as well as notifying
 /// cargo of the source file.
 pub fn build_script<P: AsRef<Path>>(source: P) -> Result<(), Error> {
-    load_and_generate_default(source, None).map(::std::mem::drop)
+    load_and_generate(source, default_out_file(None)?, None, UnknownFields::Deny, false).map(::std::mem::drop)
+}
+
+/// Generates the source code for a library shared by multiple binaries.
+///
+/// This is same as `build_script()`, but the usage shows the name of the executed binary instead
+/// of the name of the package. Include the code in the library using `include_config!(pub)` and
+/// use `config::Config` from it in the binaries.
+pub fn build_script_library<P: AsRef<Path>>(source: P) -> Result<(), Error> {
+    load_and_generate(source, default_out_file(None)?, None, UnknownFields::Deny, true).map(::std::mem::drop)
 }
 
 /// Generates the source code for you
@@ -217,23 +619,58 @@ pub fn build_script<P: AsRef<Path>>(source: P) -> Result<(), Error> {
 /// generating the name of the file (it's called `config.rs` inside `OUT_DIR`) as well as notifying
 /// cargo of the source file.
 pub fn build_script_auto() -> Result<(), Error> {
+    generate_from_manifest(None)
+}
+
+/// Generates the source code like `build_script_auto()`, but only for the given binaries
+///
+/// Cargo doesn't tell build scripts which binary is being built, so `build_script_auto()`
+/// generates the specifications of all binaries listed in `metadata.configure_me.bin`. Build
+/// scripts that know which binaries are needed, e.g. from enabled features, can skip the others.
+/// A single specification shared by all binaries is always generated.
+pub fn build_script_auto_binaries(binaries: &[&str]) -> Result<(), Error> {
+    generate_from_manifest(Some(binaries))
+}
+
+/// Returns the specifications of the `selected` binaries, or all of them if it's `None`
+fn select_binaries(binaries: HashMap<String, PathBuf>, selected: Option<&[&str]>) -> Result<Vec<(String, PathBuf)>, Error> {
+    match selected {
+        Some(selected) => {
+            if let Some(unknown) = selected.iter().find(|binary| !binaries.contains_key(**binary)) {
+                return Err(ErrorData::UnknownBinary((*unknown).to_owned()).into());
+            }
+            Ok(binaries.into_iter().filter(|(binary, _)| selected.contains(&&**binary)).collect())
+        },
+        None => Ok(binaries.into_iter().collect()),
+    }
+}
+
+fn generate_from_manifest(selected: Option<&[&str]>) -> Result<(), Error> {
     use manifest::SpecificationPaths;
 
     let manifest_dir = manifest::get_dir()?;
     let manifest_file = manifest_dir.join("Cargo.toml");
 
-    let paths = manifest_file
+    let metadata = manifest_file
         .load_manifest()?
         .package.ok_or(manifest::Error::MissingPackage)?
         .metadata.ok_or(manifest::Error::MissingMetadata)?
-        .configure_me.ok_or(manifest::Error::MissingConfigureMeMetadata)?
-        .spec_paths;
+        .configure_me.ok_or(manifest::Error::MissingConfigureMeMetadata)?;
+
+    let out_dir = metadata.out_dir.map(|out_dir| manifest_dir.join(out_dir));
+    if let Some(out_dir) = &out_dir {
+        std::fs::create_dir_all(out_dir).map_err(|error| ErrorData::Open { file: out_dir.clone(), error })?;
+    }
+    let out_file = |binary: Option<&str>| match &out_dir {
+        Some(out_dir) => Ok(out_dir.join(&*out_file_name(binary))),
+        None => default_out_file(binary),
+    };
 
-    match paths {
-        SpecificationPaths::Single(path) => load_and_generate_default(manifest_dir.join(path), None).map(::std::mem::drop),
+    match metadata.spec_paths {
+        SpecificationPaths::Single(path) => load_and_generate(manifest_dir.join(path), out_file(None)?, None, metadata.unknown_fields, metadata.library).map(::std::mem::drop),
         SpecificationPaths::PerBinary(binaries) => {
-            for (binary, path) in binaries {
-                load_and_generate_default(manifest_dir.join(path), Some(&binary)).map(::std::mem::drop)?;
+            for (binary, path) in select_binaries(binaries, selected)? {
+                load_and_generate(manifest_dir.join(path), out_file(Some(&binary))?, Some(&binary), metadata.unknown_fields, metadata.library).map(::std::mem::drop)?;
             }
             Ok(())
         },
@@ -256,7 +693,7 @@ pub fn build_script_with_man<P: AsRef<Path>>(source: P) -> Result<(), Error> {
 /// page.
 #[cfg(feature = "man")]
 pub fn build_script_with_man_written_to<P: AsRef<Path>, M: AsRef<Path> + Into<PathBuf>>(source: P, output: M) -> Result<(), Error> {
-    let config_spec = load_and_generate_default(source, None)?;
+    let config_spec = load_and_generate(source, default_out_file(None)?, None, UnknownFields::Deny, false)?;
     let manifest = manifest::BuildScript.load_manifest()?;
     let man_page = gen_man::generate_man_page(&config_spec, manifest.borrow())?;
 
@@ -431,123 +868,3287 @@ type = "String"
 merge_fn = "(|a: &mut String, b: String| a.push_str(&b))"
 "#;
 
-    pub struct ExpectedOutput {
-        pub raw_config: &'static str,
-        pub validate: &'static str,
-        pub merge_in: &'static str,
-        pub merge_args: &'static str,
-        pub config: &'static str,
-        pub arg_parse_error: &'static str,
-    }
+    pub const NARGS: &str =
+r#"
+[[param]]
+name = "point"
+type = "i32"
+abbr = "p"
+nargs = 2
 
-    pub const EXPECTED_EMPTY: ExpectedOutput = ExpectedOutput {
-        raw_config: include_str!("../tests/expected_outputs/empty/raw_config.rs"),
-        validate: include_str!("../tests/expected_outputs/empty/validate.rs"),
-        merge_in: include_str!("../tests/expected_outputs/empty/merge_in.rs"),
-        merge_args: include_str!("../tests/expected_outputs/empty/merge_args.rs"),
-        config: include_str!("../tests/expected_outputs/empty/config.rs"),
-        arg_parse_error: include_str!("../tests/expected_outputs/empty/arg_parse_error.rs"),
-    };
+[[param]]
+name = "files"
+type = "String"
+nargs = { min = 1, max = 3 }
 
-    pub const EXPECTED_SINGLE_OPTIONAL_PARAM: ExpectedOutput = ExpectedOutput {
-        raw_config: include_str!("../tests/expected_outputs/single_optional_param/raw_config.rs"),
-        validate: include_str!("../tests/expected_outputs/single_optional_param/validate.rs"),
-        merge_in: include_str!("../tests/expected_outputs/single_optional_param/merge_in.rs"),
-        merge_args: include_str!("../tests/expected_outputs/single_optional_param/merge_args.rs"),
-        config: include_str!("../tests/expected_outputs/single_optional_param/config.rs"),
-        arg_parse_error: include_str!("../tests/expected_outputs/single_optional_param/arg_parse_error.rs"),
-    };
+[[switch]]
+name = "verbose"
+abbr = "v"
+"#;
 
-    pub const EXPECTED_SINGLE_MANDATORY_PARAM: ExpectedOutput = ExpectedOutput {
-        raw_config: include_str!("../tests/expected_outputs/single_mandatory_param/raw_config.rs"),
-        validate: include_str!("../tests/expected_outputs/single_mandatory_param/validate.rs"),
-        merge_in: include_str!("../tests/expected_outputs/single_mandatory_param/merge_in.rs"),
-        merge_args: include_str!("../tests/expected_outputs/single_mandatory_param/merge_args.rs"),
-        config: include_str!("../tests/expected_outputs/single_mandatory_param/config.rs"),
-        arg_parse_error: include_str!("../tests/expected_outputs/single_mandatory_param/arg_parse_error.rs"),
-    };
+    pub const LONG_OPTIONS: &str =
+r#"
+[general]
+conf_file_param = "config"
+long_option_prefixes = true
+case_insensitive_long_options = true
+slash_options = true
 
-    pub const EXPECTED_SINGLE_DEFAULT_PARAM: ExpectedOutput = ExpectedOutput {
-        raw_config: include_str!("../tests/expected_outputs/single_default_param/raw_config.rs"),
-        validate: include_str!("../tests/expected_outputs/single_default_param/validate.rs"),
-        merge_in: include_str!("../tests/expected_outputs/single_default_param/merge_in.rs"),
-        merge_args: include_str!("../tests/expected_outputs/single_default_param/merge_args.rs"),
-        config: include_str!("../tests/expected_outputs/single_default_param/config.rs"),
-        arg_parse_error: include_str!("../tests/expected_outputs/single_default_param/arg_parse_error.rs"),
-    };
+[[param]]
+name = "port"
+type = "u16"
 
-    pub const EXPECTED_SINGLE_SWITCH: ExpectedOutput = ExpectedOutput {
-        raw_config: include_str!("../tests/expected_outputs/single_switch/raw_config.rs"),
-        validate: include_str!("../tests/expected_outputs/single_switch/validate.rs"),
-        merge_in: include_str!("../tests/expected_outputs/single_switch/merge_in.rs"),
-        merge_args: include_str!("../tests/expected_outputs/single_switch/merge_args.rs"),
-        config: include_str!("../tests/expected_outputs/single_switch/config.rs"),
-        arg_parse_error: include_str!("../tests/expected_outputs/single_switch/arg_parse_error.rs"),
-    };
+[[param]]
+name = "port_range"
+type = "u16"
 
-    pub const EXPECTED_SHORT_SWITCHES: ExpectedOutput = ExpectedOutput {
-        raw_config: include_str!("../tests/expected_outputs/short_switches/raw_config.rs"),
-        validate: include_str!("../tests/expected_outputs/short_switches/validate.rs"),
-        merge_in: include_str!("../tests/expected_outputs/short_switches/merge_in.rs"),
-        merge_args: include_str!("../tests/expected_outputs/short_switches/merge_args.rs"),
-        config: include_str!("../tests/expected_outputs/short_switches/config.rs"),
-        arg_parse_error: include_str!("../tests/expected_outputs/short_switches/arg_parse_error.rs"),
-    };
+[[param]]
+name = "bind_addr"
+type = "String"
 
-    fn check(src: &str, expected: &str) {
-        let mut src = src.as_bytes();
-        let mut out = Vec::new();
-        generate_source(&mut src, &mut out).unwrap();
-        assert_eq!(::std::str::from_utf8(&out).unwrap(), expected);
-    }
+[[switch]]
+name = "verbose"
 
-    #[test]
-    fn empty() {
-        check("", include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/empty-config.rs")));
-    }
+[[switch]]
+name = "color"
+default = true
+"#;
 
-    #[test]
-    fn single_optional_param() {
-        check(SINGLE_OPTIONAL_PARAM, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_optional_param-config.rs")));
-    }
+    pub const LENIENT: &str =
+r#"
+[general]
+conf_file_param = "config"
+lenient_config_values = true
 
-    #[test]
-    fn single_mandatory_param() {
-        check(SINGLE_MANDATORY_PARAM, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_mandatory_param-config.rs")));
-    }
+[[param]]
+name = "port"
+type = "u16"
 
-    #[test]
-    fn single_default_param() {
-        check(SINGLE_DEFAULT_PARAM, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_default_param-config.rs")));
-    }
+[[param]]
+name = "name"
+type = "String"
 
-    #[test]
-    fn single_switch() {
-        check(SINGLE_SWITCH, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_switch-config.rs")));
-    }
+[[param]]
+name = "raw"
+type = "::std::ffi::OsString"
 
-    #[test]
-    fn multiple_params() {
-        check(MULTIPLE_PARAMS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/multiple_params-config.rs")));
-    }
+[[switch]]
+name = "verbose"
+count = true
+"#;
 
-    #[test]
-    fn no_arg() {
-        check(NO_ARG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/no_arg-config.rs")));
-    }
+    pub const SWITCH_VALUES: &str =
+r#"
+[general]
+env_prefix = "SWITCH_VALUES_TEST"
+conf_file_param = "config"
+true_values = ["yes", "on"]
+false_values = ["no", "off"]
 
-    #[test]
-    fn short_switches() {
-        check(SHORT_SWITCHES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/short_switches-config.rs")));
-    }
+[[switch]]
+name = "verbose"
 
-    #[test]
-    fn conf_files() {
-        check(CONF_FILES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/conf_files-config.rs")));
-    }
+[[switch]]
+name = "color"
+default = true
+"#;
+
+    pub const EMPTY_ENV_VARS: &str =
+r#"
+[general]
+env_prefix = "EMPTY_ENV_TEST"
+empty_env_var = "unset"
+
+[[param]]
+name = "tls_cert"
+type = "String"
+
+[[param]]
+name = "name"
+type = "String"
+empty_env_var = "value"
+
+[[param]]
+name = "key"
+type = "String"
+empty_env_var = "error"
+
+[[switch]]
+name = "verbose"
+"#;
+
+    pub const NON_UTF8: &str =
+r#"
+[general]
+env_prefix = "NON_UTF8_TEST"
+conf_file_param = "config"
+
+[[param]]
+name = "path"
+type = "::std::path::PathBuf"
+abbr = "p"
+
+[[param]]
+name = "raw"
+type = "::std::ffi::OsString"
+"#;
+
+    pub const MISSING_FIELDS: &str =
+r#"
+[general]
+env_prefix = "MISSING_FIELDS_TEST"
+
+[[param]]
+name = "host"
+type = "String"
+optional = false
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "token"
+type = "String"
+optional = false
+argument = false
+
+[[param]]
+name = "secret"
+type = "String"
+optional = false
+argument = false
+env_var = false
+
+[[param]]
+name = "user"
+type = "String"
+optional = true
+"#;
+
+    pub const DUPLICATE_ARGS: &str =
+r#"
+[general]
+duplicate_arg = "error"
+
+[[param]]
+name = "host"
+type = "String"
+abbr = "H"
+
+[[param]]
+name = "port"
+type = "u16"
+duplicate_arg = "first"
+
+[[param]]
+name = "level"
+type = "String"
+duplicate_arg = "last"
+
+[[param]]
+name = "tag"
+type = "String"
+merge_fn = "(|a: &mut String, b: String| { a.push(','); a.push_str(&b) })"
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+
+[[switch]]
+name = "color"
+default = true
+duplicate_arg = "first"
+
+[[switch]]
+name = "debug"
+abbr = "d"
+count = true
+"#;
+
+    pub const FORWARD_SEPARATOR: &str =
+r#"
+[general]
+forward_separator = true
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+"#;
+
+    pub const EXPLAIN_CONFIG: &str =
+r#"
+[general]
+env_prefix = "EXPLAIN_CONFIG_TEST"
+conf_file_param = "config"
+explain_config_switch = "explain_config"
+
+[[param]]
+name = "port"
+type = "u16"
+abbr = "p"
+
+[[param]]
+name = "host"
+type = "String"
+
+[[param]]
+name = "tag"
+type = "String"
+merge_fn = "(|a: &mut String, b: String| a.push_str(&b))"
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+
+[[switch]]
+name = "debug"
+count = true
+"#;
+
+    pub const INSECURE_CONFIG_FILES: &str =
+r#"
+[general]
+insecure_config_files = "error"
+
+[[param]]
+name = "token"
+type = "String"
+"#;
+
+    pub const CONFIG_LIMITS: &str =
+r#"
+[general]
+max_config_file_size = 256
+max_config_string_length = 8
+max_config_depth = 2
+mmap_config_threshold = 64
+
+[[param]]
+name = "name"
+type = "String"
+"#;
+
+    pub const GLOBAL_CONFIG: &str =
+r#"
+[general]
+global_config = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+"#;
+
+    pub const SHARED_CONFIG: &str =
+r#"
+[general]
+shared_config = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+"#;
+
+    pub const EXPORT_ENV_VARS: &str =
+r#"
+[general]
+env_prefix = "export"
+export_env_vars = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "bind_addr"
+type = "::std::net::IpAddr"
+default = "::std::net::IpAddr::V4(::std::net::Ipv4Addr::LOCALHOST)"
+
+[[param]]
+name = "tls_cert"
+type = "::std::path::PathBuf"
+
+[[param]]
+name = "peers"
+type = "String"
+nargs = 2
+
+[[switch]]
+name = "verbose"
+count = true
+
+[[switch]]
+name = "debug"
+
+[[switch]]
+name = "tls"
+default = true
+env_var = false
+"#;
+
+    pub const EXPORT_ARGS: &str =
+r#"
+[general]
+export_args = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "offset"
+type = "i32"
+default = "0"
+
+[[param]]
+name = "tls_cert"
+type = "::std::path::PathBuf"
+
+[[param]]
+name = "peers"
+type = "String"
+nargs = 2
+
+[[param]]
+name = "secret"
+type = "String"
+argument = false
+
+[[switch]]
+name = "verbose"
+count = true
+
+[[switch]]
+name = "debug"
+
+[[switch]]
+name = "tls"
+default = true
+"#;
+
+    pub const NON_DEFAULT_FIELDS: &str =
+r#"
+[general]
+non_default_fields = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "timeout"
+type = "u32"
+default = "30"
+convert_into = "u64"
+
+[[param]]
+name = "peers"
+type = "String"
+default = "[\"a\".to_owned(), \"b\".to_owned()]"
+nargs = 2
+
+[[param]]
+name = "password"
+type = "String"
+secret = true
+
+[[switch]]
+name = "verbose"
+count = true
+
+[[switch]]
+name = "debug"
+
+[[switch]]
+name = "tls"
+default = true
+"#;
+
+    pub const INTERACTIVE_SETUP: &str =
+r#"
+[general]
+interactive_setup = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+doc = "Port to listen on"
+
+[[param]]
+name = "bind_addr"
+type = "::std::net::Ipv4Addr"
+default = "::std::net::Ipv4Addr::new(0, 0, 0, 0)"
+doc = "IP address to bind to"
+
+[[param]]
+name = "name"
+type = "String"
+
+[[param]]
+name = "peers"
+type = "String"
+nargs = { min = 1, max = 3 }
+
+[[switch]]
+name = "verbose"
+count = true
+
+[[switch]]
+name = "tls"
+default = true
+doc = "Use TLS"
+"#;
+
+    pub const CHECK_CONFIG: &str =
+r#"
+[general]
+check_config_switch = "check_config"
+case_insensitive_long_options = true
+slash_options = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+"#;
+
+    pub const MERGE_REPORT: &str =
+r#"
+[general]
+env_prefix = "report"
+conf_file_param = "config"
+merge_report = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "peers"
+type = "String"
+nargs = 2
+
+[[param]]
+name = "tags"
+type = "String"
+merge_fn = "(|a: &mut String, b: String| { a.push(','); a.push_str(&b) })"
+
+[[param]]
+name = "password"
+type = "String"
+secret = true
+
+[[switch]]
+name = "verbose"
+count = true
+
+[[switch]]
+name = "debug"
+"#;
+
+    pub const CONFIG_TABLE: &str =
+r#"
+[general]
+config_table = "services.my-app"
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "host"
+type = "String"
+default = "\"localhost\".to_owned()"
+
+[[switch]]
+name = "verbose"
+"#;
+
+    pub const RELOAD_IF_CHANGED: &str =
+r#"
+[general]
+conf_file_param = "config"
+reload_if_changed = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+"#;
+
+    pub const ARBITRARY: &str =
+r#"
+[general]
+arbitrary = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "peers"
+type = "String"
+nargs = { min = 1, max = 3 }
+optional = true
+
+[[param]]
+name = "ratio"
+type = "u8"
+nargs = 2
+default = "[1, 2]"
+
+[[switch]]
+name = "verbose"
+count = true
+"#;
+
+    pub const REDACTED_RENDERING: &str =
+r#"
+[general]
+config_table = "server"
+redacted_rendering = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+
+[[param]]
+name = "password"
+type = "String"
+secret = true
+
+[[param]]
+name = "peers"
+type = "String"
+nargs = { min = 1, max = 3 }
+default = "vec![\"localhost\".to_owned()]"
+
+[[param]]
+name = "data_dir"
+type = "::std::ffi::OsString"
+
+[[switch]]
+name = "verbose"
+"#;
+
+    pub const AUDIT: &str =
+r#"
+[general]
+env_prefix = "audit"
+conf_file_param = "config"
+audit = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+
+[[param]]
+name = "token"
+type = "String"
+secret = true
+
+[[param]]
+name = "tags"
+type = "String"
+merge_fn = "(|a: &mut String, b: String| { a.push(','); a.push_str(&b) })"
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+count = true
+"#;
+
+    pub const RENAMED_FROM: &str =
+r#"
+[general]
+env_prefix = "renamed"
+conf_file_param = "config"
+explain_config_switch = "explain_config"
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+renamed_from = ["listen_port", "bind_port"]
+
+[[param]]
+name = "data_dir"
+type = "::std::path::PathBuf"
+renamed_from = ["data_path"]
+empty_env_var = "error"
+"#;
+
+    pub const MULTIPLE: &str =
+r#"
+[general]
+env_prefix = "multiple"
+conf_file_param = "config"
+
+[[param]]
+name = "tag"
+type = "String"
+abbr = "t"
+multiple = true
+doc = "Tags attached to the records"
+
+[[param]]
+name = "port"
+type = "u16"
+abbr = "p"
+multiple = true
+list_merge = "replace"
+env_delimiter = ":"
+default = "vec![8080]"
+"#;
+
+    pub const ENUM_VALUES: &str =
+r#"
+[general]
+env_prefix = "enum_values"
+export_env_vars = true
+redacted_rendering = true
+
+[[param]]
+name = "format"
+type = "Format"
+values = ["json", "yaml", "plain-text"]
+abbr = "f"
+default = "Format::Json"
+doc = "Output format"
+
+[[param]]
+name = "levels"
+type = "LogLevel"
+values = ["error", "warn", "info"]
+multiple = true
+optional = true
+"#;
+
+    pub const SECTIONS: &str =
+r#"
+[general]
+env_prefix = "sections"
+interactive_setup = true
+arbitrary = true
+redacted_rendering = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+
+[[section]]
+name = "database"
+doc = "Connection to the database"
+
+[[section.param]]
+name = "url"
+type = "String"
+optional = false
+doc = "URL of the database"
+
+[[section.param]]
+name = "pool_size"
+type = "u32"
+default = "4"
+
+[[section]]
+name = "log"
+
+[[section.param]]
+name = "level"
+type = "String"
+optional = true
+renamed_from = ["verbosity"]
+"#;
+
+    pub const MAP: &str =
+r#"
+[general]
+env_prefix = "map"
+conf_file_param = "config"
+export_env_vars = true
+export_args = true
+non_default_fields = true
+interactive_setup = true
+merge_report = true
+
+[[param]]
+name = "label"
+type = "String"
+abbr = "l"
+map = true
+doc = "Labels attached to the records"
+
+[[param]]
+name = "limit"
+type = "u32"
+map = true
+default = "::std::collections::HashMap::new()"
+"#;
+
+    pub const POSITIONAL: &str =
+r#"
+[general]
+env_prefix = "positional"
+
+[[param]]
+name = "level"
+type = "u32"
+default = "1"
+doc = "Compression level"
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+
+[[positional]]
+name = "input"
+type = "String"
+doc = "Name of the archive"
+
+[[positional]]
+name = "count"
+type = "u32"
+optional = true
+doc = "Maximum number of files to add"
+
+[[positional]]
+name = "files"
+type = "::std::path::PathBuf"
+optional = true
+variadic = true
+"#;
+
+    pub const SUBCOMMANDS: &str =
+r#"
+[general]
+env_prefix = "tool"
+conf_file_param = "config"
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+doc = "Print more information"
+
+[[subcommand]]
+name = "serve"
+doc = "Start the server"
+
+[[subcommand.param]]
+name = "port"
+type = "u16"
+default = "8080"
+doc = "Port to listen on"
+
+[[subcommand.switch]]
+name = "tls"
+doc = "Serve HTTPS"
+
+[[subcommand]]
+name = "check"
+doc = "Check a file without serving it"
+
+[[subcommand.positional]]
+name = "path"
+type = "::std::path::PathBuf"
+doc = "File to check"
+"#;
+
+    pub const ALIASES: &str =
+r#"
+[general]
+env_prefix = "aliases"
+
+[[param]]
+name = "color"
+type = "String"
+default = "\"auto\".to_owned()"
+aliases = ["colour"]
+doc = "When to use colors"
+
+[[param]]
+name = "threads"
+type = "u32"
+aliases = ["jobs", "workers"]
+show_aliases = true
+doc = "Number of worker threads"
+
+[[switch]]
+name = "quiet"
+aliases = ["silent"]
+show_aliases = true
+
+[[switch]]
+name = "cache"
+default = true
+aliases = ["caching"]
+doc = "Cache downloaded files"
+"#;
+
+    pub const RANGES: &str =
+r#"
+[general]
+env_prefix = "ranges"
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+min = 1
+max = 65535
+doc = "Port to listen on"
+
+[[param]]
+name = "ratio"
+type = "f64"
+min = 0
+max = 1
+
+[[param]]
+name = "retries"
+type = "u8"
+optional = false
+max = 10
+"#;
+
+    pub const PATTERNS: &str =
+r#"
+[general]
+env_prefix = "patterns"
+
+[[param]]
+name = "slug"
+type = "String"
+optional = false
+validate_regex = "^[a-z0-9-]+$"
+doc = "Identifier used in URLs"
+
+[[param]]
+name = "branch"
+type = "String"
+default = "\"main\".to_owned()"
+validate_regex = "^[a-z]+(/[a-z]+)*$"
+
+[[param]]
+name = "tag"
+type = "String"
+multiple = true
+validate_regex = "^v[0-9]+$"
+"#;
+
+    pub const RELATIONS: &str =
+r#"
+[general]
+env_prefix = "relations"
+
+[[param]]
+name = "tls_cert"
+type = "String"
+requires = ["tls_key"]
+conflicts_with = ["insecure"]
+doc = "Certificate to serve"
+
+[[param]]
+name = "tls_key"
+type = "String"
+
+[[param]]
+name = "http_port"
+type = "u16"
+default = "80"
+requires = ["insecure"]
+
+[[switch]]
+name = "insecure"
+"#;
+
+    pub const GROUPS: &str =
+r#"
+[general]
+env_prefix = "groups"
+
+[[param]]
+name = "token"
+type = "String"
+
+[[param]]
+name = "token_file"
+type = "String"
+
+[[param]]
+name = "host"
+type = "String"
+
+[[param]]
+name = "socket"
+type = "String"
+
+[[switch]]
+name = "json"
+
+[[switch]]
+name = "yaml"
+
+[[group]]
+name = "format"
+doc = "Output format"
+members = ["json", "yaml"]
+
+[[group]]
+name = "auth"
+members = ["token", "token_file"]
+required = true
+
+[[group]]
+name = "connection"
+members = ["host", "socket"]
+required = true
+multiple = true
+"#;
+
+    pub const VALIDATORS: &str =
+r#"
+[general]
+env_prefix = "validators"
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+validate = "crate::validators::check_port"
+
+[[param]]
+name = "user"
+type = "String"
+validate = "crate::validators::check_user"
+
+[[param]]
+name = "peer"
+type = "String"
+multiple = true
+validate = "crate::validators::check_user"
+"#;
+
+    pub const VALIDATE_CONFIG: &str =
+r#"
+[general]
+env_prefix = "validate_config"
+validate = "crate::check_workers"
+
+[[param]]
+name = "min_workers"
+type = "u32"
+default = "1"
+
+[[param]]
+name = "max_workers"
+type = "u32"
+default = "4"
+"#;
+
+    pub const SECRETS: &str =
+r#"
+[general]
+env_prefix = "secrets"
+conf_file_param = "config"
+debug = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+
+[[param]]
+name = "password"
+type = "String"
+optional = false
+secret = true
+validate_regex = "^[^ ]+$"
+
+[[param]]
+name = "pin"
+type = "u32"
+secret = true
+max = 9999
+
+[[switch]]
+name = "verbose"
+
+[[section]]
+name = "database"
+
+[[section.param]]
+name = "url"
+type = "String"
+default = "\"postgres://localhost\".to_owned()"
+
+[[section.param]]
+name = "token"
+type = "String"
+secret = true
+"#;
+
+    pub const DURATIONS: &str =
+r#"
+[general]
+env_prefix = "durations"
+conf_file_param = "config"
+
+[[param]]
+name = "timeout"
+type = "duration"
+default = "::std::time::Duration::from_secs(30)"
+doc = "How long to wait for a response"
+
+[[param]]
+name = "retry_delay"
+type = "duration"
+"#;
+
+    pub const SIZES: &str =
+r#"
+[general]
+env_prefix = "sizes"
+conf_file_param = "config"
+
+[[param]]
+name = "cache_size"
+type = "size"
+default = "64 * 1024 * 1024"
+max = 1073741824
+doc = "Maximum size of the cache"
+
+[[param]]
+name = "buffer_size"
+type = "size"
+
+[[positional]]
+name = "limit"
+type = "size"
+optional = true
+"#;
+
+    pub const DEFAULT_FN: &str =
+r#"
+[general]
+env_prefix = "default_fn"
+
+[[param]]
+name = "workers"
+type = "usize"
+default_fn = "crate::default_workers"
+doc = "Number of worker threads"
+
+[[param]]
+name = "host"
+type = "String"
+default = "\"localhost\".to_owned()"
+"#;
+
+    pub const COUNT_SWITCHES: &str =
+r#"
+[general]
+env_prefix = "count_switches"
+conf_file_param = "config"
+export_args = true
+non_default_fields = true
+arbitrary = true
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+count = true
+map_to = "Verbosity"
+values = ["warn", "info", "debug", "trace"]
+doc = "Print more details"
+
+[[switch]]
+name = "quiet"
+abbr = "q"
+count = true
+max_count = 2
+
+[[switch]]
+name = "level"
+count = true
+max_count = 3
+map_to = "crate::Level"
+map_values = ["crate::Level::Low", "crate::Level::High"]
+"#;
+
+    pub const JSON_CONFIG: &str =
+r#"
+[general]
+env_prefix = "json_config"
+conf_file_param = "config"
+json_config = true
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+doc = "Port to listen on"
+
+[[param]]
+name = "peers"
+type = "String"
+merge_fn = "(|a: &mut String, b: String| { a.push(','); a.push_str(&b) })"
+
+[[switch]]
+name = "verbose"
+"#;
+
+    pub const ENV_FILE: &str =
+r#"
+[general]
+env_prefix = "env_file"
+env_file = "tests/config_files/env_file.env"
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+doc = "Port to listen on"
+
+[[param]]
+name = "host"
+type = "String"
+default = "\"localhost\".to_owned()"
+
+[[param]]
+name = "label"
+type = "String"
+map = true
+
+[[switch]]
+name = "verbose"
+"#;
+
+    pub const DROP_INS: &str =
+r#"
+[general]
+env_prefix = "drop_ins"
+conf_file_param = "config"
+merge_report = true
+drop_ins = true
+
+[[param]]
+name = "port"
+type = "u16"
+
+[[param]]
+name = "host"
+type = "String"
+
+[[param]]
+name = "tag"
+type = "String"
+merge_fn = "(|a: &mut String, b: String| a.push_str(&b))"
+"#;
+
+    pub const CONFIG_INCLUDES: &str =
+r#"
+[general]
+env_prefix = "config_includes"
+conf_file_param = "config"
+config_includes = true
+
+[[param]]
+name = "port"
+type = "u16"
+
+[[param]]
+name = "host"
+type = "String"
+
+[[param]]
+name = "tag"
+type = "String"
+merge_fn = "(|a: &mut String, b: String| a.push_str(&b))"
+"#;
+
+    pub const LOADED_CONFIG_FILES: &str =
+r#"
+[general]
+env_prefix = "loaded_config_files"
+conf_file_param = "config"
+loaded_config_files = true
+
+[[param]]
+name = "port"
+type = "u16"
+
+[[param]]
+name = "host"
+type = "String"
+"#;
+
+    pub const REMOTE_CONFIG: &str =
+r#"
+[general]
+env_prefix = "remote_config"
+conf_file_param = "config"
+remote_config = true
+remote_config_http = true
+remote_config_timeout = 1
+max_config_file_size = 64
+
+[[param]]
+name = "port"
+type = "u16"
+
+[[param]]
+name = "host"
+type = "String"
+"#;
+
+    pub const PROFILES: &str =
+r#"
+[general]
+env_prefix = "profiles"
+conf_file_param = "config"
+profile_param = "profile"
+long_option_prefixes = true
+case_insensitive_long_options = true
+
+[[param]]
+name = "port"
+type = "u16"
+
+[[param]]
+name = "host"
+type = "String"
+"#;
+
+    pub const WATCH_CONFIG: &str =
+r#"
+[general]
+conf_file_param = "config"
+reload_if_changed = true
+watch_config = true
+
+[[param]]
+name = "port"
+type = "u16"
+"#;
+
+    pub const DUMP_CONFIG: &str =
+r#"
+[general]
+dump_config_switch = "dump_config"
+long_option_prefixes = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+
+[[param]]
+name = "password"
+type = "String"
+secret = true
+
+[[switch]]
+name = "verbose"
+"#;
+
+    pub const SERIALIZE: &str =
+r#"
+[general]
+serialize = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+
+[[param]]
+name = "labels"
+type = "String"
+map = true
+
+[[param]]
+name = "password"
+type = "String"
+secret = true
+
+[[param]]
+name = "timeout"
+type = "duration"
+
+[[param]]
+name = "tags"
+type = "String"
+multiple = true
+
+[[switch]]
+name = "verbose"
+
+[[section]]
+name = "database"
+
+[[section.param]]
+name = "url"
+type = "String"
+"#;
+
+    pub const PARTIAL_CONFIG: &str =
+r#"
+[general]
+env_prefix = "partial_config"
+partial_config = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+
+[[param]]
+name = "host"
+type = "String"
+optional = false
+
+[[switch]]
+name = "verbose"
+
+[[positional]]
+name = "input"
+type = "String"
+optional = true
+"#;
+
+    pub const VERSION: &str =
+r#"
+[general]
+version = true
+
+[[param]]
+name = "port"
+type = "u16"
+abbr = "p"
+default = "8080"
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+"#;
+
+    pub const NEGATABLE: &str =
+r#"
+[defaults]
+negatable = true
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+doc = "Print more information"
+
+[[switch]]
+name = "color"
+aliases = ["colour"]
+
+[[switch]]
+name = "dry_run"
+negatable = false
+
+[[switch]]
+name = "debug"
+count = true
+
+[[switch]]
+name = "cache"
+default = true
+"#;
+
+    pub const TRAILING: &str =
+r#"
+[[switch]]
+name = "verbose"
+abbr = "v"
+
+[[positional]]
+name = "host"
+type = "String"
+doc = "Host to probe"
+
+[[positional]]
+name = "ports"
+type = "u16"
+optional = true
+variadic = true
+trailing = true
+doc = "Ports to probe"
+"#;
+
+    pub const RICH_HELP: &str =
+r#"
+[general]
+env_prefix = "app"
+rich_help = true
+help_integer_notation = true
+
+[[param]]
+name = "port"
+type = "u16"
+abbr = "p"
+default = "8080"
+doc = "Port to listen on"
+
+[[param]]
+name = "bind_addr"
+type = "String"
+default = "\"0.0.0.0\".to_owned()"
+doc = "Address the server binds to, use 127.0.0.1 to accept only local connections"
+
+[[param]]
+name = "token"
+type = "String"
+secret = true
+default = "\"changeme\".to_owned()"
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+doc = "Print more information"
+
+[[positional]]
+name = "root"
+type = "::std::path::PathBuf"
+optional = true
+doc = "Directory to serve"
+"#;
+
+    pub const USER_CONFIG: &str =
+r#"
+[general]
+name = "user_config"
+user_config = true
+
+[[param]]
+name = "port"
+type = "u16"
+default = "8080"
+
+[[param]]
+name = "host"
+type = "String"
+"#;
+
+    pub const DEFAULTS_FIELDS: &str =
+r#"
+[defaults]
+args = false
+env_vars = true
+
+[[param]]
+name = "foo"
+type = "u32"
+"#;
+
+    /// Every field of the specification format, used to check the schema
+    pub const ALL_FIELDS: &str =
+r#"
+[general]
+name = "app"
+summary = "An app"
+doc = "An app doing things"
+env_prefix = "APP"
+conf_file_param = "config"
+conf_dir_param = "conf_dir"
+config_table = "services.app"
+explain_config_switch = "explain_config"
+check_config_switch = "check_config"
+require_doc = false
+require_explicit_optional = false
+long_option_prefixes = false
+case_insensitive_long_options = false
+slash_options = false
+forward_separator = false
+lenient_config_values = false
+true_values = ["1", "true"]
+false_values = ["0", "false"]
+empty_env_var = "value"
+duplicate_arg = "last"
+insecure_config_files = "allow"
+max_config_file_size = 65536
+max_config_string_length = 4096
+max_config_depth = 16
+global_config = false
+shared_config = false
+export_env_vars = false
+export_args = false
+non_default_fields = false
+interactive_setup = false
+merge_report = false
+reload_if_changed = false
+json_config = false
+arbitrary = false
+redacted_rendering = false
+audit = false
+
+[defaults]
+args = true
+env_vars = true
+optional = true
+
+[[param]]
+name = "foo"
+abbr = "f"
+type = "u32"
+optional = true
+doc = "A foo"
+argument = true
+env_var = true
+convert_into = "u64"
+merge_fn = "(|a: &mut u32, b: u32| *a += b)"
+empty_env_var = "error"
+enabled_if_feature = "foo"
+
+[[param]]
+name = "bar"
+type = "u32"
+default = "42"
+nargs = { min = 1, max = 3 }
+duplicate_arg = "error"
+secret = false
+
+[[switch]]
+name = "verbose"
+abbr = "v"
+doc = "Be verbose"
+env_var = true
+count = true
+enabled_if_feature = "verbose-log"
+
+[[switch]]
+name = "fast"
+default = true
+duplicate_arg = "first"
+
+[[positional]]
+name = "files"
+type = "String"
+optional = true
+variadic = true
+doc = "Files to process"
+"#;
+
+    pub const EXTENDS_BASE: &str =
+r#"
+[general]
+env_prefix = "BASE"
+summary = "Base app"
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+doc = "Port to listen on"
+
+[[param]]
+name = "timeout"
+type = "u64"
+default = "30"
+
+[[switch]]
+name = "verbose"
+"#;
+
+    pub const EXTENDS_CHILD: &str =
+r#"
+extends = "base.toml"
+
+[general]
+env_prefix = "CHILD"
+
+[[override]]
+name = "port"
+default = "8080"
+
+[[override]]
+name = "timeout"
+optional = true
+doc = "Timeout in seconds"
+
+[[override]]
+name = "verbose"
+doc = "Be verbose"
+
+[[param]]
+name = "bind_addr"
+type = "::std::net::IpAddr"
+"#;
+
+    pub const DB_FRAGMENT: &str =
+r#"
+[[param]]
+name = "host"
+abbr = "H"
+type = "String"
+doc = "Database host"
+
+[[param]]
+name = "port"
+type = "u16"
+default = "5432"
+
+[[switch]]
+name = "tls"
+"#;
+
+    pub const INCLUDE_FRAGMENTS: &str =
+r#"
+[[include]]
+file = "db.toml"
+prefix = "primary_db_"
+
+[[include]]
+file = "db.toml"
+prefix = "replica_db_"
+
+[[param]]
+name = "workers"
+type = "u32"
+"#;
+
+    pub const REQUIRE_EXPLICIT_OPTIONAL: &str =
+r#"
+[general]
+require_explicit_optional = true
+
+[[param]]
+name = "foo"
+type = "u32"
+optional = true
+
+[[param]]
+name = "bar"
+type = "u32"
+
+[[param]]
+name = "baz"
+type = "u32"
+default = "42"
+
+[[param]]
+name = "qux"
+type = "u32"
+"#;
+
+    pub const REQUIRE_DOC: &str =
+r#"
+[general]
+require_doc = true
+
+[[param]]
+name = "foo"
+type = "u32"
+doc = "A foo"
+
+[[param]]
+name = "bar"
+type = "u32"
+
+[[switch]]
+name = "baz"
+"#;
+
+    pub struct ExpectedOutput {
+        pub raw_config: &'static str,
+        pub validate: &'static str,
+        pub merge_args: &'static str,
+    }
+
+    pub const EXPECTED_EMPTY: ExpectedOutput = ExpectedOutput {
+        raw_config: include_str!("../tests/expected_outputs/empty/raw_config.rs"),
+        validate: include_str!("../tests/expected_outputs/empty/validate.rs"),
+        merge_args: include_str!("../tests/expected_outputs/empty/merge_args.rs"),
+    };
+
+    pub const EXPECTED_SINGLE_OPTIONAL_PARAM: ExpectedOutput = ExpectedOutput {
+        raw_config: include_str!("../tests/expected_outputs/single_optional_param/raw_config.rs"),
+        validate: include_str!("../tests/expected_outputs/single_optional_param/validate.rs"),
+        merge_args: include_str!("../tests/expected_outputs/single_optional_param/merge_args.rs"),
+    };
+
+    pub const EXPECTED_SINGLE_MANDATORY_PARAM: ExpectedOutput = ExpectedOutput {
+        raw_config: include_str!("../tests/expected_outputs/single_mandatory_param/raw_config.rs"),
+        validate: include_str!("../tests/expected_outputs/single_mandatory_param/validate.rs"),
+        merge_args: include_str!("../tests/expected_outputs/single_mandatory_param/merge_args.rs"),
+    };
+
+    pub const EXPECTED_SINGLE_DEFAULT_PARAM: ExpectedOutput = ExpectedOutput {
+        raw_config: include_str!("../tests/expected_outputs/single_default_param/raw_config.rs"),
+        validate: include_str!("../tests/expected_outputs/single_default_param/validate.rs"),
+        merge_args: include_str!("../tests/expected_outputs/single_default_param/merge_args.rs"),
+    };
+
+    pub const EXPECTED_SINGLE_SWITCH: ExpectedOutput = ExpectedOutput {
+        raw_config: include_str!("../tests/expected_outputs/single_switch/raw_config.rs"),
+        validate: include_str!("../tests/expected_outputs/single_switch/validate.rs"),
+        merge_args: include_str!("../tests/expected_outputs/single_switch/merge_args.rs"),
+    };
+
+    pub const EXPECTED_SHORT_SWITCHES: ExpectedOutput = ExpectedOutput {
+        raw_config: include_str!("../tests/expected_outputs/short_switches/raw_config.rs"),
+        validate: include_str!("../tests/expected_outputs/short_switches/validate.rs"),
+        merge_args: include_str!("../tests/expected_outputs/short_switches/merge_args.rs"),
+    };
+
+    fn check(src: &str, expected: &str) {
+        let mut src = src.as_bytes();
+        let mut out = Vec::new();
+        generate_source(&mut src, &mut out).unwrap();
+        assert_eq!(::std::str::from_utf8(&out).unwrap(), expected);
+    }
+
+    #[test]
+    fn empty() {
+        check("", include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/empty-config.rs")));
+    }
+
+    #[test]
+    fn single_optional_param() {
+        check(SINGLE_OPTIONAL_PARAM, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_optional_param-config.rs")));
+    }
+
+    #[test]
+    fn single_mandatory_param() {
+        check(SINGLE_MANDATORY_PARAM, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_mandatory_param-config.rs")));
+    }
+
+    #[test]
+    fn single_default_param() {
+        check(SINGLE_DEFAULT_PARAM, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_default_param-config.rs")));
+    }
+
+    #[test]
+    fn single_switch() {
+        check(SINGLE_SWITCH, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_switch-config.rs")));
+    }
+
+    #[test]
+    fn multiple_params() {
+        check(MULTIPLE_PARAMS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/multiple_params-config.rs")));
+    }
+
+    #[test]
+    fn no_arg() {
+        check(NO_ARG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/no_arg-config.rs")));
+    }
+
+    #[test]
+    fn short_switches() {
+        check(SHORT_SWITCHES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/short_switches-config.rs")));
+    }
+
+    #[test]
+    fn conf_files() {
+        check(CONF_FILES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/conf_files-config.rs")));
+    }
+
+    #[test]
+    fn custom_merge_fn() {
+        check(CUSTOM_MERGE_FN, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/with_custom_merge-config.rs")));
+    }
+
+    #[test]
+    fn nargs() {
+        check(NARGS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/nargs-config.rs")));
+    }
+
+    #[test]
+    fn long_options() {
+        check(LONG_OPTIONS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/long_options-config.rs")));
+    }
+
+    #[test]
+    fn lenient() {
+        check(LENIENT, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/lenient-config.rs")));
+    }
+
+    #[test]
+    fn switch_values() {
+        check(SWITCH_VALUES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/switch_values-config.rs")));
+    }
+
+    #[test]
+    fn empty_env_vars() {
+        check(EMPTY_ENV_VARS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/empty_env_vars-config.rs")));
+    }
+
+    #[test]
+    fn non_utf8() {
+        check(NON_UTF8, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/non_utf8-config.rs")));
+    }
+
+    #[test]
+    fn missing_fields() {
+        check(MISSING_FIELDS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/missing_fields-config.rs")));
+    }
+
+    #[test]
+    fn duplicate_args() {
+        check(DUPLICATE_ARGS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/duplicate_args-config.rs")));
+    }
+
+    #[test]
+    fn forward_separator() {
+        check(FORWARD_SEPARATOR, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/forward_separator-config.rs")));
+    }
+
+    #[test]
+    fn explain_config() {
+        check(EXPLAIN_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/explain_config-config.rs")));
+    }
+
+    #[test]
+    fn insecure_config_files() {
+        check(INSECURE_CONFIG_FILES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/insecure_config_files-config.rs")));
+    }
+
+    #[test]
+    fn config_limits() {
+        check(CONFIG_LIMITS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/config_limits-config.rs")));
+    }
+
+    #[test]
+    fn global_config() {
+        check(GLOBAL_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/global_config-config.rs")));
+    }
+
+    #[test]
+    fn shared_config() {
+        check(SHARED_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/shared_config-config.rs")));
+    }
+
+    #[test]
+    fn export_env_vars() {
+        check(EXPORT_ENV_VARS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/export_env_vars-config.rs")));
+    }
+
+    #[test]
+    fn export_args() {
+        check(EXPORT_ARGS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/export_args-config.rs")));
+    }
+
+    #[test]
+    fn non_default_fields() {
+        check(NON_DEFAULT_FIELDS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/non_default_fields-config.rs")));
+    }
+
+    #[test]
+    fn interactive_setup() {
+        check(INTERACTIVE_SETUP, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/interactive_setup-config.rs")));
+    }
+
+    #[test]
+    fn check_config() {
+        check(CHECK_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/check_config-config.rs")));
+    }
+
+    #[test]
+    fn merge_report() {
+        check(MERGE_REPORT, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/merge_report-config.rs")));
+    }
+
+    #[test]
+    fn config_table() {
+        check(CONFIG_TABLE, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/config_table-config.rs")));
+    }
+
+    #[test]
+    fn config_table_interactive_setup() {
+        let generated = ::generate_to_string("[general]\nconfig_table = \"services.app\"\ninteractive_setup = true\n").unwrap();
+        assert!(generated.contains("wizard.table(\"services.app\");"));
+    }
+
+    #[test]
+    fn invalid_config_table() {
+        let err = ::generate_to_string("[general]\nconfig_table = \"services..app\"\n").unwrap_err();
+        assert!(err.to_string().contains("general.config_table \"services..app\" must be keys"), "{}", err);
+    }
+
+    #[test]
+    fn reload_if_changed() {
+        check(RELOAD_IF_CHANGED, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/reload_if_changed-config.rs")));
+    }
+
+    #[test]
+    fn arbitrary() {
+        check(ARBITRARY, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/arbitrary-config.rs")));
+    }
+
+    #[test]
+    fn redacted_rendering() {
+        check(REDACTED_RENDERING, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/redacted_rendering-config.rs")));
+    }
+
+    #[test]
+    fn audit() {
+        check(AUDIT, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/audit-config.rs")));
+    }
+
+    #[test]
+    fn renamed_from() {
+        check(RENAMED_FROM, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/renamed_from-config.rs")));
+    }
+
+    #[test]
+    fn renamed_from_defined() {
+        let spec = "[[param]]\nname = \"port\"\ntype = \"u16\"\nrenamed_from = [\"listen_port\"]\n\n[[param]]\nname = \"listen_port\"\ntype = \"u16\"\n";
+        let err = generate_source(&mut spec.as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"port\": renamed_from can't contain the name of a param or switch"), "{}", err);
+    }
+
+    #[test]
+    fn multiple() {
+        check(MULTIPLE, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/multiple-config.rs")));
+    }
+
+    #[test]
+    fn invalid_multiple() {
+        let err = generate_source(&mut "[[param]]\nname = \"tag\"\ntype = \"String\"\nmultiple = true\nnargs = 2\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"tag\": multiple = true can't be combined with nargs, merge_fn or duplicate_arg, use list_merge to combine the values"), "{}", err);
+
+        let err = generate_source(&mut "[[param]]\nname = \"tag\"\ntype = \"String\"\nlist_merge = \"replace\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"tag\": list_merge and env_delimiter require multiple = true"), "{}", err);
+    }
+
+    #[test]
+    fn enum_values() {
+        check(ENUM_VALUES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/enum_values-config.rs")));
+    }
+
+    #[test]
+    fn invalid_values() {
+        let err = generate_source(&mut "[[param]]\nname = \"format\"\ntype = \"Format\"\nvalues = [\"plain-text\", \"plain_text\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"format\": values must be distinct, start with a letter and consist of [a-zA-Z0-9_-]"), "{}", err);
+
+        let err = generate_source(&mut "[[param]]\nname = \"format\"\ntype = \"Format\"\nvalues = [\"1\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"format\": values must be distinct, start with a letter and consist of [a-zA-Z0-9_-]"), "{}", err);
+
+        let err = generate_source(&mut "[[param]]\nname = \"format\"\ntype = \"::std::string::String\"\nvalues = [\"json\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"format\": type of a param with values must be the name of the generated enum"), "{}", err);
+
+        let spec = "[[param]]\nname = \"input\"\ntype = \"Format\"\nvalues = [\"json\"]\n\n[[param]]\nname = \"output\"\ntype = \"Format\"\nvalues = [\"json\"]\n";
+        let err = generate_source(&mut spec.as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"output\": values of this type are already defined by another param, omit them to reuse the enum"), "{}", err);
+
+        for ty in &["Config", "Error", "ArgParseError", "Option"] {
+            let spec = format!("[[param]]\nname = \"format\"\ntype = \"{}\"\nvalues = [\"json\"]\n", ty);
+            let err = generate_source(&mut spec.as_bytes(), Vec::new()).unwrap_err();
+            assert!(err.to_string().ends_with("param \"format\": the name of the generated enum is already used by a generated item or a standard type"), "{}", err);
+        }
+
+        let spec = "[[param]]\nname = \"format\"\ntype = \"Database\"\nvalues = [\"json\"]\n\n[[section]]\nname = \"database\"\n";
+        let err = generate_source(&mut spec.as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"format\": the name of the generated enum is already used by a generated item or a standard type"), "{}", err);
+    }
+
+    #[test]
+    fn sections() {
+        check(SECTIONS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/sections-config.rs")));
+    }
+
+    #[test]
+    fn section_conflicts() {
+        let spec = "[[param]]\nname = \"database_url\"\ntype = \"String\"\n\n[[section]]\nname = \"database\"\n\n[[section.param]]\nname = \"url\"\ntype = \"String\"\n";
+        let err = generate_source(&mut spec.as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"database_url\": the name prefixed by the name of the section is already used by a param or switch"), "{}", err);
+
+        let spec = "[[switch]]\nname = \"database\"\n\n[[section]]\nname = \"database\"\n";
+        let err = generate_source(&mut spec.as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("section \"database\": the name is already used by a param, switch or another section"), "{}", err);
+    }
+
+    #[test]
+    fn map() {
+        check(MAP, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/map-config.rs")));
+    }
+
+    #[test]
+    fn invalid_map() {
+        let err = generate_source(&mut "[[param]]\nname = \"label\"\ntype = \"String\"\nmap = true\nmultiple = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"label\": map = true can't be combined with nargs, multiple, merge_fn or duplicate_arg, the entries are merged by their keys"), "{}", err);
+
+        let map = "[general]\nenv_prefix = \"app\"\n\n[[param]]\nname = \"label\"\ntype = \"String\"\nmap = true\n\n";
+        let err = generate_source(&mut format!("{}[[switch]]\nname = \"label_all\"\n", map).as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"label\": the environment variables of the map would include the one of \"label_all\", rename one of them or set env_var = false"), "{}", err);
+        generate_source(&mut format!("{}[[switch]]\nname = \"label_all\"\nenv_var = false\n", map).as_bytes(), Vec::new()).unwrap();
+    }
+
+    #[test]
+    fn positional() {
+        check(POSITIONAL, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/positional-config.rs")));
+    }
+
+    #[test]
+    fn invalid_positionals() {
+        let err = generate_source(&mut "[[param]]\nname = \"input\"\ntype = \"String\"\n\n[[positional]]\nname = \"input\"\ntype = \"String\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("positional \"input\": the name is already used by a param, switch, section or another positional"), "{}", err);
+
+        let err = generate_source(&mut "[[positional]]\nname = \"files\"\ntype = \"String\"\nvariadic = true\n\n[[positional]]\nname = \"output\"\ntype = \"String\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("positional \"files\": only the last positional can be variadic"), "{}", err);
+
+        let err = generate_source(&mut "[[positional]]\nname = \"input\"\ntype = \"String\"\noptional = true\n\n[[positional]]\nname = \"output\"\ntype = \"String\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("positional \"output\": a mandatory positional can't follow an optional one"), "{}", err);
+    }
+
+    #[test]
+    fn subcommands() {
+        check(SUBCOMMANDS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/subcommands-config.rs")));
+    }
+
+    #[test]
+    fn invalid_subcommands() {
+        let err = generate_source(&mut "[[subcommand]]\nname = \"serve\"\n\n[[subcommand]]\nname = \"serve\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("subcommand \"serve\": another subcommand has the same name"), "{}", err);
+
+        let err = generate_source(&mut "[[positional]]\nname = \"input\"\ntype = \"String\"\n\n[[subcommand]]\nname = \"serve\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("subcommands can't be combined with positionals, the first argument after the options selects the subcommand"), "{}", err);
+
+        let err = generate_source(&mut "[[switch]]\nname = \"subcommand\"\n\n[[subcommand]]\nname = \"serve\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("the field `subcommand` holding the selected subcommand is already used by a param or switch"), "{}", err);
+
+        let err = generate_source(&mut "[[subcommand]]\nname = \"raw\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("subcommand \"raw\": raw and prelude are reserved for the generated modules"), "{}", err);
+
+        let err = generate_source(&mut "[[subcommand]]\nname = \"serve\"\nfile = \"serve.toml\"\n\n[[subcommand.switch]]\nname = \"tls\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("file can't be combined with params, switches or positionals of the subcommand, declare them in the file"), "{}", err);
+    }
+
+    #[test]
+    fn aliases() {
+        check(ALIASES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/aliases-config.rs")));
+    }
+
+    #[test]
+    fn alias_defined() {
+        let err = generate_source(&mut "[[param]]\nname = \"color\"\ntype = \"String\"\naliases = [\"quiet\"]\n\n[[switch]]\nname = \"quiet\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"color\": aliases can't contain the name of a param, switch, an old name or another alias"), "{}", err);
+
+        let err = generate_source(&mut "[[param]]\nname = \"color\"\ntype = \"String\"\naliases = [\"colour\"]\n\n[[switch]]\nname = \"quiet\"\naliases = [\"colour\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"color\": aliases can't contain the name of a param, switch, an old name or another alias"), "{}", err);
+
+        let err = generate_source(&mut "[[param]]\nname = \"color\"\ntype = \"String\"\nrenamed_from = [\"colour\"]\naliases = [\"colour\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"color\": aliases can't contain the name of a param, switch, an old name or another alias"), "{}", err);
+    }
+
+    #[test]
+    fn ranges() {
+        check(RANGES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/ranges-config.rs")));
+    }
+
+    #[test]
+    fn invalid_ranges() {
+        let err = generate_source(&mut "[[param]]\nname = \"name\"\ntype = \"String\"\nmin = 1\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"name\": min and max require a param of a primitive numeric type taking a single value"), "{}", err);
+
+        let err = generate_source(&mut "[[param]]\nname = \"port\"\ntype = \"u16\"\nmultiple = true\nmax = 10\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"port\": min and max require a param of a primitive numeric type taking a single value"), "{}", err);
+
+        for bounds in &["min = 0.5", "max = 256", "min = -1", "min = 10\nmax = 5"] {
+            let src = format!("[[param]]\nname = \"level\"\ntype = \"u8\"\n{}\n", bounds);
+            let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+            assert!(err.to_string().ends_with("param \"level\": min and max of an integer param must be integers fitting into its type and min can't exceed max"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn patterns() {
+        check(PATTERNS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/patterns-config.rs")));
+    }
+
+    #[test]
+    fn invalid_patterns() {
+        for src in &["[[param]]\nname = \"slug\"\ntype = \"u32\"\nvalidate_regex = \"^[0-9]+$\"\n", "[[param]]\nname = \"slug\"\ntype = \"String\"\nnargs = 2\nvalidate_regex = \"^[a-z]+$\"\n"] {
+            let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+            assert!(err.to_string().ends_with("param \"slug\": validate_regex requires a param of type String taking a single value or multiple = true"), "{}", err);
+        }
+
+        let err = generate_source(&mut "[[param]]\nname = \"slug\"\ntype = \"String\"\nvalidate_regex = \"^[a-z+$\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("param \"slug\": `validate_regex` is not a valid regular expression: "), "{}", err);
+    }
+
+    #[test]
+    fn relations() {
+        check(RELATIONS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/relations-config.rs")));
+    }
+
+    #[test]
+    fn unknown_relations() {
+        let srcs = [
+            "[[param]]\nname = \"tls_cert\"\ntype = \"String\"\nrequires = [\"tls_key\"]\n",
+            "[[param]]\nname = \"tls_cert\"\ntype = \"String\"\nconflicts_with = [\"tls_cert\"]\n",
+            "[[param]]\nname = \"tls_cert\"\ntype = \"String\"\nrequires = [\"path\"]\n\n[[positional]]\nname = \"path\"\ntype = \"String\"\n",
+        ];
+        for src in &srcs {
+            let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+            assert!(err.to_string().ends_with("param \"tls_cert\": requires and conflicts_with must contain names of other params or switches"), "{}", err);
+        }
+
+        let err = generate_source(&mut "[[switch]]\nname = \"insecure\"\nconflicts_with = [\"tls\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"insecure\": requires and conflicts_with must contain names of other params or switches"), "{}", err);
+    }
+
+    #[test]
+    fn groups() {
+        check(GROUPS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/groups-config.rs")));
+    }
+
+    #[test]
+    fn invalid_groups() {
+        let params = "[[param]]\nname = \"token\"\ntype = \"String\"\n\n[[switch]]\nname = \"json\"\n\n";
+        let cases = [
+            ("[[group]]\nname = \"auth\"\nmembers = []\n", "members must be distinct names of params or switches"),
+            ("[[group]]\nname = \"auth\"\nmembers = [\"token\", \"password\"]\n", "members must be distinct names of params or switches"),
+            ("[[group]]\nname = \"auth\"\nmembers = [\"token\", \"token\"]\n", "members must be distinct names of params or switches"),
+            ("[[group]]\nname = \"auth\"\nmembers = [\"token\", \"json\"]\nmultiple = true\n", "a group allowing multiple members must be required, otherwise it doesn't check anything"),
+            ("[[group]]\nname = \"auth\"\nmembers = [\"token\", \"json\"]\n\n[[group]]\nname = \"auth\"\nmembers = [\"token\"]\nrequired = true\n", "another group has the same name"),
+        ];
+        for (group, message) in &cases {
+            let src = format!("{}{}", params, group);
+            let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+            assert!(err.to_string().ends_with(&format!("group \"auth\": {}", message)), "{}", err);
+        }
+    }
+
+    #[test]
+    fn validators() {
+        check(VALIDATORS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/validators-config.rs")));
+    }
+
+    #[test]
+    fn invalid_validate_fn() {
+        let err = generate_source(&mut "[[param]]\nname = \"port\"\ntype = \"u16\"\nvalidate = \"check port\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("param \"port\": `validate` is not a valid path of a function: "), "{}", err);
+    }
+
+    #[test]
+    fn validate_config() {
+        check(VALIDATE_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/validate_config-config.rs")));
+    }
+
+    #[test]
+    fn invalid_general_validate() {
+        let err = generate_source(&mut "[general]\nvalidate = \"check config\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "general.validate \"check config\" is not a valid path of a function");
+    }
+
+    #[test]
+    fn secrets() {
+        check(SECRETS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/secrets-config.rs")));
+    }
+
+    #[test]
+    fn durations() {
+        check(DURATIONS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/durations-config.rs")));
+    }
+
+    #[test]
+    fn invalid_duration_multiple() {
+        let err = generate_source(&mut "[[param]]\nname = \"timeouts\"\ntype = \"duration\"\nmultiple = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("params of type duration take a single value, they can't use nargs, multiple or map"), "{}", err);
+    }
+
+    #[test]
+    fn sizes() {
+        check(SIZES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/sizes-config.rs")));
+    }
+
+    #[test]
+    fn invalid_size_multiple() {
+        let err = generate_source(&mut "[[param]]\nname = \"limits\"\ntype = \"size\"\nnargs = 2\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("params of type size take a single value, they can't use nargs, multiple or map"), "{}", err);
+    }
+
+    #[test]
+    fn default_fn() {
+        check(DEFAULT_FN, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/default_fn-config.rs")));
+    }
+
+    #[test]
+    fn invalid_default_fn() {
+        let err = generate_source(&mut "[[param]]\nname = \"workers\"\ntype = \"usize\"\ndefault = \"4\"\ndefault_fn = \"default_workers\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"workers\": default and default_fn can't be combined"), "{}", err);
+        let err = generate_source(&mut "[[param]]\nname = \"workers\"\ntype = \"usize\"\ndefault_fn = \"default workers\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("param \"workers\": `default_fn` is not a valid path of a function"), "{}", err);
+    }
+
+    #[test]
+    fn count_switches() {
+        check(COUNT_SWITCHES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/count_switches-config.rs")));
+    }
+
+    #[test]
+    fn invalid_count_switch() {
+        let err = generate_source(&mut "[[switch]]\nname = \"verbose\"\nmax_count = 3\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"verbose\": max_count, map_to, values and map_values require count = true"), "{}", err);
+        let err = generate_source(&mut "[[switch]]\nname = \"verbose\"\ncount = true\nmax_count = 0\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"verbose\": max_count must be at least 1"), "{}", err);
+        let err = generate_source(&mut "[[switch]]\nname = \"verbose\"\ncount = true\nmap_to = \"Verbosity\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"verbose\": map_to requires either values naming the variants of a generated enum or map_values, the values for the counts"), "{}", err);
+        let err = generate_source(&mut "[[switch]]\nname = \"verbose\"\ncount = true\nmap_to = \"log::Level\"\nvalues = [\"warn\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"verbose\": map_to of a switch with values must be the name of the generated enum"), "{}", err);
+    }
+
+    #[test]
+    fn json_config() {
+        check(JSON_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/json_config-config.rs")));
+    }
+
+    #[test]
+    fn env_file() {
+        check(ENV_FILE, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/env_file-config.rs")));
+    }
+
+    #[test]
+    fn drop_ins() {
+        check(DROP_INS, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/drop_ins-config.rs")));
+    }
+
+    #[test]
+    fn config_includes() {
+        check(CONFIG_INCLUDES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/config_includes-config.rs")));
+    }
+
+    #[test]
+    fn loaded_config_files() {
+        check(LOADED_CONFIG_FILES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/loaded_config_files-config.rs")));
+    }
+
+    #[test]
+    fn remote_config() {
+        check(REMOTE_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/remote_config-config.rs")));
+    }
+
+    #[test]
+    fn profiles() {
+        check(PROFILES, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/profiles-config.rs")));
+    }
+
+    #[test]
+    fn watch_config() {
+        check(WATCH_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/watch_config-config.rs")));
+    }
+
+    #[test]
+    fn dump_config() {
+        check(DUMP_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/dump_config-config.rs")));
+    }
+
+    #[test]
+    fn serialize() {
+        check(SERIALIZE, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/serialize-config.rs")));
+    }
+
+    #[test]
+    fn partial_config() {
+        check(PARTIAL_CONFIG, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/partial_config-config.rs")));
+    }
+
+    #[test]
+    fn version() {
+        check(VERSION, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/version-config.rs")));
+    }
+
+    #[test]
+    fn negatable() {
+        check(NEGATABLE, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/negatable-config.rs")));
+    }
+
+    #[test]
+    fn trailing() {
+        check(TRAILING, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/trailing-config.rs")));
+    }
+
+    #[test]
+    fn rich_help() {
+        check(RICH_HELP, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/rich_help-config.rs")));
+    }
+
+    #[test]
+    fn reserved_include() {
+        let err = generate_source(&mut "[general]\nconfig_includes = true\n\n[[param]]\nname = \"include\"\ntype = \"String\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"include\": include is reserved for the files included by config files when general.config_includes is set"), "{}", err);
+        let err = generate_source(&mut "[general]\nconfig_includes = true\n\n[[switch]]\nname = \"verbose\"\naliases = [\"include\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"verbose\": include is reserved for the files included by config files when general.config_includes is set"), "{}", err);
+        assert!(generate_source(&mut "[[param]]\nname = \"include\"\ntype = \"String\"\n".as_bytes(), Vec::new()).is_ok());
+    }
+
+    #[test]
+    fn reserved_profile() {
+        let err = generate_source(&mut "[general]\nprofile_param = \"env\"\n\n[[param]]\nname = \"profile\"\ntype = \"String\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"profile\": profile is reserved for the profiles of config files when general.profile_param is set"), "{}", err);
+        let err = generate_source(&mut "[general]\nprofile_param = \"env\"\n\n[[switch]]\nname = \"verbose\"\naliases = [\"profile\"]\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"verbose\": profile is reserved for the profiles of config files when general.profile_param is set"), "{}", err);
+        assert!(generate_source(&mut "[[param]]\nname = \"profile\"\ntype = \"String\"\n".as_bytes(), Vec::new()).is_ok());
+    }
+
+    #[test]
+    fn reserved_version() {
+        let err = generate_source(&mut "[general]\nversion = true\n\n[[switch]]\nname = \"version\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"version\": version and the short option V are reserved for --version when general.version is set"), "{}", err);
+        let err = generate_source(&mut "[general]\nversion = \"1.0.0\"\n\n[[param]]\nname = \"verbosity\"\ntype = \"u8\"\nabbr = \"V\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("param \"verbosity\": version and the short option V are reserved for --version when general.version is set"), "{}", err);
+        assert!(generate_source(&mut "[general]\nversion = false\n\n[[switch]]\nname = \"version\"\n".as_bytes(), Vec::new()).is_ok());
+    }
+
+    #[test]
+    fn negatable_requires_normal_switch() {
+        let err = generate_source(&mut "[[switch]]\nname = \"debug\"\ncount = true\nnegatable = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"debug\": negatable requires a switch that is neither inverted nor count"), "{}", err);
+        let err = generate_source(&mut "[[switch]]\nname = \"cache\"\ndefault = true\nnegatable = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("switch \"cache\": negatable requires a switch that is neither inverted nor count"), "{}", err);
+    }
+
+    #[test]
+    fn trailing_requires_variadic() {
+        let err = generate_source(&mut "[[positional]]\nname = \"args\"\ntype = \"String\"\ntrailing = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("positional \"args\": trailing requires variadic = true"), "{}", err);
+    }
+
+    #[test]
+    fn user_config() {
+        let mut out = Vec::new();
+        generate_source(&mut USER_CONFIG.as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("    pub fn including_user_config() -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> {\n        Self::including_optional_config_files(::configure_me::helpers::user_config_files(\"user_config\"))\n    }\n"), "{}", out);
+    }
+
+    #[test]
+    fn user_config_without_name() {
+        let err = generate_source(&mut "[general]\nuser_config = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("general.user_config requires general.name, which names the directories of the config files"), "{}", err);
+    }
+
+    #[test]
+    fn watch_config_without_reload() {
+        let err = generate_source(&mut "[general]\nwatch_config = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().ends_with("general.watch_config requires general.reload_if_changed, which keeps the sources of the configuration"), "{}", err);
+    }
+
+    #[test]
+    fn duplicate_arg_with_merge_fn() {
+        let src = "[[param]]\nname = \"tag\"\ntype = \"String\"\nmerge_fn = \"(|a: &mut String, b: String| a.push_str(&b))\"\nduplicate_arg = \"error\"\n";
+        let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("duplicate_arg can't be set on a param with merge_fn or a count switch"), "{}", err);
+    }
+
+    #[test]
+    fn ambiguous_switch_value() {
+        let src = "[general]\ntrue_values = [\"yes\"]\nfalse_values = [\"no\", \"yes\"]\n\n[[switch]]\nname = \"verbose\"\n";
+        let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "\"yes\" is in both general.true_values and general.false_values");
+    }
+
+    #[test]
+    fn defaults_fields() {
+        let spec = ::load(&mut DEFAULTS_FIELDS.as_bytes()).unwrap();
+        assert!(spec.deprecations.is_empty());
+        assert!(!spec.params[0].argument);
+        assert!(spec.params[0].env_var);
+    }
+
+    const RENAMED_ARGUMENT: &[::legacy::Renamed] = &[::legacy::Renamed { section: "param", old: "arg", new: "argument" }];
+
+    #[test]
+    fn renamed_field() {
+        let src = "[[param]]\nname = \"foo\"\ntype = \"u32\"\narg = false\n\n[[param]]\nname = \"bar\"\ntype = \"u32\"\n";
+        let mut deprecations = Vec::new();
+        let migrated = ::legacy::migrate(src, RENAMED_ARGUMENT, &mut deprecations).unwrap().unwrap();
+        let deprecations = deprecations.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(deprecations, ["`param.arg` is deprecated and will be removed in the next release, use `param.argument` instead"]);
+        let spec = ::load(&mut migrated.as_bytes()).unwrap();
+        assert!(!spec.params[0].argument);
+        assert!(spec.params[1].argument);
+    }
+
+    #[test]
+    fn unused_renamed_field() {
+        let mut deprecations = Vec::new();
+        assert!(::legacy::migrate(SINGLE_OPTIONAL_PARAM, RENAMED_ARGUMENT, &mut deprecations).unwrap().is_none());
+        assert!(deprecations.is_empty());
+    }
+
+    #[test]
+    fn renamed_field_with_new_field() {
+        let src = "[[param]]\nname = \"foo\"\ntype = \"u32\"\narg = false\nargument = true\n";
+        let err = ::legacy::migrate(src, RENAMED_ARGUMENT, &mut Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "both `param.arg` and `param.argument` are specified, remove the deprecated one");
+    }
 
     #[test]
-    fn custom_merge_fn() {
-        check(CUSTOM_MERGE_FN, include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/with_custom_merge-config.rs")));
+    fn skips_unchanged_spec() {
+        use std::io::Write;
+
+        let out_dir = ::std::env::temp_dir().join(format!("configure_me_stamp_test_{}", ::std::process::id()));
+        ::std::fs::create_dir_all(&out_dir).unwrap();
+        let out_file = out_dir.join("configure_me_config.rs");
+        let _ = ::std::fs::remove_file(&out_file);
+
+        let spec = ::load(&mut SINGLE_OPTIONAL_PARAM.as_bytes()).unwrap();
+        ::generate_to_file_if_changed(&spec, &out_file).unwrap();
+        assert!(out_dir.join("configure_me_config.rs.stamp").exists());
+
+        // Modify the output to detect whether it gets rewritten
+        ::std::fs::OpenOptions::new().append(true).open(&out_file).unwrap().write_all(b"// untouched").unwrap();
+        ::generate_to_file_if_changed(&spec, &out_file).unwrap();
+        assert!(::std::fs::read_to_string(&out_file).unwrap().ends_with("// untouched"));
+
+        let spec = ::load(&mut SINGLE_MANDATORY_PARAM.as_bytes()).unwrap();
+        ::generate_to_file_if_changed(&spec, &out_file).unwrap();
+        assert!(!::std::fs::read_to_string(&out_file).unwrap().ends_with("// untouched"));
+
+        ::std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn keeps_unchanged_outputs() {
+        let out_dir = ::std::env::temp_dir().join(format!("configure_me_unchanged_outputs_test_{}", ::std::process::id()));
+        ::std::fs::create_dir_all(&out_dir).unwrap();
+        let spec_file = out_dir.join("config_spec.toml");
+        ::std::fs::write(&spec_file, SINGLE_OPTIONAL_PARAM).unwrap();
+        let modified = |name: &str| ::std::fs::metadata(out_dir.join(name)).unwrap().modified().unwrap();
+
+        let generate = || ::load_and_generate(&spec_file, out_dir.join("configure_me_config.rs"), None, ::UnknownFields::Deny, true).unwrap();
+
+        generate();
+        let params = modified("params.json");
+        ::std::fs::write(out_dir.join("options.md"), "outdated").unwrap();
+        generate();
+        assert_eq!(modified("params.json"), params);
+        assert_ne!(::std::fs::read_to_string(out_dir.join("options.md")).unwrap(), "outdated");
+
+        ::std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn generate_to_dir() {
+        let out_dir = ::std::env::temp_dir().join(format!("configure_me_generate_to_test_{}", ::std::process::id()));
+        ::std::fs::create_dir_all(&out_dir).unwrap();
+        let spec_file = out_dir.join("config_spec.toml");
+        ::std::fs::write(&spec_file, SINGLE_OPTIONAL_PARAM).unwrap();
+
+        ::generate_to(&spec_file, &out_dir).unwrap();
+        ::generate_to(&spec_file, out_dir.join("explicit.rs")).unwrap();
+
+        let expected = include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/single_optional_param-config.rs"));
+        assert_eq!(::std::fs::read_to_string(out_dir.join("configure_me_config.rs")).unwrap(), expected);
+        assert_eq!(::std::fs::read_to_string(out_dir.join("explicit.rs")).unwrap(), expected);
+
+        ::std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn error_location() {
+        let src = "[[param]]\nname = \"foo\"\ntype = \"u32\"\noptional = false\ndefault = \"42\"\n";
+        let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "5:11: param \"foo\": default conflicts with optional = false");
+
+        let err = ::load(&mut src.as_bytes()).err().unwrap().in_file("config_spec.toml".as_ref());
+        assert_eq!(err.to_string(), "config_spec.toml:5:11: param \"foo\": default conflicts with optional = false");
+
+        let src = "[[switch]]\nname = \"foo\"\nabbr = \"1\"\n";
+        let err = generate_source(&mut src.as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "3:8: switch \"foo\": invalid short switch: must be [a-zA-Z]");
+    }
+
+    #[test]
+    fn generate_to_string() {
+        let expected = include_str!(concat!(env!("OUT_DIR"), "/expected_outputs/multiple_params-config.rs"));
+        assert_eq!(::generate_to_string(MULTIPLE_PARAMS).unwrap(), expected);
+        assert_eq!(::generate_to_string("[[param]]\nname = \"foo\"\n").unwrap_err().to_string(), "failed to parse config specification: missing field `type` for key `param`");
+    }
+
+    #[test]
+    fn schema_covers_all_fields() {
+        fn check_keys(value: &::toml::Value) {
+            match value {
+                ::toml::Value::Table(table) => for (key, value) in table {
+                    assert!(::SPEC_SCHEMA.contains(&format!("\"{}\":", key)), "field {} is missing in the schema", key);
+                    check_keys(value);
+                },
+                ::toml::Value::Array(items) => items.iter().for_each(check_keys),
+                _ => (),
+            }
+        }
+
+        ::validate_spec(ALL_FIELDS.as_bytes()).unwrap();
+        check_keys(&ALL_FIELDS.parse().unwrap());
+        check_keys(&EXTENDS_CHILD.parse().unwrap());
+        check_keys(&INCLUDE_FRAGMENTS.parse().unwrap());
+        ::validate_spec(SUBCOMMANDS.as_bytes()).unwrap();
+        check_keys(&SUBCOMMANDS.parse().unwrap());
+        ::validate_spec(ALIASES.as_bytes()).unwrap();
+        check_keys(&ALIASES.parse().unwrap());
+        ::validate_spec(RANGES.as_bytes()).unwrap();
+        check_keys(&RANGES.parse().unwrap());
+        ::validate_spec(PATTERNS.as_bytes()).unwrap();
+        check_keys(&PATTERNS.parse().unwrap());
+        ::validate_spec(RELATIONS.as_bytes()).unwrap();
+        check_keys(&RELATIONS.parse().unwrap());
+        ::validate_spec(GROUPS.as_bytes()).unwrap();
+        check_keys(&GROUPS.parse().unwrap());
+        ::validate_spec(VALIDATORS.as_bytes()).unwrap();
+        check_keys(&VALIDATORS.parse().unwrap());
+        ::validate_spec(VALIDATE_CONFIG.as_bytes()).unwrap();
+        check_keys(&VALIDATE_CONFIG.parse().unwrap());
+        ::validate_spec(SECRETS.as_bytes()).unwrap();
+        check_keys(&SECRETS.parse().unwrap());
+        ::validate_spec(DURATIONS.as_bytes()).unwrap();
+        check_keys(&DURATIONS.parse().unwrap());
+        ::validate_spec(SIZES.as_bytes()).unwrap();
+        check_keys(&SIZES.parse().unwrap());
+        ::validate_spec(DEFAULT_FN.as_bytes()).unwrap();
+        check_keys(&DEFAULT_FN.parse().unwrap());
+        ::validate_spec(COUNT_SWITCHES.as_bytes()).unwrap();
+        check_keys(&COUNT_SWITCHES.parse().unwrap());
+        ::validate_spec(JSON_CONFIG.as_bytes()).unwrap();
+        check_keys(&JSON_CONFIG.parse().unwrap());
+        ::validate_spec(ENV_FILE.as_bytes()).unwrap();
+        check_keys(&ENV_FILE.parse().unwrap());
+        ::validate_spec(USER_CONFIG.as_bytes()).unwrap();
+        check_keys(&USER_CONFIG.parse().unwrap());
+        ::validate_spec(DROP_INS.as_bytes()).unwrap();
+        check_keys(&DROP_INS.parse().unwrap());
+        ::validate_spec(CONFIG_INCLUDES.as_bytes()).unwrap();
+        check_keys(&CONFIG_INCLUDES.parse().unwrap());
+        ::validate_spec(LOADED_CONFIG_FILES.as_bytes()).unwrap();
+        check_keys(&LOADED_CONFIG_FILES.parse().unwrap());
+        ::validate_spec(REMOTE_CONFIG.as_bytes()).unwrap();
+        check_keys(&REMOTE_CONFIG.parse().unwrap());
+        ::validate_spec(PROFILES.as_bytes()).unwrap();
+        check_keys(&PROFILES.parse().unwrap());
+        ::validate_spec(WATCH_CONFIG.as_bytes()).unwrap();
+        check_keys(&WATCH_CONFIG.parse().unwrap());
+        ::validate_spec(DUMP_CONFIG.as_bytes()).unwrap();
+        check_keys(&DUMP_CONFIG.parse().unwrap());
+        ::validate_spec(SERIALIZE.as_bytes()).unwrap();
+        check_keys(&SERIALIZE.parse().unwrap());
+        ::validate_spec(PARTIAL_CONFIG.as_bytes()).unwrap();
+        check_keys(&PARTIAL_CONFIG.parse().unwrap());
+        ::validate_spec(VERSION.as_bytes()).unwrap();
+        check_keys(&VERSION.parse().unwrap());
+        ::validate_spec(NEGATABLE.as_bytes()).unwrap();
+        check_keys(&NEGATABLE.parse().unwrap());
+        ::validate_spec(TRAILING.as_bytes()).unwrap();
+        check_keys(&TRAILING.parse().unwrap());
+        ::validate_spec(RICH_HELP.as_bytes()).unwrap();
+        check_keys(&RICH_HELP.parse().unwrap());
+    }
+
+    #[test]
+    fn require_doc() {
+        let mut src = REQUIRE_DOC.as_bytes();
+        let err = generate_source(&mut src, Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "documentation is required (general.require_doc = true), but these fields are missing doc: bar, baz");
+    }
+
+    #[test]
+    fn require_explicit_optional() {
+        let mut src = REQUIRE_EXPLICIT_OPTIONAL.as_bytes();
+        let err = generate_source(&mut src, Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "explicit optionality is required (general.require_explicit_optional = true), but these params have neither optional nor default: bar, qux");
+    }
+
+    #[test]
+    fn select_binaries() {
+        let binaries = [("foo", "foo.toml"), ("bar", "bar.toml")].iter().map(|(binary, path)| (binary.to_string(), ::std::path::PathBuf::from(path))).collect::<::std::collections::HashMap<_, _>>();
+        assert_eq!(::select_binaries(binaries.clone(), None).unwrap().len(), 2);
+        assert_eq!(::select_binaries(binaries.clone(), Some(&["bar"])).unwrap(), [("bar".to_owned(), "bar.toml".into())]);
+        assert_eq!(::select_binaries(binaries.clone(), Some(&[])).unwrap(), []);
+        let err = ::select_binaries(binaries, Some(&["bar", "baz"])).unwrap_err();
+        assert_eq!(err.to_string(), "binary `baz` has no specification in `package.metadata.configure_me.bin`");
+    }
+
+    #[test]
+    fn init() {
+        use init::StarterParam;
+
+        let crate_dir = ::std::env::temp_dir().join(format!("configure_me_init_test_{}", ::std::process::id()));
+        ::std::fs::create_dir_all(&crate_dir).unwrap();
+        ::std::fs::write(crate_dir.join("Cargo.toml"), "[package]\nname = \"foo\"\nversion = \"0.1.0\"").unwrap();
+
+        let params = [
+            StarterParam { name: "port".to_owned(), ty: "u16".to_owned(), optional: false, doc: Some("Port to \"listen\" on".to_owned()), },
+            StarterParam { name: "bind_addr".to_owned(), ty: "::std::net::IpAddr".to_owned(), optional: true, doc: None, },
+        ];
+        ::init::init(&crate_dir, &params).unwrap();
+
+        let spec = ::load_from_file(crate_dir.join("config_spec.toml")).unwrap();
+        assert_eq!(spec.params.len(), 2);
+        assert!(::std::fs::read_to_string(crate_dir.join("build.rs")).unwrap().contains("build_script_auto()"));
+        let manifest = ::std::fs::read_to_string(crate_dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.ends_with("version = \"0.1.0\"\n\n[package.metadata.configure_me]\nspec = \"config_spec.toml\"\n"));
+
+        let err = ::init::init(&crate_dir, &params).unwrap_err();
+        assert!(err.to_string().starts_with("refusing to overwrite"));
+
+        ::std::fs::remove_dir_all(&crate_dir).unwrap();
+    }
+
+    #[test]
+    fn clap_import() {
+        let json = r#"{
+            "name": "server",
+            "about": "Serves files",
+            "args": [
+                { "id": "port", "long": "port", "short": "p", "help": "Port to listen on", "required": true, "type": "u16", "env": "SERVER_PORT" },
+                { "id": "root", "long": "root-dir", "default_values": ["/srv"], "type": "::std::path::PathBuf", "env": "SERVER_ROOT_DIR" },
+                { "id": "peers", "long": "peer", "action": "Append", "num_args": { "min": 1, "max": 18446744073709551615 }, "env": "PEERS" },
+                { "id": "verbose", "long": "verbose", "short": "v", "action": "Count" },
+                { "id": "color", "long": "no-color", "short": "C", "action": "SetFalse" },
+                { "id": "file", "action": "Set" },
+                { "id": "help", "long": "help", "short": "h", "action": "Help" }
+            ]
+        }"#;
+        let spec = ::clap_import::spec_from_json(json.as_bytes()).unwrap();
+        assert_eq!(spec, r#"# Converted from the clap command `server`, review the notes
+
+[general]
+name = "server"
+summary = "Serves files"
+env_prefix = "SERVER"
+
+[defaults]
+env_vars = false
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+abbr = "p"
+doc = "Port to listen on"
+env_var = true
+
+[[param]]
+name = "root_dir"
+type = "::std::path::PathBuf"
+default = "\"/srv\".into()"
+env_var = true
+
+# Note: the environment variable PEERS doesn't match the others, it's not read
+# Note: clap appends the values of repeated arguments, only the last one is kept unless `merge_fn` is set
+[[param]]
+name = "peer"
+type = "String"
+nargs = { min = 1, max = 4294967295 }
+optional = true
+
+[[switch]]
+name = "verbose"
+count = true
+abbr = "v"
+
+# Note: inverted switches can't have short options, `-C` is dropped
+[[switch]]
+name = "color"
+default = true
+
+# Note: the positional argument `file` is returned among the remaining arguments
+
+"#);
+
+        let err = ::clap_import::spec_from_json(&b"{\"args\": {}}"[..]).unwrap_err();
+        assert!(err.to_string().starts_with("failed to parse the description of the clap command: invalid type: map, expected a sequence"), "{}", err);
+    }
+
+    #[test]
+    fn derive_import() {
+        let source = r#"
+            use clap::{ArgAction, Parser};
+
+            /// Serves files
+            ///
+            /// Longer description.
+            #[derive(Debug, Parser)]
+            #[command(name = "server", version)]
+            struct Args {
+                /// Port to listen on
+                #[arg(short, long, env = "SERVER_PORT")]
+                port: u16,
+
+                #[arg(long = "root-dir", default_value = "/srv", env)]
+                root: std::path::PathBuf,
+
+                #[arg(long, value_name = "ADDR")]
+                peers: Vec<String>,
+
+                #[arg(short, long, action = ArgAction::Count)]
+                verbose: u8,
+
+                #[arg(long)]
+                timeout: Option<u64>,
+
+                #[command(flatten)]
+                logging: Logging,
+            }
+        "#;
+        let migration = ::derive_import::spec_from_derive(source).unwrap();
+        assert_eq!(migration.spec, r#"# Converted from the clap command `server`, review the notes
+
+[general]
+name = "server"
+summary = "Serves files"
+env_prefix = "SERVER"
+
+[defaults]
+env_vars = false
+
+[[param]]
+name = "port"
+type = "u16"
+optional = false
+abbr = "p"
+doc = "Port to listen on"
+env_var = true
+
+# Note: the environment variable ROOT doesn't match the others, it's not read
+[[param]]
+name = "root_dir"
+type = "std::path::PathBuf"
+default = "\"/srv\".into()"
+
+# Note: clap appends the values of repeated arguments, only the last one is kept unless `merge_fn` is set
+[[param]]
+name = "peers"
+type = "String"
+optional = true
+
+[[switch]]
+name = "verbose"
+count = true
+abbr = "v"
+
+[[param]]
+name = "timeout"
+type = "u64"
+optional = true
+
+"#);
+        assert_eq!(migration.unsupported, [
+            "struct `Args`: `version` isn't supported",
+            "field `peers`: `value_name` isn't supported",
+            "field `logging`: flattened structs and subcommands aren't supported, the field is skipped",
+        ]);
+
+        let err = ::derive_import::spec_from_derive("struct Args { port: u16 }").unwrap_err();
+        assert_eq!(err.to_string(), "no struct deriving Parser or StructOpt found");
+    }
+
+    #[test]
+    fn extends() {
+        use config::Optionality;
+
+        let dir = ::std::env::temp_dir().join(format!("configure_me_extends_test_{}", ::std::process::id()));
+        ::std::fs::create_dir_all(dir.join("child")).unwrap();
+        let child_file = dir.join("child").join("config_spec.toml");
+        ::std::fs::write(dir.join("child").join("base.toml"), EXTENDS_BASE).unwrap();
+        ::std::fs::write(&child_file, EXTENDS_CHILD).unwrap();
+
+        let spec = ::load_from_file(&child_file).unwrap();
+        assert_eq!(spec.general.env_prefix.as_ref().unwrap(), "CHILD");
+        assert_eq!(spec.general.summary.as_ref().unwrap(), "Base app");
+        let params = spec.params.iter().map(|param| param.name.as_snake_case()).collect::<Vec<_>>();
+        assert_eq!(params, ["port", "timeout", "bind_addr"]);
+        match &spec.params[0].optionality {
+            Optionality::DefaultValue(default) => assert_eq!(default, "8080"),
+            _ => panic!("port should have a default value"),
+        }
+        assert!(matches!(spec.params[1].optionality, Optionality::Optional));
+        assert_eq!(spec.params[1].doc.as_ref().unwrap(), "Timeout in seconds");
+        assert_eq!(spec.switches[0].doc.as_ref().unwrap(), "Be verbose");
+        assert_eq!(spec.extended_files, [dir.join("child").join("base.toml")]);
+
+        let check_err = |child: &str, expected: &str| {
+            ::std::fs::write(&child_file, child).unwrap();
+            assert_eq!(::load_from_file(&child_file).err().unwrap().to_string(), expected);
+        };
+        let child_display = child_file.display();
+        check_err("extends = \"base.toml\"\n[[param]]\nname = \"port\"\ntype = \"u8\"\n", &format!("{}:3:8: param \"port\": already defined in the extended specification, use [[override]] to change it", child_display));
+        check_err("extends = \"base.toml\"\n[[override]]\nname = \"foo\"\n", &format!("{}:3:8: override \"foo\": no param or switch with this name in the extended specification", child_display));
+        check_err("extends = \"base.toml\"\n[[override]]\nname = \"verbose\"\noptional = true\n", &format!("{}:3:8: override \"verbose\": only doc can be overridden for switches", child_display));
+        check_err("[[override]]\nname = \"port\"\n", &format!("{}:2:8: override \"port\": no param or switch with this name in the extended specification", child_display));
+        check_err("extends = \"config_spec.toml\"\n", &format!("too many nested `extends` or `include` when loading {}, is there a cycle?", child_file.parent().unwrap().join("config_spec.toml").display()));
+
+        // Errors in the extended specification point into it
+        ::std::fs::write(dir.join("child").join("base.toml"), "\n[[param]]\nname = \"foo\"\ntype = \"u8\"\noptional = false\ndefault = \"1\"\n").unwrap();
+        check_err(EXTENDS_CHILD, &format!("{}:6:11: param \"foo\": default conflicts with optional = false", dir.join("child").join("base.toml").display()));
+
+        ::std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn manifest_from_str() {
+        use manifest::{LoadManifest, SpecificationPaths};
+
+        let manifest = "[package]\nname = \"foo\"\nversion = \"0.1.0\"\n\n[package.metadata.configure_me]\nspec = \"config_spec.toml\"\n";
+        let metadata = manifest.load_manifest().unwrap().package.unwrap().metadata.unwrap().configure_me.unwrap();
+        match metadata.spec_paths {
+            SpecificationPaths::Single(path) => assert_eq!(path, ::std::path::Path::new("config_spec.toml")),
+            _ => panic!("expected a single specification"),
+        }
+        assert!(manifest.to_owned().load_manifest().is_ok());
+
+        let err = "[package".load_manifest().err().unwrap();
+        assert_eq!(err.path(), ::std::path::Path::new("<memory>"));
+    }
+
+    #[test]
+    #[cfg(feature = "man")]
+    fn man_description_from_manifest() {
+        use manifest::LoadManifest;
+
+        let manifest = "[package]\nname = \"foo\"\nversion = \"0.1.0\"\ndescription = \"Does foo things\"\n".load_manifest().unwrap();
+
+        let spec = ::load(&b""[..]).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.contains("foo \\- Does foo things"));
+        assert!(!man.contains("DESCRIPTION"));
+
+        let spec = ::load("[general]\nsummary = \"Foo\"\n".as_bytes()).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.contains("foo \\- Foo"));
+        assert!(man.contains("DESCRIPTION\nDoes foo things"));
+    }
+
+    #[test]
+    #[cfg(feature = "man")]
+    fn man_subcommands() {
+        use manifest::LoadManifest;
+
+        let manifest = "[package]\nname = \"tool\"\nversion = \"0.1.0\"\n".load_manifest().unwrap();
+        let spec = ::load(SUBCOMMANDS.as_bytes()).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.contains("\\fBtool\\fR [FLAGS] [OPTIONS] [SUBCOMMAND]\n"), "{}", man);
+        assert!(man.contains(".SH SUBCOMMAND SERVE\nStart the server\n.TP\n\\fB\\-\\-port\\fR=\\fIPORT\\fR [default: \\fI8080\\fR]\nPort to listen on\n"), "{}", man);
+        assert!(man.contains(".SH SUBCOMMAND CHECK\nCheck a file without serving it\n.TP\n\\fIPATH\\fR\nFile to check\n.SH EXIT STATUS"), "{}", man);
+    }
+
+    #[test]
+    #[cfg(feature = "man")]
+    fn man_explain_config_switch() {
+        use manifest::LoadManifest;
+
+        let manifest = "[package]\nname = \"foo\"\nversion = \"0.1.0\"\n".load_manifest().unwrap();
+        let spec = ::load(EXPLAIN_CONFIG.as_bytes()).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.contains("\\fB\\-\\-explain\\-config\\fR\nExplains which source overrode each value.\n"), "{}", man);
+    }
+
+    #[test]
+    #[cfg(feature = "man")]
+    fn man_version() {
+        use manifest::LoadManifest;
+
+        let manifest = "[package]\nname = \"foo\"\nversion = \"0.1.0\"\n".load_manifest().unwrap();
+        let spec = ::load(VERSION.as_bytes()).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.starts_with(".TH FOO 1 \"\" \"foo 0.1.0\"\n"), "{}", man);
+
+        let spec = ::load("[general]\nname = \"bar\"\nversion = \"2.0.0\"\n".as_bytes()).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.starts_with(".TH BAR 1 \"\" \"bar 2.0.0\"\n"), "{}", man);
+
+        let spec = ::load(&b""[..]).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.starts_with(".TH FOO 1\n"), "{}", man);
+    }
+
+    #[test]
+    #[cfg(feature = "man")]
+    fn man_aliases() {
+        use manifest::LoadManifest;
+
+        let manifest = "[package]\nname = \"foo\"\nversion = \"0.1.0\"\n".load_manifest().unwrap();
+        let spec = ::load(ALIASES.as_bytes()).unwrap();
+        let man = ::gen_man::generate_man_page(&spec, &manifest).unwrap();
+        assert!(man.contains("When to use colors. Aliases: \\-\\-colour."), "{}", man);
+        assert!(man.contains("Cache downloaded files. Aliases: \\-\\-no\\-caching."), "{}", man);
+    }
+
+    #[test]
+    fn program_name() {
+        let generated = ::generate_to_string("[general]\nname = \"my-app\"\n").unwrap();
+        assert!(generated.contains("HelpRequested(\"my-app\".to_owned())"));
+        assert!(!generated.contains("HelpRequested(self._program_path"));
+
+        let mut spec = ::load(&b""[..]).unwrap();
+        assert_eq!(spec.program_name(), None);
+        spec.inferred_name = Some("my-bin".to_owned());
+        assert_eq!(spec.program_name(), Some("my-bin"));
+        spec.general.name = Some("my-app".to_owned());
+        assert_eq!(spec.program_name(), Some("my-app"));
+    }
+
+    #[test]
+    fn help_about() {
+        let generated = ::generate_to_string("[general]\nsummary = \"Serves {files}\"\ndoc = \"Serves \\\"files\\\" over HTTP.\"\n").unwrap();
+        assert!(generated.contains("write!(f, \"Serves {{files}}\\n\\nServes \\\"files\\\" over HTTP.\\n\\nUsage: {}"), "{}", generated);
+
+        let mut spec = ::load(&b""[..]).unwrap();
+        spec.package_description = Some("Does foo things".to_owned());
+        let mut generated = String::new();
+        ::codegen::generate_code(&spec, &mut generated).unwrap();
+        assert!(generated.contains("write!(f, \"Does foo things\\n\\nUsage: {}"), "{}", generated);
+
+        let mut spec = ::load("[general]\nsummary = \"Foo\"\nrich_help = true\n".as_bytes()).unwrap();
+        spec.package_description = Some("Does foo things".to_owned());
+        let mut generated = String::new();
+        ::codegen::generate_code(&spec, &mut generated).unwrap();
+        assert!(generated.contains("display_help(f, program_name, Some(\"Foo\\n\\nDoes foo things\"), "), "{}", generated);
+    }
+
+    #[test]
+    fn help_integer_notation() {
+        let spec = "[[param]]\nname = \"mask\"\ntype = \"u32\"\n";
+        let generated = ::generate_to_string(spec).unwrap();
+        assert!(!generated.contains("Integers may also be written"), "{}", generated);
+        assert!(!generated.contains("NotatedInt"), "{}", generated);
+        let generated = ::generate_to_string(&format!("[general]\nhelp_integer_notation = true\n\n{}", spec)).unwrap();
+        assert!(generated.contains("\\n\\nIntegers may also be written in hexadecimal (0x1F)"), "{}", generated);
+        // The hints of parse errors mention the notations too
+        assert!(generated.contains("display_arg_error::<::configure_me::helpers::NotatedInt<u32>>(f, \"--mask\", err)"), "{}", generated);
+    }
+
+    #[test]
+    fn env_prefix_from_program_name() {
+        let spec = ::load_data(b"[[param]]\nname = \"port\"\ntype = \"u16\"\n", None, 0, ::UnknownFields::Deny, Some("my-bin")).unwrap();
+        assert_eq!(spec.general.env_prefix.as_deref(), Some("my_bin"));
+        let mut generated = String::new();
+        ::codegen::generate_code(&spec, &mut generated).unwrap();
+        assert!(generated.contains("\"MY_BIN_PORT\""), "{}", generated);
+        // The usage shows the path the program was executed with unless `name` is set
+        assert!(generated.contains("HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into())"));
+
+        let spec = ::load_data(b"[general]\nname = \"my-app\"\n", None, 0, ::UnknownFields::Deny, Some("my-bin")).unwrap();
+        assert_eq!(spec.general.env_prefix.as_deref(), Some("my_app"));
+        let spec = ::load_data(b"[general]\nname = \"my-app\"\nenv_prefix = \"app\"\n", None, 0, ::UnknownFields::Deny, Some("my-bin")).unwrap();
+        assert_eq!(spec.general.env_prefix.as_deref(), Some("app"));
+    }
+
+    #[test]
+    fn library_program_name() {
+        let mut spec = ::load(&b""[..]).unwrap();
+        spec.library = true;
+        let mut generated = String::new();
+        ::codegen::generate_code(&spec, &mut generated).unwrap();
+        assert!(generated.contains("HelpRequested(::configure_me::helpers::program_name(self._program_path.as_ref().unwrap()))"));
+
+        spec.general.name = Some("my-app".to_owned());
+        let mut generated = String::new();
+        ::codegen::generate_code(&spec, &mut generated).unwrap();
+        assert!(generated.contains("HelpRequested(\"my-app\".to_owned())"));
+    }
+
+    #[test]
+    fn invalid_type() {
+        let err = generate_source(&mut "[[param]]\nname = \"foo\"\ntype = \"Vec<u8\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "3:8: param \"foo\": `type` is not a valid Rust type: expected `,`");
+
+        let err = generate_source(&mut "[[param]]\nname = \"foo\"\ntype = \"u8\"\nconvert_into = \"std::foo bar\"\n".as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "4:16: param \"foo\": `convert_into` is not a valid Rust type: unexpected token");
+    }
+
+    #[test]
+    fn invalid_nargs() {
+        let err = generate_source(&mut "[[param]]\nname = \"foo\"\ntype = \"u8\"\nnargs = { min = 3, max = 2 }\n".as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "4:9: param \"foo\": nargs must be at least 1, min can't exceed max and a fixed count can't exceed 32");
+
+        let err = generate_source(&mut "[[param]]\nname = \"foo\"\ntype = \"u8\"\nnargs = 2\nenv_var = true\n".as_bytes(), Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "4:9: param \"foo\": params taking multiple values can't be read from environment variables");
+
+        let spec = ::load("[general]\nenv_prefix = \"app\"\n\n[[param]]\nname = \"foo\"\ntype = \"u8\"\nnargs = 2\n".as_bytes()).unwrap();
+        assert!(!spec.params[0].env_var);
+        assert_eq!(spec.params[0].field_type(), "[u8; 2]");
+    }
+
+    #[test]
+    fn params_json() {
+        let src = "[general]\nenv_prefix = \"app\"\n\n[[param]]\nname = \"bind_addr\"\ntype = \"String\"\nabbr = \"b\"\ndefault = \"\\\"0.0.0.0\\\".to_owned()\"\ndoc = \"Address to bind to\"\n\n[[switch]]\nname = \"no_color\"\ndefault = true\n";
+        let spec = ::load(src.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["params"][0]["name"], "bind_addr");
+        assert_eq!(json["params"][0]["type"], "String");
+        assert_eq!(json["params"][0]["optional"], true);
+        assert_eq!(json["params"][0]["default"], "\"0.0.0.0\".to_owned()");
+        assert_eq!(json["params"][0]["doc"], "Address to bind to");
+        assert_eq!(json["params"][0]["long"], "--bind-addr");
+        assert_eq!(json["params"][0]["short"], "-b");
+        assert_eq!(json["params"][0]["env_var"], "APP_BIND_ADDR");
+        assert_eq!(json["switches"][0]["long"], "--no-no-color");
+        assert_eq!(json["switches"][0]["inverted"], true);
+        assert_eq!(json["switches"][0]["env_var"], "APP_NO_COLOR");
+    }
+
+    #[test]
+    fn options_markdown() {
+        let src = "[general]\nenv_prefix = \"app\"\n\n[[param]]\nname = \"bind_addr\"\ntype = \"String\"\nabbr = \"b\"\ndefault = \"\\\"0.0.0.0\\\".to_owned()\"\ndoc = \"Address to bind to\"\n\n[[switch]]\nname = \"verbose\"\ncount = true\n";
+        let spec = ::load(src.as_bytes()).unwrap();
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+
+        let expected = "### `--bind-addr BIND_ADDR`, `-b BIND_ADDR`\n\nAddress to bind to\n\n* Type: `String`\n* Default: `\"0.0.0.0\".to_owned()`\n* Environment variable: `APP_BIND_ADDR`\n\n### `--verbose`\n\n* Can be repeated\n* Environment variable: `APP_VERBOSE`\n\n";
+        assert_eq!(markdown, expected);
+    }
+
+    #[test]
+    fn example_config() {
+        let src = "[general]\nname = \"app\"\nenv_prefix = \"app\"\nexample_config = true\n\n[[param]]\nname = \"bind_addr\"\ntype = \"String\"\ndefault = \"\\\"0.0.0.0\\\".to_owned()\"\ndoc = \"Address to bind to\"\n\n[[param]]\nname = \"port\"\ntype = \"u16\"\noptional = false\n\n[[param]]\nname = \"timeout\"\ntype = \"duration\"\noptional = true\nenv_var = false\n\n[[switch]]\nname = \"verbose\"\ncount = true\n\n[[section]]\nname = \"database\"\ndoc = \"Connection to the database\"\n\n[[section.param]]\nname = \"url\"\ntype = \"String\"\noptional = true\n";
+        let spec = ::load(src.as_bytes()).unwrap();
+        let mut example = String::new();
+        ::gen_example::generate_example(&spec, &mut example).unwrap();
+
+        let expected = "# Example configuration of app\n\n# Address to bind to\n# Type: String\n# Default: \"0.0.0.0\".to_owned()\n# Environment variable: APP_BIND_ADDR\n#bind_addr = \"0.0.0.0\"\n\n# Type: u16\n# Mandatory\n# Environment variable: APP_PORT\nport = 0\n\n# Type: ::std::time::Duration\n# Optional\n#timeout = \"30s\"\n\n# Type: number of occurrences\n# Default: 0\n# Environment variable: APP_VERBOSE\n#verbose = 0\n\n# Connection to the database\n[database]\n\n# Type: String\n# Optional\n# Environment variable: APP_DATABASE_URL\n#url = \"\"\n\n";
+        assert_eq!(example, expected);
+        // Uncommenting all values gives a valid config file
+        let uncommented = example.lines().map(|line| if line.contains(" = ") { line.trim_start_matches('#') } else { line }).collect::<Vec<_>>().join("\n");
+        uncommented.parse::<::toml::Value>().unwrap();
+    }
+
+    #[test]
+    fn powershell_completion() {
+        let src = "[general]\nname = \"app\"\nconf_file_param = \"config\"\n\n[[param]]\nname = \"format\"\ntype = \"Format\"\nabbr = \"f\"\nvalues = [\"json\", \"toml\"]\ndoc = \"Output format\"\n\n[[param]]\nname = \"token\"\ntype = \"String\"\nargument = false\n\n[[switch]]\nname = \"verbose\"\ndoc = \"Print the\\nuser's files\"\nnegatable = true\n";
+        let spec = ::load(src.as_bytes()).unwrap();
+        let mut completion = String::new();
+        ::gen_powershell::generate_powershell(&spec, "app", &mut completion).unwrap();
+
+        let expected = r#"# PowerShell completion of app
+# Load it in your profile: . path\to\completion.ps1
+
+Register-ArgumentCompleter -Native -CommandName 'app' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $words = @($commandAst.CommandElements | Where-Object { $_.Extent.EndOffset -lt $cursorPosition } | ForEach-Object { "$_" })
+    $previous = $words[-1]
+    $values = @()
+    $options = @(
+        @('--config', 'ParameterName', 'Loads configuration from the specified file.'),
+        @('--format', 'ParameterName', 'Output format. Possible values: json, toml.'),
+        @('-f', 'ParameterName', 'Output format. Possible values: json, toml.'),
+        @('--verbose', 'ParameterName', 'Print the user''s files'),
+        @('--no-verbose', 'ParameterName', 'Turned off by --no-verbose.'),
+        @('--help', 'ParameterName', 'Prints the help and exits.'),
+        @('-h', 'ParameterName', 'Prints the help and exits.')
+    )
+    if ($previous -ceq '--format' -or $previous -ceq '-f') {
+        $values = @('json', 'toml')
+    }
+
+    if ($values) {
+        $values | Where-Object { $_ -clike "$wordToComplete*" } | ForEach-Object {
+            [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+        }
+        return
+    }
+    $options | Where-Object { $_[0] -clike "$wordToComplete*" } | ForEach-Object {
+        [System.Management.Automation.CompletionResult]::new($_[0], $_[0], $_[1], $_[2])
+    }
+}
+"#;
+        assert_eq!(completion, expected);
+    }
+
+    #[test]
+    fn powershell_completion_subcommands() {
+        let spec = ::load(SUBCOMMANDS.as_bytes()).unwrap();
+        let mut completion = String::new();
+        ::gen_powershell::generate_powershell(&spec, "tool", &mut completion).unwrap();
+        assert!(completion.contains("    $subcommand = $words | Select-Object -Skip 1 | Where-Object { $_ -cin @('serve', 'check') } | Select-Object -First 1\n    switch -CaseSensitive (\"$subcommand\") {\n        'serve' {\n            $options = @(\n                @('--port', 'ParameterName', 'Port to listen on'),\n"), "{}", completion);
+        assert!(completion.contains("        default {\n            $options = @(\n                @('--config', 'ParameterName', 'Loads configuration from the specified file.'),\n"), "{}", completion);
+        assert!(completion.contains("                @('serve', 'ParameterValue', 'Start the server'),\n"), "{}", completion);
+    }
+
+    #[test]
+    fn positionals_json_and_markdown() {
+        let spec = ::load(POSITIONAL.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["positionals"][0]["name"], "input");
+        assert_eq!(json["positionals"][0]["optional"], false);
+        assert_eq!(json["positionals"][2]["type"], "::std::path::PathBuf");
+        assert_eq!(json["positionals"][2]["variadic"], true);
+
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+        assert!(markdown.ends_with("### `[FILES...]`\n\n* Type: `Vec<::std::path::PathBuf>`\n* Optional positional argument\n* Takes all remaining arguments\n\n"), "{}", markdown);
+    }
+
+    #[test]
+    fn aliases_json_and_markdown() {
+        let spec = ::load(ALIASES.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["params"][1]["aliases"], ::serde_json::json!(["--jobs", "--workers"]));
+        assert_eq!(json["switches"][1]["aliases"], ::serde_json::json!(["--no-caching"]));
+
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+        assert!(markdown.contains("### `--threads THREADS`\n\nNumber of worker threads\n\n* Type: `u32`\n* Optional\n* Aliases: `--jobs`, `--workers`\n"), "{}", markdown);
+    }
+
+    #[test]
+    fn ranges_json_and_markdown() {
+        let spec = ::load(RANGES.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["params"][0]["min"], 1);
+        assert_eq!(json["params"][0]["max"], 65535);
+        assert_eq!(json["params"][2]["min"], ::serde_json::Value::Null);
+        assert_eq!(json["params"][2]["max"], 10);
+
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+        assert!(markdown.contains("### `--port PORT`\n\nPort to listen on\n\n* Type: `u16`\n* Default: `8080`\n* Range: from 1 to 65535\n"), "{}", markdown);
+        assert!(markdown.contains("* Range: at most 10\n"), "{}", markdown);
+    }
+
+    #[test]
+    fn patterns_json_and_markdown() {
+        let spec = ::load(PATTERNS.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["params"][0]["validate_regex"], "^[a-z0-9-]+$");
+        assert_eq!(json["params"][1]["validate_regex"], "^[a-z]+(/[a-z]+)*$");
+
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+        assert!(markdown.contains("### `--slug SLUG`\n\nIdentifier used in URLs\n\n* Type: `String`\n* Mandatory\n* Must match: `^[a-z0-9-]+$`\n"), "{}", markdown);
+    }
+
+    #[test]
+    fn relations_json_and_markdown() {
+        let spec = ::load(RELATIONS.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["params"][0]["requires"], ::serde_json::json!(["tls_key"]));
+        assert_eq!(json["params"][0]["conflicts_with"], ::serde_json::json!(["insecure"]));
+        assert_eq!(json["switches"][0]["requires"], ::serde_json::json!([]));
+
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+        assert!(markdown.contains("### `--tls-cert TLS_CERT`\n\nCertificate to serve\n\n* Type: `String`\n* Optional\n* Requires: `tls_key`\n* Conflicts with: `insecure`\n"), "{}", markdown);
+    }
+
+    #[test]
+    fn groups_json_and_markdown() {
+        let spec = ::load(GROUPS.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["groups"][0], ::serde_json::json!({ "name": "format", "doc": "Output format", "members": ["json", "yaml"], "required": false, "multiple": false }));
+        assert_eq!(json["groups"][2]["multiple"], true);
+
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+        assert!(markdown.contains("### Group `format`\n\nOutput format\n\n* Members: `json`, `yaml`\n* At most one can be set\n\n"), "{}", markdown);
+        assert!(markdown.contains("### Group `auth`\n\n* Members: `token`, `token_file`\n* Exactly one must be set\n\n"), "{}", markdown);
+        assert!(markdown.contains("* At least one must be set\n"), "{}", markdown);
+    }
+
+    #[test]
+    fn subcommands_json_and_markdown() {
+        let spec = ::load(SUBCOMMANDS.as_bytes()).unwrap();
+        let json = ::serde_json::from_str::<::serde_json::Value>(&::gen_json::generate_json(&spec)).unwrap();
+
+        assert_eq!(json["subcommands"][0]["name"], "serve");
+        assert_eq!(json["subcommands"][0]["doc"], "Start the server");
+        assert_eq!(json["subcommands"][0]["options"]["params"][0]["env_var"], "TOOL_SERVE_PORT");
+        assert_eq!(json["subcommands"][1]["options"]["positionals"][0]["name"], "path");
+
+        let mut markdown = String::new();
+        ::gen_markdown::generate_markdown(&spec, &mut markdown).unwrap();
+        assert!(markdown.contains("### Subcommand `serve`\n\nStart the server\n\n#### `--port PORT`\n\nPort to listen on\n\n"), "{}", markdown);
+        assert!(markdown.ends_with("### Subcommand `check`\n\nCheck a file without serving it\n\n#### `PATH`\n\nFile to check\n\n* Type: `::std::path::PathBuf`\n* Mandatory positional argument\n\n"), "{}", markdown);
+    }
+
+    #[test]
+    fn subcommand_files() {
+        let dir = ::std::env::temp_dir().join(format!("configure_me_subcommand_test_{}", ::std::process::id()));
+        ::std::fs::create_dir_all(&dir).unwrap();
+        let spec_file = dir.join("config_spec.toml");
+        ::std::fs::write(dir.join("serve.toml"), "[general]\nenv_prefix = \"server\"\n\n[[param]]\nname = \"port\"\ntype = \"u16\"\n").unwrap();
+        ::std::fs::write(&spec_file, "[general]\nname = \"tool\"\nenv_prefix = \"tool\"\n\n[[subcommand]]\nname = \"serve\"\nfile = \"serve.toml\"\n").unwrap();
+
+        let spec = ::load_from_file(&spec_file).unwrap();
+        let serve = &spec.subcommands[0].config;
+        assert_eq!(serve.params[0].name.as_snake_case(), "port");
+        assert_eq!(serve.general.env_prefix.as_deref(), Some("server"));
+        assert_eq!(serve.general.config_table.as_deref(), Some("serve"));
+        assert_eq!(serve.program_name(), Some("tool serve"));
+        assert_eq!(spec.extended_files, [dir.join("serve.toml")]);
+
+        ::std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unknown_fields() {
+        use UnknownFields;
+
+        let src = "future = 1\n\n[general]\nshiny = true\n\n[[param]]\nname = \"foo\"\ntype = \"u32\"\nvalidator = \"check\"\n";
+
+        let err = ::load(src.as_bytes()).err().unwrap();
+        assert_eq!(err.to_string(), "unknown field `future` in the config specification");
+
+        let spec = ::load_data(src.as_bytes(), None, 0, UnknownFields::Warn, None).unwrap();
+        assert_eq!(spec.ignored_fields, ["future", "general.shiny", "param[0].validator"]);
+        assert_eq!(spec.params.len(), 1);
+    }
+
+    #[test]
+    fn include_fragments() {
+        let dir = ::std::env::temp_dir().join(format!("configure_me_include_test_{}", ::std::process::id()));
+        ::std::fs::create_dir_all(&dir).unwrap();
+        let spec_file = dir.join("config_spec.toml");
+        ::std::fs::write(dir.join("db.toml"), DB_FRAGMENT).unwrap();
+        ::std::fs::write(&spec_file, INCLUDE_FRAGMENTS).unwrap();
+
+        let spec = ::load_from_file(&spec_file).unwrap();
+        let params = spec.params.iter().map(|param| param.name.as_snake_case()).collect::<Vec<_>>();
+        assert_eq!(params, ["workers", "primary_db_host", "primary_db_port", "replica_db_host", "replica_db_port"]);
+        assert!(spec.params.iter().all(|param| param.abbr.is_none()));
+        let switches = spec.switches.iter().map(|switch| switch.name.as_snake_case()).collect::<Vec<_>>();
+        assert_eq!(switches, ["primary_db_tls", "replica_db_tls"]);
+        assert_eq!(spec.extended_files, [dir.join("db.toml"), dir.join("db.toml")]);
+
+        let check_err = |spec: &str, expected: &str| {
+            ::std::fs::write(&spec_file, spec).unwrap();
+            assert_eq!(::load_from_file(&spec_file).err().unwrap().to_string(), format!("{}:{}", spec_file.display(), expected));
+        };
+        check_err("[[include]]\nfile = \"db.toml\"\n\n[[include]]\nfile = \"db.toml\"\n", "5:8: param \"host\": defined more than once, include the fragment with a different prefix");
+        check_err("[[include]]\nfile = \"db.toml\"\nprefix = \"Db\"\n", "2:8: include \"Db\": prefix must consist of [a-z0-9_] and not start with a digit");
+
+        // Without a prefix, short options are kept
+        ::std::fs::write(&spec_file, "[[include]]\nfile = \"db.toml\"\n").unwrap();
+        assert_eq!(::load_from_file(&spec_file).unwrap().params[0].abbr, Some('H'));
+
+        // Groups are prefixed like the params and switches
+        let grouped = format!("{}\n[[group]]\nname = \"endpoint\"\nmembers = [\"host\", \"tls\"]\n", DB_FRAGMENT);
+        ::std::fs::write(dir.join("db.toml"), grouped).unwrap();
+        ::std::fs::write(&spec_file, INCLUDE_FRAGMENTS).unwrap();
+        let spec = ::load_from_file(&spec_file).unwrap();
+        let groups = spec.groups.iter().map(|group| (group.name.as_snake_case(), group.members.iter().map(|member| member.as_snake_case()).collect::<Vec<_>>())).collect::<Vec<_>>();
+        assert_eq!(groups, [
+            ("primary_db_endpoint", vec!["primary_db_host", "primary_db_tls"]),
+            ("replica_db_endpoint", vec!["replica_db_host", "replica_db_tls"]),
+        ]);
+        check_err("[[include]]\nfile = \"db.toml\"\n\n[[include]]\nfile = \"db.toml\"\nprefix = \"other_\"\n\n[[group]]\nname = \"endpoint\"\nmembers = [\"host\"]\n", "2:8: group \"endpoint\": defined more than once, include the fragment with a different prefix");
+
+        ::std::fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -0,0 +1,108 @@
+extern crate configure_me_codegen;
+
+use configure_me_codegen::init::StarterParam;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: configure_me_codegen init [CRATE_DIR]
+       configure_me_codegen import-clap [JSON_FILE]
+       configure_me_codegen import-derive RUST_FILE
+
+Commands:
+    init           sets up configure_me in an existing crate (current directory by default)
+    import-clap    prints the specification converted from a clap command described in JSON (stdin by default)
+    import-derive  prints the specification converted from a struct deriving clap's Parser or StructOpt";
+
+fn prompt<R: BufRead>(input: &mut R, question: &str) -> io::Result<String> {
+    eprint!("{}", question);
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    Ok(answer.trim().to_owned())
+}
+
+fn ask_params<R: BufRead>(input: &mut R) -> io::Result<Vec<StarterParam>> {
+    let mut params = Vec::new();
+    loop {
+        let name = prompt(input, "Name of the parameter (leave empty to finish): ")?;
+        if name.is_empty() {
+            return Ok(params);
+        }
+
+        let ty = prompt(input, "Type [String]: ")?;
+        let ty = if ty.is_empty() { "String".to_owned() } else { ty };
+        let optional = prompt(input, "Optional? [Y/n]: ")?;
+        let optional = !optional.eq_ignore_ascii_case("n") && !optional.eq_ignore_ascii_case("no");
+        let doc = prompt(input, "Documentation: ")?;
+        let doc = if doc.is_empty() { None } else { Some(doc) };
+
+        params.push(StarterParam { name, ty, optional, doc, });
+    }
+}
+
+fn import_clap(file: Option<PathBuf>) {
+    let spec = match file {
+        Some(file) => std::fs::File::open(&file)
+            .map_err(|error| format!("failed to open file {}: {}", file.display(), error))
+            .and_then(|file| configure_me_codegen::clap_import::spec_from_json(io::BufReader::new(file)).map_err(|error| error.to_string())),
+        None => configure_me_codegen::clap_import::spec_from_json(io::stdin().lock()).map_err(|error| error.to_string()),
+    };
+    match spec {
+        Ok(spec) => print!("{}", spec),
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            std::process::exit(1);
+        },
+    }
+}
+
+fn import_derive(file: PathBuf) {
+    let migration = std::fs::read_to_string(&file)
+        .map_err(|error| format!("failed to open file {}: {}", file.display(), error))
+        .and_then(|source| configure_me_codegen::derive_import::spec_from_derive(&source).map_err(|error| error.to_string()));
+    match migration {
+        Ok(migration) => {
+            print!("{}", migration.spec);
+            for unsupported in migration.unsupported {
+                eprintln!("Unsupported: {}", unsupported);
+            }
+        },
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            std::process::exit(1);
+        },
+    }
+}
+
+fn main() {
+    let mut args = std::env::args_os().skip(1);
+    match args.next() {
+        Some(ref command) if command == "init" => (),
+        Some(ref command) if command == "import-clap" => return import_clap(args.next().map(PathBuf::from)),
+        Some(ref command) if command == "import-derive" => match args.next() {
+            Some(file) => return import_derive(file.into()),
+            None => {
+                eprintln!("{}", USAGE);
+                std::process::exit(1);
+            },
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(1);
+        },
+    }
+    let crate_dir = args.next().map_or_else(|| PathBuf::from("."), PathBuf::from);
+
+    let stdin = io::stdin();
+    let params = ask_params(&mut stdin.lock()).unwrap_or_else(|error| {
+        eprintln!("Failed to read the answer: {}", error);
+        std::process::exit(1);
+    });
+
+    if let Err(error) = configure_me_codegen::init::init(&crate_dir, &params) {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+
+    eprintln!("Created {} and build.rs, don't forget to add configure_me to [dependencies] and configure_me_codegen to [build-dependencies].", configure_me_codegen::init::SPEC_FILE_NAME);
+}
@@ -29,14 +29,25 @@ pub enum SpecificationPaths {
 
 /// Metadata of this crate
 #[derive(Deserialize)]
+#[non_exhaustive]
 pub struct ConfigureMeMetadata {
     /// Path to the specification
     ///
     /// Must be relative to Cargo.toml directory
     #[serde(flatten)]
     pub spec_paths: SpecificationPaths,
-    #[serde(skip)]
-    _private: (),
+    /// Directory to write generated code into instead of `OUT_DIR`
+    ///
+    /// Must be relative to Cargo.toml directory
+    pub out_dir: Option<PathBuf>,
+    /// Whether unknown fields in the specification are errors or warnings
+    #[serde(default)]
+    pub unknown_fields: super::UnknownFields,
+    /// Whether the code is included in a library shared by multiple binaries
+    ///
+    /// The usage then shows the name of the executed binary instead of the package.
+    #[serde(default)]
+    pub library: bool,
 }
 
 /// Metadata used in manifest
@@ -46,6 +57,9 @@ pub struct Metadata {
     pub configure_me: Option<ConfigureMeMetadata>,
 }
 
+/// Path reported by `LoadError` for manifests parsed from memory
+const IN_MEMORY_PATH: &str = "<memory>";
+
 /// Error that occured when loading Cargo.toml
 #[derive(Debug)]
 pub struct LoadError {
@@ -133,7 +147,7 @@ impl LoadManifest for Manifest {
     }
 }
 
-impl<'a> LoadManifest for &'a Manifest {
+impl LoadManifest for &Manifest {
     type Error = void::Void;
     type Manifest = Self;
 
@@ -142,7 +156,7 @@ impl<'a> LoadManifest for &'a Manifest {
     }
 }
 
-impl<'a> LoadManifest for &'a Path {
+impl LoadManifest for &Path {
     type Error = LoadError;
     type Manifest = Manifest;
 
@@ -170,7 +184,44 @@ impl LoadManifest for PathBuf {
     }
 }
 
-impl<'a> LoadManifest for &'a PathBuf {
+impl LoadManifest for &PathBuf {
+    type Error = LoadError;
+    type Manifest = Manifest;
+
+    fn load_manifest(self) -> Result<Self::Manifest, Self::Error> {
+        (&**self).load_manifest()
+    }
+}
+
+/// Parses the contents of the manifest from memory.
+///
+/// The path of `LoadError` is `<memory>` in this case.
+impl LoadManifest for &str {
+    type Error = LoadError;
+    type Manifest = Manifest;
+
+    fn load_manifest(self) -> Result<Self::Manifest, Self::Error> {
+        Manifest::from_slice_with_metadata(self.as_bytes()).map_err(|error| {
+            LoadError {
+                path: Path::new(IN_MEMORY_PATH).into(),
+                error,
+            }
+        })
+    }
+}
+
+/// Parses the contents of the manifest from memory.
+impl LoadManifest for String {
+    type Error = LoadError;
+    type Manifest = Manifest;
+
+    fn load_manifest(self) -> Result<Self::Manifest, Self::Error> {
+        (&*self).load_manifest()
+    }
+}
+
+/// Parses the contents of the manifest from memory.
+impl LoadManifest for &String {
     type Error = LoadError;
     type Manifest = Manifest;
 
@@ -218,8 +269,8 @@ macro_rules! impl_load_manifest_ref {
     }
 }
 
-impl_load_manifest!(Manifest, PathBuf, BuildScript, CurrentDir);
-impl_load_manifest_ref!(Manifest, PathBuf, Path);
+impl_load_manifest!(Manifest, PathBuf, String, BuildScript, CurrentDir);
+impl_load_manifest_ref!(Manifest, PathBuf, Path, str, String);
 
 pub (crate) fn get_dir() -> Result<PathBuf, super::Error> {
     std::env::var_os("CARGO_MANIFEST_DIR")
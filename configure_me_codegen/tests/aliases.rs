@@ -0,0 +1,60 @@
+macro_rules! test_name { () => { "aliases" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_file(content: &str) -> Result<config::Config, config::Error> {
+    let path = std::env::temp_dir().join(format!("configure_me_aliases_{}.toml", std::process::id()));
+    std::fs::write(&path, content).unwrap();
+    let config = load(&["aliases"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    config
+}
+
+#[test]
+fn canonical_names() {
+    let config = load(&["aliases", "--color", "never", "--threads", "4", "--quiet", "--no-cache"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.color, "never");
+    assert_eq!(config.threads, Some(4));
+    assert!(config.quiet);
+    assert!(!config.cache);
+}
+
+#[test]
+fn aliases_on_command_line() {
+    let config = load(&["aliases", "--colour=always", "--jobs", "2", "--silent", "--no-caching"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.color, "always");
+    assert_eq!(config.threads, Some(2));
+    assert!(config.quiet);
+    assert!(!config.cache);
+
+    let config = load(&["aliases", "--threads", "2", "--workers", "8", "--silent=false"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.threads, Some(8));
+    assert!(!config.quiet);
+
+    let err = load(&["aliases", "--jobs"], &[]).err().expect("value is missing");
+    assert_eq!(err.to_string(), "A value to argument '--jobs' is missing.");
+}
+
+#[test]
+fn aliases_in_config_file() {
+    let config = load_file("colour = \"never\"\njobs = 3\nsilent = true\ncaching = false\n").unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.color, "never");
+    assert_eq!(config.threads, Some(3));
+    assert!(config.quiet);
+    assert!(!config.cache);
+}
+
+#[test]
+fn usage() {
+    let err = load(&["aliases", "--help"], &[]).err().expect("help is requested");
+    let usage = err.to_string();
+    assert!(usage.contains("\n        --color       When to use colors\n"), "{}", usage);
+    assert!(usage.contains("\n        --threads     Number of worker threads. Aliases: --jobs, --workers.\n"), "{}", usage);
+    assert!(usage.contains("\n        --quiet       Aliases: --silent.\n"), "{}", usage);
+    assert!(!usage.contains("--colour"), "{}", usage);
+    assert!(!usage.contains("--no-caching"), "{}", usage);
+}
@@ -0,0 +1,33 @@
+macro_rules! test_name { () => { "arbitrary" } }
+
+include!("glue/boilerplate.rs");
+
+use configure_me::arbitrary::{Arbitrary, Unstructured};
+
+#[test]
+fn respects_nargs() {
+    let data = (0..1024u32).map(|i| (i * 7919 % 251) as u8).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&data);
+    let mut seen_peers = false;
+    while !u.is_empty() {
+        let config = match config::Config::arbitrary(&mut u) {
+            Ok(config) => config,
+            Err(_) => break,
+        };
+        if let Some(peers) = config.peers {
+            assert!((1..=3).contains(&peers.len()), "{} peers", peers.len());
+            seen_peers = true;
+        }
+        let _: [u8; 2] = config.ratio;
+        let _: u16 = config.port;
+        let _: u32 = config.verbose;
+    }
+    assert!(seen_peers);
+}
+
+#[test]
+fn empty_data() {
+    let config = config::Config::arbitrary(&mut Unstructured::new(&[])).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 0);
+    assert!(config.peers.is_none());
+}
@@ -0,0 +1,58 @@
+macro_rules! test_name { () => { "audit" } }
+
+include!("glue/boilerplate.rs");
+
+use std::sync::{Arc, Mutex};
+
+type Entries = Arc<Mutex<Vec<(&'static str, Vec<String>, Option<String>)>>>;
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Vec<(&'static str, Vec<String>, Option<String>)> {
+    let entries = Entries::default();
+    let recorded = Arc::clone(&entries);
+    config::Config::set_audit_callback(move |entry| {
+        let sources = entry.sources.iter().map(ToString::to_string).collect();
+        recorded.lock().unwrap().push((entry.name, sources, entry.value.clone()));
+    });
+    if let Err(err) = config::Config::custom_args_and_optional_files(args, files) {
+        panic!("{}", err);
+    }
+    let entries = entries.lock().unwrap().clone();
+    entries
+}
+
+// The callback is global, so the scenarios run sequentially
+#[test]
+fn audit() {
+    let entries = load(&["audit"], &[]);
+    assert_eq!(entries, [
+        ("port", vec![], Some("8080".to_owned())),
+        ("token", vec![], None),
+        ("tags", vec![], None),
+        ("verbose", vec![], Some("0".to_owned())),
+    ]);
+
+    let path = std::env::temp_dir().join(format!("configure_me_audit_{}.toml", std::process::id()));
+    std::fs::write(&path, "port = 1234\ntoken = \"hunter2\"\ntags = \"a\"\n").unwrap();
+    let entries = load(&["audit", "--port", "42", "--tags", "b", "-vv"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    let file = format!("config file {}", path.display());
+    assert_eq!(entries, [
+        ("port", vec!["argument --port".to_owned()], Some("42".to_owned())),
+        ("token", vec![file.clone()], Some("<redacted>".to_owned())),
+        ("tags", vec![file, "argument --tags".to_owned()], Some("a,b".to_owned())),
+        ("verbose", vec!["argument -v".to_owned()], Some("2".to_owned())),
+    ]);
+
+    // The callback isn't called with the lock held, so it can load the configuration itself
+    let nested = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&nested);
+    config::Config::set_audit_callback(move |entry| {
+        if entry.name == "port" {
+            config::Config::set_audit_callback(|_| ());
+            let config = config::Config::custom_args_and_optional_files(&["audit"], &[] as &[&std::path::Path]).unwrap().0;
+            recorded.lock().unwrap().push(config.port);
+        }
+    });
+    let _ = config::Config::custom_args_and_optional_files(&["audit"], &[] as &[&std::path::Path]).unwrap();
+    assert_eq!(*nested.lock().unwrap(), [8080]);
+}
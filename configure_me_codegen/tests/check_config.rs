@@ -0,0 +1,99 @@
+macro_rules! test_name { () => { "check_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+use std::process::Command;
+
+const CHILD_ARGS: &str = "CONFIGURE_ME_CHECK_CONFIG_CHILD_ARGS";
+const CHILD_FILE: &str = "CONFIGURE_ME_CHECK_CONFIG_CHILD_FILE";
+
+/// Loads the config in a child process running the `child` test, returns whether it succeeded
+/// and its stdout and stderr
+fn check(args: &[&str], file_content: Option<&str>) -> (bool, String, String) {
+    let file = std::env::temp_dir().join(format!("configure_me_check_config_{}_{}.toml", args.join("_").replace('-', ""), std::process::id()));
+    if let Some(content) = file_content {
+        std::fs::write(&file, content).unwrap();
+    }
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "child", "--nocapture", "--test-threads=1", "--quiet"])
+        .env(CHILD_ARGS, args.join("\n"))
+        .env(CHILD_FILE, &file)
+        .output()
+        .unwrap();
+    if file_content.is_some() {
+        std::fs::remove_file(&file).unwrap();
+    }
+    (output.status.success(), String::from_utf8(output.stdout).unwrap(), String::from_utf8(output.stderr).unwrap())
+}
+
+/// Does nothing unless started by `check()`
+#[test]
+fn child() {
+    let args = match std::env::var(CHILD_ARGS) {
+        Ok(args) => args,
+        Err(_) => return,
+    };
+    let args = Some("check_config").into_iter().chain(args.split('\n').filter(|arg| !arg.is_empty())).map(ToOwned::to_owned).collect::<Vec<_>>();
+    let file = PathBuf::from(std::env::var_os(CHILD_FILE).unwrap());
+    match config::Config::custom_args_and_optional_files(args, Some(file)) {
+        Ok(_) => println!("Returned the configuration"),
+        Err(err) => println!("Returned an error: {}", err),
+    }
+}
+
+#[test]
+fn valid() {
+    let (success, stdout, _) = check(&["--check-config", "--port", "1"], None);
+    assert!(success);
+    assert!(stdout.contains("The configuration is valid."));
+    assert!(!stdout.contains("Returned"));
+}
+
+#[test]
+fn invalid() {
+    let (success, stdout, stderr) = check(&["--check-config"], None);
+    assert!(!success);
+    assert!(stderr.contains("Error: Invalid configuration: "), "{}", stderr);
+    assert!(stderr.contains("The configuration is invalid."));
+    assert!(!stdout.contains("Returned"));
+}
+
+#[test]
+fn invalid_file() {
+    let (success, stdout, stderr) = check(&["--port", "1", "--check-config"], Some("port = \"foo\""));
+    assert!(!success);
+    assert!(stderr.contains("Error: Failed to parse configuration file "), "{}", stderr);
+    assert!(!stdout.contains("Returned"));
+}
+
+#[test]
+fn valid_file() {
+    let (success, stdout, _) = check(&["--check-config"], Some("port = 1"));
+    assert!(success);
+    assert!(stdout.contains("The configuration is valid."));
+}
+
+#[test]
+fn not_checked_after_separator() {
+    let (success, stdout, _) = check(&["--port", "1", "--", "--check-config"], None);
+    assert!(success);
+    assert!(stdout.contains("Returned the configuration"));
+}
+
+#[test]
+fn without_switch() {
+    let (success, stdout, _) = check(&[], None);
+    assert!(success);
+    assert!(stdout.contains("Returned an error: Invalid configuration: "));
+}
+
+#[test]
+fn normalized_switch() {
+    for switch in &["--CHECK-CONFIG", "/check-config"] {
+        let (success, stdout, _) = check(&[switch, "--port", "1"], None);
+        assert!(success, "{}", switch);
+        assert!(stdout.contains("The configuration is valid."), "{}: {}", switch, stdout);
+        assert!(!stdout.contains("Returned"), "{}: {}", switch, stdout);
+    }
+}
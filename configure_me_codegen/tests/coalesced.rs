@@ -169,3 +169,35 @@ fn value_coalesced_separate_count3() {
     assert_eq!(cfg.c, 3);
     assert_eq!(cfg.d, Some("42".to_owned()))
 }
+
+#[test]
+fn value_consumes_rest_of_group() {
+    use std::iter;
+    use std::path::PathBuf;
+
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "-cdab", "-a"], iter::empty::<PathBuf>()).unwrap();
+    assert!(tail.next().is_none());
+
+    assert!(cfg.a);
+    assert!(!cfg.b);
+    assert_eq!(cfg.c, 1);
+    assert_eq!(cfg.d, Some("ab".to_owned()))
+}
+
+#[test]
+fn value_missing_at_end_of_group() {
+    use std::iter;
+    use std::path::PathBuf;
+
+    let err = config::Config::custom_args_and_optional_files(&["app", "-acd"], iter::empty::<PathBuf>()).map(|(cfg, _)| cfg).err().expect("missing value");
+    assert!(matches!(err, config::Error::Arguments(config::ArgParseError::MissingArgument("-d"))), "{}", err);
+}
+
+#[test]
+fn unknown_switch_in_group() {
+    use std::iter;
+    use std::path::PathBuf;
+
+    let err = config::Config::custom_args_and_optional_files(&["app", "-axb"], iter::empty::<PathBuf>()).map(|(cfg, _)| cfg).err().expect("unknown switch");
+    assert!(matches!(err, config::Error::Arguments(config::ArgParseError::UnknownArgument(ref arg)) if arg == "-x"), "{}", err);
+}
@@ -0,0 +1,71 @@
+macro_rules! test_name { () => { "config_includes" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::{Path, PathBuf};
+
+/// Creates the config files in a fresh directory, the first one is the one being loaded
+fn write_configs(name: &str, files: &[(&str, &str)]) -> (PathBuf, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("configure_me_config_includes_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    for (file, content) in files {
+        let path = dir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    let main = dir.join(files[0].0);
+    (dir, main)
+}
+
+fn load(args: &[&str], files: &[&Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(name: &str, files: &[(&str, &str)]) -> String {
+    let (dir, main) = write_configs(name, files);
+    let err = load(&["config_includes"], &[&main]).err().expect("the includes are invalid").to_string();
+    std::fs::remove_dir_all(&dir).unwrap();
+    err
+}
+
+#[test]
+fn file_overrides_includes() {
+    let (dir, main) = write_configs("override", &[
+        ("app.toml", "include = [\"base.toml\", \"conf/extra.toml\"]\nhost = \"main\"\ntag = \"m\"\n"),
+        ("base.toml", "port = 1\nhost = \"base\"\ntag = \"b\"\n"),
+        ("conf/extra.toml", "include = [\"nested.toml\"]\nport = 2\ntag = \"e\"\n"),
+        ("conf/nested.toml", "port = 3\ntag = \"n\"\n"),
+    ]);
+    let config = load(&["config_includes"], &[&main]);
+    let from_arg = load(&["config_includes", "--config", main.to_str().unwrap()], &[]);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    for config in [config, from_arg] {
+        let config = config.unwrap_or_else(|err| panic!("{}", err));
+        assert_eq!(config.port, Some(2));
+        assert_eq!(config.host.as_deref(), Some("main"));
+        assert_eq!(config.tag.as_deref(), Some("bnem"));
+    }
+}
+
+#[test]
+fn invalid_includes() {
+    let err = load_err("cycle", &[("a.toml", "include = [\"b.toml\"]\n"), ("b.toml", "include = [\"a.toml\"]\n")]);
+    assert!(err.ends_with("a.toml: the file includes itself"), "{}", err);
+
+    let err = load_err("missing", &[("app.toml", "include = [\"missing.toml\"]\nport = 1\n")]);
+    assert!(err.ends_with("missing.toml: the included file doesn't exist"), "{}", err);
+
+    // A chain of files where all but the last one include the next one
+    let depth = configure_me::helpers::MAX_INCLUDE_DEPTH;
+    let names = (0..=(depth + 1)).map(|i| format!("{}.toml", i)).collect::<Vec<_>>();
+    let contents = names.iter().skip(1).map(|next| format!("include = [\"{}\"]\n", next)).chain(Some("port = 1\n".to_owned())).collect::<Vec<_>>();
+    let files = names.iter().zip(&contents).map(|(name, content)| (name.as_str(), content.as_str())).collect::<Vec<_>>();
+    let err = load_err("depth", &files);
+    assert!(err.ends_with(&format!("{}.toml: more than {} config files include each other", depth, depth)), "{}", err);
+
+    let (dir, main) = write_configs("max_depth", &files[1..]);
+    let config = load(&["config_includes"], &[&main]);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(config.unwrap_or_else(|err| panic!("{}", err)).port, Some(1));
+}
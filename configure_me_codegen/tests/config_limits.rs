@@ -0,0 +1,47 @@
+macro_rules! test_name { () => { "config_limits" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(name: &str, content: &str) -> Result<config::Config, String> {
+    let file = std::env::temp_dir().join(format!("configure_me_config_limits_{}_{}.toml", name, std::process::id()));
+    std::fs::write(&file, content).unwrap();
+    let result = config::Config::custom_args_and_optional_files(&["config_limits"], Some(&file));
+    std::fs::remove_file(&file).unwrap();
+    let prefix = format!("Failed to read configuration file {}: ", file.display());
+    result.map(|(config, _)| config).map_err(|err| err.to_string().trim_start_matches(&prefix).to_owned())
+}
+
+#[test]
+fn within_limits() {
+    let content = "name = \"[[[[\" # [[[[\nlist = [[1], [2]]\nquoted = 'a\\\"b'\nmultiline = \"\"\"a\"\"\"\n\n[section]\nkey = { a = 1 }\n";
+    let config = load("within", content).unwrap();
+    assert_eq!(config.name, Some("[[[[".to_owned()));
+}
+
+#[test]
+fn file_too_large() {
+    let content = format!("name = \"abc\"\n{}", "# padding\n".repeat(30));
+    assert_eq!(load("large", &content).err().unwrap(), "the file is larger than 256 bytes");
+}
+
+#[test]
+fn string_too_long() {
+    assert_eq!(load("string", "\nname = \"abcdefghi\"\n").err().unwrap(), "line 2: string is longer than 8 bytes");
+    assert_eq!(load("multiline", "name = '''\nabcdefghi'''\n").err().unwrap(), "line 2: string is longer than 8 bytes");
+}
+
+#[test]
+fn nested_too_deep() {
+    assert_eq!(load("array", "list = [[[1]]]\n").err().unwrap(), "line 1: tables or arrays are nested deeper than 2 levels");
+    assert_eq!(load("table", "[a.b.c]\nkey = 1\n").err().unwrap(), "line 1: tables or arrays are nested deeper than 2 levels");
+    assert_eq!(load("dotted", "[a]\nb.c.d = 1\n").err().unwrap(), "line 2: tables or arrays are nested deeper than 2 levels");
+    assert_eq!(load("multiline_array", "[a]\nlist = [\n  [1],\n]\n").err().unwrap(), "line 3: tables or arrays are nested deeper than 2 levels");
+}
+
+#[test]
+fn mapped_above_threshold() {
+    let content = format!("name = \"abc\"\n{}", "# padding\n".repeat(10));
+    assert_eq!(load("mapped", &content).unwrap().name, Some("abc".to_owned()));
+    let content = format!("{}name = \"abcdefghi\"\n", "# padding\n".repeat(10));
+    assert_eq!(load("mapped_string", &content).err().unwrap(), "line 11: string is longer than 8 bytes");
+}
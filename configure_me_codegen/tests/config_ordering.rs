@@ -50,4 +50,23 @@ fn config_ordering() {
     assert_eq!(config.foo, Some(42));
     let (config, _) = config::Config::custom_args_and_optional_files(&["test".as_ref(), "--foo=50".as_ref(), "--config".as_ref(), fortytwo.as_path()], &[&fortyseven]).unwrap();
     assert_eq!(config.foo, Some(42));
+    // A file specified again is read only once, but it still overrides what precedes it
+    let (config, _) = config::Config::custom_args_and_optional_files(&["test".as_ref(), "--foo=50".as_ref(), "--config".as_ref(), fortyseven.as_path()], &[&fortyseven]).unwrap();
+    assert_eq!(config.foo, Some(47));
+    let (config, _) = config::Config::custom_args_and_optional_files(&["test".as_ref(), "--config".as_ref(), fortyseven.as_path()], &[&fortytwo, &fortyseven]).unwrap();
+    assert_eq!(config.foo, Some(47));
+    let (config, _) = config::Config::custom_args_and_optional_files(&["test".as_ref(), "--config".as_ref(), fortytwo.as_path(), "--config".as_ref(), fortyseven.as_path(), "--config".as_ref(), fortytwo.as_path()], empty_args).unwrap();
+    assert_eq!(config.foo, Some(42));
+}
+
+#[test]
+fn conf_dir_files_are_sorted() {
+    let dir = std::env::temp_dir().join(format!("configure_me_conf_dir_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("10-nested")).unwrap();
+    std::fs::write(dir.join("20-b.toml"), "foo = 2\n").unwrap();
+    std::fs::write(dir.join("10-a.toml"), "foo = 1\n").unwrap();
+    let config = config::Config::custom_args_and_optional_files(&["test".as_ref(), "--foo=50".as_ref(), "--conf-dir".as_ref(), dir.as_os_str()], &[] as &[&str]).map(|(config, _)| config.foo);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(config.unwrap_or_else(|err| panic!("{}", err)), Some(2));
 }
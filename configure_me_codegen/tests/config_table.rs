@@ -0,0 +1,38 @@
+macro_rules! test_name { () => { "config_table" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+fn write_config(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("configure_me_config_table_{}_{}.toml", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn load(name: &str, contents: &str) -> Result<config::Config, config::Error> {
+    let path = write_config(name, contents);
+    let result = config::Config::custom_args_and_optional_files(&["config_table"], &[&path]).map(|(config, _)| config);
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+#[test]
+fn reads_sub_table() {
+    let config = load("sub_table", "port = 1\n\n[services.my-app]\nport = 8080\nverbose = true\n\n[services.other]\nport = 9090\n").unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.host, "localhost");
+    assert!(config.verbose);
+}
+
+#[test]
+fn missing_table() {
+    let err = load("missing_table", "port = 1\n").err().expect("the port is mandatory");
+    assert!(err.to_string().contains("port"), "{}", err);
+}
+
+#[test]
+fn not_a_table() {
+    let err = load("not_a_table", "services = 42\n").err().expect("services must be a table");
+    assert!(err.to_string().contains("`services` is not a table"), "{}", err);
+}
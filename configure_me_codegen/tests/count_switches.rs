@@ -0,0 +1,54 @@
+macro_rules! test_name { () => { "count_switches" } }
+
+include!("glue/boilerplate.rs");
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Level {
+    Low,
+    High,
+}
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the switch is repeated too many times").to_string()
+}
+
+#[test]
+fn counts_are_mapped() {
+    let config = load(&["count_switches"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.verbose, config::Verbosity::Warn);
+    assert_eq!(config.quiet, 0);
+    assert_eq!(config.level, Level::Low);
+
+    let config = load(&["count_switches", "-vv", "--level", "-q"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.verbose, config::Verbosity::Debug);
+    assert_eq!(config.quiet, 1);
+    assert_eq!(config.level, Level::High);
+
+    // Counts past the last value keep it
+    let config = load(&["count_switches", "-vvvvvv", "--level", "--level", "--level"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.verbose, config::Verbosity::Trace);
+    assert_eq!(config.level, Level::High);
+}
+
+#[test]
+fn max_count() {
+    assert_eq!(load_err(&["count_switches", "-qqq"], &[]), "Invalid configuration: Configuration parameter 'quiet' is 3, but it must be at most 2.");
+
+    let path = std::env::temp_dir().join(format!("configure_me_count_switches_{}.toml", std::process::id()));
+    std::fs::write(&path, "level = 4\n").unwrap();
+    let err = load_err(&["count_switches"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err, "Invalid configuration: Configuration parameter 'level' is 4, but it must be at most 3.");
+}
+
+#[test]
+fn mapped_counts_are_exported() {
+    let config = load(&["count_switches", "-vv", "--level"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.to_args(), ["--verbose", "--verbose", "--level"]);
+    let fields = config.non_default_fields().into_iter().map(|field| (field.name, field.value)).collect::<Vec<_>>();
+    assert_eq!(fields, [("verbose", Some("2".to_owned())), ("level", Some("1".to_owned()))]);
+}
@@ -29,6 +29,17 @@ fn custom_args_with_two_remaining() {
     assert_eq!(remaining.next(), None);
 }
 
+#[test]
+fn custom_args_stop_at_first_positional() {
+    let (config, mut remaining) = config::Config::custom_args_and_optional_files(&["custom_args", "run", "--foo", "42", "--unknown"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.foo, None);
+    assert_eq!(remaining.next(), Some("run".into()));
+    assert_eq!(remaining.next(), Some("--foo".into()));
+    assert_eq!(remaining.next(), Some("42".into()));
+    assert_eq!(remaining.next(), Some("--unknown".into()));
+    assert_eq!(remaining.next(), None);
+}
+
 #[test]
 fn custom_args_with_two_dashes() {
     let (config, mut remaining) = config::Config::custom_args_and_optional_files(&["custom_args", "--", "--foo", "42"], iter::empty::<PathBuf>()).unwrap();
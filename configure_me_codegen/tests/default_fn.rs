@@ -0,0 +1,35 @@
+macro_rules! test_name { () => { "default_fn" } }
+
+include!("glue/boilerplate.rs");
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn default_workers() -> usize {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    8
+}
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+// A single test, so that the calls are counted and the environment is modified without races.
+#[test]
+fn default_fn_called_only_without_value() {
+    let config = load(&["default_fn", "--workers", "2"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.workers, 2);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+    std::env::set_var("DEFAULT_FN_WORKERS", "3");
+    let from_env = load(&["default_fn"], &[]).map(|config| config.workers);
+    std::env::remove_var("DEFAULT_FN_WORKERS");
+    assert_eq!(from_env.unwrap_or_else(|err| panic!("{}", err)), 3);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+    let config = load(&["default_fn"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.workers, 8);
+    assert_eq!(config.host, "localhost");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
@@ -10,7 +10,7 @@ fn no_args() {
     let result = config::Config::custom_args_and_optional_files(&["display"], iter::empty::<PathBuf>());
     match result {
         Ok(_) => panic!("This shouldn't succeed"),
-        Err(err) => assert_eq!(err.to_string(), "Invalid configuration: Configuration parameter 'foo' not specified."),
+        Err(err) => assert_eq!(err.to_string(), "Invalid configuration: Configuration parameter 'foo' (--foo, TEST_APP_FOO) not specified."),
     }
 }
 
@@ -0,0 +1,67 @@
+macro_rules! test_name { () => { "drop_ins" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::{Path, PathBuf};
+
+use config::ReportSource;
+
+/// Creates `app.toml` and its drop-in directory in a fresh directory
+fn write_configs(name: &str, main: Option<&str>, drop_ins: &[(&str, &str)]) -> (PathBuf, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("configure_me_drop_ins_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("app.toml.d")).unwrap();
+    let main_path = dir.join("app.toml");
+    if let Some(main) = main {
+        std::fs::write(&main_path, main).unwrap();
+    }
+    for (file, content) in drop_ins {
+        std::fs::write(dir.join("app.toml.d").join(file), content).unwrap();
+    }
+    (dir, main_path)
+}
+
+fn load(args: &[&str], files: &[&Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+#[test]
+fn drop_ins_override_file() {
+    let (dir, main) = write_configs("override", Some("port = 1\nhost = \"a\"\ntag = \"x\"\n"), &[
+        ("20-b.toml", "host = \"c\"\n"),
+        ("10-a.toml", "port = 2\nhost = \"b\"\ntag = \"y\"\n"),
+        ("notes.txt", "port = 3\n"),
+    ]);
+    let config = load(&["drop_ins"], &[&main]);
+    let from_arg = load(&["drop_ins", "--config", main.to_str().unwrap()], &[]);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    for config in [config, from_arg] {
+        let config = config.unwrap_or_else(|err| panic!("{}", err));
+        assert_eq!(config.port, Some(2));
+        assert_eq!(config.host.as_deref(), Some("c"));
+        assert_eq!(config.tag.as_deref(), Some("xy"));
+    }
+}
+
+#[test]
+fn drop_ins_without_file() {
+    let (dir, main) = write_configs("without_file", None, &[("port.toml", "port = 8080\n")]);
+    let config = load(&["drop_ins"], &[&main]);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(config.unwrap_or_else(|err| panic!("{}", err)).port, Some(8080));
+
+    // A missing file without drop-ins is still optional
+    assert_eq!(load(&["drop_ins"], &[&main]).unwrap_or_else(|err| panic!("{}", err)).port, None);
+}
+
+#[test]
+fn drop_ins_are_explained() {
+    let (dir, main) = write_configs("explain", Some("host = \"a\"\n"), &[("10-host.toml", "host = \"b\"\n")]);
+    let report = config::Config::explain(&["drop_ins"], std::iter::empty::<(&str, &str)>(), &[&main]);
+    std::fs::remove_dir_all(&dir).unwrap();
+    let report = report.unwrap_or_else(|err| panic!("{}", err));
+    let host = report.fields.iter().find(|field| field.name == "host").unwrap();
+    let sources = host.contributions.iter().map(|contribution| contribution.source.clone()).collect::<Vec<_>>();
+    assert_eq!(sources, [ReportSource::File(main.clone()), ReportSource::File(dir.join("app.toml.d").join("10-host.toml"))]);
+}
@@ -0,0 +1,57 @@
+macro_rules! test_name { () => { "dump_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::process::{Command, Output};
+
+const CHILD_VAR: &str = "CONFIGURE_ME_DUMP_CONFIG_CHILD";
+
+/// Loads the configuration with the arguments passed to the test by `run_child()`
+#[test]
+fn dump_child() {
+    let args = match std::env::var(CHILD_VAR) {
+        Ok(args) => args,
+        Err(_) => return,
+    };
+    let args = std::iter::once("dump_config").chain(args.split(' ')).collect::<Vec<_>>();
+    match config::Config::custom_args_and_optional_files(args, &[] as &[&str]).map(|(config, _)| config) {
+        Ok(_) => println!("the configuration was returned"),
+        Err(err) => println!("error: {}", err),
+    }
+}
+
+fn run_child(args: &str) -> Output {
+    Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "dump_child", "--nocapture", "--test-threads=1"])
+        .env(CHILD_VAR, args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn effective_config_is_dumped() {
+    let output = run_child("--dump-config --password hunter2 --verbose");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("password = \"<redacted>\"\nport = 8080\nverbose = true\n"), "{}", stdout);
+    assert!(!stdout.contains("hunter2"), "{}", stdout);
+    assert!(!stdout.contains("the configuration was returned"), "{}", stdout);
+
+    let output = run_child("--dump-config --port invalid");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("Error: "), "{}", stderr);
+
+    // The configuration is returned as usual without the switch
+    let output = run_child("--port 80");
+    assert!(String::from_utf8(output.stdout).unwrap().contains("the configuration was returned"));
+}
+
+#[test]
+fn abbreviated_switch() {
+    let output = run_child("--dump --port 80");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("port = 80\n"), "{}", stdout);
+    assert!(!stdout.contains("the configuration was returned"), "{}", stdout);
+}
@@ -0,0 +1,61 @@
+macro_rules! test_name { () => { "duplicate_args" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn parse(args: &[&str]) -> Result<config::Config, config::Error> {
+    let args = iter::once("duplicate_args").chain(args.iter().cloned());
+    config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()).map(|(config, _)| config)
+}
+
+fn error_message(args: &[&str]) -> String {
+    match parse(args) {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn single_occurrences() {
+    let config = parse(&["--host", "example.com", "--port", "80", "-v", "--no-color"]).unwrap();
+    assert_eq!(config.host, Some("example.com".to_owned()));
+    assert_eq!(config.port, Some(80));
+    assert!(config.verbose);
+    assert!(!config.color);
+}
+
+#[test]
+fn error() {
+    assert_eq!(error_message(&["--host", "example.com", "--host", "example.org"]), "The argument '--host' was specified more than once.");
+    assert_eq!(error_message(&["--host", "example.com", "-H", "example.org"]), "The argument '-H' was specified more than once.");
+    assert_eq!(error_message(&["-v", "--verbose=false"]), "The argument '--verbose' was specified more than once.");
+    assert_eq!(error_message(&["-vv"]), "The argument '-v' was specified more than once.");
+}
+
+#[test]
+fn first_wins() {
+    let config = parse(&["--port", "80", "--port", "8080"]).unwrap();
+    assert_eq!(config.port, Some(80));
+    let config = parse(&["--no-color=false", "--no-color"]).unwrap();
+    assert!(config.color);
+}
+
+#[test]
+fn first_wins_still_parses_values() {
+    assert!(parse(&["--port", "80", "--port", "eighty"]).is_err());
+}
+
+#[test]
+fn last_wins() {
+    let config = parse(&["--level", "info", "--level", "debug"]).unwrap();
+    assert_eq!(config.level, Some("debug".to_owned()));
+}
+
+#[test]
+fn repeatable() {
+    let config = parse(&["--tag", "a", "--tag", "b", "-dd", "--debug"]).unwrap();
+    assert_eq!(config.tag, Some("a,b".to_owned()));
+    assert_eq!(config.debug, 3);
+}
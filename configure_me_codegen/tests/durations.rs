@@ -0,0 +1,36 @@
+macro_rules! test_name { () => { "durations" } }
+
+include!("glue/boilerplate.rs");
+
+use std::time::Duration;
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the duration is invalid").to_string()
+}
+
+#[test]
+fn durations_from_args() {
+    let config = load(&["durations", "--timeout", "1h30m", "--retry-delay", "500ms"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.timeout, Duration::from_secs(90 * 60));
+    assert_eq!(config.retry_delay, Some(Duration::from_millis(500)));
+
+    let config = load(&["durations"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.timeout, Duration::from_secs(30));
+    assert_eq!(config.retry_delay, None);
+}
+
+#[test]
+fn invalid_durations() {
+    let err = load_err(&["durations", "--timeout", "5 parsecs"], &[]);
+    assert!(err.starts_with("Failed to parse argument '--timeout'"), "{}", err);
+
+    let path = std::env::temp_dir().join(format!("configure_me_durations_invalid_{}.toml", std::process::id()));
+    std::fs::write(&path, "timeout = \"soon\"\n").unwrap();
+    let err = load_err(&["durations"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert!(err.contains("invalid duration 'soon'"), "{}", err);
+}
@@ -0,0 +1,27 @@
+//! Has its own binary because it sets an env var, which the tests in `durations.rs` would see
+
+macro_rules! test_name { () => { "durations" } }
+
+include!("glue/boilerplate.rs");
+
+use std::time::Duration;
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+#[test]
+fn durations_from_all_sources() {
+    let path = std::env::temp_dir().join(format!("configure_me_durations_{}.toml", std::process::id()));
+    std::fs::write(&path, "timeout = \"5m\"\nretry_delay = \"2s\"\n").unwrap();
+    let from_file = load(&["durations"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    let from_file = from_file.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(from_file.timeout, Duration::from_secs(300));
+    assert_eq!(from_file.retry_delay, Some(Duration::from_secs(2)));
+
+    std::env::set_var("DURATIONS_TIMEOUT", "1m 15s");
+    let from_env = load(&["durations"], &[]);
+    std::env::remove_var("DURATIONS_TIMEOUT");
+    assert_eq!(from_env.unwrap_or_else(|err| panic!("{}", err)).timeout, Duration::from_secs(75));
+}
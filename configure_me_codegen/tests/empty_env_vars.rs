@@ -0,0 +1,29 @@
+macro_rules! test_name { () => { "empty_env_vars" } }
+
+include!("glue/boilerplate.rs");
+
+fn parse() -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(&["test"], None::<&str>).map(|(config, _)| config)
+}
+
+// A single test, so that the environment isn't modified concurrently.
+#[test]
+fn empty_env_vars() {
+    std::env::set_var("EMPTY_ENV_TEST_TLS_CERT", "");
+    std::env::set_var("EMPTY_ENV_TEST_NAME", "");
+    std::env::set_var("EMPTY_ENV_TEST_VERBOSE", "");
+    let config = parse().unwrap();
+    assert_eq!(config.tls_cert, None);
+    assert_eq!(config.name.as_ref().map(AsRef::as_ref), Some(""));
+    assert!(!config.verbose);
+
+    std::env::set_var("EMPTY_ENV_TEST_KEY", "");
+    let err = parse().err().expect("empty key is rejected");
+    assert_eq!(err.to_string(), "The environment variable 'EMPTY_ENV_TEST_KEY' is set but empty.");
+
+    std::env::set_var("EMPTY_ENV_TEST_KEY", "secret");
+    std::env::set_var("EMPTY_ENV_TEST_TLS_CERT", "cert.pem");
+    let config = parse().unwrap();
+    assert_eq!(config.key.as_ref().map(AsRef::as_ref), Some("secret"));
+    assert_eq!(config.tls_cert.as_ref().map(AsRef::as_ref), Some("cert.pem"));
+}
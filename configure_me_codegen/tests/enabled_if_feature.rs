@@ -0,0 +1,28 @@
+//! Has its own binary because it sets an env var, which would race with other tests reading them
+
+extern crate configure_me_codegen;
+
+const FEATURES: &str =
+r#"
+[[param]]
+name = "metrics_port"
+type = "u16"
+enabled_if_feature = "configure-me-test-metrics"
+
+[[param]]
+name = "tracing_addr"
+type = "String"
+enabled_if_feature = "configure-me-test-tracing"
+
+[[switch]]
+name = "verbose"
+"#;
+
+#[test]
+fn enabled_if_feature() {
+    std::env::set_var("CARGO_FEATURE_CONFIGURE_ME_TEST_METRICS", "1");
+    let code = configure_me_codegen::generate_to_string(FEATURES).unwrap();
+    assert!(code.contains("metrics_port"));
+    assert!(!code.contains("tracing_addr"));
+    assert!(code.contains("verbose"));
+}
@@ -0,0 +1,48 @@
+macro_rules! test_name { () => { "enum_values" } }
+
+include!("glue/boilerplate.rs");
+
+use config::{Format, LogLevel};
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_ok(args: &[&str], files: &[&std::path::Path]) -> config::Config {
+    load(args, files).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("plain-text".parse::<Format>().unwrap(), Format::PlainText);
+    assert_eq!(Format::PlainText.to_string(), "plain-text");
+    assert_eq!(Format::VALUES, ["json", "yaml", "plain-text"]);
+    let err = "xml".parse::<Format>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid value 'xml', possible values: json, yaml, plain-text");
+}
+
+#[test]
+fn enum_values() {
+    let config = load_ok(&["enum_values"], &[]);
+    assert_eq!(config.format, Format::Json);
+    assert!(config.levels.is_none());
+
+    let config = load_ok(&["enum_values", "-f", "yaml", "--levels", "warn", "--levels=error"], &[]);
+    assert_eq!(config.format, Format::Yaml);
+    assert_eq!(config.levels.unwrap(), [LogLevel::Warn, LogLevel::Error]);
+
+    let err = load(&["enum_values", "--format", "xml"], &[]).err().expect("invalid format is rejected");
+    assert!(err.to_string().contains("invalid value 'xml', possible values: json, yaml, plain-text"), "{}", err);
+
+    let path = std::env::temp_dir().join(format!("configure_me_enum_values_{}.toml", std::process::id()));
+    std::fs::write(&path, "format = \"plain-text\"\nlevels = [\"info\"]\n").unwrap();
+    let config = load_ok(&["enum_values"], &[&path]);
+    assert_eq!(config.format, Format::PlainText);
+    assert_eq!(config.levels.as_ref().unwrap(), &[LogLevel::Info]);
+    assert!(config.to_redacted_toml().unwrap().contains("format = \"plain-text\""));
+
+    std::fs::write(&path, "format = \"xml\"\n").unwrap();
+    let err = load(&["enum_values"], &[&path]).err().expect("invalid format is rejected");
+    std::fs::remove_file(&path).unwrap();
+    assert!(err.to_string().contains("invalid value 'xml', possible values: json, yaml, plain-text"), "{}", err);
+}
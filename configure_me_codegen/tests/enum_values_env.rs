@@ -0,0 +1,31 @@
+//! Has its own binary because it sets env vars, which the tests in `enum_values.rs` would see
+
+macro_rules! test_name { () => { "enum_values" } }
+
+include!("glue/boilerplate.rs");
+
+use config::{Format, LogLevel};
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_ok(args: &[&str], files: &[&std::path::Path]) -> config::Config {
+    load(args, files).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[test]
+fn enum_values_from_env() {
+    std::env::set_var("ENUM_VALUES_FORMAT", "yaml");
+    std::env::set_var("ENUM_VALUES_LEVELS", "error,info");
+    let config = load_ok(&["enum_values"], &[]);
+    assert_eq!(config.format, Format::Yaml);
+    assert_eq!(config.levels.as_ref().unwrap(), &[LogLevel::Error, LogLevel::Info]);
+    let vars = config.to_env_vars();
+    assert!(vars.contains(&("ENUM_VALUES_FORMAT".into(), "yaml".into())));
+    assert!(vars.contains(&("ENUM_VALUES_LEVELS".into(), "error,info".into())));
+
+    std::env::set_var("ENUM_VALUES_FORMAT", "xml");
+    let err = load(&["enum_values"], &[]).err().expect("invalid format is rejected");
+    assert!(err.to_string().contains("invalid value 'xml', possible values: json, yaml, plain-text"), "{}", err);
+}
@@ -0,0 +1,36 @@
+macro_rules! test_name { () => { "env_file" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, &[] as &[&std::path::Path]).map(|(config, _)| config)
+}
+
+// A single test, so that the environment and the path of the file aren't modified concurrently.
+#[test]
+fn env_file_before_env_vars() {
+    let config = load(&["env_file"]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.host, "dev.example.com");
+    assert_eq!(config.label.unwrap()["team"], "core # not a comment");
+    assert!(config.verbose);
+
+    std::env::set_var("ENV_FILE_PORT", "9090");
+    let from_env = load(&["env_file", "--host", "example.com"]);
+    std::env::remove_var("ENV_FILE_PORT");
+    let from_env = from_env.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(from_env.port, 9090);
+    assert_eq!(from_env.host, "example.com");
+
+    let path = std::env::temp_dir().join(format!("configure_me_env_file_{}.env", std::process::id()));
+    std::fs::write(&path, "ENV_FILE_PORT=1234\nENV_FILE_HOST\n").unwrap();
+    config::Config::set_env_file(&path);
+    let err = load(&["env_file"]).err().expect("the file is invalid").to_string();
+    std::fs::remove_file(&path).unwrap();
+    assert!(err.ends_with("line 2: expected NAME=value"), "{}", err);
+
+    // A missing file sets no variables
+    config::Config::set_env_file(&path);
+    let err = load(&["env_file"]).err().expect("the port is missing").to_string();
+    assert_eq!(err, "Invalid configuration: Configuration parameter 'port' (--port, ENV_FILE_PORT) not specified.");
+}
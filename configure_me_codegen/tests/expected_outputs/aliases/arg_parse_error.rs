@@ -0,0 +1,3 @@
+    FieldColor(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldThreads(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,4 @@
+    pub color: String,
+    pub threads: Option<u32>,
+    pub quiet: bool,
+    pub cache: bool,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--color COLOR] [--threads THREADS] [--quiet] [--no-cache]\n\nArguments:\n        --color       When to use colors\n        --threads     Number of worker threads. Aliases: --jobs, --workers.\n        --quiet       Aliases: --silent.\n        --no-cache    Cache downloaded files", program_name),
+        ArgParseError::FieldColor(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--color", err),
+        ArgParseError::FieldThreads(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u32>>(f, "--threads", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,4 @@
+        EnvParseError::FieldColor(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "ALIASES_COLOR", err),
+        EnvParseError::FieldThreads(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u32>>(f, "ALIASES_THREADS", err),
+        EnvParseError::FieldQuiet(ref err) => ::configure_me::helpers::display_switch_error(f, "ALIASES_QUIET", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::FieldCache(ref err) => ::configure_me::helpers::display_switch_error(f, "ALIASES_CACHE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,4 @@
+    FieldColor(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldThreads(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldQuiet(::std::ffi::OsString),
+    FieldCache(::std::ffi::OsString),
@@ -0,0 +1,40 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--color", &arg, &mut iter) {
+                    let color = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--color"), ArgParseError::FieldColor))?;
+
+                    self.color = Some(color);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--colour", &arg, &mut iter) {
+                    let color = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--colour"), ArgParseError::FieldColor))?;
+
+                    self.color = Some(color);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--threads", &arg, &mut iter) {
+                    let threads = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--threads"), ArgParseError::FieldThreads))?.0;
+
+                    self.threads = Some(threads);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--jobs", &arg, &mut iter) {
+                    let threads = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--jobs"), ArgParseError::FieldThreads))?.0;
+
+                    self.threads = Some(threads);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--workers", &arg, &mut iter) {
+                    let threads = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--workers"), ArgParseError::FieldThreads))?.0;
+
+                    self.threads = Some(threads);
+                } else if arg == *"--quiet" {
+                    self.quiet = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--quiet", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--quiet", value))?;
+                    self.quiet = Some(value);
+                } else if arg == *"--silent" {
+                    self.quiet = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--silent", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--silent", value))?;
+                    self.quiet = Some(value);
+                } else if arg == *"--no-cache" {
+                    self.cache = Some(false);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--no-cache", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--no-cache", value))?;
+                    self.cache = Some(!value);
+                } else if arg == *"--no-caching" {
+                    self.cache = Some(false);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--no-caching", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--no-caching", value))?;
+                    self.cache = Some(!value);
@@ -0,0 +1,8 @@
+        ::configure_me::helpers::merge(&mut self.color, ::configure_me::helpers::parse_env("ALIASES_COLOR").map_err(super::EnvParseError::FieldColor)?);
+        ::configure_me::helpers::merge(&mut self.threads, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u32>>("ALIASES_THREADS").map_err(super::EnvParseError::FieldThreads)?.map(|value| value.0));
+        if let Some(val) = ::std::env::var_os("ALIASES_QUIET") {
+            self.quiet = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldQuiet)?);
+        }
+        if let Some(val) = ::std::env::var_os("ALIASES_CACHE") {
+            self.cache = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldCache)?);
+        }
@@ -0,0 +1,8 @@
+            ::configure_me::helpers::merge(&mut self.color, other.color);
+            ::configure_me::helpers::merge(&mut self.threads, other.threads);
+            if other.quiet.is_some() {
+                self.quiet = other.quiet;
+            }
+            if other.cache.is_some() {
+                self.cache = other.cache;
+            }
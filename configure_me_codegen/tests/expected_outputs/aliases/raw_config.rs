@@ -0,0 +1,11 @@
+        #[serde(alias = "colour")]
+        color: Option<String>,
+        #[serde(alias = "jobs")]
+        #[serde(alias = "workers")]
+        threads: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        #[serde(alias = "silent")]
+        quiet: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        #[serde(alias = "caching")]
+        cache: Option<bool>,
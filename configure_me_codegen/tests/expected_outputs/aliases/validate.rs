@@ -0,0 +1,9 @@
+            let color = self.color.unwrap_or_else(|| { "auto".to_owned() });
+            let threads = self.threads;
+
+            Ok(super::Config {
+                color: color.into(),
+                threads: threads.map(Into::into),
+                quiet: self.quiet.unwrap_or(false),
+                cache: self.cache.unwrap_or(true),
+            })
@@ -0,0 +1,13 @@
+
+::configure_me::if_test_helpers! {
+    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for Config {
+        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {
+            Ok(Config {
+                port: u.arbitrary()?,
+                peers: if u.arbitrary()? { Some(::configure_me::helpers::arbitrary_values(u, 1, 3)?) } else { None },
+                ratio: u.arbitrary()?,
+                verbose: u.arbitrary()?,
+            })
+        }
+    }
+}
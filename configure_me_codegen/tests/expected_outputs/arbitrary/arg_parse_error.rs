@@ -0,0 +1,3 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPeers(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldRatio(<::configure_me::helpers::Int<u8> as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,4 @@
+    pub port: u16,
+    pub peers: Option<Vec<String>>,
+    pub ratio: [u8; 2],
+    pub verbose: u32,
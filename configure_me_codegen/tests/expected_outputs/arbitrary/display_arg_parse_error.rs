@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--peers PEERS...] [--ratio RATIO RATIO] [--verbose ...]", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldPeers(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--peers", err),
+        ArgParseError::FieldRatio(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u8>>(f, "--ratio", err),
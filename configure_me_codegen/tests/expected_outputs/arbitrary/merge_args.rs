@@ -0,0 +1,23 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peers", &arg, &mut iter) {
+                    let mut peers = vec![value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peers"), ArgParseError::FieldPeers))?];
+                    while peers.len() < 3 {
+                        if iter.peek().map_or(true, |next| next.to_str().map_or(false, |next| next.starts_with('-'))) {
+                            break;
+                        }
+                        peers.push(::configure_me::parse_arg::ParseArg::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("--peers"))?).map_err(ArgParseError::FieldPeers)?);
+                    }
+
+                    self.peers = Some(peers);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u8>, _, _>("--ratio", &arg, &mut iter) {
+                    let ratio = [
+                        value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--ratio"), ArgParseError::FieldRatio))?.0,
+                        <::configure_me::helpers::Int<u8> as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("--ratio"))?).map_err(ArgParseError::FieldRatio)?.0,
+                    ];
+
+                    self.ratio = Some(ratio);
+                } else if arg == *"--verbose" {
+                    *(self.verbose.get_or_insert(0)) += 1;
@@ -0,0 +1,6 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.peers, other.peers);
+            ::configure_me::helpers::merge(&mut self.ratio, other.ratio);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,4 @@
+        port: Option<u16>,
+        peers: Option<Vec<String>>,
+        ratio: Option<[u8; 2]>,
+        verbose: Option<u32>,
@@ -0,0 +1,19 @@
+            let port = self.port;
+            let peers = self.peers;
+            let ratio = self.ratio.unwrap_or_else(|| { [1, 2] });
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                peers: peers.map(Into::into),
+                ratio: ratio.into(),
+                verbose: self.verbose.unwrap_or(0),
+            })
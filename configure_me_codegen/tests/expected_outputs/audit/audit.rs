@@ -0,0 +1,24 @@
+
+static AUDIT_CALLBACK: ::std::sync::Mutex<Option<::configure_me::helpers::AuditCallback>> = ::std::sync::Mutex::new(None);
+
+impl Config {
+    /// Registers the callback invoked for every param and switch when the configuration is loaded
+    ///
+    /// The callback gets the sources of the value and the value itself, redacted for secret params.
+    /// It may be invoked from multiple threads at once and may load the configuration itself.
+    pub fn set_audit_callback<F: Fn(&::configure_me::helpers::AuditEntry) + Send + Sync + 'static>(callback: F) {
+        *AUDIT_CALLBACK.lock().unwrap_or_else(|err| err.into_inner()) = Some(::std::sync::Arc::new(callback));
+    }
+
+    fn audit(&self, sources: &::configure_me::helpers::Sources) {
+        let callback = AUDIT_CALLBACK.lock().unwrap_or_else(|err| err.into_inner()).clone();
+        let callback = match callback {
+            Some(callback) => callback,
+            None => return,
+        };
+        callback(&::configure_me::helpers::AuditEntry { name: "port", sources: sources.of("port"), value: Some(::configure_me::helpers::format_value(&self.port)) });
+        callback(&::configure_me::helpers::AuditEntry { name: "token", sources: sources.of("token"), value: self.token.as_ref().map(|_| ::configure_me::helpers::REDACTED.to_owned()) });
+        callback(&::configure_me::helpers::AuditEntry { name: "tags", sources: sources.of("tags"), value: self.tags.as_ref().map(|value| ::configure_me::helpers::format_value(value)) });
+        callback(&::configure_me::helpers::AuditEntry { name: "verbose", sources: sources.of("verbose"), value: Some(self.verbose.to_string()) });
+    }
+}
@@ -0,0 +1,4 @@
+    pub port: u16,
+    pub token: Option<String>,
+    pub tags: Option<String>,
+    pub verbose: u32,
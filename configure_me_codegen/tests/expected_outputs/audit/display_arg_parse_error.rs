@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --config         Load configuration from this file.\n        --port\n\n        --token\n\n        --tags\n\n        -v, --verbose\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldToken(_) => ::configure_me::helpers::display_secret_arg_error::<String>(f, "--token"),
+        ArgParseError::FieldTags(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tags", err),
@@ -0,0 +1,9 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "AUDIT_PORT", err),
+        EnvParseError::FieldToken(_) => ::configure_me::helpers::display_secret_env_error::<String>(f, "AUDIT_TOKEN"),
+        EnvParseError::FieldTags(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "AUDIT_TAGS", err),
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value for 'AUDIT_VERBOSE': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::SecretFile { name, ref file, ref error } => write!(f, "Failed to read the file {} named by the environment variable '{}_FILE': {}", file.display(), name, error),
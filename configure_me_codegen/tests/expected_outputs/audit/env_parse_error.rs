@@ -0,0 +1,5 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldToken(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTags(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    SecretFile { name: &'static str, file: ::std::path::PathBuf, error: ::std::io::Error },
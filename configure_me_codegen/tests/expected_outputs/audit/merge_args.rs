@@ -0,0 +1,27 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self._sources.set("port", ::configure_me::helpers::Source::Argument("--port"));
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--token", &arg, &mut iter) {
+                    let token = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--token"), ArgParseError::FieldToken))?;
+
+                    self._sources.set("token", ::configure_me::helpers::Source::Argument("--token"));
+                    self.token = Some(token);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tags", &arg, &mut iter) {
+                    let tags = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tags"), ArgParseError::FieldTags))?;
+
+                    self._sources.add("tags", ::configure_me::helpers::Source::Argument("--tags"));
+                    ::configure_me::helpers::merge_with(&mut self.tags, Some(tags), (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+                } else if arg == *"--verbose" {
+                    self._sources.add("verbose", ::configure_me::helpers::Source::Argument("--verbose"));
+                    *(self.verbose.get_or_insert(0)) += 1;
@@ -0,0 +1,7 @@
+        ::configure_me::helpers::merge(&mut self.port, self._sources.track("port", ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("AUDIT_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0), ::configure_me::helpers::Source::EnvVar("AUDIT_PORT")));
+        ::configure_me::helpers::merge(&mut self.token, self._sources.track("token", ::configure_me::helpers::parse_env_value(::configure_me::helpers::secret_env_var("AUDIT_TOKEN", |name| ::std::env::var_os(name)).map_err(|(file, error)| super::EnvParseError::SecretFile { name: "AUDIT_TOKEN", file, error })?).map_err(super::EnvParseError::FieldToken)?, ::configure_me::helpers::Source::EnvVar("AUDIT_TOKEN")));
+        ::configure_me::helpers::merge_with(&mut self.tags, self._sources.track_combined("tags", ::configure_me::helpers::parse_env("AUDIT_TAGS").map_err(super::EnvParseError::FieldTags)?, ::configure_me::helpers::Source::EnvVar("AUDIT_TAGS")), (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+        if let Some(val) = ::std::env::var_os("AUDIT_VERBOSE") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldVerbose)?;            self.verbose = Some(val);
+            self._sources.add("verbose", ::configure_me::helpers::Source::EnvVar("AUDIT_VERBOSE"));
+        }
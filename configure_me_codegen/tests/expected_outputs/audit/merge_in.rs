@@ -0,0 +1,7 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.token, other.token);
+            ::configure_me::helpers::merge_with(&mut self.tags, other.tags, (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            self._sources.merge_in(other._sources);
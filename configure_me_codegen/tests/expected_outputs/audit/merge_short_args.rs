@@ -0,0 +1,3 @@
+                        } else if short == 'v' {
+                            self._sources.add("verbose", ::configure_me::helpers::Source::Argument("-v"));
+                            *(self.verbose.get_or_insert(0)) += 1;
@@ -0,0 +1,7 @@
+        port: Option<u16>,
+        #[serde(default, deserialize_with = "secret_token")]
+        token: Option<String>,
+        tags: Option<String>,
+        verbose: Option<u32>,
+        #[serde(skip)]
+        _sources: ::configure_me::helpers::Sources,
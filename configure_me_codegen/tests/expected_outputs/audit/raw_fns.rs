@@ -0,0 +1,28 @@
+
+    fn secret_token<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+        ::configure_me::helpers::deserialize_secret(deserializer)
+    }
+
+    fn track_file(mut config: Config, file: &::std::path::Path) -> Config {
+        if config.port.is_some() {
+            config._sources.set("port", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.token.is_some() {
+            config._sources.set("token", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.tags.is_some() {
+            config._sources.add("tags", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.verbose.is_some() {
+            config._sources.add("verbose", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        config
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
@@ -0,0 +1,12 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let token = self.token;
+            let tags = self.tags;
+
+            let config = super::Config {
+                port: port.into(),
+                token: token.map(Into::into),
+                tags: tags.map(Into::into),
+                verbose: self.verbose.unwrap_or(0),
+            };
+            config.audit(&self._sources);
+            Ok(config)
@@ -0,0 +1,27 @@
+
+impl Config {
+    /// Loads and validates the configuration and exits with a report instead of returning
+    fn check_config<I>(args: Vec<::std::ffi::OsString>, config_files: I) -> ! where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+        use std::io::Write;
+
+        let args = ::configure_me::helpers::remove_normalized_switch(args, "--check-config", raw::normalize_long_option);
+        match Self::custom_args_and_optional_files(args, config_files) {
+            Ok(_) => {
+                println!("The configuration is valid.");
+                std::io::stdout().flush().expect("failed to flush stdout");
+                ::std::process::exit(0)
+            },
+            Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {
+                println!("{}", err);
+                std::io::stdout().flush().expect("failed to flush stdout");
+                ::std::process::exit(0)
+            },
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                eprintln!("The configuration is invalid.");
+                std::io::stderr().flush().expect("failed to flush stderr");
+                ::std::process::exit(1)
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+                } else if arg == *"--check-config" {
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
@@ -0,0 +1,25 @@
+
+    pub const VALUED_OPTIONS: &[(&str, usize, usize)] = &[("--port", 1, 1)];
+
+    const LONG_OPTIONS: &[&str] = &["--help", "--check-config", "--port"];
+
+    pub fn normalize_long_option(arg: ::std::ffi::OsString) -> Result<::std::ffi::OsString, ArgParseError> {
+        let (name, value) = match arg.to_str() {
+            Some(arg) if arg.starts_with("--") && arg.len() > 2 => match arg.find('=') {
+                Some(pos) => (arg[..pos].to_owned(), arg[pos..].to_owned()),
+                None => (arg.to_owned(), String::new()),
+            },
+            Some("/?") => return Ok("--help".into()),
+            Some(arg) if arg.starts_with('/') && arg.len() > 1 => match arg.find(&[':', '='][..]) {
+                Some(pos) => (format!("--{}", &arg[1..pos]), format!("={}", &arg[(pos + 1)..])),
+                None => (format!("--{}", &arg[1..]), String::new()),
+            },
+            _ => return Ok(arg),
+        };
+        let name = name.to_ascii_lowercase();
+
+        if LONG_OPTIONS.contains(&&*name) {
+            return Ok(format!("{}{}", name, value).into());
+        }
+        Ok(arg)
+    }
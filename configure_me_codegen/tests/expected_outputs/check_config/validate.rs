@@ -0,0 +1,14 @@
+            let port = self.port;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+            })
@@ -1,3 +1,3 @@
-    FieldFoo(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFoo(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
     OpenConfDir(std::io::Error, std::path::PathBuf),
     ReadConfDir(std::io::Error, std::path::PathBuf),
@@ -1,6 +1,11 @@
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
                     let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
-                    let mut config = Config::load(file_path)?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
                     self.merge_in(config);
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--conf-dir", &arg, &mut iter) {
                     let dir_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--conf-dir"), |never| match never {}))?;
@@ -10,16 +15,24 @@
                         Err(err) => return Err(ArgParseError::OpenConfDir(err, dir_path).into()),
                     };
 
+                    let mut files = Vec::new();
                     for file in dir {
                         let file = match file {
                             Ok(file) => file,
                             Err(err) => return Err(ArgParseError::ReadConfDir(err, dir_path).into()),
                         };
+                        if !file.file_type().map_or(false, |file_type| file_type.is_dir()) {
+                            files.push(file.path());
+                        }
+                    }
 
-                        let mut config = Config::load(file.path())?;
+                    // Later files override earlier ones
+                    files.sort();
+                    for file_path in files {
+                        let mut config = self.load_once(file_path)?;
                         self.merge_in(config);
                     }
-                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--foo", &arg, &mut iter) {
-                    let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?;
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--foo", &arg, &mut iter) {
+                    let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?.0;
 
                     self.foo = Some(foo);
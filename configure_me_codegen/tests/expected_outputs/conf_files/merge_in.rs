@@ -1,3 +1 @@
-            if other.foo.is_some() {
-                self.foo = other.foo;
-            }
+            ::configure_me::helpers::merge(&mut self.foo, other.foo);
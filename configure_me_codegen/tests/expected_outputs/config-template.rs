@@ -2,6 +2,7 @@ pub mod prelude {
     pub use super::{Config, ResultExt};
 }
 
+<<"enums.rs">>
 pub enum ArgParseError {
     MissingArgument(&'static str),
     UnknownArgument(String),
@@ -44,14 +45,45 @@ impl ::std::fmt::Debug for EnvParseError {
     }
 }
 
+/// Mandatory param missing in all sources
+pub struct MissingField {
+    pub name: &'static str,
+    /// Arguments and environment variables that can specify it
+    pub sources: &'static [&'static str],
+}
+
 pub enum ValidationError {
-    MissingField(&'static str),
+    MissingFields(Vec<MissingField>),
+    /// Value of a param outside of the range allowed by its `min` and `max`
+    OutOfRange { name: &'static str, value: String, range: &'static str },
+    /// Value of a param not matching its `validate_regex`
+    PatternMismatch { name: &'static str, value: String, pattern: &'static str },
+    /// Value of a param rejected by its `validate` function
+    Invalid { name: &'static str, message: String },
+    /// Error returned by the function in `general.validate`
+    Custom(String),
+    /// Param or switch set without another one listed in its `requires`
+    MissingRequirement { name: &'static str, sources: Vec<::configure_me::helpers::Source>, required: &'static str },
+    /// Param or switch set together with another one listed in its `conflicts_with`
+    Conflict { name: &'static str, sources: Vec<::configure_me::helpers::Source>, other: &'static str, other_sources: Vec<::configure_me::helpers::Source> },
+    /// No member of a required group set
+    GroupMissing { group: &'static str, members: &'static [&'static str] },
+    /// More than one member of a group set, with the sources of their values
+    GroupConflict { group: &'static str, members: Vec<(&'static str, Vec<::configure_me::helpers::Source>)> },
 }
 
 impl ::std::fmt::Display for ValidationError {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         match self {
-            ValidationError::MissingField(field) => write!(f, "Configuration parameter '{}' not specified.", field),
+            ValidationError::MissingFields(fields) => ::configure_me::helpers::display_missing_fields(f, fields.iter().map(|field| (field.name, field.sources))),
+            ValidationError::OutOfRange { name, value, range } => write!(f, "Configuration parameter '{}' is {}, but it must be {}.", name, value, range),
+            ValidationError::PatternMismatch { name, value, pattern } => write!(f, "Configuration parameter '{}' is '{}', but it must match the pattern '{}'.", name, value, pattern),
+            ValidationError::Invalid { name, message } => write!(f, "Configuration parameter '{}' is invalid: {}", name, message),
+            ValidationError::Custom(error) => write!(f, "{}", error),
+            ValidationError::MissingRequirement { name, sources, required } => ::configure_me::helpers::display_missing_requirement(f, name, sources, required),
+            ValidationError::Conflict { name, sources, other, other_sources } => ::configure_me::helpers::display_conflict(f, name, sources, other, other_sources),
+            ValidationError::GroupMissing { group, members } => ::configure_me::helpers::display_group_missing(f, group, members),
+            ValidationError::GroupConflict { group, members } => ::configure_me::helpers::display_group_conflict(f, group, members),
         }
     }
 }
@@ -109,23 +141,45 @@ impl ::std::fmt::Debug for Error {
 mod raw {
     use ::std::path::PathBuf;
     use super::{ArgParseError, ValidationError};
+<<"raw_imports.rs">>
 
     #[derive(Deserialize, Default)]
     #[serde(crate = "crate::configure_me::serde")]
     pub struct Config {
         _program_path: Option<PathBuf>,
+        #[serde(skip)]
+        pub(super) _loaded_files: Vec<(PathBuf, ::configure_me::helpers::FileDigest)>,
+        #[serde(skip)]
+        pub(super) _parsed_files: Vec<(PathBuf, ::configure_me::toml::value::Table)>,
 <<"raw_config.rs">>
     }
 
     impl Config {
-        pub fn load<P: AsRef<::std::path::Path>>(config_file_name: P) -> Result<Self, super::Error> {
-            use std::io::Read;
+        pub fn parse(&self, config_file_name: &::std::path::Path, config_content: &[u8]) -> Result<Self, super::Error> {
+<<"parse_config.rs">>
+        }
+
+        fn parse_document<D: ::configure_me::helpers::ConfigDocument>(&self, config_file_name: &::std::path::Path, config_content: D) -> Result<Self, super::Error> {
+<<"parse_document.rs">>
+<<"rename_keys.rs">>
+<<"track_file.rs">>
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.into(), error })
+        }
+
+        /// Loads the file, reading and parsing it only once even if it's specified multiple times
+        pub fn load_once<P: Into<PathBuf>>(&mut self, config_file_name: P) -> Result<Self, super::Error> {
+            let config_file_name = config_file_name.into();
+            if let Some((_, table)) = self._parsed_files.iter().find(|(file, _)| *file == config_file_name) {
+                return self.parse_document(&config_file_name, table.clone());
+            }
 
-            let mut config_file = ::std::fs::File::open(&config_file_name).map_err(|error| super::Error::Reading { file: config_file_name.as_ref().into(), error })?;
-            let mut config_content = Vec::new();
-            config_file.read_to_end(&mut config_content).map_err(|error| super::Error::Reading { file: config_file_name.as_ref().into(), error })?;
-            ::configure_me::toml::from_slice(&config_content).map_err(|error| super::Error::ConfigParsing { file: config_file_name.as_ref().into(), error })
+<<"read_config_file.rs">>
+<<"parse_loaded.rs">>
+            self._loaded_files.push((config_file_name.clone(), ::configure_me::helpers::FileDigest::of(&config_content)));
+            self._parsed_files.push((config_file_name, table));
+            Ok(config)
         }
+<<"load_once_as.rs">>
 
         pub fn validate(self) -> Result<super::Config, ValidationError> {
 <<"validate.rs">>
@@ -136,12 +190,14 @@ mod raw {
         }
 
         pub fn merge_args<I: IntoIterator<Item=::std::ffi::OsString>>(&mut self, args: I) -> Result<impl Iterator<Item=::std::ffi::OsString>, super::Error> {
-            let mut iter = args.into_iter().fuse();
+            let mut iter = args.into_iter().fuse().peekable();
             self._program_path = iter.next().map(Into::into);
+<<"merge_args_init.rs">>
 
             while let Some(arg) = iter.next() {
+<<"normalize_arg.rs">>
                 if arg == *"--" {
-                    return Ok(None.into_iter().chain(iter));
+<<"separator.rs">>
                 } else if (arg == *"--help") || (arg == *"-h") {
                     return Err(ArgParseError::HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into()).into());
 <<"merge_args.rs">>
@@ -172,12 +228,15 @@ mod raw {
             Ok(())
         }
     }
+<<"raw_fns.rs">>
 }
 
 /// Configuration of the application
 pub struct Config {
 <<"config.rs">>
 }
+<<"sections.rs">>
+<<"subcommands.rs">>
 
 impl Config {
     pub fn including_optional_config_files<I>(config_files: I) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
@@ -188,10 +247,16 @@ impl Config {
         A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
         I: IntoIterator, I::Item: AsRef<::std::path::Path> {
 
+<<"optional_files_as.rs">>
+<<"check_config_call.rs">>
+<<"poll_init.rs">>
         let mut config = raw::Config::default();
+<<"profile_init.rs">>
         for path in config_files {
-            match raw::Config::load(path) {
+<<"load_config_file.rs">>
                 Ok(mut new_config) => {
+                    new_config._loaded_files = ::std::mem::replace(&mut config._loaded_files, Vec::new());
+                    new_config._parsed_files = ::std::mem::replace(&mut config._parsed_files, Vec::new());
                     std::mem::swap(&mut config, &mut new_config);
                     config.merge_in(new_config)
                 },
@@ -202,13 +267,34 @@ impl Config {
 
         config.merge_env()?;
         let remaining_args = config.merge_args(args.into_iter().map(Into::into))?;
+<<"merge_positionals.rs">>
+<<"merge_subcommand.rs">>
 
         config
             .validate()
+<<"poll_sources.rs">>
             .map(|cfg| (cfg, remaining_args))
             .map_err(Into::into)
     }
 }
+<<"global.rs">>
+<<"shared.rs">>
+<<"export_env_vars.rs">>
+<<"export_args.rs">>
+<<"non_default_fields.rs">>
+<<"interactive_setup.rs">>
+<<"check_config.rs">>
+<<"merge_report.rs">>
+<<"reload.rs">>
+<<"arbitrary.rs">>
+<<"redacted.rs">>
+<<"audit.rs">>
+<<"debug.rs">>
+<<"serialize.rs">>
+<<"env_file.rs">>
+<<"user_config.rs">>
+<<"loaded_config_files.rs">>
+<<"partial_config.rs">>
 
 pub trait ResultExt {
     type Item;
@@ -229,6 +315,7 @@ impl<T> ResultExt for Result<T, Error> {
                 std::io::stdout().flush().expect("failed to flush stdout");
                 ::std::process::exit(0)
             },
+<<"subcommand_errors.rs">>
             Err(err) => {
                 eprintln!("Error: {}", err);
                 std::io::stderr().flush().expect("failed to flush stderr");
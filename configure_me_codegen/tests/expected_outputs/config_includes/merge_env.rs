@@ -0,0 +1,3 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("CONFIG_INCLUDES_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env("CONFIG_INCLUDES_HOST").map_err(super::EnvParseError::FieldHost)?);
+        ::configure_me::helpers::merge_with(&mut self.tag, ::configure_me::helpers::parse_env("CONFIG_INCLUDES_TAG").map_err(super::EnvParseError::FieldTag)?, (|a: &mut String, b: String| a.push_str(&b)));
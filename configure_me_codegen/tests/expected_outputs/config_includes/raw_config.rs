@@ -0,0 +1,7 @@
+        port: Option<u16>,
+        host: Option<String>,
+        tag: Option<String>,
+        #[serde(default, rename = "include")]
+        _include: Vec<PathBuf>,
+        #[serde(skip)]
+        _including: ::configure_me::helpers::Includes,
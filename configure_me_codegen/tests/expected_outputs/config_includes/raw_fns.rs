@@ -0,0 +1,42 @@
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
+
+    impl Config {
+        /// Merges `config`, which is the result of loading the file, into the files it includes
+        ///
+        /// The files listed in the `include` key are relative to the directory of the file, later
+        /// ones override earlier ones and the file overrides all of them.
+        pub fn with_includes(&mut self, config_file_name: &::std::path::Path, config: Result<Self, super::Error>) -> Result<Self, super::Error> {
+            let mut config = config?;
+            if config._include.is_empty() {
+                return Ok(config);
+            }
+            let includes = ::std::mem::replace(&mut config._include, Vec::new());
+            let includes = self._including.enter(config_file_name, includes).map_err(|(file, error)| super::Error::Reading { file, error })?;
+            let included = self.load_includes(includes);
+            self._including.leave();
+            let mut included = included?;
+            included.merge_in(config);
+            Ok(included)
+        }
+
+        fn load_includes(&mut self, includes: Vec<PathBuf>) -> Result<Self, super::Error> {
+            let mut config = Self::default();
+            for file in includes {
+                self._including.check_cycle(&file).map_err(|(file, error)| super::Error::Reading { file, error })?;
+                let loaded = self.load_once(&file).map_err(|err| match err {
+                    super::Error::Reading { file, error } => super::Error::Reading { file, error: ::configure_me::helpers::include_error(error) },
+                    err => err,
+                });
+                let included = self.with_includes(&file, loaded)?;
+                config.merge_in(included);
+            }
+            Ok(config)
+        }
+    }
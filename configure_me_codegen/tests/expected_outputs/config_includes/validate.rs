@@ -0,0 +1,9 @@
+            let port = self.port;
+            let host = self.host;
+            let tag = self.tag;
+
+            Ok(super::Config {
+                port: port.map(Into::into),
+                host: host.map(Into::into),
+                tag: tag.map(Into::into),
+            })
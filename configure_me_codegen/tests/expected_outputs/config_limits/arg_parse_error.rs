@@ -0,0 +1 @@
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
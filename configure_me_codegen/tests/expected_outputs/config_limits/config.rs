@@ -0,0 +1 @@
+    pub name: Option<String>,
@@ -0,0 +1,2 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--name NAME]", program_name),
+        ArgParseError::FieldName(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--name", err),
@@ -0,0 +1,4 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--name", &arg, &mut iter) {
+                    let name = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--name"), ArgParseError::FieldName))?;
+
+                    self.name = Some(name);
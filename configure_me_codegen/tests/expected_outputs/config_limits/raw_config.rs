@@ -0,0 +1 @@
+        name: Option<String>,
@@ -0,0 +1,8 @@
+
+    const CONFIG_FILE_CHECKS: ::configure_me::helpers::ConfigFileChecks = ::configure_me::helpers::ConfigFileChecks {
+        insecure: None,
+        max_size: Some(256),
+        max_string_length: Some(8),
+        max_depth: Some(2),
+        mmap_threshold: ::configure_me::helpers::mmap_threshold(64),
+    };
@@ -0,0 +1,5 @@
+            let name = self.name;
+
+            Ok(super::Config {
+                name: name.map(Into::into),
+            })
@@ -0,0 +1,3 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,3 @@
+    pub port: u16,
+    pub host: String,
+    pub verbose: bool,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--host HOST] [--verbose]", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
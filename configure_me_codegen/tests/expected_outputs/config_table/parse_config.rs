@@ -0,0 +1 @@
+            self.parse_document(config_file_name, config_content)
@@ -0,0 +1,3 @@
+            let table = ::configure_me::helpers::ConfigDocument::into_table(&*config_content)
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.clone(), error })?;
+            let config = self.parse_document(&config_file_name, table.clone())?;
@@ -0,0 +1,4 @@
+        port: Option<u16>,
+        host: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
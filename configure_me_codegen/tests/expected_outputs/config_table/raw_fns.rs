@@ -0,0 +1,7 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
@@ -0,0 +1,17 @@
+            let port = self.port;
+            let host = self.host.unwrap_or_else(|| { "localhost".to_owned() });
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                host: host.into(),
+                verbose: self.verbose.unwrap_or(false),
+            })
@@ -0,0 +1,12 @@
+
+::configure_me::if_test_helpers! {
+    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for Config {
+        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {
+            Ok(Config {
+                verbose: { let count: u32 = u.int_in_range(0..=3)?; match count { 0 => Verbosity::Warn, 1 => Verbosity::Info, 2 => Verbosity::Debug, _ => Verbosity::Trace } },
+                quiet: u.int_in_range(0..=2)?,
+                level: { let count: u32 = u.int_in_range(0..=1)?; match count { 0 => crate::Level::Low, _ => crate::Level::High } },
+            })
+        }
+    }
+}
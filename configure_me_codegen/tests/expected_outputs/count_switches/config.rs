@@ -0,0 +1,3 @@
+    pub verbose: Verbosity,
+    pub quiet: u32,
+    pub level: crate::Level,
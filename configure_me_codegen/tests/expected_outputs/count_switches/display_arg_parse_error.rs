@@ -0,0 +1 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [-v|--verbose ...] [-q|--quiet ...] [--level ...]\n\nArguments:\n        --config         Load configuration from this file.\n        -v, --verbose    Print more details", program_name),
@@ -0,0 +1,15 @@
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value for 'COUNT_SWITCHES_VERBOSE': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldQuiet(ref err) => {
+            write!(f, "Invalid value for 'COUNT_SWITCHES_QUIET': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldLevel(ref err) => {
+            write!(f, "Invalid value for 'COUNT_SWITCHES_LEVEL': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
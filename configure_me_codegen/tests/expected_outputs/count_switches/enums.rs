@@ -0,0 +1,71 @@
+/// Values of `verbose` for the counts of its occurrences
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Verbosity {
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Verbosity {
+    /// Values accepted in arguments, environment variables and config files
+    pub const VALUES: &'static [&'static str] = &["warn", "info", "debug", "trace"];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verbosity::Warn => "warn",
+            Verbosity::Info => "info",
+            Verbosity::Debug => "debug",
+            Verbosity::Trace => "trace",
+        }
+    }
+}
+
+impl ::std::str::FromStr for Verbosity {
+    type Err = ::configure_me::helpers::InvalidValue;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "warn" => Ok(Verbosity::Warn),
+            "info" => Ok(Verbosity::Info),
+            "debug" => Ok(Verbosity::Debug),
+            "trace" => Ok(Verbosity::Trace),
+            _ => Err(::configure_me::helpers::InvalidValue::new(value, Self::VALUES)),
+        }
+    }
+}
+
+impl ::std::fmt::Display for Verbosity {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ::configure_me::parse_arg::ParseArgFromStr for Verbosity {
+    fn describe_type<W: ::std::fmt::Write>(writer: W) -> ::std::fmt::Result {
+        ::configure_me::helpers::describe_values(writer, Self::VALUES)
+    }
+}
+
+impl ::configure_me::ToArgDisplay for Verbosity {}
+
+impl<'de> ::configure_me::serde::Deserialize<'de> for Verbosity {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ::configure_me::helpers::deserialize_from_str(deserializer)
+    }
+}
+
+impl ::configure_me::serde::Serialize for Verbosity {
+    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+::configure_me::if_test_helpers! {
+    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for Verbosity {
+        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {
+            u.choose(&[Verbosity::Warn, Verbosity::Info, Verbosity::Debug, Verbosity::Trace]).copied()
+        }
+    }
+}
+
@@ -0,0 +1,3 @@
+    FieldVerbose(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldQuiet(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLevel(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,13 @@
+
+impl Config {
+    /// Returns arguments reproducing the configuration, excluding the program name
+    ///
+    /// Params without an argument and unset optional params are skipped.
+    pub fn to_args(&self) -> Vec<::std::ffi::OsString> {
+        let mut args = Vec::new();
+        args.extend((0..::configure_me::helpers::mapped_count(&self.verbose, &[Verbosity::Warn, Verbosity::Info, Verbosity::Debug, Verbosity::Trace])).map(|_| "--verbose".into()));
+        args.extend((0..self.quiet).map(|_| "--quiet".into()));
+        args.extend((0..::configure_me::helpers::mapped_count(&self.level, &[crate::Level::Low, crate::Level::High])).map(|_| "--level".into()));
+        args
+    }
+}
@@ -0,0 +1,15 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if arg == *"--verbose" {
+                    *(self.verbose.get_or_insert(0)) += 1;
+                } else if arg == *"--quiet" {
+                    *(self.quiet.get_or_insert(0)) += 1;
+                } else if arg == *"--level" {
+                    *(self.level.get_or_insert(0)) += 1;
@@ -0,0 +1,9 @@
+        if let Some(val) = ::std::env::var_os("COUNT_SWITCHES_VERBOSE") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldVerbose)?;            self.verbose = Some(val);
+        }
+        if let Some(val) = ::std::env::var_os("COUNT_SWITCHES_QUIET") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldQuiet)?;            self.quiet = Some(val);
+        }
+        if let Some(val) = ::std::env::var_os("COUNT_SWITCHES_LEVEL") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldLevel)?;            self.level = Some(val);
+        }
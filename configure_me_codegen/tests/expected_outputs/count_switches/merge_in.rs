@@ -0,0 +1,9 @@
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.quiet.is_some() {
+                self.quiet = other.quiet;
+            }
+            if other.level.is_some() {
+                self.level = other.level;
+            }
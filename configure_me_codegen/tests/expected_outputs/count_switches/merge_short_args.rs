@@ -0,0 +1,4 @@
+                        } else if short == 'v' {
+                            *(self.verbose.get_or_insert(0)) += 1;
+                        } else if short == 'q' {
+                            *(self.quiet.get_or_insert(0)) += 1;
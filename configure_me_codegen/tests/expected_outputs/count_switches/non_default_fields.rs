@@ -0,0 +1,28 @@
+
+/// Param or switch whose value differs from the default
+pub struct NonDefaultField {
+    pub name: &'static str,
+    /// `None` if the param is mandatory or unset by default
+    pub default: Option<String>,
+    /// `None` if the optional param is unset
+    pub value: Option<String>,
+}
+
+impl Config {
+    /// Returns the params and switches whose values differ from the defaults
+    ///
+    /// Mandatory params are always returned, the values of secret params are redacted.
+    pub fn non_default_fields(&self) -> Vec<NonDefaultField> {
+        let mut fields = Vec::new();
+        if ::configure_me::helpers::mapped_count(&self.verbose, &[Verbosity::Warn, Verbosity::Info, Verbosity::Debug, Verbosity::Trace]) != 0 {
+            fields.push(NonDefaultField { name: "verbose", default: Some("0".to_owned()), value: Some(::configure_me::helpers::mapped_count(&self.verbose, &[Verbosity::Warn, Verbosity::Info, Verbosity::Debug, Verbosity::Trace]).to_string()) });
+        }
+        if self.quiet != 0 {
+            fields.push(NonDefaultField { name: "quiet", default: Some("0".to_owned()), value: Some(self.quiet.to_string()) });
+        }
+        if ::configure_me::helpers::mapped_count(&self.level, &[crate::Level::Low, crate::Level::High]) != 0 {
+            fields.push(NonDefaultField { name: "level", default: Some("0".to_owned()), value: Some(::configure_me::helpers::mapped_count(&self.level, &[crate::Level::Low, crate::Level::High]).to_string()) });
+        }
+        fields
+    }
+}
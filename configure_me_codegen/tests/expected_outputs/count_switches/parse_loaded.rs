@@ -0,0 +1,4 @@
+            let table = ::configure_me::helpers::ConfigDocument::into_table(&*config_content)
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.clone(), error })?;
+            // Errors deserializing the table lack the location, parsing the content reports it
+            let config = self.parse_document(&config_file_name, table.clone()).or_else(|_| self.parse(&config_file_name, &config_content))?;
@@ -0,0 +1,3 @@
+        verbose: Option<u32>,
+        quiet: Option<u32>,
+        level: Option<u32>,
@@ -0,0 +1,8 @@
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
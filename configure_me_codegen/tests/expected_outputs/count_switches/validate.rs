@@ -0,0 +1,14 @@
+
+            if let Some(value) = self.quiet.filter(|&value| value > 2) {
+                return Err(ValidationError::OutOfRange { name: "quiet", value: value.to_string(), range: "at most 2" });
+            }
+
+            if let Some(value) = self.level.filter(|&value| value > 3) {
+                return Err(ValidationError::OutOfRange { name: "level", value: value.to_string(), range: "at most 3" });
+            }
+
+            Ok(super::Config {
+                verbose: match self.verbose.unwrap_or(0) { 0 => Verbosity::Warn, 1 => Verbosity::Info, 2 => Verbosity::Debug, _ => Verbosity::Trace },
+                quiet: self.quiet.unwrap_or(0),
+                level: match self.level.unwrap_or(0) { 0 => crate::Level::Low, _ => crate::Level::High },
+            })
@@ -0,0 +1,2 @@
+    FieldWorkers(<::configure_me::helpers::Int<usize> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
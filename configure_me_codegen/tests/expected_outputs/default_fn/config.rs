@@ -0,0 +1,2 @@
+    pub workers: usize,
+    pub host: String,
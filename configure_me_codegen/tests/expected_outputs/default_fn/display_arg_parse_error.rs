@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--workers WORKERS] [--host HOST]\n\nArguments:\n        --workers    Number of worker threads", program_name),
+        ArgParseError::FieldWorkers(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<usize>>(f, "--workers", err),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
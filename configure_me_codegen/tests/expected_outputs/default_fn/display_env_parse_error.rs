@@ -0,0 +1,2 @@
+        EnvParseError::FieldWorkers(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<usize>>(f, "DEFAULT_FN_WORKERS", err),
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "DEFAULT_FN_HOST", err),
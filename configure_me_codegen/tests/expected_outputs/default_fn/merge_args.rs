@@ -0,0 +1,8 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<usize>, _, _>("--workers", &arg, &mut iter) {
+                    let workers = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--workers"), ArgParseError::FieldWorkers))?.0;
+
+                    self.workers = Some(workers);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--host", &arg, &mut iter) {
+                    let host = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--host"), ArgParseError::FieldHost))?;
+
+                    self.host = Some(host);
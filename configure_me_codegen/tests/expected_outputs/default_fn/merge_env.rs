@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.workers, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<usize>>("DEFAULT_FN_WORKERS").map_err(super::EnvParseError::FieldWorkers)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env("DEFAULT_FN_HOST").map_err(super::EnvParseError::FieldHost)?);
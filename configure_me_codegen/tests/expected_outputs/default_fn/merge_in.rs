@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge(&mut self.workers, other.workers);
+            ::configure_me::helpers::merge(&mut self.host, other.host);
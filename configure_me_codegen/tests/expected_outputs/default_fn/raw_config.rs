@@ -0,0 +1,2 @@
+        workers: Option<usize>,
+        host: Option<String>,
@@ -0,0 +1,7 @@
+            let workers = self.workers.unwrap_or_else(|| { crate::default_workers() });
+            let host = self.host.unwrap_or_else(|| { "localhost".to_owned() });
+
+            Ok(super::Config {
+                workers: workers.into(),
+                host: host.into(),
+            })
@@ -0,0 +1,3 @@
+    pub port: Option<u16>,
+    pub host: Option<String>,
+    pub tag: Option<String>,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [--port PORT] [--host HOST] [--tag TAG]\n\nArguments:\n        --config    Load configuration from this file.", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
+        ArgParseError::FieldTag(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tag", err),
@@ -0,0 +1,3 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "DROP_INS_PORT", err),
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "DROP_INS_HOST", err),
+        EnvParseError::FieldTag(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "DROP_INS_TAG", err),
@@ -0,0 +1,2 @@
+            let loaded = config.load_once(path.as_ref());
+            match config.with_drop_ins(path.as_ref(), loaded) {
@@ -0,0 +1,21 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = self.with_drop_ins(&file_path, loaded)?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--host", &arg, &mut iter) {
+                    let host = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--host"), ArgParseError::FieldHost))?;
+
+                    self.host = Some(host);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tag", &arg, &mut iter) {
+                    let tag = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tag"), ArgParseError::FieldTag))?;
+
+                    ::configure_me::helpers::merge_with(&mut self.tag, Some(tag), (|a: &mut String, b: String| a.push_str(&b)));
@@ -0,0 +1,3 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.host, other.host);
+            ::configure_me::helpers::merge_with(&mut self.tag, other.tag, (|a: &mut String, b: String| a.push_str(&b)));
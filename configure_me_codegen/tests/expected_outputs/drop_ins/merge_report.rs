@@ -0,0 +1,83 @@
+
+/// Source contributing a value
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ReportSource {
+    File(::std::path::PathBuf),
+    Environment,
+    /// Includes the config files loaded by arguments
+    Arguments,
+}
+
+/// Value of a param or switch contributed by a source
+pub struct Contribution {
+    pub source: ReportSource,
+    pub value: String,
+}
+
+/// Values of a param or switch contributed by the sources
+pub struct FieldReport {
+    pub name: &'static str,
+    /// Whether the contributions are combined instead of overriding each other
+    pub combined: bool,
+    /// Sorted from the lowest priority to the highest one
+    pub contributions: Vec<Contribution>,
+}
+
+impl FieldReport {
+    /// The contribution used in the configuration unless they are combined
+    pub fn winner(&self) -> Option<&Contribution> {
+        self.contributions.last()
+    }
+}
+
+/// Values contributed by each source, see `Config::explain()`
+pub struct MergeReport {
+    /// Params and switches in the order of the specification
+    pub fields: Vec<FieldReport>,
+}
+
+impl Config {
+    /// Reports the values contributed by each source without validating the configuration
+    ///
+    /// `env` is used instead of the environment variables of the process.
+    pub fn explain<A, E, K, V, I>(args: A, env: E, config_files: I) -> Result<MergeReport, Error> where
+        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
+        E: IntoIterator<Item=(K, V)>, K: Into<::std::ffi::OsString>, V: Into<::std::ffi::OsString>,
+        I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+
+        let mut fields = vec![
+            FieldReport { name: "port", combined: false, contributions: Vec::new() },
+            FieldReport { name: "host", combined: false, contributions: Vec::new() },
+            FieldReport { name: "tag", combined: true, contributions: Vec::new() },
+        ];
+
+        // Earlier files override later ones
+        let config_files = config_files.into_iter().map(|path| path.as_ref().to_owned()).collect::<Vec<_>>();
+        let mut files = Vec::new();
+        for path in config_files {
+            let drop_ins = ::configure_me::helpers::drop_in_files(&path).map_err(|(file, error)| Error::Reading { file, error })?;
+            files.extend(drop_ins.into_iter().rev());
+            files.push(path);
+        }
+        let config_files = files;
+        let mut loader = raw::Config::default();
+        for path in config_files.into_iter().rev() {
+            match loader.load_once(&path) {
+                Ok(config) => raw::report_values(&config, &ReportSource::File(path), &mut fields),
+                Err(Error::Reading { ref error, .. }) if error.kind() == ::std::io::ErrorKind::NotFound => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        let env = env.into_iter().map(|(name, value)| (name.into(), value.into())).collect::<::std::collections::HashMap<::std::ffi::OsString, ::std::ffi::OsString>>();
+        let mut config = raw::Config::default();
+        config.merge_env_with(|name| env.get(::std::ffi::OsStr::new(name)).cloned())?;
+        raw::report_values(&config, &ReportSource::Environment, &mut fields);
+
+        let mut config = raw::Config::default();
+        config.merge_args(args.into_iter().map(Into::into))?;
+        raw::report_values(&config, &ReportSource::Arguments, &mut fields);
+
+        Ok(MergeReport { fields })
+    }
+}
@@ -0,0 +1,3 @@
+        port: Option<u16>,
+        host: Option<String>,
+        tag: Option<String>,
@@ -0,0 +1,50 @@
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
+
+    impl Config {
+        /// Merges the drop-ins of the config file into `config`, which is the result of loading the file
+        ///
+        /// The drop-ins are the `*.toml` files in the directory named like the file with `.d` appended,
+        /// they override the file in lexical order and are used even if the file doesn't exist.
+        pub fn with_drop_ins(&mut self, config_file_name: &::std::path::Path, config: Result<Self, super::Error>) -> Result<Self, super::Error> {
+            let drop_ins = ::configure_me::helpers::drop_in_files(config_file_name).map_err(|(file, error)| super::Error::Reading { file, error })?;
+            let mut config = match config {
+                Err(super::Error::Reading { ref error, .. }) if error.kind() == ::std::io::ErrorKind::NotFound && !drop_ins.is_empty() => Self::default(),
+                config => config?,
+            };
+            for file in drop_ins {
+                let drop_in = self.load_once(file)?;
+                config.merge_in(drop_in);
+            }
+            Ok(config)
+        }
+    }
+
+    impl Config {
+        /// Merges environment variables looked up using `var`
+        pub fn merge_env_with<F: Fn(&str) -> Option<::std::ffi::OsString>>(&mut self, var: F) -> Result<(), super::Error> {
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env_value::<::configure_me::helpers::Int<u16>>(var("DROP_INS_PORT")).map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env_value(var("DROP_INS_HOST")).map_err(super::EnvParseError::FieldHost)?);
+        ::configure_me::helpers::merge_with(&mut self.tag, ::configure_me::helpers::parse_env_value(var("DROP_INS_TAG")).map_err(super::EnvParseError::FieldTag)?, (|a: &mut String, b: String| a.push_str(&b)));
+            Ok(())
+        }
+    }
+
+    /// Adds the values set in `config` to the reports of the fields, which are in the order of the specification
+    pub fn report_values(config: &Config, source: &super::ReportSource, fields: &mut [super::FieldReport]) {
+        if let Some(value) = &config.port {
+            fields[0].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_value(value) });
+        }
+        if let Some(value) = &config.host {
+            fields[1].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_value(value) });
+        }
+        if let Some(value) = &config.tag {
+            fields[2].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_value(value) });
+        }
+    }
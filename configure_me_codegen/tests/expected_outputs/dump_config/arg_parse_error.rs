@@ -0,0 +1,4 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPassword(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    AmbiguousArgument(String, Vec<&'static str>),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,33 @@
+
+impl Config {
+    /// Loads and validates the configuration and exits printing it as a config file instead of
+    /// returning, the values of secret params are redacted
+    fn dump_config<I>(args: Vec<::std::ffi::OsString>, config_files: I) -> ! where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+        use std::io::Write;
+
+        let args = ::configure_me::helpers::remove_normalized_switch(args, "--dump-config", raw::normalize_long_option);
+        let config = Self::custom_args_and_optional_files(args, config_files).map(|(config, _)| config);
+        match config.map(|config| config.to_redacted_toml()) {
+            Ok(Ok(toml)) => {
+                print!("{}", toml);
+                std::io::stdout().flush().expect("failed to flush stdout");
+                ::std::process::exit(0)
+            },
+            Ok(Err(err)) => {
+                eprintln!("Error: failed to format the configuration: {}", err);
+                std::io::stderr().flush().expect("failed to flush stderr");
+                ::std::process::exit(1)
+            },
+            Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {
+                println!("{}", err);
+                std::io::stdout().flush().expect("failed to flush stdout");
+                ::std::process::exit(0)
+            },
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::io::stderr().flush().expect("failed to flush stderr");
+                ::std::process::exit(1)
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+        let args = args.into_iter().map(Into::into).collect::<Vec<::std::ffi::OsString>>();
+        if ::configure_me::helpers::has_switch(&::configure_me::helpers::normalize_long_options(&args, raw::normalize_long_option), "--dump-config", raw::VALUED_OPTIONS) {
+            Self::dump_config(args, config_files);
+        }
+
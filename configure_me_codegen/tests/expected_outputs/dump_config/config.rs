@@ -0,0 +1,3 @@
+    pub port: u16,
+    pub password: Option<String>,
+    pub verbose: bool,
@@ -0,0 +1,5 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--password PASSWORD] [--verbose]", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldPassword(_) => ::configure_me::helpers::display_secret_arg_error::<String>(f, "--password"),
+        ArgParseError::AmbiguousArgument(arg, candidates) => write!(f, "The argument '{}' is ambiguous, it may mean: {}.", arg, candidates.join(", ")),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,14 @@
+                } else if arg == *"--dump-config" {
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password", &arg, &mut iter) {
+                    let password = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password"), ArgParseError::FieldPassword))?;
+
+                    self.password = Some(password);
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
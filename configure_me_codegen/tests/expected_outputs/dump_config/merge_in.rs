@@ -0,0 +1,5 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.password, other.password);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
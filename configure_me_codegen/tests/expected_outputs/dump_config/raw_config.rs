@@ -0,0 +1,5 @@
+        port: Option<u16>,
+        #[serde(default, deserialize_with = "secret_password")]
+        password: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
@@ -0,0 +1,37 @@
+
+    fn secret_password<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+        ::configure_me::helpers::deserialize_secret(deserializer)
+    }
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    pub const VALUED_OPTIONS: &[(&str, usize, usize)] = &[("--port", 1, 1), ("--password", 1, 1)];
+
+    const LONG_OPTIONS: &[&str] = &["--help", "--dump-config", "--port", "--password", "--verbose"];
+
+    pub fn normalize_long_option(arg: ::std::ffi::OsString) -> Result<::std::ffi::OsString, ArgParseError> {
+        let (name, value) = match arg.to_str() {
+            Some(arg) if arg.starts_with("--") && arg.len() > 2 => match arg.find('=') {
+                Some(pos) => (arg[..pos].to_owned(), arg[pos..].to_owned()),
+                None => (arg.to_owned(), String::new()),
+            },
+            _ => return Ok(arg),
+        };
+
+        if LONG_OPTIONS.contains(&&*name) {
+            return Ok(format!("{}{}", name, value).into());
+        }
+
+        let candidates = LONG_OPTIONS.iter().cloned().filter(|option| option.starts_with(&*name)).collect::<Vec<_>>();
+        match candidates.len() {
+            0 => (),
+            1 => return Ok(format!("{}{}", candidates[0], value).into()),
+            _ => return Err(ArgParseError::AmbiguousArgument(name, candidates)),
+        }
+        Ok(arg)
+    }
@@ -0,0 +1,28 @@
+
+impl Config {
+    /// Formats the values of params and switches into a single line, e.g. for logs
+    ///
+    /// Unset optional params are skipped, the values of secret params are redacted.
+    pub fn to_redacted_string(&self) -> String {
+        let mut fields = Vec::new();
+        fields.push(("port", ::configure_me::helpers::format_value(&self.port)));
+        if self.password.is_some() {
+            fields.push(("password", ::configure_me::helpers::REDACTED.to_owned()));
+        }
+        fields.push(("verbose", self.verbose.to_string()));
+        ::configure_me::helpers::join_fields(&fields)
+    }
+
+    /// Formats the configuration as a config file
+    ///
+    /// Unset optional params are skipped, the values of secret params are redacted.
+    pub fn to_redacted_toml(&self) -> Result<String, ::configure_me::toml::ser::Error> {
+        let mut table = ::configure_me::toml::value::Table::new();
+        table.insert("port".to_owned(), ::configure_me::toml::Value::try_from(&self.port)?);
+        if self.password.is_some() {
+            table.insert("password".to_owned(), ::configure_me::toml::Value::String(::configure_me::helpers::REDACTED.to_owned()));
+        }
+        table.insert("verbose".to_owned(), ::configure_me::toml::Value::try_from(self.verbose)?);
+        ::configure_me::helpers::format_toml(table, &[])
+    }
+}
@@ -0,0 +1,8 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let password = self.password;
+
+            Ok(super::Config {
+                port: port.into(),
+                password: password.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+            })
@@ -0,0 +1,6 @@
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLevel(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTag(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
+    DuplicateArgument(&'static str),
@@ -0,0 +1,7 @@
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub level: Option<String>,
+    pub tag: Option<String>,
+    pub verbose: bool,
+    pub color: bool,
+    pub debug: u32,
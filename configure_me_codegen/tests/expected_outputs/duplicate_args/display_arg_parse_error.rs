@@ -0,0 +1,7 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        -H, --host\n\n        --port\n\n        --level\n\n        --tag\n\n        -v, --verbose\nno-color\n\n        -d, --debug\n", program_name),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldLevel(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--level", err),
+        ArgParseError::FieldTag(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tag", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        ArgParseError::DuplicateArgument(arg) => write!(f, "The argument '{}' was specified more than once.", arg),
@@ -0,0 +1,49 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--host", &arg, &mut iter) {
+                    let host = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--host"), ArgParseError::FieldHost))?;
+
+                    if seen_host {
+                        return Err(ArgParseError::DuplicateArgument("--host").into());
+                    }
+                    seen_host = true;
+                    self.host = Some(host);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    if !seen_port {
+                        seen_port = true;
+                        self.port = Some(port);
+                    }
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--level", &arg, &mut iter) {
+                    let level = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--level"), ArgParseError::FieldLevel))?;
+
+                    self.level = Some(level);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tag", &arg, &mut iter) {
+                    let tag = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tag"), ArgParseError::FieldTag))?;
+
+                    ::configure_me::helpers::merge_with(&mut self.tag, Some(tag), (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+                } else if arg == *"--verbose" {
+                    if seen_verbose {
+                        return Err(ArgParseError::DuplicateArgument("--verbose").into());
+                    }
+                    seen_verbose = true;
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    if seen_verbose {
+                        return Err(ArgParseError::DuplicateArgument("--verbose").into());
+                    }
+                    seen_verbose = true;
+                    self.verbose = Some(value);
+                } else if arg == *"--no-color" {
+                    if !seen_color {
+                        seen_color = true;
+                        self.color = Some(false);
+                    }
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--no-color", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--no-color", value))?;
+                    if !seen_color {
+                        seen_color = true;
+                        self.color = Some(!value);
+                    }
+                } else if arg == *"--debug" {
+                    *(self.debug.get_or_insert(0)) += 1;
@@ -0,0 +1,4 @@
+            let mut seen_host = false;
+            let mut seen_port = false;
+            let mut seen_verbose = false;
+            let mut seen_color = false;
@@ -0,0 +1,13 @@
+            ::configure_me::helpers::merge(&mut self.host, other.host);
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.level, other.level);
+            ::configure_me::helpers::merge_with(&mut self.tag, other.tag, (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.color.is_some() {
+                self.color = other.color;
+            }
+            if other.debug.is_some() {
+                self.debug = other.debug;
+            }
@@ -0,0 +1,15 @@
+                        } else if short == 'H' {
+                            if seen_host {
+                                return Err(ArgParseError::DuplicateArgument("-H").into());
+                            }
+                            seen_host = true;
+                            self.host = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-H"), ArgParseError::FieldHost))?);
+                            break;
+                        } else if short == 'v' {
+                            if seen_verbose {
+                                return Err(ArgParseError::DuplicateArgument("-v").into());
+                            }
+                            seen_verbose = true;
+                            self.verbose = Some(true);
+                        } else if short == 'd' {
+                            *(self.debug.get_or_insert(0)) += 1;
@@ -0,0 +1,9 @@
+        host: Option<String>,
+        port: Option<u16>,
+        level: Option<String>,
+        tag: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        color: Option<bool>,
+        debug: Option<u32>,
@@ -0,0 +1,14 @@
+            let host = self.host;
+            let port = self.port;
+            let level = self.level;
+            let tag = self.tag;
+
+            Ok(super::Config {
+                host: host.map(Into::into),
+                port: port.map(Into::into),
+                level: level.map(Into::into),
+                tag: tag.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+                color: self.color.unwrap_or(true),
+                debug: self.debug.unwrap_or(0),
+            })
@@ -0,0 +1,2 @@
+    pub timeout: ::std::time::Duration,
+    pub retry_delay: Option<::std::time::Duration>,
@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [--timeout TIMEOUT] [--retry-delay RETRY_DELAY]\n\nArguments:\n        --config         Load configuration from this file.\n        --timeout        How long to wait for a response. Duration such as 30s, \n                         5m or 1h30m.\n        --retry-delay    Duration such as 30s, 5m or 1h30m.", program_name),
+        ArgParseError::FieldTimeout(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::HumanDuration>(f, "--timeout", err),
+        ArgParseError::FieldRetryDelay(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::HumanDuration>(f, "--retry-delay", err),
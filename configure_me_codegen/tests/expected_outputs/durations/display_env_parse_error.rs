@@ -0,0 +1,2 @@
+        EnvParseError::FieldTimeout(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::HumanDuration>(f, "DURATIONS_TIMEOUT", err),
+        EnvParseError::FieldRetryDelay(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::HumanDuration>(f, "DURATIONS_RETRY_DELAY", err),
@@ -0,0 +1,2 @@
+    FieldTimeout(<::configure_me::helpers::HumanDuration as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldRetryDelay(<::configure_me::helpers::HumanDuration as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,17 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::HumanDuration, _, _>("--timeout", &arg, &mut iter) {
+                    let timeout = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--timeout"), ArgParseError::FieldTimeout))?.0;
+
+                    self.timeout = Some(timeout);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::HumanDuration, _, _>("--retry-delay", &arg, &mut iter) {
+                    let retry_delay = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--retry-delay"), ArgParseError::FieldRetryDelay))?.0;
+
+                    self.retry_delay = Some(retry_delay);
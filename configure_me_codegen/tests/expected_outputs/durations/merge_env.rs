@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.timeout, ::configure_me::helpers::parse_env::<::configure_me::helpers::HumanDuration>("DURATIONS_TIMEOUT").map_err(super::EnvParseError::FieldTimeout)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.retry_delay, ::configure_me::helpers::parse_env::<::configure_me::helpers::HumanDuration>("DURATIONS_RETRY_DELAY").map_err(super::EnvParseError::FieldRetryDelay)?.map(|value| value.0));
@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge(&mut self.timeout, other.timeout);
+            ::configure_me::helpers::merge(&mut self.retry_delay, other.retry_delay);
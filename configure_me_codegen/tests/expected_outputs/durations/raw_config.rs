@@ -0,0 +1,4 @@
+        #[serde(default, deserialize_with = "::configure_me::helpers::deserialize_duration")]
+        timeout: Option<::std::time::Duration>,
+        #[serde(default, deserialize_with = "::configure_me::helpers::deserialize_duration")]
+        retry_delay: Option<::std::time::Duration>,
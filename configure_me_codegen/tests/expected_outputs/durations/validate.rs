@@ -0,0 +1,7 @@
+            let timeout = self.timeout.unwrap_or_else(|| { ::std::time::Duration::from_secs(30) });
+            let retry_delay = self.retry_delay;
+
+            Ok(super::Config {
+                timeout: timeout.into(),
+                retry_delay: retry_delay.map(Into::into),
+            })
@@ -0,0 +1,5 @@
+            let config_content = ::std::fs::read(&config_file_name).map_err(|error| super::Error::Reading { file: config_file_name.clone(), error })?;
+            let table = ::configure_me::helpers::ConfigDocument::into_table(&*config_content)
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.clone(), error })?;
+            // Errors deserializing the table lack the location, parsing the content reports it
+            let config = self.parse_document(&config_file_name, table.clone()).or_else(|_| self.parse(&config_file_name, &config_content))?;
@@ -0,0 +1,4 @@
+    FieldTlsCert(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldKey(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
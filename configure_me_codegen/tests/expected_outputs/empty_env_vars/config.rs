@@ -0,0 +1,4 @@
+    pub tls_cert: Option<String>,
+    pub name: Option<String>,
+    pub key: Option<String>,
+    pub verbose: bool,
@@ -0,0 +1,5 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--tls-cert TLS_CERT] [--name NAME] [--key KEY] [--verbose]", program_name),
+        ArgParseError::FieldTlsCert(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tls-cert", err),
+        ArgParseError::FieldName(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--name", err),
+        ArgParseError::FieldKey(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--key", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,5 @@
+        EnvParseError::FieldTlsCert(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "EMPTY_ENV_TEST_TLS_CERT", err),
+        EnvParseError::FieldName(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "EMPTY_ENV_TEST_NAME", err),
+        EnvParseError::FieldKey(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "EMPTY_ENV_TEST_KEY", err),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "EMPTY_ENV_TEST_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::Empty(name) => write!(f, "The environment variable '{}' is set but empty.", name),
@@ -0,0 +1,5 @@
+    FieldTlsCert(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldKey(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
+    Empty(&'static str),
@@ -0,0 +1,17 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tls-cert", &arg, &mut iter) {
+                    let tls_cert = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tls-cert"), ArgParseError::FieldTlsCert))?;
+
+                    self.tls_cert = Some(tls_cert);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--name", &arg, &mut iter) {
+                    let name = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--name"), ArgParseError::FieldName))?;
+
+                    self.name = Some(name);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--key", &arg, &mut iter) {
+                    let key = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--key"), ArgParseError::FieldKey))?;
+
+                    self.key = Some(key);
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
@@ -0,0 +1,6 @@
+        ::configure_me::helpers::merge(&mut self.tls_cert, ::configure_me::helpers::parse_env_ignore_empty("EMPTY_ENV_TEST_TLS_CERT").map_err(super::EnvParseError::FieldTlsCert)?);
+        ::configure_me::helpers::merge(&mut self.name, ::configure_me::helpers::parse_env("EMPTY_ENV_TEST_NAME").map_err(super::EnvParseError::FieldName)?);
+        ::configure_me::helpers::merge(&mut self.key, ::configure_me::helpers::parse_env_reject_empty("EMPTY_ENV_TEST_KEY").map_err(|err| err.map_or(super::EnvParseError::Empty("EMPTY_ENV_TEST_KEY"), super::EnvParseError::FieldKey))?);
+        if let Some(val) = ::std::env::var_os("EMPTY_ENV_TEST_VERBOSE").filter(|val| !val.is_empty()) {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
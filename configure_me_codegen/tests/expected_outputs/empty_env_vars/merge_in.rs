@@ -0,0 +1,6 @@
+            ::configure_me::helpers::merge(&mut self.tls_cert, other.tls_cert);
+            ::configure_me::helpers::merge(&mut self.name, other.name);
+            ::configure_me::helpers::merge(&mut self.key, other.key);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,5 @@
+        tls_cert: Option<String>,
+        name: Option<String>,
+        key: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
@@ -0,0 +1,10 @@
+            let tls_cert = self.tls_cert;
+            let name = self.name;
+            let key = self.key;
+
+            Ok(super::Config {
+                tls_cert: tls_cert.map(Into::into),
+                name: name.map(Into::into),
+                key: key.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+            })
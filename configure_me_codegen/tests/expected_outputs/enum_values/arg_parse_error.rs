@@ -0,0 +1,2 @@
+    FieldFormat(<Format as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLevels(<LogLevel as ::configure_me::parse_arg::ParseArg>::Error),
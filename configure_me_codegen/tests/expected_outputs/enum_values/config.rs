@@ -0,0 +1,2 @@
+    pub format: Format,
+    pub levels: Option<Vec<LogLevel>>,
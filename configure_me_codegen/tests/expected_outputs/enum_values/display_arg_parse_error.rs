@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-f FORMAT|--format FORMAT] [--levels LEVELS ...]\n\nArguments:\n        -f, --format    Output format. Possible values: json, yaml, plain-text.\n        --levels        Possible values: error, warn, info.", program_name),
+        ArgParseError::FieldFormat(err) => ::configure_me::helpers::display_arg_error::<Format>(f, "--format", err),
+        ArgParseError::FieldLevels(err) => ::configure_me::helpers::display_arg_error::<LogLevel>(f, "--levels", err),
@@ -0,0 +1,2 @@
+        EnvParseError::FieldFormat(ref err) => ::configure_me::helpers::display_env_error::<Format>(f, "ENUM_VALUES_FORMAT", err),
+        EnvParseError::FieldLevels(ref err) => ::configure_me::helpers::display_env_error::<LogLevel>(f, "ENUM_VALUES_LEVELS", err),
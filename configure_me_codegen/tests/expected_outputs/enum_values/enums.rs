@@ -0,0 +1,120 @@
+/// Possible values of `format`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Format {
+    Json,
+    Yaml,
+    PlainText,
+}
+
+impl Format {
+    /// Values accepted in arguments, environment variables and config files
+    pub const VALUES: &'static [&'static str] = &["json", "yaml", "plain-text"];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Yaml => "yaml",
+            Format::PlainText => "plain-text",
+        }
+    }
+}
+
+impl ::std::str::FromStr for Format {
+    type Err = ::configure_me::helpers::InvalidValue;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "json" => Ok(Format::Json),
+            "yaml" => Ok(Format::Yaml),
+            "plain-text" => Ok(Format::PlainText),
+            _ => Err(::configure_me::helpers::InvalidValue::new(value, Self::VALUES)),
+        }
+    }
+}
+
+impl ::std::fmt::Display for Format {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ::configure_me::parse_arg::ParseArgFromStr for Format {
+    fn describe_type<W: ::std::fmt::Write>(writer: W) -> ::std::fmt::Result {
+        ::configure_me::helpers::describe_values(writer, Self::VALUES)
+    }
+}
+
+impl ::configure_me::ToArgDisplay for Format {}
+
+impl<'de> ::configure_me::serde::Deserialize<'de> for Format {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ::configure_me::helpers::deserialize_from_str(deserializer)
+    }
+}
+
+impl ::configure_me::serde::Serialize for Format {
+    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Possible values of `levels`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+}
+
+impl LogLevel {
+    /// Values accepted in arguments, environment variables and config files
+    pub const VALUES: &'static [&'static str] = &["error", "warn", "info"];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+        }
+    }
+}
+
+impl ::std::str::FromStr for LogLevel {
+    type Err = ::configure_me::helpers::InvalidValue;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "error" => Ok(LogLevel::Error),
+            "warn" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            _ => Err(::configure_me::helpers::InvalidValue::new(value, Self::VALUES)),
+        }
+    }
+}
+
+impl ::std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ::configure_me::parse_arg::ParseArgFromStr for LogLevel {
+    fn describe_type<W: ::std::fmt::Write>(writer: W) -> ::std::fmt::Result {
+        ::configure_me::helpers::describe_values(writer, Self::VALUES)
+    }
+}
+
+impl ::configure_me::ToArgDisplay for LogLevel {}
+
+impl<'de> ::configure_me::serde::Deserialize<'de> for LogLevel {
+    fn deserialize<D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ::configure_me::helpers::deserialize_from_str(deserializer)
+    }
+}
+
+impl ::configure_me::serde::Serialize for LogLevel {
+    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
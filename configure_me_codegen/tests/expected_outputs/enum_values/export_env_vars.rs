@@ -0,0 +1,14 @@
+
+impl Config {
+    /// Returns environment variables reproducing the configuration in a child process
+    ///
+    /// Params and switches without an environment variable and unset optional params are skipped.
+    pub fn to_env_vars(&self) -> Vec<(::std::ffi::OsString, ::std::ffi::OsString)> {
+        let mut vars = Vec::new();
+        vars.push(("ENUM_VALUES_FORMAT".into(), ::configure_me::ToArg::to_arg(&self.format)));
+        if let Some(value) = &self.levels {
+            vars.push(("ENUM_VALUES_LEVELS".into(), ::configure_me::helpers::join_values(value, ',')));
+        }
+        vars
+    }
+}
@@ -0,0 +1,8 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--format", &arg, &mut iter) {
+                    let format = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--format"), ArgParseError::FieldFormat))?;
+
+                    self.format = Some(format);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--levels", &arg, &mut iter) {
+                    let levels = vec![value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--levels"), ArgParseError::FieldLevels))?];
+
+                    ::configure_me::helpers::merge_with(&mut self.levels, Some(levels), ::configure_me::helpers::append_values);
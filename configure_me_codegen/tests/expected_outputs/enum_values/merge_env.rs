@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.format, ::configure_me::helpers::parse_env("ENUM_VALUES_FORMAT").map_err(super::EnvParseError::FieldFormat)?);
+        ::configure_me::helpers::merge_with(&mut self.levels, ::configure_me::helpers::parse_env::<::configure_me::helpers::Delimited<LogLevel, ','>>("ENUM_VALUES_LEVELS").map_err(super::EnvParseError::FieldLevels)?.map(|values| values.0), ::configure_me::helpers::append_values);
@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge(&mut self.format, other.format);
+            ::configure_me::helpers::merge_with(&mut self.levels, other.levels, ::configure_me::helpers::append_values);
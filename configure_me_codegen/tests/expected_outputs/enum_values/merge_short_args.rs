@@ -0,0 +1,3 @@
+                        } else if short == 'f' {
+                            self.format = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-f"), ArgParseError::FieldFormat))?);
+                            break;
@@ -0,0 +1,2 @@
+        format: Option<Format>,
+        levels: Option<Vec<LogLevel>>,
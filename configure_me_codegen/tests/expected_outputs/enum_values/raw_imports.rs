@@ -0,0 +1,2 @@
+    use super::Format;
+    use super::LogLevel;
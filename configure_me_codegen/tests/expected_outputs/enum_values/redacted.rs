@@ -0,0 +1,26 @@
+
+impl Config {
+    /// Formats the values of params and switches into a single line, e.g. for logs
+    ///
+    /// Unset optional params are skipped, the values of secret params are redacted.
+    pub fn to_redacted_string(&self) -> String {
+        let mut fields = Vec::new();
+        fields.push(("format", ::configure_me::helpers::format_value(&self.format)));
+        if let Some(value) = &self.levels {
+            fields.push(("levels", ::configure_me::helpers::format_values(value)));
+        }
+        ::configure_me::helpers::join_fields(&fields)
+    }
+
+    /// Formats the configuration as a config file
+    ///
+    /// Unset optional params are skipped, the values of secret params are redacted.
+    pub fn to_redacted_toml(&self) -> Result<String, ::configure_me::toml::ser::Error> {
+        let mut table = ::configure_me::toml::value::Table::new();
+        table.insert("format".to_owned(), ::configure_me::toml::Value::try_from(&self.format)?);
+        if let Some(value) = &self.levels {
+            table.insert("levels".to_owned(), ::configure_me::toml::Value::try_from(value)?);
+        }
+        ::configure_me::helpers::format_toml(table, &[])
+    }
+}
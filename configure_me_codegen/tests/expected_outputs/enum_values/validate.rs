@@ -0,0 +1,7 @@
+            let format = self.format.unwrap_or_else(|| { Format::Json });
+            let levels = self.levels;
+
+            Ok(super::Config {
+                format: format.into(),
+                levels: levels.map(Into::into),
+            })
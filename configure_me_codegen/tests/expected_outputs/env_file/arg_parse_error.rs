@@ -0,0 +1,4 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLabel(<::configure_me::helpers::KeyValue<String> as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,4 @@
+    pub port: u16,
+    pub host: String,
+    pub label: Option<::std::collections::HashMap<String, String>>,
+    pub verbose: bool,
@@ -0,0 +1,5 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--host HOST] [--label KEY=VALUE ...] [--verbose]\n\nArguments:\n        --port    Port to listen on", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
+        ArgParseError::FieldLabel(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::KeyValue<String>>(f, "--label", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,4 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "ENV_FILE_PORT", err),
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "ENV_FILE_HOST", err),
+        EnvParseError::FieldLabel(ref name, ref err) => ::configure_me::helpers::display_env_error::<String>(f, name, err),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "ENV_FILE_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
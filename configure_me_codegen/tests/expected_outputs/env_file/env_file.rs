@@ -0,0 +1,9 @@
+
+static ENV_FILE: ::std::sync::Mutex<Option<::std::path::PathBuf>> = ::std::sync::Mutex::new(None);
+
+impl Config {
+    /// Sets the path of the `.env` file loaded with the environment variables, `tests/config_files/env_file.env` by default
+    pub fn set_env_file<P: Into<::std::path::PathBuf>>(path: P) {
+        *ENV_FILE.lock().unwrap_or_else(|err| err.into_inner()) = Some(path.into());
+    }
+}
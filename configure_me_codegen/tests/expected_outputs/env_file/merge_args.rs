@@ -0,0 +1,18 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--host", &arg, &mut iter) {
+                    let host = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--host"), ArgParseError::FieldHost))?;
+
+                    self.host = Some(host);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::KeyValue<String>, _, _>("--label", &arg, &mut iter) {
+                    let ::configure_me::helpers::KeyValue(key, value) = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--label"), ArgParseError::FieldLabel))?;
+                    let label = ::std::iter::once((key, value)).collect::<::std::collections::HashMap<String, String>>();
+
+                    ::configure_me::helpers::merge_with(&mut self.label, Some(label), ::configure_me::helpers::merge_maps);
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
@@ -0,0 +1,3 @@
+            let env_file_path = super::ENV_FILE.lock().unwrap_or_else(|err| err.into_inner()).clone().unwrap_or_else(|| "tests/config_files/env_file.env".into());
+            let env_file = ::configure_me::helpers::EnvFile::load(&env_file_path).map_err(|error| super::Error::Reading { file: env_file_path, error })?;
+            self.merge_env_with(|name| env_file.var(name), env_file.vars())?;
@@ -0,0 +1,6 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.host, other.host);
+            ::configure_me::helpers::merge_with(&mut self.label, other.label, ::configure_me::helpers::merge_maps);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,5 @@
+        port: Option<u16>,
+        host: Option<String>,
+        label: Option<::std::collections::HashMap<String, String>>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
@@ -0,0 +1,21 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    impl Config {
+        /// Merges environment variables looked up using `var`, the entries of maps are taken from `vars`
+        pub fn merge_env_with<F: Fn(&str) -> Option<::std::ffi::OsString>, V: IntoIterator<Item=(::std::ffi::OsString, ::std::ffi::OsString)>>(&mut self, var: F, vars: V) -> Result<(), super::Error> {
+            let vars = vars.into_iter().collect::<Vec<_>>();
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env_value::<::configure_me::helpers::Int<u16>>(var("ENV_FILE_PORT")).map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env_value(var("ENV_FILE_HOST")).map_err(super::EnvParseError::FieldHost)?);
+        ::configure_me::helpers::merge_with(&mut self.label, ::configure_me::helpers::parse_env_map::<String, _>("ENV_FILE_LABEL_", vars.iter().cloned()).map_err(|(name, err)| super::EnvParseError::FieldLabel(name, err))?, ::configure_me::helpers::merge_maps);
+        if let Some(val) = var("ENV_FILE_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
+            Ok(())
+        }
+    }
@@ -0,0 +1,19 @@
+            let port = self.port;
+            let host = self.host.unwrap_or_else(|| { "localhost".to_owned() });
+            let label = self.label;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port", "ENV_FILE_PORT"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                host: host.into(),
+                label: label.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+            })
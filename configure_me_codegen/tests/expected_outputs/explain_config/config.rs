@@ -0,0 +1,6 @@
+    pub port: Option<u16>,
+    pub host: Option<String>,
+    pub tag: Option<String>,
+    pub verbose: bool,
+    pub debug: u32,
+    _overrides: Vec<::configure_me::helpers::Override>,
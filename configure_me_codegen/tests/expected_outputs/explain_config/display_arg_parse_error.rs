@@ -0,0 +1,5 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --config            Load configuration from this file.\n        --explain-config    Explain which source overrode each value.\n        -p, --port\n\n        --host\n\n        --tag\n\n        -v, --verbose\n\n        --debug\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
+        ArgParseError::FieldTag(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tag", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,9 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "EXPLAIN_CONFIG_TEST_PORT", err),
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "EXPLAIN_CONFIG_TEST_HOST", err),
+        EnvParseError::FieldTag(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "EXPLAIN_CONFIG_TEST_TAG", err),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "EXPLAIN_CONFIG_TEST_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::FieldDebug(ref err) => {
+            write!(f, "Invalid value for 'EXPLAIN_CONFIG_TEST_DEBUG': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
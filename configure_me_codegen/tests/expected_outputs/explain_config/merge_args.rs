@@ -0,0 +1,34 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if arg == *"--explain-config" {
+                    self._sources.explain = true;
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self._sources.set("port", ::configure_me::helpers::Source::Argument("--port"));
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--host", &arg, &mut iter) {
+                    let host = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--host"), ArgParseError::FieldHost))?;
+
+                    self._sources.set("host", ::configure_me::helpers::Source::Argument("--host"));
+                    self.host = Some(host);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tag", &arg, &mut iter) {
+                    let tag = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tag"), ArgParseError::FieldTag))?;
+
+                    ::configure_me::helpers::merge_with(&mut self.tag, Some(tag), (|a: &mut String, b: String| a.push_str(&b)));
+                } else if arg == *"--verbose" {
+                    self._sources.set("verbose", ::configure_me::helpers::Source::Argument("--verbose"));
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self._sources.set("verbose", ::configure_me::helpers::Source::Argument("--verbose"));
+                    self.verbose = Some(value);
+                } else if arg == *"--debug" {
+                    *(self.debug.get_or_insert(0)) += 1;
@@ -0,0 +1,10 @@
+        ::configure_me::helpers::merge(&mut self.port, self._sources.track("port", ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("EXPLAIN_CONFIG_TEST_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0), ::configure_me::helpers::Source::EnvVar("EXPLAIN_CONFIG_TEST_PORT")));
+        ::configure_me::helpers::merge(&mut self.host, self._sources.track("host", ::configure_me::helpers::parse_env("EXPLAIN_CONFIG_TEST_HOST").map_err(super::EnvParseError::FieldHost)?, ::configure_me::helpers::Source::EnvVar("EXPLAIN_CONFIG_TEST_HOST")));
+        ::configure_me::helpers::merge_with(&mut self.tag, ::configure_me::helpers::parse_env("EXPLAIN_CONFIG_TEST_TAG").map_err(super::EnvParseError::FieldTag)?, (|a: &mut String, b: String| a.push_str(&b)));
+        if let Some(val) = ::std::env::var_os("EXPLAIN_CONFIG_TEST_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+            self._sources.set("verbose", ::configure_me::helpers::Source::EnvVar("EXPLAIN_CONFIG_TEST_VERBOSE"));
+        }
+        if let Some(val) = ::std::env::var_os("EXPLAIN_CONFIG_TEST_DEBUG") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldDebug)?;            self.debug = Some(val);
+        }
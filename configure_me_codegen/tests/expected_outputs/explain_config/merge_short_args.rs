@@ -0,0 +1,7 @@
+                        } else if short == 'p' {
+                            self._sources.set("port", ::configure_me::helpers::Source::Argument("-p"));
+                            self.port = Some(shorts.parse_remaining::<::configure_me::helpers::Int<u16>, _>(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-p"), ArgParseError::FieldPort))?.0);
+                            break;
+                        } else if short == 'v' {
+                            self._sources.set("verbose", ::configure_me::helpers::Source::Argument("-v"));
+                            self.verbose = Some(true);
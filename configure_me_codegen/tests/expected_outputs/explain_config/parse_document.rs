@@ -0,0 +1 @@
+            ::configure_me::helpers::parse_table(config_content, &[])
@@ -0,0 +1,8 @@
+        port: Option<u16>,
+        host: Option<String>,
+        tag: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        debug: Option<u32>,
+        #[serde(skip)]
+        _sources: ::configure_me::helpers::Sources,
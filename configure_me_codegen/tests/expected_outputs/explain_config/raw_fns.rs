@@ -0,0 +1,28 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    fn track_file(mut config: Config, file: &::std::path::Path) -> Config {
+        if config.port.is_some() {
+            config._sources.set("port", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.host.is_some() {
+            config._sources.set("host", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.verbose.is_some() {
+            config._sources.set("verbose", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        config
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
@@ -0,0 +1,12 @@
+            let port = self.port;
+            let host = self.host;
+            let tag = self.tag;
+
+            Ok(super::Config {
+                port: port.map(Into::into),
+                host: host.map(Into::into),
+                tag: tag.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+                debug: self.debug.unwrap_or(0),
+                _overrides: self._sources.overrides(),
+            })
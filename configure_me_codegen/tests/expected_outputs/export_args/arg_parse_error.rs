@@ -0,0 +1,5 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldOffset(<::configure_me::helpers::Int<i32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTlsCert(<::std::path::PathBuf as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPeers(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
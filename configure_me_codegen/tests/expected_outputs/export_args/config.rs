@@ -0,0 +1,8 @@
+    pub port: u16,
+    pub offset: i32,
+    pub tls_cert: Option<::std::path::PathBuf>,
+    pub peers: Option<[String; 2]>,
+    pub secret: Option<String>,
+    pub verbose: u32,
+    pub debug: bool,
+    pub tls: bool,
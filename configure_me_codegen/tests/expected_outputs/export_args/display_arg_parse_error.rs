@@ -0,0 +1,6 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --port\n\n        --offset\n\n        --tls-cert\n\n        --peers\n\n        --verbose\n\n        --debug\nno-tls\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldOffset(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<i32>>(f, "--offset", err),
+        ArgParseError::FieldTlsCert(err) => ::configure_me::helpers::display_arg_error::<::std::path::PathBuf>(f, "--tls-cert", err),
+        ArgParseError::FieldPeers(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--peers", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,22 @@
+
+impl Config {
+    /// Returns arguments reproducing the configuration, excluding the program name
+    ///
+    /// Params without an argument and unset optional params are skipped.
+    pub fn to_args(&self) -> Vec<::std::ffi::OsString> {
+        let mut args = Vec::new();
+        args.push(::configure_me::helpers::arg_with_value("--port", &self.port));
+        args.push(::configure_me::helpers::arg_with_value("--offset", &self.offset));
+        if let Some(value) = &self.tls_cert {
+            args.push(::configure_me::helpers::arg_with_value("--tls-cert", value));
+        }
+        if let Some(value) = &self.peers {
+            args.push("--peers".into());
+            args.extend(value.iter().map(::configure_me::ToArg::to_arg));
+        }
+        args.extend((0..self.verbose).map(|_| "--verbose".into()));
+        args.push(if self.debug { "--debug" } else { "--debug=0" }.into());
+        args.push(if !self.tls { "--no-tls" } else { "--no-tls=0" }.into());
+        args
+    }
+}
@@ -0,0 +1,31 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<i32>, _, _>("--offset", &arg, &mut iter) {
+                    let offset = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--offset"), ArgParseError::FieldOffset))?.0;
+
+                    self.offset = Some(offset);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tls-cert", &arg, &mut iter) {
+                    let tls_cert = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tls-cert"), ArgParseError::FieldTlsCert))?;
+
+                    self.tls_cert = Some(tls_cert);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peers", &arg, &mut iter) {
+                    let peers = [
+                        value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peers"), ArgParseError::FieldPeers))?,
+                        ::configure_me::parse_arg::ParseArg::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("--peers"))?).map_err(ArgParseError::FieldPeers)?,
+                    ];
+
+                    self.peers = Some(peers);
+                } else if arg == *"--verbose" {
+                    *(self.verbose.get_or_insert(0)) += 1;
+                } else if arg == *"--debug" {
+                    self.debug = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--debug", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--debug", value))?;
+                    self.debug = Some(value);
+                } else if arg == *"--no-tls" {
+                    self.tls = Some(false);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--no-tls", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--no-tls", value))?;
+                    self.tls = Some(!value);
@@ -0,0 +1,14 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.offset, other.offset);
+            ::configure_me::helpers::merge(&mut self.tls_cert, other.tls_cert);
+            ::configure_me::helpers::merge(&mut self.peers, other.peers);
+            ::configure_me::helpers::merge(&mut self.secret, other.secret);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.debug.is_some() {
+                self.debug = other.debug;
+            }
+            if other.tls.is_some() {
+                self.tls = other.tls;
+            }
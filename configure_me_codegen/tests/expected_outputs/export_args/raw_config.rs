@@ -0,0 +1,10 @@
+        port: Option<u16>,
+        offset: Option<i32>,
+        tls_cert: Option<::std::path::PathBuf>,
+        peers: Option<[String; 2]>,
+        secret: Option<String>,
+        verbose: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        debug: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        tls: Option<bool>,
@@ -0,0 +1,25 @@
+            let port = self.port;
+            let offset = self.offset.unwrap_or_else(|| { 0 });
+            let tls_cert = self.tls_cert;
+            let peers = self.peers;
+            let secret = self.secret;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                offset: offset.into(),
+                tls_cert: tls_cert.map(Into::into),
+                peers: peers.map(Into::into),
+                secret: secret.map(Into::into),
+                verbose: self.verbose.unwrap_or(0),
+                debug: self.debug.unwrap_or(false),
+                tls: self.tls.unwrap_or(true),
+            })
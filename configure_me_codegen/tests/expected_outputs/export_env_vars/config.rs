@@ -0,0 +1,7 @@
+    pub port: u16,
+    pub bind_addr: ::std::net::IpAddr,
+    pub tls_cert: Option<::std::path::PathBuf>,
+    pub peers: Option<[String; 2]>,
+    pub verbose: u32,
+    pub debug: bool,
+    pub tls: bool,
@@ -0,0 +1,9 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "EXPORT_PORT", err),
+        EnvParseError::FieldBindAddr(ref err) => ::configure_me::helpers::display_env_error::<::std::net::IpAddr>(f, "EXPORT_BIND_ADDR", err),
+        EnvParseError::FieldTlsCert(ref err) => ::configure_me::helpers::display_env_error::<::std::path::PathBuf>(f, "EXPORT_TLS_CERT", err),
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value for 'EXPORT_VERBOSE': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldDebug(ref err) => ::configure_me::helpers::display_switch_error(f, "EXPORT_DEBUG", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,5 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldBindAddr(<::std::net::IpAddr as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTlsCert(<::std::path::PathBuf as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDebug(::std::ffi::OsString),
@@ -0,0 +1,17 @@
+
+impl Config {
+    /// Returns environment variables reproducing the configuration in a child process
+    ///
+    /// Params and switches without an environment variable and unset optional params are skipped.
+    pub fn to_env_vars(&self) -> Vec<(::std::ffi::OsString, ::std::ffi::OsString)> {
+        let mut vars = Vec::new();
+        vars.push(("EXPORT_PORT".into(), ::configure_me::ToArg::to_arg(&self.port)));
+        vars.push(("EXPORT_BIND_ADDR".into(), ::configure_me::ToArg::to_arg(&self.bind_addr)));
+        if let Some(value) = &self.tls_cert {
+            vars.push(("EXPORT_TLS_CERT".into(), ::configure_me::ToArg::to_arg(value)));
+        }
+        vars.push(("EXPORT_VERBOSE".into(), ::configure_me::ToArg::to_arg(&self.verbose)));
+        vars.push(("EXPORT_DEBUG".into(), if self.debug { "1" } else { "0" }.into()));
+        vars
+    }
+}
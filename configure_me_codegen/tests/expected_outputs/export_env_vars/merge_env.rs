@@ -0,0 +1,9 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("EXPORT_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.bind_addr, ::configure_me::helpers::parse_env("EXPORT_BIND_ADDR").map_err(super::EnvParseError::FieldBindAddr)?);
+        ::configure_me::helpers::merge(&mut self.tls_cert, ::configure_me::helpers::parse_env("EXPORT_TLS_CERT").map_err(super::EnvParseError::FieldTlsCert)?);
+        if let Some(val) = ::std::env::var_os("EXPORT_VERBOSE") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldVerbose)?;            self.verbose = Some(val);
+        }
+        if let Some(val) = ::std::env::var_os("EXPORT_DEBUG") {
+            self.debug = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldDebug)?);
+        }
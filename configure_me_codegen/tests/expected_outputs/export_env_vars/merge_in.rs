@@ -0,0 +1,13 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.bind_addr, other.bind_addr);
+            ::configure_me::helpers::merge(&mut self.tls_cert, other.tls_cert);
+            ::configure_me::helpers::merge(&mut self.peers, other.peers);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.debug.is_some() {
+                self.debug = other.debug;
+            }
+            if other.tls.is_some() {
+                self.tls = other.tls;
+            }
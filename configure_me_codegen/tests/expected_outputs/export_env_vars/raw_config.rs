@@ -0,0 +1,9 @@
+        port: Option<u16>,
+        bind_addr: Option<::std::net::IpAddr>,
+        tls_cert: Option<::std::path::PathBuf>,
+        peers: Option<[String; 2]>,
+        verbose: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        debug: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        tls: Option<bool>,
@@ -0,0 +1 @@
+            let config_content = ::std::fs::read(&config_file_name).map_err(|error| super::Error::Reading { file: config_file_name.clone(), error })?;
@@ -0,0 +1,23 @@
+            let port = self.port;
+            let bind_addr = self.bind_addr.unwrap_or_else(|| { ::std::net::IpAddr::V4(::std::net::Ipv4Addr::LOCALHOST) });
+            let tls_cert = self.tls_cert;
+            let peers = self.peers;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port", "EXPORT_PORT"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                bind_addr: bind_addr.into(),
+                tls_cert: tls_cert.map(Into::into),
+                peers: peers.map(Into::into),
+                verbose: self.verbose.unwrap_or(0),
+                debug: self.debug.unwrap_or(false),
+                tls: self.tls.unwrap_or(true),
+            })
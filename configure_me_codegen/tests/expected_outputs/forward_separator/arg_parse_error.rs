@@ -0,0 +1 @@
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1 @@
+    pub verbose: bool,
@@ -0,0 +1,2 @@
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
@@ -0,0 +1 @@
+                    return Ok(Some(arg).into_iter().chain(iter));
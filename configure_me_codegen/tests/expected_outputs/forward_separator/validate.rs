@@ -0,0 +1,4 @@
+
+            Ok(super::Config {
+                verbose: self.verbose.unwrap_or(false),
+            })
@@ -0,0 +1,2 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT]", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
@@ -0,0 +1,26 @@
+
+static GLOBAL_CONFIG: ::std::sync::OnceLock<Config> = ::std::sync::OnceLock::new();
+
+impl Config {
+    /// Stores the configuration so that it's accessible using `Config::global()`
+    ///
+    /// Panics if the configuration was already stored.
+    pub fn init_global(self) -> &'static Self {
+        if GLOBAL_CONFIG.set(self).is_err() {
+            panic!("the global configuration was already initialized");
+        }
+        Self::global()
+    }
+
+    /// Returns the configuration stored by `Config::init_global()`
+    ///
+    /// Panics if it wasn't stored yet.
+    pub fn global() -> &'static Self {
+        GLOBAL_CONFIG.get().expect("the global configuration wasn't initialized, call Config::init_global() first")
+    }
+
+    /// Returns the configuration stored by `Config::init_global()`, if any
+    pub fn try_global() -> Option<&'static Self> {
+        GLOBAL_CONFIG.get()
+    }
+}
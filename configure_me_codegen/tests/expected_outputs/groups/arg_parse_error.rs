@@ -0,0 +1,5 @@
+    FieldToken(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTokenFile(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldSocket(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
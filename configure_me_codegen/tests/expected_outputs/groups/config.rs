@@ -0,0 +1,6 @@
+    pub token: Option<String>,
+    pub token_file: Option<String>,
+    pub host: Option<String>,
+    pub socket: Option<String>,
+    pub json: bool,
+    pub yaml: bool,
@@ -0,0 +1,6 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --token\n\n        --token-file\n\n        --host\n\n        --socket\n\n        --json\n\n        --yaml\n", program_name),
+        ArgParseError::FieldToken(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--token", err),
+        ArgParseError::FieldTokenFile(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--token-file", err),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
+        ArgParseError::FieldSocket(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--socket", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
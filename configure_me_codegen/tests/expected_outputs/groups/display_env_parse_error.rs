@@ -0,0 +1,6 @@
+        EnvParseError::FieldToken(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "GROUPS_TOKEN", err),
+        EnvParseError::FieldTokenFile(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "GROUPS_TOKEN_FILE", err),
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "GROUPS_HOST", err),
+        EnvParseError::FieldSocket(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "GROUPS_SOCKET", err),
+        EnvParseError::FieldJson(ref err) => ::configure_me::helpers::display_switch_error(f, "GROUPS_JSON", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::FieldYaml(ref err) => ::configure_me::helpers::display_switch_error(f, "GROUPS_YAML", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
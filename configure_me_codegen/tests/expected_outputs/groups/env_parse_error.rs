@@ -0,0 +1,6 @@
+    FieldToken(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTokenFile(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldSocket(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldJson(::std::ffi::OsString),
+    FieldYaml(::std::ffi::OsString),
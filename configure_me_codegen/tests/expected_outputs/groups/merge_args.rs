@@ -0,0 +1,34 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--token", &arg, &mut iter) {
+                    let token = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--token"), ArgParseError::FieldToken))?;
+
+                    self._sources.set("token", ::configure_me::helpers::Source::Argument("--token"));
+                    self.token = Some(token);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--token-file", &arg, &mut iter) {
+                    let token_file = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--token-file"), ArgParseError::FieldTokenFile))?;
+
+                    self._sources.set("token_file", ::configure_me::helpers::Source::Argument("--token-file"));
+                    self.token_file = Some(token_file);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--host", &arg, &mut iter) {
+                    let host = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--host"), ArgParseError::FieldHost))?;
+
+                    self._sources.set("host", ::configure_me::helpers::Source::Argument("--host"));
+                    self.host = Some(host);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--socket", &arg, &mut iter) {
+                    let socket = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--socket"), ArgParseError::FieldSocket))?;
+
+                    self._sources.set("socket", ::configure_me::helpers::Source::Argument("--socket"));
+                    self.socket = Some(socket);
+                } else if arg == *"--json" {
+                    self._sources.set("json", ::configure_me::helpers::Source::Argument("--json"));
+                    self.json = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--json", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--json", value))?;
+                    self._sources.set("json", ::configure_me::helpers::Source::Argument("--json"));
+                    self.json = Some(value);
+                } else if arg == *"--yaml" {
+                    self._sources.set("yaml", ::configure_me::helpers::Source::Argument("--yaml"));
+                    self.yaml = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--yaml", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--yaml", value))?;
+                    self._sources.set("yaml", ::configure_me::helpers::Source::Argument("--yaml"));
+                    self.yaml = Some(value);
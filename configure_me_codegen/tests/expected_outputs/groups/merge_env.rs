@@ -0,0 +1,12 @@
+        ::configure_me::helpers::merge(&mut self.token, self._sources.track("token", ::configure_me::helpers::parse_env("GROUPS_TOKEN").map_err(super::EnvParseError::FieldToken)?, ::configure_me::helpers::Source::EnvVar("GROUPS_TOKEN")));
+        ::configure_me::helpers::merge(&mut self.token_file, self._sources.track("token_file", ::configure_me::helpers::parse_env("GROUPS_TOKEN_FILE").map_err(super::EnvParseError::FieldTokenFile)?, ::configure_me::helpers::Source::EnvVar("GROUPS_TOKEN_FILE")));
+        ::configure_me::helpers::merge(&mut self.host, self._sources.track("host", ::configure_me::helpers::parse_env("GROUPS_HOST").map_err(super::EnvParseError::FieldHost)?, ::configure_me::helpers::Source::EnvVar("GROUPS_HOST")));
+        ::configure_me::helpers::merge(&mut self.socket, self._sources.track("socket", ::configure_me::helpers::parse_env("GROUPS_SOCKET").map_err(super::EnvParseError::FieldSocket)?, ::configure_me::helpers::Source::EnvVar("GROUPS_SOCKET")));
+        if let Some(val) = ::std::env::var_os("GROUPS_JSON") {
+            self.json = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldJson)?);
+            self._sources.set("json", ::configure_me::helpers::Source::EnvVar("GROUPS_JSON"));
+        }
+        if let Some(val) = ::std::env::var_os("GROUPS_YAML") {
+            self.yaml = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldYaml)?);
+            self._sources.set("yaml", ::configure_me::helpers::Source::EnvVar("GROUPS_YAML"));
+        }
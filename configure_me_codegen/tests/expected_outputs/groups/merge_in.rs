@@ -0,0 +1,11 @@
+            ::configure_me::helpers::merge(&mut self.token, other.token);
+            ::configure_me::helpers::merge(&mut self.token_file, other.token_file);
+            ::configure_me::helpers::merge(&mut self.host, other.host);
+            ::configure_me::helpers::merge(&mut self.socket, other.socket);
+            if other.json.is_some() {
+                self.json = other.json;
+            }
+            if other.yaml.is_some() {
+                self.yaml = other.yaml;
+            }
+            self._sources.merge_in(other._sources);
@@ -0,0 +1,3 @@
+            ::configure_me::toml::from_slice(config_content)
+                .map(|config| track_file(config, config_file_name))
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.into(), error })
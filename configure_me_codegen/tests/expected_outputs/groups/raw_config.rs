@@ -0,0 +1,10 @@
+        token: Option<String>,
+        token_file: Option<String>,
+        host: Option<String>,
+        socket: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        json: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        yaml: Option<bool>,
+        #[serde(skip)]
+        _sources: ::configure_me::helpers::Sources,
@@ -0,0 +1,29 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    fn track_file(mut config: Config, file: &::std::path::Path) -> Config {
+        if config.token.is_some() {
+            config._sources.set("token", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.token_file.is_some() {
+            config._sources.set("token_file", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.host.is_some() {
+            config._sources.set("host", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.socket.is_some() {
+            config._sources.set("socket", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.json.is_some() {
+            config._sources.set("json", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.yaml.is_some() {
+            config._sources.set("yaml", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        config
+    }
@@ -0,0 +1,38 @@
+            {
+                let members = [("json", self.json.unwrap_or(false)), ("yaml", self.yaml.unwrap_or(false))];
+                let members = members.iter().filter(|(_, is_set)| *is_set).map(|(name, _)| (*name, self._sources.of(name).into_iter().cloned().collect())).collect::<Vec<(_, Vec<_>)>>();
+                if members.len() > 1 {
+                    return Err(ValidationError::GroupConflict { group: "format", members });
+                }
+            }
+            {
+                let members = [("token", self.token.is_some()), ("token_file", self.token_file.is_some())];
+                let members = members.iter().filter(|(_, is_set)| *is_set).map(|(name, _)| (*name, self._sources.of(name).into_iter().cloned().collect())).collect::<Vec<(_, Vec<_>)>>();
+                if members.is_empty() {
+                    return Err(ValidationError::GroupMissing { group: "auth", members: &["token", "token_file"] });
+                }
+                if members.len() > 1 {
+                    return Err(ValidationError::GroupConflict { group: "auth", members });
+                }
+            }
+            {
+                let members = [("host", self.host.is_some()), ("socket", self.socket.is_some())];
+                let members = members.iter().filter(|(_, is_set)| *is_set).map(|(name, _)| (*name, self._sources.of(name).into_iter().cloned().collect())).collect::<Vec<(_, Vec<_>)>>();
+                if members.is_empty() {
+                    return Err(ValidationError::GroupMissing { group: "connection", members: &["host", "socket"] });
+                }
+            }
+
+            let token = self.token;
+            let token_file = self.token_file;
+            let host = self.host;
+            let socket = self.socket;
+
+            Ok(super::Config {
+                token: token.map(Into::into),
+                token_file: token_file.map(Into::into),
+                host: host.map(Into::into),
+                socket: socket.map(Into::into),
+                json: self.json.unwrap_or(false),
+                yaml: self.yaml.unwrap_or(false),
+            })
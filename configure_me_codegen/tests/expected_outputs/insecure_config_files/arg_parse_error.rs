@@ -0,0 +1 @@
+    FieldToken(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1 @@
+    pub token: Option<String>,
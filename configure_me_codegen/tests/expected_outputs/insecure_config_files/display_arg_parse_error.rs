@@ -0,0 +1,2 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--token TOKEN]", program_name),
+        ArgParseError::FieldToken(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--token", err),
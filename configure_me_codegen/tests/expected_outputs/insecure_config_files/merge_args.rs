@@ -0,0 +1,4 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--token", &arg, &mut iter) {
+                    let token = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--token"), ArgParseError::FieldToken))?;
+
+                    self.token = Some(token);
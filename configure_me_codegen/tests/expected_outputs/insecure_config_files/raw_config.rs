@@ -0,0 +1 @@
+        token: Option<String>,
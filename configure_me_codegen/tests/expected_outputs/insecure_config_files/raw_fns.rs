@@ -0,0 +1,8 @@
+
+    const CONFIG_FILE_CHECKS: ::configure_me::helpers::ConfigFileChecks = ::configure_me::helpers::ConfigFileChecks {
+        insecure: ::configure_me::helpers::insecure_files(::configure_me::helpers::InsecureFile::Error),
+        max_size: None,
+        max_string_length: None,
+        max_depth: None,
+        mmap_threshold: None,
+    };
@@ -0,0 +1 @@
+            let config_content = ::configure_me::helpers::read_config_file(&config_file_name, CONFIG_FILE_CHECKS).map_err(|error| super::Error::Reading { file: config_file_name.clone(), error })?;
@@ -0,0 +1,5 @@
+            let token = self.token;
+
+            Ok(super::Config {
+                token: token.map(Into::into),
+            })
@@ -0,0 +1,5 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldBindAddr(<::std::net::Ipv4Addr as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPeers(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,6 @@
+    pub port: u16,
+    pub bind_addr: ::std::net::Ipv4Addr,
+    pub name: Option<String>,
+    pub peers: Option<Vec<String>>,
+    pub verbose: u32,
+    pub tls: bool,
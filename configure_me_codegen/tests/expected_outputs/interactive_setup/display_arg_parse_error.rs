@@ -0,0 +1,6 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --port         Port to listen on\n        --bind-addr    IP address to bind to\n        --name\n\n        --peers\n\n        --verbose\n\n        --no-tls       Use TLS", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldBindAddr(err) => ::configure_me::helpers::display_arg_error::<::std::net::Ipv4Addr>(f, "--bind-addr", err),
+        ArgParseError::FieldName(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--name", err),
+        ArgParseError::FieldPeers(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--peers", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
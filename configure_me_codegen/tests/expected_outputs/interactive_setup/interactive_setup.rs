@@ -0,0 +1,23 @@
+
+impl Config {
+    /// Asks the user for the values of params and switches and writes them into a new config file
+    ///
+    /// Fails if the file already exists.
+    pub fn interactive_setup<P: AsRef<::std::path::Path>>(path: P) -> ::std::io::Result<()> {
+        let stdin = ::std::io::stdin();
+        let stdout = ::std::io::stdout();
+        Self::interactive_setup_with(path, stdin.lock(), stdout.lock())
+    }
+
+    /// Like `interactive_setup()`, reading the answers from `input` and writing the questions to `output`
+    pub fn interactive_setup_with<P: AsRef<::std::path::Path>, R: ::std::io::BufRead, W: ::std::io::Write>(path: P, input: R, output: W) -> ::std::io::Result<()> {
+        let mut wizard = ::configure_me::helpers::Wizard::new(input, output);
+        wizard.param::<u16>("port", Some("Port to listen on"), None, true)?;
+        wizard.param::<::std::net::Ipv4Addr>("bind_addr", Some("IP address to bind to"), Some(&::configure_me::helpers::format_value(&{ let default: ::std::net::Ipv4Addr = { ::std::net::Ipv4Addr::new(0, 0, 0, 0) }; default })), false)?;
+        wizard.param::<String>("name", None, None, false)?;
+        wizard.param_values::<String, Vec<String>>("peers", None, None, false)?;
+        wizard.count_switch("verbose", None)?;
+        wizard.switch("tls", Some("Use TLS"), true, raw::TRUE_VALUES, raw::FALSE_VALUES)?;
+        wizard.write(path)
+    }
+}
@@ -0,0 +1,29 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bind-addr", &arg, &mut iter) {
+                    let bind_addr = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--bind-addr"), ArgParseError::FieldBindAddr))?;
+
+                    self.bind_addr = Some(bind_addr);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--name", &arg, &mut iter) {
+                    let name = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--name"), ArgParseError::FieldName))?;
+
+                    self.name = Some(name);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peers", &arg, &mut iter) {
+                    let mut peers = vec![value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peers"), ArgParseError::FieldPeers))?];
+                    while peers.len() < 3 {
+                        if iter.peek().map_or(true, |next| next.to_str().map_or(false, |next| next.starts_with('-'))) {
+                            break;
+                        }
+                        peers.push(::configure_me::parse_arg::ParseArg::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("--peers"))?).map_err(ArgParseError::FieldPeers)?);
+                    }
+
+                    self.peers = Some(peers);
+                } else if arg == *"--verbose" {
+                    *(self.verbose.get_or_insert(0)) += 1;
+                } else if arg == *"--no-tls" {
+                    self.tls = Some(false);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--no-tls", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--no-tls", value))?;
+                    self.tls = Some(!value);
@@ -0,0 +1,10 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.bind_addr, other.bind_addr);
+            ::configure_me::helpers::merge(&mut self.name, other.name);
+            ::configure_me::helpers::merge(&mut self.peers, other.peers);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.tls.is_some() {
+                self.tls = other.tls;
+            }
@@ -0,0 +1,7 @@
+        port: Option<u16>,
+        bind_addr: Option<::std::net::Ipv4Addr>,
+        name: Option<String>,
+        peers: Option<Vec<String>>,
+        verbose: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        tls: Option<bool>,
@@ -0,0 +1,22 @@
+            let port = self.port;
+            let bind_addr = self.bind_addr.unwrap_or_else(|| { ::std::net::Ipv4Addr::new(0, 0, 0, 0) });
+            let name = self.name;
+            let peers = self.peers;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                bind_addr: bind_addr.into(),
+                name: name.map(Into::into),
+                peers: peers.map(Into::into),
+                verbose: self.verbose.unwrap_or(0),
+                tls: self.tls.unwrap_or(true),
+            })
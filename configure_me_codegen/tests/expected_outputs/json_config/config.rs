@@ -0,0 +1,3 @@
+    pub port: u16,
+    pub peers: Option<String>,
+    pub verbose: bool,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [--port PORT] [--peers PEERS] [--verbose]\n\nArguments:\n        --config    Load configuration from this file.\n        --port      Port to listen on", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldPeers(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--peers", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,3 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "JSON_CONFIG_PORT", err),
+        EnvParseError::FieldPeers(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "JSON_CONFIG_PEERS", err),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "JSON_CONFIG_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1 @@
+            match config.load_once_as(path.as_ref(), format(path.as_ref())) {
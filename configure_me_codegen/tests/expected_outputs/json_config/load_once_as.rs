@@ -0,0 +1,16 @@
+
+        /// Loads the file in `format`, reading and parsing it only once even if it's specified multiple times
+        pub fn load_once_as<P: Into<PathBuf>>(&mut self, config_file_name: P, format: ::configure_me::ConfigFormat) -> Result<Self, super::Error> {
+            let config_file_name = config_file_name.into();
+            if let Some((_, table)) = self._parsed_files.iter().find(|(file, _)| *file == config_file_name) {
+                return self.parse_document(&config_file_name, table.clone());
+            }
+
+            let config_content = ::std::fs::read(&config_file_name).map_err(|error| super::Error::Reading { file: config_file_name.clone(), error })?;
+            let table = ::configure_me::helpers::to_toml(&config_content, format)
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.clone(), error })?;
+            let config = self.parse_document(&config_file_name, table.clone())?;
+            self._loaded_files.push((config_file_name.clone(), ::configure_me::helpers::FileDigest::of(&config_content)));
+            self._parsed_files.push((config_file_name, table));
+            Ok(config)
+        }
@@ -0,0 +1,5 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("JSON_CONFIG_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge_with(&mut self.peers, ::configure_me::helpers::parse_env("JSON_CONFIG_PEERS").map_err(super::EnvParseError::FieldPeers)?, (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+        if let Some(val) = ::std::env::var_os("JSON_CONFIG_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
@@ -0,0 +1,5 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge_with(&mut self.peers, other.peers, (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,21 @@
+        Self::custom_args_and_files_with_format(args, config_files, ::configure_me::ConfigFormat::of)
+    }
+
+    /// Loads the config files in `format` regardless of their extensions
+    pub fn including_optional_config_files_as<I>(config_files: I, format: ::configure_me::ConfigFormat) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+        Self::custom_args_and_optional_files_as(::std::env::args_os(), config_files, format)
+    }
+
+    /// Loads the config files in `format` regardless of their extensions
+    pub fn custom_args_and_optional_files_as<A, I>(args: A, config_files: I, format: ::configure_me::ConfigFormat) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where
+        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
+        I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+
+        Self::custom_args_and_files_with_format(args, config_files, move |_| format)
+    }
+
+    fn custom_args_and_files_with_format<A, I, F>(args: A, config_files: I, format: F) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where
+        A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
+        I: IntoIterator, I::Item: AsRef<::std::path::Path>,
+        F: Fn(&::std::path::Path) -> ::configure_me::ConfigFormat {
+
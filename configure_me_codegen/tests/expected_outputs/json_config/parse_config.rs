@@ -0,0 +1,6 @@
+            self.parse_as(config_file_name, config_content, ::configure_me::ConfigFormat::of(config_file_name))
+        }
+
+        fn parse_as(&self, config_file_name: &::std::path::Path, config_content: &[u8], format: ::configure_me::ConfigFormat) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::to_toml(config_content, format).map_err(|error| super::Error::ConfigParsing { file: config_file_name.into(), error })?;
+            self.parse_document(config_file_name, config_content)
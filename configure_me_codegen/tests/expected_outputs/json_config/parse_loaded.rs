@@ -0,0 +1,3 @@
+            let table = ::configure_me::helpers::to_toml(&config_content, ::configure_me::ConfigFormat::of(&config_file_name))
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.clone(), error })?;
+            let config = self.parse_document(&config_file_name, table.clone())?;
@@ -0,0 +1,17 @@
+            let port = self.port;
+            let peers = self.peers;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port", "JSON_CONFIG_PORT"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                peers: peers.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+            })
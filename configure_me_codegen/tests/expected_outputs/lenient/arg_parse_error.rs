@@ -0,0 +1,3 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldName(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldRaw(<::std::ffi::OsString as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,4 @@
+    pub port: Option<u16>,
+    pub name: Option<String>,
+    pub raw: Option<::std::ffi::OsString>,
+    pub verbose: u32,
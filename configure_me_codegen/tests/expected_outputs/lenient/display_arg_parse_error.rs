@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --config    Load configuration from this file.", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldName(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--name", err),
+        ArgParseError::FieldRaw(err) => ::configure_me::helpers::display_arg_error::<::std::ffi::OsString>(f, "--raw", err),
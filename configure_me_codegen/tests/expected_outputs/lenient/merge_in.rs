@@ -0,0 +1,6 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.name, other.name);
+            ::configure_me::helpers::merge(&mut self.raw, other.raw);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
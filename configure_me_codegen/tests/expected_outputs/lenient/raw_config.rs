@@ -0,0 +1,8 @@
+        #[serde(default, deserialize_with = "lenient_port")]
+        port: Option<u16>,
+        #[serde(default, deserialize_with = "lenient_name")]
+        name: Option<String>,
+        #[serde(default, deserialize_with = "lenient_raw")]
+        raw: Option<::std::ffi::OsString>,
+        #[serde(default, deserialize_with = "lenient_verbose")]
+        verbose: Option<u32>,
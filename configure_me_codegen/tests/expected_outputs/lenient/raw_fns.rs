@@ -0,0 +1,24 @@
+
+    fn lenient_port<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<u16>, D::Error> {
+        ::configure_me::helpers::deserialize_lenient(deserializer, "port")
+    }
+
+    fn lenient_name<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+        ::configure_me::helpers::deserialize_lenient(deserializer, "name")
+    }
+
+    fn lenient_raw<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<::std::ffi::OsString>, D::Error> {
+        ::configure_me::helpers::deserialize_lenient::<String, _>(deserializer, "raw").map(|value| value.map(Into::into))
+    }
+
+    fn lenient_verbose<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<u32>, D::Error> {
+        ::configure_me::helpers::deserialize_lenient(deserializer, "verbose")
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
@@ -0,0 +1,10 @@
+            let port = self.port;
+            let name = self.name;
+            let raw = self.raw;
+
+            Ok(super::Config {
+                port: port.map(Into::into),
+                name: name.map(Into::into),
+                raw: raw.map(Into::into),
+                verbose: self.verbose.unwrap_or(0),
+            })
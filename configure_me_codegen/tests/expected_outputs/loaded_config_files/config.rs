@@ -0,0 +1,3 @@
+    pub port: Option<u16>,
+    pub host: Option<String>,
+    _loaded_config_files: Vec<::std::path::PathBuf>,
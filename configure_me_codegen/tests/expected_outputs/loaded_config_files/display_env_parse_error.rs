@@ -0,0 +1,2 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "LOADED_CONFIG_FILES_PORT", err),
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "LOADED_CONFIG_FILES_HOST", err),
@@ -0,0 +1,9 @@
+
+impl Config {
+    /// Paths of the config files that were loaded, in the order they were read
+    ///
+    /// Missing optional files aren't included, the files given in arguments come last.
+    pub fn loaded_config_files(&self) -> &[::std::path::PathBuf] {
+        &self._loaded_config_files
+    }
+}
@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("LOADED_CONFIG_FILES_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env("LOADED_CONFIG_FILES_HOST").map_err(super::EnvParseError::FieldHost)?);
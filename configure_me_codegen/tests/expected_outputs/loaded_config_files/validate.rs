@@ -0,0 +1,8 @@
+            let port = self.port;
+            let host = self.host;
+
+            Ok(super::Config {
+                port: port.map(Into::into),
+                host: host.map(Into::into),
+                _loaded_config_files: self._loaded_files.iter().map(|(file, _)| file.clone()).collect(),
+            })
@@ -0,0 +1,5 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPortRange(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldBindAddr(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    AmbiguousArgument(String, Vec<&'static str>),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
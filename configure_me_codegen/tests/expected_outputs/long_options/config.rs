@@ -0,0 +1,5 @@
+    pub port: Option<u16>,
+    pub port_range: Option<u16>,
+    pub bind_addr: Option<String>,
+    pub verbose: bool,
+    pub color: bool,
@@ -0,0 +1,6 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --config        Load configuration from this file.\n        --port\n\n        --port-range\n\n        --bind-addr\n\n        --verbose\nno-color\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldPortRange(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port-range", err),
+        ArgParseError::FieldBindAddr(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--bind-addr", err),
+        ArgParseError::AmbiguousArgument(arg, candidates) => write!(f, "The argument '{}' is ambiguous, it may mean: {}.", arg, candidates.join(", ")),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
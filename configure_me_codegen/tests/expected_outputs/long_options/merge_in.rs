@@ -0,0 +1,9 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.port_range, other.port_range);
+            ::configure_me::helpers::merge(&mut self.bind_addr, other.bind_addr);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.color.is_some() {
+                self.color = other.color;
+            }
@@ -0,0 +1 @@
+                let arg = normalize_long_option(arg)?;
@@ -0,0 +1,7 @@
+        port: Option<u16>,
+        port_range: Option<u16>,
+        bind_addr: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        color: Option<bool>,
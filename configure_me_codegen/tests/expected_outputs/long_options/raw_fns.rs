@@ -0,0 +1,48 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    const LONG_OPTIONS: &[&str] = &["--help", "--config", "--port", "--port-range", "--bind-addr", "--verbose", "--no-color"];
+
+    pub fn normalize_long_option(arg: ::std::ffi::OsString) -> Result<::std::ffi::OsString, ArgParseError> {
+        let (name, value) = match arg.to_str() {
+            Some(arg) if arg.starts_with("--") && arg.len() > 2 => match arg.find('=') {
+                Some(pos) => (arg[..pos].to_owned(), arg[pos..].to_owned()),
+                None => (arg.to_owned(), String::new()),
+            },
+            Some("/?") => return Ok("--help".into()),
+            Some(arg) if arg.starts_with('/') && arg.len() > 1 => match arg.find(&[':', '='][..]) {
+                Some(pos) => (format!("--{}", &arg[1..pos]), format!("={}", &arg[(pos + 1)..])),
+                None => (format!("--{}", &arg[1..]), String::new()),
+            },
+            _ => return Ok(arg),
+        };
+        let name = name.to_ascii_lowercase();
+
+        if LONG_OPTIONS.contains(&&*name) {
+            return Ok(format!("{}{}", name, value).into());
+        }
+
+        if arg.to_str().map_or(false, |arg| arg.starts_with('/')) {
+            return Ok(arg);
+        }
+        let candidates = LONG_OPTIONS.iter().cloned().filter(|option| option.starts_with(&*name)).collect::<Vec<_>>();
+        match candidates.len() {
+            0 => (),
+            1 => return Ok(format!("{}{}", candidates[0], value).into()),
+            _ => return Err(ArgParseError::AmbiguousArgument(name, candidates)),
+        }
+        Ok(arg)
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
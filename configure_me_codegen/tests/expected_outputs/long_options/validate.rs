@@ -0,0 +1,11 @@
+            let port = self.port;
+            let port_range = self.port_range;
+            let bind_addr = self.bind_addr;
+
+            Ok(super::Config {
+                port: port.map(Into::into),
+                port_range: port_range.map(Into::into),
+                bind_addr: bind_addr.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+                color: self.color.unwrap_or(true),
+            })
@@ -0,0 +1,2 @@
+    FieldLabel(<::configure_me::helpers::KeyValue<String> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLimit(<::configure_me::helpers::KeyValue<::configure_me::helpers::Int<u32>> as ::configure_me::parse_arg::ParseArg>::Error),
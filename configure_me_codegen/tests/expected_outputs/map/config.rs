@@ -0,0 +1,2 @@
+    pub label: Option<::std::collections::HashMap<String, String>>,
+    pub limit: ::std::collections::HashMap<String, u32>,
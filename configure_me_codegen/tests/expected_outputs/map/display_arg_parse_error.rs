@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [-l KEY=VALUE|--label KEY=VALUE ...] [--limit KEY=VALUE ...]\n\nArguments:\n        --config       Load configuration from this file.\n        -l, --label    Labels attached to the records", program_name),
+        ArgParseError::FieldLabel(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::KeyValue<String>>(f, "--label", err),
+        ArgParseError::FieldLimit(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::KeyValue<::configure_me::helpers::Int<u32>>>(f, "--limit", err),
@@ -0,0 +1,2 @@
+        EnvParseError::FieldLabel(ref name, ref err) => ::configure_me::helpers::display_env_error::<String>(f, name, err),
+        EnvParseError::FieldLimit(ref name, ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u32>>(f, name, err),
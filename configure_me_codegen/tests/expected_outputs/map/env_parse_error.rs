@@ -0,0 +1,2 @@
+    FieldLabel(String, <String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLimit(String, <::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
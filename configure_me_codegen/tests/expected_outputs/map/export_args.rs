@@ -0,0 +1,14 @@
+
+impl Config {
+    /// Returns arguments reproducing the configuration, excluding the program name
+    ///
+    /// Params without an argument and unset optional params are skipped.
+    pub fn to_args(&self) -> Vec<::std::ffi::OsString> {
+        let mut args = Vec::new();
+        if let Some(value) = &self.label {
+            args.extend(::configure_me::helpers::map_args("--label", value));
+        }
+        args.extend(::configure_me::helpers::map_args("--limit", &self.limit));
+        args
+    }
+}
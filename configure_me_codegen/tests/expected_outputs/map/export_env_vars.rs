@@ -0,0 +1,14 @@
+
+impl Config {
+    /// Returns environment variables reproducing the configuration in a child process
+    ///
+    /// Params and switches without an environment variable and unset optional params are skipped.
+    pub fn to_env_vars(&self) -> Vec<(::std::ffi::OsString, ::std::ffi::OsString)> {
+        let mut vars = Vec::new();
+        if let Some(value) = &self.label {
+            vars.extend(::configure_me::helpers::map_env_vars("MAP_LABEL_", value));
+        }
+        vars.extend(::configure_me::helpers::map_env_vars("MAP_LIMIT_", &self.limit));
+        vars
+    }
+}
@@ -0,0 +1,19 @@
+
+impl Config {
+    /// Asks the user for the values of params and switches and writes them into a new config file
+    ///
+    /// Fails if the file already exists.
+    pub fn interactive_setup<P: AsRef<::std::path::Path>>(path: P) -> ::std::io::Result<()> {
+        let stdin = ::std::io::stdin();
+        let stdout = ::std::io::stdout();
+        Self::interactive_setup_with(path, stdin.lock(), stdout.lock())
+    }
+
+    /// Like `interactive_setup()`, reading the answers from `input` and writing the questions to `output`
+    pub fn interactive_setup_with<P: AsRef<::std::path::Path>, R: ::std::io::BufRead, W: ::std::io::Write>(path: P, input: R, output: W) -> ::std::io::Result<()> {
+        let mut wizard = ::configure_me::helpers::Wizard::new(input, output);
+        wizard.param_map::<String, ::std::collections::HashMap<String, String>>("label", Some("Labels attached to the records"), None, false)?;
+        wizard.param_map::<u32, ::std::collections::HashMap<String, u32>>("limit", None, Some(&::configure_me::helpers::format_map(&{ let default: ::std::collections::HashMap<String, u32> = { ::std::collections::HashMap::new() }; default })), false)?;
+        wizard.write(path)
+    }
+}
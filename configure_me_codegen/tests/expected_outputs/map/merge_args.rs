@@ -0,0 +1,19 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::KeyValue<String>, _, _>("--label", &arg, &mut iter) {
+                    let ::configure_me::helpers::KeyValue(key, value) = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--label"), ArgParseError::FieldLabel))?;
+                    let label = ::std::iter::once((key, value)).collect::<::std::collections::HashMap<String, String>>();
+
+                    ::configure_me::helpers::merge_with(&mut self.label, Some(label), ::configure_me::helpers::merge_maps);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::KeyValue<::configure_me::helpers::Int<u32>>, _, _>("--limit", &arg, &mut iter) {
+                    let ::configure_me::helpers::KeyValue(key, value) = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--limit"), ArgParseError::FieldLimit))?;
+                    let limit = ::std::iter::once((key, value.0)).collect::<::std::collections::HashMap<String, u32>>();
+
+                    ::configure_me::helpers::merge_with(&mut self.limit, Some(limit), ::configure_me::helpers::merge_maps);
@@ -0,0 +1 @@
+            self.merge_env_with(|name| ::std::env::var_os(name), ::std::env::vars_os())?;
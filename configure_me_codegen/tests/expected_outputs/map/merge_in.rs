@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge_with(&mut self.label, other.label, ::configure_me::helpers::merge_maps);
+            ::configure_me::helpers::merge_with(&mut self.limit, other.limit, ::configure_me::helpers::merge_maps);
@@ -0,0 +1,5 @@
+                        } else if short == 'l' {
+                            let ::configure_me::helpers::KeyValue(key, value) = shorts.parse_remaining::<::configure_me::helpers::KeyValue<String>, _>(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-l"), ArgParseError::FieldLabel))?;
+                            let label = ::std::iter::once((key, value)).collect::<::std::collections::HashMap<String, String>>();
+                            ::configure_me::helpers::merge_with(&mut self.label, Some(label), ::configure_me::helpers::merge_maps);
+                            break;
@@ -0,0 +1,26 @@
+
+/// Param or switch whose value differs from the default
+pub struct NonDefaultField {
+    pub name: &'static str,
+    /// `None` if the param is mandatory or unset by default
+    pub default: Option<String>,
+    /// `None` if the optional param is unset
+    pub value: Option<String>,
+}
+
+impl Config {
+    /// Returns the params and switches whose values differ from the defaults
+    ///
+    /// Mandatory params are always returned, the values of secret params are redacted.
+    pub fn non_default_fields(&self) -> Vec<NonDefaultField> {
+        let mut fields = Vec::new();
+        if let Some(value) = &self.label {
+            fields.push(NonDefaultField { name: "label", default: None, value: Some(::configure_me::helpers::format_map(value)) });
+        }
+        let default: ::std::collections::HashMap<String, u32> = { let default: ::std::collections::HashMap<String, u32> = { ::std::collections::HashMap::new() }; default.into() };
+        if self.limit != default {
+            fields.push(NonDefaultField { name: "limit", default: Some(::configure_me::helpers::format_map(&default)), value: Some(::configure_me::helpers::format_map(&self.limit)) });
+        }
+        fields
+    }
+}
@@ -0,0 +1,2 @@
+        label: Option<::std::collections::HashMap<String, String>>,
+        limit: Option<::std::collections::HashMap<String, u32>>,
@@ -0,0 +1,28 @@
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
+
+    impl Config {
+        /// Merges environment variables looked up using `var`, the entries of maps are taken from `vars`
+        pub fn merge_env_with<F: Fn(&str) -> Option<::std::ffi::OsString>, V: IntoIterator<Item=(::std::ffi::OsString, ::std::ffi::OsString)>>(&mut self, var: F, vars: V) -> Result<(), super::Error> {
+            let vars = vars.into_iter().collect::<Vec<_>>();
+        ::configure_me::helpers::merge_with(&mut self.label, ::configure_me::helpers::parse_env_map::<String, _>("MAP_LABEL_", vars.iter().cloned()).map_err(|(name, err)| super::EnvParseError::FieldLabel(name, err))?, ::configure_me::helpers::merge_maps);
+        ::configure_me::helpers::merge_with(&mut self.limit, ::configure_me::helpers::parse_env_map::<::configure_me::helpers::Int<u32>, _>("MAP_LIMIT_", vars.iter().cloned()).map_err(|(name, err)| super::EnvParseError::FieldLimit(name, err))?.map(|map| map.into_iter().map(|(key, value)| (key, value.0)).collect()), ::configure_me::helpers::merge_maps);
+            Ok(())
+        }
+    }
+
+    /// Adds the values set in `config` to the reports of the fields, which are in the order of the specification
+    pub fn report_values(config: &Config, source: &super::ReportSource, fields: &mut [super::FieldReport]) {
+        if let Some(value) = &config.label {
+            fields[0].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_map(value) });
+        }
+        if let Some(value) = &config.limit {
+            fields[1].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_map(value) });
+        }
+    }
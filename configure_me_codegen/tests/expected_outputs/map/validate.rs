@@ -0,0 +1,7 @@
+            let label = self.label;
+            let limit = self.limit.unwrap_or_else(|| { ::std::collections::HashMap::new() });
+
+            Ok(super::Config {
+                label: label.map(Into::into),
+                limit: limit.into(),
+            })
@@ -0,0 +1,6 @@
+    pub port: u16,
+    pub peers: Option<[String; 2]>,
+    pub tags: Option<String>,
+    pub password: Option<String>,
+    pub verbose: u32,
+    pub debug: bool,
@@ -0,0 +1,10 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "REPORT_PORT", err),
+        EnvParseError::FieldTags(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "REPORT_TAGS", err),
+        EnvParseError::FieldPassword(_) => ::configure_me::helpers::display_secret_env_error::<String>(f, "REPORT_PASSWORD"),
+        EnvParseError::FieldVerbose(ref err) => {
+            write!(f, "Invalid value for 'REPORT_VERBOSE': {}.\n\nHint: the value must be ", err)?;
+            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
+            write!(f, ".")
+        },
+        EnvParseError::FieldDebug(ref err) => ::configure_me::helpers::display_switch_error(f, "REPORT_DEBUG", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::SecretFile { name, ref file, ref error } => write!(f, "Failed to read the file {} named by the environment variable '{}_FILE': {}", file.display(), name, error),
@@ -0,0 +1,6 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTags(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPassword(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDebug(::std::ffi::OsString),
+    SecretFile { name: &'static str, file: ::std::path::PathBuf, error: ::std::io::Error },
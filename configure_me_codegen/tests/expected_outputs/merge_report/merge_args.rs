@@ -0,0 +1,35 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peers", &arg, &mut iter) {
+                    let peers = [
+                        value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peers"), ArgParseError::FieldPeers))?,
+                        ::configure_me::parse_arg::ParseArg::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("--peers"))?).map_err(ArgParseError::FieldPeers)?,
+                    ];
+
+                    self.peers = Some(peers);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tags", &arg, &mut iter) {
+                    let tags = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tags"), ArgParseError::FieldTags))?;
+
+                    ::configure_me::helpers::merge_with(&mut self.tags, Some(tags), (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password", &arg, &mut iter) {
+                    let password = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password"), ArgParseError::FieldPassword))?;
+
+                    self.password = Some(password);
+                } else if arg == *"--verbose" {
+                    *(self.verbose.get_or_insert(0)) += 1;
+                } else if arg == *"--debug" {
+                    self.debug = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--debug", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--debug", value))?;
+                    self.debug = Some(value);
@@ -0,0 +1,10 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.peers, other.peers);
+            ::configure_me::helpers::merge_with(&mut self.tags, other.tags, (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+            ::configure_me::helpers::merge(&mut self.password, other.password);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.debug.is_some() {
+                self.debug = other.debug;
+            }
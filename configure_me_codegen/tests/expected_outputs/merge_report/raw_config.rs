@@ -0,0 +1,8 @@
+        port: Option<u16>,
+        peers: Option<[String; 2]>,
+        tags: Option<String>,
+        #[serde(default, deserialize_with = "secret_password")]
+        password: Option<String>,
+        verbose: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        debug: Option<bool>,
@@ -0,0 +1,57 @@
+
+    fn secret_password<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+        ::configure_me::helpers::deserialize_secret(deserializer)
+    }
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
+
+    impl Config {
+        /// Merges environment variables looked up using `var`
+        pub fn merge_env_with<F: Fn(&str) -> Option<::std::ffi::OsString>>(&mut self, var: F) -> Result<(), super::Error> {
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env_value::<::configure_me::helpers::Int<u16>>(var("REPORT_PORT")).map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge_with(&mut self.tags, ::configure_me::helpers::parse_env_value(var("REPORT_TAGS")).map_err(super::EnvParseError::FieldTags)?, (|a: &mut String, b: String| { a.push(','); a.push_str(&b) }));
+        ::configure_me::helpers::merge(&mut self.password, ::configure_me::helpers::parse_env_value(::configure_me::helpers::secret_env_var("REPORT_PASSWORD", &var).map_err(|(file, error)| super::EnvParseError::SecretFile { name: "REPORT_PASSWORD", file, error })?).map_err(super::EnvParseError::FieldPassword)?);
+        if let Some(val) = var("REPORT_VERBOSE") {
+            let val= <u32 as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(val).map_err(super::EnvParseError::FieldVerbose)?;            self.verbose = Some(val);
+        }
+        if let Some(val) = var("REPORT_DEBUG") {
+            self.debug = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldDebug)?);
+        }
+            Ok(())
+        }
+    }
+
+    /// Adds the values set in `config` to the reports of the fields, which are in the order of the specification
+    pub fn report_values(config: &Config, source: &super::ReportSource, fields: &mut [super::FieldReport]) {
+        if let Some(value) = &config.port {
+            fields[0].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_value(value) });
+        }
+        if let Some(value) = &config.peers {
+            fields[1].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_values(value) });
+        }
+        if let Some(value) = &config.tags {
+            fields[2].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::format_value(value) });
+        }
+        if config.password.is_some() {
+            fields[3].contributions.push(super::Contribution { source: source.clone(), value: ::configure_me::helpers::REDACTED.to_owned() });
+        }
+        if let Some(value) = config.verbose {
+            fields[4].contributions.push(super::Contribution { source: source.clone(), value: value.to_string() });
+        }
+        if let Some(value) = config.debug {
+            fields[5].contributions.push(super::Contribution { source: source.clone(), value: value.to_string() });
+        }
+    }
@@ -0,0 +1,22 @@
+            let port = self.port;
+            let peers = self.peers;
+            let tags = self.tags;
+            let password = self.password;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port", "REPORT_PORT"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                peers: peers.map(Into::into),
+                tags: tags.map(Into::into),
+                password: password.map(Into::into),
+                verbose: self.verbose.unwrap_or(0),
+                debug: self.debug.unwrap_or(false),
+            })
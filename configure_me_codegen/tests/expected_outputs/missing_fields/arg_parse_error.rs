@@ -0,0 +1,3 @@
+    FieldHost(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldUser(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,5 @@
+    pub host: String,
+    pub port: u16,
+    pub token: String,
+    pub secret: String,
+    pub user: Option<String>,
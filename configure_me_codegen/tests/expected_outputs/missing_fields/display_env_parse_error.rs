@@ -0,0 +1,4 @@
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "MISSING_FIELDS_TEST_HOST", err),
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "MISSING_FIELDS_TEST_PORT", err),
+        EnvParseError::FieldToken(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "MISSING_FIELDS_TEST_TOKEN", err),
+        EnvParseError::FieldUser(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "MISSING_FIELDS_TEST_USER", err),
@@ -0,0 +1,4 @@
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env("MISSING_FIELDS_TEST_HOST").map_err(super::EnvParseError::FieldHost)?);
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("MISSING_FIELDS_TEST_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.token, ::configure_me::helpers::parse_env("MISSING_FIELDS_TEST_TOKEN").map_err(super::EnvParseError::FieldToken)?);
+        ::configure_me::helpers::merge(&mut self.user, ::configure_me::helpers::parse_env("MISSING_FIELDS_TEST_USER").map_err(super::EnvParseError::FieldUser)?);
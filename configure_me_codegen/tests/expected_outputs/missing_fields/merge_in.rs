@@ -0,0 +1,5 @@
+            ::configure_me::helpers::merge(&mut self.host, other.host);
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.token, other.token);
+            ::configure_me::helpers::merge(&mut self.secret, other.secret);
+            ::configure_me::helpers::merge(&mut self.user, other.user);
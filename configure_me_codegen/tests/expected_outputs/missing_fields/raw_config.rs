@@ -0,0 +1,5 @@
+        host: Option<String>,
+        port: Option<u16>,
+        token: Option<String>,
+        secret: Option<String>,
+        user: Option<String>,
@@ -0,0 +1,31 @@
+            let host = self.host;
+            let port = self.port;
+            let token = self.token;
+            let secret = self.secret;
+            let user = self.user;
+
+            let mut missing_fields = Vec::new();
+            if host.is_none() {
+                missing_fields.push(super::MissingField { name: "host", sources: &["--host", "MISSING_FIELDS_TEST_HOST"] });
+            }
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port", "MISSING_FIELDS_TEST_PORT"] });
+            }
+            if token.is_none() {
+                missing_fields.push(super::MissingField { name: "token", sources: &["MISSING_FIELDS_TEST_TOKEN"] });
+            }
+            if secret.is_none() {
+                missing_fields.push(super::MissingField { name: "secret", sources: &[] });
+            }
+            let (host, port, token, secret) = match (host, port, token, secret) {
+                (Some(host), Some(port), Some(token), Some(secret)) => (host, port, token, secret),
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                host: host.into(),
+                port: port.into(),
+                token: token.into(),
+                secret: secret.into(),
+                user: user.map(Into::into),
+            })
@@ -0,0 +1,2 @@
+    FieldTag(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
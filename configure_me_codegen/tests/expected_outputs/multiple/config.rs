@@ -0,0 +1,2 @@
+    pub tag: Option<Vec<String>>,
+    pub port: Vec<u16>,
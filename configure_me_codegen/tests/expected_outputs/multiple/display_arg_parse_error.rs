@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [-t TAG|--tag TAG ...] [-p PORT|--port PORT ...]\n\nArguments:\n        --config     Load configuration from this file.\n        -t, --tag    Tags attached to the records", program_name),
+        ArgParseError::FieldTag(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tag", err),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
@@ -0,0 +1,2 @@
+        EnvParseError::FieldTag(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "MULTIPLE_TAG", err),
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "MULTIPLE_PORT", err),
@@ -0,0 +1 @@
+            let mut seen_port = false;
@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge_with(&mut self.tag, ::configure_me::helpers::parse_env::<::configure_me::helpers::Delimited<String, ','>>("MULTIPLE_TAG").map_err(super::EnvParseError::FieldTag)?.map(|values| values.0), ::configure_me::helpers::append_values);
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Delimited<::configure_me::helpers::Int<u16>, ':'>>("MULTIPLE_PORT").map_err(super::EnvParseError::FieldPort)?.map(|values| values.0.into_iter().map(|value| value.0).collect()));
@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge_with(&mut self.tag, other.tag, ::configure_me::helpers::append_values);
+            ::configure_me::helpers::merge(&mut self.port, other.port);
@@ -0,0 +1,13 @@
+                        } else if short == 't' {
+                            let tag = vec![shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-t"), ArgParseError::FieldTag))?];
+                            ::configure_me::helpers::merge_with(&mut self.tag, Some(tag), ::configure_me::helpers::append_values);
+                            break;
+                        } else if short == 'p' {
+                            let port = vec![shorts.parse_remaining::<::configure_me::helpers::Int<u16>, _>(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-p"), ArgParseError::FieldPort))?.0];
+                            if seen_port {
+                                self.port.get_or_insert_with(Vec::new).extend(port);
+                            } else {
+                                seen_port = true;
+                                self.port = Some(port);
+                            }
+                            break;
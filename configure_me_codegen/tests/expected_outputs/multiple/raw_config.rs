@@ -0,0 +1,2 @@
+        tag: Option<Vec<String>>,
+        port: Option<Vec<u16>>,
@@ -0,0 +1,7 @@
+            let tag = self.tag;
+            let port = self.port.unwrap_or_else(|| { vec![8080] });
+
+            Ok(super::Config {
+                tag: tag.map(Into::into),
+                port: port.into(),
+            })
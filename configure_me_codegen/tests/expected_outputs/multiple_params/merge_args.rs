@@ -1,5 +1,5 @@
-                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--foo", &arg, &mut iter) {
-                    let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?;
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--foo", &arg, &mut iter) {
+                    let foo = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--foo"), ArgParseError::FieldFoo))?.0;
 
                     self.foo = Some(foo);
                 } else if let Some(value) = ::configure_me::parse_arg::match_arg("--bar", &arg, &mut iter) {
@@ -12,5 +12,11 @@
                     self.baz = Some(baz);
                 } else if arg == *"--verbose" {
                     self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
                 } else if arg == *"--no-fast" {
                     self.fast = Some(false);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--no-fast", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--no-fast", value))?;
+                    self.fast = Some(!value);
@@ -1,30 +1,9 @@
-        if let Some(val) = ::std::env::var_os("TEST_APP_FOO") {
-            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldFoo)?;
-            self.foo = Some(val);
-        }
-        if let Some(val) = ::std::env::var_os("TEST_APP_BAR") {
-            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBar)?;
-            self.bar = Some(val);
-        }
-        if let Some(val) = ::std::env::var_os("TEST_APP_BAZ") {
-            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldBaz)?;
-            self.baz = Some(val);
-        }
+        ::configure_me::helpers::merge(&mut self.foo, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u32>>("TEST_APP_FOO").map_err(super::EnvParseError::FieldFoo)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.bar, ::configure_me::helpers::parse_env("TEST_APP_BAR").map_err(super::EnvParseError::FieldBar)?);
+        ::configure_me::helpers::merge(&mut self.baz, ::configure_me::helpers::parse_env("TEST_APP_BAZ").map_err(super::EnvParseError::FieldBaz)?);
         if let Some(val) = ::std::env::var_os("TEST_APP_VERBOSE") {
-            if val == *"1" || val == *"true" {
-                self.verbose = Some(true);
-            } else if val == *"0" || val == *"false" {
-                self.verbose = Some(false);
-            } else {
-                return Err(super::EnvParseError::FieldVerbose(val).into());
-            }
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
         }
         if let Some(val) = ::std::env::var_os("TEST_APP_FAST") {
-            if val == *"1" || val == *"true" {
-                self.fast = Some(true);
-            } else if val == *"0" || val == *"false" {
-                self.fast = Some(false);
-            } else {
-                return Err(super::EnvParseError::FieldFast(val).into());
-            }
+            self.fast = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldFast)?);
         }
@@ -1,12 +1,6 @@
-            if other.foo.is_some() {
-                self.foo = other.foo;
-            }
-            if other.bar.is_some() {
-                self.bar = other.bar;
-            }
-            if other.baz.is_some() {
-                self.baz = other.baz;
-            }
+            ::configure_me::helpers::merge(&mut self.foo, other.foo);
+            ::configure_me::helpers::merge(&mut self.bar, other.bar);
+            ::configure_me::helpers::merge(&mut self.baz, other.baz);
             if other.verbose.is_some() {
                 self.verbose = other.verbose;
             }
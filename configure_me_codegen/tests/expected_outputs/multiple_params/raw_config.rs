@@ -1,5 +1,7 @@
         foo: Option<u32>,
         bar: Option<String>,
         baz: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
         verbose: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
         fast: Option<bool>,
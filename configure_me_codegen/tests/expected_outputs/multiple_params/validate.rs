@@ -1,6 +1,15 @@
             let foo = self.foo.unwrap_or_else(|| { 42 });
             let bar = self.bar;
-            let baz = self.baz.ok_or(ValidationError::MissingField("baz"))?;
+            let baz = self.baz;
+
+            let mut missing_fields = Vec::new();
+            if baz.is_none() {
+                missing_fields.push(super::MissingField { name: "baz", sources: &["--baz", "TEST_APP_BAZ"] });
+            }
+            let baz = match baz {
+                Some(baz) => baz,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
 
             Ok(super::Config {
                 foo: foo.into(),
@@ -0,0 +1,3 @@
+    FieldPoint(<::configure_me::helpers::Int<i32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFiles(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
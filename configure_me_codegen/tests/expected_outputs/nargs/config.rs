@@ -0,0 +1,3 @@
+    pub point: Option<[i32; 2]>,
+    pub files: Option<Vec<String>>,
+    pub verbose: bool,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-p POINT POINT|--point POINT POINT] [--files FILES...] [-v|--verbose]", program_name),
+        ArgParseError::FieldPoint(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<i32>>(f, "--point", err),
+        ArgParseError::FieldFiles(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--files", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
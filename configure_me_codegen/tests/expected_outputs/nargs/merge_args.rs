@@ -0,0 +1,22 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<i32>, _, _>("--point", &arg, &mut iter) {
+                    let point = [
+                        value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--point"), ArgParseError::FieldPoint))?.0,
+                        <::configure_me::helpers::Int<i32> as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("--point"))?).map_err(ArgParseError::FieldPoint)?.0,
+                    ];
+
+                    self.point = Some(point);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--files", &arg, &mut iter) {
+                    let mut files = vec![value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--files"), ArgParseError::FieldFiles))?];
+                    while files.len() < 3 {
+                        if iter.peek().map_or(true, |next| next.to_str().map_or(false, |next| next.starts_with('-'))) {
+                            break;
+                        }
+                        files.push(::configure_me::parse_arg::ParseArg::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("--files"))?).map_err(ArgParseError::FieldFiles)?);
+                    }
+
+                    self.files = Some(files);
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
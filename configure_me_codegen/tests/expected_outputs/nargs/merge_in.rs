@@ -0,0 +1,5 @@
+            ::configure_me::helpers::merge(&mut self.point, other.point);
+            ::configure_me::helpers::merge(&mut self.files, other.files);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,9 @@
+                        } else if short == 'p' {
+                            let point = [
+                                shorts.parse_remaining::<::configure_me::helpers::Int<i32>, _>(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-p"), ArgParseError::FieldPoint))?.0,
+                                <::configure_me::helpers::Int<i32> as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(iter.next().ok_or(ArgParseError::MissingArgument("-p"))?).map_err(ArgParseError::FieldPoint)?.0,
+                            ];
+                            self.point = Some(point);
+                            break;
+                        } else if short == 'v' {
+                            self.verbose = Some(true);
@@ -0,0 +1,4 @@
+        point: Option<[i32; 2]>,
+        files: Option<Vec<String>>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
@@ -0,0 +1,8 @@
+            let point = self.point;
+            let files = self.files;
+
+            Ok(super::Config {
+                point: point.map(Into::into),
+                files: files.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+            })
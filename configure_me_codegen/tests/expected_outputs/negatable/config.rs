@@ -0,0 +1,5 @@
+    pub verbose: bool,
+    pub color: bool,
+    pub dry_run: bool,
+    pub debug: u32,
+    pub cache: bool,
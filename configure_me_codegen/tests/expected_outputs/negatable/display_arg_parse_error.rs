@@ -0,0 +1,2 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-v|--verbose] [--color] [--dry-run] [--debug ...] [--no-cache]\n\nArguments:\n        -v, --verbose    Print more information. Turned off by --no-verbose.\n        --color          Turned off by --no-color.", program_name),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1 @@
+            match config.load_once(path.as_ref()) {
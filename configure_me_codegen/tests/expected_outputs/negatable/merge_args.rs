@@ -0,0 +1,33 @@
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
+                } else if arg == *"--no-verbose" {
+                    self.verbose = Some(false);
+                } else if arg == *"--color" {
+                    self.color = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--color", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--color", value))?;
+                    self.color = Some(value);
+                } else if arg == *"--colour" {
+                    self.color = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--colour", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--colour", value))?;
+                    self.color = Some(value);
+                } else if arg == *"--no-color" {
+                    self.color = Some(false);
+                } else if arg == *"--no-colour" {
+                    self.color = Some(false);
+                } else if arg == *"--dry-run" {
+                    self.dry_run = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--dry-run", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--dry-run", value))?;
+                    self.dry_run = Some(value);
+                } else if arg == *"--debug" {
+                    *(self.debug.get_or_insert(0)) += 1;
+                } else if arg == *"--no-cache" {
+                    self.cache = Some(false);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--no-cache", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--no-cache", value))?;
+                    self.cache = Some(!value);
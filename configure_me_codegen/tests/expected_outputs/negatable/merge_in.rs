@@ -0,0 +1,15 @@
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.color.is_some() {
+                self.color = other.color;
+            }
+            if other.dry_run.is_some() {
+                self.dry_run = other.dry_run;
+            }
+            if other.debug.is_some() {
+                self.debug = other.debug;
+            }
+            if other.cache.is_some() {
+                self.cache = other.cache;
+            }
@@ -0,0 +1,10 @@
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        #[serde(alias = "colour")]
+        color: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        dry_run: Option<bool>,
+        debug: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        cache: Option<bool>,
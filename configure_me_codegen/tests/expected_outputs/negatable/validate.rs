@@ -0,0 +1,8 @@
+
+            Ok(super::Config {
+                verbose: self.verbose.unwrap_or(false),
+                color: self.color.unwrap_or(false),
+                dry_run: self.dry_run.unwrap_or(false),
+                debug: self.debug.unwrap_or(0),
+                cache: self.cache.unwrap_or(true),
+            })
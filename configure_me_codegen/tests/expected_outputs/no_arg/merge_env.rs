@@ -1,4 +1 @@
-        if let Some(val) = ::std::env::var_os("TEST_APP_FOO") {
-            let val = ::configure_me::parse_arg::ParseArg::parse_owned_arg(val).map_err(super::EnvParseError::FieldFoo)?;
-            self.foo = Some(val);
-        }
+        ::configure_me::helpers::merge(&mut self.foo, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u32>>("TEST_APP_FOO").map_err(super::EnvParseError::FieldFoo)?.map(|value| value.0));
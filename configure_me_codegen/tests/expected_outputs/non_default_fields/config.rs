@@ -0,0 +1,7 @@
+    pub port: u16,
+    pub timeout: u64,
+    pub peers: [String; 2],
+    pub password: Option<String>,
+    pub verbose: u32,
+    pub debug: bool,
+    pub tls: bool,
@@ -0,0 +1,6 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --port\n\n        --timeout\n\n        --peers\n\n        --password\n\n        --verbose\n\n        --debug\nno-tls\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldTimeout(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u32>>(f, "--timeout", err),
+        ArgParseError::FieldPeers(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--peers", err),
+        ArgParseError::FieldPassword(_) => ::configure_me::helpers::display_secret_arg_error::<String>(f, "--password"),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,13 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.timeout, other.timeout);
+            ::configure_me::helpers::merge(&mut self.peers, other.peers);
+            ::configure_me::helpers::merge(&mut self.password, other.password);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.debug.is_some() {
+                self.debug = other.debug;
+            }
+            if other.tls.is_some() {
+                self.tls = other.tls;
+            }
@@ -0,0 +1,40 @@
+
+/// Param or switch whose value differs from the default
+pub struct NonDefaultField {
+    pub name: &'static str,
+    /// `None` if the param is mandatory or unset by default
+    pub default: Option<String>,
+    /// `None` if the optional param is unset
+    pub value: Option<String>,
+}
+
+impl Config {
+    /// Returns the params and switches whose values differ from the defaults
+    ///
+    /// Mandatory params are always returned, the values of secret params are redacted.
+    pub fn non_default_fields(&self) -> Vec<NonDefaultField> {
+        let mut fields = Vec::new();
+        fields.push(NonDefaultField { name: "port", default: None, value: Some(::configure_me::helpers::format_value(&self.port)) });
+        let default: u64 = { let default: u32 = { 30 }; default.into() };
+        if self.timeout != default {
+            fields.push(NonDefaultField { name: "timeout", default: Some(::configure_me::helpers::format_value(&default)), value: Some(::configure_me::helpers::format_value(&self.timeout)) });
+        }
+        let default: [String; 2] = { let default: [String; 2] = { ["a".to_owned(), "b".to_owned()] }; default.into() };
+        if self.peers != default {
+            fields.push(NonDefaultField { name: "peers", default: Some(::configure_me::helpers::format_values(&default)), value: Some(::configure_me::helpers::format_values(&self.peers)) });
+        }
+        if self.password.is_some() {
+            fields.push(NonDefaultField { name: "password", default: None, value: Some(::configure_me::helpers::REDACTED.to_owned()) });
+        }
+        if self.verbose != 0 {
+            fields.push(NonDefaultField { name: "verbose", default: Some("0".to_owned()), value: Some(self.verbose.to_string()) });
+        }
+        if self.debug {
+            fields.push(NonDefaultField { name: "debug", default: Some("false".to_owned()), value: Some(self.debug.to_string()) });
+        }
+        if !self.tls {
+            fields.push(NonDefaultField { name: "tls", default: Some("true".to_owned()), value: Some(self.tls.to_string()) });
+        }
+        fields
+    }
+}
@@ -0,0 +1,10 @@
+        port: Option<u16>,
+        timeout: Option<u32>,
+        peers: Option<[String; 2]>,
+        #[serde(default, deserialize_with = "secret_password")]
+        password: Option<String>,
+        verbose: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        debug: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        tls: Option<bool>,
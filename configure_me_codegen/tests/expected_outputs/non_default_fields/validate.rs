@@ -0,0 +1,23 @@
+            let port = self.port;
+            let timeout = self.timeout.unwrap_or_else(|| { 30 });
+            let peers = self.peers.unwrap_or_else(|| { ["a".to_owned(), "b".to_owned()] });
+            let password = self.password;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                timeout: timeout.into(),
+                peers: peers.into(),
+                password: password.map(Into::into),
+                verbose: self.verbose.unwrap_or(0),
+                debug: self.debug.unwrap_or(false),
+                tls: self.tls.unwrap_or(true),
+            })
@@ -0,0 +1,2 @@
+    FieldPath(<::std::path::PathBuf as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldRaw(<::std::ffi::OsString as ::configure_me::parse_arg::ParseArg>::Error),
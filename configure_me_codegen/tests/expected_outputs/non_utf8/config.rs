@@ -0,0 +1,2 @@
+    pub path: Option<::std::path::PathBuf>,
+    pub raw: Option<::std::ffi::OsString>,
@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [-p PATH|--path PATH] [--raw RAW]\n\nArguments:\n        --config    Load configuration from this file.", program_name),
+        ArgParseError::FieldPath(err) => ::configure_me::helpers::display_arg_error::<::std::path::PathBuf>(f, "--path", err),
+        ArgParseError::FieldRaw(err) => ::configure_me::helpers::display_arg_error::<::std::ffi::OsString>(f, "--raw", err),
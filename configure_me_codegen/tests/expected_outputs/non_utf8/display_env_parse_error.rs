@@ -0,0 +1,2 @@
+        EnvParseError::FieldPath(ref err) => ::configure_me::helpers::display_env_error::<::std::path::PathBuf>(f, "NON_UTF8_TEST_PATH", err),
+        EnvParseError::FieldRaw(ref err) => ::configure_me::helpers::display_env_error::<::std::ffi::OsString>(f, "NON_UTF8_TEST_RAW", err),
@@ -0,0 +1,17 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--path", &arg, &mut iter) {
+                    let path = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--path"), ArgParseError::FieldPath))?;
+
+                    self.path = Some(path);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--raw", &arg, &mut iter) {
+                    let raw = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--raw"), ArgParseError::FieldRaw))?;
+
+                    self.raw = Some(raw);
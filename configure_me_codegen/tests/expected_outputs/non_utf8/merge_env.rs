@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.path, ::configure_me::helpers::parse_env("NON_UTF8_TEST_PATH").map_err(super::EnvParseError::FieldPath)?);
+        ::configure_me::helpers::merge(&mut self.raw, ::configure_me::helpers::parse_env("NON_UTF8_TEST_RAW").map_err(super::EnvParseError::FieldRaw)?);
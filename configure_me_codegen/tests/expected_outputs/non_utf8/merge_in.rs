@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge(&mut self.path, other.path);
+            ::configure_me::helpers::merge(&mut self.raw, other.raw);
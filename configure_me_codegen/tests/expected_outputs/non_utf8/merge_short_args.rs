@@ -0,0 +1,3 @@
+                        } else if short == 'p' {
+                            self.path = Some(shorts.parse_remaining(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-p"), ArgParseError::FieldPath))?);
+                            break;
@@ -0,0 +1,3 @@
+        path: Option<::std::path::PathBuf>,
+        #[serde(default, deserialize_with = "::configure_me::helpers::deserialize_os_string")]
+        raw: Option<::std::ffi::OsString>,
@@ -0,0 +1,7 @@
+            let path = self.path;
+            let raw = self.raw;
+
+            Ok(super::Config {
+                path: path.map(Into::into),
+                raw: raw.map(Into::into),
+            })
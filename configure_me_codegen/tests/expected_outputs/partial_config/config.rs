@@ -0,0 +1,4 @@
+    pub port: u16,
+    pub host: String,
+    pub verbose: bool,
+    pub input: Option<String>,
@@ -0,0 +1,3 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "PARTIAL_CONFIG_PORT", err),
+        EnvParseError::FieldHost(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "PARTIAL_CONFIG_HOST", err),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "PARTIAL_CONFIG_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
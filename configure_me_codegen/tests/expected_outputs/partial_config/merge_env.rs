@@ -0,0 +1,5 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("PARTIAL_CONFIG_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env("PARTIAL_CONFIG_HOST").map_err(super::EnvParseError::FieldHost)?);
+        if let Some(val) = ::std::env::var_os("PARTIAL_CONFIG_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
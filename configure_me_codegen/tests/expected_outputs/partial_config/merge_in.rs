@@ -0,0 +1,6 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.host, other.host);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            ::configure_me::helpers::merge(&mut self.input, other.input);
@@ -0,0 +1,48 @@
+
+/// Configuration that isn't validated yet
+///
+/// Layers loaded from different sources are merged in the order of their priority and then
+/// finalized, which applies the defaults and validates the result.
+#[derive(Default)]
+pub struct PartialConfig(raw::Config);
+
+impl PartialConfig {
+    /// Loads the config file, which has to exist
+    pub fn from_file<P: AsRef<::std::path::Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let mut config = raw::Config::default();
+        let loaded = config.load_once(path);
+        loaded.map(PartialConfig)
+    }
+
+    /// Parses `content` in the format of config files, `name` identifies the source in errors
+    pub fn from_toml_str(name: &str, content: &str) -> Result<Self, Error> {
+        raw::Config::default().parse(name.as_ref(), content.as_bytes()).map(PartialConfig)
+    }
+
+    /// Loads the values of environment variables
+    pub fn from_env() -> Result<Self, Error> {
+        let mut config = raw::Config::default();
+        config.merge_env()?;
+        Ok(PartialConfig(config))
+    }
+
+    /// Parses the arguments, starting with the program name, and returns the ones not consumed
+    pub fn from_args<A>(args: A) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where A: IntoIterator, A::Item: Into<::std::ffi::OsString> {
+        let mut config = raw::Config::default();
+        let remaining_args = config.merge_args(args.into_iter().map(Into::into))?;
+        let remaining_args = config.merge_positionals(remaining_args)?;
+        Ok((PartialConfig(config), remaining_args))
+    }
+
+    /// Merges the layers, the values of `other` take precedence
+    pub fn merge(mut self, other: Self) -> Self {
+        self.0.merge_in(other.0);
+        self
+    }
+
+    /// Applies the defaults and validates the configuration
+    pub fn try_finalize(self) -> Result<Config, Error> {
+        self.0.validate().map_err(Into::into)
+    }
+}
@@ -0,0 +1,16 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    impl Config {
+        pub fn merge_positionals<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<I, super::Error> {
+            if let Some(arg) = args.next() {
+                self.input = Some(::configure_me::parse_arg::ParseArg::parse_owned_arg(arg).map_err(ArgParseError::FieldInput)?);
+            }
+            Ok(args)
+        }
+    }
@@ -0,0 +1,19 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let host = self.host;
+            let input = self.input;
+
+            let mut missing_fields = Vec::new();
+            if host.is_none() {
+                missing_fields.push(super::MissingField { name: "host", sources: &["--host", "PARTIAL_CONFIG_HOST"] });
+            }
+            let host = match host {
+                Some(host) => host,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                host: host.into(),
+                verbose: self.verbose.unwrap_or(false),
+                input,
+            })
@@ -0,0 +1,3 @@
+    pub slug: String,
+    pub branch: String,
+    pub tag: Option<Vec<String>>,
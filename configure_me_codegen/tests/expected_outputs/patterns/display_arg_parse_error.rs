@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--slug SLUG] [--branch BRANCH] [--tag TAG ...]\n\nArguments:\n        --slug    Identifier used in URLs", program_name),
+        ArgParseError::FieldSlug(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--slug", err),
+        ArgParseError::FieldBranch(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--branch", err),
+        ArgParseError::FieldTag(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tag", err),
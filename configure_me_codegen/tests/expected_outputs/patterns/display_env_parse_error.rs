@@ -0,0 +1,3 @@
+        EnvParseError::FieldSlug(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "PATTERNS_SLUG", err),
+        EnvParseError::FieldBranch(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "PATTERNS_BRANCH", err),
+        EnvParseError::FieldTag(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "PATTERNS_TAG", err),
@@ -0,0 +1,3 @@
+    FieldSlug(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldBranch(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTag(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,12 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--slug", &arg, &mut iter) {
+                    let slug = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--slug"), ArgParseError::FieldSlug))?;
+
+                    self.slug = Some(slug);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--branch", &arg, &mut iter) {
+                    let branch = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--branch"), ArgParseError::FieldBranch))?;
+
+                    self.branch = Some(branch);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tag", &arg, &mut iter) {
+                    let tag = vec![value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tag"), ArgParseError::FieldTag))?];
+
+                    ::configure_me::helpers::merge_with(&mut self.tag, Some(tag), ::configure_me::helpers::append_values);
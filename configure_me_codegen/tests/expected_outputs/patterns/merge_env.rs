@@ -0,0 +1,3 @@
+        ::configure_me::helpers::merge(&mut self.slug, ::configure_me::helpers::parse_env("PATTERNS_SLUG").map_err(super::EnvParseError::FieldSlug)?);
+        ::configure_me::helpers::merge(&mut self.branch, ::configure_me::helpers::parse_env("PATTERNS_BRANCH").map_err(super::EnvParseError::FieldBranch)?);
+        ::configure_me::helpers::merge_with(&mut self.tag, ::configure_me::helpers::parse_env::<::configure_me::helpers::Delimited<String, ','>>("PATTERNS_TAG").map_err(super::EnvParseError::FieldTag)?.map(|values| values.0), ::configure_me::helpers::append_values);
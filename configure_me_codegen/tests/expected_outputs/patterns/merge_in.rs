@@ -0,0 +1,3 @@
+            ::configure_me::helpers::merge(&mut self.slug, other.slug);
+            ::configure_me::helpers::merge(&mut self.branch, other.branch);
+            ::configure_me::helpers::merge_with(&mut self.tag, other.tag, ::configure_me::helpers::append_values);
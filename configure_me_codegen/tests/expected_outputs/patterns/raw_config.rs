@@ -0,0 +1,3 @@
+        slug: Option<String>,
+        branch: Option<String>,
+        tag: Option<Vec<String>>,
@@ -0,0 +1,33 @@
+            let slug = self.slug;
+            let branch = self.branch.unwrap_or_else(|| { "main".to_owned() });
+            let tag = self.tag;
+
+            let mut missing_fields = Vec::new();
+            if slug.is_none() {
+                missing_fields.push(super::MissingField { name: "slug", sources: &["--slug", "PATTERNS_SLUG"] });
+            }
+            let slug = match slug {
+                Some(slug) => slug,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            static SLUG_PATTERN: ::configure_me::helpers::Pattern = ::configure_me::helpers::Pattern::new("^[a-z0-9-]+$");
+            if let Some(value) = ::std::iter::once(&slug).find(|value| !SLUG_PATTERN.is_match(value)) {
+                return Err(ValidationError::PatternMismatch { name: "slug", value: value.clone(), pattern: SLUG_PATTERN.as_str() });
+            }
+
+            static BRANCH_PATTERN: ::configure_me::helpers::Pattern = ::configure_me::helpers::Pattern::new("^[a-z]+(/[a-z]+)*$");
+            if let Some(value) = ::std::iter::once(&branch).find(|value| !BRANCH_PATTERN.is_match(value)) {
+                return Err(ValidationError::PatternMismatch { name: "branch", value: value.clone(), pattern: BRANCH_PATTERN.as_str() });
+            }
+
+            static TAG_PATTERN: ::configure_me::helpers::Pattern = ::configure_me::helpers::Pattern::new("^v[0-9]+$");
+            if let Some(value) = tag.iter().flatten().find(|value| !TAG_PATTERN.is_match(value)) {
+                return Err(ValidationError::PatternMismatch { name: "tag", value: value.clone(), pattern: TAG_PATTERN.as_str() });
+            }
+
+            Ok(super::Config {
+                slug: slug.into(),
+                branch: branch.into(),
+                tag: tag.map(Into::into),
+            })
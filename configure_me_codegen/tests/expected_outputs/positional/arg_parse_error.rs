@@ -0,0 +1,5 @@
+    FieldLevel(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldInput(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldCount(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFiles(<::std::path::PathBuf as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
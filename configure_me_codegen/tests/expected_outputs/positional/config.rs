@@ -0,0 +1,5 @@
+    pub level: u32,
+    pub verbose: bool,
+    pub input: String,
+    pub count: Option<u32>,
+    pub files: Vec<::std::path::PathBuf>,
@@ -0,0 +1,6 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--level LEVEL] [-v|--verbose] INPUT [COUNT] [FILES...]\n\nArguments:\n        --level    Compression level\n\nPositional arguments:\n        INPUT      Name of the archive\n        COUNT      Maximum number of files to add", program_name),
+        ArgParseError::FieldLevel(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u32>>(f, "--level", err),
+        ArgParseError::FieldInput(err) => ::configure_me::helpers::display_arg_error::<String>(f, "INPUT", err),
+        ArgParseError::FieldCount(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u32>>(f, "COUNT", err),
+        ArgParseError::FieldFiles(err) => ::configure_me::helpers::display_arg_error::<::std::path::PathBuf>(f, "FILES", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
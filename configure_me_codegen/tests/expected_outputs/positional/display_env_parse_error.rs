@@ -0,0 +1,2 @@
+        EnvParseError::FieldLevel(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u32>>(f, "POSITIONAL_LEVEL", err),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "POSITIONAL_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
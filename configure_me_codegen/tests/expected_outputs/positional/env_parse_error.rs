@@ -0,0 +1,2 @@
+    FieldLevel(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
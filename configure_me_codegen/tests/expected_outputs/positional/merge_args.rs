@@ -0,0 +1,9 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--level", &arg, &mut iter) {
+                    let level = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--level"), ArgParseError::FieldLevel))?.0;
+
+                    self.level = Some(level);
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
@@ -0,0 +1,4 @@
+        ::configure_me::helpers::merge(&mut self.level, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u32>>("POSITIONAL_LEVEL").map_err(super::EnvParseError::FieldLevel)?.map(|value| value.0));
+        if let Some(val) = ::std::env::var_os("POSITIONAL_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
@@ -0,0 +1,4 @@
+            ::configure_me::helpers::merge(&mut self.level, other.level);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,9 @@
+        level: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        #[serde(skip)]
+        input: Option<String>,
+        #[serde(skip)]
+        count: Option<u32>,
+        #[serde(skip)]
+        files: Option<Vec<::std::path::PathBuf>>,
@@ -0,0 +1,21 @@
+            let level = self.level.unwrap_or_else(|| { 1 });
+            let input = self.input;
+            let count = self.count;
+            let files = self.files;
+
+            let mut missing_fields = Vec::new();
+            if input.is_none() {
+                missing_fields.push(super::MissingField { name: "input", sources: &["INPUT"] });
+            }
+            let input = match input {
+                Some(input) => input,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                level: level.into(),
+                verbose: self.verbose.unwrap_or(false),
+                input,
+                count,
+                files: files.unwrap_or_default(),
+            })
@@ -0,0 +1,2 @@
+    pub port: Option<u16>,
+    pub host: Option<String>,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [--profile PROFILE] [--port PORT] [--host HOST]\n\nArguments:\n        --config     Load configuration from this file.\n        --profile    Use this profile of config files.", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--host", err),
+        ArgParseError::AmbiguousArgument(arg, candidates) => write!(f, "The argument '{}' is ambiguous, it may mean: {}.", arg, candidates.join(", ")),
@@ -0,0 +1,3 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.host, other.host);
+            self._profile = other._profile.or(self._profile.take());
@@ -0,0 +1,2 @@
+            let config_content = ::configure_me::helpers::select_profile(config_content, self._profile.as_deref()).map_err(|error| super::Error::ConfigParsing { file: config_file_name.into(), error })?;
+            ::configure_me::helpers::parse_table(config_content, &[])
@@ -0,0 +1,2 @@
+        let args = args.into_iter().map(Into::into).collect::<Vec<::std::ffi::OsString>>();
+        config._profile = ::configure_me::helpers::selected_profile(&::configure_me::helpers::normalize_long_options(&args, raw::normalize_long_option), "--profile", raw::VALUED_OPTIONS, ::std::env::var_os("PROFILES_PROFILE"));
@@ -0,0 +1,4 @@
+        port: Option<u16>,
+        host: Option<String>,
+        #[serde(skip)]
+        pub(super) _profile: Option<String>,
@@ -0,0 +1,35 @@
+
+    pub const VALUED_OPTIONS: &[(&str, usize, usize)] = &[("--config", 1, 1), ("--profile", 1, 1), ("--port", 1, 1), ("--host", 1, 1)];
+
+    const LONG_OPTIONS: &[&str] = &["--help", "--config", "--profile", "--port", "--host"];
+
+    pub fn normalize_long_option(arg: ::std::ffi::OsString) -> Result<::std::ffi::OsString, ArgParseError> {
+        let (name, value) = match arg.to_str() {
+            Some(arg) if arg.starts_with("--") && arg.len() > 2 => match arg.find('=') {
+                Some(pos) => (arg[..pos].to_owned(), arg[pos..].to_owned()),
+                None => (arg.to_owned(), String::new()),
+            },
+            _ => return Ok(arg),
+        };
+        let name = name.to_ascii_lowercase();
+
+        if LONG_OPTIONS.contains(&&*name) {
+            return Ok(format!("{}{}", name, value).into());
+        }
+
+        let candidates = LONG_OPTIONS.iter().cloned().filter(|option| option.starts_with(&*name)).collect::<Vec<_>>();
+        match candidates.len() {
+            0 => (),
+            1 => return Ok(format!("{}{}", candidates[0], value).into()),
+            _ => return Err(ArgParseError::AmbiguousArgument(name, candidates)),
+        }
+        Ok(arg)
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
@@ -0,0 +1,3 @@
+    pub port: u16,
+    pub ratio: Option<f64>,
+    pub retries: u8,
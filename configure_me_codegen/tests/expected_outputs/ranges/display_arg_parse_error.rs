@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--ratio RATIO] [--retries RETRIES]\n\nArguments:\n        --port    Port to listen on", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldRatio(err) => ::configure_me::helpers::display_arg_error::<f64>(f, "--ratio", err),
+        ArgParseError::FieldRetries(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u8>>(f, "--retries", err),
@@ -0,0 +1,3 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "RANGES_PORT", err),
+        EnvParseError::FieldRatio(ref err) => ::configure_me::helpers::display_env_error::<f64>(f, "RANGES_RATIO", err),
+        EnvParseError::FieldRetries(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u8>>(f, "RANGES_RETRIES", err),
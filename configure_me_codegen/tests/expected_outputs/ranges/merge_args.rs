@@ -0,0 +1,12 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--ratio", &arg, &mut iter) {
+                    let ratio = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--ratio"), ArgParseError::FieldRatio))?;
+
+                    self.ratio = Some(ratio);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u8>, _, _>("--retries", &arg, &mut iter) {
+                    let retries = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--retries"), ArgParseError::FieldRetries))?.0;
+
+                    self.retries = Some(retries);
@@ -0,0 +1,3 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("RANGES_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.ratio, ::configure_me::helpers::parse_env("RANGES_RATIO").map_err(super::EnvParseError::FieldRatio)?);
+        ::configure_me::helpers::merge(&mut self.retries, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u8>>("RANGES_RETRIES").map_err(super::EnvParseError::FieldRetries)?.map(|value| value.0));
@@ -0,0 +1,3 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.ratio, other.ratio);
+            ::configure_me::helpers::merge(&mut self.retries, other.retries);
@@ -0,0 +1,3 @@
+        port: Option<u16>,
+        ratio: Option<f64>,
+        retries: Option<u8>,
@@ -0,0 +1,30 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let ratio = self.ratio;
+            let retries = self.retries;
+
+            let mut missing_fields = Vec::new();
+            if retries.is_none() {
+                missing_fields.push(super::MissingField { name: "retries", sources: &["--retries", "RANGES_RETRIES"] });
+            }
+            let retries = match retries {
+                Some(retries) => retries,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            if let Some(value) = Some(port).filter(|&value| value < 1) {
+                return Err(ValidationError::OutOfRange { name: "port", value: value.to_string(), range: "from 1 to 65535" });
+            }
+
+            if let Some(value) = ratio.filter(|&value| value < 0.0 || value > 1.0) {
+                return Err(ValidationError::OutOfRange { name: "ratio", value: value.to_string(), range: "from 0 to 1" });
+            }
+
+            if let Some(value) = Some(retries).filter(|&value| value > 10) {
+                return Err(ValidationError::OutOfRange { name: "retries", value: value.to_string(), range: "at most 10" });
+            }
+
+            Ok(super::Config {
+                port: port.into(),
+                ratio: ratio.map(Into::into),
+                retries: retries.into(),
+            })
@@ -0,0 +1,5 @@
+    pub port: u16,
+    pub password: Option<String>,
+    pub peers: Vec<String>,
+    pub data_dir: Option<::std::ffi::OsString>,
+    pub verbose: bool,
@@ -0,0 +1,7 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.password, other.password);
+            ::configure_me::helpers::merge(&mut self.peers, other.peers);
+            ::configure_me::helpers::merge(&mut self.data_dir, other.data_dir);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,8 @@
+        port: Option<u16>,
+        #[serde(default, deserialize_with = "secret_password")]
+        password: Option<String>,
+        peers: Option<Vec<String>>,
+        #[serde(default, deserialize_with = "::configure_me::helpers::deserialize_os_string")]
+        data_dir: Option<::std::ffi::OsString>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
@@ -0,0 +1,21 @@
+            let port = self.port;
+            let password = self.password;
+            let peers = self.peers.unwrap_or_else(|| { vec!["localhost".to_owned()] });
+            let data_dir = self.data_dir;
+
+            let mut missing_fields = Vec::new();
+            if port.is_none() {
+                missing_fields.push(super::MissingField { name: "port", sources: &["--port"] });
+            }
+            let port = match port {
+                Some(port) => port,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                password: password.map(Into::into),
+                peers: peers.into(),
+                data_dir: data_dir.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+            })
@@ -0,0 +1,4 @@
+    FieldTlsCert(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTlsKey(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldHttpPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,4 @@
+    pub tls_cert: Option<String>,
+    pub tls_key: Option<String>,
+    pub http_port: u16,
+    pub insecure: bool,
@@ -0,0 +1,5 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --tls-cert     Certificate to serve\n        --tls-key\n\n        --http-port\n\n        --insecure\n", program_name),
+        ArgParseError::FieldTlsCert(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tls-cert", err),
+        ArgParseError::FieldTlsKey(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tls-key", err),
+        ArgParseError::FieldHttpPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--http-port", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
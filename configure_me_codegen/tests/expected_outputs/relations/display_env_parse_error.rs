@@ -0,0 +1,4 @@
+        EnvParseError::FieldTlsCert(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "RELATIONS_TLS_CERT", err),
+        EnvParseError::FieldTlsKey(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "RELATIONS_TLS_KEY", err),
+        EnvParseError::FieldHttpPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "RELATIONS_HTTP_PORT", err),
+        EnvParseError::FieldInsecure(ref err) => ::configure_me::helpers::display_switch_error(f, "RELATIONS_INSECURE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,22 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tls-cert", &arg, &mut iter) {
+                    let tls_cert = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tls-cert"), ArgParseError::FieldTlsCert))?;
+
+                    self._sources.set("tls_cert", ::configure_me::helpers::Source::Argument("--tls-cert"));
+                    self.tls_cert = Some(tls_cert);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tls-key", &arg, &mut iter) {
+                    let tls_key = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tls-key"), ArgParseError::FieldTlsKey))?;
+
+                    self._sources.set("tls_key", ::configure_me::helpers::Source::Argument("--tls-key"));
+                    self.tls_key = Some(tls_key);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--http-port", &arg, &mut iter) {
+                    let http_port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--http-port"), ArgParseError::FieldHttpPort))?.0;
+
+                    self._sources.set("http_port", ::configure_me::helpers::Source::Argument("--http-port"));
+                    self.http_port = Some(http_port);
+                } else if arg == *"--insecure" {
+                    self._sources.set("insecure", ::configure_me::helpers::Source::Argument("--insecure"));
+                    self.insecure = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--insecure", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--insecure", value))?;
+                    self._sources.set("insecure", ::configure_me::helpers::Source::Argument("--insecure"));
+                    self.insecure = Some(value);
@@ -0,0 +1,7 @@
+        ::configure_me::helpers::merge(&mut self.tls_cert, self._sources.track("tls_cert", ::configure_me::helpers::parse_env("RELATIONS_TLS_CERT").map_err(super::EnvParseError::FieldTlsCert)?, ::configure_me::helpers::Source::EnvVar("RELATIONS_TLS_CERT")));
+        ::configure_me::helpers::merge(&mut self.tls_key, self._sources.track("tls_key", ::configure_me::helpers::parse_env("RELATIONS_TLS_KEY").map_err(super::EnvParseError::FieldTlsKey)?, ::configure_me::helpers::Source::EnvVar("RELATIONS_TLS_KEY")));
+        ::configure_me::helpers::merge(&mut self.http_port, self._sources.track("http_port", ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("RELATIONS_HTTP_PORT").map_err(super::EnvParseError::FieldHttpPort)?.map(|value| value.0), ::configure_me::helpers::Source::EnvVar("RELATIONS_HTTP_PORT")));
+        if let Some(val) = ::std::env::var_os("RELATIONS_INSECURE") {
+            self.insecure = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldInsecure)?);
+            self._sources.set("insecure", ::configure_me::helpers::Source::EnvVar("RELATIONS_INSECURE"));
+        }
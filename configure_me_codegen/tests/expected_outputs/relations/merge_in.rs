@@ -0,0 +1,7 @@
+            ::configure_me::helpers::merge(&mut self.tls_cert, other.tls_cert);
+            ::configure_me::helpers::merge(&mut self.tls_key, other.tls_key);
+            ::configure_me::helpers::merge(&mut self.http_port, other.http_port);
+            if other.insecure.is_some() {
+                self.insecure = other.insecure;
+            }
+            self._sources.merge_in(other._sources);
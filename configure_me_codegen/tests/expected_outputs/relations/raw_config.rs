@@ -0,0 +1,7 @@
+        tls_cert: Option<String>,
+        tls_key: Option<String>,
+        http_port: Option<u16>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        insecure: Option<bool>,
+        #[serde(skip)]
+        _sources: ::configure_me::helpers::Sources,
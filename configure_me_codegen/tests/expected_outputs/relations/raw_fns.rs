@@ -0,0 +1,23 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    fn track_file(mut config: Config, file: &::std::path::Path) -> Config {
+        if config.tls_cert.is_some() {
+            config._sources.set("tls_cert", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.tls_key.is_some() {
+            config._sources.set("tls_key", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.http_port.is_some() {
+            config._sources.set("http_port", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.insecure.is_some() {
+            config._sources.set("insecure", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        config
+    }
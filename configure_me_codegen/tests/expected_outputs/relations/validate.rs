@@ -0,0 +1,20 @@
+            if self.tls_cert.is_some() && self.tls_key.is_none() {
+                return Err(ValidationError::MissingRequirement { name: "tls_cert", sources: self._sources.of("tls_cert").into_iter().cloned().collect(), required: "tls_key" });
+            }
+            if self.tls_cert.is_some() && self.insecure.unwrap_or(false) {
+                return Err(ValidationError::Conflict { name: "tls_cert", sources: self._sources.of("tls_cert").into_iter().cloned().collect(), other: "insecure", other_sources: self._sources.of("insecure").into_iter().cloned().collect() });
+            }
+            if self.http_port.is_some() && !self.insecure.unwrap_or(false) {
+                return Err(ValidationError::MissingRequirement { name: "http_port", sources: self._sources.of("http_port").into_iter().cloned().collect(), required: "insecure" });
+            }
+
+            let tls_cert = self.tls_cert;
+            let tls_key = self.tls_key;
+            let http_port = self.http_port.unwrap_or_else(|| { 80 });
+
+            Ok(super::Config {
+                tls_cert: tls_cert.map(Into::into),
+                tls_key: tls_key.map(Into::into),
+                http_port: http_port.into(),
+                insecure: self.insecure.unwrap_or(false),
+            })
@@ -0,0 +1,2 @@
+    pub port: u16,
+    _poll_state: ::configure_me::helpers::PollState,
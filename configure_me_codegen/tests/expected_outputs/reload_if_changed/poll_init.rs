@@ -0,0 +1,5 @@
+        let args = args.into_iter().map(Into::into).collect::<Vec<::std::ffi::OsString>>();
+        let watched_args = args.clone();
+        let mut watched_files = Vec::new();
+        let config_files = config_files.into_iter().inspect(|path| watched_files.push(path.as_ref().to_owned()));
+
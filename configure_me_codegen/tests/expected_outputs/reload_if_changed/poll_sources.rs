@@ -0,0 +1,4 @@
+            .map(|mut cfg| {
+                cfg._poll_state.set_sources(watched_args, watched_files);
+                cfg
+            })
@@ -0,0 +1,14 @@
+
+impl Config {
+    /// Loads the configuration again if any config file changed since it was loaded
+    ///
+    /// The same arguments and config files are used, environment variables are read again.
+    pub fn reload_if_changed(&self) -> Result<Option<Config>, Error> {
+        if !self._poll_state.changed() {
+            return Ok(None);
+        }
+
+        Self::custom_args_and_optional_files(self._poll_state.args().to_vec(), self._poll_state.optional_files())
+            .map(|(config, _)| Some(config))
+    }
+}
@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("REMOTE_CONFIG_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.host, ::configure_me::helpers::parse_env("REMOTE_CONFIG_HOST").map_err(super::EnvParseError::FieldHost)?);
@@ -0,0 +1,2 @@
+        port: Option<u16>,
+        host: Option<String>,
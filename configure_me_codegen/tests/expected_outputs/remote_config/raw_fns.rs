@@ -0,0 +1,16 @@
+
+    const CONFIG_FILE_CHECKS: ::configure_me::helpers::ConfigFileChecks = ::configure_me::helpers::ConfigFileChecks {
+        insecure: None,
+        max_size: Some(64),
+        max_string_length: None,
+        max_depth: None,
+        mmap_threshold: None,
+    };
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), CONFIG_FILE_CHECKS).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
@@ -0,0 +1 @@
+            let config_content = ::configure_me::helpers::read_config_source(&config_file_name, CONFIG_FILE_CHECKS, ::std::time::Duration::from_secs(1), true).map_err(|error| super::Error::Reading { file: config_file_name.clone(), error })?;
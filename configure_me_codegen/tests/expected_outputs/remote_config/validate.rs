@@ -0,0 +1,7 @@
+            let port = self.port;
+            let host = self.host;
+
+            Ok(super::Config {
+                port: port.map(Into::into),
+                host: host.map(Into::into),
+            })
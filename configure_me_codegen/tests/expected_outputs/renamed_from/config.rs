@@ -0,0 +1,3 @@
+    pub port: u16,
+    pub data_dir: Option<::std::path::PathBuf>,
+    _overrides: Vec<::configure_me::helpers::Override>,
@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [--explain-config] [--port PORT] [--data-dir DATA_DIR]\n\nArguments:\n        --config            Load configuration from this file.\n        --explain-config    Explain which source overrode each value.", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldDataDir(err) => ::configure_me::helpers::display_arg_error::<::std::path::PathBuf>(f, "--data-dir", err),
@@ -0,0 +1,3 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "RENAMED_PORT", err),
+        EnvParseError::FieldDataDir(ref err) => ::configure_me::helpers::display_env_error::<::std::path::PathBuf>(f, "RENAMED_DATA_DIR", err),
+        EnvParseError::Empty(name) => write!(f, "The environment variable '{}' is set but empty.", name),
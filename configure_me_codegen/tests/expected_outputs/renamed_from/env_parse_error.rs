@@ -0,0 +1,3 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDataDir(<::std::path::PathBuf as ::configure_me::parse_arg::ParseArg>::Error),
+    Empty(&'static str),
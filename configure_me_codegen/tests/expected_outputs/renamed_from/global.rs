@@ -0,0 +1,9 @@
+
+impl Config {
+    /// Values overridden by a source with a higher priority, empty unless `--explain-config` was passed
+    ///
+    /// Params with `merge_fn` and count switches combine their values, so they aren't included.
+    pub fn overrides(&self) -> &[::configure_me::helpers::Override] {
+        &self._overrides
+    }
+}
@@ -0,0 +1,21 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if arg == *"--explain-config" {
+                    self._sources.explain = true;
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self._sources.set("port", ::configure_me::helpers::Source::Argument("--port"));
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--data-dir", &arg, &mut iter) {
+                    let data_dir = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--data-dir"), ArgParseError::FieldDataDir))?;
+
+                    self._sources.set("data_dir", ::configure_me::helpers::Source::Argument("--data-dir"));
+                    self.data_dir = Some(data_dir);
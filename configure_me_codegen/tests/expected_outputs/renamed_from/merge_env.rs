@@ -0,0 +1,4 @@
+        let port_env_var = ::configure_me::helpers::renamed_env_var("RENAMED_PORT", &["RENAMED_LISTEN_PORT", "RENAMED_BIND_PORT"], |name| ::std::env::var_os(name));
+        ::configure_me::helpers::merge(&mut self.port, self._sources.track("port", ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>(port_env_var).map_err(super::EnvParseError::FieldPort)?.map(|value| value.0), ::configure_me::helpers::Source::EnvVar(port_env_var)));
+        let data_dir_env_var = ::configure_me::helpers::renamed_env_var("RENAMED_DATA_DIR", &["RENAMED_DATA_PATH"], |name| ::std::env::var_os(name));
+        ::configure_me::helpers::merge(&mut self.data_dir, self._sources.track("data_dir", ::configure_me::helpers::parse_env_reject_empty(data_dir_env_var).map_err(|err| err.map_or(super::EnvParseError::Empty(data_dir_env_var), super::EnvParseError::FieldDataDir))?, ::configure_me::helpers::Source::EnvVar(data_dir_env_var)));
@@ -0,0 +1,3 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.data_dir, other.data_dir);
+            self._sources.merge_in(other._sources);
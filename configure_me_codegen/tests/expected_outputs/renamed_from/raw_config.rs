@@ -0,0 +1,10 @@
+        port: Option<u16>,
+        #[serde(rename = "listen_port")]
+        _renamed_listen_port: Option<u16>,
+        #[serde(rename = "bind_port")]
+        _renamed_bind_port: Option<u16>,
+        data_dir: Option<::std::path::PathBuf>,
+        #[serde(rename = "data_path")]
+        _renamed_data_path: Option<::std::path::PathBuf>,
+        #[serde(skip)]
+        _sources: ::configure_me::helpers::Sources,
@@ -0,0 +1,25 @@
+
+    fn rename_keys(mut config: Config, file: &::std::path::Path) -> Config {
+        ::configure_me::helpers::rename_key(&mut config.port, config._renamed_listen_port.take(), "listen_port", "port", file);
+        ::configure_me::helpers::rename_key(&mut config.port, config._renamed_bind_port.take(), "bind_port", "port", file);
+        ::configure_me::helpers::rename_key(&mut config.data_dir, config._renamed_data_path.take(), "data_path", "data_dir", file);
+        config
+    }
+
+    fn track_file(mut config: Config, file: &::std::path::Path) -> Config {
+        if config.port.is_some() {
+            config._sources.set("port", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        if config.data_dir.is_some() {
+            config._sources.set("data_dir", ::configure_me::helpers::Source::File(file.to_owned()));
+        }
+        config
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
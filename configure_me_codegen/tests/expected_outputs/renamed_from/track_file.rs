@@ -0,0 +1 @@
+                .map(|config| track_file(config, config_file_name))
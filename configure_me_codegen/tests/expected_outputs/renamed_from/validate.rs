@@ -0,0 +1,8 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let data_dir = self.data_dir;
+
+            Ok(super::Config {
+                port: port.into(),
+                data_dir: data_dir.map(Into::into),
+                _overrides: self._sources.overrides(),
+            })
@@ -0,0 +1,5 @@
+    pub port: u16,
+    pub bind_addr: String,
+    pub token: String,
+    pub verbose: bool,
+    pub root: Option<::std::path::PathBuf>,
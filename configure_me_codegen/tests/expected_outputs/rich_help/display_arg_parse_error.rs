@@ -0,0 +1,17 @@
+        ArgParseError::HelpRequested(program_name) => ::configure_me::helpers::display_help(f, program_name, None, " [-p PORT|--port PORT] [--bind-addr BIND_ADDR] [--token TOKEN] [-v|--verbose] [ROOT]", &[
+            ::configure_me::helpers::HelpSection { heading: "Arguments", entries: &[
+                ::configure_me::helpers::HelpEntry { names: "-p, --port PORT", doc: "Port to listen on", default: Some("8080"), env_var: Some("APP_PORT") },
+                ::configure_me::helpers::HelpEntry { names: "--bind-addr BIND_ADDR", doc: "Address the server binds to, use 127.0.0.1 to accept only local connections", default: Some("\"0.0.0.0\""), env_var: Some("APP_BIND_ADDR") },
+                ::configure_me::helpers::HelpEntry { names: "--token TOKEN", doc: "", default: None, env_var: Some("APP_TOKEN") },
+                ::configure_me::helpers::HelpEntry { names: "-v, --verbose", doc: "Print more information", default: None, env_var: Some("APP_VERBOSE") },
+                ::configure_me::helpers::HelpEntry { names: "-h, --help", doc: "Print this help and exit.", default: None, env_var: None },
+            ] },
+            ::configure_me::helpers::HelpSection { heading: "Positional arguments", entries: &[
+                ::configure_me::helpers::HelpEntry { names: "ROOT", doc: "Directory to serve", default: None, env_var: None },
+            ] },
+        ], Some("Integers may also be written in hexadecimal (0x1F), octal (0o755) or binary (0b1010) notation.")),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::NotatedInt<u16>>(f, "--port", err),
+        ArgParseError::FieldBindAddr(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--bind-addr", err),
+        ArgParseError::FieldToken(_) => ::configure_me::helpers::display_secret_arg_error::<String>(f, "--token"),
+        ArgParseError::FieldRoot(err) => ::configure_me::helpers::display_arg_error::<::std::path::PathBuf>(f, "ROOT", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,5 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::NotatedInt<u16>>(f, "APP_PORT", err),
+        EnvParseError::FieldBindAddr(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "APP_BIND_ADDR", err),
+        EnvParseError::FieldToken(_) => ::configure_me::helpers::display_secret_env_error::<String>(f, "APP_TOKEN"),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "APP_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::SecretFile { name, ref file, ref error } => write!(f, "Failed to read the file {} named by the environment variable '{}_FILE': {}", file.display(), name, error),
@@ -0,0 +1,6 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("APP_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.bind_addr, ::configure_me::helpers::parse_env("APP_BIND_ADDR").map_err(super::EnvParseError::FieldBindAddr)?);
+        ::configure_me::helpers::merge(&mut self.token, ::configure_me::helpers::parse_env_value(::configure_me::helpers::secret_env_var("APP_TOKEN", |name| ::std::env::var_os(name)).map_err(|(file, error)| super::EnvParseError::SecretFile { name: "APP_TOKEN", file, error })?).map_err(super::EnvParseError::FieldToken)?);
+        if let Some(val) = ::std::env::var_os("APP_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
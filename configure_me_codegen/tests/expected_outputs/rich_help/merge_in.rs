@@ -0,0 +1,6 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.bind_addr, other.bind_addr);
+            ::configure_me::helpers::merge(&mut self.token, other.token);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
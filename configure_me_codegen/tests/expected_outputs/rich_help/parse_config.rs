@@ -0,0 +1,2 @@
+            ::configure_me::toml::from_slice(config_content)
+                .map_err(|error| super::Error::ConfigParsing { file: config_file_name.into(), error })
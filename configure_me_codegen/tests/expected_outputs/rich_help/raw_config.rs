@@ -0,0 +1,8 @@
+        port: Option<u16>,
+        bind_addr: Option<String>,
+        #[serde(default, deserialize_with = "secret_token")]
+        token: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        #[serde(skip)]
+        root: Option<::std::path::PathBuf>,
@@ -0,0 +1,12 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let bind_addr = self.bind_addr.unwrap_or_else(|| { "0.0.0.0".to_owned() });
+            let token = self.token.unwrap_or_else(|| { "changeme".to_owned() });
+            let root = self.root;
+
+            Ok(super::Config {
+                port: port.into(),
+                bind_addr: bind_addr.into(),
+                token: token.into(),
+                verbose: self.verbose.unwrap_or(false),
+                root,
+            })
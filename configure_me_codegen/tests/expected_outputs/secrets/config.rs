@@ -0,0 +1,5 @@
+    pub port: u16,
+    pub password: String,
+    pub pin: Option<u32>,
+    pub verbose: bool,
+    pub database: Database,
@@ -0,0 +1,21 @@
+
+impl ::std::fmt::Debug for Config {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("Config")
+            .field("port", &self.port)
+            .field("password", &::configure_me::helpers::Redacted)
+            .field("pin", &self.pin.as_ref().map(|_| ::configure_me::helpers::Redacted))
+            .field("verbose", &self.verbose)
+            .field("database", &self.database)
+            .finish()
+    }
+}
+
+impl ::std::fmt::Debug for Database {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("Database")
+            .field("url", &self.url)
+            .field("token", &self.token.as_ref().map(|_| ::configure_me::helpers::Redacted))
+            .finish()
+    }
+}
@@ -0,0 +1,7 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --config            Load configuration from this file.\n        --port\n\n        --password\n\n        --pin\n\n        --database-url\n\n        --database-token\n\n        --verbose\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldPassword(_) => ::configure_me::helpers::display_secret_arg_error::<String>(f, "--password"),
+        ArgParseError::FieldPin(_) => ::configure_me::helpers::display_secret_arg_error::<::configure_me::helpers::Int<u32>>(f, "--pin"),
+        ArgParseError::FieldDatabaseUrl(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--database-url", err),
+        ArgParseError::FieldDatabaseToken(_) => ::configure_me::helpers::display_secret_arg_error::<String>(f, "--database-token"),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
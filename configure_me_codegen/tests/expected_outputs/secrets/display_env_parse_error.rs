@@ -0,0 +1,7 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "SECRETS_PORT", err),
+        EnvParseError::FieldPassword(_) => ::configure_me::helpers::display_secret_env_error::<String>(f, "SECRETS_PASSWORD"),
+        EnvParseError::FieldPin(_) => ::configure_me::helpers::display_secret_env_error::<::configure_me::helpers::Int<u32>>(f, "SECRETS_PIN"),
+        EnvParseError::FieldDatabaseUrl(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "SECRETS_DATABASE_URL", err),
+        EnvParseError::FieldDatabaseToken(_) => ::configure_me::helpers::display_secret_env_error::<String>(f, "SECRETS_DATABASE_TOKEN"),
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "SECRETS_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::SecretFile { name, ref file, ref error } => write!(f, "Failed to read the file {} named by the environment variable '{}_FILE': {}", file.display(), name, error),
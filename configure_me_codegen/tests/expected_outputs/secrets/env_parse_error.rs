@@ -0,0 +1,7 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPassword(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPin(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDatabaseUrl(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDatabaseToken(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldVerbose(::std::ffi::OsString),
+    SecretFile { name: &'static str, file: ::std::path::PathBuf, error: ::std::io::Error },
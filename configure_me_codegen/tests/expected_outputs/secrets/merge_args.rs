@@ -0,0 +1,34 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password", &arg, &mut iter) {
+                    let password = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password"), ArgParseError::FieldPassword))?;
+
+                    self.password = Some(password);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--pin", &arg, &mut iter) {
+                    let pin = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--pin"), ArgParseError::FieldPin))?.0;
+
+                    self.pin = Some(pin);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--database-url", &arg, &mut iter) {
+                    let database_url = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--database-url"), ArgParseError::FieldDatabaseUrl))?;
+
+                    self.database_url = Some(database_url);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--database-token", &arg, &mut iter) {
+                    let database_token = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--database-token"), ArgParseError::FieldDatabaseToken))?;
+
+                    self.database_token = Some(database_token);
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
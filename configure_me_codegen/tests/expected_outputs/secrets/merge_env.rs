@@ -0,0 +1,8 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("SECRETS_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.password, ::configure_me::helpers::parse_env_value(::configure_me::helpers::secret_env_var("SECRETS_PASSWORD", |name| ::std::env::var_os(name)).map_err(|(file, error)| super::EnvParseError::SecretFile { name: "SECRETS_PASSWORD", file, error })?).map_err(super::EnvParseError::FieldPassword)?);
+        ::configure_me::helpers::merge(&mut self.pin, ::configure_me::helpers::parse_env_value::<::configure_me::helpers::Int<u32>>(::configure_me::helpers::secret_env_var("SECRETS_PIN", |name| ::std::env::var_os(name)).map_err(|(file, error)| super::EnvParseError::SecretFile { name: "SECRETS_PIN", file, error })?).map_err(super::EnvParseError::FieldPin)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.database_url, ::configure_me::helpers::parse_env("SECRETS_DATABASE_URL").map_err(super::EnvParseError::FieldDatabaseUrl)?);
+        ::configure_me::helpers::merge(&mut self.database_token, ::configure_me::helpers::parse_env_value(::configure_me::helpers::secret_env_var("SECRETS_DATABASE_TOKEN", |name| ::std::env::var_os(name)).map_err(|(file, error)| super::EnvParseError::SecretFile { name: "SECRETS_DATABASE_TOKEN", file, error })?).map_err(super::EnvParseError::FieldDatabaseToken)?);
+        if let Some(val) = ::std::env::var_os("SECRETS_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
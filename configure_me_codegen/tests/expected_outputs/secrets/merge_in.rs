@@ -0,0 +1,8 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.password, other.password);
+            ::configure_me::helpers::merge(&mut self.pin, other.pin);
+            ::configure_me::helpers::merge(&mut self.database_url, other.database_url);
+            ::configure_me::helpers::merge(&mut self.database_token, other.database_token);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
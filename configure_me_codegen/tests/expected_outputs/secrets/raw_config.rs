@@ -0,0 +1,10 @@
+        port: Option<u16>,
+        #[serde(default, deserialize_with = "secret_password")]
+        password: Option<String>,
+        #[serde(default, deserialize_with = "secret_pin")]
+        pin: Option<u32>,
+        database_url: Option<String>,
+        #[serde(default, deserialize_with = "secret_database_token")]
+        database_token: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
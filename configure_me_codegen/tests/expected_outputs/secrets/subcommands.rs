@@ -0,0 +1,5 @@
+
+pub struct Database {
+    pub url: String,
+    pub token: Option<String>,
+}
@@ -0,0 +1 @@
+            ::configure_me::helpers::parse_sections(config_content, &[], &[("database", &["url", "token"])])
@@ -0,0 +1,34 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let password = self.password;
+            let pin = self.pin;
+            let database_url = self.database_url.unwrap_or_else(|| { "postgres://localhost".to_owned() });
+            let database_token = self.database_token;
+
+            let mut missing_fields = Vec::new();
+            if password.is_none() {
+                missing_fields.push(super::MissingField { name: "password", sources: &["--password", "SECRETS_PASSWORD"] });
+            }
+            let password = match password {
+                Some(password) => password,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            if pin.filter(|&value| value > 9999).is_some() {
+                return Err(ValidationError::OutOfRange { name: "pin", value: ::configure_me::helpers::REDACTED.to_owned(), range: "at most 9999" });
+            }
+
+            static PASSWORD_PATTERN: ::configure_me::helpers::Pattern = ::configure_me::helpers::Pattern::new("^[^ ]+$");
+            if ::std::iter::once(&password).any(|value| !PASSWORD_PATTERN.is_match(value)) {
+                return Err(ValidationError::PatternMismatch { name: "password", value: ::configure_me::helpers::REDACTED.to_owned(), pattern: PASSWORD_PATTERN.as_str() });
+            }
+
+            Ok(super::Config {
+                port: port.into(),
+                password: password.into(),
+                pin: pin.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+                database: super::Database {
+                    url: database_url.into(),
+                    token: database_token.map(Into::into),
+                },
+            })
@@ -0,0 +1,29 @@
+
+::configure_me::if_test_helpers! {
+    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for Config {
+        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {
+            Ok(Config {
+                port: u.arbitrary()?,
+                database: u.arbitrary()?,
+                log: u.arbitrary()?,
+            })
+        }
+    }
+
+    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for Database {
+        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {
+            Ok(Database {
+                url: u.arbitrary()?,
+                pool_size: u.arbitrary()?,
+            })
+        }
+    }
+
+    impl<'a> ::configure_me::arbitrary::Arbitrary<'a> for Log {
+        fn arbitrary(u: &mut ::configure_me::arbitrary::Unstructured<'a>) -> ::configure_me::arbitrary::Result<Self> {
+            Ok(Log {
+                level: u.arbitrary()?,
+            })
+        }
+    }
+}
@@ -0,0 +1,4 @@
+    pub port: u16,
+    /// Connection to the database
+    pub database: Database,
+    pub log: Log,
@@ -0,0 +1,5 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --port\n\n        --database-url          URL of the database\n        --database-pool-size\n\n        --log-level\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldDatabaseUrl(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--database-url", err),
+        ArgParseError::FieldDatabasePoolSize(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u32>>(f, "--database-pool-size", err),
+        ArgParseError::FieldLogLevel(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--log-level", err),
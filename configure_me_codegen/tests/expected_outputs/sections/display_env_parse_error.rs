@@ -0,0 +1,4 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "SECTIONS_PORT", err),
+        EnvParseError::FieldDatabaseUrl(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "SECTIONS_DATABASE_URL", err),
+        EnvParseError::FieldDatabasePoolSize(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u32>>(f, "SECTIONS_DATABASE_POOL_SIZE", err),
+        EnvParseError::FieldLogLevel(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "SECTIONS_LOG_LEVEL", err),
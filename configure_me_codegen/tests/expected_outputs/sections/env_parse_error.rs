@@ -0,0 +1,4 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDatabaseUrl(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDatabasePoolSize(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLogLevel(<String as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,23 @@
+
+impl Config {
+    /// Asks the user for the values of params and switches and writes them into a new config file
+    ///
+    /// Fails if the file already exists.
+    pub fn interactive_setup<P: AsRef<::std::path::Path>>(path: P) -> ::std::io::Result<()> {
+        let stdin = ::std::io::stdin();
+        let stdout = ::std::io::stdout();
+        Self::interactive_setup_with(path, stdin.lock(), stdout.lock())
+    }
+
+    /// Like `interactive_setup()`, reading the answers from `input` and writing the questions to `output`
+    pub fn interactive_setup_with<P: AsRef<::std::path::Path>, R: ::std::io::BufRead, W: ::std::io::Write>(path: P, input: R, output: W) -> ::std::io::Result<()> {
+        let mut wizard = ::configure_me::helpers::Wizard::new(input, output);
+        wizard.param::<u16>("port", None, Some(&::configure_me::helpers::format_value(&{ let default: u16 = { 8080 }; default })), false)?;
+        wizard.table("database");
+        wizard.param::<String>("url", Some("URL of the database"), None, true)?;
+        wizard.param::<u32>("pool_size", None, Some(&::configure_me::helpers::format_value(&{ let default: u32 = { 4 }; default })), false)?;
+        wizard.table("log");
+        wizard.param::<String>("level", None, None, false)?;
+        wizard.write(path)
+    }
+}
@@ -0,0 +1,16 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--database-url", &arg, &mut iter) {
+                    let database_url = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--database-url"), ArgParseError::FieldDatabaseUrl))?;
+
+                    self.database_url = Some(database_url);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--database-pool-size", &arg, &mut iter) {
+                    let database_pool_size = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--database-pool-size"), ArgParseError::FieldDatabasePoolSize))?.0;
+
+                    self.database_pool_size = Some(database_pool_size);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--log-level", &arg, &mut iter) {
+                    let log_level = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--log-level"), ArgParseError::FieldLogLevel))?;
+
+                    self.log_level = Some(log_level);
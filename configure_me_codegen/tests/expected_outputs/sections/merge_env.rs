@@ -0,0 +1,5 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("SECTIONS_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.database_url, ::configure_me::helpers::parse_env("SECTIONS_DATABASE_URL").map_err(super::EnvParseError::FieldDatabaseUrl)?);
+        ::configure_me::helpers::merge(&mut self.database_pool_size, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u32>>("SECTIONS_DATABASE_POOL_SIZE").map_err(super::EnvParseError::FieldDatabasePoolSize)?.map(|value| value.0));
+        let log_level_env_var = ::configure_me::helpers::renamed_env_var("SECTIONS_LOG_LEVEL", &["SECTIONS_LOG_VERBOSITY"], |name| ::std::env::var_os(name));
+        ::configure_me::helpers::merge(&mut self.log_level, ::configure_me::helpers::parse_env(log_level_env_var).map_err(super::EnvParseError::FieldLogLevel)?);
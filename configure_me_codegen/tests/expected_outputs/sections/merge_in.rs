@@ -0,0 +1,4 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.database_url, other.database_url);
+            ::configure_me::helpers::merge(&mut self.database_pool_size, other.database_pool_size);
+            ::configure_me::helpers::merge(&mut self.log_level, other.log_level);
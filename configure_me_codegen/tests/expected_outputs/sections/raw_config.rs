@@ -0,0 +1,6 @@
+        port: Option<u16>,
+        database_url: Option<String>,
+        database_pool_size: Option<u32>,
+        log_level: Option<String>,
+        #[serde(rename = "log_verbosity")]
+        _renamed_log_verbosity: Option<String>,
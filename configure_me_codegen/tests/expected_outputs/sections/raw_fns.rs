@@ -0,0 +1,5 @@
+
+    fn rename_keys(mut config: Config, file: &::std::path::Path) -> Config {
+        ::configure_me::helpers::rename_key(&mut config.log_level, config._renamed_log_verbosity.take(), "log.verbosity", "log.level", file);
+        config
+    }
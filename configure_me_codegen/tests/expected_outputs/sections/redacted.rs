@@ -0,0 +1,38 @@
+
+impl Config {
+    /// Formats the values of params and switches into a single line, e.g. for logs
+    ///
+    /// Unset optional params are skipped, the values of secret params are redacted.
+    pub fn to_redacted_string(&self) -> String {
+        let mut fields = Vec::new();
+        fields.push(("port", ::configure_me::helpers::format_value(&self.port)));
+        fields.push(("database_url", ::configure_me::helpers::format_value(&self.database.url)));
+        fields.push(("database_pool_size", ::configure_me::helpers::format_value(&self.database.pool_size)));
+        if let Some(value) = &self.log.level {
+            fields.push(("log_level", ::configure_me::helpers::format_value(value)));
+        }
+        ::configure_me::helpers::join_fields(&fields)
+    }
+
+    /// Formats the configuration as a config file
+    ///
+    /// Unset optional params are skipped, the values of secret params are redacted.
+    pub fn to_redacted_toml(&self) -> Result<String, ::configure_me::toml::ser::Error> {
+        let mut table = ::configure_me::toml::value::Table::new();
+        table.insert("port".to_owned(), ::configure_me::toml::Value::try_from(&self.port)?);
+        let mut database_table = ::configure_me::toml::value::Table::new();
+        database_table.insert("url".to_owned(), ::configure_me::toml::Value::try_from(&self.database.url)?);
+        database_table.insert("pool_size".to_owned(), ::configure_me::toml::Value::try_from(&self.database.pool_size)?);
+        if !database_table.is_empty() {
+            table.insert("database".to_owned(), ::configure_me::toml::Value::Table(database_table));
+        }
+        let mut log_table = ::configure_me::toml::value::Table::new();
+        if let Some(value) = &self.log.level {
+            log_table.insert("level".to_owned(), ::configure_me::toml::Value::try_from(value)?);
+        }
+        if !log_table.is_empty() {
+            table.insert("log".to_owned(), ::configure_me::toml::Value::Table(log_table));
+        }
+        ::configure_me::helpers::format_toml(table, &[])
+    }
+}
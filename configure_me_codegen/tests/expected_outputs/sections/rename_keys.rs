@@ -0,0 +1 @@
+                .map(|config| rename_keys(config, config_file_name))
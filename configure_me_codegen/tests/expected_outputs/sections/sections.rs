@@ -0,0 +1,10 @@
+
+/// Connection to the database
+pub struct Database {
+    pub url: String,
+    pub pool_size: u32,
+}
+
+pub struct Log {
+    pub level: Option<String>,
+}
@@ -0,0 +1 @@
+                    return Ok(None.into_iter().chain(iter));
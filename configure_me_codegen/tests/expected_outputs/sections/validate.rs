@@ -0,0 +1,24 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let database_url = self.database_url;
+            let database_pool_size = self.database_pool_size.unwrap_or_else(|| { 4 });
+            let log_level = self.log_level;
+
+            let mut missing_fields = Vec::new();
+            if database_url.is_none() {
+                missing_fields.push(super::MissingField { name: "database_url", sources: &["--database-url", "SECTIONS_DATABASE_URL"] });
+            }
+            let database_url = match database_url {
+                Some(database_url) => database_url,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                port: port.into(),
+                database: super::Database {
+                    url: database_url.into(),
+                    pool_size: database_pool_size.into(),
+                },
+                log: super::Log {
+                    level: log_level.map(Into::into),
+                },
+            })
@@ -0,0 +1,7 @@
+    FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLabels(<::configure_me::helpers::KeyValue<String> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldPassword(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTimeout(<::configure_me::helpers::HumanDuration as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldTags(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldDatabaseUrl(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,7 @@
+    pub port: u16,
+    pub labels: Option<::std::collections::HashMap<String, String>>,
+    pub password: Option<String>,
+    pub timeout: Option<::std::time::Duration>,
+    pub tags: Option<Vec<String>>,
+    pub verbose: bool,
+    pub database: Database,
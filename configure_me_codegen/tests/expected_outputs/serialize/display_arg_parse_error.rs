@@ -0,0 +1,8 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...]\n\nArguments:\n        --port\n\n        --labels\n\n        --password\n\n        --timeout         Duration such as 30s, 5m or 1h30m.\n        --tags\n\n        --database-url\n\n        --verbose\n", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldLabels(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::KeyValue<String>>(f, "--labels", err),
+        ArgParseError::FieldPassword(_) => ::configure_me::helpers::display_secret_arg_error::<String>(f, "--password"),
+        ArgParseError::FieldTimeout(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::HumanDuration>(f, "--timeout", err),
+        ArgParseError::FieldTags(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--tags", err),
+        ArgParseError::FieldDatabaseUrl(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--database-url", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
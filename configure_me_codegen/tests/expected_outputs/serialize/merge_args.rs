@@ -0,0 +1,30 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::KeyValue<String>, _, _>("--labels", &arg, &mut iter) {
+                    let ::configure_me::helpers::KeyValue(key, value) = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--labels"), ArgParseError::FieldLabels))?;
+                    let labels = ::std::iter::once((key, value)).collect::<::std::collections::HashMap<String, String>>();
+
+                    ::configure_me::helpers::merge_with(&mut self.labels, Some(labels), ::configure_me::helpers::merge_maps);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--password", &arg, &mut iter) {
+                    let password = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--password"), ArgParseError::FieldPassword))?;
+
+                    self.password = Some(password);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::HumanDuration, _, _>("--timeout", &arg, &mut iter) {
+                    let timeout = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--timeout"), ArgParseError::FieldTimeout))?.0;
+
+                    self.timeout = Some(timeout);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--tags", &arg, &mut iter) {
+                    let tags = vec![value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--tags"), ArgParseError::FieldTags))?];
+
+                    ::configure_me::helpers::merge_with(&mut self.tags, Some(tags), ::configure_me::helpers::append_values);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--database-url", &arg, &mut iter) {
+                    let database_url = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--database-url"), ArgParseError::FieldDatabaseUrl))?;
+
+                    self.database_url = Some(database_url);
+                } else if arg == *"--verbose" {
+                    self.verbose = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--verbose", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--verbose", value))?;
+                    self.verbose = Some(value);
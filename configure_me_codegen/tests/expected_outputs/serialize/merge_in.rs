@@ -0,0 +1,9 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge_with(&mut self.labels, other.labels, ::configure_me::helpers::merge_maps);
+            ::configure_me::helpers::merge(&mut self.password, other.password);
+            ::configure_me::helpers::merge(&mut self.timeout, other.timeout);
+            ::configure_me::helpers::merge_with(&mut self.tags, other.tags, ::configure_me::helpers::append_values);
+            ::configure_me::helpers::merge(&mut self.database_url, other.database_url);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
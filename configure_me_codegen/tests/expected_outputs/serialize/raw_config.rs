@@ -0,0 +1,10 @@
+        port: Option<u16>,
+        labels: Option<::std::collections::HashMap<String, String>>,
+        #[serde(default, deserialize_with = "secret_password")]
+        password: Option<String>,
+        #[serde(default, deserialize_with = "::configure_me::helpers::deserialize_duration")]
+        timeout: Option<::std::time::Duration>,
+        tags: Option<Vec<String>>,
+        database_url: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
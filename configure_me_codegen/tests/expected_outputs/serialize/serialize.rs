@@ -0,0 +1,26 @@
+
+impl ::configure_me::serde::Serialize for Config {
+    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use ::configure_me::serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Config", 7)?;
+        state.serialize_field("port", &self.port)?;
+        state.serialize_field("password", &self.password.as_ref().map(|_| ::configure_me::helpers::REDACTED))?;
+        state.serialize_field("timeout", &self.timeout.as_ref().map(|value| ::configure_me::helpers::SerializeAsArg(value)))?;
+        state.serialize_field("tags", &self.tags)?;
+        state.serialize_field("verbose", &self.verbose)?;
+        state.serialize_field("labels", &self.labels)?;
+        state.serialize_field("database", &self.database)?;
+        state.end()
+    }
+}
+
+impl ::configure_me::serde::Serialize for Database {
+    fn serialize<S: ::configure_me::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use ::configure_me::serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Database", 1)?;
+        state.serialize_field("url", &self.url)?;
+        state.end()
+    }
+}
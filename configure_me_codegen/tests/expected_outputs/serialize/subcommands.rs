@@ -0,0 +1,4 @@
+
+pub struct Database {
+    pub url: Option<String>,
+}
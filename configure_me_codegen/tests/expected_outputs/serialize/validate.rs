@@ -0,0 +1,18 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let labels = self.labels;
+            let password = self.password;
+            let timeout = self.timeout;
+            let tags = self.tags;
+            let database_url = self.database_url;
+
+            Ok(super::Config {
+                port: port.into(),
+                labels: labels.map(Into::into),
+                password: password.map(Into::into),
+                timeout: timeout.map(Into::into),
+                tags: tags.map(Into::into),
+                verbose: self.verbose.unwrap_or(false),
+                database: super::Database {
+                    url: database_url.map(Into::into),
+                },
+            })
@@ -0,0 +1 @@
+    pub port: u16,
@@ -0,0 +1 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
@@ -0,0 +1,29 @@
+
+/// Configuration that can be replaced atomically while it's being used
+///
+/// Requires the `shared` feature of `configure_me`.
+pub struct SharedConfig(::configure_me::arc_swap::ArcSwap<Config>);
+
+impl SharedConfig {
+    pub fn new(config: Config) -> Self {
+        SharedConfig(::configure_me::arc_swap::ArcSwap::from_pointee(config))
+    }
+
+    /// Returns the current configuration
+    ///
+    /// The returned value isn't affected by later calls to `replace()`.
+    pub fn load(&self) -> ::std::sync::Arc<Config> {
+        self.0.load_full()
+    }
+
+    /// Atomically replaces the configuration, returning the previous one
+    pub fn replace(&self, config: Config) -> ::std::sync::Arc<Config> {
+        self.0.swap(::std::sync::Arc::new(config))
+    }
+}
+
+impl From<Config> for SharedConfig {
+    fn from(config: Config) -> Self {
+        SharedConfig::new(config)
+    }
+}
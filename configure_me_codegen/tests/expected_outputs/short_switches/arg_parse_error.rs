@@ -1,2 +1,3 @@
     FieldD(<String as ::configure_me::parse_arg::ParseArg>::Error),
     FieldE(<String as ::configure_me::parse_arg::ParseArg>::Error),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
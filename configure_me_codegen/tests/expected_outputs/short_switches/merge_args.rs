@@ -8,9 +8,18 @@
                     self.e = Some(e);
                 } else if arg == *"--a" {
                     self.a = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--a", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--a", value))?;
+                    self.a = Some(value);
                 } else if arg == *"--b" {
                     self.b = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--b", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--b", value))?;
+                    self.b = Some(value);
                 } else if arg == *"--c" {
                     *(self.c.get_or_insert(0)) += 1;
                 } else if arg == *"--foo-bar" {
                     self.foo_bar = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--foo-bar", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--foo-bar", value))?;
+                    self.foo_bar = Some(value);
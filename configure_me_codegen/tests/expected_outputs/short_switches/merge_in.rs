@@ -1,9 +1,5 @@
-            if other.d.is_some() {
-                self.d = other.d;
-            }
-            if other.e.is_some() {
-                self.e = other.e;
-            }
+            ::configure_me::helpers::merge(&mut self.d, other.d);
+            ::configure_me::helpers::merge(&mut self.e, other.e);
             if other.a.is_some() {
                 self.a = other.a;
             }
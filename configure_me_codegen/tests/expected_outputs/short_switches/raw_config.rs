@@ -1,6 +1,9 @@
         d: Option<String>,
         e: Option<String>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
         a: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
         b: Option<bool>,
         c: Option<u32>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
         foo_bar: Option<bool>,
@@ -1 +1 @@
-    FieldFoo(<u32 as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldFoo(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
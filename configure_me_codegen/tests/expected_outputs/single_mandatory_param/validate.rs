@@ -1,4 +1,13 @@
-            let foo = self.foo.ok_or(ValidationError::MissingField("foo"))?;
+            let foo = self.foo;
+
+            let mut missing_fields = Vec::new();
+            if foo.is_none() {
+                missing_fields.push(super::MissingField { name: "foo", sources: &["--foo", "TEST_APP_FOO"] });
+            }
+            let foo = match foo {
+                Some(foo) => foo,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
 
             Ok(super::Config {
                 foo: foo.into(),
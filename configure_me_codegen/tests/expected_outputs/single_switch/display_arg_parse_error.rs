@@ -1 +1,2 @@
         ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--foo]", program_name),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
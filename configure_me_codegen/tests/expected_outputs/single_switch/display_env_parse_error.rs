@@ -1,3 +1 @@
-        EnvParseError::FieldFoo(ref err) => {
-            write!(f, "Invalid value '{:?}' for 'TEST_APP_FOO'.\n\nHint: the allowed values are 0, false, 1, true.", err)
-        },
+        EnvParseError::FieldFoo(ref err) => ::configure_me::helpers::display_switch_error(f, "TEST_APP_FOO", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
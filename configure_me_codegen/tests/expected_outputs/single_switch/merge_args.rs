@@ -1,2 +1,5 @@
                 } else if arg == *"--foo" {
                     self.foo = Some(true);
+                } else if let Some(value) = ::configure_me::helpers::switch_value("--foo", &arg) {
+                    let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--foo", value))?;
+                    self.foo = Some(value);
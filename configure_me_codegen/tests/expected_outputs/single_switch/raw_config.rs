@@ -1 +1,2 @@
+        #[serde(default, deserialize_with = "deserialize_switch")]
         foo: Option<bool>,
@@ -0,0 +1,3 @@
+    FieldCacheSize(<::configure_me::helpers::ByteSize as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldBufferSize(<::configure_me::helpers::ByteSize as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldLimit(<::configure_me::helpers::ByteSize as ::configure_me::parse_arg::ParseArg>::Error),
@@ -0,0 +1,3 @@
+    pub cache_size: u64,
+    pub buffer_size: Option<u64>,
+    pub limit: Option<u64>,
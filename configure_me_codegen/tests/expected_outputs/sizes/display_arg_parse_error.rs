@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [ARGUMENTS...] [LIMIT]\n\nArguments:\n        --config         Load configuration from this file.\n        --cache-size     Maximum size of the cache. Size in bytes such as 4096, \n                         64KiB or 2GB.\n        --buffer-size    Size in bytes such as 4096, 64KiB or 2GB.\n\nPositional arguments:\n        LIMIT      Size in bytes such as 4096, 64KiB or 2GB.", program_name),
+        ArgParseError::FieldCacheSize(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::ByteSize>(f, "--cache-size", err),
+        ArgParseError::FieldBufferSize(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::ByteSize>(f, "--buffer-size", err),
+        ArgParseError::FieldLimit(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::ByteSize>(f, "LIMIT", err),
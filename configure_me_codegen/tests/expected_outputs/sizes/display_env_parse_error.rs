@@ -0,0 +1,2 @@
+        EnvParseError::FieldCacheSize(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::ByteSize>(f, "SIZES_CACHE_SIZE", err),
+        EnvParseError::FieldBufferSize(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::ByteSize>(f, "SIZES_BUFFER_SIZE", err),
@@ -0,0 +1,17 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--config", &arg, &mut iter) {
+                    let file_path: std::path::PathBuf = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--config"), |never| match never {}))?;
+                    let loaded = if file_path.as_os_str() == "-" {
+                        self.load_stdin(&file_path)
+                    } else {
+                        self.load_once(&file_path)
+                    };
+                    let mut config = loaded?;
+                    self.merge_in(config);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::ByteSize, _, _>("--cache-size", &arg, &mut iter) {
+                    let cache_size = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--cache-size"), ArgParseError::FieldCacheSize))?.0;
+
+                    self.cache_size = Some(cache_size);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::ByteSize, _, _>("--buffer-size", &arg, &mut iter) {
+                    let buffer_size = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--buffer-size"), ArgParseError::FieldBufferSize))?.0;
+
+                    self.buffer_size = Some(buffer_size);
@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.cache_size, ::configure_me::helpers::parse_env::<::configure_me::helpers::ByteSize>("SIZES_CACHE_SIZE").map_err(super::EnvParseError::FieldCacheSize)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.buffer_size, ::configure_me::helpers::parse_env::<::configure_me::helpers::ByteSize>("SIZES_BUFFER_SIZE").map_err(super::EnvParseError::FieldBufferSize)?.map(|value| value.0));
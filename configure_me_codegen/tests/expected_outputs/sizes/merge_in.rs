@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge(&mut self.cache_size, other.cache_size);
+            ::configure_me::helpers::merge(&mut self.buffer_size, other.buffer_size);
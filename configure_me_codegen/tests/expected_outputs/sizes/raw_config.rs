@@ -0,0 +1,6 @@
+        #[serde(default, deserialize_with = "::configure_me::helpers::deserialize_size")]
+        cache_size: Option<u64>,
+        #[serde(default, deserialize_with = "::configure_me::helpers::deserialize_size")]
+        buffer_size: Option<u64>,
+        #[serde(skip)]
+        limit: Option<u64>,
@@ -0,0 +1,17 @@
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
+
+    impl Config {
+        pub fn merge_positionals<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<I, super::Error> {
+            if let Some(arg) = args.next() {
+                self.limit = Some(<::configure_me::helpers::ByteSize as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::FieldLimit)?.0);
+            }
+            Ok(args)
+        }
+    }
@@ -0,0 +1,13 @@
+            let cache_size = self.cache_size.unwrap_or_else(|| { 64 * 1024 * 1024 });
+            let buffer_size = self.buffer_size;
+            let limit = self.limit;
+
+            if let Some(value) = Some(cache_size).filter(|&value| value > 1073741824) {
+                return Err(ValidationError::OutOfRange { name: "cache_size", value: value.to_string(), range: "at most 1073741824" });
+            }
+
+            Ok(super::Config {
+                cache_size: cache_size.into(),
+                buffer_size: buffer_size.map(Into::into),
+                limit,
+            })
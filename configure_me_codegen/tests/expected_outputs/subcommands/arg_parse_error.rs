@@ -0,0 +1,4 @@
+    UnknownSubcommand(String),
+    SubcommandServe(Box<serve::Error>),
+    SubcommandCheck(Box<check::Error>),
+    InvalidSwitchValue(&'static str, ::std::ffi::OsString),
@@ -0,0 +1,2 @@
+    pub verbose: bool,
+    pub subcommand: Option<Subcommand>,
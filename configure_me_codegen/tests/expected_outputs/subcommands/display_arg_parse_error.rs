@@ -0,0 +1,5 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--config CONF_FILE] [-v|--verbose] [SUBCOMMAND ...]\n\nArguments:\n        --config         Load configuration from this file.\n        -v, --verbose    Print more information\n\nSubcommands:\n        serve      Start the server\n        check      Check a file without serving it", program_name),
+        ArgParseError::UnknownSubcommand(arg) => write!(f, "An unknown subcommand '{}' was specified.", arg),
+        ArgParseError::SubcommandServe(err) => write!(f, "{}", err),
+        ArgParseError::SubcommandCheck(err) => write!(f, "{}", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1 @@
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "TOOL_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
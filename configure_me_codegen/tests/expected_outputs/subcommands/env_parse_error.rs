@@ -0,0 +1 @@
+    FieldVerbose(::std::ffi::OsString),
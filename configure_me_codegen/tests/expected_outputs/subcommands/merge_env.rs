@@ -0,0 +1,3 @@
+        if let Some(val) = ::std::env::var_os("TOOL_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
@@ -0,0 +1,3 @@
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
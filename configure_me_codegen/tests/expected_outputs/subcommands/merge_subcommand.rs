@@ -0,0 +1 @@
+        let remaining_args = config.merge_subcommand(remaining_args)?;
@@ -0,0 +1,40 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    impl Config {
+        /// Loads the config file from the standard input, given as `-` in the arguments
+        fn load_stdin(&self, config_file_name: &::std::path::Path) -> Result<Self, super::Error> {
+            let config_content = ::configure_me::helpers::read_config_reader(::std::io::stdin(), Default::default()).map_err(|error| super::Error::Reading { file: config_file_name.into(), error })?;
+            self.parse(config_file_name, &config_content)
+        }
+    }
+
+    impl Config {
+        pub fn merge_subcommand<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<::std::vec::IntoIter<::std::ffi::OsString>, super::Error> {
+            let arg = match args.next() {
+                Some(arg) => arg,
+                None => return Ok(Vec::new().into_iter()),
+            };
+            let program_name: String = self._program_path.as_ref().unwrap().to_string_lossy().into();
+            if arg == *"serve" {
+                let args = ::std::iter::once(format!("{} serve", program_name).into()).chain(args);
+                let config_files = self._loaded_files.iter().map(|(file, _)| file.clone()).collect::<Vec<_>>();
+                let (config, remaining_args) = super::serve::Config::custom_args_and_optional_files(args, config_files).map_err(|err| ArgParseError::SubcommandServe(Box::new(err)))?;
+                self._subcommand = Some(super::Subcommand::Serve(config));
+                Ok(remaining_args.collect::<Vec<_>>().into_iter())
+            } else if arg == *"check" {
+                let args = ::std::iter::once(format!("{} check", program_name).into()).chain(args);
+                let config_files = self._loaded_files.iter().map(|(file, _)| file.clone()).collect::<Vec<_>>();
+                let (config, remaining_args) = super::check::Config::custom_args_and_optional_files(args, config_files).map_err(|err| ArgParseError::SubcommandCheck(Box::new(err)))?;
+                self._subcommand = Some(super::Subcommand::Check(config));
+                Ok(remaining_args.collect::<Vec<_>>().into_iter())
+            } else {
+                Err(ArgParseError::UnknownSubcommand(arg.to_string_lossy().into_owned()).into())
+            }
+        }
+    }
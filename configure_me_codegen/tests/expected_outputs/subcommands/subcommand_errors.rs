@@ -0,0 +1,2 @@
+            Err(Error::Arguments(ArgParseError::SubcommandServe(err))) => serve::ResultExt::unwrap_or_exit(Err(*err)),
+            Err(Error::Arguments(ArgParseError::SubcommandCheck(err))) => check::ResultExt::unwrap_or_exit(Err(*err)),
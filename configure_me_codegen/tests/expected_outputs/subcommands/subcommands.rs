@@ -0,0 +1,658 @@
+
+/// Subcommand selected by the first argument after the options
+pub enum Subcommand {
+    /// Start the server
+    Serve(serve::Config),
+    /// Check a file without serving it
+    Check(check::Config),
+}
+
+/// Start the server
+pub mod serve {
+    #[allow(unused_imports)]
+    use super::*;
+
+    pub mod prelude {
+        pub use super::{Config, ResultExt};
+    }
+
+    pub enum ArgParseError {
+        MissingArgument(&'static str),
+        UnknownArgument(String),
+        HelpRequested(String),
+
+        FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+        InvalidSwitchValue(&'static str, ::std::ffi::OsString),
+    }
+
+    impl ::std::fmt::Display for ArgParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match self {
+                ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
+                ArgParseError::UnknownArgument(arg) => write!(f, "An unknown argument '{}' was specified.", arg),
+            ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--tls]\n\nArguments:\n        --port    Port to listen on\n        --tls     Serve HTTPS", program_name),
+            ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+            ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for ArgParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    pub enum EnvParseError {
+        FieldPort(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::Error),
+        FieldTls(::std::ffi::OsString),
+    }
+
+    impl ::std::fmt::Display for EnvParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+            EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "TOOL_SERVE_PORT", err),
+            EnvParseError::FieldTls(ref err) => ::configure_me::helpers::display_switch_error(f, "TOOL_SERVE_TLS", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for EnvParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    /// Mandatory param missing in all sources
+    pub struct MissingField {
+        pub name: &'static str,
+        /// Arguments and environment variables that can specify it
+        pub sources: &'static [&'static str],
+    }
+
+    pub enum ValidationError {
+        MissingFields(Vec<MissingField>),
+        /// Value of a param outside of the range allowed by its `min` and `max`
+        OutOfRange { name: &'static str, value: String, range: &'static str },
+        /// Value of a param not matching its `validate_regex`
+        PatternMismatch { name: &'static str, value: String, pattern: &'static str },
+        /// Value of a param rejected by its `validate` function
+        Invalid { name: &'static str, message: String },
+        /// Error returned by the function in `general.validate`
+        Custom(String),
+        /// Param or switch set without another one listed in its `requires`
+        MissingRequirement { name: &'static str, sources: Vec<::configure_me::helpers::Source>, required: &'static str },
+        /// Param or switch set together with another one listed in its `conflicts_with`
+        Conflict { name: &'static str, sources: Vec<::configure_me::helpers::Source>, other: &'static str, other_sources: Vec<::configure_me::helpers::Source> },
+        /// No member of a required group set
+        GroupMissing { group: &'static str, members: &'static [&'static str] },
+        /// More than one member of a group set, with the sources of their values
+        GroupConflict { group: &'static str, members: Vec<(&'static str, Vec<::configure_me::helpers::Source>)> },
+    }
+
+    impl ::std::fmt::Display for ValidationError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match self {
+                ValidationError::MissingFields(fields) => ::configure_me::helpers::display_missing_fields(f, fields.iter().map(|field| (field.name, field.sources))),
+                ValidationError::OutOfRange { name, value, range } => write!(f, "Configuration parameter '{}' is {}, but it must be {}.", name, value, range),
+                ValidationError::PatternMismatch { name, value, pattern } => write!(f, "Configuration parameter '{}' is '{}', but it must match the pattern '{}'.", name, value, pattern),
+                ValidationError::Invalid { name, message } => write!(f, "Configuration parameter '{}' is invalid: {}", name, message),
+                ValidationError::Custom(error) => write!(f, "{}", error),
+                ValidationError::MissingRequirement { name, sources, required } => ::configure_me::helpers::display_missing_requirement(f, name, sources, required),
+                ValidationError::Conflict { name, sources, other, other_sources } => ::configure_me::helpers::display_conflict(f, name, sources, other, other_sources),
+                ValidationError::GroupMissing { group, members } => ::configure_me::helpers::display_group_missing(f, group, members),
+                ValidationError::GroupConflict { group, members } => ::configure_me::helpers::display_group_conflict(f, group, members),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for ValidationError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    pub enum Error {
+        Reading { file: ::std::path::PathBuf, error: ::std::io::Error },
+        ConfigParsing { file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error },
+        Arguments(ArgParseError),
+        Environment(EnvParseError),
+        Validation(ValidationError),
+    }
+
+    impl From<ArgParseError> for Error {
+        fn from(err: ArgParseError) -> Self {
+            Error::Arguments(err)
+        }
+    }
+
+    impl From<EnvParseError> for Error {
+        fn from(err: EnvParseError) -> Self {
+            Error::Environment(err)
+        }
+    }
+
+    impl From<ValidationError> for Error {
+        fn from(err: ValidationError) -> Self {
+            Error::Validation(err)
+        }
+    }
+
+    impl ::std::fmt::Display for Error {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match self {
+                Error::Reading { file, error } => write!(f, "Failed to read configuration file {}: {}", file.display(), error),
+                Error::ConfigParsing { file, error } => write!(f, "Failed to parse configuration file {}: {}", file.display(), error),
+                Error::Arguments(err) => write!(f, "{}", err),
+                Error::Environment(err) => write!(f, "{}", err),
+                Error::Validation(err) => write!(f, "Invalid configuration: {}", err),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for Error {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    mod raw {
+        use ::std::path::PathBuf;
+        use super::{ArgParseError, ValidationError};
+
+        #[derive(Deserialize, Default)]
+        #[serde(crate = "crate::configure_me::serde")]
+        pub struct Config {
+            _program_path: Option<PathBuf>,
+            #[serde(skip)]
+            pub(super) _loaded_files: Vec<(PathBuf, ::configure_me::helpers::FileDigest)>,
+            #[serde(skip)]
+            pub(super) _parsed_files: Vec<(PathBuf, ::configure_me::toml::value::Table)>,
+            port: Option<u16>,
+            #[serde(default, deserialize_with = "deserialize_switch")]
+            tls: Option<bool>,
+        }
+
+        impl Config {
+            pub fn parse(&self, config_file_name: &::std::path::Path, config_content: &[u8]) -> Result<Self, super::Error> {
+                self.parse_document(config_file_name, config_content)
+            }
+
+            fn parse_document<D: ::configure_me::helpers::ConfigDocument>(&self, config_file_name: &::std::path::Path, config_content: D) -> Result<Self, super::Error> {
+                ::configure_me::helpers::parse_table(config_content, &["serve"])
+                    .map_err(|error| super::Error::ConfigParsing { file: config_file_name.into(), error })
+            }
+
+            /// Loads the file, reading and parsing it only once even if it's specified multiple times
+            pub fn load_once<P: Into<PathBuf>>(&mut self, config_file_name: P) -> Result<Self, super::Error> {
+                let config_file_name = config_file_name.into();
+                if let Some((_, table)) = self._parsed_files.iter().find(|(file, _)| *file == config_file_name) {
+                    return self.parse_document(&config_file_name, table.clone());
+                }
+
+                let config_content = ::std::fs::read(&config_file_name).map_err(|error| super::Error::Reading { file: config_file_name.clone(), error })?;
+                let table = ::configure_me::helpers::ConfigDocument::into_table(&*config_content)
+                    .map_err(|error| super::Error::ConfigParsing { file: config_file_name.clone(), error })?;
+                let config = self.parse_document(&config_file_name, table.clone())?;
+                self._loaded_files.push((config_file_name.clone(), ::configure_me::helpers::FileDigest::of(&config_content)));
+                self._parsed_files.push((config_file_name, table));
+                Ok(config)
+            }
+
+            pub fn validate(self) -> Result<super::Config, ValidationError> {
+                let port = self.port.unwrap_or_else(|| { 8080 });
+
+                Ok(super::Config {
+                    port: port.into(),
+                    tls: self.tls.unwrap_or(false),
+                })
+            }
+
+            pub fn merge_in(&mut self, other: Self) {
+                ::configure_me::helpers::merge(&mut self.port, other.port);
+                if other.tls.is_some() {
+                    self.tls = other.tls;
+                }
+            }
+
+            pub fn merge_args<I: IntoIterator<Item=::std::ffi::OsString>>(&mut self, args: I) -> Result<impl Iterator<Item=::std::ffi::OsString>, super::Error> {
+                let mut iter = args.into_iter().fuse().peekable();
+                self._program_path = iter.next().map(Into::into);
+
+                while let Some(arg) = iter.next() {
+                    if arg == *"--" {
+                        return Ok(None.into_iter().chain(iter));
+                    } else if (arg == *"--help") || (arg == *"-h") {
+                        return Err(ArgParseError::HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into()).into());
+                    } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                        let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                        self.port = Some(port);
+                    } else if arg == *"--tls" {
+                        self.tls = Some(true);
+                    } else if let Some(value) = ::configure_me::helpers::switch_value("--tls", &arg) {
+                        let value = ::configure_me::helpers::parse_switch(value, TRUE_VALUES, FALSE_VALUES).map_err(|value| ArgParseError::InvalidSwitchValue("--tls", value))?;
+                        self.tls = Some(value);
+                    } else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {
+                        for short in &mut shorts {
+                            if short == 'h' {
+                                return Err(ArgParseError::HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into()).into())
+                            } else {
+                                let mut arg = String::with_capacity(2);
+                                arg.push('-');
+                                arg.push(short);
+                                return Err(ArgParseError::UnknownArgument(arg).into());
+                            }
+                        }
+                    } else if arg.to_str().unwrap_or("").starts_with("--") {
+                        return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
+                    } else {
+                        return Ok(Some(arg).into_iter().chain(iter))
+                    }
+                }
+
+                Ok(None.into_iter().chain(iter))
+            }
+
+            pub fn merge_env(&mut self) -> Result<(), super::Error> {
+            ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("TOOL_SERVE_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+            if let Some(val) = ::std::env::var_os("TOOL_SERVE_TLS") {
+                self.tls = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldTls)?);
+            }
+                Ok(())
+            }
+        }
+
+        pub const TRUE_VALUES: &[&str] = &["1", "true"];
+        pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+        fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+            ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+        }
+    }
+
+    /// Configuration of the application
+    pub struct Config {
+        pub port: u16,
+        pub tls: bool,
+    }
+
+    impl Config {
+        pub fn including_optional_config_files<I>(config_files: I) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+            Self::custom_args_and_optional_files(::std::env::args_os(), config_files)
+        }
+
+        pub fn custom_args_and_optional_files<A, I>(args: A, config_files: I) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where
+            A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
+            I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+
+            let mut config = raw::Config::default();
+            for path in config_files {
+                match config.load_once(path.as_ref()) {
+                    Ok(mut new_config) => {
+                        new_config._loaded_files = ::std::mem::replace(&mut config._loaded_files, Vec::new());
+                        new_config._parsed_files = ::std::mem::replace(&mut config._parsed_files, Vec::new());
+                        std::mem::swap(&mut config, &mut new_config);
+                        config.merge_in(new_config)
+                    },
+                    Err(Error::Reading { ref error, .. }) if error.kind() == ::std::io::ErrorKind::NotFound => (),
+                    Err(err) => return Err(err),
+                }
+            }
+
+            config.merge_env()?;
+            let remaining_args = config.merge_args(args.into_iter().map(Into::into))?;
+
+            config
+                .validate()
+                .map(|cfg| (cfg, remaining_args))
+                .map_err(Into::into)
+        }
+    }
+
+    pub trait ResultExt {
+        type Item;
+
+        fn unwrap_or_exit(self) -> Self::Item;
+    }
+
+    impl<T> ResultExt for Result<T, Error> {
+        type Item = T;
+
+        fn unwrap_or_exit(self) -> Self::Item {
+            use std::io::Write;
+
+            match self {
+                Ok(item) => item,
+                Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {
+                    println!("{}", err);
+                    std::io::stdout().flush().expect("failed to flush stdout");
+                    ::std::process::exit(0)
+                },
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    std::io::stderr().flush().expect("failed to flush stderr");
+                    ::std::process::exit(1)
+                }
+            }
+        }
+    }
+}
+
+/// Check a file without serving it
+pub mod check {
+    #[allow(unused_imports)]
+    use super::*;
+
+    pub mod prelude {
+        pub use super::{Config, ResultExt};
+    }
+
+    pub enum ArgParseError {
+        MissingArgument(&'static str),
+        UnknownArgument(String),
+        HelpRequested(String),
+
+        FieldPath(<::std::path::PathBuf as ::configure_me::parse_arg::ParseArg>::Error),
+    }
+
+    impl ::std::fmt::Display for ArgParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match self {
+                ArgParseError::MissingArgument(arg) => write!(f, "A value to argument '{}' is missing.", arg),
+                ArgParseError::UnknownArgument(arg) => write!(f, "An unknown argument '{}' was specified.", arg),
+            ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} PATH\n\nPositional arguments:\n        PATH      File to check", program_name),
+            ArgParseError::FieldPath(err) => ::configure_me::helpers::display_arg_error::<::std::path::PathBuf>(f, "PATH", err),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for ArgParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    pub enum EnvParseError {
+    }
+
+    impl ::std::fmt::Display for EnvParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match *self {
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for EnvParseError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    /// Mandatory param missing in all sources
+    pub struct MissingField {
+        pub name: &'static str,
+        /// Arguments and environment variables that can specify it
+        pub sources: &'static [&'static str],
+    }
+
+    pub enum ValidationError {
+        MissingFields(Vec<MissingField>),
+        /// Value of a param outside of the range allowed by its `min` and `max`
+        OutOfRange { name: &'static str, value: String, range: &'static str },
+        /// Value of a param not matching its `validate_regex`
+        PatternMismatch { name: &'static str, value: String, pattern: &'static str },
+        /// Value of a param rejected by its `validate` function
+        Invalid { name: &'static str, message: String },
+        /// Error returned by the function in `general.validate`
+        Custom(String),
+        /// Param or switch set without another one listed in its `requires`
+        MissingRequirement { name: &'static str, sources: Vec<::configure_me::helpers::Source>, required: &'static str },
+        /// Param or switch set together with another one listed in its `conflicts_with`
+        Conflict { name: &'static str, sources: Vec<::configure_me::helpers::Source>, other: &'static str, other_sources: Vec<::configure_me::helpers::Source> },
+        /// No member of a required group set
+        GroupMissing { group: &'static str, members: &'static [&'static str] },
+        /// More than one member of a group set, with the sources of their values
+        GroupConflict { group: &'static str, members: Vec<(&'static str, Vec<::configure_me::helpers::Source>)> },
+    }
+
+    impl ::std::fmt::Display for ValidationError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match self {
+                ValidationError::MissingFields(fields) => ::configure_me::helpers::display_missing_fields(f, fields.iter().map(|field| (field.name, field.sources))),
+                ValidationError::OutOfRange { name, value, range } => write!(f, "Configuration parameter '{}' is {}, but it must be {}.", name, value, range),
+                ValidationError::PatternMismatch { name, value, pattern } => write!(f, "Configuration parameter '{}' is '{}', but it must match the pattern '{}'.", name, value, pattern),
+                ValidationError::Invalid { name, message } => write!(f, "Configuration parameter '{}' is invalid: {}", name, message),
+                ValidationError::Custom(error) => write!(f, "{}", error),
+                ValidationError::MissingRequirement { name, sources, required } => ::configure_me::helpers::display_missing_requirement(f, name, sources, required),
+                ValidationError::Conflict { name, sources, other, other_sources } => ::configure_me::helpers::display_conflict(f, name, sources, other, other_sources),
+                ValidationError::GroupMissing { group, members } => ::configure_me::helpers::display_group_missing(f, group, members),
+                ValidationError::GroupConflict { group, members } => ::configure_me::helpers::display_group_conflict(f, group, members),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for ValidationError {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    pub enum Error {
+        Reading { file: ::std::path::PathBuf, error: ::std::io::Error },
+        ConfigParsing { file: ::std::path::PathBuf, error: ::configure_me::toml::de::Error },
+        Arguments(ArgParseError),
+        Environment(EnvParseError),
+        Validation(ValidationError),
+    }
+
+    impl From<ArgParseError> for Error {
+        fn from(err: ArgParseError) -> Self {
+            Error::Arguments(err)
+        }
+    }
+
+    impl From<EnvParseError> for Error {
+        fn from(err: EnvParseError) -> Self {
+            Error::Environment(err)
+        }
+    }
+
+    impl From<ValidationError> for Error {
+        fn from(err: ValidationError) -> Self {
+            Error::Validation(err)
+        }
+    }
+
+    impl ::std::fmt::Display for Error {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            match self {
+                Error::Reading { file, error } => write!(f, "Failed to read configuration file {}: {}", file.display(), error),
+                Error::ConfigParsing { file, error } => write!(f, "Failed to parse configuration file {}: {}", file.display(), error),
+                Error::Arguments(err) => write!(f, "{}", err),
+                Error::Environment(err) => write!(f, "{}", err),
+                Error::Validation(err) => write!(f, "Invalid configuration: {}", err),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for Error {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            ::std::fmt::Display::fmt(self, f)
+        }
+    }
+
+    mod raw {
+        use ::std::path::PathBuf;
+        use super::{ArgParseError, ValidationError};
+
+        #[derive(Deserialize, Default)]
+        #[serde(crate = "crate::configure_me::serde")]
+        pub struct Config {
+            _program_path: Option<PathBuf>,
+            #[serde(skip)]
+            pub(super) _loaded_files: Vec<(PathBuf, ::configure_me::helpers::FileDigest)>,
+            #[serde(skip)]
+            pub(super) _parsed_files: Vec<(PathBuf, ::configure_me::toml::value::Table)>,
+            #[serde(skip)]
+            path: Option<::std::path::PathBuf>,
+        }
+
+        impl Config {
+            pub fn parse(&self, config_file_name: &::std::path::Path, config_content: &[u8]) -> Result<Self, super::Error> {
+                self.parse_document(config_file_name, config_content)
+            }
+
+            fn parse_document<D: ::configure_me::helpers::ConfigDocument>(&self, config_file_name: &::std::path::Path, config_content: D) -> Result<Self, super::Error> {
+                ::configure_me::helpers::parse_table(config_content, &["check"])
+                    .map_err(|error| super::Error::ConfigParsing { file: config_file_name.into(), error })
+            }
+
+            /// Loads the file, reading and parsing it only once even if it's specified multiple times
+            pub fn load_once<P: Into<PathBuf>>(&mut self, config_file_name: P) -> Result<Self, super::Error> {
+                let config_file_name = config_file_name.into();
+                if let Some((_, table)) = self._parsed_files.iter().find(|(file, _)| *file == config_file_name) {
+                    return self.parse_document(&config_file_name, table.clone());
+                }
+
+                let config_content = ::std::fs::read(&config_file_name).map_err(|error| super::Error::Reading { file: config_file_name.clone(), error })?;
+                let table = ::configure_me::helpers::ConfigDocument::into_table(&*config_content)
+                    .map_err(|error| super::Error::ConfigParsing { file: config_file_name.clone(), error })?;
+                let config = self.parse_document(&config_file_name, table.clone())?;
+                self._loaded_files.push((config_file_name.clone(), ::configure_me::helpers::FileDigest::of(&config_content)));
+                self._parsed_files.push((config_file_name, table));
+                Ok(config)
+            }
+
+            pub fn validate(self) -> Result<super::Config, ValidationError> {
+                let path = self.path;
+
+                let mut missing_fields = Vec::new();
+                if path.is_none() {
+                    missing_fields.push(super::MissingField { name: "path", sources: &["PATH"] });
+                }
+                let path = match path {
+                    Some(path) => path,
+                    _ => return Err(ValidationError::MissingFields(missing_fields)),
+                };
+
+                Ok(super::Config {
+                    path,
+                })
+            }
+
+            pub fn merge_in(&mut self, other: Self) {
+            }
+
+            pub fn merge_args<I: IntoIterator<Item=::std::ffi::OsString>>(&mut self, args: I) -> Result<impl Iterator<Item=::std::ffi::OsString>, super::Error> {
+                let mut iter = args.into_iter().fuse().peekable();
+                self._program_path = iter.next().map(Into::into);
+
+                while let Some(arg) = iter.next() {
+                    if arg == *"--" {
+                        return Ok(None.into_iter().chain(iter));
+                    } else if (arg == *"--help") || (arg == *"-h") {
+                        return Err(ArgParseError::HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into()).into());
+                    } else if let Some(mut shorts) = ::configure_me::parse_arg::iter_short(&arg) {
+                        for short in &mut shorts {
+                            if short == 'h' {
+                                return Err(ArgParseError::HelpRequested(self._program_path.as_ref().unwrap().to_string_lossy().into()).into())
+                            } else {
+                                let mut arg = String::with_capacity(2);
+                                arg.push('-');
+                                arg.push(short);
+                                return Err(ArgParseError::UnknownArgument(arg).into());
+                            }
+                        }
+                    } else if arg.to_str().unwrap_or("").starts_with("--") {
+                        return Err(ArgParseError::UnknownArgument(arg.into_string().unwrap()).into());
+                    } else {
+                        return Ok(Some(arg).into_iter().chain(iter))
+                    }
+                }
+
+                Ok(None.into_iter().chain(iter))
+            }
+
+            pub fn merge_env(&mut self) -> Result<(), super::Error> {
+                Ok(())
+            }
+        }
+
+        impl Config {
+            pub fn merge_positionals<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<I, super::Error> {
+                if let Some(arg) = args.next() {
+                    self.path = Some(::configure_me::parse_arg::ParseArg::parse_owned_arg(arg).map_err(ArgParseError::FieldPath)?);
+                }
+                Ok(args)
+            }
+        }
+    }
+
+    /// Configuration of the application
+    pub struct Config {
+        pub path: ::std::path::PathBuf,
+    }
+
+    impl Config {
+        pub fn including_optional_config_files<I>(config_files: I) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+            Self::custom_args_and_optional_files(::std::env::args_os(), config_files)
+        }
+
+        pub fn custom_args_and_optional_files<A, I>(args: A, config_files: I) -> Result<(Self, impl Iterator<Item=::std::ffi::OsString>), Error> where
+            A: IntoIterator, A::Item: Into<::std::ffi::OsString>,
+            I: IntoIterator, I::Item: AsRef<::std::path::Path> {
+
+            let mut config = raw::Config::default();
+            for path in config_files {
+                match config.load_once(path.as_ref()) {
+                    Ok(mut new_config) => {
+                        new_config._loaded_files = ::std::mem::replace(&mut config._loaded_files, Vec::new());
+                        new_config._parsed_files = ::std::mem::replace(&mut config._parsed_files, Vec::new());
+                        std::mem::swap(&mut config, &mut new_config);
+                        config.merge_in(new_config)
+                    },
+                    Err(Error::Reading { ref error, .. }) if error.kind() == ::std::io::ErrorKind::NotFound => (),
+                    Err(err) => return Err(err),
+                }
+            }
+
+            config.merge_env()?;
+            let remaining_args = config.merge_args(args.into_iter().map(Into::into))?;
+            let remaining_args = config.merge_positionals(remaining_args)?;
+
+            config
+                .validate()
+                .map(|cfg| (cfg, remaining_args))
+                .map_err(Into::into)
+        }
+    }
+
+    pub trait ResultExt {
+        type Item;
+
+        fn unwrap_or_exit(self) -> Self::Item;
+    }
+
+    impl<T> ResultExt for Result<T, Error> {
+        type Item = T;
+
+        fn unwrap_or_exit(self) -> Self::Item {
+            use std::io::Write;
+
+            match self {
+                Ok(item) => item,
+                Err(err @ Error::Arguments(ArgParseError::HelpRequested(_))) => {
+                    println!("{}", err);
+                    std::io::stdout().flush().expect("failed to flush stdout");
+                    ::std::process::exit(0)
+                },
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    std::io::stderr().flush().expect("failed to flush stderr");
+                    ::std::process::exit(1)
+                }
+            }
+        }
+    }
+}
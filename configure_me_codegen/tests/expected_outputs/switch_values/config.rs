@@ -0,0 +1,2 @@
+    pub verbose: bool,
+    pub color: bool,
@@ -0,0 +1,2 @@
+        EnvParseError::FieldVerbose(ref err) => ::configure_me::helpers::display_switch_error(f, "SWITCH_VALUES_TEST_VERBOSE", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
+        EnvParseError::FieldColor(ref err) => ::configure_me::helpers::display_switch_error(f, "SWITCH_VALUES_TEST_COLOR", err, raw::TRUE_VALUES, raw::FALSE_VALUES),
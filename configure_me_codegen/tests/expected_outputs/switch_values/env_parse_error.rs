@@ -0,0 +1,2 @@
+    FieldVerbose(::std::ffi::OsString),
+    FieldColor(::std::ffi::OsString),
@@ -0,0 +1,6 @@
+        if let Some(val) = ::std::env::var_os("SWITCH_VALUES_TEST_VERBOSE") {
+            self.verbose = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldVerbose)?);
+        }
+        if let Some(val) = ::std::env::var_os("SWITCH_VALUES_TEST_COLOR") {
+            self.color = Some(::configure_me::helpers::parse_switch(val, TRUE_VALUES, FALSE_VALUES).map_err(super::EnvParseError::FieldColor)?);
+        }
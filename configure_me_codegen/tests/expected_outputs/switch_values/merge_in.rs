@@ -0,0 +1,6 @@
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
+            if other.color.is_some() {
+                self.color = other.color;
+            }
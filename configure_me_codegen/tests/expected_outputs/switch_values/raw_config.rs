@@ -0,0 +1,4 @@
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        color: Option<bool>,
@@ -0,0 +1,5 @@
+
+            Ok(super::Config {
+                verbose: self.verbose.unwrap_or(false),
+                color: self.color.unwrap_or(true),
+            })
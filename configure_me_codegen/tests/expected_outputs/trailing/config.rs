@@ -0,0 +1,3 @@
+    pub verbose: bool,
+    pub host: String,
+    pub ports: Vec<u16>,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-v|--verbose] HOST [-- PORTS...]\n\nPositional arguments:\n        HOST       Host to probe\n        PORTS      Ports to probe", program_name),
+        ArgParseError::FieldHost(err) => ::configure_me::helpers::display_arg_error::<String>(f, "HOST", err),
+        ArgParseError::FieldPorts(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "PORTS", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
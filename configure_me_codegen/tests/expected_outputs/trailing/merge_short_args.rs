@@ -0,0 +1,2 @@
+                        } else if short == 'v' {
+                            self.verbose = Some(true);
@@ -0,0 +1,6 @@
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
+        #[serde(skip)]
+        host: Option<String>,
+        #[serde(skip)]
+        ports: Option<Vec<u16>>,
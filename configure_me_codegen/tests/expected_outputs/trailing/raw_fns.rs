@@ -0,0 +1,27 @@
+
+    pub const TRUE_VALUES: &[&str] = &["1", "true"];
+    pub const FALSE_VALUES: &[&str] = &["0", "false"];
+
+    fn deserialize_switch<'de, D: ::configure_me::serde::Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        ::configure_me::helpers::deserialize_switch(deserializer, TRUE_VALUES, FALSE_VALUES)
+    }
+
+    impl Config {
+        pub fn merge_positionals<I: Iterator<Item=::std::ffi::OsString>>(&mut self, mut args: I) -> Result<impl Iterator<Item=::std::ffi::OsString>, super::Error> {
+            let mut leading = Vec::new();
+            for arg in &mut args {
+                if arg == *"--" {
+                    break;
+                }
+                leading.push(arg);
+            }
+            let mut leading = leading.into_iter();
+            if let Some(arg) = leading.next() {
+                self.host = Some(::configure_me::parse_arg::ParseArg::parse_owned_arg(arg).map_err(ArgParseError::FieldHost)?);
+            }
+            for arg in &mut args {
+                self.ports.get_or_insert_with(Vec::new).push(<::configure_me::helpers::Int<u16> as ::configure_me::parse_arg::ParseArg>::parse_owned_arg(arg).map_err(ArgParseError::FieldPorts)?.0);
+            }
+            Ok(leading.chain(args))
+        }
+    }
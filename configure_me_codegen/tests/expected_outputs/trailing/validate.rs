@@ -0,0 +1,17 @@
+            let host = self.host;
+            let ports = self.ports;
+
+            let mut missing_fields = Vec::new();
+            if host.is_none() {
+                missing_fields.push(super::MissingField { name: "host", sources: &["HOST"] });
+            }
+            let host = match host {
+                Some(host) => host,
+                _ => return Err(ValidationError::MissingFields(missing_fields)),
+            };
+
+            Ok(super::Config {
+                verbose: self.verbose.unwrap_or(false),
+                host,
+                ports: ports.unwrap_or_default(),
+            })
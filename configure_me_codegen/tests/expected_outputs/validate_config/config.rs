@@ -0,0 +1,2 @@
+    pub min_workers: u32,
+    pub max_workers: u32,
@@ -0,0 +1,3 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--min-workers MIN_WORKERS] [--max-workers MAX_WORKERS]", program_name),
+        ArgParseError::FieldMinWorkers(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u32>>(f, "--min-workers", err),
+        ArgParseError::FieldMaxWorkers(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u32>>(f, "--max-workers", err),
@@ -0,0 +1,2 @@
+        EnvParseError::FieldMinWorkers(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u32>>(f, "VALIDATE_CONFIG_MIN_WORKERS", err),
+        EnvParseError::FieldMaxWorkers(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u32>>(f, "VALIDATE_CONFIG_MAX_WORKERS", err),
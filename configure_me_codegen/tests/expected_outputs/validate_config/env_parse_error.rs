@@ -0,0 +1,2 @@
+    FieldMinWorkers(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
+    FieldMaxWorkers(<::configure_me::helpers::Int<u32> as ::configure_me::parse_arg::ParseArg>::Error),
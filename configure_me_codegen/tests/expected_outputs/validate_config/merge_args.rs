@@ -0,0 +1,8 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--min-workers", &arg, &mut iter) {
+                    let min_workers = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--min-workers"), ArgParseError::FieldMinWorkers))?.0;
+
+                    self.min_workers = Some(min_workers);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u32>, _, _>("--max-workers", &arg, &mut iter) {
+                    let max_workers = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--max-workers"), ArgParseError::FieldMaxWorkers))?.0;
+
+                    self.max_workers = Some(max_workers);
@@ -0,0 +1,2 @@
+        ::configure_me::helpers::merge(&mut self.min_workers, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u32>>("VALIDATE_CONFIG_MIN_WORKERS").map_err(super::EnvParseError::FieldMinWorkers)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.max_workers, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u32>>("VALIDATE_CONFIG_MAX_WORKERS").map_err(super::EnvParseError::FieldMaxWorkers)?.map(|value| value.0));
@@ -0,0 +1,2 @@
+            ::configure_me::helpers::merge(&mut self.min_workers, other.min_workers);
+            ::configure_me::helpers::merge(&mut self.max_workers, other.max_workers);
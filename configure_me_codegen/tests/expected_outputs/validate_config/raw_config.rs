@@ -0,0 +1,2 @@
+        min_workers: Option<u32>,
+        max_workers: Option<u32>,
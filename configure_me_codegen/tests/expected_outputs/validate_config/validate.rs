@@ -0,0 +1,11 @@
+            let min_workers = self.min_workers.unwrap_or_else(|| { 1 });
+            let max_workers = self.max_workers.unwrap_or_else(|| { 4 });
+
+            let config = super::Config {
+                min_workers: min_workers.into(),
+                max_workers: max_workers.into(),
+            };
+            if let Err(error) = crate::check_workers(&config) {
+                return Err(ValidationError::Custom(error.to_string()));
+            }
+            Ok(config)
@@ -0,0 +1,3 @@
+    pub port: u16,
+    pub user: Option<String>,
+    pub peer: Option<Vec<String>>,
@@ -0,0 +1,4 @@
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [--port PORT] [--user USER] [--peer PEER ...]", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::FieldUser(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--user", err),
+        ArgParseError::FieldPeer(err) => ::configure_me::helpers::display_arg_error::<String>(f, "--peer", err),
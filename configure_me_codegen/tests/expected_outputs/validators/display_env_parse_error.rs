@@ -0,0 +1,3 @@
+        EnvParseError::FieldPort(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u16>>(f, "VALIDATORS_PORT", err),
+        EnvParseError::FieldUser(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "VALIDATORS_USER", err),
+        EnvParseError::FieldPeer(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "VALIDATORS_PEER", err),
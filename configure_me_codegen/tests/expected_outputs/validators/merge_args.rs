@@ -0,0 +1,12 @@
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg::<::configure_me::helpers::Int<u16>, _, _>("--port", &arg, &mut iter) {
+                    let port = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--port"), ArgParseError::FieldPort))?.0;
+
+                    self.port = Some(port);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--user", &arg, &mut iter) {
+                    let user = value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--user"), ArgParseError::FieldUser))?;
+
+                    self.user = Some(user);
+                } else if let Some(value) = ::configure_me::parse_arg::match_arg("--peer", &arg, &mut iter) {
+                    let peer = vec![value.map_err(|err| err.map_or(ArgParseError::MissingArgument("--peer"), ArgParseError::FieldPeer))?];
+
+                    ::configure_me::helpers::merge_with(&mut self.peer, Some(peer), ::configure_me::helpers::append_values);
@@ -0,0 +1,3 @@
+        ::configure_me::helpers::merge(&mut self.port, ::configure_me::helpers::parse_env::<::configure_me::helpers::Int<u16>>("VALIDATORS_PORT").map_err(super::EnvParseError::FieldPort)?.map(|value| value.0));
+        ::configure_me::helpers::merge(&mut self.user, ::configure_me::helpers::parse_env("VALIDATORS_USER").map_err(super::EnvParseError::FieldUser)?);
+        ::configure_me::helpers::merge_with(&mut self.peer, ::configure_me::helpers::parse_env::<::configure_me::helpers::Delimited<String, ','>>("VALIDATORS_PEER").map_err(super::EnvParseError::FieldPeer)?.map(|values| values.0), ::configure_me::helpers::append_values);
@@ -0,0 +1,3 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            ::configure_me::helpers::merge(&mut self.user, other.user);
+            ::configure_me::helpers::merge_with(&mut self.peer, other.peer, ::configure_me::helpers::append_values);
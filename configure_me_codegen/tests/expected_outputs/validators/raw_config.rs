@@ -0,0 +1,3 @@
+        port: Option<u16>,
+        user: Option<String>,
+        peer: Option<Vec<String>>,
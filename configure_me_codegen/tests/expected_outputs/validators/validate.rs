@@ -0,0 +1,27 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+            let user = self.user;
+            let peer = self.peer;
+
+            for value in ::std::iter::once(&port) {
+                if let Err(message) = crate::validators::check_port(value) {
+                    return Err(ValidationError::Invalid { name: "port", message });
+                }
+            }
+
+            for value in user.iter() {
+                if let Err(message) = crate::validators::check_user(value) {
+                    return Err(ValidationError::Invalid { name: "user", message });
+                }
+            }
+
+            for value in peer.iter().flatten() {
+                if let Err(message) = crate::validators::check_user(value) {
+                    return Err(ValidationError::Invalid { name: "peer", message });
+                }
+            }
+
+            Ok(super::Config {
+                port: port.into(),
+                user: user.map(Into::into),
+                peer: peer.map(Into::into),
+            })
@@ -0,0 +1,2 @@
+    pub port: u16,
+    pub verbose: bool,
@@ -0,0 +1,4 @@
+            ArgParseError::VersionRequested(version) => write!(f, "{}", version),
+        ArgParseError::HelpRequested(program_name) => write!(f, "Usage: {} [-p PORT|--port PORT] [-v|--verbose]", program_name),
+        ArgParseError::FieldPort(err) => ::configure_me::helpers::display_arg_error::<::configure_me::helpers::Int<u16>>(f, "--port", err),
+        ArgParseError::InvalidSwitchValue(arg, value) => ::configure_me::helpers::display_switch_error(f, arg, value, raw::TRUE_VALUES, raw::FALSE_VALUES),
@@ -0,0 +1,4 @@
+            ::configure_me::helpers::merge(&mut self.port, other.port);
+            if other.verbose.is_some() {
+                self.verbose = other.verbose;
+            }
@@ -0,0 +1,8 @@
+                        } else if short == 'V' {
+                            let program_name: String = self._program_path.as_ref().unwrap().to_string_lossy().into();
+                            return Err(ArgParseError::VersionRequested(format!("{} {}", program_name, env!("CARGO_PKG_VERSION"))).into())
+                        } else if short == 'p' {
+                            self.port = Some(shorts.parse_remaining::<::configure_me::helpers::Int<u16>, _>(&mut iter).map_err(|err| err.map_or(ArgParseError::MissingArgument("-p"), ArgParseError::FieldPort))?.0);
+                            break;
+                        } else if short == 'v' {
+                            self.verbose = Some(true);
@@ -0,0 +1,3 @@
+        port: Option<u16>,
+        #[serde(default, deserialize_with = "deserialize_switch")]
+        verbose: Option<bool>,
@@ -0,0 +1,5 @@
+            Err(err @ Error::Arguments(ArgParseError::VersionRequested(_))) => {
+                println!("{}", err);
+                std::io::stdout().flush().expect("failed to flush stdout");
+                ::std::process::exit(0)
+            },
@@ -0,0 +1,6 @@
+            let port = self.port.unwrap_or_else(|| { 8080 });
+
+            Ok(super::Config {
+                port: port.into(),
+                verbose: self.verbose.unwrap_or(false),
+            })
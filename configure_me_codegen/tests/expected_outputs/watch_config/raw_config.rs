@@ -0,0 +1 @@
+        port: Option<u16>,
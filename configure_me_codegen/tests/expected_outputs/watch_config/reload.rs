@@ -0,0 +1,36 @@
+
+impl Config {
+    /// Loads the configuration again if any config file changed since it was loaded
+    ///
+    /// The same arguments and config files are used, environment variables are read again.
+    pub fn reload_if_changed(&self) -> Result<Option<Config>, Error> {
+        if !self._poll_state.changed() {
+            return Ok(None);
+        }
+
+        Self::custom_args_and_optional_files(self._poll_state.args().to_vec(), self._poll_state.optional_files())
+            .map(|(config, _)| Some(config))
+    }
+}
+
+impl Config {
+    /// Calls `on_change` with the configuration loaded again whenever a config file changes, until
+    /// the returned watcher is dropped
+    ///
+    /// `on_change` is called from another thread. Only the config files of this configuration are
+    /// watched, the changes are detected like in `reload_if_changed()`.
+    pub fn watch<F>(&self, mut on_change: F) -> Result<::configure_me::ConfigWatcher, ::std::io::Error> where F: FnMut(Result<Config, Error>) + Send + 'static {
+        let mut poll_state = self._poll_state.clone();
+        ::configure_me::helpers::watch_files(self._poll_state.files(), move || {
+            if !poll_state.changed() {
+                return;
+            }
+
+            let config = Self::custom_args_and_optional_files(poll_state.args().to_vec(), poll_state.optional_files()).map(|(config, _)| config);
+            if let Ok(config) = &config {
+                poll_state = config._poll_state.clone();
+            }
+            on_change(config);
+        })
+    }
+}
@@ -0,0 +1,6 @@
+            let port = self.port;
+
+            Ok(super::Config {
+                port: port.map(Into::into),
+                _poll_state: ::configure_me::helpers::PollState::new(&self._loaded_files),
+            })
@@ -1,10 +1,2 @@
-        EnvParseError::FieldFoo(ref err) => {
-            write!(f, "Failed to parse environment variable 'TEST_APP_FOO': {}.\n\nHint: the value must be ", err)?;
-            <u32 as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
-            write!(f, ".")
-        },
-        EnvParseError::FieldBar(ref err) => {
-            write!(f, "Failed to parse environment variable 'TEST_APP_BAR': {}.\n\nHint: the value must be ", err)?;
-            <String as ::configure_me::parse_arg::ParseArg>::describe_type(&mut *f)?;
-            write!(f, ".")
-        },
+        EnvParseError::FieldFoo(ref err) => ::configure_me::helpers::display_env_error::<::configure_me::helpers::Int<u32>>(f, "TEST_APP_FOO", err),
+        EnvParseError::FieldBar(ref err) => ::configure_me::helpers::display_env_error::<String>(f, "TEST_APP_BAR", err),
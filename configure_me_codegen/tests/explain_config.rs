@@ -0,0 +1,87 @@
+macro_rules! test_name { () => { "explain_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+use std::process::Command;
+
+const CHILD_ENV: &str = "CONFIGURE_ME_EXPLAIN_CONFIG_CHILD";
+
+fn config_files() -> PathBuf {
+    let mut this = PathBuf::from(std::env::args_os().next().expect("Program name not specified"));
+
+    while let Some(file_name) = this.file_name() {
+        if *file_name == *"target" {
+            break;
+        }
+
+        this.pop();
+    }
+
+    if !this.pop() {
+        panic!("Can't find test assets");
+    }
+
+    this.push("configure_me_codegen");
+    if !this.exists() {
+        this.pop();
+    }
+    this.push("tests");
+    this.push("config_files");
+    this
+}
+
+/// Parses the config in a child process running the `child` test and returns the notes it printed
+fn notes(explain: bool) -> Vec<String> {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "child", "--nocapture", "--test-threads=1"])
+        .env(CHILD_ENV, if explain { "explain" } else { "quiet" })
+        .env("EXPLAIN_CONFIG_TEST_PORT", "9090")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stderr)
+        .unwrap()
+        .lines()
+        .filter(|line| line.starts_with("Note: "))
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Does nothing unless started by `notes()`
+#[test]
+fn child() {
+    let explain = match std::env::var(CHILD_ENV) {
+        Ok(explain) => explain == "explain",
+        Err(_) => return,
+    };
+    let extra = config_files().join("explain_config_extra.toml");
+    let mut args = vec!["explain_config".to_owned(), "--config".to_owned(), extra.to_str().unwrap().to_owned(), "-p".to_owned(), "1".to_owned(), "-v".to_owned()];
+    if explain {
+        args.push("--explain-config".to_owned());
+    }
+    let (config, _) = config::Config::custom_args_and_optional_files(args, Some(config_files().join("explain_config.toml"))).unwrap();
+    assert_eq!(config.port, Some(1));
+    assert_eq!(config.host.as_ref().map(AsRef::as_ref), Some("localhost"));
+    assert!(config.verbose);
+    for override_ in config.overrides() {
+        eprintln!("Note: {}", override_);
+    }
+}
+
+#[test]
+fn overrides_are_explained() {
+    let file = config_files().join("explain_config.toml");
+    let extra = config_files().join("explain_config_extra.toml");
+    assert_eq!(notes(true), [
+        format!("Note: the value of 'port' from config file {} was overridden by environment variable EXPLAIN_CONFIG_TEST_PORT", file.display()),
+        format!("Note: the value of 'port' from environment variable EXPLAIN_CONFIG_TEST_PORT was overridden by config file {}", extra.display()),
+        format!("Note: the value of 'port' from config file {} was overridden by argument -p", extra.display()),
+        format!("Note: the value of 'verbose' from config file {} was overridden by argument -v", file.display()),
+    ]);
+}
+
+#[test]
+fn nothing_is_explained_without_switch() {
+    assert!(notes(false).is_empty());
+}
@@ -0,0 +1,46 @@
+macro_rules! test_name { () => { "export_args" } }
+
+include!("glue/boilerplate.rs");
+
+use std::ffi::OsString;
+use std::iter;
+use std::path::PathBuf;
+
+fn parse<A: IntoIterator>(args: A) -> config::Config where A::Item: Into<OsString> {
+    config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()).map(|(config, _)| config).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn args(config: &config::Config) -> Vec<String> {
+    config.to_args().into_iter().map(|arg| arg.into_string().unwrap()).collect()
+}
+
+fn round_trip(config: &config::Config) -> config::Config {
+    parse(iter::once(OsString::from("export_args")).chain(config.to_args()))
+}
+
+#[test]
+fn defaults() {
+    let config = parse(&["export_args", "--port", "443"]);
+    assert_eq!(args(&config), ["--port=443", "--offset=0", "--debug=0", "--no-tls=0"]);
+    assert_eq!(args(&round_trip(&config)), args(&config));
+}
+
+#[test]
+fn all_set() {
+    let config = parse(&["export_args", "--port", "443", "--offset", "-5", "--tls-cert", "/etc/cert.pem", "--peers", "a", "b", "--verbose", "--verbose", "--debug", "--no-tls"]);
+    assert_eq!(args(&config), ["--port=443", "--offset=-5", "--tls-cert=/etc/cert.pem", "--peers", "a", "b", "--verbose", "--verbose", "--debug", "--no-tls"]);
+
+    let reparsed = round_trip(&config);
+    assert_eq!(args(&reparsed), args(&config));
+    assert_eq!(reparsed.offset, -5);
+    assert_eq!(reparsed.peers, Some(["a".to_owned(), "b".to_owned()]));
+    assert_eq!(reparsed.verbose, 2);
+    assert!(!reparsed.tls);
+}
+
+#[test]
+fn params_without_arguments_are_skipped() {
+    let mut config = parse(&["export_args", "--port", "443"]);
+    config.secret = Some("hunter2".to_owned());
+    assert!(!args(&config).iter().any(|arg| arg.contains("hunter2")));
+}
@@ -0,0 +1,40 @@
+macro_rules! test_name { () => { "export_env_vars" } }
+
+include!("glue/boilerplate.rs");
+
+use std::ffi::OsString;
+use std::iter;
+use std::path::PathBuf;
+
+fn parse(args: &[&str]) -> config::Config {
+    config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()).map(|(config, _)| config).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn vars(config: &config::Config) -> Vec<(String, String)> {
+    config.to_env_vars().into_iter().map(|(name, value)| (name.into_string().unwrap(), value.into_string().unwrap())).collect()
+}
+
+fn pair(name: &str, value: &str) -> (String, String) {
+    (name.to_owned(), value.to_owned())
+}
+
+// A single test because it modifies the environment of the process
+#[test]
+fn export_env_vars() {
+    let config = parse(&["export_env_vars", "--port", "443", "--peers", "a", "b"]);
+    assert_eq!(vars(&config), [pair("EXPORT_PORT", "443"), pair("EXPORT_BIND_ADDR", "127.0.0.1"), pair("EXPORT_VERBOSE", "0"), pair("EXPORT_DEBUG", "0")]);
+
+    let config = parse(&["export_env_vars", "--port", "443", "--bind-addr", "::1", "--tls-cert", "/etc/cert.pem", "--verbose", "--verbose", "--debug", "--no-tls"]);
+    let exported = config.to_env_vars();
+    assert_eq!(vars(&config), [pair("EXPORT_PORT", "443"), pair("EXPORT_BIND_ADDR", "::1"), pair("EXPORT_TLS_CERT", "/etc/cert.pem"), pair("EXPORT_VERBOSE", "2"), pair("EXPORT_DEBUG", "1")]);
+
+    for (name, value) in &exported {
+        std::env::set_var(name, value);
+    }
+    let child = parse(&["export_env_vars"]);
+    assert_eq!(child.to_env_vars(), exported);
+    assert_eq!(child.bind_addr, config.bind_addr);
+    assert_eq!(child.tls_cert, Some(PathBuf::from("/etc/cert.pem")));
+    assert!(child.tls);
+    assert_eq!(exported.iter().find(|(name, _)| name == "EXPORT_TLS").map(|(_, value)| value), None::<&OsString>);
+}
@@ -0,0 +1,24 @@
+macro_rules! test_name { () => { "forward_separator" } }
+
+include!("glue/boilerplate.rs");
+
+use std::ffi::OsString;
+use std::iter;
+use std::path::PathBuf;
+
+fn remaining_args(args: &[&str]) -> Vec<OsString> {
+    let args = iter::once("forward_separator").chain(args.iter().cloned());
+    let (_, remaining) = config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()).unwrap();
+    remaining.collect()
+}
+
+#[test]
+fn separator_is_forwarded() {
+    assert_eq!(remaining_args(&["-v", "--", "child", "--", "-x"]), ["--", "child", "--", "-x"]);
+}
+
+#[test]
+fn no_separator() {
+    assert_eq!(remaining_args(&["-v", "child", "--", "-x"]), ["child", "--", "-x"]);
+    assert!(remaining_args(&["-v"]).is_empty());
+}
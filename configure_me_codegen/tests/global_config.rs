@@ -0,0 +1,22 @@
+macro_rules! test_name { () => { "global_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+// A single test because the global configuration can only be initialized once per process.
+#[test]
+fn global_config() {
+    assert!(config::Config::try_global().is_none());
+
+    let (config, _) = config::Config::custom_args_and_optional_files(&["global_config", "--port", "443"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(config.init_global().port, 443);
+    assert_eq!(config::Config::global().port, 443);
+    assert_eq!(config::Config::try_global().map(|config| config.port), Some(443));
+
+    let (config, _) = config::Config::custom_args_and_optional_files(&["global_config"], iter::empty::<PathBuf>()).unwrap();
+    let result = std::panic::catch_unwind(move || { config.init_global(); });
+    assert!(result.is_err());
+    assert_eq!(config::Config::global().port, 443);
+}
@@ -3,6 +3,7 @@ extern crate configure_me;
 
 mod config {
     #![allow(unused)]
+    #![allow(clippy::all)]
 
     include!(concat!(env!("OUT_DIR"), "/expected_outputs/", test_name!(), "-config.rs"));
 }
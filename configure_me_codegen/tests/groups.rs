@@ -0,0 +1,34 @@
+macro_rules! test_name { () => { "groups" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the group is violated").to_string()
+}
+
+#[test]
+fn valid_groups() {
+    let config = load(&["groups", "--token", "secret", "--host", "example.com", "--socket", "/run/app.sock", "--json"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.token.as_deref(), Some("secret"));
+    assert!(config.json);
+    assert!(!config.yaml);
+
+    let config = load(&["groups", "--token-file", "token.txt", "--host", "example.com"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.token_file.as_deref(), Some("token.txt"));
+    assert!(!config.json);
+}
+
+#[test]
+fn missing_member() {
+    assert_eq!(load_err(&["groups", "--host", "example.com"], &[]), "Invalid configuration: One of configuration parameters 'token', 'token_file' (group 'auth') must be set.");
+    assert_eq!(load_err(&["groups", "--token", "secret"], &[]), "Invalid configuration: One of configuration parameters 'host', 'socket' (group 'connection') must be set.");
+}
+
+#[test]
+fn too_many_members() {
+    assert_eq!(load_err(&["groups", "--json", "--yaml"], &[]), "Invalid configuration: At most one of configuration parameters in group 'format' can be set, but 'json' (from argument --json) and 'yaml' (from argument --yaml) are.");
+}
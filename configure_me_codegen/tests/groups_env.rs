@@ -0,0 +1,24 @@
+//! Has its own binary because it sets an env var, which the tests in `groups.rs` would see
+
+macro_rules! test_name { () => { "groups" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the group is violated").to_string()
+}
+
+#[test]
+fn conflict_reports_sources() {
+    let path = std::env::temp_dir().join(format!("configure_me_groups_{}.toml", std::process::id()));
+    std::fs::write(&path, "token = \"secret\"\n").unwrap();
+    std::env::set_var("GROUPS_TOKEN_FILE", "token.txt");
+    let err = load_err(&["groups", "--host", "example.com"], &[&path]);
+    std::env::remove_var("GROUPS_TOKEN_FILE");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err, format!("Invalid configuration: At most one of configuration parameters in group 'auth' can be set, but 'token' (from config file {}) and 'token_file' (from environment variable GROUPS_TOKEN_FILE) are.", path.display()));
+}
@@ -0,0 +1,52 @@
+#![cfg(unix)]
+
+macro_rules! test_name { () => { "insecure_config_files" } }
+
+include!("glue/boilerplate.rs");
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+fn config_file(name: &str, mode: u32) -> PathBuf {
+    let file = std::env::temp_dir().join(format!("configure_me_insecure_config_files_{}_{}.toml", name, std::process::id()));
+    std::fs::write(&file, "token = \"secret\"\n").unwrap();
+    std::fs::set_permissions(&file, std::fs::Permissions::from_mode(mode)).unwrap();
+    file
+}
+
+#[test]
+fn private_file_is_loaded() {
+    let file = config_file("private", 0o600);
+    let result = config::Config::custom_args_and_optional_files(&["insecure_config_files"], Some(&file));
+    std::fs::remove_file(&file).unwrap();
+    let (config, _) = result.unwrap();
+    assert_eq!(config.token, Some("secret".to_owned()));
+}
+
+#[test]
+fn world_writable_file_is_refused() {
+    let file = config_file("world_writable", 0o666);
+    let result = config::Config::custom_args_and_optional_files(&["insecure_config_files"], Some(&file));
+    std::fs::remove_file(&file).unwrap();
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), format!("Failed to read configuration file {}: the file is writable by all users", file.display())),
+    }
+}
+
+#[test]
+fn file_in_world_writable_dir_is_refused() {
+    let dir = std::env::temp_dir().join(format!("configure_me_insecure_config_files_dir_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let dir = dir.canonicalize().unwrap();
+    std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o777)).unwrap();
+    let file = dir.join("config.toml");
+    std::fs::write(&file, "token = \"secret\"\n").unwrap();
+    std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o600)).unwrap();
+    let result = config::Config::custom_args_and_optional_files(&["insecure_config_files"], Some(&file));
+    std::fs::remove_dir_all(&dir).unwrap();
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), format!("Failed to read configuration file {}: the file is in the directory {}, which is writable by all users", file.display(), dir.display())),
+    }
+}
@@ -0,0 +1,28 @@
+macro_rules! test_name { () => { "single_mandatory_param" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn parse(args: &[&str]) -> Result<u32, config::Error> {
+    config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()).map(|(config, _)| config.foo)
+}
+
+#[test]
+fn prefixed_args() {
+    assert_eq!(parse(&["int_radix", "--foo", "42"]).unwrap_or_else(|err| panic!("{}", err)), 42);
+    assert_eq!(parse(&["int_radix", "--foo", "0x1F"]).unwrap_or_else(|err| panic!("{}", err)), 0x1F);
+    assert_eq!(parse(&["int_radix", "--foo=0o755"]).unwrap_or_else(|err| panic!("{}", err)), 0o755);
+    assert_eq!(parse(&["int_radix", "--foo", "0b1010"]).unwrap_or_else(|err| panic!("{}", err)), 0b1010);
+    assert_eq!(parse(&["int_radix", "--foo", "+0XfF"]).unwrap_or_else(|err| panic!("{}", err)), 255);
+}
+
+#[test]
+fn invalid_args() {
+    for value in &["0x", "0xZZ", "0x+1", "0b2", "-0x1", "0x100000000"] {
+        assert!(parse(&["int_radix", "--foo", value]).is_err(), "{} was accepted", value);
+    }
+    let err = parse(&["int_radix", "--foo", "0x+1"]).unwrap_err();
+    assert!(err.to_string().starts_with("Failed to parse argument '--foo': the sign must go before the prefix of the notation."), "{}", err);
+}
@@ -0,0 +1,20 @@
+//! Has its own binary because it sets an env var, which the tests in `int_radix.rs` would see
+
+macro_rules! test_name { () => { "single_mandatory_param" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn parse(args: &[&str]) -> Result<u32, config::Error> {
+    config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()).map(|(config, _)| config.foo)
+}
+
+#[test]
+fn prefixed_env_var() {
+    std::env::set_var("TEST_APP_FOO", "0o17");
+    let result = parse(&["int_radix"]);
+    std::env::remove_var("TEST_APP_FOO");
+    assert_eq!(result.unwrap_or_else(|err| panic!("{}", err)), 0o17);
+}
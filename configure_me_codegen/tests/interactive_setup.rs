@@ -0,0 +1,63 @@
+macro_rules! test_name { () => { "interactive_setup" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+/// Runs the setup with `answers`, returning the questions, the written file and the loaded config
+fn setup(name: &str, answers: &str) -> (String, String, config::Config) {
+    let file = std::env::temp_dir().join(format!("configure_me_interactive_setup_{}_{}.toml", name, std::process::id()));
+    let mut output = Vec::new();
+    config::Config::interactive_setup_with(&file, answers.as_bytes(), &mut output).unwrap();
+    let content = std::fs::read_to_string(&file).unwrap();
+    let config = config::Config::custom_args_and_optional_files(&["interactive_setup"], &[&file])
+        .map(|(config, _)| config)
+        .unwrap_or_else(|err| panic!("{}", err));
+    std::fs::remove_file(&file).unwrap();
+    (String::from_utf8(output).unwrap(), content, config)
+}
+
+#[test]
+fn defaults() {
+    let (questions, content, config) = setup("defaults", "\n443\n\n\n\n\n\n");
+    assert!(questions.contains("Port to listen on\nport [required]: The value is required.\nport [required]: "));
+    assert!(questions.contains("bind_addr [default: 0.0.0.0]: "));
+    assert!(questions.contains("peers [space-separated, optional]: "));
+    assert_eq!(content, "# Port to listen on\nport = 443\n");
+    assert_eq!(config.port, 443);
+    assert!(config.tls);
+}
+
+#[test]
+fn all_answered() {
+    let (questions, content, config) = setup("all_answered", "443\n127.0.0.1\nmy \"app\"\na b\n2\nno\nfalse\n");
+    assert!(questions.contains("tls [default: true]: Invalid value: the allowed values are "));
+    assert_eq!(content, "# Port to listen on\nport = 443\n# IP address to bind to\nbind_addr = \"127.0.0.1\"\nname = \"my \\\"app\\\"\"\npeers = [\"a\", \"b\"]\nverbose = 2\n# Use TLS\ntls = false\n");
+    assert_eq!(config.bind_addr, "127.0.0.1".parse::<std::net::Ipv4Addr>().unwrap());
+    assert_eq!(config.name.as_ref().map(AsRef::as_ref), Some("my \"app\""));
+    assert_eq!(config.peers, Some(vec!["a".to_owned(), "b".to_owned()]));
+    assert_eq!(config.verbose, 2);
+    assert!(!config.tls);
+}
+
+#[test]
+fn invalid_value() {
+    let (questions, _, config) = setup("invalid_value", "100000\n443\n\n\n\n\n\n");
+    assert!(questions.contains("port [required]: Invalid value: "));
+    assert_eq!(config.port, 443);
+}
+
+#[test]
+fn input_ended() {
+    let file = std::env::temp_dir().join(format!("configure_me_interactive_setup_input_ended_{}.toml", std::process::id()));
+    let error = config::Config::interactive_setup_with(&file, "443\n".as_bytes(), Vec::new()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    assert!(!file.exists());
+}
+
+#[test]
+fn existing_file_is_kept() {
+    let file = PathBuf::from("Cargo.toml");
+    let error = config::Config::interactive_setup_with(&file, "443\n\n\n\n\n\n".as_bytes(), Vec::new()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::AlreadyExists);
+}
@@ -0,0 +1,59 @@
+macro_rules! test_name { () => { "json_config" } }
+
+include!("glue/boilerplate.rs");
+
+use configure_me::ConfigFormat;
+use std::path::PathBuf;
+
+fn write_config(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("configure_me_json_config_{}_{}", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+#[test]
+fn json_and_toml_files() {
+    let json = write_config("merged.json", r#"{ "port": 8080, "peers": "alice", "verbose": true }"#);
+    let toml = write_config("merged.toml", "peers = \"bob\"\n");
+    let config = load(&["json_config"], &[&json, &toml]);
+    std::fs::remove_file(&json).unwrap();
+    std::fs::remove_file(&toml).unwrap();
+    let config = config.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.peers.as_deref(), Some("bob,alice"));
+    assert!(config.verbose);
+}
+
+#[test]
+fn json_from_config_param() {
+    let json = write_config("param.JSON", r#"{ "port": 443 }"#);
+    let config = load(&["json_config", "--config", json.to_str().unwrap()], &[]);
+    std::fs::remove_file(&json).unwrap();
+    assert_eq!(config.unwrap_or_else(|err| panic!("{}", err)).port, 443);
+}
+
+#[test]
+fn explicit_format() {
+    let path = write_config("explicit.conf", r#"{ "port": 1234 }"#);
+    let as_json = config::Config::custom_args_and_optional_files_as(&["json_config"], &[&path], ConfigFormat::Json).map(|(config, _)| config.port);
+    let as_toml = load(&["json_config"], &[&path]).map(|config| config.port);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(as_json.unwrap_or_else(|err| panic!("{}", err)), 1234);
+    assert!(as_toml.is_err());
+}
+
+#[test]
+fn invalid_json() {
+    let not_object = write_config("array.json", "[1, 2]");
+    let malformed = write_config("malformed.json", r#"{ "port": "#);
+    let err = load(&["json_config"], &[&not_object]).err().expect("the config file isn't an object").to_string();
+    assert!(err.contains("the JSON config file must contain an object"), "{}", err);
+    let err = load(&["json_config"], &[&malformed]).err().expect("the config file is malformed").to_string();
+    assert!(err.starts_with("Failed to parse configuration file"), "{}", err);
+    std::fs::remove_file(&not_object).unwrap();
+    std::fs::remove_file(&malformed).unwrap();
+}
@@ -0,0 +1,57 @@
+macro_rules! test_name { () => { "profiles" } }
+
+include!("glue/boilerplate.rs");
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const LARGE: usize = 1024 * 1024;
+
+/// Counts allocations of at least `LARGE` bytes
+struct CountingAlloc;
+
+static LARGE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() >= LARGE {
+            LARGE_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size >= LARGE {
+            LARGE_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+// The only test in this file, so that no other test allocates concurrently.
+#[test]
+fn string_from_profile_config_file_is_allocated_once() {
+    let blob = "A".repeat(LARGE);
+    let file = std::env::temp_dir().join(format!("configure_me_large_profile_values_{}.toml", std::process::id()));
+    std::fs::write(&file, format!("host = \"{}\"\n\n[profile.prod]\nport = 443\n", blob)).unwrap();
+
+    LARGE_ALLOCATIONS.store(0, Ordering::SeqCst);
+    let result = config::Config::custom_args_and_optional_files(&["test", "--profile", "prod"], Some(&file));
+    let allocations = LARGE_ALLOCATIONS.load(Ordering::SeqCst);
+    std::fs::remove_file(&file).unwrap();
+
+    let (config, _) = result.unwrap();
+    assert_eq!(config.host.as_deref(), Some(&*blob));
+    assert_eq!(config.port, Some(443));
+    // The table with the profile applied is deserialized directly instead of being serialized
+    // and parsed again. The content, the parsed table and its copy kept in case the file is
+    // specified again are the only large allocations.
+    assert!(allocations <= 3, "{} large allocations", allocations);
+}
@@ -0,0 +1,55 @@
+macro_rules! test_name { () => { "multiple_params" } }
+
+include!("glue/boilerplate.rs");
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const LARGE: usize = 1024 * 1024;
+
+/// Counts allocations of at least `LARGE` bytes
+struct CountingAlloc;
+
+static LARGE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() >= LARGE {
+            LARGE_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size >= LARGE {
+            LARGE_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+// The only test in this file, so that no other test allocates concurrently.
+#[test]
+fn string_from_config_file_is_allocated_once() {
+    let blob = "A".repeat(LARGE);
+    let file = std::env::temp_dir().join(format!("configure_me_large_values_{}.toml", std::process::id()));
+    std::fs::write(&file, format!("baz = \"{}\"\n", blob)).unwrap();
+
+    LARGE_ALLOCATIONS.store(0, Ordering::SeqCst);
+    let result = config::Config::custom_args_and_optional_files(&["test"], Some(&file));
+    let allocations = LARGE_ALLOCATIONS.load(Ordering::SeqCst);
+    std::fs::remove_file(&file).unwrap();
+
+    let (config, _) = result.unwrap();
+    assert_eq!(config.baz, blob);
+    // One buffer for the file, one for the parsed file kept in case it's specified again and
+    // one for the value, which is then moved through merging and validation.
+    assert!(allocations <= 3, "{} large allocations", allocations);
+}
@@ -0,0 +1,55 @@
+macro_rules! test_name { () => { "lenient" } }
+
+include!("glue/boilerplate.rs");
+
+fn config_files() -> std::path::PathBuf {
+    let mut this = std::path::PathBuf::from(std::env::args_os().next().expect("Program name not specified"));
+
+    while let Some(file_name) = this.file_name() {
+        if *file_name == *"target" {
+            break;
+        }
+
+        this.pop();
+    }
+
+    if !this.pop() {
+        panic!("Can't find test assets");
+    }
+
+    this.push("configure_me_codegen");
+    if !this.exists() {
+        this.pop();
+    }
+    this.push("tests");
+    this.push("config_files");
+    this
+}
+
+#[test]
+fn converts_integers_and_strings() {
+    let file = config_files().join("lenient.toml");
+    let (config, _) = config::Config::custom_args_and_optional_files(&["test"], Some(&file)).unwrap();
+    assert_eq!(config.port, Some(8080));
+    assert_eq!(config.name.as_ref().map(AsRef::as_ref), Some("42"));
+    assert_eq!(config.raw, Some("47".into()));
+}
+
+#[test]
+fn converts_whole_floats() {
+    let file = config_files().join("lenient_float.toml");
+    let (config, _) = config::Config::custom_args_and_optional_files(&["test"], Some(&file)).unwrap();
+    assert_eq!(config.port, Some(8080));
+}
+
+#[test]
+fn rejects_lossy_conversion() {
+    let file = config_files().join("lenient_invalid.toml");
+    let err = config::Config::custom_args_and_optional_files(&["test"], Some(&file)).err().expect("parsing should fail");
+    assert!(err.to_string().contains("port"), "{}", err);
+}
+
+#[test]
+fn arguments_are_unaffected() {
+    assert!(config::Config::custom_args_and_optional_files(&["test", "--port", "8080.0"], None::<&str>).is_err());
+}
@@ -0,0 +1,28 @@
+macro_rules! test_name { () => { "loaded_config_files" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+fn write_config(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("configure_me_loaded_config_files_{}_{}.toml", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn config_params_are_layered() {
+    let default = write_config("default", "port = 1\nhost = \"default\"\n");
+    let first = write_config("first", "port = 2\nhost = \"first\"\n");
+    let second = write_config("second", "port = 3\n");
+    let missing = std::env::temp_dir().join(format!("configure_me_loaded_config_files_{}_missing.toml", std::process::id()));
+    let args = ["loaded_config_files", "--config", first.to_str().unwrap(), "--config", second.to_str().unwrap()];
+    let config = config::Config::custom_args_and_optional_files(&args, &[&default, &missing]).map(|(config, _)| config);
+    for file in [&default, &first, &second] {
+        std::fs::remove_file(file).unwrap();
+    }
+    let config = config.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, Some(3));
+    assert_eq!(config.host.as_deref(), Some("first"));
+    assert_eq!(config.loaded_config_files(), [default, first, second]);
+}
@@ -0,0 +1,93 @@
+macro_rules! test_name { () => { "long_options" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn exact() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "--port", "42", "--no-color"], iter::empty::<PathBuf>()).unwrap();
+    assert!(tail.next().is_none());
+
+    assert_eq!(cfg.port, Some(42));
+    assert!(!cfg.color);
+}
+
+#[test]
+fn unambiguous_prefix() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "--port-r", "42", "--bind=localhost", "--verb", "--no"], iter::empty::<PathBuf>()).unwrap();
+    assert!(tail.next().is_none());
+
+    assert_eq!(cfg.port_range, Some(42));
+    assert_eq!(cfg.bind_addr, Some("localhost".to_owned()));
+    assert!(cfg.verbose);
+    assert!(!cfg.color);
+}
+
+#[test]
+fn ambiguous_prefix() {
+    let result = config::Config::custom_args_and_optional_files(&["app", "--po", "42"], iter::empty::<PathBuf>());
+    match result {
+        Err(config::Error::Arguments(config::ArgParseError::AmbiguousArgument(arg, candidates))) => {
+            assert_eq!(arg, "--po");
+            assert_eq!(candidates, ["--port", "--port-range"]);
+        },
+        _ => panic!("ambiguity not detected"),
+    }
+}
+
+#[test]
+fn values_are_not_expanded() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "--bind-addr", "--verb", "--", "--port"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(tail.next(), Some("--port".into()));
+
+    assert_eq!(cfg.bind_addr, Some("--verb".to_owned()));
+    assert!(!cfg.verbose);
+}
+
+#[test]
+fn unknown() {
+    let result = config::Config::custom_args_and_optional_files(&["app", "--portal"], iter::empty::<PathBuf>());
+    match result {
+        Err(config::Error::Arguments(config::ArgParseError::UnknownArgument(arg))) => assert_eq!(arg, "--portal"),
+        _ => panic!("unknown argument not detected"),
+    }
+}
+
+#[test]
+fn ignores_case() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "--Port", "42", "--BIND-ADDR=Localhost", "--No-Col"], iter::empty::<PathBuf>()).unwrap();
+    assert!(tail.next().is_none());
+
+    assert_eq!(cfg.port, Some(42));
+    assert_eq!(cfg.bind_addr, Some("Localhost".to_owned()));
+    assert!(!cfg.color);
+}
+
+#[test]
+fn slash_options() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "/port", "42", "/Bind-Addr:localhost", "/verbose", "/tmp"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(tail.next(), Some("/tmp".into()));
+
+    assert_eq!(cfg.port, Some(42));
+    assert_eq!(cfg.bind_addr, Some("localhost".to_owned()));
+    assert!(cfg.verbose);
+}
+
+#[test]
+fn slash_help() {
+    let result = config::Config::custom_args_and_optional_files(&["app", "/?"], iter::empty::<PathBuf>());
+    match result {
+        Err(config::Error::Arguments(config::ArgParseError::HelpRequested(_))) => (),
+        _ => panic!("help not requested"),
+    }
+}
+
+#[test]
+fn slash_options_are_not_prefixes() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "/verb"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(tail.next(), Some("/verb".into()));
+
+    assert!(!cfg.verbose);
+}
@@ -0,0 +1,55 @@
+macro_rules! test_name { () => { "map" } }
+
+include!("glue/boilerplate.rs");
+
+use std::collections::HashMap;
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_ok(args: &[&str], files: &[&std::path::Path]) -> config::Config {
+    load(args, files).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn map<T: Clone>(entries: &[(&str, T)]) -> HashMap<String, T> {
+    entries.iter().map(|(key, value)| (key.to_string(), value.clone())).collect()
+}
+
+// A single test, so that the environment isn't modified concurrently.
+#[test]
+fn map_params() {
+    let config = load_ok(&["map"], &[]);
+    assert!(config.label.is_none());
+    assert!(config.limit.is_empty());
+
+    let config = load_ok(&["map", "--label", "team=core", "-l", "tier=gold=1", "--label=team=infra", "--limit", "cpu=0x10"], &[]);
+    assert_eq!(config.label.as_ref().unwrap(), &map(&[("team", "infra".to_owned()), ("tier", "gold=1".to_owned())]));
+    assert_eq!(config.limit, map(&[("cpu", 16)]));
+    assert_eq!(config.to_args(), ["--label=team=infra", "--label=tier=gold=1", "--limit=cpu=16"]);
+
+    let err = load(&["map", "--label", "team"], &[]).err().expect("missing separator is rejected");
+    assert!(err.to_string().contains("Failed to parse argument '--label': the key isn't followed by '='"), "{}", err);
+
+    let path = std::env::temp_dir().join(format!("configure_me_map_{}.toml", std::process::id()));
+    std::fs::write(&path, "[label]\nteam = \"core\"\nregion = \"eu\"\n\n[limit]\nmemory = 512\n").unwrap();
+    let config = load_ok(&["map", "--label", "team=infra"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(config.label.as_ref().unwrap(), &map(&[("team", "infra".to_owned()), ("region", "eu".to_owned())]));
+    assert_eq!(config.limit, map(&[("memory", 512)]));
+
+    std::env::set_var("MAP_LABEL_TEAM", "env");
+    std::env::set_var("MAP_LIMIT_CPU", "4");
+    let config = load_ok(&["map", "-l", "owner=me"], &[]);
+    assert_eq!(config.label.as_ref().unwrap(), &map(&[("team", "env".to_owned()), ("owner", "me".to_owned())]));
+    assert_eq!(config.limit, map(&[("cpu", 4)]));
+    let vars = config.to_env_vars();
+    assert!(vars.contains(&("MAP_LABEL_OWNER".into(), "me".into())));
+    assert!(vars.contains(&("MAP_LIMIT_CPU".into(), "4".into())));
+
+    std::env::set_var("MAP_LIMIT_CPU", "many");
+    let err = load(&["map"], &[]).err().expect("invalid value is rejected");
+    assert!(err.to_string().contains("Failed to parse environment variable 'MAP_LIMIT_CPU'"), "{}", err);
+    std::env::remove_var("MAP_LIMIT_CPU");
+    std::env::remove_var("MAP_LABEL_TEAM");
+}
@@ -0,0 +1,79 @@
+macro_rules! test_name { () => { "merge_report" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+use config::ReportSource;
+
+type Field = (&'static str, bool, Vec<(ReportSource, String)>);
+
+fn report<E: IntoIterator<Item=(&'static str, &'static str)>>(args: &[&str], env: E, files: &[PathBuf]) -> Vec<Field> {
+    config::Config::explain(args, env, files)
+        .unwrap_or_else(|err| panic!("{}", err))
+        .fields
+        .into_iter()
+        .map(|field| (field.name, field.combined, field.contributions.into_iter().map(|contribution| (contribution.source, contribution.value)).collect()))
+        .collect()
+}
+
+fn write_config(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("configure_me_merge_report_{}_{}.toml", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn empty() {
+    let fields = report(&["merge_report"], iter::empty(), &[]);
+    assert_eq!(fields, [
+        ("port", false, vec![]),
+        ("peers", false, vec![]),
+        ("tags", true, vec![]),
+        ("password", false, vec![]),
+        ("verbose", true, vec![]),
+        ("debug", false, vec![]),
+    ]);
+}
+
+#[test]
+fn arguments_win() {
+    let fields = report(&["merge_report", "--port", "443", "--peers", "a", "b", "--verbose", "--verbose"], vec![("REPORT_PORT", "80"), ("REPORT_DEBUG", "true")], &[]);
+    assert_eq!(fields[0].2, [(ReportSource::Environment, "80".to_owned()), (ReportSource::Arguments, "443".to_owned())]);
+    assert_eq!(fields[1].2, [(ReportSource::Arguments, "a b".to_owned())]);
+    assert_eq!(fields[4].2, [(ReportSource::Arguments, "2".to_owned())]);
+    assert_eq!(fields[5].2, [(ReportSource::Environment, "true".to_owned())]);
+}
+
+#[test]
+fn files_in_priority_order() {
+    let high = write_config("high", "port = 1\npassword = \"hunter2\"\n");
+    let low = write_config("low", "port = 2\ndebug = true\n");
+    let missing = std::env::temp_dir().join(format!("configure_me_merge_report_missing_{}.toml", std::process::id()));
+    let fields = report(&["merge_report"], vec![("REPORT_PASSWORD", "secret")], &[high.clone(), missing, low.clone()]);
+    let _ = std::fs::remove_file(&high);
+    let _ = std::fs::remove_file(&low);
+
+    assert_eq!(fields[0].2, [(ReportSource::File(low.clone()), "2".to_owned()), (ReportSource::File(high.clone()), "1".to_owned())]);
+    assert_eq!(fields[3].2, [(ReportSource::File(high), "<redacted>".to_owned()), (ReportSource::Environment, "<redacted>".to_owned())]);
+    assert_eq!(fields[5].2, [(ReportSource::File(low), "true".to_owned())]);
+}
+
+#[test]
+fn winner() {
+    let report = config::Config::explain(&["merge_report", "--port", "443"], vec![("REPORT_PORT", "80")], iter::empty::<PathBuf>()).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(report.fields[0].winner().map(|contribution| &*contribution.value), Some("443"));
+    assert!(report.fields[1].winner().is_none());
+}
+
+#[test]
+fn invalid_env_var() {
+    assert!(config::Config::explain(&["merge_report"], vec![("REPORT_PORT", "x")], iter::empty::<PathBuf>()).is_err());
+}
+
+#[test]
+fn combined() {
+    let fields = report(&["merge_report", "--tags", "b"], vec![("REPORT_TAGS", "a")], &[]);
+    assert_eq!(fields[2], ("tags", true, vec![(ReportSource::Environment, "a".to_owned()), (ReportSource::Arguments, "b".to_owned())]));
+}
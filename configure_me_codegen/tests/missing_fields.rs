@@ -0,0 +1,28 @@
+macro_rules! test_name { () => { "missing_fields" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn all_missing_fields_are_reported() {
+    let result = config::Config::custom_args_and_optional_files(&["missing_fields"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(err) => assert_eq!(err.to_string(), "Invalid configuration: Configuration parameters 'host' (--host, MISSING_FIELDS_TEST_HOST), 'port' (--port, MISSING_FIELDS_TEST_PORT), 'token' (MISSING_FIELDS_TEST_TOKEN), 'secret' not specified."),
+    }
+}
+
+#[test]
+fn only_remaining_fields_are_reported() {
+    let result = config::Config::custom_args_and_optional_files(&["missing_fields", "--host", "localhost"], iter::empty::<PathBuf>());
+    match result {
+        Ok(_) => panic!("This shouldn't succeed"),
+        Err(config::Error::Validation(config::ValidationError::MissingFields(fields))) => {
+            let names = fields.iter().map(|field| field.name).collect::<Vec<_>>();
+            assert_eq!(names, ["port", "token", "secret"]);
+        },
+        Err(err) => panic!("Unexpected error: {}", err),
+    }
+}
@@ -0,0 +1,37 @@
+macro_rules! test_name { () => { "multiple" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> config::Config {
+    config::Config::custom_args_and_optional_files(args, files)
+        .map(|(config, _)| config)
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+// A single test, so that the environment isn't modified concurrently.
+#[test]
+fn multiple() {
+    let config = load(&["multiple"], &[]);
+    assert!(config.tag.is_none());
+    assert_eq!(config.port, [8080]);
+
+    let config = load(&["multiple", "-t", "a", "--tag", "b", "--port=1", "-p2"], &[]);
+    assert_eq!(config.tag.unwrap(), ["a", "b"]);
+    assert_eq!(config.port, [1, 2]);
+
+    let path = std::env::temp_dir().join(format!("configure_me_multiple_{}.toml", std::process::id()));
+    std::fs::write(&path, "tag = [\"file\"]\nport = [1, 2]\n").unwrap();
+    let config = load(&["multiple", "--tag", "arg", "--port", "3", "--port", "4"], &[&path]);
+    assert_eq!(config.tag.unwrap(), ["file", "arg"]);
+    assert_eq!(config.port, [3, 4]);
+
+    std::env::set_var("MULTIPLE_TAG", "env1,env2");
+    std::env::set_var("MULTIPLE_PORT", "5:0x6");
+    let config = load(&["multiple"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(config.tag.unwrap(), ["file", "env1", "env2"]);
+    assert_eq!(config.port, [5, 6]);
+
+    std::env::set_var("MULTIPLE_PORT", "");
+    assert!(load(&["multiple"], &[]).port.is_empty());
+}
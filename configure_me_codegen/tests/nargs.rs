@@ -0,0 +1,51 @@
+macro_rules! test_name { () => { "nargs" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+#[test]
+fn fixed() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "--point", "3", "-4", "rest"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(tail.next(), Some("rest".into()));
+
+    assert_eq!(cfg.point, Some([3, -4]));
+    assert!(cfg.files.is_none());
+}
+
+#[test]
+fn fixed_short_coalesced() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "-vp3", "4"], iter::empty::<PathBuf>()).unwrap();
+    assert!(tail.next().is_none());
+
+    assert!(cfg.verbose);
+    assert_eq!(cfg.point, Some([3, 4]));
+}
+
+#[test]
+fn fixed_missing_value() {
+    let result = config::Config::custom_args_and_optional_files(&["app", "--point", "3"], iter::empty::<PathBuf>());
+    match result {
+        Err(config::Error::Arguments(config::ArgParseError::MissingArgument("--point"))) => (),
+        _ => panic!("missing value not detected"),
+    }
+}
+
+#[test]
+fn bounded_stops_at_option() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "--files", "a", "b", "--verbose"], iter::empty::<PathBuf>()).unwrap();
+    assert!(tail.next().is_none());
+
+    assert_eq!(cfg.files, Some(vec!["a".to_owned(), "b".to_owned()]));
+    assert!(cfg.verbose);
+}
+
+#[test]
+fn bounded_stops_at_max() {
+    let (cfg, mut tail) = config::Config::custom_args_and_optional_files(&["app", "--files=a", "b", "c", "d"], iter::empty::<PathBuf>()).unwrap();
+    assert_eq!(tail.next(), Some("d".into()));
+    assert!(tail.next().is_none());
+
+    assert_eq!(cfg.files, Some(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]));
+}
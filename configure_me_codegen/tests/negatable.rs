@@ -0,0 +1,38 @@
+macro_rules! test_name { () => { "negatable" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], content: &str) -> Result<config::Config, config::Error> {
+    let path = std::env::temp_dir().join(format!("configure_me_negatable_{}_{}.toml", std::process::id(), args.len()));
+    std::fs::write(&path, content).unwrap();
+    let config = config::Config::custom_args_and_optional_files(args, &[&path]).map(|(config, _)| config);
+    std::fs::remove_file(&path).unwrap();
+    config
+}
+
+#[test]
+fn negation_overrides_config_file() {
+    let config = load(&["negatable", "--no-verbose", "--no-colour"], "verbose = true\ncolor = true\n").unwrap_or_else(|err| panic!("{}", err));
+    assert!(!config.verbose);
+    assert!(!config.color);
+
+    let config = load(&["negatable"], "verbose = true\ncolor = true\n").unwrap_or_else(|err| panic!("{}", err));
+    assert!(config.verbose);
+    assert!(config.color);
+}
+
+#[test]
+fn last_occurrence_wins() {
+    let config = load(&["negatable", "--no-verbose", "-v"], "").unwrap_or_else(|err| panic!("{}", err));
+    assert!(config.verbose);
+    let config = load(&["negatable", "--verbose", "--no-verbose"], "").unwrap_or_else(|err| panic!("{}", err));
+    assert!(!config.verbose);
+}
+
+#[test]
+fn only_negatable_switches_are_negated() {
+    let err = load(&["negatable", "--no-dry-run"], "").err().expect("--no-dry-run is unknown");
+    assert!(matches!(err, config::Error::Arguments(config::ArgParseError::UnknownArgument(_))), "{}", err);
+    let err = load(&["negatable", "--no-debug"], "").err().expect("--no-debug is unknown");
+    assert!(matches!(err, config::Error::Arguments(config::ArgParseError::UnknownArgument(_))), "{}", err);
+}
@@ -0,0 +1,44 @@
+macro_rules! test_name { () => { "non_default_fields" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+
+fn non_default_fields(args: &[&str]) -> Vec<(&'static str, Option<String>, Option<String>)> {
+    config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>())
+        .map(|(config, _)| config)
+        .unwrap_or_else(|err| panic!("{}", err))
+        .non_default_fields()
+        .into_iter()
+        .map(|field| (field.name, field.default, field.value))
+        .collect()
+}
+
+fn some(value: &str) -> Option<String> {
+    Some(value.to_owned())
+}
+
+#[test]
+fn defaults() {
+    assert_eq!(non_default_fields(&["non_default_fields", "--port", "443"]), [("port", None, some("443"))]);
+}
+
+#[test]
+fn same_as_defaults() {
+    assert_eq!(non_default_fields(&["non_default_fields", "--port", "443", "--timeout", "30", "--peers", "a", "b", "--no-tls=false"]), [("port", None, some("443"))]);
+}
+
+#[test]
+fn changed() {
+    let fields = non_default_fields(&["non_default_fields", "--port", "443", "--timeout", "5", "--peers", "a", "c", "--password", "hunter2", "--verbose", "--debug", "--no-tls"]);
+    assert_eq!(fields, [
+        ("port", None, some("443")),
+        ("timeout", some("30"), some("5")),
+        ("peers", some("a b"), some("a c")),
+        ("password", None, some("<redacted>")),
+        ("verbose", some("0"), some("1")),
+        ("debug", some("false"), some("true")),
+        ("tls", some("true"), some("false")),
+    ]);
+}
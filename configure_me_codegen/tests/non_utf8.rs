@@ -0,0 +1,65 @@
+#![cfg(unix)]
+
+macro_rules! test_name { () => { "non_utf8" } }
+
+include!("glue/boilerplate.rs");
+
+use std::ffi::OsString;
+use std::os::unix::ffi::OsStringExt;
+use std::path::PathBuf;
+
+fn config_files() -> PathBuf {
+    let mut this = PathBuf::from(std::env::args_os().next().expect("Program name not specified"));
+
+    while let Some(file_name) = this.file_name() {
+        if *file_name == *"target" {
+            break;
+        }
+
+        this.pop();
+    }
+
+    if !this.pop() {
+        panic!("Can't find test assets");
+    }
+
+    this.push("configure_me_codegen");
+    if !this.exists() {
+        this.pop();
+    }
+    this.push("tests");
+    this.push("config_files");
+    this
+}
+
+fn non_utf8(prefix: &str) -> OsString {
+    let mut bytes = prefix.as_bytes().to_vec();
+    bytes.extend_from_slice(b"caf\xe9");
+    OsString::from_vec(bytes)
+}
+
+fn parse(args: Vec<OsString>) -> Result<config::Config, config::Error> {
+    let args = Some(OsString::from("test")).into_iter().chain(args);
+    config::Config::custom_args_and_optional_files(args, None::<&str>).map(|(config, _)| config)
+}
+
+#[test]
+fn arguments() {
+    let config = parse(vec!["--path".into(), non_utf8(""), "--raw".into(), non_utf8("")]).unwrap();
+    assert_eq!(config.path, Some(PathBuf::from(non_utf8(""))));
+    assert_eq!(config.raw, Some(non_utf8("")));
+
+    let config = parse(vec![non_utf8("--path="), non_utf8("--raw=")]).unwrap();
+    assert_eq!(config.path, Some(PathBuf::from(non_utf8(""))));
+    assert_eq!(config.raw, Some(non_utf8("")));
+
+    let config = parse(vec![non_utf8("-p")]).unwrap();
+    assert_eq!(config.path, Some(PathBuf::from(non_utf8(""))));
+}
+
+#[test]
+fn config_file() {
+    let config = parse(vec!["--config".into(), config_files().join("non_utf8.toml").into()]).unwrap();
+    assert_eq!(config.path, Some(PathBuf::from("/etc/app.conf")));
+    assert_eq!(config.raw, Some(OsString::from("value")));
+}
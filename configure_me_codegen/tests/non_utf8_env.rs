@@ -0,0 +1,35 @@
+//! Has its own binary because it sets env vars, which the tests in `non_utf8.rs` would see
+
+#![cfg(unix)]
+
+macro_rules! test_name { () => { "non_utf8" } }
+
+include!("glue/boilerplate.rs");
+
+use std::ffi::OsString;
+use std::os::unix::ffi::OsStringExt;
+use std::path::PathBuf;
+
+fn non_utf8(prefix: &str) -> OsString {
+    let mut bytes = prefix.as_bytes().to_vec();
+    bytes.extend_from_slice(b"caf\xe9");
+    OsString::from_vec(bytes)
+}
+
+fn parse(args: Vec<OsString>) -> Result<config::Config, config::Error> {
+    let args = Some(OsString::from("test")).into_iter().chain(args);
+    config::Config::custom_args_and_optional_files(args, None::<&str>).map(|(config, _)| config)
+}
+
+#[test]
+fn env_vars() {
+    std::env::set_var("NON_UTF8_TEST_PATH", non_utf8(""));
+    std::env::set_var("NON_UTF8_TEST_RAW", non_utf8(""));
+    let result = parse(Vec::new());
+    std::env::remove_var("NON_UTF8_TEST_PATH");
+    std::env::remove_var("NON_UTF8_TEST_RAW");
+
+    let config = result.unwrap();
+    assert_eq!(config.path, Some(PathBuf::from(non_utf8(""))));
+    assert_eq!(config.raw, Some(non_utf8("")));
+}
@@ -0,0 +1,69 @@
+//! Benchmark of parsing arguments and env vars compared to starting a process
+//!
+//! Ignored by default, run it with `cargo test --release --test parse_cost -- --ignored --nocapture`
+
+macro_rules! test_name { () => { "multiple_params" } }
+
+include!("glue/boilerplate.rs");
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Counts all allocations
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+const PARSES: u32 = 100_000;
+const PROCESSES: u32 = 100;
+
+// The only test in this file, so that no other test allocates concurrently.
+#[test]
+#[ignore]
+fn parse_cost() {
+    let args = ["test", "--foo", "1", "--bar", "bar", "--baz", "baz", "--verbose", "--no-fast"];
+    std::env::set_var("TEST_APP_BAR", "from env");
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..PARSES {
+        let (config, _) = config::Config::custom_args_and_optional_files(&args, None::<&str>).unwrap();
+        assert_eq!(config.foo, 1);
+    }
+    let parse = start.elapsed() / PARSES;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / f64::from(PARSES);
+    std::env::remove_var("TEST_APP_BAR");
+
+    // Starting this binary only to list no tests approximates starting a small Rust program
+    let exe = std::env::current_exe().unwrap();
+    let start = Instant::now();
+    for _ in 0..PROCESSES {
+        let status = std::process::Command::new(&exe).args(["--list", "--exact", "none"]).output().unwrap().status;
+        assert!(status.success());
+    }
+    let process = start.elapsed() / PROCESSES;
+
+    println!("parsing {} arguments and 1 env var: {:?} and {:.1} allocations", args.len() - 1, parse, allocations);
+    println!("starting a process: {:?}", process);
+    assert!(parse < process / 10, "parsing takes {:?}, starting a process {:?}", parse, process);
+}
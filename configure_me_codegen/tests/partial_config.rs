@@ -0,0 +1,36 @@
+macro_rules! test_name { () => { "partial_config" } }
+
+include!("glue/boilerplate.rs");
+
+use config::PartialConfig;
+
+#[test]
+fn layers_are_merged_by_priority() {
+    let path = std::env::temp_dir().join(format!("configure_me_partial_config_{}.toml", std::process::id()));
+    std::fs::write(&path, "port = 1\nhost = \"file\"\n").unwrap();
+    let file = PartialConfig::from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    let file = file.unwrap_or_else(|err| panic!("{}", err));
+
+    let database = PartialConfig::from_toml_str("database", "host = \"database\"\nverbose = true\n").unwrap_or_else(|err| panic!("{}", err));
+    let (args, mut remaining_args) = PartialConfig::from_args(&["partial_config", "--port", "3", "input", "rest"]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(remaining_args.next().as_deref(), Some("rest".as_ref()));
+
+    let config = file.merge(database).merge(args).try_finalize().unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 3);
+    assert_eq!(config.host, "database");
+    assert!(config.verbose);
+    assert_eq!(config.input.as_deref(), Some("input"));
+}
+
+#[test]
+fn finalizing_applies_defaults_and_validates() {
+    let config = PartialConfig::from_toml_str("database", "host = \"database\"\n").unwrap().try_finalize().unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 8080);
+
+    let err = PartialConfig::default().try_finalize().err().expect("host is missing");
+    assert!(matches!(err, config::Error::Validation(config::ValidationError::MissingFields(_))), "{}", err);
+
+    let err = PartialConfig::from_toml_str("database", "port = \"invalid\"\n").err().expect("invalid port");
+    assert!(err.to_string().starts_with("Failed to parse configuration file database"), "{}", err);
+}
@@ -0,0 +1,29 @@
+macro_rules! test_name { () => { "patterns" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the value doesn't match").to_string()
+}
+
+#[test]
+fn matching_values() {
+    let config = load(&["patterns", "--slug", "my-project-2", "--branch", "release/next", "--tag", "v1", "--tag", "v22"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.slug, "my-project-2");
+    assert_eq!(config.branch, "release/next");
+    assert_eq!(config.tag, Some(vec!["v1".to_owned(), "v22".to_owned()]));
+
+    let config = load(&["patterns", "--slug", "x"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.branch, "main");
+    assert_eq!(config.tag, None);
+}
+
+#[test]
+fn mismatching_values() {
+    assert_eq!(load_err(&["patterns", "--slug", "My Project"], &[]), "Invalid configuration: Configuration parameter 'slug' is 'My Project', but it must match the pattern '^[a-z0-9-]+$'.");
+    assert_eq!(load_err(&["patterns", "--slug", "x", "--tag", "v1", "--tag", "latest"], &[]), "Invalid configuration: Configuration parameter 'tag' is 'latest', but it must match the pattern '^v[0-9]+$'.");
+}
@@ -0,0 +1,27 @@
+//! Has its own binary because it sets an env var, which the tests in `patterns.rs` would see
+
+macro_rules! test_name { () => { "patterns" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the value doesn't match").to_string()
+}
+
+#[test]
+fn pattern_checked_in_all_sources() {
+    let path = std::env::temp_dir().join(format!("configure_me_patterns_{}.toml", std::process::id()));
+    std::fs::write(&path, "slug = \"x\"\nbranch = \"feature/\"\n").unwrap();
+    let from_file = load(&["patterns"], &[&path]).err().map(|err| err.to_string());
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(from_file.as_deref(), Some("Invalid configuration: Configuration parameter 'branch' is 'feature/', but it must match the pattern '^[a-z]+(/[a-z]+)*$'."));
+
+    std::env::set_var("PATTERNS_SLUG", "under_score");
+    let from_env = load_err(&["patterns"], &[]);
+    std::env::remove_var("PATTERNS_SLUG");
+    assert_eq!(from_env, "Invalid configuration: Configuration parameter 'slug' is 'under_score', but it must match the pattern '^[a-z0-9-]+$'.");
+}
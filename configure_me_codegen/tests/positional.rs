@@ -0,0 +1,59 @@
+macro_rules! test_name { () => { "positional" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+fn load(args: &[&str]) -> Result<(config::Config, Vec<std::ffi::OsString>), config::Error> {
+    let no_files: &[&std::path::Path] = &[];
+    config::Config::custom_args_and_optional_files(args, no_files).map(|(config, remaining)| (config, remaining.collect()))
+}
+
+fn load_ok(args: &[&str]) -> config::Config {
+    load(args).unwrap_or_else(|err| panic!("{}", err)).0
+}
+
+#[test]
+fn mandatory_only() {
+    let config = load_ok(&["positional", "backup.tar"]);
+    assert_eq!(config.input, "backup.tar");
+    assert_eq!(config.count, None);
+    assert!(config.files.is_empty());
+}
+
+#[test]
+fn all_positionals() {
+    let (config, remaining) = load(&["positional", "-v", "--level", "9", "backup.tar", "0x10", "a.txt", "b.txt"]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.level, 9);
+    assert!(config.verbose);
+    assert_eq!(config.input, "backup.tar");
+    assert_eq!(config.count, Some(16));
+    assert_eq!(config.files, [PathBuf::from("a.txt"), PathBuf::from("b.txt")]);
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn after_separator() {
+    let config = load_ok(&["positional", "--", "--backup.tar"]);
+    assert_eq!(config.input, "--backup.tar");
+}
+
+#[test]
+fn missing_mandatory() {
+    let err = load(&["positional", "-v"]).err().expect("missing input is rejected");
+    assert_eq!(err.to_string(), "Invalid configuration: Configuration parameter 'input' (INPUT) not specified.");
+}
+
+#[test]
+fn invalid_value() {
+    let err = load(&["positional", "backup.tar", "many"]).err().expect("invalid count is rejected");
+    assert!(err.to_string().starts_with("Failed to parse argument 'COUNT'"), "{}", err);
+}
+
+#[test]
+fn usage() {
+    let err = load(&["positional", "--help"]).err().expect("help is requested");
+    let usage = err.to_string();
+    assert!(usage.starts_with("Usage: positional [--level LEVEL] [-v|--verbose] INPUT [COUNT] [FILES...]"), "{}", usage);
+    assert!(usage.contains("\n\nPositional arguments:\n        INPUT      Name of the archive\n"), "{}", usage);
+}
@@ -0,0 +1,78 @@
+macro_rules! test_name { () => { "profiles" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+fn write_config(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("configure_me_profiles_{}_{}.toml", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn load(args: &[&str], config_file: &PathBuf) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, &[config_file]).map(|(config, _)| config)
+}
+
+#[test]
+fn profile_values_are_layered() {
+    let config_file = write_config("layered", "port = 1\nhost = \"base\"\n\n[profile.production]\nport = 2\n\n[profile.dev]\nhost = \"dev\"\n");
+    let base = load(&["profiles"], &config_file);
+    let production = load(&["profiles", "--profile", "production"], &config_file);
+    let dev = load(&["profiles", "--profile=dev", "--port", "3"], &config_file);
+    let unknown = load(&["profiles", "--profile", "staging"], &config_file);
+    let empty = write_config("empty", "");
+    let from_arg = load(&["profiles", "--profile", "production", "--config", config_file.to_str().unwrap()], &empty);
+    std::fs::remove_file(&config_file).unwrap();
+    std::fs::remove_file(&empty).unwrap();
+
+    let base = base.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((base.port, base.host.as_deref()), (Some(1), Some("base")));
+    let production = production.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((production.port, production.host.as_deref()), (Some(2), Some("base")));
+    // Arguments still override the values of the profile
+    let dev = dev.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((dev.port, dev.host.as_deref()), (Some(3), Some("dev")));
+    let unknown = unknown.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((unknown.port, unknown.host.as_deref()), (Some(1), Some("base")));
+    let from_arg = from_arg.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((from_arg.port, from_arg.host.as_deref()), (Some(2), Some("base")));
+}
+
+#[test]
+fn invalid_profiles() {
+    let config_file = write_config("not_table", "port = 1\nprofile = \"production\"\n");
+    let err = load(&["profiles"], &config_file).err().expect("profile isn't a table");
+    std::fs::remove_file(&config_file).unwrap();
+    assert!(err.to_string().contains("`profile` is not a table"), "{}", err);
+
+    let config_file = write_config("profile_not_table", "port = 1\n\n[profile]\nproduction = 2\n");
+    let err = load(&["profiles", "--profile", "production"], &config_file).err().expect("the profile isn't a table");
+    std::fs::remove_file(&config_file).unwrap();
+    assert!(err.to_string().contains("`profile.production` is not a table"), "{}", err);
+}
+
+#[test]
+fn abbreviated_profile_option() {
+    let config_file = write_config("abbreviated", "port = 1\n\n[profile.production]\nport = 2\n");
+    let prefix = load(&["profiles", "--prof", "production"], &config_file);
+    let upper_case = load(&["profiles", "--PROFILE=production"], &config_file);
+    std::fs::remove_file(&config_file).unwrap();
+
+    assert_eq!(prefix.unwrap_or_else(|err| panic!("{}", err)).port, Some(2));
+    assert_eq!(upper_case.unwrap_or_else(|err| panic!("{}", err)).port, Some(2));
+}
+
+#[test]
+fn profile_option_in_values() {
+    let config_file = write_config("in_values", "port = 1\nhost = \"base\"\n\n[profile.production]\nport = 2\n");
+    let value = load(&["profiles", "--host", "--profile", "production"], &config_file);
+    let separated = load(&["profiles", "--", "--profile", "production"], &config_file);
+    std::fs::remove_file(&config_file).unwrap();
+
+    // `--profile` is the value of `--host`, so no profile is selected
+    let value = value.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((value.port, value.host.as_deref()), (Some(1), Some("--profile")));
+    // Arguments after `--` aren't options
+    assert_eq!(separated.unwrap_or_else(|err| panic!("{}", err)).port, Some(1));
+}
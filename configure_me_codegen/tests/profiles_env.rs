@@ -0,0 +1,32 @@
+//! Has its own binary because it sets an env var, which the tests in `profiles.rs` would see
+
+macro_rules! test_name { () => { "profiles" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+fn write_config(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("configure_me_profiles_{}_{}.toml", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn load(args: &[&str], config_file: &PathBuf) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, &[config_file]).map(|(config, _)| config)
+}
+
+#[test]
+fn profile_from_env() {
+    let config_file = write_config("env", "port = 1\nhost = \"base\"\n\n[profile.production]\nport = 2\n\n[profile.dev]\nhost = \"dev\"\n");
+    std::env::set_var("PROFILES_PROFILE", "production");
+    let from_env = load(&["profiles"], &config_file);
+    let arg_over_env = load(&["profiles", "--profile", "dev"], &config_file);
+    std::env::remove_var("PROFILES_PROFILE");
+    std::fs::remove_file(&config_file).unwrap();
+
+    let from_env = from_env.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((from_env.port, from_env.host.as_deref()), (Some(2), Some("base")));
+    let arg_over_env = arg_over_env.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!((arg_over_env.port, arg_over_env.host.as_deref()), (Some(1), Some("dev")));
+}
@@ -0,0 +1,30 @@
+macro_rules! test_name { () => { "ranges" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the value is out of range").to_string()
+}
+
+#[test]
+fn values_in_range() {
+    let config = load(&["ranges", "--retries", "10", "--port", "1", "--ratio", "0.5"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.retries, 10);
+    assert_eq!(config.port, 1);
+    assert_eq!(config.ratio, Some(0.5));
+
+    let config = load(&["ranges", "--retries", "0"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.ratio, None);
+}
+
+#[test]
+fn values_out_of_range() {
+    assert_eq!(load_err(&["ranges", "--retries", "11"], &[]), "Invalid configuration: Configuration parameter 'retries' is 11, but it must be at most 10.");
+    assert_eq!(load_err(&["ranges", "--retries", "1", "--port", "0"], &[]), "Invalid configuration: Configuration parameter 'port' is 0, but it must be from 1 to 65535.");
+    assert_eq!(load_err(&["ranges", "--retries", "1", "--ratio", "1.5"], &[]), "Invalid configuration: Configuration parameter 'ratio' is 1.5, but it must be from 0 to 1.");
+}
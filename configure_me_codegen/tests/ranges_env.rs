@@ -0,0 +1,23 @@
+//! Has its own binary because it sets an env var, which the tests in `ranges.rs` would see
+
+macro_rules! test_name { () => { "ranges" } }
+
+include!("glue/boilerplate.rs");
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    config::Config::custom_args_and_optional_files(args, files).err().expect("the value is out of range").to_string()
+}
+
+#[test]
+fn range_checked_in_all_sources() {
+    let path = std::env::temp_dir().join(format!("configure_me_ranges_{}.toml", std::process::id()));
+    std::fs::write(&path, "retries = 20\n").unwrap();
+    let from_file = load_err(&["ranges"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(from_file, "Invalid configuration: Configuration parameter 'retries' is 20, but it must be at most 10.");
+
+    std::env::set_var("RANGES_RATIO", "-0.25");
+    let from_env = load_err(&["ranges", "--retries", "1"], &[]);
+    std::env::remove_var("RANGES_RATIO");
+    assert_eq!(from_env, "Invalid configuration: Configuration parameter 'ratio' is -0.25, but it must be from 0 to 1.");
+}
@@ -0,0 +1,40 @@
+macro_rules! test_name { () => { "redacted_rendering" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str]) -> config::Config {
+    config::Config::custom_args_and_optional_files(args, std::iter::empty::<&str>())
+        .map(|(config, _)| config)
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[test]
+fn string() {
+    let config = load(&["redacted", "--port", "42", "--password", "hunter2", "--peers", "a", "b", "--verbose"]);
+    assert_eq!(config.to_redacted_string(), "port = 42, password = <redacted>, peers = a b, verbose = true");
+}
+
+#[test]
+fn string_skips_unset() {
+    let config = load(&["redacted", "--port", "42", "--data-dir", "/var/lib/foo"]);
+    assert_eq!(config.to_redacted_string(), "port = 42, peers = localhost, data_dir = /var/lib/foo, verbose = false");
+}
+
+#[test]
+fn toml() {
+    let config = load(&["redacted", "--port", "42", "--password", "hunter2", "--data-dir", "/var/lib/foo"]);
+    let rendered = config.to_redacted_toml().unwrap();
+    assert!(!rendered.contains("hunter2"), "{}", rendered);
+
+    let path = std::env::temp_dir().join(format!("configure_me_redacted_rendering_toml_{}.toml", std::process::id()));
+    std::fs::write(&path, &rendered).unwrap();
+    let loaded = config::Config::custom_args_and_optional_files(&["redacted"], &[&path])
+        .map(|(config, _)| config)
+        .unwrap_or_else(|err| panic!("{}", err));
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.port, 42);
+    assert_eq!(loaded.password.as_deref(), Some("<redacted>"));
+    assert_eq!(loaded.peers, ["localhost"]);
+    assert_eq!(loaded.data_dir.as_deref(), Some(std::ffi::OsStr::new("/var/lib/foo")));
+    assert!(!loaded.verbose);
+}
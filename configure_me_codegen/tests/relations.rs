@@ -0,0 +1,28 @@
+macro_rules! test_name { () => { "relations" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the relation is violated").to_string()
+}
+
+#[test]
+fn satisfied_relations() {
+    let config = load(&["relations", "--tls-cert", "cert.pem", "--tls-key", "key.pem"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.tls_cert.as_deref(), Some("cert.pem"));
+    assert_eq!(config.http_port, 80);
+
+    let config = load(&["relations", "--insecure", "--http-port", "8080"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert!(config.insecure);
+    assert_eq!(config.http_port, 8080);
+}
+
+#[test]
+fn missing_requirement() {
+    assert_eq!(load_err(&["relations", "--tls-cert", "cert.pem"], &[]), "Invalid configuration: Configuration parameter 'tls_cert' (from argument --tls-cert) requires 'tls_key', which isn't set.");
+    assert_eq!(load_err(&["relations", "--http-port", "8080"], &[]), "Invalid configuration: Configuration parameter 'http_port' (from argument --http-port) requires 'insecure', which isn't set.");
+}
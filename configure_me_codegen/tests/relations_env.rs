@@ -0,0 +1,24 @@
+//! Has its own binary because it sets an env var, which the tests in `relations.rs` would see
+
+macro_rules! test_name { () => { "relations" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the relation is violated").to_string()
+}
+
+#[test]
+fn conflict_reports_sources() {
+    let path = std::env::temp_dir().join(format!("configure_me_relations_{}.toml", std::process::id()));
+    std::fs::write(&path, "insecure = true\n").unwrap();
+    std::env::set_var("RELATIONS_TLS_CERT", "cert.pem");
+    let err = load_err(&["relations", "--tls-key", "key.pem"], &[&path]);
+    std::env::remove_var("RELATIONS_TLS_CERT");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err, format!("Invalid configuration: Configuration parameters 'tls_cert' (from environment variable RELATIONS_TLS_CERT) and 'insecure' (from config file {}) can't be used together.", path.display()));
+}
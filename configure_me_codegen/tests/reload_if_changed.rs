@@ -0,0 +1,77 @@
+macro_rules! test_name { () => { "reload_if_changed" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("configure_me_reload_if_changed_{}_{}.toml", name, std::process::id()))
+}
+
+fn load(args: &[&str], files: &[&PathBuf]) -> config::Config {
+    config::Config::custom_args_and_optional_files(args, files)
+        .map(|(config, _)| config)
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn reload(config: &config::Config) -> Option<u16> {
+    config.reload_if_changed()
+        .unwrap_or_else(|err| panic!("{}", err))
+        .map(|config| config.port)
+}
+
+#[test]
+fn optional_files() {
+    let present = temp_path("present");
+    let missing = temp_path("missing");
+    let _ = std::fs::remove_file(&missing);
+    std::fs::write(&present, "port = 1\n").unwrap();
+
+    let config = load(&["reload_if_changed"], &[&missing, &present]);
+    assert_eq!(config.port, 1);
+    assert_eq!(reload(&config), None);
+
+    // Rewriting the same content isn't a change
+    std::fs::write(&present, "port = 1\n").unwrap();
+    assert_eq!(reload(&config), None);
+
+    std::fs::write(&present, "port = 22\n").unwrap();
+    assert_eq!(reload(&config), Some(22));
+
+    // Earlier files have a higher priority
+    std::fs::write(&missing, "port = 333\n").unwrap();
+    assert_eq!(reload(&config), Some(333));
+
+    let _ = std::fs::remove_file(&present);
+    let _ = std::fs::remove_file(&missing);
+}
+
+#[test]
+fn file_from_arguments() {
+    let file = temp_path("argument");
+    std::fs::write(&file, "port = 1\n").unwrap();
+
+    let config = load(&["reload_if_changed", "--config", file.to_str().unwrap()], &[]);
+    assert_eq!(config.port, 1);
+    assert_eq!(reload(&config), None);
+
+    std::fs::write(&file, "port = 4444\n").unwrap();
+    let reloaded = config.reload_if_changed().unwrap_or_else(|err| panic!("{}", err)).expect("the file changed");
+    assert_eq!(reloaded.port, 4444);
+    assert_eq!(reload(&reloaded), None);
+
+    std::fs::remove_file(&file).unwrap();
+    assert!(config.reload_if_changed().is_err());
+}
+
+#[test]
+fn arguments_are_kept() {
+    let file = temp_path("kept_arguments");
+    std::fs::write(&file, "port = 1\n").unwrap();
+
+    let config = load(&["reload_if_changed", "--port", "80"], &[&file]);
+    std::fs::write(&file, "port = 22\n").unwrap();
+    assert_eq!(reload(&config), Some(80));
+
+    let _ = std::fs::remove_file(&file);
+}
@@ -0,0 +1,88 @@
+macro_rules! test_name { () => { "remote_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+/// Responds to a single request of a test client
+fn respond(stream: TcpStream) {
+    let mut request_line = String::new();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    reader.read_line(&mut request_line).unwrap();
+    let mut header = String::new();
+    while reader.read_line(&mut header).unwrap() > 2 {
+        header.clear();
+    }
+    let path = request_line.split(' ').nth(1).unwrap_or("");
+    let (status, body) = match path {
+        "/app.toml" => ("200 OK", "port = 8080\nhost = \"remote\"\n".to_owned()),
+        "/large.toml" => ("200 OK", format!("host = \"{}\"\n", "x".repeat(64))),
+        "/error.toml" => ("500 Internal Server Error", String::new()),
+        "/slow.toml" => {
+            std::thread::sleep(std::time::Duration::from_secs(3));
+            return;
+        },
+        _ => ("404 Not Found", String::new()),
+    };
+    let mut stream = stream;
+    let _ = write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body);
+}
+
+/// Starts a server of config files, returns its URL
+fn serve() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = stream.unwrap();
+            std::thread::spawn(move || respond(stream));
+        }
+    });
+    url
+}
+
+fn load(args: &[&str], files: &[&str]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(url: &str) -> String {
+    load(&["remote_config"], &[url]).err().expect("the remote config file is invalid").to_string()
+}
+
+#[test]
+fn remote_config_files() {
+    let url = serve();
+    let app = format!("{}/app.toml", url);
+    let config = load(&["remote_config"], &[&app]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, Some(8080));
+    assert_eq!(config.host.as_deref(), Some("remote"));
+
+    let config = load(&["remote_config", "--config", &app], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, Some(8080));
+
+    // Unlike the local ones, missing remote files aren't optional
+    let missing = format!("{}/missing.toml", url);
+    let err = load_err(&missing);
+    assert!(err.ends_with("the server responded 404 Not Found"), "{}", err);
+    assert!(load(&["remote_config", "--config", &missing], &[]).is_err());
+}
+
+#[test]
+fn plain_http_requires_opt_in() {
+    let app = format!("{}/app.toml", serve());
+    let read = |allow_http| ::configure_me::helpers::read_config_source(app.as_ref(), Default::default(), std::time::Duration::from_secs(1), allow_http);
+    assert_eq!(read(false).unwrap_err().kind(), std::io::ErrorKind::PermissionDenied);
+    assert_eq!(&*read(true).unwrap(), b"port = 8080\nhost = \"remote\"\n");
+}
+
+#[test]
+fn invalid_remote_config_files() {
+    let url = serve();
+    let err = load_err(&format!("{}/large.toml", url));
+    assert!(err.ends_with("the file is larger than 64 bytes"), "{}", err);
+    let err = load_err(&format!("{}/error.toml", url));
+    assert!(err.ends_with("the server responded 500 Internal Server Error"), "{}", err);
+    let err = load_err(&format!("{}/slow.toml", url));
+    assert!(err.starts_with("Failed to read configuration file"), "{}", err);
+}
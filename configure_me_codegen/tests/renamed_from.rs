@@ -0,0 +1,35 @@
+macro_rules! test_name { () => { "renamed_from" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(&["renamed"], files).map(|(config, _)| config)
+}
+
+fn load_file(content: &str) -> config::Config {
+    let path = std::env::temp_dir().join(format!("configure_me_renamed_from_{}.toml", std::process::id()));
+    std::fs::write(&path, content).unwrap();
+    let config = load(&[&path]);
+    std::fs::remove_file(&path).unwrap();
+    config.unwrap_or_else(|err| panic!("{}", err))
+}
+
+// A single test, so that the environment isn't modified concurrently.
+#[test]
+fn renamed_from() {
+    let config = load_file("listen_port = 1234\ndata_path = \"/srv\"\n");
+    assert_eq!(config.port, 1234);
+    assert_eq!(config.data_dir.as_deref(), Some(std::path::Path::new("/srv")));
+
+    let config = load_file("port = 42\nbind_port = 1234\n");
+    assert_eq!(config.port, 42);
+
+    std::env::set_var("RENAMED_BIND_PORT", "1234");
+    assert_eq!(load(&[]).unwrap_or_else(|err| panic!("{}", err)).port, 1234);
+    std::env::set_var("RENAMED_PORT", "42");
+    assert_eq!(load(&[]).unwrap_or_else(|err| panic!("{}", err)).port, 42);
+
+    std::env::set_var("RENAMED_DATA_PATH", "");
+    let err = load(&[]).err().expect("empty data path is rejected");
+    assert_eq!(err.to_string(), "The environment variable 'RENAMED_DATA_PATH' is set but empty.");
+}
@@ -0,0 +1,42 @@
+macro_rules! test_name { () => { "rich_help" } }
+
+include!("glue/boilerplate.rs");
+
+fn help() -> String {
+    let no_files: &[&std::path::Path] = &[];
+    let err = config::Config::custom_args_and_optional_files(&["rich_help", "--help"], no_files).map(|(config, _)| config).err().expect("help is requested");
+    err.to_string()
+}
+
+// A single test, because the width is taken from the environment
+#[test]
+fn wrapped_to_terminal_width() {
+    std::env::set_var("NO_COLOR", "1");
+
+    std::env::set_var("COLUMNS", "80");
+    assert_eq!(help(), "\
+Usage: rich_help [-p PORT|--port PORT] [--bind-addr BIND_ADDR] [--token TOKEN]
+        [-v|--verbose] [ROOT]
+
+Arguments:
+  -p, --port PORT          Port to listen on [default: 8080] [env: APP_PORT]
+  --bind-addr BIND_ADDR    Address the server binds to, use 127.0.0.1 to accept
+                           only local connections [default: \"0.0.0.0\"]
+                           [env: APP_BIND_ADDR]
+  --token TOKEN            [env: APP_TOKEN]
+  -v, --verbose            Print more information [env: APP_VERBOSE]
+  -h, --help               Print this help and exit.
+
+Positional arguments:
+  ROOT    Directory to serve
+
+Integers may also be written in hexadecimal (0x1F), octal (0o755) or binary
+(0b1010) notation.");
+
+    // Too narrow for the docs next to the names
+    std::env::set_var("COLUMNS", "50");
+    assert!(help().contains("\nArguments:\n  -p, --port PORT\n        Port to listen on [default: 8080]\n        [env: APP_PORT]\n"), "{}", help());
+
+    std::env::remove_var("COLUMNS");
+    assert!(help().contains("\n  -v, --verbose            Print more information [env: APP_VERBOSE]\n"), "{}", help());
+}
@@ -0,0 +1,37 @@
+macro_rules! test_name { () => { "secrets" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).expect_err("the configuration is invalid").to_string()
+}
+
+#[test]
+fn debug_redacts_secrets() {
+    let config = load(&["secrets", "--password", "hunter2", "--pin", "1234", "--database-token", "s3cr3t"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.password, "hunter2");
+    assert_eq!(format!("{:?}", config), "Config { port: 8080, password: <redacted>, pin: Some(<redacted>), verbose: false, database: Database { url: \"postgres://localhost\", token: Some(<redacted>) } }");
+
+    let config = load(&["secrets", "--password", "hunter2"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(format!("{:?}", config), "Config { port: 8080, password: <redacted>, pin: None, verbose: false, database: Database { url: \"postgres://localhost\", token: None } }");
+}
+
+#[test]
+fn errors_hide_secrets() {
+    assert_eq!(load_err(&["secrets", "--password", "hunter2", "--pin", "12345"], &[]), "Invalid configuration: Configuration parameter 'pin' is <redacted>, but it must be at most 9999.");
+    assert_eq!(load_err(&["secrets", "--password", "hunter 2"], &[]), "Invalid configuration: Configuration parameter 'password' is '<redacted>', but it must match the pattern '^[^ ]+$'.");
+    let err = load_err(&["secrets", "--password", "hunter2", "--pin", "12ab"], &[]);
+    assert!(err.starts_with("Failed to parse argument '--pin'.\n\nHint: the value must be "), "{}", err);
+    assert!(!err.contains("12ab"), "{}", err);
+
+    let path = std::env::temp_dir().join(format!("configure_me_secrets_{}.toml", std::process::id()));
+    std::fs::write(&path, "password = 1234\n").unwrap();
+    let err = load_err(&["secrets"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert!(err.contains("invalid value of a secret parameter"), "{}", err);
+    assert!(!err.contains("1234"), "{}", err);
+}
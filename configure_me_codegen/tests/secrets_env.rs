@@ -0,0 +1,33 @@
+//! Has its own binary because it sets env vars, which the tests in `secrets.rs` would see
+
+macro_rules! test_name { () => { "secrets" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).expect_err("the configuration is invalid").to_string()
+}
+
+#[test]
+fn secret_files() {
+    let path = std::env::temp_dir().join(format!("configure_me_secrets_{}", std::process::id()));
+    std::fs::write(&path, "hunter2\n").unwrap();
+    std::env::set_var("SECRETS_PASSWORD_FILE", &path);
+    let from_file = load(&["secrets"], &[]).map(|config| config.password);
+
+    std::env::set_var("SECRETS_PASSWORD", "swordfish");
+    let from_var = load(&["secrets"], &[]).map(|config| config.password);
+    std::env::remove_var("SECRETS_PASSWORD");
+
+    std::fs::remove_file(&path).unwrap();
+    let missing = load_err(&["secrets"], &[]);
+    std::env::remove_var("SECRETS_PASSWORD_FILE");
+
+    assert_eq!(from_file.unwrap_or_else(|err| panic!("{}", err)), "hunter2");
+    assert_eq!(from_var.unwrap_or_else(|err| panic!("{}", err)), "swordfish");
+    assert!(missing.starts_with(&format!("Failed to read the file {} named by the environment variable 'SECRETS_PASSWORD_FILE': ", path.display())), "{}", missing);
+}
@@ -0,0 +1,49 @@
+macro_rules! test_name { () => { "sections" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_file(args: &[&str], content: &str) -> Result<config::Config, config::Error> {
+    let path = std::env::temp_dir().join(format!("configure_me_sections_{}.toml", std::process::id()));
+    std::fs::write(&path, content).unwrap();
+    let config = load(args, &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    config
+}
+
+// A single test, so that the environment isn't modified concurrently.
+#[test]
+fn sections() {
+    let config = load(&["sections", "--database-url", "postgres://localhost", "--log-level=debug"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.database.url, "postgres://localhost");
+    assert_eq!(config.database.pool_size, 4);
+    assert_eq!(config.log.level.as_deref(), Some("debug"));
+
+    let config = load_file(&["sections"], "port = 1234\n\n[database]\nurl = \"sqlite://db\"\npool_size = 8\n\n[log]\nverbosity = \"warn\"\n").unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 1234);
+    assert_eq!(config.database.url, "sqlite://db");
+    assert_eq!(config.database.pool_size, 8);
+    assert_eq!(config.log.level.as_deref(), Some("warn"));
+
+    let toml = config.to_redacted_toml().unwrap();
+    let config = load_file(&["sections"], &toml).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.database.pool_size, 8);
+    assert_eq!(config.log.level.as_deref(), Some("warn"));
+
+    // The prefixed names are only accepted inside the section
+    let err = load_file(&["sections"], "database_url = \"sqlite://db\"\n\n[database]\nurl = \"sqlite://db\"\n").err().expect("the prefixed name is rejected");
+    assert!(err.to_string().contains("`database_url` must be written as `url` in `[database]`"), "{}", err);
+
+    let err = load_file(&["sections"], "database = 42\n").err().expect("section isn't a table");
+    assert!(err.to_string().contains("`database` is not a table"), "{}", err);
+
+    std::env::set_var("SECTIONS_DATABASE_URL", "mysql://env");
+    std::env::set_var("SECTIONS_DATABASE_POOL_SIZE", "16");
+    let config = load(&["sections"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.database.url, "mysql://env");
+    assert_eq!(config.database.pool_size, 16);
+}
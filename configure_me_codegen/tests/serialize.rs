@@ -0,0 +1,42 @@
+macro_rules! test_name { () => { "serialize" } }
+
+include!("glue/boilerplate.rs");
+
+extern crate serde_json;
+
+fn load(args: &[&str]) -> config::Config {
+    config::Config::custom_args_and_optional_files(args, &[] as &[&str]).map(|(config, _)| config).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[test]
+fn serialized_as_config_file() {
+    let config = load(&["serialize", "--labels", "team=core", "--password", "hunter2", "--timeout", "1m 30s", "--tags", "a", "--tags", "b", "--database-url", "postgres://localhost"]);
+    let toml = configure_me::toml::to_string(&config).unwrap();
+    assert_eq!(toml, "port = 8080\npassword = \"<redacted>\"\ntimeout = \"1m 30s\"\ntags = [\"a\", \"b\"]\nverbose = false\n\n[labels]\nteam = \"core\"\n\n[database]\nurl = \"postgres://localhost\"\n");
+
+    // The serialized configuration can be loaded again
+    let path = std::env::temp_dir().join(format!("configure_me_serialize_{}.toml", std::process::id()));
+    std::fs::write(&path, toml.replace("\"<redacted>\"", "\"hunter2\"")).unwrap();
+    let reloaded = config::Config::custom_args_and_optional_files(&["serialize"], &[&path]).map(|(config, _)| config);
+    std::fs::remove_file(&path).unwrap();
+    let reloaded = reloaded.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(reloaded.timeout, config.timeout);
+    assert_eq!(reloaded.tags, config.tags);
+    assert_eq!(reloaded.labels, config.labels);
+    assert_eq!(reloaded.database.url, config.database.url);
+}
+
+#[test]
+fn unset_values_are_null_in_json() {
+    let config = load(&["serialize", "--verbose"]);
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(json, serde_json::json!({
+        "port": 8080,
+        "labels": null,
+        "password": null,
+        "timeout": null,
+        "tags": null,
+        "verbose": true,
+        "database": { "url": null },
+    }));
+}
@@ -0,0 +1,32 @@
+macro_rules! test_name { () => { "shared_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::iter;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+
+fn parse(args: &[&str]) -> config::Config {
+    config::Config::custom_args_and_optional_files(args, iter::empty::<PathBuf>()).map(|(config, _)| config).ok().unwrap()
+}
+
+#[test]
+fn replace() {
+    let shared = config::SharedConfig::new(parse(&["shared_config"]));
+    let before = shared.load();
+    assert_eq!(before.port, 8080);
+
+    let previous = shared.replace(parse(&["shared_config", "--port", "443"]));
+    assert_eq!(previous.port, 8080);
+    assert_eq!(shared.load().port, 443);
+    assert_eq!(before.port, 8080);
+}
+
+#[test]
+fn replace_from_another_thread() {
+    let shared = Arc::new(config::SharedConfig::from(parse(&["shared_config"])));
+    let reloader = Arc::clone(&shared);
+    thread::spawn(move || { reloader.replace(parse(&["shared_config", "--port", "443"])); }).join().unwrap();
+    assert_eq!(shared.load().port, 443);
+}
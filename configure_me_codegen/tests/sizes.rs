@@ -0,0 +1,40 @@
+macro_rules! test_name { () => { "sizes" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the size is invalid").to_string()
+}
+
+#[test]
+fn sizes_from_args() {
+    let config = load(&["sizes", "--cache-size", "512MiB", "--buffer-size", "4 kB", "2GB"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.cache_size, 512 * 1024 * 1024);
+    assert_eq!(config.buffer_size, Some(4000));
+    assert_eq!(config.limit, Some(2_000_000_000));
+
+    let config = load(&["sizes", "--buffer-size", "4096"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.cache_size, 64 * 1024 * 1024);
+    assert_eq!(config.buffer_size, Some(4096));
+    assert_eq!(config.limit, None);
+}
+
+#[test]
+fn invalid_sizes() {
+    let err = load_err(&["sizes", "--buffer-size", "4 parsecs"], &[]);
+    assert!(err.starts_with("Failed to parse argument '--buffer-size'"), "{}", err);
+    assert!(err.contains("unknown unit 'parsecs'"), "{}", err);
+    let err = load_err(&["sizes", "--buffer-size", "20EiB"], &[]);
+    assert!(err.contains("the size doesn't fit into 64 bits"), "{}", err);
+    assert_eq!(load_err(&["sizes", "--cache-size", "2GiB"], &[]), "Invalid configuration: Configuration parameter 'cache_size' is 2147483648, but it must be at most 1073741824.");
+
+    let path = std::env::temp_dir().join(format!("configure_me_sizes_invalid_{}.toml", std::process::id()));
+    std::fs::write(&path, "buffer_size = \"lots\"\n").unwrap();
+    let err = load_err(&["sizes"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert!(err.contains("invalid size 'lots'"), "{}", err);
+}
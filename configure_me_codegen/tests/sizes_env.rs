@@ -0,0 +1,25 @@
+//! Has its own binary because it sets an env var, which the tests in `sizes.rs` would see
+
+macro_rules! test_name { () => { "sizes" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+#[test]
+fn sizes_from_all_sources() {
+    let path = std::env::temp_dir().join(format!("configure_me_sizes_{}.toml", std::process::id()));
+    std::fs::write(&path, "cache_size = \"1GiB\"\nbuffer_size = 8192\n").unwrap();
+    let from_file = load(&["sizes"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    let from_file = from_file.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(from_file.cache_size, 1 << 30);
+    assert_eq!(from_file.buffer_size, Some(8192));
+
+    std::env::set_var("SIZES_BUFFER_SIZE", "64KiB");
+    let from_env = load(&["sizes"], &[]);
+    std::env::remove_var("SIZES_BUFFER_SIZE");
+    assert_eq!(from_env.unwrap_or_else(|err| panic!("{}", err)).buffer_size, Some(64 * 1024));
+}
@@ -0,0 +1,44 @@
+macro_rules! test_name { () => { "conf_files" } }
+
+include!("glue/boilerplate.rs");
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const CHILD_VAR: &str = "CONFIGURE_ME_STDIN_CONFIG_CHILD";
+
+/// Loads the configuration piped to the test by `config_from_stdin()` and prints the value
+#[test]
+fn stdin_child() {
+    if std::env::var_os(CHILD_VAR).is_none() {
+        return;
+    }
+    let config = config::Config::custom_args_and_optional_files(&["test", "--foo=1", "--config", "-"], &[] as &[&str]).map(|(config, _)| config.foo);
+    match config {
+        Ok(foo) => println!("foo = {:?}", foo),
+        Err(err) => println!("error: {}", err),
+    }
+}
+
+fn run_child(stdin: &str) -> String {
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "stdin_child", "--nocapture", "--test-threads=1"])
+        .env(CHILD_VAR, "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn config_from_stdin() {
+    let output = run_child("foo = 42\n");
+    assert!(output.contains("foo = Some(42)"), "{}", output);
+
+    let output = run_child("foo = \"forty-two\"\n");
+    assert!(output.contains("error: Failed to parse configuration file -"), "{}", output);
+}
@@ -0,0 +1,72 @@
+macro_rules! test_name { () => { "subcommands" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::PathBuf;
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_ok(args: &[&str]) -> config::Config {
+    load(args, &[]).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn serve(config: config::Config) -> config::serve::Config {
+    match config.subcommand {
+        Some(config::Subcommand::Serve(serve)) => serve,
+        _ => panic!("serve wasn't selected"),
+    }
+}
+
+#[test]
+fn no_subcommand() {
+    let config = load_ok(&["tool", "-v"]);
+    assert!(config.verbose);
+    assert!(config.subcommand.is_none());
+}
+
+#[test]
+fn select_subcommand() {
+    let config = load_ok(&["tool", "--verbose", "serve", "--port", "80", "--tls"]);
+    assert!(config.verbose);
+    let serve = serve(config);
+    assert_eq!(serve.port, 80);
+    assert!(serve.tls);
+
+    let config = load_ok(&["tool", "check", "Cargo.toml"]);
+    match config.subcommand {
+        Some(config::Subcommand::Check(check)) => assert_eq!(check.path, PathBuf::from("Cargo.toml")),
+        _ => panic!("check wasn't selected"),
+    }
+}
+
+#[test]
+fn options_after_subcommand_belong_to_it() {
+    let err = load(&["tool", "serve", "-v"], &[]).err().expect("-v isn't an option of serve");
+    assert_eq!(err.to_string(), "An unknown argument '-v' was specified.");
+}
+
+#[test]
+fn unknown_subcommand() {
+    let err = load(&["tool", "stop"], &[]).err().expect("stop isn't a subcommand");
+    assert_eq!(err.to_string(), "An unknown subcommand 'stop' was specified.");
+}
+
+#[test]
+fn subcommand_errors() {
+    let err = load(&["tool", "check"], &[]).err().expect("path is missing");
+    assert_eq!(err.to_string(), "Invalid configuration: Configuration parameter 'path' (PATH) not specified.");
+
+    let err = load(&["tool", "serve", "--help"], &[]).err().expect("help is requested");
+    let usage = err.to_string();
+    assert!(usage.starts_with("Usage: tool serve [--port PORT] [--tls]"), "{}", usage);
+}
+
+#[test]
+fn usage() {
+    let err = load(&["tool", "--help"], &[]).err().expect("help is requested");
+    let usage = err.to_string();
+    assert!(usage.starts_with("Usage: tool [--config CONF_FILE] [-v|--verbose] [SUBCOMMAND ...]"), "{}", usage);
+    assert!(usage.ends_with("\n\nSubcommands:\n        serve      Start the server\n        check      Check a file without serving it"), "{}", usage);
+}
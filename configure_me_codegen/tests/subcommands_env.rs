@@ -0,0 +1,40 @@
+//! Has its own binary because it sets an env var, which the tests in `subcommands.rs` would see
+
+macro_rules! test_name { () => { "subcommands" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_ok(args: &[&str]) -> config::Config {
+    load(args, &[]).unwrap_or_else(|err| panic!("{}", err))
+}
+
+fn serve(config: config::Config) -> config::serve::Config {
+    match config.subcommand {
+        Some(config::Subcommand::Serve(serve)) => serve,
+        _ => panic!("serve wasn't selected"),
+    }
+}
+
+#[test]
+fn subcommand_sources() {
+    let path = std::env::temp_dir().join(format!("configure_me_subcommands_{}.toml", std::process::id()));
+    std::fs::write(&path, "verbose = true\n\n[serve]\nport = 1234\n").unwrap();
+    let config = load(&["tool", "serve"], &[&path]);
+    let from_arg = load(&["tool", "--config", path.to_str().unwrap(), "serve"], &[]);
+    std::fs::remove_file(&path).unwrap();
+
+    let config = config.unwrap_or_else(|err| panic!("{}", err));
+    assert!(config.verbose);
+    assert_eq!(serve(config).port, 1234);
+    let config = from_arg.unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(serve(config).port, 1234);
+
+    std::env::set_var("TOOL_SERVE_PORT", "443");
+    let config = load_ok(&["tool", "serve"]);
+    assert_eq!(serve(config).port, 443);
+    std::env::remove_var("TOOL_SERVE_PORT");
+}
@@ -0,0 +1,64 @@
+macro_rules! test_name { () => { "switch_values" } }
+
+include!("glue/boilerplate.rs");
+
+fn config_files() -> std::path::PathBuf {
+    let mut this = std::path::PathBuf::from(std::env::args_os().next().expect("Program name not specified"));
+
+    while let Some(file_name) = this.file_name() {
+        if *file_name == *"target" {
+            break;
+        }
+
+        this.pop();
+    }
+
+    if !this.pop() {
+        panic!("Can't find test assets");
+    }
+
+    this.push("configure_me_codegen");
+    if !this.exists() {
+        this.pop();
+    }
+    this.push("tests");
+    this.push("config_files");
+    this
+}
+
+fn parse(args: &[&str]) -> Result<config::Config, config::Error> {
+    let args = Some("test").into_iter().chain(args.iter().cloned());
+    config::Config::custom_args_and_optional_files(args, None::<&str>).map(|(config, _)| config)
+}
+
+#[test]
+fn switch_value_arguments() {
+    let config = parse(&["--verbose=yes", "--no-color=on"]).unwrap();
+    assert!(config.verbose);
+    assert!(!config.color);
+
+    let config = parse(&["--verbose=off", "--no-color=no"]).unwrap();
+    assert!(!config.verbose);
+    assert!(config.color);
+
+    // Plain switches still work
+    let config = parse(&["--verbose", "--no-color"]).unwrap();
+    assert!(config.verbose);
+    assert!(!config.color);
+}
+
+#[test]
+fn invalid_switch_value_lists_allowed_values() {
+    let err = parse(&["--verbose=1"]).err().expect("1 isn't allowed");
+    assert_eq!(err.to_string(), "Invalid value '1' for '--verbose'.\n\nHint: the allowed values are no, off, yes, on.");
+}
+
+#[test]
+fn switch_values_from_config_file() {
+    let config = parse(&["--config", config_files().join("switch_values.toml").to_str().unwrap()]).unwrap();
+    assert!(config.verbose);
+    assert!(!config.color);
+
+    let err = parse(&["--config", config_files().join("switch_values_invalid.toml").to_str().unwrap()]).err().expect("maybe isn't allowed");
+    assert!(err.to_string().contains("the allowed values are true, false, no, off, yes, on"), "{}", err);
+}
@@ -0,0 +1,22 @@
+//! Has its own binary because it sets an env var, which the tests in `switch_values.rs` would see
+
+macro_rules! test_name { () => { "switch_values" } }
+
+include!("glue/boilerplate.rs");
+
+fn parse(args: &[&str]) -> Result<config::Config, config::Error> {
+    let args = Some("test").into_iter().chain(args.iter().cloned());
+    config::Config::custom_args_and_optional_files(args, None::<&str>).map(|(config, _)| config)
+}
+
+#[test]
+fn switch_values_from_env() {
+    std::env::set_var("SWITCH_VALUES_TEST_COLOR", "off");
+    let result = parse(&[]);
+    std::env::set_var("SWITCH_VALUES_TEST_COLOR", "true");
+    let err = parse(&[]).err().expect("true isn't allowed");
+    std::env::remove_var("SWITCH_VALUES_TEST_COLOR");
+
+    assert!(!result.unwrap().color);
+    assert_eq!(err.to_string(), "Invalid value 'true' for 'SWITCH_VALUES_TEST_COLOR'.\n\nHint: the allowed values are no, off, yes, on.");
+}
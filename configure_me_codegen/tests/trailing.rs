@@ -0,0 +1,53 @@
+macro_rules! test_name { () => { "trailing" } }
+
+include!("glue/boilerplate.rs");
+
+fn load(args: &[&str]) -> Result<(config::Config, Vec<std::ffi::OsString>), config::Error> {
+    let no_files: &[&std::path::Path] = &[];
+    config::Config::custom_args_and_optional_files(args, no_files).map(|(config, remaining)| (config, remaining.collect()))
+}
+
+fn load_ok(args: &[&str]) -> (config::Config, Vec<std::ffi::OsString>) {
+    load(args).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[test]
+fn captures_after_separator() {
+    let (config, remaining) = load_ok(&["trailing", "-v", "example.com", "--", "80", "443"]);
+    assert!(config.verbose);
+    assert_eq!(config.host, "example.com");
+    assert_eq!(config.ports, [80, 443]);
+    assert!(remaining.is_empty());
+
+    let (config, remaining) = load_ok(&["trailing", "example.com", "--"]);
+    assert!(config.ports.is_empty());
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn arguments_before_separator_are_remaining() {
+    let (config, remaining) = load_ok(&["trailing", "example.com", "extra", "--", "80"]);
+    assert_eq!(config.ports, [80]);
+    assert_eq!(remaining, ["extra"]);
+
+    let (config, remaining) = load_ok(&["trailing", "example.com", "80"]);
+    assert!(config.ports.is_empty());
+    assert_eq!(remaining, ["80"]);
+}
+
+#[test]
+fn each_element_is_validated() {
+    let err = load(&["trailing", "example.com", "--", "80", "http"]).err().expect("invalid port is rejected");
+    assert!(err.to_string().starts_with("Failed to parse argument 'PORTS'"), "{}", err);
+
+    // Options after the separator are values too
+    let err = load(&["trailing", "example.com", "--", "80", "-v"]).err().expect("-v is a port");
+    assert!(err.to_string().starts_with("Failed to parse argument 'PORTS'"), "{}", err);
+}
+
+#[test]
+fn usage() {
+    let err = load(&["trailing", "--help"]).err().expect("help is requested");
+    let usage = err.to_string();
+    assert!(usage.starts_with("Usage: trailing [-v|--verbose] HOST [-- PORTS...]"), "{}", usage);
+}
@@ -0,0 +1,31 @@
+macro_rules! test_name { () => { "validate_config" } }
+
+include!("glue/boilerplate.rs");
+
+fn check_workers(config: &config::Config) -> Result<(), String> {
+    if config.min_workers > config.max_workers {
+        Err(format!("min_workers ({}) can't exceed max_workers ({})", config.min_workers, config.max_workers))
+    } else {
+        Ok(())
+    }
+}
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+#[test]
+fn valid_config() {
+    let config = load(&["validate_config", "--min-workers", "4", "--max-workers", "8"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.min_workers, 4);
+    assert_eq!(config.max_workers, 8);
+}
+
+#[test]
+fn invalid_config_after_merging() {
+    let path = std::env::temp_dir().join(format!("configure_me_validate_config_{}.toml", std::process::id()));
+    std::fs::write(&path, "max_workers = 2\n").unwrap();
+    let err = load(&["validate_config", "--min-workers", "3"], &[&path]).err().map(|err| err.to_string());
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err.as_deref(), Some("Invalid configuration: min_workers (3) can't exceed max_workers (2)"));
+}
@@ -0,0 +1,52 @@
+macro_rules! test_name { () => { "validators" } }
+
+include!("glue/boilerplate.rs");
+
+mod validators {
+    pub fn check_port(port: &u16) -> Result<(), String> {
+        if *port < 1024 {
+            Err(format!("port {} is privileged", port))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_user(user: &str) -> Result<(), String> {
+        if user.is_empty() {
+            Err("the name is empty".to_owned())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn load(args: &[&str], files: &[&std::path::Path]) -> Result<config::Config, config::Error> {
+    config::Config::custom_args_and_optional_files(args, files).map(|(config, _)| config)
+}
+
+fn load_err(args: &[&str], files: &[&std::path::Path]) -> String {
+    load(args, files).err().expect("the value is invalid").to_string()
+}
+
+#[test]
+fn valid_values() {
+    let config = load(&["validators", "--port", "8443", "--user", "alice", "--peer", "bob"], &[]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, 8443);
+    assert_eq!(config.user.as_deref(), Some("alice"));
+    assert_eq!(config.peer, Some(vec!["bob".to_owned()]));
+}
+
+#[test]
+fn invalid_values() {
+    assert_eq!(load_err(&["validators", "--port", "80"], &[]), "Invalid configuration: Configuration parameter 'port' is invalid: port 80 is privileged");
+    assert_eq!(load_err(&["validators", "--peer", "bob", "--peer", ""], &[]), "Invalid configuration: Configuration parameter 'peer' is invalid: the name is empty");
+}
+
+#[test]
+fn invalid_value_in_config_file() {
+    let path = std::env::temp_dir().join(format!("configure_me_validators_{}.toml", std::process::id()));
+    std::fs::write(&path, "user = \"\"\n").unwrap();
+    let err = load_err(&["validators"], &[&path]);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err, "Invalid configuration: Configuration parameter 'user' is invalid: the name is empty");
+}
@@ -0,0 +1,25 @@
+macro_rules! test_name { () => { "version" } }
+
+include!("glue/boilerplate.rs");
+
+fn version_requested(args: &[&str]) -> Option<String> {
+    match config::Config::custom_args_and_optional_files(args, &[] as &[&str]).map(|(config, _)| config) {
+        Err(config::Error::Arguments(config::ArgParseError::VersionRequested(version))) => Some(version),
+        _ => None,
+    }
+}
+
+#[test]
+fn version_is_requested() {
+    let expected = format!("version {}", env!("CARGO_PKG_VERSION"));
+    assert_eq!(version_requested(&["version", "--version"]), Some(expected.clone()));
+    assert_eq!(version_requested(&["version", "-V"]), Some(expected.clone()));
+    assert_eq!(version_requested(&["version", "-vV"]), Some(expected));
+    assert_eq!(version_requested(&["version", "--port", "80"]), None);
+}
+
+#[test]
+fn version_is_displayed() {
+    let err = config::Config::custom_args_and_optional_files(&["version", "--version"], &[] as &[&str]).map(|(config, _)| config).err().expect("version requested");
+    assert_eq!(err.to_string(), format!("version {}", env!("CARGO_PKG_VERSION")));
+}
@@ -0,0 +1,40 @@
+macro_rules! test_name { () => { "watch_config" } }
+
+include!("glue/boilerplate.rs");
+
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Replaces the file by renaming another one over it, like editors do
+fn replace(path: &Path, content: &str) {
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, content).unwrap();
+    std::fs::rename(&tmp, path).unwrap();
+}
+
+#[test]
+fn changes_are_delivered() {
+    let dir = std::env::temp_dir().join(format!("configure_me_watch_config_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config_file = dir.join("config.toml");
+    std::fs::write(&config_file, "port = 1\n").unwrap();
+
+    let (config, _) = config::Config::custom_args_and_optional_files(&["watch_config"], &[&config_file]).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(config.port, Some(1));
+    let (sender, receiver) = mpsc::channel();
+    let watcher = config.watch(move |config| {
+        let _ = sender.send(config.map(|config| config.port).map_err(|err| err.to_string()));
+    }).unwrap();
+
+    replace(&config_file, "port = 22\n");
+    let port = receiver.recv_timeout(Duration::from_secs(10)).expect("the change wasn't delivered");
+    assert_eq!(port, Ok(Some(22)));
+
+    replace(&config_file, "port = \"invalid\"\n");
+    let err = receiver.recv_timeout(Duration::from_secs(10)).expect("the change wasn't delivered").unwrap_err();
+    assert!(err.starts_with("Failed to parse configuration file"), "{}", err);
+
+    drop(watcher);
+    std::fs::remove_dir_all(&dir).unwrap();
+}